@@ -15,12 +15,13 @@
 //!       "vvm": "../../voicevox_core/models/vvms/0.vvm",
 //!       "include-long-input-text": true,
 //!       "include-open-and-close-vvm": true,
+//!       "parallelism": 8,
 //!       "iterate-more": true
 //!     }' \
 //!   cargo bench -p voicevox_core --features load-onnxruntime --bench walltime
 //! ```
 
-use std::{env, path::PathBuf, sync::LazyLock};
+use std::{env, num::NonZero, path::PathBuf, sync::LazyLock};
 
 use serde::Deserialize;
 use voicevox_core::{AccelerationMode, StyleId};
@@ -86,6 +87,10 @@ struct Config {
     #[serde(default)]
     include_open_and_close_vvm: bool,
 
+    /// `parallel_synthesis`で同時に行う音声合成の数。
+    #[serde(default = "default_parallelism")]
+    parallelism: NonZero<usize>,
+
     /// イテレート回数を、ウォームアップも含めて増やす。
     #[serde(default)]
     iterate_more: bool,
@@ -99,6 +104,10 @@ fn default_vvm_path() -> PathBuf {
     test_util::SAMPLE_VOICE_MODEL_FILE_PATH.into()
 }
 
+fn default_parallelism() -> NonZero<usize> {
+    NonZero::new(4).expect("should be non-zero")
+}
+
 /// 製品版VVMでは"四国めたん（あまあま）"。
 fn default_style_id() -> StyleId {
     StyleId(0)
@@ -113,6 +122,15 @@ impl Config {
         }
     }
 
+    /// `parallel_synthesis`で比較する、セッションの複製数。
+    fn session_replicas(&self) -> Vec<NonZero<usize>> {
+        if self.parallelism == NonZero::<usize>::MIN {
+            vec![NonZero::<usize>::MIN]
+        } else {
+            vec![NonZero::<usize>::MIN, self.parallelism]
+        }
+    }
+
     fn iterations_for_light_operations(&self) -> Iterations {
         if self.iterate_more {
             Iterations {
@@ -200,7 +218,7 @@ fn main() {
 }
 
 mod blocking {
-    use std::sync::LazyLock;
+    use std::{num::NonZero, sync::LazyLock, thread};
 
     use divan::Bencher;
     use voicevox_core::blocking::{
@@ -339,10 +357,54 @@ mod blocking {
         }
         bencher.bench_local(run);
     }
+
+    /// `CONFIG.parallelism`個の音声合成を同時に行う。
+    ///
+    /// `session_replicas`が1のときは推論が順番待ちになるため、それ以外のときと比較することで
+    /// セッションの複製による効果がわかる。
+    ///
+    /// CPUの場合、`cpu_num_threads`はデフォルトのままであるため、複製を増やすとスレッドが論理プロセッサ
+    /// の数を超えうる。その影響も含めた結果となる。
+    #[divan::bench(
+        args = CONFIG.session_replicas(),
+        sample_count = CONFIG.iterations_for_synthesis().sample_count,
+        sample_size = CONFIG.iterations_for_synthesis().sample_size,
+    )]
+    fn parallel_synthesis(bencher: Bencher<'_, '_>, session_replicas: NonZero<usize>) {
+        let ort = Onnxruntime::get().expect("should have been initialized");
+        let synth = &Synthesizer::builder(ort)
+            .text_analyzer(FIXTURE.0.text_analyzer().clone())
+            .acceleration_mode(CONFIG.acceleration_mode.into())
+            .session_replicas(session_replicas)
+            .build()
+            .unwrap();
+        synth.load_voice_model(&FIXTURE.1).perform().unwrap();
+
+        let query = &synth
+            .create_audio_query(InputText::SHORT.value, CONFIG.style_id)
+            .unwrap();
+
+        let run = || {
+            thread::scope(|scope| {
+                (0..CONFIG.parallelism.get())
+                    .map(|_| {
+                        scope.spawn(|| synth.synthesis(query, CONFIG.style_id).perform().unwrap())
+                    })
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .map(|handle| handle.join().unwrap())
+                    .collect::<Vec<_>>()
+            })
+        };
+        for _ in 0..CONFIG.iterations_for_synthesis().warmups {
+            divan::black_box(run());
+        }
+        bencher.bench_local(run);
+    }
 }
 
 mod nonblocking {
-    use std::sync::LazyLock;
+    use std::{num::NonZero, sync::LazyLock};
 
     use divan::Bencher;
     use voicevox_core::nonblocking::{
@@ -488,4 +550,41 @@ mod nonblocking {
         }
         bencher.bench_local(run);
     }
+
+    /// `CONFIG.parallelism`個の音声合成を同時に行う。
+    ///
+    /// `blocking::parallel_synthesis`と対になる。
+    #[divan::bench(
+        args = CONFIG.session_replicas(),
+        sample_count = CONFIG.iterations_for_synthesis().sample_count,
+        sample_size = CONFIG.iterations_for_synthesis().sample_size,
+    )]
+    #[pollster::main]
+    async fn parallel_synthesis(bencher: Bencher<'_, '_>, session_replicas: NonZero<usize>) {
+        let ort = Onnxruntime::get().expect("should have been initialized");
+        let synth = &Synthesizer::builder(ort)
+            .text_analyzer(FIXTURE.0.text_analyzer().clone())
+            .acceleration_mode(CONFIG.acceleration_mode.into())
+            .session_replicas(session_replicas)
+            .build()
+            .unwrap();
+        synth.load_voice_model(&FIXTURE.1).perform().await.unwrap();
+
+        let query = &synth
+            .create_audio_query(InputText::SHORT.value, CONFIG.style_id)
+            .await
+            .unwrap();
+
+        let run = || {
+            pollster::block_on(futures_util::future::try_join_all(
+                (0..CONFIG.parallelism.get())
+                    .map(|_| synth.synthesis(query, CONFIG.style_id).perform()),
+            ))
+            .unwrap()
+        };
+        for _ in 0..CONFIG.iterations_for_synthesis().warmups {
+            divan::black_box(run());
+        }
+        bencher.bench_local(run);
+    }
 }
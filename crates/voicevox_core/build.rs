@@ -1,13 +1,30 @@
 use std::{env, sync::LazyLock};
 
-#[cfg(not(any(feature = "load-onnxruntime", feature = "link-onnxruntime")))]
-compile_error!("either `load-onnxruntime` or `link-onnxruntime` must be enabled");
+#[cfg(not(any(
+    feature = "load-onnxruntime",
+    feature = "link-onnxruntime",
+    feature = "web-onnxruntime",
+    feature = "tract",
+)))]
+compile_error!(
+    "either `load-onnxruntime`, `link-onnxruntime`, `web-onnxruntime` or `tract` must be enabled"
+);
 
 const ENV_DOWNLOAD_AND_COPY_ORT: &str = "VVCORE_BUILD_DOWNLOAD_AND_COPY_ORT";
 const ENV_TARGET_ENV: &str = "CARGO_CFG_TARGET_ENV";
 static DOWNLOAD_AND_COPY_ORT: LazyLock<bool> = LazyLock::new(|| is_true(ENV_DOWNLOAD_AND_COPY_ORT));
 
 fn main() -> Result<(), build_features::Error> {
+    // ONNX Runtimeを用いる`*-onnxruntime`のいずれかが有効であること
+    println!("cargo::rustc-check-cfg=cfg(onnxruntime)");
+    if cfg!(any(
+        feature = "load-onnxruntime",
+        feature = "link-onnxruntime",
+        feature = "web-onnxruntime",
+    )) {
+        println!("cargo::rustc-cfg=onnxruntime");
+    }
+
     if env::var("DOCS_RS").is_ok() {
         return Ok(());
     }
@@ -1,3 +1,4 @@
+#[cfg(all(not(target_family = "wasm"), onnxruntime))]
 pub mod doctest_fixtures;
 pub mod interop;
 
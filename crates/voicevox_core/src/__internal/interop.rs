@@ -5,9 +5,11 @@ pub use crate::{
     core::metas::merge as merge_metas,
     engine::talk::user_dict::{DEFAULT_WORD_TYPE, InvalidWordError},
     engine::validate::Validate,
+    error::{ErrorDetails, error_details},
     synthesizer::{
         BlockingTextAnalyzerExt, DEFAULT_CPU_NUM_THREADS, DEFAULT_ENABLE_INTERROGATIVE_UPSPEAK,
-        DEFAULT_HEAVY_INFERENCE_CANCELLABLE, MARGIN, NonblockingTextAnalyzerExt,
-        blocking::PerformInference,
+        DEFAULT_HEAVY_INFERENCE_CANCELLABLE, DEFAULT_SESSION_REPLICAS, MARGIN,
+        NonblockingTextAnalyzerExt,
+        blocking::{PerformInference, ToNonblocking},
     },
 };
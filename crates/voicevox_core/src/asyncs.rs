@@ -116,19 +116,29 @@ impl AsyncSeek for StdFile {
 /// [非同期版API]: crate::nonblocking
 pub(crate) enum BlockingThreadPool {}
 
+// WASMではファイルシステムもスレッドも無いため、ファイル操作は全て`Unsupported`とする。
 impl Async for BlockingThreadPool {
     type Mutex<T: Send + Sync + Unpin> = async_lock::Mutex<T>;
     type RoFile = AsyncRoFile;
 
     async fn open_file_ro(path: impl AsRef<Path>) -> io::Result<Self::RoFile> {
+        if cfg!(target_family = "wasm") {
+            return Err(io::ErrorKind::Unsupported.into());
+        }
         AsyncRoFile::open(path).await
     }
 
     async fn read(path: impl AsRef<Path>) -> io::Result<Vec<u8>> {
+        if cfg!(target_family = "wasm") {
+            return Err(io::ErrorKind::Unsupported.into());
+        }
         async_fs::read(path).await
     }
 
     async fn write(path: impl AsRef<Path>, content: impl AsRef<[u8]>) -> io::Result<()> {
+        if cfg!(target_family = "wasm") {
+            return Err(io::ErrorKind::Unsupported.into());
+        }
         async_fs::write(path, content).await
     }
 }
@@ -1,21 +1,23 @@
 //! ブロッキング版API。
 
 pub use crate::{
-    core::{
-        infer::runtimes::onnxruntime::blocking::Onnxruntime, voice_model::blocking::VoiceModelFile,
-    },
-    engine::talk::{
-        open_jtalk::blocking::OpenJtalk, text_analyzer::blocking::TextAnalyzer,
-        user_dict::dict::blocking::UserDict,
-    },
+    core::voice_model::blocking::VoiceModelFile,
+    engine::talk::{text_analyzer::blocking::TextAnalyzer, user_dict::dict::blocking::UserDict},
     synthesizer::blocking::Synthesizer,
 };
 
+#[cfg(onnxruntime)]
+pub use crate::core::infer::runtimes::onnxruntime::blocking::Onnxruntime;
+
+#[cfg(not(target_family = "wasm"))]
+pub use crate::engine::talk::open_jtalk::blocking::OpenJtalk;
+
 // TODO: 後で復活させる
 // https://github.com/VOICEVOX/voicevox_core/issues/970
 #[doc(hidden)]
 pub use crate::synthesizer::blocking::AudioFeature as __AudioFeature;
 
+#[cfg(onnxruntime)]
 pub mod onnxruntime {
     #[cfg(feature = "load-onnxruntime")]
     #[cfg_attr(docsrs, doc(cfg(feature = "load-onnxruntime")))]
@@ -24,7 +26,8 @@ pub mod onnxruntime {
 
 pub mod synthesizer {
     pub use crate::synthesizer::blocking::{
-        Builder, FrameSynthesis, LoadVoiceModel, Synthesis, Tts, TtsFromKana,
+        Builder, FrameRender, FrameSynthesis, LoadVoiceModel, RenderSongProject, Synthesis, Tts,
+        TtsFromKana,
     };
 
     // TODO: 後で復活させる
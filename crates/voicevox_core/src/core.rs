@@ -7,6 +7,8 @@ mod manifest;
 pub(crate) mod metas;
 pub(crate) mod status;
 pub(crate) mod voice_model;
+#[cfg(not(target_family = "wasm"))]
+pub(crate) mod vvm;
 
 pub(crate) use self::adjust::{
     Array1ExtForPostProcess, Array1ExtForPreProcess, ArrayExt, ensure_minimum_phoneme_length,
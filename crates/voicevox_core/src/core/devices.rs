@@ -1,47 +1,53 @@
 use std::{
-    collections::BTreeMap,
     fmt::{self, Display},
     ops::Index,
+    str::FromStr,
 };
 
+use anyhow::Context as _;
 use derive_more::BitAnd;
-use serde::Serialize;
+use itertools::Itertools as _;
+use serde::{Deserialize, Serialize};
+use serde_with::{DeserializeFromStr, SerializeDisplay};
 
+use super::infer::domains::{
+    FrameDecodeOperation, SingingTeacherOperation, StreamingTalkOperation, TalkOperation,
+};
 use crate::convert::ToJsonValue;
 
-pub(crate) fn test_gpus(
-    gpus: impl IntoIterator<Item = GpuSpec>,
+pub(crate) fn test_execution_providers(
+    eps: impl IntoIterator<Item = ExecutionProvider>,
     inference_rt_name: &'static str,
     devices_supported_by_inference_rt: SupportedDevices,
-    test: impl Fn(GpuSpec) -> anyhow::Result<()>,
+    test: impl Fn(ExecutionProvider) -> anyhow::Result<()>,
 ) -> DeviceAvailabilities {
     DeviceAvailabilities(
-        gpus.into_iter()
-            .map(|gpu| {
-                let availability = test_gpu(
-                    gpu,
+        eps.into_iter()
+            .map(|ep| {
+                let availability = test_execution_provider(
+                    ep,
                     inference_rt_name,
                     devices_supported_by_inference_rt,
                     &test,
                 );
-                (gpu, availability)
+                (ep, availability)
             })
             .collect(),
     )
 }
 
-fn test_gpu(
-    gpu: GpuSpec,
+fn test_execution_provider(
+    ep: ExecutionProvider,
     inference_rt_name: &'static str,
     devices_supported_by_inference_rt: SupportedDevices,
-    test: impl Fn(GpuSpec) -> anyhow::Result<()>,
+    test: impl Fn(ExecutionProvider) -> anyhow::Result<()>,
 ) -> DeviceAvailability {
-    if !SupportedDevices::THIS[gpu] {
+    if !SupportedDevices::THIS[ep] {
         DeviceAvailability::NotSupportedByThisLib
-    } else if !devices_supported_by_inference_rt[gpu] {
+    } else if !devices_supported_by_inference_rt[ep] {
         DeviceAvailability::NotSupportedByCurrentLoadedInferenceRuntime(inference_rt_name)
     } else {
-        match test(gpu) {
+        match test(ep) {
             Ok(()) => DeviceAvailability::Ok,
             Err(err) => DeviceAvailability::Err(err),
         }
@@ -52,6 +58,8 @@ fn test_gpu(
 ///
 /// あくまで本ライブラリもしくはONNX Runtimeが対応しているデバイスの情報であることに注意。GPUが使える環境ではなかったとしても`cuda`や`dml`は`true`を示しうる。
 ///
+/// 各フィールドは[`ExecutionProvider`]のそれぞれに対応する。
+///
 /// ```
 /// # #[pollster::main]
 /// # async fn main() -> anyhow::Result<()> {
@@ -86,6 +94,42 @@ pub struct SupportedDevices {
     ///
     /// [DirectML Execution Provider]: https://onnxruntime.ai/docs/execution-providers/DirectML-ExecutionProvider.html
     pub dml: bool,
+    /// TensorRTが利用可能。
+    ///
+    /// ONNX Runtimeの[TensorRT Execution Provider] (`TensorrtExecutionProvider`)に対応する。必要な環境についてはそちらを参照。
+    ///
+    /// [TensorRT Execution Provider]: https://onnxruntime.ai/docs/execution-providers/TensorRT-ExecutionProvider.html
+    pub tensorrt: bool,
+    /// ROCmが利用可能。
+    ///
+    /// ONNX Runtimeの[ROCm Execution Provider] (`ROCMExecutionProvider`)に対応する。必要な環境についてはそちらを参照。
+    ///
+    /// [ROCm Execution Provider]: https://onnxruntime.ai/docs/execution-providers/ROCm-ExecutionProvider.html
+    pub rocm: bool,
+    /// Core MLが利用可能。
+    ///
+    /// ONNX Runtimeの[CoreML Execution Provider] (`CoreMLExecutionProvider`)に対応する。必要な環境についてはそちらを参照。
+    ///
+    /// [CoreML Execution Provider]: https://onnxruntime.ai/docs/execution-providers/CoreML-ExecutionProvider.html
+    pub coreml: bool,
+    /// XNNPACKが利用可能。
+    ///
+    /// ONNX Runtimeの[XNNPACK Execution Provider] (`XnnpackExecutionProvider`)に対応する。GPUを必要とせず、CPU上で動作する。
+    ///
+    /// [XNNPACK Execution Provider]: https://onnxruntime.ai/docs/execution-providers/Xnnpack-ExecutionProvider.html
+    pub xnnpack: bool,
+    /// OpenVINOが利用可能。
+    ///
+    /// ONNX Runtimeの[OpenVINO Execution Provider] (`OpenVINOExecutionProvider`)に対応する。本ライブラリではCPUデバイスとして利用する。
+    ///
+    /// [OpenVINO Execution Provider]: https://onnxruntime.ai/docs/execution-providers/OpenVINO-ExecutionProvider.html
+    pub openvino: bool,
+    /// oneDNNが利用可能。
+    ///
+    /// ONNX Runtimeの[oneDNN Execution Provider] (`DnnlExecutionProvider`)に対応する。GPUを必要とせず、CPU上で動作する。
+    ///
+    /// [oneDNN Execution Provider]: https://onnxruntime.ai/docs/execution-providers/oneDNN-ExecutionProvider.html
+    pub onednn: bool,
 }
 
 impl SupportedDevices {
@@ -98,30 +142,52 @@ impl SupportedDevices {
     /// # use voicevox_core::SupportedDevices;
     /// assert!(SupportedDevices::THIS.cuda);
     /// assert!(SupportedDevices::THIS.dml);
+    /// assert!(SupportedDevices::THIS.xnnpack);
     /// ```
     ///
-    /// `link-onnxruntime`のフィーチャが有効化されているときは`cpu`を除き`false`となる。
+    /// `link-onnxruntime`のフィーチャが有効化されているとき、もしくはどちらのフィーチャも有効化
+    /// されていないときは[`CPU_ONLY`]となる。
     ///
     #[cfg_attr(feature = "link-onnxruntime", doc = "```")]
     #[cfg_attr(not(feature = "link-onnxruntime"), doc = "```no_run")]
     /// # use voicevox_core::SupportedDevices;
     /// assert!(!SupportedDevices::THIS.cuda);
     /// assert!(!SupportedDevices::THIS.dml);
+    /// assert!(!SupportedDevices::THIS.xnnpack);
     /// ```
+    ///
+    /// [`CPU_ONLY`]: Self::CPU_ONLY
     pub const THIS: Self = if cfg!(feature = "load-onnxruntime") {
         Self {
             cpu: true,
             cuda: true,
             dml: true,
-        }
-    } else if cfg!(feature = "link-onnxruntime") {
-        Self {
-            cpu: true,
-            cuda: false,
-            dml: false,
+            tensorrt: true,
+            rocm: true,
+            coreml: true,
+            xnnpack: true,
+            openvino: true,
+            onednn: true,
         }
     } else {
-        panic!("either `load-onnxruntime` or `link-onnxruntime` must be enabled");
+        Self::CPU_ONLY
+    };
+
+    /// `cpu`のみが`true`であるもの。
+    ///
+    /// [`InferenceRuntime`]を実装する際は、これを元に各フィールドを書き換えるとよい。
+    ///
+    /// [`InferenceRuntime`]: crate::inference_runtime::InferenceRuntime
+    pub const CPU_ONLY: Self = Self {
+        cpu: true,
+        cuda: false,
+        dml: false,
+        tensorrt: false,
+        rocm: false,
+        coreml: false,
+        xnnpack: false,
+        openvino: false,
+        onednn: false,
     };
 }
 
@@ -131,36 +197,37 @@ impl ToJsonValue for SupportedDevices {
     }
 }
 
+/// 各Execution Providerの利用可否。テストした順に並ぶ。
 #[derive(Debug)]
-pub(crate) struct DeviceAvailabilities(BTreeMap<GpuSpec, DeviceAvailability>);
+pub(crate) struct DeviceAvailabilities(Vec<(ExecutionProvider, DeviceAvailability)>);
 
 impl DeviceAvailabilities {
-    pub(crate) fn oks(&self) -> Vec<GpuSpec> {
+    pub(crate) fn oks(&self) -> Vec<ExecutionProvider> {
         self.0
             .iter()
             .filter(|(_, result)| matches!(result, DeviceAvailability::Ok))
-            .map(|(&gpu, _)| gpu)
+            .map(|&(ep, _)| ep)
             .collect()
     }
 }
 
 impl Display for DeviceAvailabilities {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for (gpu, availability) in &self.0 {
+        for (ep, availability) in &self.0 {
             match availability {
-                DeviceAvailability::Ok => writeln!(f, "* {gpu}: OK"),
+                DeviceAvailability::Ok => writeln!(f, "* {ep}: OK"),
                 DeviceAvailability::Err(err) => {
-                    writeln!(f, "* {gpu}: {err}", err = err.to_string().trim_end())
+                    writeln!(f, "* {ep}: {err}", err = err.to_string().trim_end())
                 }
                 DeviceAvailability::NotSupportedByThisLib => {
                     writeln!(
                         f,
-                        "* {gpu}: この`{name}`のビルドでは利用できません",
+                        "* {ep}: この`{name}`のビルドでは利用できません",
                         name = env!("CARGO_PKG_NAME"),
                     )
                 }
                 DeviceAvailability::NotSupportedByCurrentLoadedInferenceRuntime(name) => {
-                    writeln!(f, "* {gpu}: {name}では利用できません")
+                    writeln!(f, "* {ep}: {name}では利用できません")
                 }
             }?;
         }
@@ -176,49 +243,341 @@ enum DeviceAvailability {
     NotSupportedByCurrentLoadedInferenceRuntime(&'static str),
 }
 
-#[derive(Clone, Copy, PartialEq, Debug, derive_more::Display)]
-pub(crate) enum DeviceSpec {
+/// 推論に用いるデバイス。
+#[derive(Clone, Copy, PartialEq, Eq, Debug, derive_more::Display)]
+#[non_exhaustive]
+pub enum DeviceSpec {
+    /// CPU。
     #[display("CPU")]
     Cpu,
 
+    /// CPU以外のExecution Provider。
     #[display("{_0}")]
-    Gpu(GpuSpec),
+    ExecutionProvider(ExecutionProvider),
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, derive_more::Display)]
-pub(crate) enum GpuSpec {
-    #[display("CUDA (device_id=0)")]
-    Cuda,
+impl DeviceSpec {
+    pub(crate) fn is_gpu(self) -> bool {
+        matches!(self, Self::ExecutionProvider(ep) if ep.is_gpu())
+    }
+}
 
-    #[display("DirectML (device_id=0)")]
+/// ONNX Runtimeの、CPU以外のExecution Provider。
+///
+/// [`SupportedDevices`]の各フィールドに対応する。
+#[expect(
+    clippy::manual_non_exhaustive,
+    reason = "バインディングを作るときはexhaustiveとして扱いたい"
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ExecutionProvider {
+    /// CUDA (`device_id=0`)。
+    Cuda,
+    /// DirectML (`device_id=0`)。
     Dml,
+    /// TensorRT (`device_id=0`)。
+    TensorRt,
+    /// ROCm (`device_id=0`)。
+    Rocm,
+    /// Core ML。
+    CoreMl,
+    /// XNNPACK。CPU上で動作する。
+    Xnnpack,
+    /// OpenVINO (`device_type=CPU`)。CPU上で動作する。
+    OpenVinoCpu,
+    /// oneDNN。CPU上で動作する。
+    OneDnn,
+    #[doc(hidden)]
+    __NonExhaustive,
 }
 
-impl GpuSpec {
+impl ExecutionProvider {
+    /// [`AccelerationMode::Auto`]および[`AccelerationMode::Gpu`]で、デフォルトで試すもの。
+    ///
+    /// [`AccelerationMode::Auto`]: crate::AccelerationMode::Auto
+    /// [`AccelerationMode::Gpu`]: crate::AccelerationMode::Gpu
     pub(crate) fn defaults() -> Vec<Self> {
         vec![Self::Cuda, Self::Dml]
     }
+
+    pub(crate) fn all() -> Vec<Self> {
+        vec![
+            Self::Cuda,
+            Self::Dml,
+            Self::TensorRt,
+            Self::Rocm,
+            Self::CoreMl,
+            Self::Xnnpack,
+            Self::OpenVinoCpu,
+            Self::OneDnn,
+        ]
+    }
+
+    /// GPU（もしくはそれに準ずるアクセラレータ）を用いるかどうか。
+    pub(crate) fn is_gpu(self) -> bool {
+        match self {
+            Self::Cuda | Self::Dml | Self::TensorRt | Self::Rocm | Self::CoreMl => true,
+            Self::Xnnpack | Self::OpenVinoCpu | Self::OneDnn => false,
+            Self::__NonExhaustive => unreachable!(),
+        }
+    }
 }
 
-impl Index<GpuSpec> for SupportedDevices {
+impl Display for ExecutionProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Cuda => "CUDA (device_id=0)",
+            Self::Dml => "DirectML (device_id=0)",
+            Self::TensorRt => "TensorRT (device_id=0)",
+            Self::Rocm => "ROCm (device_id=0)",
+            Self::CoreMl => "CoreML",
+            Self::Xnnpack => "XNNPACK",
+            Self::OpenVinoCpu => "OpenVINO (device_type=CPU)",
+            Self::OneDnn => "oneDNN",
+            Self::__NonExhaustive => unreachable!(),
+        })
+    }
+}
+
+impl Index<ExecutionProvider> for SupportedDevices {
     type Output = bool;
 
-    fn index(&self, gpu: GpuSpec) -> &Self::Output {
-        match gpu {
-            GpuSpec::Cuda => &self.cuda,
-            GpuSpec::Dml => &self.dml,
+    fn index(&self, ep: ExecutionProvider) -> &Self::Output {
+        match ep {
+            ExecutionProvider::Cuda => &self.cuda,
+            ExecutionProvider::Dml => &self.dml,
+            ExecutionProvider::TensorRt => &self.tensorrt,
+            ExecutionProvider::Rocm => &self.rocm,
+            ExecutionProvider::CoreMl => &self.coreml,
+            ExecutionProvider::Xnnpack => &self.xnnpack,
+            ExecutionProvider::OpenVinoCpu => &self.openvino,
+            ExecutionProvider::OneDnn => &self.onednn,
+            ExecutionProvider::__NonExhaustive => unreachable!(),
+        }
+    }
+}
+
+/// 推論の操作。[`OperationPlacement`]の指定に用いる。
+///
+/// 文字列としては`"talk.decode"`のように、ドメインと操作の名前を`.`で繋げたもので表される。
+#[cfg_attr(doc, doc(alias = "VoicevoxInferenceOperationKind"))]
+#[expect(
+    clippy::manual_non_exhaustive,
+    reason = "バインディングを作るときはexhaustiveとして扱いたい"
+)]
+#[derive(
+    Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, DeserializeFromStr, SerializeDisplay,
+)]
+pub enum InferenceOperationKind {
+    /// トーク: 音素長の推論。 (`"talk.predict_duration"`)
+    TalkPredictDuration,
+    /// トーク: 音高の推論。 (`"talk.predict_intonation"`)
+    TalkPredictIntonation,
+    /// トーク: 波形の生成。 (`"talk.decode"`)
+    TalkDecode,
+    /// ストリーミングトーク: 音素長の推論。 (`"streaming_talk.predict_duration"`)
+    StreamingTalkPredictDuration,
+    /// ストリーミングトーク: 音高の推論。 (`"streaming_talk.predict_intonation"`)
+    StreamingTalkPredictIntonation,
+    /// ストリーミングトーク: 中間表現の生成。 (`"streaming_talk.generate_full_intermediate"`)
+    StreamingTalkGenerateFullIntermediate,
+    /// ストリーミングトーク: 中間表現からの波形の生成。 (`"streaming_talk.render_audio_segment"`)
+    StreamingTalkRenderAudioSegment,
+    /// ソング: 子音長の推論。 (`"singing_teacher.predict_sing_consonant_length"`)
+    SingingTeacherPredictSingConsonantLength,
+    /// ソング: 音高の推論。 (`"singing_teacher.predict_sing_f0"`)
+    SingingTeacherPredictSingF0,
+    /// ソング: 音量の推論。 (`"singing_teacher.predict_sing_volume"`)
+    SingingTeacherPredictSingVolume,
+    /// ソング: 波形の生成。 (`"frame_decode.sf_decode"`)
+    FrameDecodeSfDecode,
+    #[doc(hidden)]
+    __NonExhaustive,
+}
+
+impl InferenceOperationKind {
+    pub(crate) const ALL: &[Self] = &[
+        Self::TalkPredictDuration,
+        Self::TalkPredictIntonation,
+        Self::TalkDecode,
+        Self::StreamingTalkPredictDuration,
+        Self::StreamingTalkPredictIntonation,
+        Self::StreamingTalkGenerateFullIntermediate,
+        Self::StreamingTalkRenderAudioSegment,
+        Self::SingingTeacherPredictSingConsonantLength,
+        Self::SingingTeacherPredictSingF0,
+        Self::SingingTeacherPredictSingVolume,
+        Self::FrameDecodeSfDecode,
+    ];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::TalkPredictDuration => "talk.predict_duration",
+            Self::TalkPredictIntonation => "talk.predict_intonation",
+            Self::TalkDecode => "talk.decode",
+            Self::StreamingTalkPredictDuration => "streaming_talk.predict_duration",
+            Self::StreamingTalkPredictIntonation => "streaming_talk.predict_intonation",
+            Self::StreamingTalkGenerateFullIntermediate => {
+                "streaming_talk.generate_full_intermediate"
+            }
+            Self::StreamingTalkRenderAudioSegment => "streaming_talk.render_audio_segment",
+            Self::SingingTeacherPredictSingConsonantLength => {
+                "singing_teacher.predict_sing_consonant_length"
+            }
+            Self::SingingTeacherPredictSingF0 => "singing_teacher.predict_sing_f0",
+            Self::SingingTeacherPredictSingVolume => "singing_teacher.predict_sing_volume",
+            Self::FrameDecodeSfDecode => "frame_decode.sf_decode",
+            Self::__NonExhaustive => unreachable!(),
+        }
+    }
+
+    /// 特に指定が無いときに、[`OperationDevice::Accelerator`]に置くかどうか。
+    fn is_heavy(self) -> bool {
+        match self {
+            Self::TalkDecode
+            | Self::StreamingTalkRenderAudioSegment
+            | Self::FrameDecodeSfDecode => true,
+            Self::TalkPredictDuration
+            | Self::TalkPredictIntonation
+            | Self::StreamingTalkPredictDuration
+            | Self::StreamingTalkPredictIntonation
+            | Self::StreamingTalkGenerateFullIntermediate
+            | Self::SingingTeacherPredictSingConsonantLength
+            | Self::SingingTeacherPredictSingF0
+            | Self::SingingTeacherPredictSingVolume => false,
+            Self::__NonExhaustive => unreachable!(),
+        }
+    }
+}
+
+impl Display for InferenceOperationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for InferenceOperationKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|op| op.as_str() == s)
+            .with_context(|| {
+                format!(
+                    "unknown inference operation: {s:?} (expected one of: {})",
+                    Self::ALL.iter().map(|op| op.as_str()).join(", "),
+                )
+            })
+    }
+}
+
+impl From<TalkOperation> for InferenceOperationKind {
+    fn from(op: TalkOperation) -> Self {
+        match op {
+            TalkOperation::PredictDuration => Self::TalkPredictDuration,
+            TalkOperation::PredictIntonation => Self::TalkPredictIntonation,
+            TalkOperation::Decode => Self::TalkDecode,
+        }
+    }
+}
+
+impl From<StreamingTalkOperation> for InferenceOperationKind {
+    fn from(op: StreamingTalkOperation) -> Self {
+        match op {
+            StreamingTalkOperation::PredictDuration => Self::StreamingTalkPredictDuration,
+            StreamingTalkOperation::PredictIntonation => Self::StreamingTalkPredictIntonation,
+            StreamingTalkOperation::GenerateFullIntermediate => {
+                Self::StreamingTalkGenerateFullIntermediate
+            }
+            StreamingTalkOperation::RenderAudioSegment => Self::StreamingTalkRenderAudioSegment,
         }
     }
 }
 
+impl From<SingingTeacherOperation> for InferenceOperationKind {
+    fn from(op: SingingTeacherOperation) -> Self {
+        match op {
+            SingingTeacherOperation::PredictSingConsonantLength => {
+                Self::SingingTeacherPredictSingConsonantLength
+            }
+            SingingTeacherOperation::PredictSingF0 => Self::SingingTeacherPredictSingF0,
+            SingingTeacherOperation::PredictSingVolume => Self::SingingTeacherPredictSingVolume,
+        }
+    }
+}
+
+impl From<FrameDecodeOperation> for InferenceOperationKind {
+    fn from(op: FrameDecodeOperation) -> Self {
+        match op {
+            FrameDecodeOperation::SfDecode => Self::FrameDecodeSfDecode,
+        }
+    }
+}
+
+/// 推論の操作の配置先。
+#[cfg_attr(doc, doc(alias = "VoicevoxOperationPlacement"))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct OperationPlacement {
+    /// 推論を行うデバイス。
+    pub device: OperationDevice,
+    /// CPU利用数。`None`のときはシンセサイザ全体の設定に従う。0を指定すると環境に合わせたCPUが利用される。
+    #[serde(default)]
+    pub cpu_num_threads: Option<u16>,
+}
+
+impl OperationPlacement {
+    pub(crate) fn default_for(op: InferenceOperationKind) -> Self {
+        Self {
+            device: if op.is_heavy() {
+                OperationDevice::Accelerator
+            } else {
+                OperationDevice::Cpu
+            },
+            cpu_num_threads: None,
+        }
+    }
+}
+
+/// 推論の操作を行うデバイス。
+#[cfg_attr(doc, doc(alias = "VoicevoxOperationDevice"))]
+#[expect(
+    clippy::manual_non_exhaustive,
+    reason = "バインディングを作るときはexhaustiveとして扱いたい"
+)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OperationDevice {
+    /// CPU。
+    Cpu,
+    /// [`AccelerationMode`]によって選ばれたデバイス。[`AccelerationMode::Cpu`]のときや、利用可能な
+    /// ものが無かったときはCPUとなる。
+    ///
+    /// [`AccelerationMode`]: crate::AccelerationMode
+    /// [`AccelerationMode::Cpu`]: crate::AccelerationMode::Cpu
+    Accelerator,
+    #[doc(hidden)]
+    #[serde(skip)]
+    __NonExhaustive,
+}
+
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
 
-    use super::{GpuSpec, SupportedDevices};
+    use enum_map::Enum;
+    use rstest::rstest;
+
+    use super::{
+        super::infer::domains::{
+            FrameDecodeOperation, SingingTeacherOperation, StreamingTalkOperation, TalkOperation,
+        },
+        ExecutionProvider, InferenceOperationKind, SupportedDevices,
+    };
 
     #[test]
-    fn gpu_spec_defaults_is_exhaustive() {
+    fn execution_provider_all_is_exhaustive() {
         static SUPPORTED_DEVICES: SupportedDevices = SupportedDevices::THIS; // whatever
 
         assert_eq!(
@@ -227,13 +586,81 @@ mod tests {
                     unused_variables,
                     reason = "比較対象としてここは網羅されてなければなりません"
                 )]
-                let SupportedDevices { cpu: _, cuda, dml } = &SUPPORTED_DEVICES;
-                [&raw const *cuda, &raw const *dml]
+                let SupportedDevices {
+                    cpu: _,
+                    cuda,
+                    dml,
+                    tensorrt,
+                    rocm,
+                    coreml,
+                    xnnpack,
+                    openvino,
+                    onednn,
+                } = &SUPPORTED_DEVICES;
+                [
+                    &raw const *cuda,
+                    &raw const *dml,
+                    &raw const *tensorrt,
+                    &raw const *rocm,
+                    &raw const *coreml,
+                    &raw const *xnnpack,
+                    &raw const *openvino,
+                    &raw const *onednn,
+                ]
             },
-            *GpuSpec::defaults()
+            *ExecutionProvider::all()
                 .into_iter()
-                .map(|gpu| &raw const SUPPORTED_DEVICES[gpu])
+                .map(|ep| &raw const SUPPORTED_DEVICES[ep])
                 .collect::<Vec<_>>(),
         );
     }
+
+    #[test]
+    fn execution_provider_defaults_are_gpus() {
+        assert!(
+            ExecutionProvider::defaults()
+                .into_iter()
+                .all(ExecutionProvider::is_gpu)
+        );
+    }
+
+    #[rstest]
+    #[case("talk.decode", InferenceOperationKind::TalkDecode)]
+    #[case(
+        "streaming_talk.render_audio_segment",
+        InferenceOperationKind::StreamingTalkRenderAudioSegment
+    )]
+    #[case("frame_decode.sf_decode", InferenceOperationKind::FrameDecodeSfDecode)]
+    fn inference_operation_kind_roundtrips(
+        #[case] s: &str,
+        #[case] expected: InferenceOperationKind,
+    ) {
+        let op = s.parse::<InferenceOperationKind>().unwrap();
+        assert_eq!(expected, op);
+        assert_eq!(s, op.to_string());
+    }
+
+    #[test]
+    fn inference_operation_kind_all_is_exhaustive() {
+        fn kinds<O: Enum + Into<InferenceOperationKind>>()
+        -> impl Iterator<Item = InferenceOperationKind> {
+            (0..O::LENGTH).map(|i| O::from_usize(i).into())
+        }
+
+        assert_eq!(
+            InferenceOperationKind::ALL,
+            itertools::chain!(
+                kinds::<TalkOperation>(),
+                kinds::<StreamingTalkOperation>(),
+                kinds::<SingingTeacherOperation>(),
+                kinds::<FrameDecodeOperation>(),
+            )
+            .collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn inference_operation_kind_rejects_unknown_names() {
+        assert!("talk.sf_decode".parse::<InferenceOperationKind>().is_err());
+    }
 }
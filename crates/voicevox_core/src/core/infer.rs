@@ -4,6 +4,7 @@ pub(crate) mod session_set;
 
 use std::{borrow::Cow, collections::BTreeSet, fmt::Debug, sync::Arc};
 
+use derive_getters::Getters;
 use derive_new::new;
 use duplicate::duplicate_item;
 use enum_map::{Enum, EnumMap};
@@ -17,7 +18,7 @@ use crate::{
 };
 
 use super::{
-    devices::{DeviceSpec, GpuSpec},
+    devices::{DeviceSpec, ExecutionProvider},
     voice_model::ModelBytes,
 };
 
@@ -41,7 +42,7 @@ impl AsyncExt for SingleTasked {
         ctx: R::RunContext,
         (): Self::Cancellable,
     ) -> anyhow::Result<Vec<OutputTensor>> {
-        R::run_blocking(ctx)
+        R::run_blocking(ctx).map_err(Into::into)
     }
 }
 
@@ -54,26 +55,50 @@ impl AsyncExt for BlockingThreadPool {
         ctx: R::RunContext,
         cancellable: Self::Cancellable,
     ) -> anyhow::Result<Vec<OutputTensor>> {
-        R::run_async(ctx, cancellable).await
+        R::run_async(ctx, cancellable).await.map_err(Into::into)
     }
 }
 
-pub(crate) trait InferenceRuntime: 'static {
+/// 推論ランタイム。
+///
+/// [`Synthesizer`]はこのトレイトを通じてモデルの読み込みと推論を行う。標準で用意されている実装は
+/// [ONNX Runtime]であるが、このトレイトを実装することで別の推論ライブラリを用いることができる。
+///
+/// [`Synthesizer`]: crate::blocking::Synthesizer
+/// [ONNX Runtime]: crate::blocking::Onnxruntime
+pub trait InferenceRuntime: Debug + Send + Sync + 'static {
+    /// 一つのモデルを読み込んだもの。
+    ///
+    /// 推論のたびに[`RunContext`]に`Arc`として渡され、複数のスレッドから同時に利用されうる。
+    ///
+    /// [`RunContext`]: Self::RunContext
     // TODO: "session"とは何なのかを定め、ドキュメントを書く。`InferenceSessionSet`も同様。
-    type Session;
+    type Session: Send + Sync;
 
+    /// 一回の推論に対する入力を溜め込むもの。
     // 本当は`From<&'_ Self::Session>`としたいが、 rust-lang/rust#100013 が立ち塞がる
-    type RunContext: From<Arc<Self::Session>> + PushInputTensor;
+    type RunContext: From<Arc<Self::Session>> + PushInputTensor<Error = Self::Error> + Send;
+
+    /// このランタイムの各操作が返すエラー。
+    ///
+    /// VOICEVOX COREはこれを[`Error`]の[`source`]として保持する。
+    ///
+    /// [`Error`]: crate::Error
+    /// [`source`]: std::error::Error::source
+    type Error: std::error::Error + Send + Sync + 'static;
 
     /// 名前。
     const DISPLAY_NAME: &'static str;
 
     /// このランタイムで利用可能なデバイスの情報を取得する。
-    fn supported_devices(&self) -> crate::Result<SupportedDevices>;
+    fn supported_devices(&self) -> Result<SupportedDevices, Self::Error>;
 
-    /// GPUが実際に利用できそうかどうか判定する。
-    fn test_gpu(&self, gpu: GpuSpec) -> anyhow::Result<()>;
+    /// Execution Providerが実際に利用できそうかどうか判定する。
+    fn test_execution_provider(&self, ep: ExecutionProvider) -> Result<(), Self::Error>;
 
+    /// モデルを読み込み、入出力の情報と共に返す。
+    ///
+    /// 入出力の情報は、VOICEVOX COREが期待するものと照合される。
     #[expect(
         clippy::type_complexity,
         reason = "ここを呼び出すのは現状一箇所なので、可読性が著しく落ちてはいないことを考えると\
@@ -82,19 +107,28 @@ pub(crate) trait InferenceRuntime: 'static {
     fn new_session(
         &self,
         model: &ModelBytes,
-        options: InferenceSessionOptions,
-    ) -> anyhow::Result<(
-        Self::Session,
-        Vec<ParamInfo<InputScalarKind>>,
-        Vec<ParamInfo<OutputScalarKind>>,
-    )>;
+        options: &InferenceSessionOptions,
+    ) -> Result<
+        (
+            Self::Session,
+            Vec<ParamInfo<InputScalarKind>>,
+            Vec<ParamInfo<OutputScalarKind>>,
+        ),
+        Self::Error,
+    >;
 
-    fn run_blocking(ctx: Self::RunContext) -> anyhow::Result<Vec<OutputTensor>>;
+    /// 推論を実行する。
+    ///
+    /// 出力はモデルの出力の順に並んでいなければならない。
+    fn run_blocking(ctx: Self::RunContext) -> Result<Vec<OutputTensor>, Self::Error>;
 
-    async fn run_async(
+    /// 推論を実行する。
+    ///
+    /// `cancellable`が`true`のときは、返したFutureがdropされた時点で推論を中断することが望ましい。
+    fn run_async(
         ctx: Self::RunContext,
         cancellable: bool,
-    ) -> anyhow::Result<Vec<OutputTensor>>;
+    ) -> impl Future<Output = Result<Vec<OutputTensor>, Self::Error>> + Send;
 }
 
 /// 共に扱われるべき推論操作の集合を示す。
@@ -177,12 +211,14 @@ impl InputScalar for T {
         tensor: Array<Self, impl Dimension + 'static>,
         ctx: &mut impl PushInputTensor,
     ) -> anyhow::Result<()> {
-        ctx.push(name, tensor)
+        ctx.push(name, tensor).map_err(Into::into)
     }
 }
 
-#[derive(Clone, Copy, PartialEq, derive_more::Display)]
-pub(crate) enum InputScalarKind {
+/// 推論の入力テンソルの要素の型。
+#[derive(Clone, Copy, PartialEq, Eq, Debug, derive_more::Display)]
+#[non_exhaustive]
+pub enum InputScalarKind {
     #[display("int64_t")]
     Int64,
 
@@ -190,18 +226,24 @@ pub(crate) enum InputScalarKind {
     Float32,
 }
 
-pub(crate) trait PushInputTensor {
+/// [`InferenceRuntime::RunContext`]に、名前付きの入力テンソルを渡す。
+pub trait PushInputTensor {
+    /// テンソルを渡せなかったときのエラー。
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// `int64_t`のテンソルを渡す。
     fn push_int64(
         &mut self,
         name: &'static str,
         tensor: Array<i64, impl Dimension + 'static>,
-    ) -> anyhow::Result<()>;
+    ) -> Result<(), Self::Error>;
 
+    /// `float`のテンソルを渡す。
     fn push_float32(
         &mut self,
         name: &'static str,
         tensor: Array<f32, impl Dimension + 'static>,
-    ) -> anyhow::Result<()>;
+    ) -> Result<(), Self::Error>;
 }
 
 /// 推論操作の出力シグネチャ。
@@ -234,8 +276,10 @@ impl OutputScalar for T {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, derive_more::Display)]
-pub(crate) enum OutputScalarKind {
+/// 推論の出力テンソルの要素の型。
+#[derive(Clone, Copy, PartialEq, Eq, Debug, derive_more::Display)]
+#[non_exhaustive]
+pub enum OutputScalarKind {
     #[display("int64_t")]
     Int64,
 
@@ -243,8 +287,14 @@ pub(crate) enum OutputScalarKind {
     Float32,
 }
 
-pub(crate) enum OutputTensor {
+/// 推論の出力テンソル。
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum OutputTensor {
+    /// `int64_t`のテンソル。
     Int64(ArrayD<i64>),
+
+    /// `float`のテンソル。
     Float32(ArrayD<f32>),
 }
 
@@ -257,13 +307,44 @@ impl<A: OutputScalar, D: Dimension> TryFrom<OutputTensor> for Array<A, D> {
     }
 }
 
-#[cfg_attr(test, derive(Clone))]
-pub(crate) struct ParamInfo<D> {
+/// 推論の入力もしくは出力の情報。
+///
+/// `D`は[`InputScalarKind`]か[`OutputScalarKind`]。
+#[derive(Clone, Debug)]
+pub struct ParamInfo<D> {
     name: Cow<'static, str>,
     dt: D,
     ndim: Option<usize>,
 }
 
+impl<D> ParamInfo<D> {
+    /// 名前と要素の型、次元数から作る。
+    ///
+    /// 次元数が不明なときは`ndim`を`None`とする。
+    pub fn new(name: impl Into<Cow<'static, str>>, dt: D, ndim: Option<usize>) -> Self {
+        Self {
+            name: name.into(),
+            dt,
+            ndim,
+        }
+    }
+
+    /// 名前。
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 要素の型。
+    pub fn dt(&self) -> &D {
+        &self.dt
+    }
+
+    /// 次元数。
+    pub fn ndim(&self) -> Option<usize> {
+        self.ndim
+    }
+}
+
 impl<D: PartialEq> ParamInfo<D> {
     fn accepts(&self, other: &Self) -> bool {
         self.name == other.name
@@ -272,10 +353,69 @@ impl<D: PartialEq> ParamInfo<D> {
     }
 }
 
-#[derive(new, Clone, Copy, PartialEq, Debug)]
-pub(crate) struct InferenceSessionOptions {
+/// [`InferenceRuntime::new_session`]に渡される、セッションの設定。
+#[derive(new, Clone, PartialEq, Debug, Getters)]
+pub struct InferenceSessionOptions {
+    /// CPU利用数。0であれば推論ライブラリに任せる。
     pub(crate) cpu_num_threads: u16,
+    /// 推論に用いるデバイス。
     pub(crate) device: DeviceSpec,
+    /// デバイスによらない設定。
+    pub(crate) tuning: SessionTuning,
+}
+
+/// [`InferenceSessionOptions`]のうち、デバイスによらずすべてのセッションに共通する設定。
+#[derive(Clone, PartialEq, Debug, Getters)]
+pub struct SessionTuning {
+    /// 実行グラフの最適化のレベル。
+    pub(crate) graph_optimization_level: GraphOptimizationLevel,
+    /// 操作間の並列実行に用いるスレッド数。0であれば推論ライブラリに任せる。
+    pub(crate) inter_op_num_threads: u16,
+    /// CPUのメモリアリーナを用いるかどうか。
+    pub(crate) cpu_memory_arena: bool,
+    /// メモリパターンの最適化を行うかどうか。
+    pub(crate) memory_pattern: bool,
+    /// 推論結果を決定的にするかどうか。
+    pub(crate) deterministic_compute: bool,
+    /// 操作内の並列実行に用いるスレッドのアフィニティ。`None`であれば推論ライブラリに任せる。
+    pub(crate) intra_op_thread_affinities: Option<Arc<str>>,
+}
+
+impl Default for SessionTuning {
+    fn default() -> Self {
+        Self {
+            graph_optimization_level: GraphOptimizationLevel::default(),
+            inter_op_num_threads: 0,
+            cpu_memory_arena: true,
+            memory_pattern: true,
+            deterministic_compute: false,
+            intra_op_thread_affinities: None,
+        }
+    }
+}
+
+/// 推論の実行グラフに対して行う最適化のレベル。
+///
+/// 高いレベルほどセッションの作成（音声モデルの読み込み）に時間がかかる代わりに、推論が速くなる
+/// 可能性がある。
+#[cfg_attr(doc, doc(alias = "VoicevoxGraphOptimizationLevel"))]
+#[expect(
+    clippy::manual_non_exhaustive,
+    reason = "バインディングを作るときはexhaustiveとして扱いたい"
+)]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GraphOptimizationLevel {
+    /// 最適化を行わない。
+    Disable,
+    /// 定数畳み込みや冗長なノードの除去といった、基本的な最適化のみを行う。
+    #[default]
+    Basic,
+    /// ノードの融合などの、より進んだ最適化も行う。
+    Extended,
+    /// 実行環境に合わせたメモリレイアウトの変更を含む、すべての最適化を行う。
+    All,
+    #[doc(hidden)]
+    __NonExhaustive,
 }
 
 // TODO: `ShapeError`を直接扱い、データ型違いはパニックにすべきでは？
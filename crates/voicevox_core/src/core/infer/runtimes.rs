@@ -1 +1,114 @@
+#[cfg(onnxruntime)]
 pub(crate) mod onnxruntime;
+#[cfg(feature = "tract")]
+pub(crate) mod tract;
+
+#[cfg(not(onnxruntime))]
+use std::{convert::Infallible, sync::Arc};
+
+#[cfg(not(onnxruntime))]
+use ndarray::{Array, Dimension};
+
+#[cfg(not(onnxruntime))]
+use super::{
+    super::{
+        devices::{ExecutionProvider, SupportedDevices},
+        voice_model::ModelBytes,
+    },
+    InferenceRuntime, InferenceSessionOptions, InputScalarKind, OutputScalarKind, OutputTensor,
+    ParamInfo, PushInputTensor,
+};
+
+/// `Synthesizer`などの型引数`R`のデフォルト。
+///
+/// フィーチャの組み合わせに依らず、常にONNX Runtimeである。
+#[cfg(onnxruntime)]
+pub(crate) type DefaultInferenceRuntime = self::onnxruntime::blocking::Onnxruntime;
+
+/// `Synthesizer`などの型引数`R`のデフォルト。
+///
+/// ONNX Runtimeが無いビルドでは、値を持たない[`OnnxruntimeUnavailable`]が代わりに入る。
+/// tractなどを使う場合、型引数`R`は明示しなければならない。
+#[cfg(not(onnxruntime))]
+pub(crate) type DefaultInferenceRuntime = OnnxruntimeUnavailable;
+
+/// ONNX Runtimeが無いビルドにおける、`Synthesizer`などの型引数`R`のデフォルト。
+///
+/// 値を持たないため、これを型引数とする`Synthesizer`は作ることができない。
+#[cfg(not(onnxruntime))]
+#[derive(Debug)]
+pub enum OnnxruntimeUnavailable {}
+
+#[cfg(not(onnxruntime))]
+impl InferenceRuntime for OnnxruntimeUnavailable {
+    type Session = Infallible;
+    type RunContext = Self;
+    type Error = Infallible;
+
+    const DISPLAY_NAME: &'static str = "ONNX Runtime";
+
+    fn supported_devices(&self) -> Result<SupportedDevices, Self::Error> {
+        match *self {}
+    }
+
+    fn test_execution_provider(&self, _: ExecutionProvider) -> Result<(), Self::Error> {
+        match *self {}
+    }
+
+    fn new_session(
+        &self,
+        _: &ModelBytes,
+        _: &InferenceSessionOptions,
+    ) -> Result<
+        (
+            Self::Session,
+            Vec<ParamInfo<InputScalarKind>>,
+            Vec<ParamInfo<OutputScalarKind>>,
+        ),
+        Self::Error,
+    > {
+        match *self {}
+    }
+
+    fn run_blocking(ctx: Self::RunContext) -> Result<Vec<OutputTensor>, Self::Error> {
+        match ctx {}
+    }
+
+    async fn run_async(ctx: Self::RunContext, _: bool) -> Result<Vec<OutputTensor>, Self::Error> {
+        match ctx {}
+    }
+}
+
+#[cfg(not(onnxruntime))]
+impl From<Arc<Infallible>> for OnnxruntimeUnavailable {
+    fn from(sess: Arc<Infallible>) -> Self {
+        match *sess {}
+    }
+}
+
+#[cfg(not(onnxruntime))]
+impl PushInputTensor for OnnxruntimeUnavailable {
+    type Error = Infallible;
+
+    fn push_int64(
+        &mut self,
+        _: &'static str,
+        _: Array<i64, impl Dimension + 'static>,
+    ) -> Result<(), Self::Error> {
+        match *self {}
+    }
+
+    fn push_float32(
+        &mut self,
+        _: &'static str,
+        _: Array<f32, impl Dimension + 'static>,
+    ) -> Result<(), Self::Error> {
+        match *self {}
+    }
+}
+
+/// 組み込みの推論ランタイム（ONNX Runtimeおよびtract）が返すエラー。
+#[cfg(any(onnxruntime, feature = "tract"))]
+#[derive(thiserror::Error, Debug)]
+#[error(transparent)]
+pub struct BuiltinRuntimeError(#[from] anyhow::Error);
@@ -25,8 +25,10 @@ use ndarray::{Array, Dimension};
 use ort::{
     environment::Environment,
     ep::{
-        CPUExecutionProvider, CUDAExecutionProvider, DirectMLExecutionProvider,
-        ExecutionProvider as _, cuda::ConvAlgorithmSearch,
+        CPUExecutionProvider, CUDAExecutionProvider, CoreMLExecutionProvider,
+        DirectMLExecutionProvider, ExecutionProvider as _, OneDNNExecutionProvider,
+        OpenVINOExecutionProvider, ROCmExecutionProvider, TensorRTExecutionProvider,
+        XNNPACKExecutionProvider, cuda::ConvAlgorithmSearch,
     },
     session::{RunOptions, builder::GraphOptimizationLevel},
     value::{PrimitiveTensorElementType, TensorElementType, ValueType},
@@ -35,13 +37,16 @@ use tracing::warn;
 
 use crate::error::ErrorRepr;
 
-use super::super::{
+use super::{
     super::{
-        devices::{DeviceSpec, GpuSpec, SupportedDevices},
-        voice_model::ModelBytes,
+        super::{
+            devices::{DeviceSpec, ExecutionProvider, SupportedDevices},
+            voice_model::ModelBytes,
+        },
+        InferenceRuntime, InferenceSessionOptions, InputScalarKind, OutputScalarKind, OutputTensor,
+        ParamInfo, PushInputTensor, SessionTuning,
     },
-    InferenceRuntime, InferenceSessionOptions, InputScalarKind, OutputScalarKind, OutputTensor,
-    ParamInfo, PushInputTensor,
+    BuiltinRuntimeError,
 };
 
 const LIB_MIN_REQUIRED_MINOR_VERSION: u32 = ort::sys::ORT_API_VERSION;
@@ -130,6 +135,25 @@ impl Inner {
                 .into()
             })
     }
+
+    /// onnxruntime-webのように、ONNX Runtimeの代わりとなるバックエンドの`OrtApi`で初期化する。
+    ///
+    /// バージョンの確認は行わない。
+    #[cfg(all(target_family = "wasm", feature = "web-onnxruntime"))]
+    fn get_or_try_init_with_api(api: ort::sys::OrtApi) -> crate::Result<&'static Self> {
+        SINGLETON
+            .get_or_try_init(|| {
+                install(api)?;
+                Ok(Self {})
+            })
+            .map_err(|source| {
+                ErrorRepr::InitInferenceRuntime {
+                    runtime_display_name: "onnxruntime-web",
+                    source,
+                }
+                .into()
+            })
+    }
 }
 
 fn setup(
@@ -182,6 +206,10 @@ fn setup(
     assert!(!api.is_null() && api.is_aligned());
     let api = unsafe { api.read() };
 
+    install(api)
+}
+
+fn install(api: ort::sys::OrtApi) -> anyhow::Result<()> {
     let inserted = ort::set_api(api);
     if !inserted {
         warn!("`ort::set_api` already executed");
@@ -234,249 +262,366 @@ impl TargetLibOnnxruntimeInfo<'_> {
     }
 }
 
+fn register_execution_provider(
+    ep: ExecutionProvider,
+    builder: &mut ort::session::builder::SessionBuilder,
+) -> anyhow::Result<()> {
+    match ep {
+        ExecutionProvider::Cuda => CUDAExecutionProvider::default()
+            .with_conv_algorithm_search(ConvAlgorithmSearch::Heuristic)
+            .register(builder),
+        ExecutionProvider::Dml => DirectMLExecutionProvider::default().register(builder),
+        ExecutionProvider::TensorRt => TensorRTExecutionProvider::default().register(builder),
+        ExecutionProvider::Rocm => ROCmExecutionProvider::default().register(builder),
+        ExecutionProvider::CoreMl => CoreMLExecutionProvider::default().register(builder),
+        ExecutionProvider::Xnnpack => XNNPACKExecutionProvider::default().register(builder),
+        ExecutionProvider::OpenVinoCpu => OpenVINOExecutionProvider::default()
+            .with_device_type("CPU")
+            .register(builder),
+        ExecutionProvider::OneDnn => OneDNNExecutionProvider::default().register(builder),
+        ExecutionProvider::__NonExhaustive => unreachable!(),
+    }
+    .map_err(Into::into)
+}
+
+/// Execution Providerが利用可能かどうかを調べる。
+///
+/// 調べること自体に失敗した場合は、警告を出した上で利用不可能とみなす。
+fn is_available<E: ort::ep::ExecutionProvider + Default>() -> bool {
+    E::default().is_available().unwrap_or_else(|err| {
+        warn!(
+            "`{}`が利用可能かどうかの確認に失敗したため、利用不可能とみなします: {err}",
+            std::any::type_name::<E>(),
+        );
+        false
+    })
+}
+
 impl InferenceRuntime for self::blocking::Onnxruntime {
-    type Session = async_lock::Mutex<ort::session::Session>; // WASMでは`ort`を利用しないので、ここはasync-lockを用いてよいはず
+    type Session = async_lock::Mutex<ort::session::Session>; // WASMでは`run_async`のみを利用するので、ここはasync-lockを用いてよいはず
     type RunContext = OnnxruntimeRunContext;
+    type Error = BuiltinRuntimeError;
 
     const DISPLAY_NAME: &'static str = if cfg!(feature = "load-onnxruntime") {
         "現在ロードされているONNX Runtime"
     } else if cfg!(feature = "link-onnxruntime") {
         "現在リンクされているONNX Runtime"
+    } else if cfg!(target_family = "wasm") {
+        "onnxruntime-web"
     } else {
-        panic!("either `load-onnxruntime` or `link-onnxruntime` must be enabled");
+        panic!(
+            "either `load-onnxruntime` or `link-onnxruntime` must be enabled, or the target must \
+             be WASM",
+        );
     };
 
-    fn supported_devices(&self) -> crate::Result<SupportedDevices> {
-        (|| {
-            let cpu = CPUExecutionProvider::default().is_available()?;
-            let cuda = CUDAExecutionProvider::default().is_available()?;
-            let dml = DirectMLExecutionProvider::default().is_available()?;
-
-            ensure!(cpu, "missing `CPUExecutionProvider`");
-
-            Ok(SupportedDevices {
-                cpu: true,
-                cuda,
-                dml,
-            })
-        })()
-        .map_err(ErrorRepr::GetSupportedDevices)
-        .map_err(Into::into)
+    fn supported_devices(&self) -> Result<SupportedDevices, Self::Error> {
+        supported_devices().map_err(Into::into)
     }
 
-    fn test_gpu(&self, gpu: GpuSpec) -> anyhow::Result<()> {
-        let sess_builder = &mut ort::session::builder::SessionBuilder::new()?;
-        match gpu {
-            GpuSpec::Cuda => CUDAExecutionProvider::default()
-                .with_conv_algorithm_search(ConvAlgorithmSearch::Heuristic)
-                .register(sess_builder),
-            GpuSpec::Dml => DirectMLExecutionProvider::default().register(sess_builder),
-        }
-        .map_err(Into::into)
+    fn test_execution_provider(&self, ep: ExecutionProvider) -> Result<(), Self::Error> {
+        test_execution_provider(ep).map_err(Into::into)
     }
 
     fn new_session(
         &self,
         model: &ModelBytes,
-        options: InferenceSessionOptions,
-    ) -> anyhow::Result<(
-        Self::Session,
-        Vec<ParamInfo<InputScalarKind>>,
-        Vec<ParamInfo<OutputScalarKind>>,
-    )> {
-        static IS_VOICEVOX_ONNXRUNTIME: LazyLock<bool> =
-            LazyLock::new(|| ort::info().starts_with("VOICEVOX ORT Build Info: "));
-
-        let mut builder = ort::session::Session::builder()?
-            .with_optimization_level(GraphOptimizationLevel::Level1)
+        options: &InferenceSessionOptions,
+    ) -> Result<
+        (
+            Self::Session,
+            Vec<ParamInfo<InputScalarKind>>,
+            Vec<ParamInfo<OutputScalarKind>>,
+        ),
+        Self::Error,
+    > {
+        new_session(model, options).map_err(Into::into)
+    }
+
+    fn run_blocking(ctx: Self::RunContext) -> Result<Vec<OutputTensor>, Self::Error> {
+        run_blocking(ctx).map_err(Into::into)
+    }
+
+    async fn run_async(
+        ctx: Self::RunContext,
+        cancellable: bool,
+    ) -> Result<Vec<OutputTensor>, Self::Error> {
+        run_async(ctx, cancellable).await.map_err(Into::into)
+    }
+}
+
+fn supported_devices() -> anyhow::Result<SupportedDevices> {
+    let cpu = is_available::<CPUExecutionProvider>();
+    let cuda = is_available::<CUDAExecutionProvider>();
+    let dml = is_available::<DirectMLExecutionProvider>();
+    let tensorrt = is_available::<TensorRTExecutionProvider>();
+    let rocm = is_available::<ROCmExecutionProvider>();
+    let coreml = is_available::<CoreMLExecutionProvider>();
+    let xnnpack = is_available::<XNNPACKExecutionProvider>();
+    let openvino = is_available::<OpenVINOExecutionProvider>();
+    let onednn = is_available::<OneDNNExecutionProvider>();
+
+    ensure!(cpu, "missing `CPUExecutionProvider`");
+
+    Ok(SupportedDevices {
+        cpu: true,
+        cuda,
+        dml,
+        tensorrt,
+        rocm,
+        coreml,
+        xnnpack,
+        openvino,
+        onednn,
+    })
+}
+
+fn test_execution_provider(ep: ExecutionProvider) -> anyhow::Result<()> {
+    let sess_builder = &mut ort::session::builder::SessionBuilder::new()?;
+    register_execution_provider(ep, sess_builder)
+}
+
+#[expect(
+    clippy::type_complexity,
+    reason = "ここを呼び出すのは現状一箇所なので、可読性が著しく落ちてはいないことを考えると\
+              別にこのままでいいはず"
+)]
+fn new_session(
+    model: &ModelBytes,
+    options: &InferenceSessionOptions,
+) -> anyhow::Result<(
+    async_lock::Mutex<ort::session::Session>,
+    Vec<ParamInfo<InputScalarKind>>,
+    Vec<ParamInfo<OutputScalarKind>>,
+)> {
+    static IS_VOICEVOX_ONNXRUNTIME: LazyLock<bool> =
+        LazyLock::new(|| ort::info().starts_with("VOICEVOX ORT Build Info: "));
+
+    let SessionTuning {
+        graph_optimization_level,
+        inter_op_num_threads,
+        cpu_memory_arena,
+        memory_pattern,
+        deterministic_compute,
+        intra_op_thread_affinities,
+    } = &options.tuning;
+
+    let graph_optimization_level = match graph_optimization_level {
+        crate::GraphOptimizationLevel::Disable => GraphOptimizationLevel::Disable,
+        crate::GraphOptimizationLevel::Basic => GraphOptimizationLevel::Level1,
+        crate::GraphOptimizationLevel::Extended => GraphOptimizationLevel::Level2,
+        crate::GraphOptimizationLevel::All => GraphOptimizationLevel::Level3,
+        crate::GraphOptimizationLevel::__NonExhaustive => unreachable!(),
+    };
+
+    let mut builder = ort::session::Session::builder()?
+        .with_optimization_level(graph_optimization_level)
+        .map_err(ort::Error::<()>::from)?
+        .with_intra_threads(options.cpu_num_threads.into())
+        .map_err(ort::Error::<()>::from)?
+        .with_memory_pattern(*memory_pattern)
+        .map_err(ort::Error::<()>::from)?
+        .with_deterministic_compute(*deterministic_compute)
+        .map_err(ort::Error::<()>::from)?;
+
+    // inter-opのスレッドプールは、並列実行モードでのみ使われる
+    if *inter_op_num_threads > 0 {
+        builder = builder
+            .with_parallel_execution(true)
             .map_err(ort::Error::<()>::from)?
-            .with_intra_threads(options.cpu_num_threads.into())
+            .with_inter_threads((*inter_op_num_threads).into())
             .map_err(ort::Error::<()>::from)?;
+    }
 
-        match options.device {
-            DeviceSpec::Cpu => {}
-            DeviceSpec::Gpu(GpuSpec::Cuda) => {
-                CUDAExecutionProvider::default()
-                    .with_conv_algorithm_search(ConvAlgorithmSearch::Heuristic)
-                    .register(&mut builder)?;
-            }
-            DeviceSpec::Gpu(GpuSpec::Dml) => {
+    if let Some(intra_op_thread_affinities) = intra_op_thread_affinities {
+        builder = builder
+            .with_config_entry(
+                "session.intra_op_thread_affinities",
+                intra_op_thread_affinities,
+            )
+            .map_err(ort::Error::<()>::from)?;
+    }
+
+    match options.device {
+        DeviceSpec::Cpu => {}
+        DeviceSpec::ExecutionProvider(ep) => {
+            if ep == ExecutionProvider::Dml {
                 builder = builder
                     .with_parallel_execution(false)
                     .map_err(ort::Error::<()>::from)?
                     .with_memory_pattern(false)
                     .map_err(ort::Error::<()>::from)?;
-                DirectMLExecutionProvider::default().register(&mut builder)?;
             }
-        };
+            register_execution_provider(ep, &mut builder)?;
+        }
+    };
+
+    if !cpu_memory_arena {
+        // 他のExecution Providerより優先されないよう、最後に登録する
+        CPUExecutionProvider::default()
+            .with_arena_allocator(false)
+            .register(&mut builder)?;
+    }
 
-        let sess = match model {
-            ModelBytes::Onnx(onnx) => builder.commit_from_memory(onnx),
-            ModelBytes::VvBin(bin) => {
-                ensure!(
-                    *IS_VOICEVOX_ONNXRUNTIME,
-                    "This ONNX Runtime does not support \"vv-bin\" format \
-                     (note: load/link `voicevox_onnxruntime` instead of ` onnxruntime`)",
+    let sess = match model {
+        ModelBytes::Onnx(onnx) => builder.commit_from_memory(onnx),
+        ModelBytes::VvBin(bin) => {
+            ensure!(
+                *IS_VOICEVOX_ONNXRUNTIME,
+                "This ONNX Runtime does not support \"vv-bin\" format \
+                 (note: load/link `voicevox_onnxruntime` instead of ` onnxruntime`)",
+            );
+            builder
+                .with_config_entry("session.use_vv_bin", "1")
+                .map_err(ort::Error::<()>::from)?
+                .commit_from_memory(bin)
+        }
+    }?;
+
+    let input_param_infos = sess
+        .inputs()
+        .iter()
+        .map(|info| {
+            let ValueType::Tensor { ty, .. } = info.dtype() else {
+                bail!(
+                    "unexpected input value type for `{}`. currently `ONNX_TYPE_TENSOR` and \
+                     `ONNX_TYPE_SPARSETENSOR` is supported",
+                    info.name(),
                 );
-                builder
-                    .with_config_entry("session.use_vv_bin", "1")
-                    .map_err(ort::Error::<()>::from)?
-                    .commit_from_memory(bin)
-            }
-        }?;
-
-        let input_param_infos = sess
-            .inputs()
-            .iter()
-            .map(|info| {
-                let ValueType::Tensor { ty, .. } = info.dtype() else {
-                    bail!(
-                        "unexpected input value type for `{}`. currently `ONNX_TYPE_TENSOR` and \
-                         `ONNX_TYPE_SPARSETENSOR` is supported",
-                        info.name(),
-                    );
-                };
-
-                let dt = match ty {
-                    TensorElementType::Float32 => Ok(InputScalarKind::Float32),
-                    TensorElementType::Uint8 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT8"),
-                    TensorElementType::Int8 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_INT8"),
-                    TensorElementType::Uint16 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT16"),
-                    TensorElementType::Int16 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_INT16"),
-                    TensorElementType::Int32 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_INT32"),
-                    TensorElementType::Int64 => Ok(InputScalarKind::Int64),
-                    TensorElementType::String => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_STRING"),
-                    TensorElementType::Bfloat16 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_BFLOAT16"),
-                    TensorElementType::Float16 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT16"),
-                    TensorElementType::Float64 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_DOUBLE"),
-                    TensorElementType::Uint32 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT32"),
-                    TensorElementType::Uint64 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT64"),
-                    TensorElementType::Bool => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_BOOL"),
-                    TensorElementType::Complex64 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_COMPLEX64"),
-                    TensorElementType::Complex128 => {
-                        Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_COMPLEX128")
-                    }
-                    TensorElementType::Float8E4M3FN => {
-                        Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E4M3FN")
-                    }
-                    TensorElementType::Float8E4M3FNUZ => {
-                        Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E4M3FNUZ")
-                    }
-                    TensorElementType::Float8E5M2 => {
-                        Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E5M2")
-                    }
-                    TensorElementType::Float8E5M2FNUZ => {
-                        Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E5M2FNUZ")
-                    }
-                    TensorElementType::Uint4 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT4"),
-                    TensorElementType::Int4 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_INT4"),
-                    TensorElementType::Undefined => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED"),
-                }
-                .map_err(|actual| {
-                    anyhow!(
-                        "unsupported input datatype `{actual}` for `{}`",
-                        info.name(),
-                    )
-                })?;
+            };
 
-                Ok(ParamInfo {
-                    name: info.name().to_owned().into(),
-                    dt,
-                    ndim: info.dtype().tensor_shape().map(|s| s.len()),
-                })
-            })
-            .collect::<anyhow::Result<_>>()?;
-
-        let output_param_infos = sess
-            .outputs()
-            .iter()
-            .map(|info| {
-                let ValueType::Tensor { ty, .. } = info.dtype() else {
-                    bail!(
-                        "unexpected output value type for `{}`. currently `ONNX_TYPE_TENSOR` and \
-                         `ONNX_TYPE_SPARSETENSOR` is supported",
-                        info.name(),
-                    );
-                };
-
-                let dt = match ty {
-                    TensorElementType::Float32 => Ok(OutputScalarKind::Float32),
-                    TensorElementType::Uint8 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT8"),
-                    TensorElementType::Int8 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_INT8"),
-                    TensorElementType::Uint16 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT16"),
-                    TensorElementType::Int16 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_INT16"),
-                    TensorElementType::Int32 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_INT32"),
-                    TensorElementType::Int64 => Ok(OutputScalarKind::Int64),
-                    TensorElementType::String => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_STRING"),
-                    TensorElementType::Bfloat16 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_BFLOAT16"),
-                    TensorElementType::Float16 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT16"),
-                    TensorElementType::Float64 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_DOUBLE"),
-                    TensorElementType::Uint32 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT32"),
-                    TensorElementType::Uint64 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT64"),
-                    TensorElementType::Bool => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_BOOL"),
-                    TensorElementType::Complex64 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_COMPLEX64"),
-                    TensorElementType::Complex128 => {
-                        Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_COMPLEX128")
-                    }
-                    TensorElementType::Float8E4M3FN => {
-                        Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E4M3FN")
-                    }
-                    TensorElementType::Float8E4M3FNUZ => {
-                        Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E4M3FNUZ")
-                    }
-                    TensorElementType::Float8E5M2 => {
-                        Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E5M2")
-                    }
-                    TensorElementType::Float8E5M2FNUZ => {
-                        Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E5M2FNUZ")
-                    }
-                    TensorElementType::Uint4 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT4"),
-                    TensorElementType::Int4 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_INT4"),
-                    TensorElementType::Undefined => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED"),
+            let dt = match ty {
+                TensorElementType::Float32 => Ok(InputScalarKind::Float32),
+                TensorElementType::Uint8 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT8"),
+                TensorElementType::Int8 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_INT8"),
+                TensorElementType::Uint16 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT16"),
+                TensorElementType::Int16 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_INT16"),
+                TensorElementType::Int32 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_INT32"),
+                TensorElementType::Int64 => Ok(InputScalarKind::Int64),
+                TensorElementType::String => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_STRING"),
+                TensorElementType::Bfloat16 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_BFLOAT16"),
+                TensorElementType::Float16 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT16"),
+                TensorElementType::Float64 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_DOUBLE"),
+                TensorElementType::Uint32 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT32"),
+                TensorElementType::Uint64 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT64"),
+                TensorElementType::Bool => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_BOOL"),
+                TensorElementType::Complex64 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_COMPLEX64"),
+                TensorElementType::Complex128 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_COMPLEX128"),
+                TensorElementType::Float8E4M3FN => {
+                    Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E4M3FN")
                 }
-                .map_err(|actual| {
-                    anyhow!(
-                        "unsupported output datatype `{actual}` for `{}`",
-                        info.name(),
-                    )
-                })?;
+                TensorElementType::Float8E4M3FNUZ => {
+                    Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E4M3FNUZ")
+                }
+                TensorElementType::Float8E5M2 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E5M2"),
+                TensorElementType::Float8E5M2FNUZ => {
+                    Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E5M2FNUZ")
+                }
+                TensorElementType::Uint4 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT4"),
+                TensorElementType::Int4 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_INT4"),
+                TensorElementType::Undefined => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED"),
+            }
+            .map_err(|actual| {
+                anyhow!(
+                    "unsupported input datatype `{actual}` for `{}`",
+                    info.name(),
+                )
+            })?;
+
+            Ok(ParamInfo {
+                name: info.name().to_owned().into(),
+                dt,
+                ndim: info.dtype().tensor_shape().map(|s| s.len()),
+            })
+        })
+        .collect::<anyhow::Result<_>>()?;
 
-                Ok(ParamInfo {
-                    name: info.name().to_owned().into(),
-                    dt,
-                    ndim: info.dtype().tensor_shape().map(|s| s.len()),
-                })
+    let output_param_infos = sess
+        .outputs()
+        .iter()
+        .map(|info| {
+            let ValueType::Tensor { ty, .. } = info.dtype() else {
+                bail!(
+                    "unexpected output value type for `{}`. currently `ONNX_TYPE_TENSOR` and \
+                     `ONNX_TYPE_SPARSETENSOR` is supported",
+                    info.name(),
+                );
+            };
+
+            let dt = match ty {
+                TensorElementType::Float32 => Ok(OutputScalarKind::Float32),
+                TensorElementType::Uint8 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT8"),
+                TensorElementType::Int8 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_INT8"),
+                TensorElementType::Uint16 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT16"),
+                TensorElementType::Int16 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_INT16"),
+                TensorElementType::Int32 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_INT32"),
+                TensorElementType::Int64 => Ok(OutputScalarKind::Int64),
+                TensorElementType::String => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_STRING"),
+                TensorElementType::Bfloat16 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_BFLOAT16"),
+                TensorElementType::Float16 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT16"),
+                TensorElementType::Float64 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_DOUBLE"),
+                TensorElementType::Uint32 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT32"),
+                TensorElementType::Uint64 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT64"),
+                TensorElementType::Bool => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_BOOL"),
+                TensorElementType::Complex64 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_COMPLEX64"),
+                TensorElementType::Complex128 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_COMPLEX128"),
+                TensorElementType::Float8E4M3FN => {
+                    Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E4M3FN")
+                }
+                TensorElementType::Float8E4M3FNUZ => {
+                    Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E4M3FNUZ")
+                }
+                TensorElementType::Float8E5M2 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E5M2"),
+                TensorElementType::Float8E5M2FNUZ => {
+                    Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E5M2FNUZ")
+                }
+                TensorElementType::Uint4 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT4"),
+                TensorElementType::Int4 => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_INT4"),
+                TensorElementType::Undefined => Err("ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED"),
+            }
+            .map_err(|actual| {
+                anyhow!(
+                    "unsupported output datatype `{actual}` for `{}`",
+                    info.name(),
+                )
+            })?;
+
+            Ok(ParamInfo {
+                name: info.name().to_owned().into(),
+                dt,
+                ndim: info.dtype().tensor_shape().map(|s| s.len()),
             })
-            .collect::<anyhow::Result<_>>()?;
+        })
+        .collect::<anyhow::Result<_>>()?;
 
-        Ok((sess.into(), input_param_infos, output_param_infos))
-    }
+    Ok((sess.into(), input_param_infos, output_param_infos))
+}
 
-    fn run_blocking(
-        OnnxruntimeRunContext { sess, inputs }: Self::RunContext,
-    ) -> anyhow::Result<Vec<OutputTensor>> {
-        extract_outputs(&sess.lock_blocking().run(inputs)?)
-    }
+fn run_blocking(
+    OnnxruntimeRunContext { sess, inputs }: OnnxruntimeRunContext,
+) -> anyhow::Result<Vec<OutputTensor>> {
+    extract_outputs(&sess.lock_blocking().run(inputs)?)
+}
 
-    async fn run_async(
-        OnnxruntimeRunContext { sess, inputs }: Self::RunContext,
-        cancellable: bool,
-    ) -> anyhow::Result<Vec<OutputTensor>> {
-        if cancellable {
-            extract_outputs(
-                &sess
-                    .lock()
-                    .await
-                    .run_async(inputs, &RunOptions::new()?)?
-                    .await?,
-            )
-        } else {
-            ::blocking::unblock(move || extract_outputs(&sess.lock_blocking().run(inputs)?)).await
-        }
+async fn run_async(
+    OnnxruntimeRunContext { sess, inputs }: OnnxruntimeRunContext,
+    cancellable: bool,
+) -> anyhow::Result<Vec<OutputTensor>> {
+    // WASMではスレッドが使えないため、常に`run_async`を用いる
+    if cancellable || cfg!(target_family = "wasm") {
+        extract_outputs(
+            &sess
+                .lock()
+                .await
+                .run_async(inputs, &RunOptions::new()?)?
+                .await?,
+        )
+    } else {
+        ::blocking::unblock(move || extract_outputs(&sess.lock_blocking().run(inputs)?)).await
     }
 }
 
-pub(crate) struct OnnxruntimeRunContext {
+pub struct OnnxruntimeRunContext {
     sess: Arc<async_lock::Mutex<ort::session::Session>>,
     inputs: Vec<(&'static str, ort::session::SessionInputValue<'static>)>,
 }
@@ -506,6 +651,8 @@ impl From<Arc<async_lock::Mutex<ort::session::Session>>> for OnnxruntimeRunConte
 }
 
 impl PushInputTensor for OnnxruntimeRunContext {
+    type Error = BuiltinRuntimeError;
+
     #[duplicate_item(
         method           T;
         [ push_int64 ]   [ i64 ];
@@ -515,8 +662,8 @@ impl PushInputTensor for OnnxruntimeRunContext {
         &mut self,
         name: &'static str,
         tensor: Array<T, impl Dimension + 'static>,
-    ) -> anyhow::Result<()> {
-        self.push_input(name, tensor)
+    ) -> Result<(), Self::Error> {
+        self.push_input(name, tensor).map_err(Into::into)
     }
 }
 
@@ -553,7 +700,7 @@ fn extract_outputs(
 pub(crate) mod blocking {
     use ref_cast::{RefCastCustom, ref_cast_custom};
 
-    use crate::SupportedDevices;
+    use crate::{SupportedDevices, error::ErrorRepr};
 
     use super::{super::super::InferenceRuntime, Inner};
 
@@ -698,6 +845,11 @@ pub(crate) mod blocking {
             Inner::get_or_try_init().map(Onnxruntime::new)
         }
 
+        #[cfg(all(target_family = "wasm", feature = "web-onnxruntime"))]
+        pub(super) fn init_once_with_api(api: ort::sys::OrtApi) -> crate::Result<&'static Self> {
+            Inner::get_or_try_init_with_api(api).map(Onnxruntime::new)
+        }
+
         #[cfg(test)]
         pub(crate) fn from_test_util_data() -> anyhow::Result<&'static Self> {
             #[cfg(feature = "load-onnxruntime")]
@@ -718,6 +870,7 @@ pub(crate) mod blocking {
         #[cfg_attr(doc, doc(alias = "voicevox_onnxruntime_create_supported_devices_json"))]
         pub fn supported_devices(&self) -> crate::Result<SupportedDevices> {
             <Self as InferenceRuntime>::supported_devices(self)
+                .map_err(|err| ErrorRepr::GetSupportedDevices(err.into()).into())
         }
     }
 
@@ -884,6 +1037,34 @@ pub(crate) mod nonblocking {
             Ok(Self::from_blocking(inner))
         }
 
+        /// [onnxruntime-web]を読み込み、ONNX Runtimeの代わりとして初期化する。
+        ///
+        /// WASM向け。onnxruntime-webに対してはバージョンの確認を行わない。
+        ///
+        /// 一度成功したら以後は同じ参照を返す。
+        ///
+        /// WASMではスレッドが使えないため、このモジュールの非同期APIであっても推論などの重い処理は
+        /// JavaScriptのイベントループ上でそのまま実行される。
+        ///
+        /// [onnxruntime-web]: https://onnxruntime.ai/docs/get-started/with-javascript/web.html
+        #[cfg(all(target_family = "wasm", feature = "web-onnxruntime"))]
+        #[cfg_attr(
+            docsrs,
+            doc(cfg(all(target_family = "wasm", feature = "web-onnxruntime")))
+        )]
+        pub async fn load_web_once() -> crate::Result<&'static Self> {
+            if let Some(onnxruntime) = Self::get() {
+                return Ok(onnxruntime);
+            }
+            let api = ort_web::api(ort_web::FEATURE_NONE).await.map_err(|err| {
+                crate::error::ErrorRepr::InitInferenceRuntime {
+                    runtime_display_name: "onnxruntime-web",
+                    source: anyhow::anyhow!("{err}"),
+                }
+            })?;
+            super::blocking::Onnxruntime::init_once_with_api(api).map(Self::from_blocking)
+        }
+
         #[cfg(test)]
         pub(crate) async fn from_test_util_data() -> anyhow::Result<&'static Self> {
             crate::task::asyncify(super::blocking::Onnxruntime::from_test_util_data)
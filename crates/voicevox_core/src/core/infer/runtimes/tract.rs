@@ -0,0 +1,254 @@
+use std::sync::Arc;
+
+use anyhow::{Context as _, anyhow, bail};
+use duplicate::duplicate_item;
+use ndarray::{Array, ArrayD, Dimension, IxDyn};
+use tract_onnx::prelude::{
+    Datum, DatumType, Framework as _, InferenceModelExt as _, TValue, TVec, Tensor, TypedModel,
+    TypedRunnableModel,
+};
+
+use super::{
+    super::{
+        super::{
+            devices::{DeviceSpec, ExecutionProvider, SupportedDevices},
+            voice_model::ModelBytes,
+        },
+        InferenceRuntime, InferenceSessionOptions, InputScalarKind, OutputScalarKind, OutputTensor,
+        ParamInfo, PushInputTensor,
+    },
+    BuiltinRuntimeError,
+};
+
+/// [tract]による推論ランタイム。
+///
+/// Pure Rustで書かれているため、ONNX Runtimeを用意する必要が無い。ただしCPUでのみ動作し、
+/// [`InferenceSessionOptions`]のうちスレッド数に関する設定は無視される。また"vv-bin"形式のモデルは
+/// 扱えない。
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// use voicevox_core::{blocking::Synthesizer, inference_runtime::Tract};
+///
+/// let synthesizer = Synthesizer::builder_with_runtime(Tract::get()).build()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [tract]: https://github.com/sonos/tract
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct Tract;
+
+impl Tract {
+    /// インスタンスを得る。
+    pub fn get() -> &'static Self {
+        &Self
+    }
+}
+
+impl InferenceRuntime for Tract {
+    type Session = TractSession;
+    type RunContext = TractRunContext;
+    type Error = BuiltinRuntimeError;
+
+    const DISPLAY_NAME: &'static str = "tract";
+
+    fn supported_devices(&self) -> Result<SupportedDevices, Self::Error> {
+        Ok(SupportedDevices::CPU_ONLY)
+    }
+
+    fn test_execution_provider(&self, ep: ExecutionProvider) -> Result<(), Self::Error> {
+        Err(anyhow!("tract does not support {ep}").into())
+    }
+
+    fn new_session(
+        &self,
+        model: &ModelBytes,
+        options: &InferenceSessionOptions,
+    ) -> Result<
+        (
+            Self::Session,
+            Vec<ParamInfo<InputScalarKind>>,
+            Vec<ParamInfo<OutputScalarKind>>,
+        ),
+        Self::Error,
+    > {
+        new_session(model, options).map_err(Into::into)
+    }
+
+    fn run_blocking(ctx: Self::RunContext) -> Result<Vec<OutputTensor>, Self::Error> {
+        run_blocking(ctx).map_err(Into::into)
+    }
+
+    async fn run_async(ctx: Self::RunContext, _: bool) -> Result<Vec<OutputTensor>, Self::Error> {
+        // tractは推論の中断をサポートしていないため、`cancellable`は無視する
+        ::blocking::unblock(move || Self::run_blocking(ctx)).await
+    }
+}
+
+#[expect(
+    clippy::type_complexity,
+    reason = "ここを呼び出すのは現状一箇所なので、可読性が著しく落ちてはいないことを考えると\
+              別にこのままでいいはず"
+)]
+fn new_session(
+    model: &ModelBytes,
+    options: &InferenceSessionOptions,
+) -> anyhow::Result<(
+    TractSession,
+    Vec<ParamInfo<InputScalarKind>>,
+    Vec<ParamInfo<OutputScalarKind>>,
+)> {
+    if let DeviceSpec::ExecutionProvider(ep) = options.device {
+        bail!("tract does not support {ep}");
+    }
+
+    let model = match model {
+        ModelBytes::Onnx(onnx) => tract_onnx::onnx()
+            .model_for_read(&mut &**onnx)?
+            .into_typed()?,
+        ModelBytes::VvBin(_) => bail!("tract does not support \"vv-bin\" format"),
+    };
+
+    let input_param_infos = model
+        .input_outlets()?
+        .iter()
+        .map(|&outlet| {
+            let name = &model.node(outlet.node).name;
+            let fact = model.outlet_fact(outlet)?;
+
+            let dt = match fact.datum_type {
+                DatumType::I64 => InputScalarKind::Int64,
+                DatumType::F32 => InputScalarKind::Float32,
+                dt => bail!("unsupported input datatype `{dt:?}` for `{name}`"),
+            };
+
+            Ok(ParamInfo::new(name.clone(), dt, Some(fact.rank())))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let output_param_infos = model
+        .output_outlets()?
+        .iter()
+        .map(|&outlet| {
+            let name = model
+                .outlet_label(outlet)
+                .unwrap_or(model.node(outlet.node).name.as_str());
+            let fact = model.outlet_fact(outlet)?;
+
+            let dt = match fact.datum_type {
+                DatumType::I64 => OutputScalarKind::Int64,
+                DatumType::F32 => OutputScalarKind::Float32,
+                dt => bail!("unsupported output datatype `{dt:?}` for `{name}`"),
+            };
+
+            Ok(ParamInfo::new(name.to_owned(), dt, Some(fact.rank())))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let model = match options.tuning.graph_optimization_level {
+        crate::GraphOptimizationLevel::Disable => model,
+        crate::GraphOptimizationLevel::Basic => model.into_decluttered()?,
+        crate::GraphOptimizationLevel::Extended | crate::GraphOptimizationLevel::All => {
+            model.into_optimized()?
+        }
+        crate::GraphOptimizationLevel::__NonExhaustive => unreachable!(),
+    };
+
+    let sess = TractSession {
+        plan: model.into_runnable()?,
+        input_names: input_param_infos
+            .iter()
+            .map(|info| info.name().to_owned())
+            .collect(),
+    };
+
+    Ok((sess, input_param_infos, output_param_infos))
+}
+
+fn run_blocking(
+    TractRunContext { sess, mut inputs }: TractRunContext,
+) -> anyhow::Result<Vec<OutputTensor>> {
+    // tractはモデルの入力の順に入力を受け取る
+    let inputs = sess
+        .input_names
+        .iter()
+        .map(|name| {
+            let i = inputs
+                .iter()
+                .position(|(input_name, _)| input_name == name)
+                .with_context(|| format!("missing input `{name}`"))?;
+            Ok(inputs.swap_remove(i).1.into())
+        })
+        .collect::<anyhow::Result<TVec<TValue>>>()?;
+
+    sess.plan
+        .run(inputs)?
+        .into_iter()
+        .map(|output| extract_output(output.into_tensor()))
+        .collect()
+}
+
+pub struct TractSession {
+    plan: TypedRunnableModel<TypedModel>,
+    input_names: Vec<String>,
+}
+
+pub struct TractRunContext {
+    sess: Arc<TractSession>,
+    inputs: Vec<(&'static str, Tensor)>,
+}
+
+impl TractRunContext {
+    fn push_input(
+        &mut self,
+        name: &'static str,
+        input: Array<impl Datum + Copy, impl Dimension + 'static>,
+    ) -> anyhow::Result<()> {
+        let input = Tensor::from_shape(input.shape(), &input.iter().copied().collect::<Vec<_>>())?;
+        self.inputs.push((name, input));
+        Ok(())
+    }
+}
+
+impl From<Arc<TractSession>> for TractRunContext {
+    fn from(sess: Arc<TractSession>) -> Self {
+        Self {
+            sess,
+            inputs: vec![],
+        }
+    }
+}
+
+impl PushInputTensor for TractRunContext {
+    type Error = BuiltinRuntimeError;
+
+    #[duplicate_item(
+        method           T;
+        [ push_int64 ]   [ i64 ];
+        [ push_float32 ] [ f32 ];
+    )]
+    fn method(
+        &mut self,
+        name: &'static str,
+        tensor: Array<T, impl Dimension + 'static>,
+    ) -> Result<(), Self::Error> {
+        self.push_input(name, tensor).map_err(Into::into)
+    }
+}
+
+fn extract_output(output: Tensor) -> anyhow::Result<OutputTensor> {
+    let shape = IxDyn(output.shape());
+    match output.datum_type() {
+        DatumType::I64 => {
+            let output = ArrayD::from_shape_vec(shape, output.as_slice::<i64>()?.to_owned())?;
+            Ok(OutputTensor::Int64(output))
+        }
+        DatumType::F32 => {
+            let output = ArrayD::from_shape_vec(shape, output.as_slice::<f32>()?.to_owned())?;
+            Ok(OutputTensor::Float32(output))
+        }
+        _ => bail!("unexpected output tensor element data type"),
+    }
+}
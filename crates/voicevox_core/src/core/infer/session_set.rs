@@ -1,4 +1,13 @@
-use std::{collections::HashMap, fmt::Display, marker::PhantomData, sync::Arc};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    marker::PhantomData,
+    num::NonZero,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
 
 use anyhow::bail;
 use enum_map::{Enum as _, EnumMap};
@@ -11,8 +20,13 @@ use super::{
     InferenceRuntime, InferenceSessionOptions, InferenceSignature, ParamInfo,
 };
 
+/// 推論操作ごとのセッションの集合。
+///
+/// 一つの推論操作に対して`replicas`個のセッションを持ち、[`get`]のたびに空いているものを割り当てる。
+///
+/// [`get`]: Self::get
 pub(crate) struct InferenceSessionSet<R: InferenceRuntime, D: InferenceDomain>(
-    EnumMap<D::Operation, Arc<R::Session>>,
+    EnumMap<D::Operation, Box<[SessionReplica<R>]>>,
 );
 
 impl<R: InferenceRuntime, D: InferenceDomain> InferenceSessionSet<R, D> {
@@ -20,6 +34,7 @@ impl<R: InferenceRuntime, D: InferenceDomain> InferenceSessionSet<R, D> {
         rt: &R,
         model_bytes: &EnumMap<D::Operation, ModelBytes>,
         options: &EnumMap<D::Operation, InferenceSessionOptions>,
+        replicas: NonZero<usize>,
     ) -> anyhow::Result<Self> {
         let mut sessions = model_bytes
             .iter()
@@ -27,13 +42,22 @@ impl<R: InferenceRuntime, D: InferenceDomain> InferenceSessionSet<R, D> {
                 let (expected_input_param_infos, expected_output_param_infos) =
                     <D::Operation as InferenceOperation>::PARAM_INFOS[op];
 
-                let (sess, actual_input_param_infos, actual_output_param_infos) =
-                    rt.new_session(model_bytes, options[op])?;
+                let replicas = (0..replicas.get())
+                    .map(|_| {
+                        let (sess, actual_input_param_infos, actual_output_param_infos) =
+                            rt.new_session(model_bytes, &options[op])?;
+
+                        check_param_infos(expected_input_param_infos, &actual_input_param_infos)?;
+                        check_param_infos(expected_output_param_infos, &actual_output_param_infos)?;
 
-                check_param_infos(expected_input_param_infos, &actual_input_param_infos)?;
-                check_param_infos(expected_output_param_infos, &actual_output_param_infos)?;
+                        Ok(SessionReplica {
+                            sess: sess.into(),
+                            in_flight: Default::default(),
+                        })
+                    })
+                    .collect::<anyhow::Result<_>>()?;
 
-                Ok((op.into_usize(), sess.into()))
+                Ok((op.into_usize(), replicas))
             })
             .collect::<anyhow::Result<HashMap<_, _>>>()?;
 
@@ -76,15 +100,59 @@ impl<R: InferenceRuntime, D: InferenceDomain> InferenceSessionSet<R, D> {
     where
         I: InferenceInputSignature<Signature: InferenceSignature<Domain = D>>,
     {
+        let replicas = &self.0[I::Signature::OPERATION];
+
+        // 空いているものがあればそれを、無ければ実行中の推論が最も少ないものを使う
+        let replica = replicas
+            .iter()
+            .find(|replica| {
+                replica
+                    .in_flight
+                    .compare_exchange(0, 1, Ordering::AcqRel, Ordering::Relaxed)
+                    .is_ok()
+            })
+            .unwrap_or_else(|| {
+                let replica = replicas
+                    .iter()
+                    .min_by_key(|replica| replica.in_flight.load(Ordering::Relaxed))
+                    .expect("should not be empty");
+                replica.in_flight.fetch_add(1, Ordering::AcqRel);
+                replica
+            });
+
         InferenceSessionCell {
-            inner: self.0[I::Signature::OPERATION].clone(),
+            inner: replica.sess.clone(),
+            _in_flight: InFlightGuard(replica.in_flight.clone()),
             marker: PhantomData,
         }
     }
+
+    #[cfg(test)]
+    pub(in super::super) fn in_flight(&self, op: D::Operation) -> Vec<usize> {
+        self.0[op]
+            .iter()
+            .map(|replica| replica.in_flight.load(Ordering::Relaxed))
+            .collect()
+    }
+}
+
+struct SessionReplica<R: InferenceRuntime> {
+    sess: Arc<R::Session>,
+    in_flight: Arc<AtomicUsize>,
+}
+
+/// 実行中の推論の数を、ドロップ時に一つ減らす。
+struct InFlightGuard(Arc<AtomicUsize>);
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
 }
 
 pub(in super::super) struct InferenceSessionCell<R: InferenceRuntime, I> {
     inner: Arc<R::Session>,
+    _in_flight: InFlightGuard,
     marker: PhantomData<fn(I)>,
 }
 
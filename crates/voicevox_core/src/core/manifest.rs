@@ -4,12 +4,16 @@ use std::{
     sync::Arc,
 };
 
+use anyhow::ensure;
 use derive_getters::Getters;
 use derive_more::{Deref, Index};
 use derive_new::new;
+use duplicate::duplicate;
 use enum_map::EnumMap;
+use itertools::Itertools as _;
 use serde::{Deserialize, Deserializer, Serialize, de};
 use serde_with::{DisplayFromStr, serde_as};
+use sha2::{Digest as _, Sha256};
 
 use crate::{StyleId, VoiceModelId};
 
@@ -126,8 +130,49 @@ pub struct Manifest {
     pub(super) id: VoiceModelId,
     metas_filename: String,
     domains: InferenceDomainMap<ManifestDomains>,
+    /// ファイル名から、そのファイルのSHA-256ダイジェストへのマップ。
+    sha256_digests: BTreeMap<String, Sha256Digest>,
 }
 
+impl Manifest {
+    /// manifest.json自身を除く、このmanifestが参照するファイルの名前。
+    pub(super) fn filenames(&self) -> Vec<&str> {
+        let mut filenames = vec![&*self.metas_filename];
+        duplicate! {
+            [
+                field;
+                [ talk ];
+                [ streaming_talk ];
+                [ singing_teacher ];
+                [ frame_decode ];
+            ]
+            if let Some(domain) = &self.domains.field {
+                filenames.extend(
+                    domain
+                        .model_files()
+                        .map(|ModelFile { filename, .. }| &**filename),
+                );
+            }
+        }
+        filenames.into_iter().unique().collect()
+    }
+
+    /// `filename`のダイジェストが記載されていれば、`content`と照合する。
+    pub(super) fn verify_digest(&self, filename: &str, content: &[u8]) -> anyhow::Result<()> {
+        if let Some(Sha256Digest(expected)) = self.sha256_digests.get(filename) {
+            ensure!(
+                Sha256::digest(content)[..] == expected[..],
+                "`{filename}`のSHA-256ダイジェストが一致しません",
+            );
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(transparent)]
+pub(super) struct Sha256Digest(#[serde(with = "hex::serde")] [u8; 32]);
+
 /// 現行（vvm_format_version=2）のManifestスキーマ
 #[derive(Debug, Deserialize)]
 struct ManifestSchemaV2 {
@@ -136,6 +181,8 @@ struct ManifestSchemaV2 {
     metas_filename: String,
     #[serde(flatten)]
     domains: InferenceDomainMap<ManifestDomains>,
+    #[serde(default)]
+    sha256_digests: BTreeMap<String, Sha256Digest>,
 }
 
 impl From<ManifestSchemaV2> for Manifest {
@@ -145,6 +192,7 @@ impl From<ManifestSchemaV2> for Manifest {
             id: schema.id,
             metas_filename: schema.metas_filename,
             domains: schema.domains,
+            sha256_digests: schema.sha256_digests,
         }
     }
 }
@@ -172,6 +220,7 @@ impl From<ManifestSchemaV1> for Manifest {
                 singing_teacher: schema.singing_teacher,
                 frame_decode: schema.frame_decode,
             },
+            sha256_digests: BTreeMap::new(),
         }
     }
 }
@@ -190,6 +239,12 @@ pub(crate) struct ManifestDomain<D: InferenceDomain> {
     pub(super) style_id_to_inner_voice_id: StyleIdToInnerVoiceId,
 }
 
+impl<D: InferenceDomain> ManifestDomain<D> {
+    pub(super) fn model_files(&self) -> impl Iterator<Item = &ModelFile> {
+        self.filenames.values()
+    }
+}
+
 #[derive(Deserialize, Clone, Debug)]
 pub(crate) struct ModelFile {
     pub(super) r#type: ModelFileType,
@@ -1,6 +1,8 @@
 use std::{
     any,
     fmt::{self, Debug},
+    num::NonZero,
+    sync::Arc,
 };
 
 use duplicate::{duplicate, duplicate_item};
@@ -8,6 +10,7 @@ use educe::Educe;
 use enum_map::EnumMap;
 use indexmap::IndexMap;
 use itertools::iproduct;
+use tracing::info;
 
 use crate::{
     Result,
@@ -26,7 +29,10 @@ use super::{
     },
     manifest::{InnerVoiceId, StyleIdToInnerVoiceId},
     metas::{self, CharacterMeta, StyleId, StyleMeta, VoiceModelMeta},
-    voice_model::{ModelBytesWithInnerVoiceIdsByDomain, VoiceModelHeader, VoiceModelId},
+    voice_model::{
+        ModelBytes, ModelBytesWithInnerVoiceIdsByDomain, StyleFilter, VoiceModelHeader,
+        VoiceModelId,
+    },
 };
 
 /// `Synthesizer::load_voice_model`の実行時に、同じ[`id`]の`VoiceModelFile`が既に読み込まれていたときのふるまい。
@@ -53,69 +59,235 @@ pub enum OnExistingVoiceModelId {
     Skip,
 }
 
+/// 使われたときに初めて読み込まれる音声モデル。
+pub(crate) trait LazyVoiceModel: Send + Sync + 'static {
+    fn header(&self) -> &Arc<VoiceModelHeader>;
+
+    /// すべての`InferenceDomain`のモデルを読む。
+    ///
+    /// `trusted_public_keys`が`Some`であれば、manifestの署名も検証する。
+    ///
+    /// # Performance
+    ///
+    /// ファイルI/Oを伴うため、非同期ランタイム上では直接実行されるべきではない。
+    fn read_inference_models_blocking(
+        &self,
+        trusted_public_keys: Option<&[[u8; 32]]>,
+    ) -> LoadModelResult<InferenceDomainMap<ModelBytesWithInnerVoiceIdsByDomain>>;
+}
+
 #[derive(Debug)]
 pub(crate) struct Status<R: InferenceRuntime> {
     pub(crate) rt: &'static R,
     loaded_models: std::sync::Mutex<LoadedModels<R>>,
+    registered_models: std::sync::Mutex<RegisteredModels>,
     session_options: InferenceDomainMap<SessionOptionsByDomain>,
+    session_replicas: NonZero<usize>,
+    memory_budget: Option<u64>,
+    /// 登録された音声モデルを自動で読み込むときに、署名の検証に用いる。`None`であれば署名を要求しない。
+    trusted_public_keys: Option<Vec<[u8; 32]>>,
 }
 
 impl<R: InferenceRuntime> Status<R> {
     pub(crate) fn new(
         rt: &'static R,
         session_options: InferenceDomainMap<SessionOptionsByDomain>,
+        session_replicas: NonZero<usize>,
+        memory_budget: Option<u64>,
+        trusted_public_keys: Option<Vec<[u8; 32]>>,
     ) -> Self {
         Self {
             rt,
             loaded_models: Default::default(),
+            registered_models: Default::default(),
             session_options,
+            session_replicas,
+            memory_budget,
+            trusted_public_keys,
         }
     }
 
+    /// 音声モデルを読み込む。
+    ///
+    /// `model_contents`は`style_filter`で絞り込まれたものであるべきである。
     pub(crate) fn insert_model(
         &self,
         model_header: &VoiceModelHeader,
         model_contents: &InferenceDomainMap<ModelBytesWithInnerVoiceIdsByDomain>,
+        style_filter: &StyleFilter,
         on_existing: OnExistingVoiceModelId,
     ) -> Result<()> {
+        self.insert_model_pinned(model_header, model_contents, style_filter, on_existing)
+            .map(drop)
+    }
+
+    /// 音声モデルを読み込み、読み込んだ音声モデルを[`PinnedModel`]として返す。
+    fn insert_model_pinned(
+        &self,
+        model_header: &VoiceModelHeader,
+        model_contents: &InferenceDomainMap<ModelBytesWithInnerVoiceIdsByDomain>,
+        style_filter: &StyleFilter,
+        on_existing: OnExistingVoiceModelId,
+    ) -> Result<PinnedModel<R>> {
+        let metas = model_header.filtered_metas(style_filter)?;
+
         self.loaded_models
             .lock()
             .unwrap()
-            .ensure_acceptable(model_header, on_existing)?;
+            .ensure_acceptable(model_header, &metas, on_existing)?;
 
         let session_sets_with_inner_ids = model_contents
-            .create_session_sets(self.rt, &self.session_options)
+            .create_session_sets(self.rt, &self.session_options, self.session_replicas)
             .map_err(|source| LoadModelError {
                 path: model_header.path.clone(),
                 context: LoadModelErrorKind::InvalidModelData,
                 source: Some(source),
             })?;
+        let approx_memory = model_contents.approx_memory(self.session_replicas);
 
-        self.loaded_models.lock().unwrap().insert(
+        let pinned = self.loaded_models.lock().unwrap().insert(
             model_header,
+            metas,
             session_sets_with_inner_ids,
+            approx_memory,
             on_existing,
         )?;
-        Ok(())
+
+        self.evict_lru();
+        Ok(pinned)
+    }
+
+    #[cfg(test)]
+    pub(crate) fn session_options(&self) -> &InferenceDomainMap<SessionOptionsByDomain> {
+        &self.session_options
     }
 
     pub(crate) fn unload_model(&self, voice_model_id: VoiceModelId) -> Result<()> {
         self.loaded_models.lock().unwrap().remove(voice_model_id)
     }
 
+    /// 音声モデルを登録し、そのスタイルが使われたときに自動で読み込まれるようにする。
+    ///
+    /// 同じ`VoiceModelId`の音声モデルが既に登録されていた場合は置き換える。
+    pub(crate) fn register_model(&self, model: Arc<dyn LazyVoiceModel>) {
+        self.registered_models.lock().unwrap().insert(model);
+    }
+
+    pub(crate) fn unregister_model(&self, voice_model_id: VoiceModelId) -> Result<()> {
+        self.registered_models
+            .lock()
+            .unwrap()
+            .remove(voice_model_id)
+    }
+
+    pub(crate) fn is_registered_model(&self, voice_model_id: VoiceModelId) -> bool {
+        self.registered_models
+            .lock()
+            .unwrap()
+            .0
+            .contains_key(&voice_model_id)
+    }
+
+    /// `style_id`を持つ音声モデルが、登録されているが読み込まれていないかどうか。
+    pub(crate) fn needs_autoload(&self, style_id: StyleId) -> bool {
+        !self.is_loaded_model_by_style_id(style_id)
+            && self
+                .registered_models
+                .lock()
+                .unwrap()
+                .find(style_id)
+                .is_some()
+    }
+
+    /// `style_id`を持つ音声モデルが登録されていて、かつ読み込まれていなければ読み込む。
+    ///
+    /// 読み込み後に`memory_budget`を超えていれば、登録されている音声モデルのうち最も長い間使われて
+    /// いないものから順にアンロードする。
+    ///
+    /// `style_id`を持つ音声モデルが読み込まれていれば、それを[`PinnedModel`]として返す。
+    ///
+    /// # Performance
+    ///
+    /// ファイルI/OとCPU/GPU-boundな操作を伴うため、非同期ランタイム上では直接実行されるべきではない。
+    pub(crate) fn autoload(&self, style_id: StyleId) -> Result<Option<PinnedModel<R>>> {
+        if let Some(pinned) = self.pin(style_id) {
+            return Ok(Some(pinned));
+        }
+        let Some(model) = self.registered_models.lock().unwrap().find(style_id) else {
+            return Ok(None);
+        };
+
+        let header = model.header();
+        let contents = model.read_inference_models_blocking(self.trusted_public_keys.as_deref())?;
+        // TOCTOUが起きたとしても同じ音声モデルを二度読み込むだけで済むため、`Reload`とする。
+        let pinned = self.insert_model_pinned(
+            header,
+            &contents,
+            &StyleFilter::default(),
+            OnExistingVoiceModelId::Reload,
+        )?;
+        info!(
+            voice_model_id = %header.manifest.id,
+            style_id = style_id.0,
+            "音声モデルを自動で読み込みました",
+        );
+        Ok(Some(pinned))
+    }
+
+    /// `style_id`を持つ音声モデルが読み込まれていれば、それを[`PinnedModel`]として返す。
+    pub(crate) fn pin(&self, style_id: StyleId) -> Option<PinnedModel<R>> {
+        self.loaded_models.lock().unwrap().pin(style_id)
+    }
+
+    /// 読み込まれている音声モデルのメモリ使用量の概算値の合計が`memory_budget`を超えていれば、
+    /// 登録されている音声モデルを最も長い間使われていないものから順にアンロードする。
+    ///
+    /// [`PinnedModel`]が存在する音声モデルはアンロードの対象外とする。
+    fn evict_lru(&self) {
+        let Some(memory_budget) = self.memory_budget else {
+            return;
+        };
+        let evictables = self
+            .registered_models
+            .lock()
+            .unwrap()
+            .0
+            .keys()
+            .copied()
+            .collect::<Vec<_>>();
+
+        let mut loaded_models = self.loaded_models.lock().unwrap();
+        while loaded_models.approx_memory() > memory_budget {
+            let Some((voice_model_id, approx_memory)) = loaded_models.remove_lru(&evictables)
+            else {
+                break;
+            };
+            info!(
+                %voice_model_id,
+                approx_memory,
+                memory_budget,
+                "メモリ使用量の上限を超えたため、音声モデルをアンロードしました",
+            );
+        }
+    }
+
     pub(crate) fn metas(&self) -> VoiceModelMeta {
         self.loaded_models.lock().unwrap().metas()
     }
 
-    /// あるスタイルに対応する`VoiceModelId`と`InnerVoiceId`の組を返す。
+    /// あるスタイルに対応する音声モデルと`InnerVoiceId`の組を返す。
     ///
     /// `StyleId` → `InnerVoiceId`のマッピングが存在しない場合は、`InnerVoiceId`としては
     /// `style_id`と同じ値を返す。
     pub(crate) fn ids_for<D: InferenceDomainExt>(
         &self,
         style_id: StyleId,
-    ) -> Result<(VoiceModelId, InnerVoiceId)> {
-        self.loaded_models.lock().unwrap().ids_for::<D>(style_id)
+    ) -> Result<(PinnedModel<R>, InnerVoiceId)> {
+        let mut loaded_models = self.loaded_models.lock().unwrap();
+        let (model_id, inner_voice_id) = loaded_models.ids_for::<D>(style_id)?;
+        loaded_models.touch(model_id);
+        let pinned = loaded_models.0[&model_id].pin();
+        Ok((pinned, inner_voice_id))
     }
 
     pub(crate) fn contains_domain<D: InferenceDomainExt>(&self, style_id: StyleId) -> bool {
@@ -146,10 +318,10 @@ impl<R: InferenceRuntime> Status<R> {
     ///
     /// # Panics
     ///
-    /// `self`が`model_id`を含んでいないとき、パニックする。
+    /// `model`に対応する`InferenceDomain`が欠けているとき、パニックする。
     pub(crate) async fn run_session<A, I>(
         &self,
-        model_id: VoiceModelId,
+        model: &PinnedModel<R>,
         input: I,
         cancellable: A::Cancellable,
     ) -> Result<<I::Signature as InferenceSignature>::Output>
@@ -158,17 +330,54 @@ impl<R: InferenceRuntime> Status<R> {
         I: InferenceInputSignature,
         <I::Signature as InferenceSignature>::Domain: InferenceDomainExt,
     {
-        let sess = self.loaded_models.lock().unwrap().get(model_id);
+        let sess = model.get();
         sess.run::<A>(input, cancellable).await
     }
 }
 
+/// 使用中の音声モデル。[`Status::ids_for`]や[`Status::autoload`]から得られる。
+///
+/// これが存在する間、音声モデルは`memory_budget`によってアンロードされない。また明示的に
+/// アンロードされた場合でも、セッションはこれが破棄されるまで保持される。
+pub(crate) struct PinnedModel<R: InferenceRuntime>(
+    Arc<InferenceDomainMap<SessionSetsWithInnerVoiceIdsByDomain<R>>>,
+);
+
+impl<R: InferenceRuntime> PinnedModel<R> {
+    /// # Panics
+    ///
+    /// 対応する`InferenceDomain`が欠けているとき、パニックする。
+    fn get<I>(&self) -> InferenceSessionCell<R, I>
+    where
+        I: InferenceInputSignature,
+        <I::Signature as InferenceSignature>::Domain: InferenceDomainExt,
+    {
+        let (_, session_set) = self
+            .0
+            .get::<<I::Signature as InferenceSignature>::Domain>()
+            .as_ref()
+            .unwrap_or_else(|| {
+                let type_name = any::type_name::<<I::Signature as InferenceSignature>::Domain>()
+                    .split("::")
+                    .last()
+                    .unwrap();
+                panic!(
+                    "missing session set for `{type_name}` (should be checked in \
+                     `VoiceModelHeader::new` and `ids_for`)",
+                );
+            });
+        session_set.get()
+    }
+}
+
 /// 読み込んだモデルの`Session`とそのメタ情報を保有し、追加/削除/取得の操作を提供する。
 ///
 /// この構造体のメソッドは、すべて一瞬で完了すべきである。
+///
+/// 二つ目のフィールドは、[`LoadedModel::last_used`]に用いる論理時刻。
 #[derive(Educe)]
 #[educe(Default(bound = "R: InferenceRuntime"))]
-struct LoadedModels<R: InferenceRuntime>(IndexMap<VoiceModelId, LoadedModel<R>>);
+struct LoadedModels<R: InferenceRuntime>(IndexMap<VoiceModelId, LoadedModel<R>>, u64);
 
 impl<R: InferenceRuntime> Debug for LoadedModels<R> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -180,7 +389,22 @@ impl<R: InferenceRuntime> Debug for LoadedModels<R> {
 
 struct LoadedModel<R: InferenceRuntime> {
     metas: VoiceModelMeta,
-    session_sets_with_inner_ids: InferenceDomainMap<SessionSetsWithInnerVoiceIdsByDomain<R>>,
+    /// [`PinnedModel`]と共有される。
+    session_sets_with_inner_ids: Arc<InferenceDomainMap<SessionSetsWithInnerVoiceIdsByDomain<R>>>,
+    /// メモリ使用量の概算値（バイト）。
+    approx_memory: u64,
+    /// 最後に使われた論理時刻。
+    last_used: u64,
+}
+
+impl<R: InferenceRuntime> LoadedModel<R> {
+    fn pin(&self) -> PinnedModel<R> {
+        PinnedModel(self.session_sets_with_inner_ids.clone())
+    }
+
+    fn is_pinned(&self) -> bool {
+        Arc::strong_count(&self.session_sets_with_inner_ids) > 1
+    }
 }
 
 impl<R: InferenceRuntime> LoadedModels<R> {
@@ -188,6 +412,36 @@ impl<R: InferenceRuntime> LoadedModels<R> {
         metas::merge(self.0.values().flat_map(|LoadedModel { metas, .. }| metas))
     }
 
+    fn approx_memory(&self) -> u64 {
+        self.0
+            .values()
+            .map(|&LoadedModel { approx_memory, .. }| approx_memory)
+            .sum()
+    }
+
+    fn touch(&mut self, model_id: VoiceModelId) {
+        let now = self.tick();
+        if let Some(model) = self.0.get_mut(&model_id) {
+            model.last_used = now;
+        }
+    }
+
+    fn tick(&mut self) -> u64 {
+        self.1 += 1;
+        self.1
+    }
+
+    /// `candidates`のうち、[`PinnedModel`]が存在せず最も長い間使われていない音声モデルを取り除く。
+    fn remove_lru(&mut self, candidates: &[VoiceModelId]) -> Option<(VoiceModelId, u64)> {
+        let (&model_id, _) = self
+            .0
+            .iter()
+            .filter(|(model_id, model)| candidates.contains(model_id) && !model.is_pinned())
+            .min_by_key(|(_, LoadedModel { last_used, .. })| *last_used)?;
+        let LoadedModel { approx_memory, .. } = self.0.shift_remove(&model_id)?;
+        Some((model_id, approx_memory))
+    }
+
     fn ids_for<D: InferenceDomainExt>(
         &self,
         style_id: StyleId,
@@ -230,32 +484,16 @@ impl<R: InferenceRuntime> LoadedModels<R> {
         Ok((*model_id, inner_voice_id))
     }
 
-    /// # Panics
-    ///
-    /// 次の場合にパニックする。
-    ///
-    /// - `self`が`model_id`を含んでいないとき
-    /// - 対応する`InferenceDomain`が欠けているとき
-    fn get<I>(&self, model_id: VoiceModelId) -> InferenceSessionCell<R, I>
-    where
-        I: InferenceInputSignature,
-        <I::Signature as InferenceSignature>::Domain: InferenceDomainExt,
-    {
-        let (_, session_set) = self.0[&model_id]
-            .session_sets_with_inner_ids
-            .get::<<I::Signature as InferenceSignature>::Domain>()
-            .as_ref()
-            .unwrap_or_else(|| {
-                let type_name = any::type_name::<<I::Signature as InferenceSignature>::Domain>()
-                    .split("::")
-                    .last()
-                    .unwrap();
-                panic!(
-                    "missing session set for `{type_name}` (should be checked in \
-                     `VoiceModelHeader::new` and `ids_for`)",
-                );
-            });
-        session_set.get()
+    fn pin(&self, style_id: StyleId) -> Option<PinnedModel<R>> {
+        self.0
+            .values()
+            .find(|LoadedModel { metas, .. }| {
+                metas
+                    .iter()
+                    .flat_map(|CharacterMeta { styles, .. }| styles)
+                    .any(|style| style.id == style_id)
+            })
+            .map(LoadedModel::pin)
     }
 
     fn contains_domain<D: InferenceDomainExt>(&self, style_id: StyleId) -> bool {
@@ -301,6 +539,7 @@ impl<R: InferenceRuntime> LoadedModels<R> {
     fn ensure_acceptable(
         &self,
         model_header: &VoiceModelHeader,
+        metas: &VoiceModelMeta,
         on_existing: OnExistingVoiceModelId,
     ) -> LoadModelResult<()> {
         let error = |context| LoadModelError {
@@ -323,7 +562,7 @@ impl<R: InferenceRuntime> LoadedModels<R> {
         // FIXME: https://github.com/VOICEVOX/voicevox_core/pull/761#discussion_r1590200343
 
         let loaded = self.characters();
-        let external = model_header.metas.iter();
+        let external = metas.iter();
         for (loaded, external) in iproduct!(loaded, external) {
             if loaded.speaker_uuid == external.speaker_uuid {
                 loaded.warn_diff_except_styles(external);
@@ -331,8 +570,7 @@ impl<R: InferenceRuntime> LoadedModels<R> {
         }
 
         let loaded = self.styles().map(|&StyleMeta { id, .. }| id);
-        let external = model_header
-            .metas
+        let external = metas
             .iter()
             .flat_map(|CharacterMeta { styles, .. }| styles)
             .map(|&StyleMeta { id, .. }| id);
@@ -347,15 +585,20 @@ impl<R: InferenceRuntime> LoadedModels<R> {
     fn insert(
         &mut self,
         model_header: &VoiceModelHeader,
+        metas: VoiceModelMeta,
         session_sets_with_inner_ids: InferenceDomainMap<SessionSetsWithInnerVoiceIdsByDomain<R>>,
+        approx_memory: u64,
         on_existing: OnExistingVoiceModelId,
-    ) -> Result<()> {
-        self.ensure_acceptable(model_header, on_existing)?;
+    ) -> Result<PinnedModel<R>> {
+        self.ensure_acceptable(model_header, &metas, on_existing)?;
 
+        let last_used = self.tick();
         let entry = self.0.entry(model_header.manifest.id);
         let model = LoadedModel {
-            metas: model_header.metas.clone(),
-            session_sets_with_inner_ids,
+            metas,
+            session_sets_with_inner_ids: Arc::new(session_sets_with_inner_ids),
+            approx_memory,
+            last_used,
         };
 
         match entry {
@@ -365,14 +608,11 @@ impl<R: InferenceRuntime> LoadedModels<R> {
                 }
                 OnExistingVoiceModelId::Reload => {
                     entry.insert(model);
-                    Ok(())
+                    Ok(entry.get().pin())
                 }
-                OnExistingVoiceModelId::Skip => Ok(()),
+                OnExistingVoiceModelId::Skip => Ok(entry.get().pin()),
             },
-            indexmap::map::Entry::Vacant(entry) => {
-                entry.insert(model);
-                Ok(())
-            }
+            indexmap::map::Entry::Vacant(entry) => Ok(entry.insert(model).pin()),
         }
     }
 
@@ -393,6 +633,43 @@ impl<R: InferenceRuntime> LoadedModels<R> {
     }
 }
 
+/// 登録された[`LazyVoiceModel`]を保有する。
+#[derive(Default)]
+struct RegisteredModels(IndexMap<VoiceModelId, Arc<dyn LazyVoiceModel>>);
+
+impl Debug for RegisteredModels {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_set().entries(self.0.keys()).finish()
+    }
+}
+
+impl RegisteredModels {
+    fn insert(&mut self, model: Arc<dyn LazyVoiceModel>) {
+        self.0.insert(model.header().manifest.id, model);
+    }
+
+    fn remove(&mut self, model_id: VoiceModelId) -> Result<()> {
+        if self.0.shift_remove(&model_id).is_none() {
+            return Err(ErrorRepr::ModelNotFound { model_id }.into());
+        }
+        Ok(())
+    }
+
+    fn find(&self, style_id: StyleId) -> Option<Arc<dyn LazyVoiceModel>> {
+        self.0
+            .values()
+            .find(|model| {
+                model
+                    .header()
+                    .metas
+                    .iter()
+                    .flat_map(|CharacterMeta { styles, .. }| styles)
+                    .any(|style| style.id == style_id)
+            })
+            .cloned()
+    }
+}
+
 pub(crate) trait InferenceDomainExt: InferenceDomain {
     fn visit<R: InferenceRuntime>(
         map: &InferenceDomainMap<SessionSetsWithInnerVoiceIdsByDomain<R>>,
@@ -427,6 +704,7 @@ impl InferenceDomainMap<ModelBytesWithInnerVoiceIdsByDomain> {
         &self,
         rt: &R,
         session_options: &InferenceDomainMap<SessionOptionsByDomain>,
+        session_replicas: NonZero<usize>,
     ) -> anyhow::Result<InferenceDomainMap<SessionSetsWithInnerVoiceIdsByDomain<R>>> {
         duplicate! {
             [
@@ -440,7 +718,12 @@ impl InferenceDomainMap<ModelBytesWithInnerVoiceIdsByDomain> {
                 .field
                 .as_ref()
                 .map(|(inner_voice_ids, model_bytes)| {
-                    let session_set = InferenceSessionSet::new(rt, model_bytes, &session_options.field)?;
+                    let session_set = InferenceSessionSet::new(
+                        rt,
+                        model_bytes,
+                        &session_options.field,
+                        session_replicas,
+                    )?;
                     Ok::<_, anyhow::Error>((inner_voice_ids.clone(), session_set))
                 })
                 .transpose()?;
@@ -453,6 +736,26 @@ impl InferenceDomainMap<ModelBytesWithInnerVoiceIdsByDomain> {
             frame_decode,
         })
     }
+
+    /// セッションのメモリ使用量の概算値（バイト）。
+    ///
+    /// モデルのバイト列の大きさとセッションの数の積とする。
+    fn approx_memory(&self, session_replicas: NonZero<usize>) -> u64 {
+        let mut sum = 0;
+        duplicate! {
+            [
+                field;
+                [ talk ];
+                [ streaming_talk ];
+                [ singing_teacher ];
+                [ frame_decode ];
+            ]
+            if let Some((_, model_bytes)) = &self.field {
+                sum += model_bytes.values().map(ModelBytes::len).sum::<usize>();
+            }
+        }
+        (sum * session_replicas.get()) as u64
+    }
 }
 
 type SessionOptionsByDomain =
@@ -463,186 +766,491 @@ type SessionSetsWithInnerVoiceIdsByDomain<R> =
 
 #[cfg(test)]
 mod tests {
-    use std::sync::{Arc, LazyLock};
+    use std::{convert::Infallible, marker::PhantomData, num::NonZero, sync::Arc};
 
+    use duplicate::duplicate_item;
     use enum_map::{Enum, EnumMap, enum_map};
     use ndarray::{Array, Dimension};
     use pretty_assertions::assert_eq;
-    use rstest::{fixture, rstest};
-    use strum::IntoEnumIterator as _;
+    use rstest::rstest;
     use uuid::{Uuid, uuid};
 
     use crate::{
-        CharacterMeta, CharacterVersion, OnExistingVoiceModelId, StyleMeta, StyleType,
-        SupportedDevices,
+        CharacterMeta, CharacterVersion, StyleId, StyleMeta, StyleType, SupportedDevices,
+        error::LoadModelResult,
     };
 
     use super::{
         super::{
-            devices::{DeviceSpec, GpuSpec},
+            devices::{DeviceSpec, ExecutionProvider},
             infer::{
                 InferenceOperation, InferenceRuntime, InferenceSessionOptions, InputScalarKind,
                 OutputScalarKind, OutputTensor, ParamInfo, PushInputTensor,
                 domains::{
-                    FrameDecodeOperation, InferenceDomainMap, SingingTeacherOperation,
-                    StreamingTalkOperation, TalkOperation, inference_domain_map,
+                    InferenceDomainMap, TalkDomain, TalkOperation, inference_domain_map,
+                    talk::PredictDurationInput,
                 },
             },
             voice_model::{ModelBytes, ModelBytesWithInnerVoiceIdsByDomain, VoiceModelHeader},
         },
-        Status,
+        LazyVoiceModel, Status,
     };
 
-    #[rstest]
-    #[case(DeviceSpec::Gpu(GpuSpec::Cuda), 0)]
-    #[case(DeviceSpec::Gpu(GpuSpec::Cuda), 1)]
-    #[case(DeviceSpec::Gpu(GpuSpec::Cuda), 8)]
-    #[case(DeviceSpec::Cpu, 2)]
-    #[case(DeviceSpec::Cpu, 4)]
-    #[case(DeviceSpec::Cpu, 8)]
-    #[case(DeviceSpec::Cpu, 0)]
-    fn status_new_works(#[case] device_for_heavy: DeviceSpec, #[case] cpu_num_threads: u16) {
-        let light_session_options = InferenceSessionOptions::new(cpu_num_threads, DeviceSpec::Cpu);
-        let heavy_session_options = InferenceSessionOptions::new(cpu_num_threads, device_for_heavy);
-        let session_options = InferenceDomainMap {
-            talk: enum_map! {
-                TalkOperation::PredictDuration | TalkOperation::PredictIntonation => {
-                    light_session_options
-                }
-                TalkOperation::Decode => heavy_session_options,
-            },
-            streaming_talk: enum_map! {
-                StreamingTalkOperation::PredictDuration
-                | StreamingTalkOperation::PredictIntonation
-                | StreamingTalkOperation::GenerateFullIntermediate => light_session_options,
-                StreamingTalkOperation::RenderAudioSegment => heavy_session_options,
-            },
-            singing_teacher: enum_map! {
-                SingingTeacherOperation::PredictSingConsonantLength
-                | SingingTeacherOperation::PredictSingF0
-                | SingingTeacherOperation::PredictSingVolume => light_session_options,
-            },
-            frame_decode: enum_map! {
-                FrameDecodeOperation::SfDecode => heavy_session_options,
+    #[duplicate_item(
+        runtime   Runtime                                         enabled;
+        [ mock ]  [ super::InferenceRuntimeMock ]                 [ all() ];
+        [ tract ] [ crate::core::infer::runtimes::tract::Tract ] [ feature = "tract" ];
+    )]
+    #[cfg(enabled)]
+    mod runtime {
+        use std::{num::NonZero, sync::Arc};
+
+        use enum_map::enum_map;
+        use pretty_assertions::assert_eq;
+        use rstest::{fixture, rstest};
+        use strum::IntoEnumIterator as _;
+        use uuid::uuid;
+
+        use crate::{CharacterMeta, OnExistingVoiceModelId, StyleId, StyleMeta};
+
+        use super::{
+            super::{
+                super::{
+                    devices::{DeviceSpec, ExecutionProvider},
+                    infer::{
+                        InferenceSessionOptions,
+                        domains::{
+                            FrameDecodeOperation, InferenceDomainMap, SingingTeacherOperation,
+                            StreamingTalkOperation, TalkDomain, TalkOperation,
+                            talk::PredictDurationInput,
+                        },
+                    },
+                    voice_model::StyleFilter,
+                },
+                Status,
             },
+            LazyVoiceModelMock, TestRuntime as _, dummy_contents, header, status_with_replicas,
         };
-        let status = Status::new(&InferenceRuntimeMock, session_options);
 
-        assert_eq!(
-            light_session_options,
-            status.session_options.streaming_talk[StreamingTalkOperation::PredictDuration],
-        );
-        assert_eq!(
-            light_session_options,
-            status.session_options.streaming_talk[StreamingTalkOperation::PredictIntonation],
-        );
-        assert_eq!(
-            light_session_options,
-            status.session_options.streaming_talk[StreamingTalkOperation::GenerateFullIntermediate],
-        );
-        assert_eq!(
-            heavy_session_options,
-            status.session_options.streaming_talk[StreamingTalkOperation::RenderAudioSegment],
-        );
+        #[rstest]
+        #[case(DeviceSpec::ExecutionProvider(ExecutionProvider::Cuda), 0)]
+        #[case(DeviceSpec::ExecutionProvider(ExecutionProvider::Cuda), 1)]
+        #[case(DeviceSpec::ExecutionProvider(ExecutionProvider::Cuda), 8)]
+        #[case(DeviceSpec::ExecutionProvider(ExecutionProvider::Xnnpack), 4)]
+        #[case(DeviceSpec::Cpu, 2)]
+        #[case(DeviceSpec::Cpu, 4)]
+        #[case(DeviceSpec::Cpu, 8)]
+        #[case(DeviceSpec::Cpu, 0)]
+        fn status_new_works(#[case] device_for_heavy: DeviceSpec, #[case] cpu_num_threads: u16) {
+            let light_session_options =
+                InferenceSessionOptions::new(cpu_num_threads, DeviceSpec::Cpu, Default::default());
+            let heavy_session_options =
+                InferenceSessionOptions::new(cpu_num_threads, device_for_heavy, Default::default());
+            let session_options = InferenceDomainMap {
+                talk: enum_map! {
+                    TalkOperation::PredictDuration | TalkOperation::PredictIntonation => {
+                        light_session_options.clone()
+                    }
+                    TalkOperation::Decode => heavy_session_options.clone(),
+                },
+                streaming_talk: enum_map! {
+                    StreamingTalkOperation::PredictDuration
+                    | StreamingTalkOperation::PredictIntonation
+                    | StreamingTalkOperation::GenerateFullIntermediate => light_session_options.clone(),
+                    StreamingTalkOperation::RenderAudioSegment => heavy_session_options.clone(),
+                },
+                singing_teacher: enum_map! {
+                    SingingTeacherOperation::PredictSingConsonantLength
+                    | SingingTeacherOperation::PredictSingF0
+                    | SingingTeacherOperation::PredictSingVolume => light_session_options.clone(),
+                },
+                frame_decode: enum_map! {
+                    FrameDecodeOperation::SfDecode => heavy_session_options.clone(),
+                },
+            };
+            let status = Status::new(
+                Runtime::instance(),
+                session_options,
+                NonZero::<usize>::MIN,
+                None,
+                None,
+            );
+
+            assert_eq!(
+                light_session_options,
+                status.session_options.streaming_talk[StreamingTalkOperation::PredictDuration],
+            );
+            assert_eq!(
+                light_session_options,
+                status.session_options.streaming_talk[StreamingTalkOperation::PredictIntonation],
+            );
+            assert_eq!(
+                light_session_options,
+                status.session_options.streaming_talk
+                    [StreamingTalkOperation::GenerateFullIntermediate],
+            );
+            assert_eq!(
+                heavy_session_options,
+                status.session_options.streaming_talk[StreamingTalkOperation::RenderAudioSegment],
+            );
+
+            assert!(status.loaded_models.lock().unwrap().0.is_empty());
+        }
 
-        assert!(status.loaded_models.lock().unwrap().0.is_empty());
-    }
+        #[rstest]
+        fn is_loaded_model_returns_false_for_nonexisting_model_id(status: Status<Runtime>) {
+            assert!(!status.is_loaded_model(uuid!("00000000-0000-4000-a000-000000000001").into()));
+        }
 
-    #[rstest]
-    fn is_loaded_model_returns_false_for_nonexisting_model_id(
-        status: Status<InferenceRuntimeMock>,
-    ) {
-        assert!(!status.is_loaded_model(uuid!("00000000-0000-4000-a000-000000000001").into()));
-    }
+        #[rstest]
+        fn insert_model_without_id_duplications_succeeds_regardless_of_on_existing(
+            status: Status<Runtime>,
+        ) {
+            let contents = &dummy_contents::<Runtime>();
+            let h1 = &header(uuid!("00000000-0000-4000-a000-000000000001"), [0, 1]);
+            let h2 = &header(uuid!("00000000-0000-4000-a000-000000000002"), [2, 3]);
+
+            for o in OnExistingVoiceModelId::iter() {
+                status
+                    .insert_model(h1, contents, &StyleFilter::default(), o)
+                    .unwrap();
+                status
+                    .insert_model(h2, contents, &StyleFilter::default(), o)
+                    .unwrap();
+                assert!(status.is_loaded_model(h1.manifest.id));
+                assert!(status.is_loaded_model(h2.manifest.id));
 
-    #[rstest]
-    fn insert_model_without_id_duplications_succeeds_regardless_of_on_existing(
-        status: Status<InferenceRuntimeMock>,
-    ) {
-        let h1 = &header(uuid!("00000000-0000-4000-a000-000000000001"), [0, 1]);
-        let h2 = &header(uuid!("00000000-0000-4000-a000-000000000002"), [2, 3]);
+                status.unload_model(h1.manifest.id).unwrap();
+                status.unload_model(h2.manifest.id).unwrap();
+                assert!(!status.is_loaded_model(h1.manifest.id));
+                assert!(!status.is_loaded_model(h2.manifest.id));
+            }
+        }
 
-        for o in OnExistingVoiceModelId::iter() {
-            status.insert_model(h1, &DUMMY_CONTENTS, o).unwrap();
-            status.insert_model(h2, &DUMMY_CONTENTS, o).unwrap();
-            assert!(status.is_loaded_model(h1.manifest.id));
-            assert!(status.is_loaded_model(h2.manifest.id));
+        #[rstest]
+        fn style_id_duplication_is_denied_regardless_of_on_existing(status: Status<Runtime>) {
+            let contents = &dummy_contents::<Runtime>();
+            use OnExistingVoiceModelId::Error;
 
-            status.unload_model(h1.manifest.id).unwrap();
-            status.unload_model(h2.manifest.id).unwrap();
-            assert!(!status.is_loaded_model(h1.manifest.id));
-            assert!(!status.is_loaded_model(h2.manifest.id));
+            let h1 = &header(uuid!("00000000-0000-4000-a000-000000000001"), [0, 1]);
+            let h2 = &header(uuid!("00000000-0000-4000-a000-000000000002"), [1, 2]);
+
+            status
+                .insert_model(h1, contents, &StyleFilter::default(), Error)
+                .unwrap();
+
+            for o in OnExistingVoiceModelId::iter() {
+                let err = status
+                    .insert_model(h2, contents, &StyleFilter::default(), o)
+                    .unwrap_err();
+                assert_eq!(crate::ErrorKind::StyleAlreadyLoaded, err.kind());
+            }
         }
-    }
 
-    #[rstest]
-    fn style_id_duplication_is_denied_regardless_of_on_existing(
-        status: Status<InferenceRuntimeMock>,
-    ) {
-        use OnExistingVoiceModelId::Error;
+        #[rstest]
+        fn same_model_id_bypasses_style_id_check(status: Status<Runtime>) {
+            let contents = &dummy_contents::<Runtime>();
+            use OnExistingVoiceModelId::{Error, Reload, Skip};
+
+            let h1 = &header(uuid!("00000000-0000-4000-a000-000000000001"), [0, 1]);
+            let h2 = &header(uuid!("00000000-0000-4000-a000-000000000001"), [1, 2]);
+
+            status
+                .insert_model(h1, contents, &StyleFilter::default(), Error)
+                .unwrap();
+
+            let err = status
+                .insert_model(h1, contents, &StyleFilter::default(), Error)
+                .unwrap_err();
+            assert_eq!(crate::ErrorKind::ModelAlreadyLoaded, err.kind());
+            status
+                .insert_model(h2, contents, &StyleFilter::default(), Reload)
+                .unwrap();
+            status
+                .insert_model(h1, contents, &StyleFilter::default(), Skip)
+                .unwrap();
+        }
 
-        let h1 = &header(uuid!("00000000-0000-4000-a000-000000000001"), [0, 1]);
-        let h2 = &header(uuid!("00000000-0000-4000-a000-000000000002"), [1, 2]);
+        #[rstest]
+        fn on_existing_error_denies_model_id_duplication(status: Status<Runtime>) {
+            let contents = &dummy_contents::<Runtime>();
+            use OnExistingVoiceModelId::Error;
 
-        status.insert_model(h1, &DUMMY_CONTENTS, Error).unwrap();
+            let h = &header(uuid!("00000000-0000-4000-a000-000000000001"), [0]);
 
-        for o in OnExistingVoiceModelId::iter() {
-            let err = status.insert_model(h2, &DUMMY_CONTENTS, o).unwrap_err();
-            assert_eq!(crate::ErrorKind::StyleAlreadyLoaded, err.kind());
+            status
+                .insert_model(h, contents, &StyleFilter::default(), Error)
+                .unwrap();
+            let err = status
+                .insert_model(h, contents, &StyleFilter::default(), Error)
+                .unwrap_err();
+            assert_eq!(crate::ErrorKind::ModelAlreadyLoaded, err.kind());
         }
-    }
 
-    #[rstest]
-    fn same_model_id_bypasses_style_id_check(status: Status<InferenceRuntimeMock>) {
-        use OnExistingVoiceModelId::{Error, Reload, Skip};
+        #[rstest]
+        fn on_existing_reload_allows_model_id_duplication(status: Status<Runtime>) {
+            let contents = &dummy_contents::<Runtime>();
+            use OnExistingVoiceModelId::Reload;
 
-        let h1 = &header(uuid!("00000000-0000-4000-a000-000000000001"), [0, 1]);
-        let h2 = &header(uuid!("00000000-0000-4000-a000-000000000001"), [1, 2]);
+            let h = &header(uuid!("00000000-0000-4000-a000-000000000001"), [0]);
 
-        status.insert_model(h1, &DUMMY_CONTENTS, Error).unwrap();
+            status
+                .insert_model(h, contents, &StyleFilter::default(), Reload)
+                .unwrap();
+            status
+                .insert_model(h, contents, &StyleFilter::default(), Reload)
+                .unwrap();
+        }
 
-        let err = status.insert_model(h1, &DUMMY_CONTENTS, Error).unwrap_err();
-        assert_eq!(crate::ErrorKind::ModelAlreadyLoaded, err.kind());
-        status.insert_model(h2, &DUMMY_CONTENTS, Reload).unwrap();
-        status.insert_model(h1, &DUMMY_CONTENTS, Skip).unwrap();
-    }
+        #[rstest]
+        fn on_existing_skip_allows_model_id_duplication(status: Status<Runtime>) {
+            let contents = &dummy_contents::<Runtime>();
+            use OnExistingVoiceModelId::Skip;
 
-    #[rstest]
-    fn on_existing_error_denies_model_id_duplication(status: Status<InferenceRuntimeMock>) {
-        use OnExistingVoiceModelId::Error;
+            let h = &header(uuid!("00000000-0000-4000-a000-000000000001"), [0]);
+
+            status
+                .insert_model(h, contents, &StyleFilter::default(), Skip)
+                .unwrap();
+            status
+                .insert_model(h, contents, &StyleFilter::default(), Skip)
+                .unwrap();
+        }
+
+        #[rstest]
+        fn style_filter_restricts_loaded_styles(status: Status<Runtime>) {
+            let contents = &dummy_contents::<Runtime>();
+            use OnExistingVoiceModelId::Error;
+
+            let h1 = &header(uuid!("00000000-0000-4000-a000-000000000001"), [0, 1]);
+            let h2 = &header(uuid!("00000000-0000-4000-a000-000000000002"), [1, 2]);
+            let filter = &StyleFilter {
+                style_types: None,
+                style_ids: Some([StyleId(0)].into()),
+            };
+
+            status.insert_model(h1, contents, filter, Error).unwrap();
+            assert!(status.is_loaded_model_by_style_id(StyleId(0)));
+            assert!(!status.is_loaded_model_by_style_id(StyleId(1)));
+
+            let err = status.ids_for::<TalkDomain>(StyleId(1)).unwrap_err();
+            assert_eq!(crate::ErrorKind::StyleNotFound, err.kind());
+
+            // 読み込まなかったスタイルIDは、他の音声モデルとは重複しない
+            status
+                .insert_model(h2, contents, &StyleFilter::default(), Error)
+                .unwrap();
+            assert_eq!(
+                [0, 1, 2],
+                *status
+                    .metas()
+                    .iter()
+                    .flat_map(|CharacterMeta { styles, .. }| styles)
+                    .map(|StyleMeta { id, .. }| id.0)
+                    .collect::<Vec<_>>(),
+            );
+        }
 
-        let h = &header(uuid!("00000000-0000-4000-a000-000000000001"), [0]);
+        #[rstest]
+        #[case(1, 3, &[3])]
+        #[case(3, 3, &[1, 1, 1])]
+        #[case(3, 2, &[1, 1, 0])]
+        #[case(2, 3, &[2, 1])]
+        fn sessions_are_dispatched_to_idle_replicas(
+            #[case] replicas: usize,
+            #[case] num_cells: usize,
+            #[case] expected: &[usize],
+        ) {
+            let contents = &dummy_contents::<Runtime>();
+            let status = status_with_replicas::<Runtime>(NonZero::new(replicas).unwrap());
+            let h = &header(uuid!("00000000-0000-4000-a000-000000000001"), [0]);
+            status
+                .insert_model(
+                    h,
+                    contents,
+                    &StyleFilter::default(),
+                    OnExistingVoiceModelId::Error,
+                )
+                .unwrap();
+
+            let (pinned, _) = status.ids_for::<TalkDomain>(StyleId(0)).unwrap();
+            let cells = (0..num_cells)
+                .map(|_| pinned.get::<PredictDurationInput>())
+                .collect::<Vec<_>>();
+
+            let (_, session_set) = pinned.0.talk.as_ref().unwrap();
+            assert_eq!(
+                expected,
+                session_set.in_flight(TalkOperation::PredictDuration)
+            );
+
+            drop(cells);
+            assert!(
+                session_set
+                    .in_flight(TalkOperation::PredictDuration)
+                    .iter()
+                    .all(|&n| n == 0)
+            );
+        }
+
+        #[rstest]
+        fn registered_model_is_loaded_on_first_use(status: Status<Runtime>) {
+            let h = header(uuid!("00000000-0000-4000-a000-000000000001"), [0, 1]);
+            let model_id = h.manifest.id;
+
+            status.register_model(Arc::new(LazyVoiceModelMock::<Runtime>::new(h.into())));
+            assert!(status.is_registered_model(model_id));
+            assert!(!status.is_loaded_model(model_id));
+            assert!(status.needs_autoload(StyleId(1)));
+            assert!(!status.needs_autoload(StyleId(2)));
+
+            status.autoload(StyleId(1)).unwrap();
+            assert!(status.is_loaded_model(model_id));
+            assert!(!status.needs_autoload(StyleId(0)));
+
+            // 登録されていないスタイルIDに対しては何もしない
+            status.autoload(StyleId(2)).unwrap();
+            let err = status.ids_for::<TalkDomain>(StyleId(2)).unwrap_err();
+            assert_eq!(crate::ErrorKind::StyleNotFound, err.kind());
+
+            status.unregister_model(model_id).unwrap();
+            assert!(!status.is_registered_model(model_id));
+            assert!(status.is_loaded_model(model_id));
+            let err = status.unregister_model(model_id).unwrap_err();
+            assert_eq!(crate::ErrorKind::ModelNotFound, err.kind());
+        }
+
+        #[rstest]
+        fn unregistered_models_are_not_evicted(status: Status<Runtime>) {
+            let contents = &dummy_contents::<Runtime>();
+            let status = Status {
+                memory_budget: Some(0),
+                ..status
+            };
+            let h1 = &header(uuid!("00000000-0000-4000-a000-000000000001"), [0]);
+            let h2 = header(uuid!("00000000-0000-4000-a000-000000000002"), [1]);
+            let model_id2 = h2.manifest.id;
+
+            status
+                .insert_model(
+                    h1,
+                    contents,
+                    &StyleFilter::default(),
+                    OnExistingVoiceModelId::Error,
+                )
+                .unwrap();
+            status.register_model(Arc::new(LazyVoiceModelMock::<Runtime>::new(h2.into())));
+            status.autoload(StyleId(1)).unwrap();
+
+            assert!(status.is_loaded_model(h1.manifest.id));
+            assert!(status.is_loaded_model(model_id2));
+        }
 
-        status.insert_model(h, &DUMMY_CONTENTS, Error).unwrap();
-        let err = status.insert_model(h, &DUMMY_CONTENTS, Error).unwrap_err();
-        assert_eq!(crate::ErrorKind::ModelAlreadyLoaded, err.kind());
+        #[fixture]
+        fn status() -> Status<Runtime> {
+            status_with_replicas(NonZero::<usize>::MIN)
+        }
     }
 
     #[rstest]
-    fn on_existing_reload_allows_model_id_duplication(status: Status<InferenceRuntimeMock>) {
-        use OnExistingVoiceModelId::Reload;
+    #[case(None, &[true, true, true])]
+    #[case(Some(6), &[true, false, true])]
+    #[case(Some(3), &[false, false, true])]
+    fn least_recently_used_models_are_evicted_over_memory_budget(
+        #[case] memory_budget: Option<u64>,
+        #[case] expected: &[bool],
+    ) {
+        // `InferenceRuntimeMock`用のダミーのモデルのメモリ使用量の概算値は3バイト
+        let status = status_with::<InferenceRuntimeMock>(NonZero::<usize>::MIN, memory_budget);
+        let model_ids = [
+            uuid!("00000000-0000-4000-a000-000000000001"),
+            uuid!("00000000-0000-4000-a000-000000000002"),
+            uuid!("00000000-0000-4000-a000-000000000003"),
+        ]
+        .into_iter()
+        .zip(0..)
+        .map(|(model_id, style_id)| {
+            let h = header(model_id, [style_id]);
+            status.register_model(Arc::new(LazyVoiceModelMock::<InferenceRuntimeMock>::new(
+                h.into(),
+            )));
+            model_id.into()
+        })
+        .collect::<Vec<_>>();
 
-        let h = &header(uuid!("00000000-0000-4000-a000-000000000001"), [0]);
+        status.autoload(StyleId(0)).unwrap();
+        status.autoload(StyleId(1)).unwrap();
+        status.autoload(StyleId(0)).unwrap();
+        status.ids_for::<TalkDomain>(StyleId(0)).unwrap();
+        status.autoload(StyleId(2)).unwrap();
 
-        status.insert_model(h, &DUMMY_CONTENTS, Reload).unwrap();
-        status.insert_model(h, &DUMMY_CONTENTS, Reload).unwrap();
+        assert_eq!(
+            expected,
+            model_ids
+                .iter()
+                .map(|&model_id| status.is_loaded_model(model_id))
+                .collect::<Vec<_>>(),
+        );
     }
 
     #[rstest]
-    fn on_existing_skip_allows_model_id_duplication(status: Status<InferenceRuntimeMock>) {
-        use OnExistingVoiceModelId::Skip;
+    fn pinned_models_are_not_evicted() {
+        // `InferenceRuntimeMock`用のダミーのモデルのメモリ使用量の概算値は3バイト
+        let status = status_with::<InferenceRuntimeMock>(NonZero::<usize>::MIN, Some(3));
+        let model_ids = [
+            uuid!("00000000-0000-4000-a000-000000000001"),
+            uuid!("00000000-0000-4000-a000-000000000002"),
+            uuid!("00000000-0000-4000-a000-000000000003"),
+        ]
+        .into_iter()
+        .zip(0..)
+        .map(|(model_id, style_id)| {
+            let h = header(model_id, [style_id]);
+            status.register_model(Arc::new(LazyVoiceModelMock::<InferenceRuntimeMock>::new(
+                h.into(),
+            )));
+            model_id.into()
+        })
+        .collect::<Vec<_>>();
+        let is_loaded = || {
+            model_ids
+                .iter()
+                .map(|&model_id| status.is_loaded_model(model_id))
+                .collect::<Vec<_>>()
+        };
+
+        let pinned = status.autoload(StyleId(0)).unwrap();
+        status.autoload(StyleId(1)).unwrap();
+        assert_eq!([true, true, false], *is_loaded());
 
-        let h = &header(uuid!("00000000-0000-4000-a000-000000000001"), [0]);
+        drop(pinned);
+        let (pinned, _) = status.ids_for::<TalkDomain>(StyleId(1)).unwrap();
+        status.autoload(StyleId(2)).unwrap();
+        assert_eq!([false, true, true], *is_loaded());
 
-        status.insert_model(h, &DUMMY_CONTENTS, Skip).unwrap();
-        status.insert_model(h, &DUMMY_CONTENTS, Skip).unwrap();
+        // 明示的にアンロードされても、セッションは使える
+        status.unload_model(model_ids[1]).unwrap();
+        pinned.get::<PredictDurationInput>();
     }
 
-    #[fixture]
-    fn status() -> Status<InferenceRuntimeMock> {
+    fn status_with_replicas<R: TestRuntime>(replicas: NonZero<usize>) -> Status<R> {
+        status_with(replicas, None)
+    }
+
+    fn status_with<R: TestRuntime>(
+        replicas: NonZero<usize>,
+        memory_budget: Option<u64>,
+    ) -> Status<R> {
         Status::new(
-            &InferenceRuntimeMock,
-            inference_domain_map!(enum_map!(_ => InferenceSessionOptions::new(0, DeviceSpec::Cpu))),
+            R::instance(),
+            inference_domain_map!(enum_map!(_ => InferenceSessionOptions::new(
+                0,
+                DeviceSpec::Cpu,
+                Default::default(),
+            ))),
+            replicas,
+            memory_budget,
+            None,
         )
     }
 
@@ -687,46 +1295,115 @@ mod tests {
                 order: None,
             }]
             .into(),
-            path: "".into(),
+            signature: None,
+            path: None,
         }
     }
 
-    static DUMMY_CONTENTS: LazyLock<InferenceDomainMap<ModelBytesWithInnerVoiceIdsByDomain>> =
-        LazyLock::new(|| InferenceDomainMap {
-            talk: Some((
-                Default::default(),
-                EnumMap::from_fn(|op: TalkOperation| ModelBytes::Onnx(vec![op.into_usize() as u8])),
-            )),
+    fn dummy_contents<R: TestRuntime>() -> InferenceDomainMap<ModelBytesWithInnerVoiceIdsByDomain> {
+        InferenceDomainMap {
+            talk: Some((Default::default(), EnumMap::from_fn(R::talk_model))),
             streaming_talk: None,
             singing_teacher: None,
             frame_decode: None,
-        });
+        }
+    }
+
+    struct LazyVoiceModelMock<R>(Arc<VoiceModelHeader>, PhantomData<fn() -> R>);
+
+    impl<R> LazyVoiceModelMock<R> {
+        fn new(header: Arc<VoiceModelHeader>) -> Self {
+            Self(header, PhantomData)
+        }
+    }
+
+    impl<R: TestRuntime> LazyVoiceModel for LazyVoiceModelMock<R> {
+        fn header(&self) -> &Arc<VoiceModelHeader> {
+            &self.0
+        }
+
+        fn read_inference_models_blocking(
+            &self,
+            _: Option<&[[u8; 32]]>,
+        ) -> LoadModelResult<InferenceDomainMap<ModelBytesWithInnerVoiceIdsByDomain>> {
+            Ok(dummy_contents::<R>())
+        }
+    }
+
+    /// 以下のテストに用いる推論ランタイム。
+    trait TestRuntime: InferenceRuntime {
+        fn instance() -> &'static Self;
 
+        /// `TalkOperation`に対応するモデル。
+        fn talk_model(op: TalkOperation) -> ModelBytes;
+    }
+
+    impl TestRuntime for InferenceRuntimeMock {
+        fn instance() -> &'static Self {
+            &InferenceRuntimeMock
+        }
+
+        fn talk_model(op: TalkOperation) -> ModelBytes {
+            ModelBytes::Onnx(vec![op.into_usize() as u8])
+        }
+    }
+
+    #[cfg(feature = "tract")]
+    impl TestRuntime for crate::inference_runtime::Tract {
+        fn instance() -> &'static Self {
+            Self::get()
+        }
+
+        fn talk_model(op: TalkOperation) -> ModelBytes {
+            macro_rules! sample_model {
+                ($filename:literal) => {
+                    include_bytes!(concat!(
+                        env!("CARGO_MANIFEST_DIR"),
+                        "/../../model/sample.vvm/",
+                        $filename,
+                    ))
+                };
+            }
+
+            let onnx: &[u8] = match op {
+                TalkOperation::PredictDuration => sample_model!("predict_duration.onnx"),
+                TalkOperation::PredictIntonation => sample_model!("predict_intonation.onnx"),
+                TalkOperation::Decode => sample_model!("decode.onnx"),
+            };
+            ModelBytes::Onnx(onnx.to_owned())
+        }
+    }
+
+    #[derive(Debug)]
     struct InferenceRuntimeMock;
 
     impl InferenceRuntime for InferenceRuntimeMock {
         type Session = ();
         type RunContext = DummyRunContext;
+        type Error = Infallible;
 
         const DISPLAY_NAME: &'static str = "InferenceRuntimeMock";
 
-        fn supported_devices(&self) -> crate::Result<SupportedDevices> {
+        fn supported_devices(&self) -> Result<SupportedDevices, Self::Error> {
             unimplemented!();
         }
 
-        fn test_gpu(&self, _: GpuSpec) -> anyhow::Result<()> {
+        fn test_execution_provider(&self, _: ExecutionProvider) -> Result<(), Self::Error> {
             unimplemented!();
         }
 
         fn new_session(
             &self,
             model: &ModelBytes,
-            _: InferenceSessionOptions,
-        ) -> anyhow::Result<(
-            Self::Session,
-            Vec<ParamInfo<InputScalarKind>>,
-            Vec<ParamInfo<OutputScalarKind>>,
-        )> {
+            _: &InferenceSessionOptions,
+        ) -> Result<
+            (
+                Self::Session,
+                Vec<ParamInfo<InputScalarKind>>,
+                Vec<ParamInfo<OutputScalarKind>>,
+            ),
+            Self::Error,
+        > {
             let ModelBytes::Onnx(model) = model else {
                 unreachable!()
             };
@@ -736,11 +1413,11 @@ mod tests {
             Ok(((), in_infos.to_owned(), out_infos.to_owned()))
         }
 
-        fn run_blocking(_: Self::RunContext) -> anyhow::Result<Vec<OutputTensor>> {
+        fn run_blocking(_: Self::RunContext) -> Result<Vec<OutputTensor>, Self::Error> {
             unimplemented!();
         }
 
-        async fn run_async(_: Self::RunContext, _: bool) -> anyhow::Result<Vec<OutputTensor>> {
+        async fn run_async(_: Self::RunContext, _: bool) -> Result<Vec<OutputTensor>, Self::Error> {
             unimplemented!();
         }
     }
@@ -754,11 +1431,13 @@ mod tests {
     }
 
     impl PushInputTensor for DummyRunContext {
+        type Error = Infallible;
+
         fn push_int64(
             &mut self,
             _: &'static str,
             _: Array<i64, impl Dimension + 'static>,
-        ) -> anyhow::Result<()> {
+        ) -> Result<(), Self::Error> {
             unimplemented!();
         }
 
@@ -766,7 +1445,7 @@ mod tests {
             &mut self,
             _: &'static str,
             _: Array<f32, impl Dimension + 'static>,
-        ) -> anyhow::Result<()> {
+        ) -> Result<(), Self::Error> {
             unimplemented!();
         }
     }
@@ -3,13 +3,16 @@
 //! VVM ファイルの定義と形式は[ドキュメント](../../../docs/guide/dev/vvm.md)を参照。
 
 use std::{
-    collections::HashMap,
+    collections::{BTreeSet, HashMap},
     fmt::{self, Debug},
+    io::{self, SeekFrom},
     path::{Path, PathBuf},
+    pin::Pin,
     sync::Arc,
+    task::{self, Poll},
 };
 
-use anyhow::{Context as _, anyhow};
+use anyhow::{Context as _, anyhow, bail, ensure};
 use derive_more::From;
 use easy_ext::ext;
 use enum_map::{Enum, EnumMap};
@@ -21,9 +24,10 @@ use serde::Deserialize;
 use uuid::Uuid;
 
 use crate::{
-    CharacterMeta, StyleMeta, StyleType, VoiceModelMeta,
+    CharacterMeta, StyleId, StyleMeta, StyleType, VoiceModelMeta,
     asyncs::{Async, Mutex as _},
     error::{LoadModelError, LoadModelErrorKind, LoadModelResult},
+    future::FutureExt as _,
 };
 
 use super::{
@@ -39,6 +43,7 @@ use super::{
         StyleIdToInnerVoiceId,
     },
     metas::{VoiceModelMetaSchemaV1, VoiceModelMetaSchemaV2},
+    status::LazyVoiceModel,
 };
 
 pub(super) type ModelBytesWithInnerVoiceIdsByDomain = inference_domain_map_values!(
@@ -82,24 +87,49 @@ pub(crate) struct Inner<A: Async> {
     #[not_covariant]
     inference_model_entries: InferenceDomainMap<InferenceModelEntries<'this>>,
 
-    zip: A::Mutex<A::RoFile>,
+    zip: A::Mutex<Source<A::RoFile>>,
 }
 
+pub(super) const MANIFEST_FILENAME: &str = "manifest.json";
+
+/// manifest.jsonに対する、Ed25519の分離署名（64バイト）。
+pub(super) const MANIFEST_SIGNATURE_FILENAME: &str = "manifest.json.sig";
+
 impl<A: Async> Inner<A> {
     async fn open(path: impl AsRef<Path>) -> crate::Result<Self> {
-        const MANIFEST_FILENAME: &str = "manifest.json";
-
         let path = path.as_ref();
 
+        let file = A::open_file_ro(path)
+            .await
+            .with_context(|| {
+                // fs-errのと同じにする
+                format!("failed to open file `{}`", path.display())
+            })
+            .map_err(|source| LoadModelError {
+                path: Some(path.to_owned()),
+                context: LoadModelErrorKind::OpenZipFile,
+                source: Some(source),
+            })?;
+
+        Self::from_source(Source::File(file), Some(path)).await
+    }
+
+    async fn from_reader(
+        rdr: impl AsyncRead + AsyncSeek + Send + Sync + Unpin + 'static,
+    ) -> crate::Result<Self> {
+        Self::from_source(Source::Reader(Box::new(rdr)), None).await
+    }
+
+    async fn from_source(source: Source<A::RoFile>, path: Option<&Path>) -> crate::Result<Self> {
         let error = |context, source| LoadModelError {
-            path: path.to_owned(),
+            path: path.map(ToOwned::to_owned),
             context,
             source: Some(source),
         };
 
-        let mut zip = A::open_zip(path)
+        let mut zip = async_zip::base::read::seek::ZipFileReader::with_bufreader(source)
             .await
-            .map_err(|source| error(LoadModelErrorKind::OpenZipFile, source))?;
+            .map_err(|source| error(LoadModelErrorKind::OpenZipFile, source.into()))?;
 
         let indices = zip.entry_indices_by_utf8_filenames();
         let find_entry_index = |filename: &str| {
@@ -109,7 +139,7 @@ impl<A: Async> Inner<A> {
                 .copied()
         };
 
-        let manifest = &async {
+        let manifest_json = async {
             let idx = find_entry_index(MANIFEST_FILENAME)?;
             zip.read_file(idx).await
         }
@@ -122,9 +152,35 @@ impl<A: Async> Inner<A> {
                 source,
             )
         })?;
-        let manifest = serde_json::from_slice::<Manifest>(manifest)
+        let manifest = serde_json::from_slice::<Manifest>(&manifest_json)
             .map_err(|source| error(LoadModelErrorKind::InvalidModelFormat, source.into()))?;
 
+        let signature = OptionFuture::from(
+            indices
+                .get(MANIFEST_SIGNATURE_FILENAME)
+                .map(|&idx| zip.read_file(idx)),
+        )
+        .await
+        .transpose()
+        .map_err(|source| {
+            error(
+                LoadModelErrorKind::ReadZipEntry {
+                    filename: MANIFEST_SIGNATURE_FILENAME.to_owned(),
+                },
+                source,
+            )
+        })?
+        .map(|signature| {
+            let signature = ed25519_dalek::Signature::from_slice(&signature)
+                .with_context(|| format!("{MANIFEST_SIGNATURE_FILENAME}が不正です"))?;
+            Ok(ManifestSignature {
+                manifest_json: manifest_json.into(),
+                signature,
+            })
+        })
+        .transpose()
+        .map_err(|source| error(LoadModelErrorKind::InvalidModelFormat, source))?;
+
         let metas = &async {
             let idx = find_entry_index(manifest.metas_filename())?;
             zip.read_file(idx).await
@@ -138,8 +194,11 @@ impl<A: Async> Inner<A> {
                 source,
             )
         })?;
+        manifest
+            .verify_digest(manifest.metas_filename(), metas)
+            .map_err(|source| error(LoadModelErrorKind::VerifyModel, source))?;
 
-        let header = VoiceModelHeader::new(manifest, metas, path)?.into();
+        let header = VoiceModelHeader::new(manifest, metas, signature, path)?.into();
 
         return InnerTryBuilder {
             header,
@@ -213,17 +272,32 @@ impl<A: Async> Inner<A> {
         self.borrow_header()
     }
 
+    /// `filter`に合うスタイルのために必要な`InferenceDomain`のモデルのみを読む。
+    ///
+    /// 読んだファイルはmanifestに記載されたSHA-256ダイジェストと照合する。`trusted_public_keys`が
+    /// `Some`であれば、manifestの署名も検証する。
     pub(crate) async fn read_inference_models(
         &self,
+        filter: &StyleFilter,
+        trusted_public_keys: Option<&[[u8; 32]]>,
     ) -> LoadModelResult<InferenceDomainMap<ModelBytesWithInnerVoiceIdsByDomain>> {
-        let path = &self.borrow_header().path;
+        let header = self.borrow_header();
+        let path = &header.path;
 
         let error = |context, source| LoadModelError {
-            path: path.to_owned(),
+            path: path.clone(),
             context,
             source: Some(source),
         };
 
+        header.ensure_style_filter_matches(filter)?;
+
+        if let Some(trusted_public_keys) = trusted_public_keys {
+            header
+                .verify_signature(trusted_public_keys)
+                .map_err(|source| error(LoadModelErrorKind::VerifyModel, source))?;
+        }
+
         let zip = &mut *self.borrow_zip().lock().await;
         let mut zip = async_zip::base::read::seek::ZipFileReader::with_bufreader(zip)
             .await
@@ -234,7 +308,7 @@ impl<A: Async> Inner<A> {
                 let (index, ModelFile { r#type, filename }): (usize, ModelFile) = $entry;
                 let bytes = zip
                     .read_file(index)
-                    .map_err(move |source| {
+                    .map_err(|source| {
                         error(
                             LoadModelErrorKind::ReadZipEntry {
                                 filename: (*filename).to_owned(),
@@ -243,6 +317,10 @@ impl<A: Async> Inner<A> {
                         )
                     })
                     .await?;
+                header
+                    .manifest
+                    .verify_digest(&filename, &bytes)
+                    .map_err(|source| error(LoadModelErrorKind::VerifyModel, source))?;
                 ModelBytes::new(r#type, bytes)
             }};
         }
@@ -258,6 +336,14 @@ impl<A: Async> Inner<A> {
                 .map(inference_domain_map!(|e| e.as_ref().map(Into::into)))
         });
 
+        let talk = talk.filter(|_| header.requires_domain::<TalkDomain>(filter));
+        let streaming_talk =
+            streaming_talk.filter(|_| header.requires_domain::<StreamingTalkDomain>(filter));
+        let singing_teacher =
+            singing_teacher.filter(|_| header.requires_domain::<SingingTeacherDomain>(filter));
+        let frame_decode =
+            frame_decode.filter(|_| header.requires_domain::<FrameDecodeDomain>(filter));
+
         let talk = OptionFuture::from(talk.map(async |(entries, style_id_to_inner_voice_id)| {
             let [predict_duration, predict_intonation, decode] = entries.into_array();
 
@@ -346,10 +432,24 @@ impl<A: Async> Inner<A> {
     }
 }
 
+impl<A: Async> LazyVoiceModel for Inner<A> {
+    fn header(&self) -> &Arc<VoiceModelHeader> {
+        self.borrow_header()
+    }
+
+    fn read_inference_models_blocking(
+        &self,
+        trusted_public_keys: Option<&[[u8; 32]]>,
+    ) -> LoadModelResult<InferenceDomainMap<ModelBytesWithInnerVoiceIdsByDomain>> {
+        self.read_inference_models(&StyleFilter::default(), trusted_public_keys)
+            .block_on()
+    }
+}
+
 impl<A: Async> Inner<A> {
     fn fill_debug_struct_body(&self, mut fmt: fmt::DebugStruct<'_, '_>) -> fmt::Result
     where
-        A::Mutex<A::RoFile>: Debug,
+        A::Mutex<Source<A::RoFile>>: Debug,
     {
         fmt.field("header", self.header());
         self.with_inference_model_entries(|inference_model_entries| {
@@ -387,19 +487,44 @@ where
     }
 }
 
-#[ext]
-impl<A: Async> A {
-    async fn open_zip(
-        path: &Path,
-    ) -> anyhow::Result<
-        async_zip::base::read::seek::ZipFileReader<futures_util::io::BufReader<A::RoFile>>,
-    > {
-        let zip = Self::open_file_ro(path).await.with_context(|| {
-            // fs-errのと同じにする
-            format!("failed to open file `{}`", path.display())
-        })?;
-        let zip = async_zip::base::read::seek::ZipFileReader::with_bufreader(zip).await?;
-        Ok(zip)
+/// VVMの読み込み元。
+#[derive(derive_more::Debug)]
+pub(crate) enum Source<F> {
+    #[debug("{_0:?}")]
+    File(F),
+
+    /// メモリ上のバイト列や、ユーザーから与えられたリーダー。
+    #[debug("Reader(_)")]
+    Reader(Box<dyn AsyncReadSeek>),
+}
+
+trait AsyncReadSeek: AsyncRead + AsyncSeek + Send + Sync + Unpin {}
+
+impl<R: AsyncRead + AsyncSeek + Send + Sync + Unpin> AsyncReadSeek for R {}
+
+impl<F: AsyncRead + Unpin> AsyncRead for Source<F> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut task::Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        match self.get_mut() {
+            Self::File(file) => Pin::new(file).poll_read(cx, buf),
+            Self::Reader(rdr) => Pin::new(rdr).poll_read(cx, buf),
+        }
+    }
+}
+
+impl<F: AsyncSeek + Unpin> AsyncSeek for Source<F> {
+    fn poll_seek(
+        self: Pin<&mut Self>,
+        cx: &mut task::Context<'_>,
+        pos: SeekFrom,
+    ) -> Poll<io::Result<u64>> {
+        match self.get_mut() {
+            Self::File(file) => Pin::new(file).poll_seek(cx, pos),
+            Self::Reader(rdr) => Pin::new(rdr).poll_seek(cx, pos),
+        }
     }
 }
 
@@ -416,8 +541,8 @@ impl<R: AsyncRead + AsyncSeek + Unpin>
     }
 }
 
-#[ext]
-impl<R: AsyncBufRead + AsyncSeek + Unpin> async_zip::base::read::seek::ZipFileReader<R> {
+#[ext(ZipFileReaderExt)]
+pub(super) impl<R: AsyncBufRead + AsyncSeek + Unpin> async_zip::base::read::seek::ZipFileReader<R> {
     fn entry_indices_by_utf8_filenames(&self) -> HashMap<String, usize> {
         self.file()
             .entries()
@@ -446,13 +571,21 @@ pub(crate) struct VoiceModelHeader {
     ///
     /// `manifest`が対応していない`StyleType`のスタイルは含まれるべきではない。
     pub(super) metas: VoiceModelMeta,
-    pub(super) path: PathBuf,
+    /// manifest.jsonの署名。署名されていない場合は`None`。
+    pub(super) signature: Option<ManifestSignature>,
+    /// VVMのファイルパス。メモリ上のバイト列やリーダーから開いた場合は`None`。
+    pub(super) path: Option<PathBuf>,
 }
 
 impl VoiceModelHeader {
-    fn new(manifest: Manifest, metas: &[u8], path: &Path) -> LoadModelResult<Self> {
+    fn new(
+        manifest: Manifest,
+        metas: &[u8],
+        signature: Option<ManifestSignature>,
+        path: Option<&Path>,
+    ) -> LoadModelResult<Self> {
         let error = |context, source| LoadModelError {
-            path: path.to_owned(),
+            path: path.map(ToOwned::to_owned),
             context,
             source: Some(source),
         };
@@ -499,13 +632,156 @@ impl VoiceModelHeader {
         Ok(Self {
             manifest,
             metas,
-            path: path.to_owned(),
+            signature,
+            path: path.map(ToOwned::to_owned),
         })
     }
+
+    /// manifestの署名を`trusted_public_keys`のいずれかで検証する。
+    ///
+    /// 署名されたmanifestであっても、ダイジェストが記載されていないファイルは改竄を検出できない。
+    /// そのためmanifestが参照するすべてのファイルについて、ダイジェストの記載も要求する。
+    fn verify_signature(&self, trusted_public_keys: &[[u8; 32]]) -> anyhow::Result<()> {
+        let Some(ManifestSignature {
+            manifest_json,
+            signature,
+        }) = &self.signature
+        else {
+            bail!("署名されていません");
+        };
+
+        let verified = trusted_public_keys
+            .iter()
+            .filter_map(|key| ed25519_dalek::VerifyingKey::from_bytes(key).ok())
+            .any(|key| key.verify_strict(manifest_json, signature).is_ok());
+        ensure!(
+            verified,
+            "信頼された公開鍵のいずれでも署名を検証できませんでした"
+        );
+
+        if let Some(filename) = self
+            .manifest
+            .filenames()
+            .into_iter()
+            .find(|filename| !self.manifest.sha256_digests().contains_key(*filename))
+        {
+            bail!("`{filename}`のSHA-256ダイジェストがmanifestに記載されていません");
+        }
+        Ok(())
+    }
+
+    /// `filter`に合うスタイルのみを残したメタ情報を返す。
+    ///
+    /// スタイルが一つも残らなかったキャラクターは除かれる。`filter`がこのモデルに合わない場合は
+    /// [`ensure_style_filter_matches`]と同様にエラーとなる。
+    ///
+    /// [`ensure_style_filter_matches`]: Self::ensure_style_filter_matches
+    pub(crate) fn filtered_metas(&self, filter: &StyleFilter) -> LoadModelResult<VoiceModelMeta> {
+        self.ensure_style_filter_matches(filter)?;
+        Ok(self
+            .metas
+            .iter()
+            .filter_map(|character| {
+                let styles = character
+                    .styles
+                    .iter()
+                    .filter(|style| filter.accepts(style))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                (!styles.is_empty()).then(|| CharacterMeta {
+                    styles,
+                    ..character.clone()
+                })
+            })
+            .collect())
+    }
+
+    /// `filter`で指定されたスタイルIDがすべてこのモデルに含まれており、かつ`filter`に合うスタイルが
+    /// 一つ以上あることを確かめる。
+    fn ensure_style_filter_matches(&self, filter: &StyleFilter) -> LoadModelResult<()> {
+        let error = |context| LoadModelError {
+            path: self.path.clone(),
+            context,
+            source: None,
+        };
+
+        let styles = || {
+            self.metas
+                .iter()
+                .flat_map(|CharacterMeta { styles, .. }| styles)
+        };
+
+        if let Some(style_ids) = &filter.style_ids
+            && let Some(&id) = style_ids
+                .iter()
+                .find(|&&id| !styles().any(|style| style.id == id))
+        {
+            return Err(error(LoadModelErrorKind::StyleNotInModel { id }));
+        }
+        if !styles().any(|style| filter.accepts(style)) {
+            return Err(error(LoadModelErrorKind::NoStyleMatched));
+        }
+        Ok(())
+    }
+
+    /// `filter`に合うスタイルのために、`D`のモデルを読み込む必要があるかどうか。
+    ///
+    /// `filter`は[`ensure_style_filter_matches`]で確かめられているものとする。
+    ///
+    /// [`ensure_style_filter_matches`]: Self::ensure_style_filter_matches
+    fn requires_domain<D: InferenceDomain>(&self, filter: &StyleFilter) -> bool {
+        filter.is_unrestricted()
+            || self
+                .metas
+                .iter()
+                .flat_map(|CharacterMeta { styles, .. }| styles)
+                .any(|style| filter.accepts(style) && D::style_types().contains(&style.r#type))
+    }
+}
+
+#[derive(derive_more::Debug)]
+pub(crate) struct ManifestSignature {
+    /// 署名の対象となった、manifest.jsonのバイト列。
+    #[debug(ignore)]
+    manifest_json: Box<[u8]>,
+    signature: ed25519_dalek::Signature,
+}
+
+/// 音声モデルのうち、読み込むスタイルの条件。
+///
+/// `Synthesizer::load_voice_model`で指定する。ここから外れたスタイルは読み込まれなかったものとして
+/// 扱われ、そのスタイルのためだけに必要な`InferenceDomain`のモデルも読み込まれない。
+#[derive(Default, Clone, Debug)]
+pub(crate) struct StyleFilter {
+    /// `None`であれば、すべての`StyleType`を受け入れる。
+    pub(crate) style_types: Option<BTreeSet<StyleType>>,
+    /// `None`であれば、すべての`StyleId`を受け入れる。
+    pub(crate) style_ids: Option<BTreeSet<StyleId>>,
+}
+
+impl StyleFilter {
+    fn accepts(&self, style: &StyleMeta) -> bool {
+        self.style_types
+            .as_ref()
+            .is_none_or(|style_types| style_types.contains(&style.r#type))
+            && self
+                .style_ids
+                .as_ref()
+                .is_none_or(|style_ids| style_ids.contains(&style.id))
+    }
+
+    fn is_unrestricted(&self) -> bool {
+        self.style_types.is_none() && self.style_ids.is_none()
+    }
 }
 
-pub(crate) enum ModelBytes {
+/// VVMから読み出された、一つの推論モデルの内容。
+#[non_exhaustive]
+pub enum ModelBytes {
+    /// ONNX形式。
     Onnx(Vec<u8>),
+
+    /// VOICEVOX ONNX Runtime独自の"vv-bin"形式。
     VvBin(Vec<u8>),
 }
 
@@ -516,6 +792,11 @@ impl ModelBytes {
             ModelFileType::VvBin => Self::VvBin,
         })(bytes)
     }
+
+    pub(super) fn len(&self) -> usize {
+        let (Self::Onnx(bytes) | Self::VvBin(bytes)) = self;
+        bytes.len()
+    }
 }
 
 impl InferenceDomainMap<ManifestDomains> {
@@ -571,9 +852,12 @@ impl InferenceDomainMap<ManifestDomains> {
 pub(crate) mod blocking {
     use std::{
         fmt::{self, Debug},
+        io::{Read, Seek},
         path::Path,
     };
 
+    use futures_util::io::AllowStdIo;
+
     use crate::{VoiceModelMeta, asyncs::SingleTasked, future::FutureExt as _};
 
     use super::{Inner, VoiceModelId};
@@ -591,6 +875,37 @@ pub(crate) mod blocking {
             Inner::open(path).block_on().map(Self)
         }
 
+        /// メモリ上のVVMを開く。
+        ///
+        /// 暗号化されたアセットや、Androidの`assets/`など、ファイルシステム上に無いVVMを扱うのに用いる。
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// # fn main() -> anyhow::Result<()> {
+        /// use voicevox_core::blocking::VoiceModelFile;
+        ///
+        /// let bytes = std::fs::read(test_util::SAMPLE_VOICE_MODEL_FILE_PATH)?;
+        /// let model = VoiceModelFile::from_bytes(bytes)?;
+        /// # Ok(())
+        /// # }
+        /// ```
+        #[cfg_attr(doc, doc(alias = "voicevox_voice_model_file_open_from_memory"))]
+        pub fn from_bytes(bytes: impl AsRef<[u8]> + Send + Sync + 'static) -> crate::Result<Self> {
+            Self::from_reader(std::io::Cursor::new(bytes))
+        }
+
+        /// [`Read`]` + `[`Seek`]からVVMを開く。
+        ///
+        /// リーダーは`VoiceModelFile`が閉じられるまで保持され、[`Synthesizer::load_voice_model`]の度に読まれる。
+        ///
+        /// [`Synthesizer::load_voice_model`]: crate::blocking::Synthesizer::load_voice_model
+        pub fn from_reader(rdr: impl Read + Seek + Send + Sync + 'static) -> crate::Result<Self> {
+            Inner::from_reader(AllowStdIo::new(Box::new(rdr)))
+                .block_on()
+                .map(Self)
+        }
+
         /// VVMファイルを閉じる。
         pub fn close(self) -> (VoiceModelId, VoiceModelMeta) {
             let heads = self.0.into_heads();
@@ -601,6 +916,10 @@ pub(crate) mod blocking {
             &self.0
         }
 
+        pub(crate) fn into_inner(self) -> Inner<SingleTasked> {
+            self.0
+        }
+
         /// ID。
         ///
         /// 詳細は[`VoiceModelId`]を参照。
@@ -630,9 +949,11 @@ pub(crate) mod nonblocking {
         path::Path,
     };
 
+    use futures_io::{AsyncRead, AsyncSeek};
+
     use crate::{Result, VoiceModelMeta, asyncs::BlockingThreadPool};
 
-    use super::{Inner, VoiceModelId};
+    use super::{Inner, Source, VoiceModelId};
 
     /// 音声モデルファイル。
     ///
@@ -652,10 +973,32 @@ pub(crate) mod nonblocking {
             Inner::open(path).await.map(Self)
         }
 
+        /// メモリ上のVVMを開く。
+        ///
+        /// 暗号化されたアセットや、Androidの`assets/`など、ファイルシステム上に無いVVMを扱うのに用いる。
+        pub async fn from_bytes(
+            bytes: impl AsRef<[u8]> + Send + Sync + Unpin + 'static,
+        ) -> Result<Self> {
+            Self::from_reader(futures_util::io::Cursor::new(bytes)).await
+        }
+
+        /// [`AsyncRead`]` + `[`AsyncSeek`]からVVMを開く。
+        ///
+        /// リーダーは`VoiceModelFile`が閉じられるまで保持され、[`Synthesizer::load_voice_model`]の度に読まれる。
+        ///
+        /// [`Synthesizer::load_voice_model`]: crate::nonblocking::Synthesizer::load_voice_model
+        pub async fn from_reader(
+            rdr: impl AsyncRead + AsyncSeek + Send + Sync + Unpin + 'static,
+        ) -> Result<Self> {
+            Inner::from_reader(rdr).await.map(Self)
+        }
+
         /// VVMファイルを閉じる。
         pub async fn close(self) -> (VoiceModelId, VoiceModelMeta) {
             let heads = self.0.into_heads();
-            heads.zip.into_inner().close().await;
+            if let Source::File(file) = heads.zip.into_inner() {
+                file.close().await;
+            }
             (*heads.header.manifest.id(), heads.header.metas.clone())
         }
 
@@ -663,6 +1006,10 @@ pub(crate) mod nonblocking {
             &self.0
         }
 
+        pub(crate) fn into_inner(self) -> Inner<BlockingThreadPool> {
+            self.0
+        }
+
         /// ID。
         ///
         /// 詳細は[`VoiceModelId`]を参照。
@@ -689,11 +1036,14 @@ mod tests {
     use rstest::rstest;
     use serde_json::json;
 
-    use crate::{CharacterMeta, StyleType};
+    use crate::{CharacterMeta, StyleId, StyleType, error::ErrorRepr};
 
-    use super::super::{
-        infer::domains::{InferenceDomainMap, inference_domain_map},
-        manifest::ManifestDomains,
+    use super::{
+        super::{
+            infer::domains::{InferenceDomainMap, inference_domain_map},
+            manifest::ManifestDomains,
+        },
+        StyleFilter,
     };
 
     #[rstest]
@@ -726,6 +1076,119 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[rstest]
+    #[tokio::test]
+    async fn from_bytes_works() -> anyhow::Result<()> {
+        let bytes = fs_err::read(::test_util::SAMPLE_VOICE_MODEL_FILE_PATH)?;
+
+        let expected = crate::nonblocking::VoiceModelFile::sample().await?;
+        let actual = crate::nonblocking::VoiceModelFile::from_bytes(bytes).await?;
+
+        assert_eq!(expected.id(), actual.id());
+        assert_eq!(expected.metas(), actual.metas());
+        assert_eq!(None, actual.inner().header().path);
+        Ok(())
+    }
+
+    #[rstest]
+    fn from_reader_works() -> anyhow::Result<()> {
+        let file = fs_err::File::open(::test_util::SAMPLE_VOICE_MODEL_FILE_PATH)?;
+
+        let expected =
+            crate::blocking::VoiceModelFile::open(::test_util::SAMPLE_VOICE_MODEL_FILE_PATH)?;
+        let actual = crate::blocking::VoiceModelFile::from_reader(file)?;
+
+        assert_eq!(expected.id(), actual.id());
+        assert_eq!(expected.metas(), actual.metas());
+        Ok(())
+    }
+
+    #[rstest]
+    fn from_bytes_reports_in_memory_source() {
+        let err = crate::blocking::VoiceModelFile::from_bytes(b"not a zip").unwrap_err();
+        assert_eq!(crate::ErrorKind::OpenZipFile, err.kind());
+        assert!(
+            err.to_string()
+                .starts_with("メモリ上のVVMの読み込みに失敗しました"),
+            "{err}",
+        );
+    }
+
+    #[rstest]
+    #[case(None, None, [true, true, true, true])]
+    #[case(Some(&[StyleType::Talk][..]), None, [true, false, false, false])]
+    #[case(Some(&[StyleType::StreamingTalk][..]), None, [false, true, false, false])]
+    #[case(
+        Some(&[StyleType::SingingTeacher, StyleType::FrameDecode][..]),
+        None,
+        [false, false, true, true]
+    )]
+    #[case(None, Some(&[0][..]), [true, false, false, false])]
+    #[case(None, Some(&[3000][..]), [false, false, false, true])]
+    #[tokio::test]
+    async fn read_inference_models_respects_style_filter(
+        #[case] style_types: Option<&[StyleType]>,
+        #[case] style_ids: Option<&[u32]>,
+        #[case] expected: [bool; 4],
+    ) -> anyhow::Result<()> {
+        let filter = StyleFilter {
+            style_types: style_types.map(|style_types| style_types.iter().copied().collect()),
+            style_ids: style_ids.map(|style_ids| style_ids.iter().copied().map(StyleId).collect()),
+        };
+
+        let model = crate::nonblocking::VoiceModelFile::sample().await?;
+        let InferenceDomainMap {
+            talk,
+            streaming_talk,
+            singing_teacher,
+            frame_decode,
+        } = model.inner().read_inference_models(&filter, None).await?;
+
+        let actual = [
+            talk.is_some(),
+            streaming_talk.is_some(),
+            singing_teacher.is_some(),
+            frame_decode.is_some(),
+        ];
+        assert_eq!(expected, actual);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(None, Some(&[0, 12345][..]), crate::ErrorKind::StyleNotFound, "スタイル`12345`")]
+    #[case(
+        Some(&[StyleType::Talk][..]),
+        Some(&[6000][..]),
+        crate::ErrorKind::StyleNotFound,
+        "条件に合うスタイル"
+    )]
+    #[case(Some(&[][..]), None, crate::ErrorKind::StyleNotFound, "条件に合うスタイル")]
+    #[tokio::test]
+    async fn read_inference_models_rejects_unmatched_style_filter(
+        #[case] style_types: Option<&[StyleType]>,
+        #[case] style_ids: Option<&[u32]>,
+        #[case] expected_kind: crate::ErrorKind,
+        #[case] expected_message: &str,
+    ) -> anyhow::Result<()> {
+        let filter = StyleFilter {
+            style_types: style_types.map(|style_types| style_types.iter().copied().collect()),
+            style_ids: style_ids.map(|style_ids| style_ids.iter().copied().map(StyleId).collect()),
+        };
+
+        let model = crate::nonblocking::VoiceModelFile::sample().await?;
+        let err = crate::Error::from(ErrorRepr::from(
+            model
+                .inner()
+                .read_inference_models(&filter, None)
+                .await
+                .unwrap_err(),
+        ));
+        assert_eq!(expected_kind, err.kind());
+        assert!(err.to_string().contains(expected_message), "{err}");
+        assert!(model.inner().header().filtered_metas(&filter).is_err());
+        Ok(())
+    }
+
     fn character(style_types: &'static [StyleType]) -> CharacterMeta {
         let styles = style_types
             .iter()
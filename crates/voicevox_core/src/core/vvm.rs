@@ -0,0 +1,593 @@
+//! VVMの作成と検査。公開APIは[`crate::vvm`]から再エクスポートされる。
+//!
+//! VVMの形式は[ドキュメント](../../../docs/guide/dev/vvm.md)を参照。
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    num::NonZero,
+    path::Path,
+};
+
+use anyhow::{Context as _, bail, ensure};
+use async_zip::{Compression, ZipEntryBuilder, base::write::ZipFileWriter};
+use ed25519_dalek::{Signer as _, SigningKey};
+use enum_map::enum_map;
+use itertools::Itertools as _;
+use sha2::{Digest as _, Sha256};
+
+use crate::{
+    CharacterMeta, StyleId, StyleMeta, VoiceModelId, asyncs::SingleTasked, future::FutureExt as _,
+};
+
+use super::{
+    devices::DeviceSpec,
+    infer::{InferenceRuntime, InferenceSessionOptions, domains::inference_domain_map},
+    manifest::{FormatVersion, Manifest},
+    status::Status,
+    voice_model::{
+        self, MANIFEST_FILENAME, MANIFEST_SIGNATURE_FILENAME, StyleFilter, ZipFileReaderExt as _,
+    },
+};
+
+/// ディレクトリ内の`manifest.json`と、それが参照するメタ情報とモデルファイルをVVMにまとめる。
+///
+/// `manifest.json`が参照していないファイルは含めない。
+pub fn pack(dir: &Path) -> anyhow::Result<Vec<u8>> {
+    let manifest_json = fs_err::read(dir.join(MANIFEST_FILENAME))?;
+    let manifest = serde_json::from_slice::<Manifest>(&manifest_json)
+        .with_context(|| format!("{MANIFEST_FILENAME}が不正です"))?;
+
+    let entries = [(MANIFEST_FILENAME.to_owned(), manifest_json)]
+        .into_iter()
+        .chain(
+            manifest
+                .filenames()
+                .into_iter()
+                .map(|filename| {
+                    ensure!(
+                        filename != MANIFEST_FILENAME,
+                        "`{filename}`はモデルファイルとしては使えません",
+                    );
+                    let content = fs_err::read(dir.join(filename))?;
+                    Ok((filename.to_owned(), content))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?,
+        );
+    let vvm = write_zip(entries)?;
+
+    // `VoiceModelFile`として開けることを確かめる
+    crate::blocking::VoiceModelFile::from_bytes(vvm.clone())?;
+    Ok(vvm)
+}
+
+/// `vvm_format_version=1`のVVMを`vvm_format_version=2`に変換する。
+///
+/// `vvm_format_version=1`のマニフェストとメタ情報は、そのまま`vvm_format_version=2`のものとして解釈
+/// できる。そのため`manifest.json`の`vvm_format_version`のみを書き換え、他のファイルはそのまま残す。
+///
+/// ただし`manifest.json`の署名は無効になるため、`manifest.json.sig`は取り除く。
+///
+/// 既に`vvm_format_version=2`であれば`None`を返す。
+pub fn upgrade(vvm: &[u8]) -> anyhow::Result<Option<Vec<u8>>> {
+    let entries = read_zip(vvm)?;
+
+    let (manifest_json, manifest) = find_manifest(&entries)?;
+    if matches!(manifest.vvm_format_version(), FormatVersion::V2) {
+        return Ok(None);
+    }
+
+    let mut manifest_json = serde_json::from_slice::<serde_json::Value>(manifest_json)?;
+    manifest_json["vvm_format_version"] = 2.into();
+    let manifest_json = serde_json::to_vec_pretty(&manifest_json)?;
+
+    let vvm = write_zip(
+        entries
+            .into_iter()
+            .filter(|(filename, _)| filename != MANIFEST_SIGNATURE_FILENAME)
+            .map(|(filename, content)| {
+                if filename == MANIFEST_FILENAME {
+                    (filename, manifest_json.clone())
+                } else {
+                    (filename, content)
+                }
+            }),
+    )?;
+
+    crate::blocking::VoiceModelFile::from_bytes(vvm.clone())?;
+    Ok(Some(vvm))
+}
+
+/// VVMにEd25519で署名する。
+///
+/// `manifest.json`が参照するすべてのファイルのSHA-256ダイジェストを`manifest.json`の
+/// `sha256_digests`に書き込んだ上で、その`manifest.json`に対する分離署名を`manifest.json.sig`として
+/// 加える。既に署名されていれば、署名とダイジェストを置き換える。
+///
+/// `signing_key`はEd25519の秘密鍵（シード）。
+pub fn sign(vvm: &[u8], signing_key: &[u8; 32]) -> anyhow::Result<Vec<u8>> {
+    let entries = read_zip(vvm)?;
+
+    let (manifest_json, manifest) = find_manifest(&entries)?;
+    ensure!(
+        matches!(manifest.vvm_format_version(), FormatVersion::V2),
+        "`vvm_format_version=1`のVVMには署名できません。先に`upgrade`してください",
+    );
+
+    let sha256_digests = manifest
+        .filenames()
+        .into_iter()
+        .map(|filename| {
+            let (_, content) = entries
+                .iter()
+                .find(|(f, _)| f == filename)
+                .with_context(|| format!("`{filename}`がありません"))?;
+            Ok((filename, hex::encode(Sha256::digest(content))))
+        })
+        .collect::<anyhow::Result<BTreeMap<_, _>>>()?;
+
+    let mut manifest_json = serde_json::from_slice::<serde_json::Value>(manifest_json)?;
+    manifest_json["sha256_digests"] = serde_json::to_value(sha256_digests)?;
+    let manifest_json = serde_json::to_vec_pretty(&manifest_json)?;
+
+    let signature = SigningKey::from_bytes(signing_key).sign(&manifest_json);
+
+    let vvm = write_zip(
+        entries
+            .iter()
+            .filter(|(filename, _)| filename != MANIFEST_SIGNATURE_FILENAME)
+            .map(|(filename, content)| {
+                if filename == MANIFEST_FILENAME {
+                    (filename.clone(), manifest_json.clone())
+                } else {
+                    (filename.clone(), content.clone())
+                }
+            })
+            .chain([(
+                MANIFEST_SIGNATURE_FILENAME.to_owned(),
+                signature.to_bytes().into(),
+            )]),
+    )?;
+
+    crate::blocking::VoiceModelFile::from_bytes(vvm.clone())?;
+    Ok(vvm)
+}
+
+/// Ed25519の秘密鍵（シード）から、対応する公開鍵を得る。
+///
+/// [`sign`]で署名したVVMを読み込む側には、この公開鍵を信頼された鍵として渡す。
+pub fn public_key(signing_key: &[u8; 32]) -> [u8; 32] {
+    SigningKey::from_bytes(signing_key)
+        .verifying_key()
+        .to_bytes()
+}
+
+/// VVMを検査する。
+///
+/// 次を検査する。
+///
+/// - `vvm_format_version`が最新であるか
+/// - VVM内でスタイルIDが重複していないか
+/// - 与えられたVVMの間でスタイルIDが衝突していないか
+/// - 各モデルファイルの入出力が、推論操作のシグネチャと一致するか（`runtime`が与えられた場合）
+///
+/// VVMとして開けるかどうか自体は、[`VoiceModelFile`]を開く時点で検査される。
+///
+/// [`VoiceModelFile`]: crate::blocking::VoiceModelFile
+pub fn lint<'a, R: InferenceRuntime>(
+    models: impl IntoIterator<Item = &'a crate::blocking::VoiceModelFile>,
+    runtime: Option<&'static R>,
+) -> Vec<Diagnostic> {
+    let models = models
+        .into_iter()
+        .map(|model| model.inner())
+        .collect::<Vec<_>>();
+    let mut diagnostics = vec![];
+
+    for model in &models {
+        let header = model.header();
+        let voice_model_id = header.manifest.id;
+        let mut push = |problem| {
+            diagnostics.push(Diagnostic {
+                voice_model_id,
+                problem,
+            })
+        };
+
+        if matches!(header.manifest.vvm_format_version(), FormatVersion::V1) {
+            push(Problem::OutdatedFormatVersion);
+        }
+
+        for (style_id, count) in style_ids(&header.metas).counts().into_iter().sorted() {
+            if count > 1 {
+                push(Problem::DuplicatedStyleId { style_id });
+            }
+        }
+
+        if let Some(runtime) = runtime
+            && let Err(err) = check_signatures(runtime, model)
+        {
+            push(Problem::InvalidModelData(err));
+        }
+    }
+
+    let mut owners = BTreeMap::<StyleId, VoiceModelId>::new();
+    for model in &models {
+        let header = model.header();
+        let voice_model_id = header.manifest.id;
+        for style_id in style_ids(&header.metas).collect::<BTreeSet<_>>() {
+            match owners.get(&style_id) {
+                Some(&other) => diagnostics.push(Diagnostic {
+                    voice_model_id,
+                    problem: Problem::StyleIdCollision { style_id, other },
+                }),
+                None => {
+                    owners.insert(style_id, voice_model_id);
+                }
+            }
+        }
+    }
+
+    return diagnostics;
+
+    fn style_ids(metas: &[CharacterMeta]) -> impl Iterator<Item = StyleId> + '_ {
+        metas
+            .iter()
+            .flat_map(|CharacterMeta { styles, .. }| styles)
+            .map(|&StyleMeta { id, .. }| id)
+    }
+
+    fn check_signatures<R: InferenceRuntime>(
+        runtime: &'static R,
+        model: &voice_model::Inner<SingleTasked>,
+    ) -> crate::Result<()> {
+        let status = Status::new(
+            runtime,
+            inference_domain_map!(enum_map!(_ => InferenceSessionOptions::new(
+                0,
+                DeviceSpec::Cpu,
+                Default::default(),
+            ))),
+            NonZero::<usize>::MIN,
+            None,
+            None,
+        );
+        let filter = &StyleFilter::default();
+        let contents = model.read_inference_models(filter, None).block_on()?;
+        status.insert_model(model.header(), &contents, filter, Default::default())
+    }
+}
+
+/// [`lint`]で見つかった問題。
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct Diagnostic {
+    /// 問題が見つかったVVMのID。
+    pub voice_model_id: VoiceModelId,
+    /// 問題の内容。
+    pub problem: Problem,
+}
+
+/// [`Diagnostic`]の内容。
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Problem {
+    /// `vvm_format_version`が古い。
+    #[error("`vvm_format_version=1`は古い形式です。`vvm_format_version=2`に変換できます")]
+    OutdatedFormatVersion,
+
+    /// VVM内でスタイルIDが重複している。
+    #[error("スタイルID {}が重複しています", style_id.0)]
+    DuplicatedStyleId { style_id: StyleId },
+
+    /// 他のVVMとスタイルIDが衝突している。
+    #[error("スタイルID {}が音声モデル{other}と衝突しています", style_id.0)]
+    StyleIdCollision {
+        style_id: StyleId,
+        other: VoiceModelId,
+    },
+
+    /// モデルファイルが読み込めないか、入出力が推論操作のシグネチャと一致しない。
+    #[error("モデルファイルが不正です")]
+    InvalidModelData(#[source] crate::Error),
+}
+
+impl Problem {
+    /// 問題の深刻度。
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::OutdatedFormatVersion => Severity::Warning,
+            Self::DuplicatedStyleId { .. }
+            | Self::StyleIdCollision { .. }
+            | Self::InvalidModelData(_) => Severity::Error,
+        }
+    }
+}
+
+/// [`Problem`]の深刻度。
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, derive_more::Display)]
+pub enum Severity {
+    /// 読み込みは可能だが、修正が望ましい。
+    #[display("warning")]
+    Warning,
+    /// 読み込みに失敗するか、他のVVMと併用できない。
+    #[display("error")]
+    Error,
+}
+
+fn find_manifest(entries: &[(String, Vec<u8>)]) -> anyhow::Result<(&[u8], Manifest)> {
+    let (_, manifest_json) = entries
+        .iter()
+        .find(|(filename, _)| filename == MANIFEST_FILENAME)
+        .with_context(|| format!("`{MANIFEST_FILENAME}`がありません"))?;
+    let manifest = serde_json::from_slice::<Manifest>(manifest_json)
+        .with_context(|| format!("{MANIFEST_FILENAME}が不正です"))?;
+    Ok((manifest_json, manifest))
+}
+
+fn read_zip(zip: &[u8]) -> anyhow::Result<Vec<(String, Vec<u8>)>> {
+    async {
+        let mut zip = async_zip::base::read::seek::ZipFileReader::with_bufreader(
+            futures_util::io::Cursor::new(zip),
+        )
+        .await?;
+
+        let filenames = zip
+            .file()
+            .entries()
+            .iter()
+            .map(|entry| {
+                let filename = entry.filename().as_str()?;
+                if filename.ends_with('/') {
+                    bail!("ディレクトリ（`{filename}`）は含められません");
+                }
+                Ok(filename.to_owned())
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let mut entries = Vec::with_capacity(filenames.len());
+        for (index, filename) in filenames.into_iter().enumerate() {
+            let content = zip.read_file(index).await?;
+            entries.push((filename, content));
+        }
+        Ok(entries)
+    }
+    .block_on()
+}
+
+fn write_zip(entries: impl IntoIterator<Item = (String, Vec<u8>)>) -> anyhow::Result<Vec<u8>> {
+    async {
+        let mut zip = ZipFileWriter::new(futures_util::io::Cursor::new(vec![]));
+        for (filename, content) in entries {
+            // ONNXはほとんど圧縮できないため、無圧縮とする
+            let entry = ZipEntryBuilder::new(filename.into(), Compression::Stored);
+            zip.write_entry_whole(entry, &content).await?;
+        }
+        Ok(zip.close().await?.into_inner())
+    }
+    .block_on()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use rstest::rstest;
+
+    use crate::{
+        ErrorKind, StyleId, VoiceModelId, blocking::VoiceModelFile,
+        core::infer::runtimes::DefaultInferenceRuntime, future::FutureExt as _,
+    };
+
+    use super::{
+        MANIFEST_FILENAME, MANIFEST_SIGNATURE_FILENAME, Problem, StyleFilter, read_zip, write_zip,
+    };
+
+    #[rstest]
+    fn pack_restores_unpacked_vvm() -> anyhow::Result<()> {
+        let vvm = &fs_err::read(test_util::SAMPLE_VOICE_MODEL_FILE_PATH)?;
+        let dir = tempfile::tempdir()?;
+        for (filename, content) in read_zip(vvm)? {
+            fs_err::write(dir.path().join(filename), content)?;
+        }
+        fs_err::write(dir.path().join("unreferenced.txt"), "")?;
+
+        let packed = read_zip(&super::pack(dir.path())?)?;
+
+        assert_eq!(
+            read_zip(vvm)?.into_iter().collect::<BTreeSet<_>>(),
+            packed.into_iter().collect::<BTreeSet<_>>(),
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    fn upgrade_rewrites_only_vvm_format_version() -> anyhow::Result<()> {
+        let vvm = &v1_sample_voice_model()?;
+
+        let upgraded = super::upgrade(vvm)?.expect("should be upgraded");
+        assert!(super::upgrade(&upgraded)?.is_none());
+
+        let (before, after) = (read_zip(vvm)?, read_zip(&upgraded)?);
+        assert_eq!(before.len(), after.len());
+        for ((filename1, content1), (filename2, content2)) in itertools::zip_eq(before, after) {
+            assert_eq!(filename1, filename2);
+            if filename1 == MANIFEST_FILENAME {
+                let mut manifest1 = serde_json::from_slice::<serde_json::Value>(&content1)?;
+                let manifest2 = serde_json::from_slice::<serde_json::Value>(&content2)?;
+                manifest1["vvm_format_version"] = 2.into();
+                assert_eq!(manifest1, manifest2);
+            } else {
+                assert_eq!(content1, content2);
+            }
+        }
+        Ok(())
+    }
+
+    #[rstest]
+    fn lint_reports_outdated_format_version_and_style_id_collisions() -> anyhow::Result<()> {
+        let sample = &VoiceModelFile::open(test_util::SAMPLE_VOICE_MODEL_FILE_PATH)?;
+        let v1 = &VoiceModelFile::from_bytes(v1_sample_voice_model()?)?;
+
+        let diagnostics = super::lint([sample, v1], None::<&DefaultInferenceRuntime>);
+
+        assert!(diagnostics.iter().any(|diagnostic| {
+            diagnostic.voice_model_id == v1.id()
+                && matches!(diagnostic.problem, Problem::OutdatedFormatVersion)
+        }));
+
+        let collisions = diagnostics
+            .iter()
+            .filter_map(|diagnostic| match diagnostic.problem {
+                Problem::StyleIdCollision { style_id, other } => {
+                    Some((diagnostic.voice_model_id, style_id, other))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let expected_style_ids = [0, 1, 303, 3000, 6000].map(StyleId);
+        assert_eq!(
+            expected_style_ids
+                .map(|style_id| (v1.id(), style_id, sample.id()))
+                .as_slice(),
+            collisions,
+        );
+        Ok(())
+    }
+
+    #[cfg(onnxruntime)]
+    #[rstest]
+    fn lint_accepts_sample_voice_model_with_onnxruntime() -> anyhow::Result<()> {
+        let sample = &VoiceModelFile::open(test_util::SAMPLE_VOICE_MODEL_FILE_PATH)?;
+        let onnxruntime = crate::blocking::Onnxruntime::from_test_util_data()?;
+
+        let diagnostics = super::lint([sample], Some(onnxruntime));
+
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+        Ok(())
+    }
+
+    #[cfg(feature = "tract")]
+    #[rstest]
+    fn lint_accepts_sample_voice_model_with_tract() -> anyhow::Result<()> {
+        let sample = &VoiceModelFile::open(test_util::SAMPLE_VOICE_MODEL_FILE_PATH)?;
+
+        let diagnostics = super::lint([sample], Some(crate::inference_runtime::Tract::get()));
+
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(None, true)]
+    #[case(Some(SIGNING_KEY), true)]
+    #[case(Some(OTHER_SIGNING_KEY), false)]
+    fn signed_vvm_is_verified_against_trusted_public_keys(
+        #[case] trusted_signing_key: Option<&[u8; 32]>,
+        #[case] expected: bool,
+    ) -> anyhow::Result<()> {
+        let vvm = fs_err::read(test_util::SAMPLE_VOICE_MODEL_FILE_PATH)?;
+        let signed = VoiceModelFile::from_bytes(super::sign(&vvm, SIGNING_KEY)?)?;
+
+        let trusted_public_keys = trusted_signing_key.map(|key| [super::public_key(key)]);
+        let result = signed
+            .inner()
+            .read_inference_models(
+                &StyleFilter::default(),
+                trusted_public_keys.as_ref().map(|k| &k[..]),
+            )
+            .block_on();
+
+        match (expected, result) {
+            (true, Ok(_)) => {}
+            (false, Err(err)) => {
+                assert_eq!(ErrorKind::VerifyModel, crate::Error::from(err).kind());
+            }
+            (_, result) => panic!("unexpected result: {:?}", result.map(|_| ())),
+        }
+        Ok(())
+    }
+
+    #[rstest]
+    fn unsigned_vvm_is_rejected_when_trusted_public_keys_are_given() -> anyhow::Result<()> {
+        let sample = VoiceModelFile::open(test_util::SAMPLE_VOICE_MODEL_FILE_PATH)?;
+
+        let err = sample
+            .inner()
+            .read_inference_models(
+                &StyleFilter::default(),
+                Some(&[super::public_key(SIGNING_KEY)][..]),
+            )
+            .block_on()
+            .map(|_| ())
+            .unwrap_err();
+
+        assert_eq!(ErrorKind::VerifyModel, crate::Error::from(err).kind());
+        Ok(())
+    }
+
+    #[rstest]
+    fn tampered_model_files_are_detected_by_sha256_digests() -> anyhow::Result<()> {
+        let vvm = fs_err::read(test_util::SAMPLE_VOICE_MODEL_FILE_PATH)?;
+        let signed = read_zip(&super::sign(&vvm, SIGNING_KEY)?)?;
+
+        let tamper = |target: fn(&str) -> bool| {
+            write_zip(signed.iter().cloned().map(|(filename, mut content)| {
+                if target(&filename) {
+                    content.push(0);
+                }
+                (filename, content)
+            }))
+        };
+
+        let err =
+            VoiceModelFile::from_bytes(tamper(|filename| filename == "metas.json")?).unwrap_err();
+        assert_eq!(ErrorKind::VerifyModel, err.kind());
+
+        let model = VoiceModelFile::from_bytes(tamper(|filename| {
+            ![MANIFEST_FILENAME, MANIFEST_SIGNATURE_FILENAME, "metas.json"].contains(&filename)
+        })?)?;
+        let err = model
+            .inner()
+            .read_inference_models(&StyleFilter::default(), None)
+            .block_on()
+            .map(|_| ())
+            .unwrap_err();
+        assert_eq!(ErrorKind::VerifyModel, crate::Error::from(err).kind());
+        Ok(())
+    }
+
+    const SIGNING_KEY: &[u8; 32] = &[1; 32];
+    const OTHER_SIGNING_KEY: &[u8; 32] = &[2; 32];
+
+    /// サンプルのVVMを、IDを変えて`vvm_format_version=1`にしたもの。
+    ///
+    /// `streaming_talk`は`vvm_format_version=1`には無いため、マニフェストとメタ情報から取り除く。
+    fn v1_sample_voice_model() -> anyhow::Result<Vec<u8>> {
+        let vvm = fs_err::read(test_util::SAMPLE_VOICE_MODEL_FILE_PATH)?;
+        let entries = read_zip(&vvm)?
+            .into_iter()
+            .map(|(filename, content)| {
+                let mut json = match &*filename {
+                    MANIFEST_FILENAME | "metas.json" => {
+                        serde_json::from_slice::<serde_json::Value>(&content)?
+                    }
+                    _ => return Ok((filename, content)),
+                };
+                if filename == MANIFEST_FILENAME {
+                    json["vvm_format_version"] = 1.into();
+                    json["id"] = VoiceModelId(uuid::Uuid::nil()).to_string().into();
+                    json.as_object_mut().unwrap().shift_remove("streaming_talk");
+                } else {
+                    for character in json.as_array_mut().unwrap() {
+                        character["styles"]
+                            .as_array_mut()
+                            .unwrap()
+                            .retain(|style| style["type"] != "streaming_talk");
+                    }
+                }
+                Ok((filename, serde_json::to_vec(&json)?))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        write_zip(entries)
+    }
+}
@@ -5,6 +5,13 @@ use crate::{FrameAudioQuery, SamplingRate};
 use super::{DEFAULT_SAMPLING_RATE, talk::ValidatedAudioQuery};
 
 pub(crate) fn to_s16le_pcm(wave: &[f32], query: &impl HasPcmOptions) -> Vec<u8> {
+    s16le_from_f32_pcm(&to_f32_pcm(wave, query))
+}
+
+/// 音声波形に音量・サンプリングレート・ステレオの設定を適用し、`[-1.0, 1.0]`の範囲のPCMにする。
+///
+/// ステレオの場合はインターリーブされたものになる。
+pub(crate) fn to_f32_pcm(wave: &[f32], query: &impl HasPcmOptions) -> Vec<f32> {
     let PcmOptions {
         volume_scale,
         output_sampling_rate,
@@ -13,15 +20,38 @@ pub(crate) fn to_s16le_pcm(wave: &[f32], query: &impl HasPcmOptions) -> Vec<u8>
     let num_channels: u16 = if output_stereo { 2 } else { 1 };
     let repeat_count: u32 =
         (output_sampling_rate.get().get() / DEFAULT_SAMPLING_RATE) * num_channels as u32;
-    let bytes_size = wave.len() as u32 * repeat_count * 2;
-    let buf: Vec<u8> = Vec::with_capacity(bytes_size as usize);
-    let mut cur = Cursor::new(buf);
+    let mut pcm = Vec::with_capacity(wave.len() * repeat_count as usize);
 
     for value in wave {
         let v = (value * volume_scale).clamp(-1., 1.);
-        let data = (v * 0x7fff as f32) as i16;
+        pcm.extend(std::iter::repeat_n(v, repeat_count as usize));
+    }
+
+    pcm
+}
+
+/// [`to_f32_pcm`]で得たPCMを、16bit PCMに変換する。
+pub(crate) fn s16le_from_f32_pcm(pcm: &[f32]) -> Vec<u8> {
+    pcm.iter()
+        .flat_map(|v| ((v * 0x7fff as f32) as i16).to_le_bytes())
+        .collect()
+}
+
+/// 24000Hzのステレオ音声を、インターリーブされた16bit PCMに変換する。
+pub(crate) fn stereo_to_s16le_pcm(
+    frames: &[[f32; 2]],
+    output_sampling_rate: SamplingRate,
+) -> Vec<u8> {
+    let repeat_count = output_sampling_rate.get().get() / DEFAULT_SAMPLING_RATE;
+    let bytes_size = frames.len() as u32 * repeat_count * 2 * 2;
+    let buf: Vec<u8> = Vec::with_capacity(bytes_size as usize);
+    let mut cur = Cursor::new(buf);
+
+    for frame in frames {
+        let [left, right] = frame.map(|v| (v.clamp(-1., 1.) * 0x7fff as f32) as i16);
         for _ in 0..repeat_count {
-            cur.write_all(&data.to_le_bytes()).unwrap();
+            cur.write_all(&left.to_le_bytes()).unwrap();
+            cur.write_all(&right.to_le_bytes()).unwrap();
         }
     }
 
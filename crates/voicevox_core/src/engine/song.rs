@@ -1,3 +1,47 @@
 pub(crate) mod interpret;
+pub(crate) mod mix;
 pub(crate) mod queries;
+pub(crate) mod transform;
 pub(crate) mod validate;
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use pretty_assertions::assert_eq;
+
+    /// `song-public-apis.txt`が、`macros::song_public_apis!`が列挙する公開APIの一覧と一致することを確かめる。
+    ///
+    /// `$UPDATE_SONG_PUBLIC_APIS`が設定されているときは、`song-public-apis.txt`を書き換える。
+    #[test]
+    fn song_public_apis_are_up_to_date() {
+        const PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/song-public-apis.txt");
+        const HEADER: &str = "\
+# 歌唱音声合成に関する公開API。
+#
+# `macros::song_public_apis!`によってソースコードから生成される。手で編集せず、次のコマンドで更新すること。
+#
+#     UPDATE_SONG_PUBLIC_APIS=1 cargo test -p voicevox_core song_public_apis
+#
+# 現在この一覧と突き合わせてテストされているのは、次の言語のAPIのみである。
+#
+# - voicevox_core_java_api: lib/src/test/java/jp/hiroshiba/voicevoxcore/SongApiParityTest.java
+
+";
+
+        let expected = macros::song_public_apis!()
+            .into_iter()
+            .map(|api| format!("{api}\n"))
+            .fold(HEADER.to_owned(), |acc, line| acc + &line);
+
+        if env::var_os("UPDATE_SONG_PUBLIC_APIS").is_some() {
+            fs::write(PATH, &expected).unwrap();
+            return;
+        }
+        assert_eq!(
+            expected,
+            fs::read_to_string(PATH).unwrap(),
+            "`song-public-apis.txt`が古い。`UPDATE_SONG_PUBLIC_APIS=1`で更新すること",
+        );
+    }
+}
@@ -1,11 +1,15 @@
-use std::iter;
+use std::{iter, ops::Range, ptr};
 
 use arrayvec::ArrayVec;
+use itertools::Itertools as _;
 use ndarray::Array1;
 use typeshare::U53;
 
 use crate::{
-    FrameAudioQuery, FramePhoneme, NoteId, collections::NonEmptySlice, numerics::U53Ext as _,
+    Dynamics, FrameAudioQuery, FramePhoneme, NoteId,
+    collections::NonEmptySlice,
+    error::{InvalidQueryError, InvalidQueryErrorSource},
+    numerics::U53Ext as _,
 };
 
 use super::{
@@ -13,9 +17,19 @@ use super::{
         acoustic_feature_extractor::{OptionalConsonant, PhonemeCode},
         ndarray::IteratorExt as _,
     },
-    validate::{Lyric, PauOrKeyAndLyric, ValidatedNote, note_seq::ValidatedNoteSeq},
+    validate::{
+        BREATH_PHONEME, Lyric, PauOrKeyAndLyric, ValidatedNote, note_seq::ValidatedNoteSeq,
+    },
 };
 
+/// 部分的な歌唱音声合成の際、区間の前後に文脈として余分に推論するフレーム数。
+///
+/// `sf_decode`の受容野はトーク用のデコーダとは異なり明らかでないため、トーク用の`MARGIN`より広めにとる。
+pub(crate) const SF_DECODE_MARGIN: usize = 32;
+
+/// ブレスの区間における音量のエンベロープの最大値。
+const BREATH_PEAK_VOLUME: f32 = 0.08;
+
 /// 子音長と音符長から音素長を計算する。
 ///
 /// 子音はノートの頭にくるようにするため、予測された子音長は前のノートの長さを超えないように調整される。
@@ -101,10 +115,57 @@ pub(crate) fn repeat_phoneme_code_and_key(
     iter::repeat_n((phoneme, key), n)
 }
 
+pub(crate) fn repeat_note<'a>(
+    FramePhoneme { frame_length, .. }: &FramePhoneme,
+    note: &'a ValidatedNote,
+) -> impl Iterator<Item = &'a ValidatedNote> {
+    iter::repeat_n(note, typeshare::usize_from_u53_saturated(*frame_length))
+}
+
+/// 推論された音量に、音符の強弱とブレスを反映する。
+///
+/// ブレスの区間では、息を吸って吐くように立ち上がって減衰するエンベロープ（最大値は[`BREATH_PEAK_VOLUME`]）
+/// を推論された音量と合成する。その上で、強弱に応じた倍率を掛ける。
+pub(crate) fn adjust_volumes<'a>(
+    volumes: &mut [f32],
+    notes_by_frame: impl IntoIterator<Item = &'a ValidatedNote>,
+) {
+    let frames = iter::zip(volumes, notes_by_frame).chunk_by(|&(_, note)| ptr::from_ref(note));
+
+    for (_, frames) in &frames {
+        let frames = frames.collect::<Vec<_>>();
+        let n = frames.len();
+
+        for (
+            i,
+            (
+                volume,
+                ValidatedNote {
+                    pau_or_key_and_lyric,
+                    dynamics,
+                    ..
+                },
+            ),
+        ) in frames.into_iter().enumerate()
+        {
+            if *pau_or_key_and_lyric == PauOrKeyAndLyric::Breath {
+                *volume = volume.max(breath_envelope(i, n));
+            }
+            *volume *= dynamics.map_or(1., Dynamics::volume_scale);
+        }
+    }
+}
+
+/// `n`フレームのブレスにおける、`i`フレーム目のエンベロープ。正弦波の半周期の形をとる。
+fn breath_envelope(i: usize, n: usize) -> f32 {
+    let t = (i as f32 + 0.5) / n as f32;
+    BREATH_PEAK_VOLUME * (std::f32::consts::PI * t).sin()
+}
+
 impl PauOrKeyAndLyric {
     fn key(&self) -> i64 {
         match *self {
-            Self::Pau => -1,
+            Self::Pau | Self::Breath => -1,
             Self::KeyAndLyric { key, .. } => key.into(),
         }
     }
@@ -138,7 +199,7 @@ impl From<&'_ ValidatedNoteSeq> for ConsonantLengthsFeature {
             }: &ValidatedNote,
         ) -> (i64, i64, i64) {
             match *pau_or_key_and_lyric {
-                PauOrKeyAndLyric::Pau => (
+                PauOrKeyAndLyric::Pau | PauOrKeyAndLyric::Breath => (
                     frame_length.to_i64(),
                     OptionalConsonant::None as _,
                     PhonemeCode::MorablePau as _,
@@ -180,6 +241,13 @@ impl From<&'_ ValidatedNoteSeq> for Vec<PhonemeFeature> {
                 .into_iter()
                 .collect(),
 
+                PauOrKeyAndLyric::Breath => [PhonemeFeature {
+                    phoneme: BREATH_PHONEME,
+                    note_id: id.clone(),
+                }]
+                .into_iter()
+                .collect(),
+
                 // TODO: Rust 1.91以降なら`std::iter::chain`がある
                 PauOrKeyAndLyric::KeyAndLyric {
                     lyric:
@@ -246,11 +314,61 @@ impl From<&'_ FrameAudioQuery> for SfDecoderFeature {
     }
 }
 
+impl SfDecoderFeature {
+    /// 指定したフレーム区間に対応する特徴量を、両端に最大`margin`フレームのマージンを追加した上で切り出す。
+    ///
+    /// マージンは特徴量の両端を越えない範囲で追加される。実際に追加された前後のマージン幅も返す。
+    pub(crate) fn crop_with_margin(
+        &self,
+        range: Range<usize>,
+        margin: usize,
+    ) -> Result<(Self, [usize; 2]), InvalidQueryError> {
+        let Self {
+            frame_phonemes,
+            f0s,
+            volumes,
+        } = self;
+
+        let frame_length = [frame_phonemes.len(), f0s.len(), volumes.len()]
+            .into_iter()
+            .min()
+            .expect("should not be empty");
+
+        if range.start > range.end || range.end > frame_length {
+            return Err(InvalidQueryError {
+                what: "フレーム区間",
+                value: Some(Box::new(range)),
+                source: Some(InvalidQueryErrorSource::OutOfRangeFrames),
+            });
+        }
+
+        let start = range.start.saturating_sub(margin);
+        let end = (range.end + margin).min(frame_length);
+        let slice = ndarray::s![start..end];
+
+        let cropped = Self {
+            frame_phonemes: frame_phonemes.slice(slice).to_owned(),
+            f0s: f0s.slice(slice).to_owned(),
+            volumes: volumes.slice(slice).to_owned(),
+        };
+        Ok((cropped, [range.start - start, end - range.end]))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
 
-    use crate::collections::NonEmptySlice;
+    use crate::{
+        Dynamics,
+        collections::NonEmptySlice,
+        error::{InvalidQueryError, InvalidQueryErrorSource},
+    };
+
+    use super::{
+        super::validate::{PauOrKeyAndLyric, ValidatedNote},
+        SfDecoderFeature,
+    };
 
     #[rstest]
     #[case(&[0, 0, 0], &[0, 0, 0], &[0, 0, 0])]
@@ -277,6 +395,104 @@ mod tests {
         phoneme_lengths(&[1], &[0]);
     }
 
+    #[rstest]
+    #[case(2..4, 2, &[0, 1, 2, 3, 4, 5], [2, 2])]
+    #[case(1..4, 2, &[0, 1, 2, 3, 4, 5], [1, 2])]
+    #[case(2..5, 2, &[0, 1, 2, 3, 4, 5], [2, 1])]
+    #[case(0..6, 2, &[0, 1, 2, 3, 4, 5], [0, 0])]
+    #[case(3..3, 1, &[2, 3, 4], [1, 1])]
+    fn crop_with_margin_works(
+        #[case] range: std::ops::Range<usize>,
+        #[case] margin: usize,
+        #[case] expected_frames: &[i64],
+        #[case] expected_margins: [usize; 2],
+    ) {
+        let (cropped, margins) = feature(6).crop_with_margin(range, margin).unwrap();
+        let expected_values = expected_frames
+            .iter()
+            .map(|&i| i as f32)
+            .collect::<Vec<_>>();
+        assert_eq!(expected_frames, cropped.frame_phonemes.to_vec());
+        assert_eq!(expected_values, cropped.f0s.to_vec());
+        assert_eq!(expected_values, cropped.volumes.to_vec());
+        assert_eq!(expected_margins, margins);
+    }
+
+    #[rstest]
+    #[case(0..7)]
+    #[case(4..3)]
+    fn crop_with_margin_fails_for_out_of_range(#[case] range: std::ops::Range<usize>) {
+        // TODO: MSRVが1.96.0になったら`assert_matches!`を使う
+        assert!(matches!(
+            feature(6).crop_with_margin(range, 2),
+            Err(InvalidQueryError {
+                source: Some(InvalidQueryErrorSource::OutOfRangeFrames),
+                ..
+            }),
+        ));
+    }
+
+    #[test]
+    fn adjust_volumes_works() {
+        let mut volumes: [f32; 4] = [0., 0.5, 0., 0.5];
+        super::adjust_volumes(
+            &mut volumes,
+            &[
+                note(PauOrKeyAndLyric::Pau, None),
+                note(PauOrKeyAndLyric::Pau, Some(Dynamics::P)),
+                note(PauOrKeyAndLyric::Breath, None),
+                note(PauOrKeyAndLyric::Breath, Some(Dynamics::Ff)),
+            ],
+        );
+        assert_eq!(
+            [
+                0.,
+                0.5 * Dynamics::P.volume_scale(),
+                super::BREATH_PEAK_VOLUME,
+                0.5 * Dynamics::Ff.volume_scale(),
+            ],
+            volumes,
+        );
+
+        fn note(
+            pau_or_key_and_lyric: PauOrKeyAndLyric,
+            dynamics: Option<Dynamics>,
+        ) -> ValidatedNote {
+            ValidatedNote {
+                id: None,
+                pau_or_key_and_lyric,
+                frame_length: 1u8.into(),
+                dynamics,
+            }
+        }
+    }
+
+    #[test]
+    fn adjust_volumes_shapes_breaths() {
+        let breath = &ValidatedNote {
+            id: None,
+            pau_or_key_and_lyric: PauOrKeyAndLyric::Breath,
+            frame_length: 5u8.into(),
+            dynamics: None,
+        };
+        let mut volumes = [0.; 5];
+        super::adjust_volumes(&mut volumes, [breath; 5]);
+
+        assert!((super::BREATH_PEAK_VOLUME - volumes[2]).abs() < 1e-6);
+        assert!(volumes.iter().all(|&v| 0. < v && v <= volumes[2]));
+        assert!(volumes[..3].is_sorted());
+        assert!(volumes[2..].is_sorted_by(|a, b| a >= b));
+        assert!((volumes[0] - volumes[4]).abs() < 1e-6);
+    }
+
+    fn feature(frame_length: usize) -> SfDecoderFeature {
+        SfDecoderFeature {
+            frame_phonemes: (0..frame_length as i64).collect(),
+            f0s: (0..frame_length).map(|i| i as f32).collect(),
+            volumes: (0..frame_length).map(|i| i as f32).collect(),
+        }
+    }
+
     fn phoneme_lengths(consonant_lengths: &[i64], note_durations: &[u32]) -> Vec<u64> {
         let consonant_lengths = NonEmptySlice::new(consonant_lengths).unwrap();
         let note_durations = &note_durations
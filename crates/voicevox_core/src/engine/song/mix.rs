@@ -0,0 +1,187 @@
+use serde::{Deserialize, Serialize};
+use typed_floats::{NonNaNFinite, PositiveFinite};
+
+use crate::{
+    StyleId,
+    error::{InvalidQueryError, InvalidQueryErrorSource},
+    numerics::{non_nan_finite_f32, positive_finite_f32},
+};
+
+use super::queries::Score;
+
+/// 複数トラックの歌唱音声合成における、一つのトラック。
+///
+/// # Example
+///
+/// ```
+/// # use voicevox_core::{Score, SongTrack, StyleId};
+/// #
+/// # let score = serde_json::from_str::<Score>(r#"{ "notes": [] }"#).unwrap();
+/// let mut track = SongTrack::new(score, StyleId(6000), StyleId(3000));
+/// track.gain = 0.8_f32.try_into().unwrap();
+/// track.pan = (-0.5_f32).try_into().unwrap();
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct SongTrack {
+    /// 楽譜。
+    pub score: Score,
+
+    /// [`score`]から[`FrameAudioQuery`]を生成するためのスタイル。
+    ///
+    /// [`score`]: Self::score
+    /// [`FrameAudioQuery`]: crate::FrameAudioQuery
+    pub singing_teacher: StyleId,
+
+    /// 歌唱音声合成に用いるスタイル。
+    pub singer: StyleId,
+
+    /// 音量の倍率。
+    pub gain: PositiveFinite<f32>,
+
+    /// 定位。`-1`が左、`0`が中央、`1`が右。
+    ///
+    /// `0`以外の場合、反対側のチャンネルの音量が`1 - |pan|`倍される。
+    pub pan: NonNaNFinite<f32>,
+}
+
+impl SongTrack {
+    /// `SongTrack`をコンストラクトする。[`gain`]は`1`、[`pan`]は`0`となる。
+    ///
+    /// [`gain`]: Self::gain
+    /// [`pan`]: Self::pan
+    pub fn new(score: Score, singing_teacher: StyleId, singer: StyleId) -> Self {
+        Self {
+            score,
+            singing_teacher,
+            singer,
+            gain: positive_finite_f32!(1.),
+            pan: non_nan_finite_f32!(0.),
+        }
+    }
+
+    /// 左右それぞれのチャンネルに対する倍率。
+    pub(crate) fn channel_gains(&self) -> Result<[f32; 2], InvalidQueryError> {
+        let gain = f32::from(self.gain);
+        let pan = f32::from(self.pan);
+
+        if !(-1. ..=1.).contains(&pan) {
+            return Err(InvalidQueryError {
+                what: "パン",
+                value: Some(Box::new(pan)),
+                source: Some(InvalidQueryErrorSource::OutOfRangePan),
+            });
+        }
+
+        Ok([gain * (1. - pan).min(1.), gain * (1. + pan).min(1.)])
+    }
+}
+
+/// ミックスした結果の振幅の上限。0dBFSに対して約1dBのヘッドルームを設ける。
+const OUTPUT_CEILING: f32 = 0.89;
+
+/// 24000Hzのモノラル音声を、先頭を揃えてステレオに重ね合わせる。
+///
+/// 重ね合わせた結果のピークが[`OUTPUT_CEILING`]を超える場合、波形が歪まないよう全体の音量を一律に下げる。
+#[derive(Default)]
+pub(crate) struct StereoMixer {
+    frames: Vec<[f32; 2]>,
+}
+
+impl StereoMixer {
+    pub(crate) fn add(&mut self, wave: &[f32], [left, right]: [f32; 2]) {
+        if self.frames.len() < wave.len() {
+            self.frames.resize(wave.len(), [0., 0.]);
+        }
+        for ([l, r], sample) in itertools::zip(&mut self.frames, wave) {
+            *l += sample * left;
+            *r += sample * right;
+        }
+    }
+
+    pub(crate) fn into_frames(mut self) -> Vec<[f32; 2]> {
+        let peak = self
+            .frames
+            .iter()
+            .flatten()
+            .fold(0., |peak: f32, sample| peak.max(sample.abs()));
+        if peak > OUTPUT_CEILING {
+            let gain = OUTPUT_CEILING / peak;
+            for sample in self.frames.iter_mut().flatten() {
+                *sample *= gain;
+            }
+        }
+        self.frames
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        Score, StyleId,
+        error::{InvalidQueryError, InvalidQueryErrorSource},
+    };
+
+    use super::{SongTrack, StereoMixer};
+
+    #[rstest]
+    #[case([1., 1.], 1., 0.)]
+    #[case([1., 0.], 1., -1.)]
+    #[case([0.5, 1.], 1., 0.5)]
+    #[case([0.25, 0.5], 0.5, 0.5)]
+    fn channel_gains_works(#[case] expected: [f32; 2], #[case] gain: f32, #[case] pan: f32) {
+        let track = SongTrack {
+            gain: gain.try_into().unwrap(),
+            pan: pan.try_into().unwrap(),
+            ..track()
+        };
+        assert_eq!(expected, track.channel_gains().unwrap());
+    }
+
+    #[test]
+    fn channel_gains_fails_for_out_of_range_pan() {
+        let track = SongTrack {
+            pan: 1.5_f32.try_into().unwrap(),
+            ..track()
+        };
+        // TODO: MSRVが1.96.0になったら`assert_matches!`を使う
+        assert!(matches!(
+            track.channel_gains(),
+            Err(InvalidQueryError {
+                source: Some(InvalidQueryErrorSource::OutOfRangePan),
+                ..
+            }),
+        ));
+    }
+
+    #[test]
+    fn stereo_mixer_works() {
+        let mut mixer = StereoMixer::default();
+        mixer.add(&[0.5, 0.5], [1., 0.5]);
+        mixer.add(&[0.25, 0.25, 0.25], [0., 1.]);
+        assert_eq!([[0.5, 0.5], [0.5, 0.5], [0., 0.25]], mixer.into_frames(),);
+    }
+
+    #[test]
+    fn stereo_mixer_limits_peaks() {
+        let mut mixer = StereoMixer::default();
+        mixer.add(&[0.8, 0.4], [1., 1.]);
+        mixer.add(&[0.8, 0.4], [1., 0.]);
+
+        let frames = mixer.into_frames();
+        let [[l0, r0], [l1, r1]] = frames[..] else {
+            panic!("should be two frames");
+        };
+        assert!((super::OUTPUT_CEILING - l0).abs() < 1e-6);
+        // 音量は一律に下げられるため、各サンプルの比は保たれる
+        assert!((l0 / r0 - 2.).abs() < 1e-6);
+        assert!((l0 / l1 - 2.).abs() < 1e-6);
+        assert!((l1 / r1 - 2.).abs() < 1e-6);
+    }
+
+    fn track() -> SongTrack {
+        SongTrack::new(Score { notes: vec![] }, StyleId(0), StyleId(0))
+    }
+}
@@ -23,6 +23,7 @@ use crate::{
 
 use super::super::Phoneme;
 
+pub(super) use self::optional_lyric::LyricMora;
 pub use self::{key::Key, optional_lyric::OptionalLyric};
 
 /// 楽譜情報。
@@ -64,6 +65,7 @@ pub use self::{key::Key, optional_lyric::OptionalLyric};
 ///
 /// [`ErrorKind::InvalidQuery`]: crate::ErrorKind::InvalidQuery
 /// [`validate`メソッド]: Self::validate
+#[cfg_attr(doc, doc(alias = "VoicevoxScore"))]
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Score {
@@ -104,6 +106,16 @@ impl From<&'_ Score> for serde_json::Value {
 ///
 /// let rest = serde_json::from_str::<Note>(r#"{ "key": null, "frame_length": 45, "lyric": "" }"#)
 ///     .unwrap();
+///
+/// let forte = serde_json::from_str::<Note>(
+///     r#"{ "key": 65, "frame_length": 45, "lyric": "ファ", "dynamics": "f" }"#,
+/// )
+/// .unwrap();
+///
+/// let breath = serde_json::from_str::<Note>(
+///     r#"{ "key": null, "frame_length": 15, "lyric": "", "breath": true }"#,
+/// )
+/// .unwrap();
 /// ```
 ///
 /// [`ErrorKind::InvalidQuery`]: crate::ErrorKind::InvalidQuery
@@ -141,6 +153,23 @@ pub struct Note {
     ///
     /// [子音の侵食]: https://github.com/VOICEVOX/voicevox_core/blob/main/docs/guide/user/song.md#子音の侵食
     pub frame_length: U53,
+
+    /// 強弱。
+    ///
+    /// `Some(_)`の場合、この音符の区間の音量が[`Dynamics::volume_scale`]倍される。`None`は[`Dynamics::Mf`]と同じ。
+    pub dynamics: Option<Dynamics>,
+
+    /// ブレスであるかどうか。
+    ///
+    /// `true`の場合、休符の区間を無音ではなく息継ぎの音にする。休符でのみ`true`にできる。
+    ///
+    /// # Serde
+    ///
+    /// [Serde]においては省略可能であり、省略時は`false`となる。
+    ///
+    /// [Serde]: serde
+    #[serde(default)]
+    pub breath: bool,
 }
 
 impl From<&'_ Note> for serde_json::Value {
@@ -149,6 +178,61 @@ impl From<&'_ Note> for serde_json::Value {
     }
 }
 
+/// 音符の強弱。
+///
+/// # Serde
+///
+/// [Serde]においては各バリアント名はlowercaseとなる。
+///
+/// [Serde]: serde
+#[derive(
+    Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, strum::Display, Deserialize, Serialize,
+)]
+#[strum(serialize_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum Dynamics {
+    /// ピアニッシモ。
+    Pp,
+
+    /// ピアノ。
+    P,
+
+    /// メゾピアノ。
+    Mp,
+
+    /// メゾフォルテ。
+    Mf,
+
+    /// フォルテ。
+    F,
+
+    /// フォルティッシモ。
+    Ff,
+}
+
+impl Dynamics {
+    /// 音量に掛ける倍率。[`Mf`]を`1`とする。
+    ///
+    /// ```
+    /// # use voicevox_core::Dynamics;
+    /// #
+    /// assert_eq!(1., Dynamics::Mf.volume_scale());
+    /// assert!(Dynamics::P.volume_scale() < Dynamics::F.volume_scale());
+    /// ```
+    ///
+    /// [`Mf`]: Self::Mf
+    pub const fn volume_scale(self) -> f32 {
+        match self {
+            Self::Pp => 0.4,
+            Self::P => 0.55,
+            Self::Mp => 0.75,
+            Self::Mf => 1.,
+            Self::F => 1.3,
+            Self::Ff => 1.6,
+        }
+    }
+}
+
 /// 定数から[`Key`]をコンストラクトする。
 ///
 /// ```
@@ -340,7 +424,7 @@ impl<'de> Deserialize<'de> for OptionalLyric {
             type Value = OptionalLyric;
 
             fn expecting(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-                write!(fmt, "a string that represents zero or one mora kana")
+                write!(fmt, "a string that represents zero or more mora kana")
             }
 
             fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
@@ -363,6 +447,7 @@ impl<'de> Deserialize<'de> for OptionalLyric {
 ///
 /// [Serde]: serde
 /// [データのシリアライゼーション]: https://github.com/VOICEVOX/voicevox_core/blob/main/docs/guide/user/serialization.md
+#[cfg_attr(doc, doc(alias = "VoicevoxFrameAudioQuery"))]
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
@@ -702,19 +787,20 @@ mod key {
 }
 
 mod optional_lyric {
-    use arrayvec::ArrayVec;
     use derive_more::AsRef;
     use serde_with::SerializeDisplay;
+    use smallvec::SmallVec;
     use smol_str::SmolStr;
 
     use super::super::super::{
         acoustic_feature_extractor::{NonPauBaseVowel, OptionalConsonant},
-        mora_mappings::MORA_KANA_TO_MORA_PHONEMES,
+        mora_mappings::{MORA_KANA_TO_MORA_PHONEMES, MoraKana},
     };
 
     /// 音符の歌詞、または休符を表わす[無音]。
     ///
-    /// - 音符の歌詞の場合、一つのモーラを表すひらがな/カタカナ。
+    /// - 音符の歌詞の場合、一つ以上のモーラを表すひらがな/カタカナ。複数のモーラから成る場合、音符のフレーム長はモーラごとに分割される。
+    ///     - `ー`は直前のモーラの母音を延ばす。歌詞の先頭にある場合、直前の音符の最後の母音を延ばす（メリスマ）。
     /// - 休符を表わす無音の場合、空文字列。
     ///
     /// # Examples
@@ -725,9 +811,11 @@ mod optional_lyric {
     /// "ア".parse::<OptionalLyric>()?;
     /// "ヴォ".parse::<OptionalLyric>()?;
     /// "ん".parse::<OptionalLyric>()?; // 平仮名
+    /// "きゃっ".parse::<OptionalLyric>()?; // 複数モーラ
+    /// "ー".parse::<OptionalLyric>()?; // 直前の母音を延ばす
     /// "".parse::<OptionalLyric>()?; // 無音
     ///
-    /// "アア".parse::<OptionalLyric>().unwrap_err(); // 複数モーラは現状非対応
+    /// "a".parse::<OptionalLyric>().unwrap_err();
     /// # anyhow::Ok(())
     /// ```
     ///
@@ -756,7 +844,7 @@ mod optional_lyric {
         /// # Invariant
         ///
         /// This must come from `text`.
-        pub(super) phonemes: ArrayVec<(OptionalConsonant, NonPauBaseVowel), 1>,
+        pub(super) phonemes: SmallVec<[LyricMora; 1]>,
     }
 
     impl OptionalLyric {
@@ -772,7 +860,7 @@ mod optional_lyric {
         /// [無音]: crate::Phoneme::MorablePau
         pub const PAU: Self = Self {
             text: SmolStr::new_static(""),
-            phonemes: ArrayVec::new_const(),
+            phonemes: SmallVec::new_const(),
         };
 
         pub(super) fn new(text: &str) -> Result<Self, ()> {
@@ -780,21 +868,64 @@ mod optional_lyric {
                 return Ok(Self::default());
             }
 
-            let mora_kana = hira_to_kana(text).parse().map_err(|_| ())?;
+            let phonemes = split_moras(&hira_to_kana(text))?;
 
             Ok(Self {
                 text: text.into(),
-                phonemes: [MORA_KANA_TO_MORA_PHONEMES[mora_kana]].into(),
+                phonemes,
             })
         }
 
-        pub(in super::super) fn phonemes(
-            &self,
-        ) -> &ArrayVec<(OptionalConsonant, NonPauBaseVowel), 1> {
+        pub(in super::super) fn phonemes(&self) -> &[LyricMora] {
             &self.phonemes
         }
     }
 
+    /// 歌詞中の一つのモーラ。
+    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+    pub(in super::super) enum LyricMora {
+        Mora(OptionalConsonant, NonPauBaseVowel),
+
+        /// `ー`。直前のモーラの母音を延ばす。
+        Continuation,
+    }
+
+    impl LyricMora {
+        const CONTINUATION: char = 'ー';
+    }
+
+    /// カタカナの文字列をモーラに分割する。拗音のような二文字のモーラは一文字のモーラより優先される。
+    fn split_moras(kana: &str) -> Result<SmallVec<[LyricMora; 1]>, ()> {
+        let mut moras = SmallVec::new();
+        let mut rest = kana;
+
+        while !rest.is_empty() {
+            if let Some(tail) = rest.strip_prefix(LyricMora::CONTINUATION) {
+                moras.push(LyricMora::Continuation);
+                rest = tail;
+                continue;
+            }
+
+            let (mora_kana, tail) = [2, 1]
+                .into_iter()
+                .find_map(|num_chars| {
+                    let end = rest
+                        .char_indices()
+                        .nth(num_chars)
+                        .map_or(rest.len(), |(i, _)| i);
+                    let mora_kana = rest[..end].parse::<MoraKana>().ok()?;
+                    Some((mora_kana, &rest[end..]))
+                })
+                .ok_or(())?;
+
+            let (consonant, vowel) = MORA_KANA_TO_MORA_PHONEMES[mora_kana];
+            moras.push(LyricMora::Mora(consonant, vowel));
+            rest = tail;
+        }
+
+        Ok(moras)
+    }
+
     pub(super) fn hira_to_kana(s: &str) -> SmolStr {
         s.chars()
             .map(|c| match c {
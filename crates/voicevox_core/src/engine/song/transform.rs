@@ -0,0 +1,371 @@
+//! [`Score`]と[`FrameAudioQuery`]に対する移調と伸縮。
+
+use std::{fmt::Debug, ops::RangeInclusive};
+
+use typed_floats::StrictlyPositiveFinite;
+use typeshare::U53;
+
+use crate::{
+    FrameAudioQuery, FramePhoneme, Note, Score,
+    error::{InvalidQueryError, InvalidQueryErrorSource},
+};
+
+/// [`Score::stretch`]と[`FrameAudioQuery::stretch`]で受け付ける倍率の範囲。
+///
+/// 伸縮後のデータの大きさが元のデータの大きさに比例して抑えられるよう、上限を設ける。
+const STRETCH_RATIO_RANGE: RangeInclusive<f32> = 1. / 16. ..=16.;
+
+impl Score {
+    /// 全ての音符を`semitones`半音だけ移調する。
+    ///
+    /// [`Key`]の範囲を超える音符は[`Key::MIN`]または[`Key::MAX`]に丸められる。休符は変化しない。
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use voicevox_core::{key, Score};
+    /// #
+    /// let mut score = serde_json::from_str::<Score>(
+    ///     r#"
+    /// {
+    ///   "notes": [
+    ///     { "key": null, "frame_length": 15, "lyric": "" },
+    ///     { "key": 60, "frame_length": 45, "lyric": "ド" }
+    ///   ]
+    /// }
+    ///     "#,
+    /// )
+    /// .unwrap();
+    ///
+    /// score.transpose(-4);
+    ///
+    /// assert_eq!(Some(key!(56)), score.notes[1].key);
+    /// ```
+    ///
+    /// [`Key`]: crate::Key
+    /// [`Key::MIN`]: crate::Key::MIN
+    /// [`Key::MAX`]: crate::Key::MAX
+    pub fn transpose(&mut self, semitones: i8) {
+        for Note { key, .. } in &mut self.notes {
+            if let Some(key) = key {
+                *key = key.saturating_add_signed(semitones);
+            }
+        }
+    }
+
+    /// 全ての音符と休符のフレーム長を`ratio`倍する。
+    ///
+    /// 端数は各音符の終了位置が四捨五入されるように調整される。そのため全体のフレーム長は元の`ratio`倍を四捨五入したものになる。
+    ///
+    /// 同じ`ratio`で[`FrameAudioQuery::stretch`]を行ったものとは、[`ensure_compatible`]を満たしたままとなる。
+    ///
+    /// # Errors
+    ///
+    /// 以下の場合、[`ErrorKind::InvalidQuery`]を返す。このとき`self`は変更されない。
+    ///
+    /// - `ratio`が1/16以上16以下ではないとき。
+    /// - 伸縮後のフレーム長の合計が2<sup>53</sup>以上になるとき。
+    ///
+    /// [`ensure_compatible`]: crate::ensure_compatible
+    /// [`ErrorKind::InvalidQuery`]: crate::ErrorKind::InvalidQuery
+    pub fn stretch(&mut self, ratio: StrictlyPositiveFinite<f32>) -> crate::Result<()> {
+        let frame_lengths = stretch_frame_lengths(
+            self.notes
+                .iter()
+                .map(|&Note { frame_length, .. }| frame_length),
+            ratio,
+        )?;
+        for (Note { frame_length, .. }, new_frame_length) in
+            itertools::zip_eq(&mut self.notes, frame_lengths)
+        {
+            *frame_length = new_frame_length;
+        }
+        Ok(())
+    }
+}
+
+impl FrameAudioQuery {
+    /// [`f0`]を`2^(semitones/12)`倍することで、`semitones`半音だけ移調する。
+    ///
+    /// [`Score::transpose`]を行った楽譜から生成しなおすのとは異なり、音素やフレーム長、音量は変化しない。
+    ///
+    /// [`f0`]: Self::f0
+    pub fn transpose(&mut self, semitones: i8) {
+        let scale = 2f32.powf(f32::from(semitones) / 12.);
+        for f0 in &mut self.f0 {
+            *f0 = (f32::from(*f0) * scale)
+                .min(f32::MAX)
+                .try_into()
+                .expect("should be positive and finite");
+        }
+    }
+
+    /// 全ての音素のフレーム長を`ratio`倍し、それに合わせて[`f0`]と[`volume`]を線形補間する。
+    ///
+    /// 端数の扱いについては[`Score::stretch`]と同じ。[`f0`]については無声（`0`）のフレームと有声のフレームの間は補間されず、近い方のフレームの値が用いられる。
+    ///
+    /// # Errors
+    ///
+    /// [`Score::stretch`]と同じ。
+    ///
+    /// [`f0`]: Self::f0
+    /// [`volume`]: Self::volume
+    pub fn stretch(&mut self, ratio: StrictlyPositiveFinite<f32>) -> crate::Result<()> {
+        let frame_lengths = stretch_frame_lengths(
+            self.phonemes
+                .iter()
+                .map(|&FramePhoneme { frame_length, .. }| frame_length),
+            ratio,
+        )?;
+        for (FramePhoneme { frame_length, .. }, new_frame_length) in
+            itertools::zip_eq(&mut self.phonemes, frame_lengths)
+        {
+            *frame_length = new_frame_length;
+        }
+
+        self.f0 = resample(&self.f0, ratio, |a, b, t| {
+            if a == 0. || b == 0. {
+                if t < 0.5 { a } else { b }
+            } else {
+                a + (b - a) * t
+            }
+        });
+        self.volume = resample(&self.volume, ratio, |a, b, t| a + (b - a) * t);
+        Ok(())
+    }
+}
+
+/// 各要素の終了位置を`ratio`倍して四捨五入することで、フレーム長を伸縮する。
+fn stretch_frame_lengths(
+    frame_lengths: impl IntoIterator<Item = U53>,
+    ratio: StrictlyPositiveFinite<f32>,
+) -> Result<Vec<U53>, InvalidQueryError> {
+    if !STRETCH_RATIO_RANGE.contains(&f32::from(ratio)) {
+        return Err(InvalidQueryError {
+            what: "伸縮の倍率",
+            value: Some(Box::new(ratio)),
+            source: Some(InvalidQueryErrorSource::OutOfRangeStretchRatio),
+        });
+    }
+    let ratio = f64::from(f32::from(ratio));
+
+    let mut end = 0u64;
+    let mut stretched_end = U53::from(0u8);
+    frame_lengths
+        .into_iter()
+        .map(|frame_length| {
+            let prev_stretched_end = stretched_end;
+            stretched_end = end
+                .checked_add(frame_length.into())
+                .inspect(|&new_end| end = new_end)
+                .and_then(|end| U53::try_from((end as f64 * ratio).round() as u64).ok())
+                .ok_or_else(|| InvalidQueryError {
+                    what: "伸縮後のフレーム長",
+                    value: None,
+                    source: Some(InvalidQueryErrorSource::TooLongAfterStretch),
+                })?;
+            Ok((u64::from(stretched_end) - u64::from(prev_stretched_end))
+                .try_into()
+                .expect("should be less than the stretched end"))
+        })
+        .collect()
+}
+
+/// 長さを`ratio`倍して四捨五入したものにリサンプリングする。
+///
+/// `interpolate(a, b, t)`は`a`と`b`の間の`t`（`0`以上`1`未満）の位置の値を返す。
+fn resample<T>(
+    values: &[T],
+    ratio: StrictlyPositiveFinite<f32>,
+    interpolate: impl Fn(f32, f32, f32) -> f32,
+) -> Vec<T>
+where
+    T: Copy + Into<f32> + TryFrom<f32>,
+    T::Error: Debug,
+{
+    let Some(last_index) = values.len().checked_sub(1) else {
+        return vec![];
+    };
+    let ratio = f64::from(f32::from(ratio));
+    let len = (values.len() as f64 * ratio).round() as usize;
+
+    (0..len)
+        .map(|i| {
+            // 各フレームの中心同士を対応させる
+            let pos = ((i as f64 + 0.5) / ratio - 0.5).clamp(0., last_index as f64);
+            let index = pos.floor() as usize;
+            let t = (pos - index as f64) as f32;
+            let a = values[index].into();
+            let b = values[(index + 1).min(last_index)].into();
+            interpolate(a, b, t)
+                .try_into()
+                .expect("interpolated value should be in the same range")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use typed_floats::PositiveFinite;
+    use typeshare::U53;
+
+    use crate::{FrameAudioQuery, Note, Score, ensure_compatible, key};
+
+    #[rstest]
+    #[case(&[45, 45, 45], &[30, 30, 30], 1.5)]
+    #[case(&[15, 15, 15], &[30, 30, 30], 0.5)]
+    #[case(&[2, 1, 2, 1], &[1, 1, 1, 1], 1.5)]
+    #[case(&[1, 0, 1, 0], &[1, 1, 1, 1], 0.5)]
+    fn stretch_frame_lengths_works(
+        #[case] expected: &[u64],
+        #[case] frame_lengths: &[u32],
+        #[case] ratio: f32,
+    ) {
+        assert_eq!(
+            expected,
+            super::stretch_frame_lengths(
+                frame_lengths.iter().copied().map(U53::from),
+                ratio.try_into().unwrap(),
+            )
+            .unwrap()
+            .into_iter()
+            .map(u64::from)
+            .collect::<Vec<_>>(),
+        );
+    }
+
+    #[rstest]
+    #[case(&[1., 1.5, 2.], &[1., 2.], 1.5)]
+    #[case(&[0., 0.25, 0.75, 1.], &[0., 1.], 2.)]
+    #[case(&[1.5], &[1., 2.], 0.5)]
+    #[case(&[], &[], 2.)]
+    fn resample_works(#[case] expected: &[f32], #[case] values: &[f32], #[case] ratio: f32) {
+        let values = values
+            .iter()
+            .map(|&v| v.try_into().unwrap())
+            .collect::<Vec<PositiveFinite<f32>>>();
+        assert_eq!(
+            expected,
+            super::resample(&values, ratio.try_into().unwrap(), |a, b, t| a
+                + (b - a) * t)
+            .into_iter()
+            .map(f32::from)
+            .collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn transpose_works() {
+        let mut score = score();
+        score.transpose(2);
+        assert_eq!(
+            [None, Some(key!(62)), Some(key!(64))],
+            *score
+                .notes
+                .iter()
+                .map(|&Note { key, .. }| key)
+                .collect::<Vec<_>>(),
+        );
+
+        let mut frame_audio_query = frame_audio_query();
+        frame_audio_query.transpose(12);
+        assert_eq!(
+            [0., 440., 440.],
+            *frame_audio_query
+                .f0
+                .iter()
+                .copied()
+                .map(f32::from)
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    #[rstest]
+    #[case(2.)]
+    #[case(0.5)]
+    #[case(1.3)]
+    fn stretch_keeps_compatibility(#[case] ratio: f32) {
+        let ratio = ratio.try_into().unwrap();
+
+        let mut score = score();
+        let mut frame_audio_query = frame_audio_query();
+        score.stretch(ratio).unwrap();
+        frame_audio_query.stretch(ratio).unwrap();
+
+        ensure_compatible(&score, &frame_audio_query).unwrap();
+
+        let total_frame_length = frame_audio_query.total_frame_length();
+        assert_eq!(total_frame_length, frame_audio_query.f0.len());
+        assert_eq!(total_frame_length, frame_audio_query.volume.len());
+        assert_eq!(
+            total_frame_length as u64,
+            score
+                .notes
+                .iter()
+                .map(|&Note { frame_length, .. }| u64::from(frame_length))
+                .sum::<u64>(),
+        );
+    }
+
+    #[rstest]
+    #[case(1. / 32.)]
+    #[case(17.)]
+    #[case(f32::MAX)]
+    fn stretch_denies_out_of_range_ratios(#[case] ratio: f32) {
+        let ratio = ratio.try_into().unwrap();
+
+        let mut score = score();
+        let err = score.stretch(ratio).unwrap_err();
+        assert_eq!(crate::ErrorKind::InvalidQuery, err.kind());
+        assert_eq!(self::score(), score);
+
+        let mut frame_audio_query = frame_audio_query();
+        let err = frame_audio_query.stretch(ratio).unwrap_err();
+        assert_eq!(crate::ErrorKind::InvalidQuery, err.kind());
+        assert_eq!(self::frame_audio_query(), frame_audio_query);
+    }
+
+    #[test]
+    fn stretch_denies_too_long_results() {
+        let frame_lengths = [((1u64 << 53) - 1).try_into().unwrap(), U53::from(1u8)];
+        let err =
+            super::stretch_frame_lengths(frame_lengths, 1f32.try_into().unwrap()).unwrap_err();
+        assert_eq!("伸縮後のフレーム長", err.what);
+    }
+
+    fn score() -> Score {
+        serde_json::from_str(
+            r#"
+{
+  "notes": [
+    { "key": null, "frame_length": 1, "lyric": "" },
+    { "key": 60, "frame_length": 1, "lyric": "ア" },
+    { "key": 62, "frame_length": 1, "lyric": "イ" }
+  ]
+}
+            "#,
+        )
+        .unwrap()
+    }
+
+    fn frame_audio_query() -> FrameAudioQuery {
+        serde_json::from_str(
+            r#"
+{
+  "f0": [0.0, 220.0, 220.0],
+  "volume": [0.0, 0.5, 0.5],
+  "phonemes": [
+    { "phoneme": "pau", "frame_length": 1, "note_id": null },
+    { "phoneme": "a", "frame_length": 1, "note_id": null },
+    { "phoneme": "i", "frame_length": 1, "note_id": null }
+  ],
+  "volumeScale": 1.0,
+  "outputSamplingRate": 24000,
+  "outputStereo": false
+}
+            "#,
+        )
+        .unwrap()
+    }
+}
@@ -1,6 +1,8 @@
 use std::num::NonZero;
 
 use arrayvec::ArrayVec;
+use itertools::Itertools as _;
+use smallvec::SmallVec;
 use tracing::warn;
 use typeshare::U53;
 
@@ -15,7 +17,9 @@ use super::{
         sampling_rate::SamplingRate,
         validate::Validate as _,
     },
-    queries::{FrameAudioQuery, FramePhoneme, Key, Note, NoteId, OptionalLyric, Score},
+    queries::{
+        Dynamics, FrameAudioQuery, FramePhoneme, Key, LyricMora, Note, NoteId, OptionalLyric, Score,
+    },
 };
 
 use self::note_seq::ValidatedNoteSeq;
@@ -88,10 +92,12 @@ impl Score {
     ///
     /// - [`notes`]の要素のうちいずれかが[不正]。
     /// - [`notes`]が空であるか、もしくは先頭が音符。
+    /// - [`notes`]の要素のうちいずれかの[`lyric`]が`ー`から始まり、かつその直前が休符。
     ///
     /// [`ErrorKind::InvalidQuery`]: crate::ErrorKind::InvalidQuery
     /// [`notes`]: Self::notes
     /// [不正]: Note::validate
+    /// [`lyric`]: Note::lyric
     #[cfg_attr(doc, doc(alias = "voicevox_score_validate"))]
     pub fn validate(&self) -> crate::Result<()> {
         ValidatedScore::try_from(self)
@@ -109,16 +115,22 @@ impl Note {
     ///
     /// - [`key`]が`None`かつ[`lyric`]が[`PAU`]以外。
     /// - [`key`]が`Some(_)`かつ[`lyric`]が[`PAU`]。
+    /// - [`breath`]が`true`かつ音符である。
+    /// - [`lyric`]が複数のモーラから成り、かつ[`frame_length`]がそのモーラ数より小さい。
+    ///
+    /// [`lyric`]が`ー`から始まる場合、直前の音符との関係は[`Score::validate`]で検証される。
     ///
     /// [`ErrorKind::InvalidQuery`]: crate::ErrorKind::InvalidQuery
     /// [`key`]: Self::key
     /// [`lyric`]: Self::lyric
+    /// [`breath`]: Self::breath
+    /// [`frame_length`]: Self::frame_length
     /// [`PAU`]: OptionalLyric::PAU
     #[cfg_attr(doc, doc(alias = "voicevox_note_validate"))]
     pub fn validate(&self) -> crate::Result<()> {
-        ValidatedNote::try_from(self)
-            .map(|_| ())
-            .map_err(Into::into)
+        PauOrKeyAndLyric::key_for(self.key, &self.lyric, self.breath)?;
+        ensure_frame_length_for_moras(self.frame_length, &self.lyric)?;
+        Ok(())
     }
 }
 
@@ -207,8 +219,11 @@ impl<'a> TryFrom<&'a [Note]> for ValidatedNoteSeq {
     fn try_from(notes: &'a [Note]) -> Result<Self, Self::Error> {
         let notes = notes
             .iter()
-            .map(TryInto::try_into)
-            .collect::<Result<Vec<_>, _>>()?;
+            .try_fold(vec![], |mut acc: Vec<ValidatedNote>, note| {
+                let prev_vowel = acc.last().and_then(ValidatedNote::vowel);
+                acc.extend(ValidatedNote::split(note, prev_vowel)?);
+                Ok::<_, InvalidQueryError>(acc)
+            })?;
 
         NonEmptyVec::new(notes)
             .and_then(Into::into)
@@ -226,16 +241,99 @@ impl AsRef<[ValidatedNote]> for ValidatedNoteSeq {
     }
 }
 
+/// 一つのモーラ、または休符。
+///
+/// 複数のモーラから成る[`Note`]は、モーラごとの`ValidatedNote`に分割される。
 pub(crate) struct ValidatedNote {
     pub(crate) id: Option<NoteId>,
     pub(crate) pau_or_key_and_lyric: PauOrKeyAndLyric,
     pub(crate) frame_length: U53,
+    pub(crate) dynamics: Option<Dynamics>,
 }
 
 impl ValidatedNote {
+    /// `note`をモーラごとに分割する。
+    ///
+    /// フレーム長はモーラ数で均等に分割され、余りは先頭のモーラから一フレームずつ割り当てられる。複数のモーラに対してフレーム長がモーラ数に満たない場合はエラーとなる。`ー`は直前のモーラの母音で置き換えられる。`note`の先頭の`ー`に対しては`prev_vowel`を用いる。
+    fn split(
+        note: &Note,
+        mut prev_vowel: Option<NonPauBaseVowel>,
+    ) -> Result<SmallVec<[Self; 1]>, InvalidQueryError> {
+        let Note {
+            id,
+            key,
+            lyric,
+            frame_length,
+            dynamics,
+            breath,
+        } = note;
+
+        let Some(key) = PauOrKeyAndLyric::key_for(*key, lyric, *breath)? else {
+            return Ok([Self {
+                id: id.clone(),
+                pau_or_key_and_lyric: if *breath {
+                    PauOrKeyAndLyric::Breath
+                } else {
+                    PauOrKeyAndLyric::Pau
+                },
+                frame_length: *frame_length,
+                dynamics: *dynamics,
+            }]
+            .into_iter()
+            .collect());
+        };
+
+        ensure_frame_length_for_moras(*frame_length, lyric)?;
+        let moras = lyric.phonemes();
+
+        moras
+            .iter()
+            .zip_eq(split_frame_length(*frame_length, moras.len()))
+            .map(|(&mora, frame_length)| {
+                let (consonant, vowel) = match mora {
+                    LyricMora::Mora(consonant, vowel) => (consonant, vowel),
+                    LyricMora::Continuation => {
+                        let vowel = prev_vowel.ok_or_else(|| InvalidQueryError {
+                            what: Note::NAME,
+                            value: Some(Box::new(lyric.clone())),
+                            source: Some(InvalidQueryErrorSource::ContinuationWithoutVowel),
+                        })?;
+                        (OptionalConsonant::None, vowel)
+                    }
+                };
+                prev_vowel = Some(vowel);
+
+                Ok(Self {
+                    id: id.clone(),
+                    pau_or_key_and_lyric: PauOrKeyAndLyric::KeyAndLyric {
+                        key,
+                        lyric: Lyric {
+                            phonemes: [(consonant, vowel)],
+                        },
+                    },
+                    frame_length,
+                    dynamics: *dynamics,
+                })
+            })
+            .collect()
+    }
+
+    fn vowel(&self) -> Option<NonPauBaseVowel> {
+        match self.pau_or_key_and_lyric {
+            PauOrKeyAndLyric::Pau | PauOrKeyAndLyric::Breath => None,
+            PauOrKeyAndLyric::KeyAndLyric {
+                lyric: Lyric {
+                    phonemes: [(_, vowel)],
+                },
+                ..
+            } => Some(vowel),
+        }
+    }
+
     fn phonemes(&self) -> ArrayVec<PhonemeCode, 2> {
         match self.pau_or_key_and_lyric {
             PauOrKeyAndLyric::Pau => [PhonemeCode::MorablePau].into_iter().collect(),
+            PauOrKeyAndLyric::Breath => [BREATH_PHONEME].into_iter().collect(),
             // TODO: Rust 1.91以降なら`std::iter::chain`がある
             PauOrKeyAndLyric::KeyAndLyric {
                 lyric:
@@ -249,52 +347,77 @@ impl ValidatedNote {
     }
 }
 
-impl TryFrom<&'_ Note> for ValidatedNote {
-    type Error = InvalidQueryError;
-
-    fn try_from(note: &'_ Note) -> Result<Self, Self::Error> {
-        let Note {
-            id,
-            key,
-            lyric,
-            frame_length,
-        } = note;
-
-        let pau_or_key_and_lyric = PauOrKeyAndLyric::new(*key, lyric)?;
-
-        Ok(Self {
-            id: id.clone(),
-            pau_or_key_and_lyric,
-            frame_length: *frame_length,
-        })
+/// 複数のモーラから成る歌詞に対し、各モーラに1フレーム以上を割り当てられるかを確かめる。
+///
+/// 単一のモーラから成る音符は、従来通りフレーム長が0であることを許す。
+fn ensure_frame_length_for_moras(
+    frame_length: U53,
+    lyric: &OptionalLyric,
+) -> Result<(), InvalidQueryError> {
+    let num_moras = u64::try_from(lyric.phonemes().len()).expect("should be small");
+    if num_moras > 1 && u64::from(frame_length) < num_moras {
+        return Err(InvalidQueryError {
+            what: Note::NAME,
+            value: Some(Box::new(lyric.clone())),
+            source: Some(InvalidQueryErrorSource::TooShortForMoras),
+        });
     }
+    Ok(())
 }
 
+fn split_frame_length(frame_length: U53, num_moras: usize) -> impl Iterator<Item = U53> {
+    let frame_length = u64::from(frame_length);
+    let num_moras = u64::try_from(num_moras).expect("should be small");
+    (0..num_moras).map(move |i| {
+        (frame_length / num_moras + u64::from(i < frame_length % num_moras))
+            .try_into()
+            .expect("should equal or be smaller than `frame_length`")
+    })
+}
+
+/// [`PauOrKeyAndLyric::Breath`]に割り当てる音素。無声化した`u`を息継ぎの音として用いる。
+pub(crate) const BREATH_PHONEME: PhonemeCode = PhonemeCode::UnvoicedVowelU;
+
 #[derive(PartialEq)]
 pub(crate) enum PauOrKeyAndLyric {
     Pau,
-    KeyAndLyric { key: Key, lyric: Lyric },
+
+    /// ブレス。推論においては、音素以外は[`Pau`]と同様に扱う。
+    ///
+    /// [`Pau`]: Self::Pau
+    Breath,
+
+    KeyAndLyric {
+        key: Key,
+        lyric: Lyric,
+    },
 }
 
 impl PauOrKeyAndLyric {
-    fn new(key: Option<Key>, lyric: &OptionalLyric) -> Result<Self, InvalidQueryError> {
-        match (key, &**lyric.phonemes()) {
-            (None, []) => Ok(Self::Pau),
-            (Some(key), &[mora]) => Ok(Self::KeyAndLyric {
-                key,
-                lyric: Lyric { phonemes: [mora] },
+    /// 音符であれば音階を、休符であれば`None`を返す。
+    fn key_for(
+        key: Option<Key>,
+        lyric: &OptionalLyric,
+        breath: bool,
+    ) -> Result<Option<Key>, InvalidQueryError> {
+        match (key, lyric.phonemes(), breath) {
+            (None, [], _) => Ok(None),
+            (Some(key), [_, ..], false) => Ok(Some(key)),
+            (Some(_), [_, ..], true) => Err(InvalidQueryError {
+                what: Note::NAME,
+                value: None,
+                source: Some(InvalidQueryErrorSource::BreathForNonPau),
             }),
-            (Some(_), []) => Err(InvalidQueryError {
+            (Some(_), [], _) => Err(InvalidQueryError {
                 what: Note::NAME,
                 value: None,
                 source: Some(InvalidQueryErrorSource::UnnecessaryKeyForPau),
             }),
-            (None, [_]) => Err(InvalidQueryError {
+            (None, [_, ..], _) => Err(InvalidQueryError {
                 what: Note::NAME,
                 value: None,
                 source: Some(InvalidQueryErrorSource::MissingKeyForNonPau),
             }),
-            (_, [_, ..]) => unreachable!("the lyric should consist of at most one mora"),
         }
     }
 
@@ -302,6 +425,7 @@ impl PauOrKeyAndLyric {
         !matches!(
             self,
             Self::Pau
+                | Self::Breath
                 | Self::KeyAndLyric {
                     lyric: Lyric {
                         phonemes: [(OptionalConsonant::None, _)],
@@ -343,14 +467,19 @@ pub(crate) mod note_seq {
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use crate::{
         error::{ErrorRepr, InvalidQueryError, InvalidQueryErrorSource},
         numerics::positive_finite_f32,
     };
 
-    use super::super::{
-        super::validate::Validate as _,
-        queries::{FrameAudioQuery, FramePhoneme, Note, Score},
+    use super::{
+        super::{
+            super::validate::Validate as _,
+            queries::{FrameAudioQuery, FramePhoneme, Note, Score},
+        },
+        ValidatedNote, ValidatedScore,
     };
 
     // TODO: トークの方と一緒に、`validated`に関するテストを書く
@@ -403,39 +532,195 @@ mod tests {
                 source: Some(InvalidQueryErrorSource::InvalidFields { .. }),
             }))
         ));
+    }
 
-        fn score<const N: usize>(notes: [Note; N]) -> Score {
-            Score {
-                notes: notes.into(),
-            }
+    #[test]
+    fn ensure_compatible_works_for_multi_mora_lyrics() {
+        super::ensure_compatible(
+            &score([
+                note(None, ""),
+                Note {
+                    frame_length: 2u8.into(),
+                    ..note(Some(0), "きゃっ")
+                },
+                note(Some(0), "ー"),
+                Note {
+                    frame_length: 2u8.into(),
+                    ..note(Some(0), "アー")
+                },
+                note(None, ""),
+            ]),
+            &frame_audio_query([
+                frame_phoneme("pau"),
+                frame_phoneme("ky"),
+                frame_phoneme("a"),
+                frame_phoneme("cl"),
+                frame_phoneme("cl"),
+                frame_phoneme("a"),
+                frame_phoneme("a"),
+                frame_phoneme("pau"),
+            ]),
+        )
+        .unwrap();
+
+        let err = super::ensure_compatible(
+            &score([
+                note(None, ""),
+                Note {
+                    frame_length: 2u8.into(),
+                    ..note(Some(0), "ーア")
+                },
+            ]),
+            &frame_audio_query([frame_phoneme("pau"), frame_phoneme("a")]),
+        )
+        .unwrap_err();
+        // TODO: MSRVが1.96.0になったら`assert_matches!`を使う
+        assert!(matches!(
+            err,
+            crate::Error(ErrorRepr::InvalidQuery(InvalidQueryError {
+                what: Score::NAME,
+                value: None,
+                source: Some(InvalidQueryErrorSource::InvalidFields { .. }),
+            }))
+        ));
+    }
+
+    #[test]
+    fn ensure_compatible_works_for_breath() {
+        super::ensure_compatible(
+            &score([
+                note(None, ""),
+                note(Some(0), "ア"),
+                Note {
+                    breath: true,
+                    ..note(None, "")
+                },
+                note(Some(0), "イ"),
+            ]),
+            &frame_audio_query([
+                frame_phoneme("pau"),
+                frame_phoneme("a"),
+                frame_phoneme("U"),
+                frame_phoneme("i"),
+            ]),
+        )
+        .unwrap();
+
+        let err = Note {
+            breath: true,
+            ..note(Some(0), "ア")
         }
+        .validate()
+        .unwrap_err();
+        // TODO: MSRVが1.96.0になったら`assert_matches!`を使う
+        assert!(matches!(
+            err,
+            crate::Error(ErrorRepr::InvalidQuery(InvalidQueryError {
+                what: Note::NAME,
+                value: None,
+                source: Some(InvalidQueryErrorSource::BreathForNonPau),
+            }))
+        ));
+    }
 
-        fn note(key: Option<u8>, lyric: &str) -> Note {
+    #[rstest]
+    #[case(&[10], 10, "ア")]
+    #[case(&[4, 3, 3], 10, "アイウ")]
+    #[case(&[2, 1, 1], 4, "きゃっー")]
+    #[case(&[1, 1, 1], 3, "きゃっー")]
+    fn validated_score_splits_frame_lengths(
+        #[case] expected: &[u64],
+        #[case] frame_length: u8,
+        #[case] lyric: &str,
+    ) {
+        let score = &score([
+            note(None, ""),
             Note {
-                id: None,
-                key: key.map(|key| key.try_into().unwrap()),
-                lyric: lyric.parse().unwrap(),
-                frame_length: 1u8.into(),
+                frame_length: frame_length.into(),
+                ..note(Some(0), lyric)
+            },
+        ]);
+        let ValidatedScore { notes } = score.try_into().unwrap();
+
+        let frame_lengths = notes
+            .iter()
+            .into_iter()
+            .skip(1)
+            .map(|&ValidatedNote { frame_length, .. }| u64::from(frame_length))
+            .collect::<Vec<_>>();
+        assert_eq!(expected, frame_lengths);
+    }
+
+    #[rstest]
+    #[case(2, "きゃっー")]
+    #[case(1, "アー")]
+    #[case(0, "アー")]
+    fn notes_shorter_than_moras_are_denied(#[case] frame_length: u8, #[case] lyric: &str) {
+        let note = Note {
+            frame_length: frame_length.into(),
+            ..note(Some(0), lyric)
+        };
+
+        let err = note.validate().unwrap_err();
+        // TODO: MSRVが1.96.0になったら`assert_matches!`を使う
+        assert!(matches!(
+            err,
+            crate::Error(ErrorRepr::InvalidQuery(InvalidQueryError {
+                what: Note::NAME,
+                value: Some(_),
+                source: Some(InvalidQueryErrorSource::TooShortForMoras),
+            }))
+        ));
+
+        let InvalidQueryError {
+            source: Some(InvalidQueryErrorSource::InvalidFields { source, .. }),
+            ..
+        } = ValidatedScore::try_from(&score([note(None, ""), note])).unwrap_err()
+        else {
+            panic!("should be `InvalidFields`");
+        };
+        assert!(matches!(
+            *source,
+            InvalidQueryError {
+                source: Some(InvalidQueryErrorSource::TooShortForMoras),
+                ..
             }
+        ));
+    }
+
+    fn score<const N: usize>(notes: [Note; N]) -> Score {
+        Score {
+            notes: notes.into(),
         }
+    }
 
-        fn frame_audio_query<const N: usize>(phonemes: [FramePhoneme; N]) -> FrameAudioQuery {
-            FrameAudioQuery {
-                f0: [].into(),
-                volume: [].into(),
-                phonemes: phonemes.into(),
-                volume_scale: positive_finite_f32!(1.),
-                output_sampling_rate: Default::default(),
-                output_stereo: true,
-            }
+    fn note(key: Option<u8>, lyric: &str) -> Note {
+        Note {
+            id: None,
+            key: key.map(|key| key.try_into().unwrap()),
+            lyric: lyric.parse().unwrap(),
+            frame_length: 1u8.into(),
+            dynamics: None,
+            breath: false,
         }
+    }
 
-        fn frame_phoneme(phoneme: &str) -> FramePhoneme {
-            FramePhoneme {
-                phoneme: phoneme.parse().unwrap(),
-                frame_length: 1u8.into(),
-                note_id: None,
-            }
+    fn frame_audio_query<const N: usize>(phonemes: [FramePhoneme; N]) -> FrameAudioQuery {
+        FrameAudioQuery {
+            f0: [].into(),
+            volume: [].into(),
+            phonemes: phonemes.into(),
+            volume_scale: positive_finite_f32!(1.),
+            output_sampling_rate: Default::default(),
+            output_stereo: true,
+        }
+    }
+
+    fn frame_phoneme(phoneme: &str) -> FramePhoneme {
+        FramePhoneme {
+            phoneme: phoneme.parse().unwrap(),
+            frame_length: 1u8.into(),
+            note_id: None,
         }
     }
 }
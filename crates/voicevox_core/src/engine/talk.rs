@@ -1,7 +1,10 @@
 mod audio_query;
+// Open JTalkはWASM向けにはビルドできない
+#[cfg(not(target_family = "wasm"))]
 mod full_context_label;
 mod interpret_query;
 mod kana_parser;
+#[cfg(not(target_family = "wasm"))]
 pub(crate) mod open_jtalk;
 pub(crate) mod text;
 pub(crate) mod text_analyzer;
@@ -11,6 +14,7 @@ pub use self::audio_query::{AccentPhrase, AudioQuery, Mora};
 pub(crate) use self::audio_query::{
     LengthedPhoneme, ValidatedAccentPhrase, ValidatedAudioQuery, ValidatedMora,
 };
+#[cfg(not(target_family = "wasm"))]
 pub(crate) use self::full_context_label::extract_full_context_label;
 pub(crate) use self::interpret_query::{DecoderFeature, initial_process, split_mora};
 pub(crate) use self::kana_parser::{KanaParseError, create_kana, parse_kana};
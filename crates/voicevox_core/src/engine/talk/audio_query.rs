@@ -119,6 +119,7 @@ impl AccentPhrase {
 ///
 /// [`ErrorKind::InvalidQuery`]: crate::ErrorKind::InvalidQuery
 /// [`validate`メソッド]: Self::validate
+#[cfg_attr(doc, doc(alias = "VoicevoxAudioQuery"))]
 #[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct AudioQuery {
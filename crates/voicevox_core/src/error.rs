@@ -10,6 +10,7 @@ use crate::{
 //use engine::
 use duplicate::duplicate_item;
 use itertools::Itertools as _;
+use serde::Serialize;
 use std::{collections::BTreeSet, fmt::Debug, path::PathBuf};
 use thiserror::Error;
 use uuid::Uuid;
@@ -45,6 +46,10 @@ impl Error {
                 LoadModelErrorKind::StyleAlreadyLoaded { .. } => ErrorKind::StyleAlreadyLoaded,
                 LoadModelErrorKind::InvalidModelFormat => ErrorKind::InvalidModelFormat,
                 LoadModelErrorKind::InvalidModelData => ErrorKind::InvalidModelData,
+                LoadModelErrorKind::VerifyModel => ErrorKind::VerifyModel,
+                LoadModelErrorKind::StyleNotInModel { .. } | LoadModelErrorKind::NoStyleMatched => {
+                    ErrorKind::StyleNotFound
+                }
             },
             ErrorRepr::GetSupportedDevices(_) => ErrorKind::GetSupportedDevices,
             ErrorRepr::StyleNotFound { .. } => ErrorKind::StyleNotFound,
@@ -63,6 +68,90 @@ impl Error {
     }
 }
 
+/// [`Error`]に含まれる構造化された情報を返す。
+pub fn error_details(err: &Error) -> ErrorDetails {
+    let mut details = ErrorDetails::default();
+    match &err.0 {
+        ErrorRepr::InitInferenceRuntime {
+            runtime_display_name,
+            ..
+        } => details.runtime = Some(*runtime_display_name),
+        ErrorRepr::LoadModel(LoadModelError { path, context, .. }) => {
+            details.path = path.as_ref().map(|path| path.display().to_string());
+            match context {
+                LoadModelErrorKind::ReadZipEntry { filename } => {
+                    details.filename = Some(filename.clone());
+                }
+                LoadModelErrorKind::ModelAlreadyLoaded { id } => details.model_id = Some(*id),
+                LoadModelErrorKind::StyleAlreadyLoaded { id }
+                | LoadModelErrorKind::StyleNotInModel { id } => details.style_id = Some(*id),
+                LoadModelErrorKind::OpenZipFile
+                | LoadModelErrorKind::InvalidModelFormat
+                | LoadModelErrorKind::InvalidModelData
+                | LoadModelErrorKind::VerifyModel
+                | LoadModelErrorKind::NoStyleMatched => {}
+            }
+        }
+        ErrorRepr::StyleNotFound { style_id, .. } => details.style_id = Some(*style_id),
+        ErrorRepr::ModelNotFound { model_id } => details.model_id = Some(*model_id),
+        ErrorRepr::AnalyzeText { text, .. } => details.text = Some(text.clone()),
+        ErrorRepr::WordNotFound(word_uuid) => details.word_uuid = Some(*word_uuid),
+        ErrorRepr::InvalidQuery(InvalidQueryError { what, value, .. }) => {
+            details.what = Some(*what);
+            details.value = value.as_ref().map(|value| format!("{value:?}"));
+        }
+        ErrorRepr::NotLoadedOpenjtalkDict
+        | ErrorRepr::GpuSupport(_)
+        | ErrorRepr::GetSupportedDevices(_)
+        | ErrorRepr::RunModel { .. }
+        | ErrorRepr::ParseKana(_)
+        | ErrorRepr::LoadUserDict(_)
+        | ErrorRepr::SaveUserDict(_)
+        | ErrorRepr::UseUserDict(_)
+        | ErrorRepr::InvalidWord(_)
+        | ErrorRepr::IncompatibleQueries(_) => {}
+    }
+    details
+}
+
+/// [`Error`]に含まれる構造化された情報。
+///
+/// [`Error`]の[`Display`]や[`source`]からは取り出しにくい値を、ラッパー向けに提供する。
+///
+/// [`Display`]: std::fmt::Display
+/// [`source`]: std::error::Error::source
+#[derive(Default, Debug, Serialize)]
+#[non_exhaustive]
+pub struct ErrorDetails {
+    /// 推論ライブラリの表示名。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runtime: Option<&'static str>,
+    /// VVMファイルのパス。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// VVM内のファイル名。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+    /// スタイルID。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style_id: Option<StyleId>,
+    /// 音声モデルID。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model_id: Option<VoiceModelId>,
+    /// 解析に失敗したテキスト。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// ユーザー辞書の単語のUUID。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub word_uuid: Option<Uuid>,
+    /// 不正なクエリの部分の名前。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub what: Option<&'static str>,
+    /// 不正な値の[`Debug`]表現。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
 #[derive(Error, Debug)]
 pub(crate) enum ErrorRepr {
     #[error("OpenJTalkの辞書が読み込まれていません")]
@@ -72,6 +161,10 @@ pub(crate) enum ErrorRepr {
     GpuSupport(DeviceAvailabilities),
 
     #[error("{runtime_display_name}のロードまたは初期化ができませんでした")]
+    #[cfg_attr(
+        not(onnxruntime),
+        allow(dead_code, reason = "現状ONNX Runtimeのロードと初期化でのみ生じる")
+    )]
     InitInferenceRuntime {
         runtime_display_name: &'static str,
         #[source]
@@ -168,9 +261,15 @@ pub enum ErrorKind {
     StyleAlreadyLoaded,
     /// 無効なモデルデータ。
     InvalidModelData,
+    /// 音声モデルの検証に失敗した。
+    ///
+    /// ファイルのダイジェストの不一致や、署名の検証の失敗を含む。
+    VerifyModel,
     /// サポートされているデバイス情報取得に失敗した。
     GetSupportedDevices,
     /// スタイルIDに対するスタイルが見つからなかった。
+    ///
+    /// 音声モデルの読み込み時に、読み込むスタイルとして指定されたものが見つからなかった場合も含む。
     StyleNotFound,
     /// 音声モデルIDに対する音声モデルが見つからなかった。
     ModelNotFound,
@@ -209,9 +308,13 @@ pub(crate) type LoadModelResult<T> = std::result::Result<T, LoadModelError>;
 
 /// 音声モデル読み込みのエラー。
 #[derive(Error, Debug)]
-#[error("`{path}`の読み込みに失敗しました: {context}")]
+#[error(
+    "{}の読み込みに失敗しました: {context}",
+    .path.as_ref().map_or_else(|| "メモリ上のVVM".to_owned(), |path| format!("`{}`", path.display())),
+)]
 pub(crate) struct LoadModelError {
-    pub(crate) path: PathBuf,
+    /// VVMのファイルパス。メモリ上のバイト列やリーダーから開いた場合は`None`。
+    pub(crate) path: Option<PathBuf>,
     pub(crate) context: LoadModelErrorKind,
     #[source]
     pub(crate) source: Option<anyhow::Error>,
@@ -231,6 +334,12 @@ pub(crate) enum LoadModelErrorKind {
     StyleAlreadyLoaded { id: StyleId },
     #[display("モデルデータを読むことができませんでした")]
     InvalidModelData,
+    #[display("モデルの検証に失敗しました")]
+    VerifyModel,
+    #[display("スタイル`{id}`はこのモデルに含まれていません")]
+    StyleNotInModel { id: StyleId },
+    #[display("読み込むスタイルの条件に合うスタイルが、このモデルにはありません")]
+    NoStyleMatched,
 }
 
 #[derive(Error, Debug)]
@@ -280,15 +389,36 @@ pub(crate) enum InvalidQueryErrorSource {
     #[error("keyがnullの場合、lyricは空文字列である必要があります。")]
     MissingKeyForNonPau,
 
+    #[error("breathがtrueの場合、keyはnull、lyricは空文字列である必要があります。")]
+    BreathForNonPau,
+
     #[error("{}以上{}以下である必要があります", Key::MIN, Key::MAX)]
     OutOfRangeKeyValue,
 
+    #[error("-1以上1以下である必要があります")]
+    OutOfRangePan,
+
+    #[error("phonemes, f0, volumeのいずれのフレーム数も超えない区間である必要があります")]
+    OutOfRangeFrames,
+
     #[error("{_0}")]
     NotInteger(serde_json::Error),
 
     #[error(r#"notesはpau (lyric="")から始まる必要があります"#)]
     InitialNoteMustBePau,
 
+    #[error(r#""ー"の直前には母音が必要です"#)]
+    ContinuationWithoutVowel,
+
+    #[error("lyricが複数のモーラから成る場合、frame_lengthはそのモーラ数以上である必要があります")]
+    TooShortForMoras,
+
+    #[error("1/16以上16以下である必要があります")]
+    OutOfRangeStretchRatio,
+
+    #[error("2^53未満である必要があります")]
+    TooLongAfterStretch,
+
     #[error(transparent)]
     InvalidAsSuperset(Box<InvalidQueryError>),
 
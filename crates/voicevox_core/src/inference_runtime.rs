@@ -0,0 +1,23 @@
+//! 推論ランタイムの抽象。
+//!
+//! [`InferenceRuntime`]を実装したものは、[`blocking::Synthesizer::builder_with_runtime`]および
+//! [`nonblocking::Synthesizer::builder_with_runtime`]により[ONNX Runtime]の代わりに用いることができる。
+//!
+//! [`blocking::Synthesizer::builder_with_runtime`]: crate::blocking::Synthesizer::builder_with_runtime
+//! [`nonblocking::Synthesizer::builder_with_runtime`]: crate::nonblocking::Synthesizer::builder_with_runtime
+//! [ONNX Runtime]: crate::blocking::Onnxruntime
+
+pub use crate::core::{
+    devices::DeviceSpec,
+    infer::{
+        InferenceRuntime, InferenceSessionOptions, InputScalarKind, OutputScalarKind, OutputTensor,
+        ParamInfo, PushInputTensor, SessionTuning,
+    },
+    voice_model::ModelBytes,
+};
+
+#[cfg(any(onnxruntime, feature = "tract"))]
+pub use crate::core::infer::runtimes::BuiltinRuntimeError;
+#[cfg(feature = "tract")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tract")))]
+pub use crate::core::infer::runtimes::tract::Tract;
@@ -10,8 +10,13 @@
 //! - **`link-onnxruntime`**: ONNX Runtimeをロード時動的リンクする。そのためビルドするためにはシステムにONNX
 //!   Runtimeがインストールされているか、`buildtime-download-onnxruntime`によるダウンロードを行う必要がある。iOSのような`dlopen`の利用が困難な環境でのみこちらを利用するべきである。_Note_:
 //!   [動的リンク対象のライブラリ名]は`onnxruntime`で固定。変更は`patchelf(1)`や`install_name_tool(1)`で行うこと。また、[ONNX RuntimeのGPU機能]を使うことは不可。
+//! - **`tract`**: Pure Rustの推論ライブラリである[tract]を用いた[`Tract`]を有効化する。ONNX
+//!   Runtimeを必要としないため、muslをターゲットとした静的リンクのビルドなどに向く。ただしCPUでのみ動作し、"vv-bin"形式のモデルは扱えない。
+//! - **`web-onnxruntime`**: WASM向け。JavaScriptの[onnxruntime-web]をONNX Runtimeの代わりとして用いる
+//!   [`nonblocking::Onnxruntime::load_web_once`]を有効化する。`load-onnxruntime`とは併用できない。
 //!
-//! このクレートの利用にあたっては上記の`load-onnxruntime`か`link-onnxruntime`のうちどちらかを有効にしなければならない。両方の有効化はコンパイルエラーとなる。[`Onnxruntime`]の初期化方法はこれらのフィーチャによって決まる。
+//! このクレートの利用にあたっては上記の`load-onnxruntime`か`link-onnxruntime`のうちどちらかを有効にしなければならない。両方の有効化はコンパイルエラーとなる。[`Onnxruntime`]の初期化方法はこれらのフィーチャによって決まる。ただし`tract`を有効にした場合は、どちらも有効にせずにONNX
+//! Runtimeを使わないことも可能。その場合はONNX Runtime関連のクレートに一切依存せず、[`Onnxruntime`]は存在しない。[`blocking::Synthesizer`]などの型引数`R`のデフォルトはフィーチャに依らずONNX Runtimeであるため、[`Tract`]を使うときは`Synthesizer<T, Tract>`のように型引数を明示する必要がある。
 //!
 //! # Build time environment variables
 //!
@@ -22,9 +27,12 @@
 //! [Cargoフィーチャ]: https://doc.rust-lang.org/stable/cargo/reference/features.html
 //! [CUDA]: https://onnxruntime.ai/docs/execution-providers/CUDA-ExecutionProvider.html
 //! [DirectML]: https://onnxruntime.ai/docs/execution-providers/DirectML-ExecutionProvider.html
+//! [onnxruntime-web]: https://onnxruntime.ai/docs/get-started/with-javascript/web.html
 //! [動的リンク対象のライブラリ名]:
 //! https://doc.rust-lang.org/cargo/reference/build-scripts.html#rustc-link-lib
 //! [`Onnxruntime`]: blocking::Onnxruntime
+//! [tract]: https://github.com/sonos/tract
+//! [`Tract`]: inference_runtime::Tract
 //! [ONNX RuntimeのGPU機能]: https://onnxruntime.ai/docs/execution-providers/
 //!
 //! # Examples
@@ -314,6 +322,9 @@
 #[cfg(all(not(doc), feature = "load-onnxruntime", feature = "link-onnxruntime"))]
 compile_error!("`load-onnxruntime` and `link-onnxruntime` cannot be enabled at the same time");
 
+#[cfg(all(not(doc), feature = "load-onnxruntime", feature = "web-onnxruntime"))]
+compile_error!("`load-onnxruntime` and `web-onnxruntime` cannot be enabled at the same time");
+
 #[cfg(all(not(feature = "load-onnxruntime"), feature = "link-onnxruntime"))]
 const _: () = {
     use dummy::*;
@@ -355,11 +366,89 @@ pub mod __doc {
     /// | `voicevox_synthesizer_delete` | 〃 |
     /// | `voicevox_voice_model_file_delete` | 〃 |
     /// | `voicevox_user_dict_delete` | 〃 |
+    /// | `voicevox_audio_query_delete` | 〃 |
     /// | `voicevox_error_result_to_message` | [`std::error::Error`]としてのエラー表示があるため |
+    /// | `voicevox_last_error_message` | 〃 |
+    /// | `voicevox_last_error_json` | 〃 |
     /// | `voicevox_frame_phoneme_validate` | 型システムで不正な状態を完全に防げるため |
+    /// | `VoicevoxTextAnalyzer` | [`blocking::TextAnalyzer`]を実装した任意の型を使えるため |
+    /// | `voicevox_synthesizer_new_with_text_analyzer` | 〃 |
+    /// | `VoicevoxLogLevel` | ログの扱いは[`tracing`]のSubscriberに委ねられるため |
+    /// | `VoicevoxLogCallback` | 〃 |
+    /// | `voicevox_set_log_callback` | 〃 |
+    /// | `voicevox_set_log_level` | 〃 |
+    /// | `VoicevoxJob` | [`nonblocking`]の`async fn`と、[`Future`]のdropによるキャンセルで表現できるため |
+    /// | `VoicevoxWavCallback` | 〃 |
+    /// | `VoicevoxJobOptions` | 〃 |
+    /// | `voicevox_make_default_job_options` | 〃 |
+    /// | `voicevox_synthesizer_synthesis_async` | 〃 |
+    /// | `voicevox_synthesizer_tts_from_kana_async` | 〃 |
+    /// | `voicevox_synthesizer_tts_async` | 〃 |
+    /// | `voicevox_synthesizer_frame_synthesis_async` | 〃 |
+    /// | `voicevox_job_cancel` | 〃 |
+    /// | `voicevox_job_delete` | 〃 |
+    /// | `VoicevoxAccentPhraseView` | [`AudioQuery`]のフィールドを直接読み書きできるため |
+    /// | `VoicevoxMoraView` | 〃 |
+    /// | `voicevox_audio_query_new_from_json` | 〃 |
+    /// | `voicevox_synthesizer_create_audio_query_handle` | 〃 |
+    /// | `voicevox_synthesizer_synthesis_from_handle` | 〃 |
+    /// | `voicevox_audio_query_create_json` | 〃 |
+    /// | `voicevox_audio_query_get_speed_scale` | 〃 |
+    /// | `voicevox_audio_query_set_speed_scale` | 〃 |
+    /// | `voicevox_audio_query_get_pitch_scale` | 〃 |
+    /// | `voicevox_audio_query_set_pitch_scale` | 〃 |
+    /// | `voicevox_audio_query_get_intonation_scale` | 〃 |
+    /// | `voicevox_audio_query_set_intonation_scale` | 〃 |
+    /// | `voicevox_audio_query_get_volume_scale` | 〃 |
+    /// | `voicevox_audio_query_set_volume_scale` | 〃 |
+    /// | `voicevox_audio_query_get_pre_phoneme_length` | 〃 |
+    /// | `voicevox_audio_query_set_pre_phoneme_length` | 〃 |
+    /// | `voicevox_audio_query_get_post_phoneme_length` | 〃 |
+    /// | `voicevox_audio_query_set_post_phoneme_length` | 〃 |
+    /// | `voicevox_audio_query_get_output_sampling_rate` | 〃 |
+    /// | `voicevox_audio_query_set_output_sampling_rate` | 〃 |
+    /// | `voicevox_audio_query_get_output_stereo` | 〃 |
+    /// | `voicevox_audio_query_set_output_stereo` | 〃 |
+    /// | `voicevox_audio_query_get_accent_phrases_length` | 〃 |
+    /// | `voicevox_audio_query_get_accent_phrase` | 〃 |
+    /// | `voicevox_audio_query_set_is_interrogative` | 〃 |
+    /// | `voicevox_audio_query_get_mora` | 〃 |
+    /// | `voicevox_audio_query_set_mora` | 〃 |
+    /// | `VoicevoxNoteView` | [`Score`]と[`FrameAudioQuery`]のフィールドを直接読み書きできるため |
+    /// | `VoicevoxFramePhonemeView` | 〃 |
+    /// | `voicevox_score_new_from_json` | 〃 |
+    /// | `voicevox_score_create_json` | 〃 |
+    /// | `voicevox_score_get_notes_length` | 〃 |
+    /// | `voicevox_score_get_note` | 〃 |
+    /// | `voicevox_synthesizer_create_sing_frame_audio_query_handle` | 〃 |
+    /// | `voicevox_score_delete` | 〃 |
+    /// | `voicevox_frame_audio_query_new_from_json` | 〃 |
+    /// | `voicevox_synthesizer_frame_synthesis_from_handle` | 〃 |
+    /// | `voicevox_frame_audio_query_create_json` | 〃 |
+    /// | `voicevox_frame_audio_query_get_frames_length` | 〃 |
+    /// | `voicevox_frame_audio_query_get_f0` | 〃 |
+    /// | `voicevox_frame_audio_query_set_f0` | 〃 |
+    /// | `voicevox_frame_audio_query_get_volume` | 〃 |
+    /// | `voicevox_frame_audio_query_set_volume` | 〃 |
+    /// | `voicevox_frame_audio_query_get_phonemes_length` | 〃 |
+    /// | `voicevox_frame_audio_query_get_phoneme` | 〃 |
+    /// | `voicevox_frame_audio_query_get_volume_scale` | 〃 |
+    /// | `voicevox_frame_audio_query_set_volume_scale` | 〃 |
+    /// | `voicevox_frame_audio_query_get_output_sampling_rate` | 〃 |
+    /// | `voicevox_frame_audio_query_set_output_sampling_rate` | 〃 |
+    /// | `voicevox_frame_audio_query_get_output_stereo` | 〃 |
+    /// | `voicevox_frame_audio_query_set_output_stereo` | 〃 |
+    /// | `voicevox_frame_audio_query_delete` | 〃 |
     ///
     /// [C API]: https://voicevox.github.io/voicevox_core/apis/c_api/voicevox__core_8h.html
     /// [Rustのデストラクタ機構]: https://doc.rust-lang.org/reference/destructors.html
+    /// [`tracing`]: https://docs.rs/crate/tracing
+    /// [`blocking::TextAnalyzer`]: crate::blocking::TextAnalyzer
+    /// [`nonblocking`]: crate::nonblocking
+    /// [`Future`]: std::future::Future
+    /// [`AudioQuery`]: crate::AudioQuery
+    /// [`Score`]: crate::Score
+    /// [`FrameAudioQuery`]: crate::FrameAudioQuery
     #[doc(alias(
         "VoicevoxLoadOnnxruntimeOptions",
         "VoicevoxInitializeOptions",
@@ -375,8 +464,79 @@ pub mod __doc {
         "voicevox_synthesizer_delete",
         "voicevox_voice_model_file_delete",
         "voicevox_user_dict_delete",
+        "voicevox_audio_query_delete",
         "voicevox_error_result_to_message",
-        "voicevox_frame_phoneme_validate"
+        "voicevox_last_error_message",
+        "voicevox_last_error_json",
+        "voicevox_frame_phoneme_validate",
+        "VoicevoxTextAnalyzer",
+        "voicevox_synthesizer_new_with_text_analyzer",
+        "VoicevoxLogLevel",
+        "VoicevoxLogCallback",
+        "voicevox_set_log_callback",
+        "voicevox_set_log_level",
+        "VoicevoxJob",
+        "VoicevoxWavCallback",
+        "VoicevoxJobOptions",
+        "voicevox_make_default_job_options",
+        "voicevox_synthesizer_synthesis_async",
+        "voicevox_synthesizer_tts_from_kana_async",
+        "voicevox_synthesizer_tts_async",
+        "voicevox_synthesizer_frame_synthesis_async",
+        "voicevox_job_cancel",
+        "voicevox_job_delete",
+        "VoicevoxAccentPhraseView",
+        "VoicevoxMoraView",
+        "voicevox_audio_query_new_from_json",
+        "voicevox_synthesizer_create_audio_query_handle",
+        "voicevox_synthesizer_synthesis_from_handle",
+        "voicevox_audio_query_create_json",
+        "voicevox_audio_query_get_speed_scale",
+        "voicevox_audio_query_set_speed_scale",
+        "voicevox_audio_query_get_pitch_scale",
+        "voicevox_audio_query_set_pitch_scale",
+        "voicevox_audio_query_get_intonation_scale",
+        "voicevox_audio_query_set_intonation_scale",
+        "voicevox_audio_query_get_volume_scale",
+        "voicevox_audio_query_set_volume_scale",
+        "voicevox_audio_query_get_pre_phoneme_length",
+        "voicevox_audio_query_set_pre_phoneme_length",
+        "voicevox_audio_query_get_post_phoneme_length",
+        "voicevox_audio_query_set_post_phoneme_length",
+        "voicevox_audio_query_get_output_sampling_rate",
+        "voicevox_audio_query_set_output_sampling_rate",
+        "voicevox_audio_query_get_output_stereo",
+        "voicevox_audio_query_set_output_stereo",
+        "voicevox_audio_query_get_accent_phrases_length",
+        "voicevox_audio_query_get_accent_phrase",
+        "voicevox_audio_query_set_is_interrogative",
+        "voicevox_audio_query_get_mora",
+        "voicevox_audio_query_set_mora",
+        "VoicevoxNoteView",
+        "VoicevoxFramePhonemeView",
+        "voicevox_score_new_from_json",
+        "voicevox_score_create_json",
+        "voicevox_score_get_notes_length",
+        "voicevox_score_get_note",
+        "voicevox_synthesizer_create_sing_frame_audio_query_handle",
+        "voicevox_score_delete",
+        "voicevox_frame_audio_query_new_from_json",
+        "voicevox_synthesizer_frame_synthesis_from_handle",
+        "voicevox_frame_audio_query_create_json",
+        "voicevox_frame_audio_query_get_frames_length",
+        "voicevox_frame_audio_query_get_f0",
+        "voicevox_frame_audio_query_set_f0",
+        "voicevox_frame_audio_query_get_volume",
+        "voicevox_frame_audio_query_set_volume",
+        "voicevox_frame_audio_query_get_phonemes_length",
+        "voicevox_frame_audio_query_get_phoneme",
+        "voicevox_frame_audio_query_get_volume_scale",
+        "voicevox_frame_audio_query_set_volume_scale",
+        "voicevox_frame_audio_query_get_output_sampling_rate",
+        "voicevox_frame_audio_query_set_output_sampling_rate",
+        "voicevox_frame_audio_query_get_output_stereo",
+        "voicevox_frame_audio_query_set_output_stereo",
+        "voicevox_frame_audio_query_delete"
     ))]
     pub mod C_APIには存在するがRust_APIには存在しないアイテム {}
 
@@ -398,6 +558,10 @@ pub mod __doc {
     ///     - [`Note`]
     ///         - [`NoteId`]
     ///         - [`OptionalLyric`]
+    ///         - [`Dynamics`]
+    /// - [`SongTrack`]
+    ///     - `Score`
+    ///     - `StyleId`
     /// - [`FrameAudioQuery`]
     ///     - [`FramePhoneme`]
     ///         - [`Phoneme`]
@@ -422,12 +586,15 @@ pub mod __doc {
     /// [`UserDictWord`]: crate::UserDictWord
     /// [`UserDictWordType`]: crate::UserDictWordType
     /// [`AudioQuery`]: crate::AudioQuery
+    /// [`Score`]: crate::Score
+    /// [`FrameAudioQuery`]: crate::FrameAudioQuery
     /// [`AccentPhrase`]: crate::AccentPhrase
     /// [`Mora`]: crate::Mora
     /// [`Score`]: crate::Score
     /// [`Note`]: crate::Note
     /// [`NoteId`]: crate::NoteId
     /// [`OptionalLyric`]: crate::OptionalLyric
+    /// [`Dynamics`]: crate::Dynamics
     /// [`FrameAudioQuery`]: crate::FrameAudioQuery
     /// [`FramePhoneme`]: crate::FramePhoneme
     /// [`Phoneme`]: crate::Phoneme
@@ -456,7 +623,10 @@ mod version;
 #[doc(hidden)]
 pub mod __internal;
 pub mod blocking;
+pub mod inference_runtime;
 pub mod nonblocking;
+#[cfg(not(target_family = "wasm"))]
+pub mod vvm;
 
 #[cfg(test)]
 mod test_util;
@@ -470,7 +640,11 @@ use rstest_reuse;
 
 pub use self::{
     core::{
-        devices::SupportedDevices,
+        devices::{
+            ExecutionProvider, InferenceOperationKind, OperationDevice, OperationPlacement,
+            SupportedDevices,
+        },
+        infer::GraphOptimizationLevel,
         metas::{CharacterMeta, CharacterVersion, StyleId, StyleMeta, StyleType, VoiceModelMeta},
         status::OnExistingVoiceModelId,
         voice_model::VoiceModelId,
@@ -478,7 +652,10 @@ pub use self::{
     engine::{
         Consonant, NonConsonant, Phoneme, SamplingRate, Sil,
         song::{
-            queries::{FrameAudioQuery, FramePhoneme, Key, Note, NoteId, OptionalLyric, Score},
+            mix::SongTrack,
+            queries::{
+                Dynamics, FrameAudioQuery, FramePhoneme, Key, Note, NoteId, OptionalLyric, Score,
+            },
             validate::ensure_compatible,
         },
         talk::{
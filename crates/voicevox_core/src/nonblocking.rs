@@ -15,17 +15,25 @@
 //! [`cpu_num_threads`]: crate::nonblocking::synthesizer::Builder::cpu_num_threads
 
 pub use crate::{
-    core::{
-        infer::runtimes::onnxruntime::nonblocking::Onnxruntime,
-        voice_model::nonblocking::VoiceModelFile,
-    },
+    core::voice_model::nonblocking::VoiceModelFile,
     engine::talk::{
-        open_jtalk::nonblocking::OpenJtalk, text_analyzer::nonblocking::TextAnalyzer,
-        user_dict::dict::nonblocking::UserDict,
+        text_analyzer::nonblocking::TextAnalyzer, user_dict::dict::nonblocking::UserDict,
     },
     synthesizer::nonblocking::Synthesizer,
 };
 
+#[cfg(onnxruntime)]
+pub use crate::core::infer::runtimes::onnxruntime::nonblocking::Onnxruntime;
+
+#[cfg(not(target_family = "wasm"))]
+pub use crate::engine::talk::open_jtalk::nonblocking::OpenJtalk;
+
+// TODO: 後で復活させる
+// https://github.com/VOICEVOX/voicevox_core/issues/970
+#[doc(hidden)]
+pub use crate::synthesizer::nonblocking::AudioFeature as __AudioFeature;
+
+#[cfg(onnxruntime)]
 pub mod onnxruntime {
     #[cfg(feature = "load-onnxruntime")]
     #[cfg_attr(docsrs, doc(cfg(feature = "load-onnxruntime")))]
@@ -34,6 +42,11 @@ pub mod onnxruntime {
 
 pub mod synthesizer {
     pub use crate::synthesizer::nonblocking::{
-        Builder, FrameSynthesis, LoadVoiceModel, Synthesis, Tts, TtsFromKana,
+        Builder, FrameRender, FrameSynthesis, LoadVoiceModel, RenderSongProject, Synthesis, Tts,
+        TtsFromKana,
     };
+
+    // TODO: 後で復活させる
+    // https://github.com/VOICEVOX/voicevox_core/issues/970
+    //pub use crate::synthesizer::nonblocking::PrecomputeRender;
 }
@@ -8,8 +8,10 @@ use educe::Educe;
 use enum_map::enum_map;
 use futures_util::TryFutureExt as _;
 use std::{
+    collections::BTreeMap,
     fmt::{self, Debug},
     marker::PhantomData,
+    num::NonZero,
     sync::Arc,
 };
 use tracing::info;
@@ -22,45 +24,53 @@ use crate::{
     collections::{NonEmptyIterator as _, NonEmptySlice, NonEmptyVec},
     core::{
         Array1ExtForPostProcess as _, Array1ExtForPreProcess as _, ArrayExt as _,
-        devices::{self, DeviceSpec, GpuSpec},
+        devices::{
+            self, DeviceSpec, ExecutionProvider, InferenceOperationKind, OperationDevice,
+            OperationPlacement,
+        },
         ensure_minimum_phoneme_length, ensure_non_nan_finite, ensure_positive_finite,
         infer::{
-            self, InferenceRuntime, InferenceSessionOptions,
+            self, InferenceRuntime, InferenceSessionOptions, SessionTuning,
             domains::{
-                DecodeInput, DecodeOutput, FrameDecodeDomain, FrameDecodeOperation,
-                GenerateFullIntermediateInput, GenerateFullIntermediateOutput, InferenceDomainMap,
+                DecodeInput, DecodeOutput, FrameDecodeDomain, GenerateFullIntermediateInput,
+                GenerateFullIntermediateOutput, InferenceDomainMap,
                 PredictSingConsonantLengthInput, PredictSingConsonantLengthOutput,
                 PredictSingF0Input, PredictSingF0Output, PredictSingVolumeInput,
                 PredictSingVolumeOutput, RenderAudioSegmentInput, RenderAudioSegmentOutput,
-                SfDecodeInput, SfDecodeOutput, SingingTeacherDomain, SingingTeacherOperation,
-                StreamingTalkDomain, StreamingTalkOperation, TalkDomain, TalkOperation,
-                streaming_talk, talk,
+                SfDecodeInput, SfDecodeOutput, SingingTeacherDomain, StreamingTalkDomain,
+                TalkDomain, streaming_talk, talk,
             },
+            runtimes::DefaultInferenceRuntime,
         },
         pad_decoder_feature,
-        status::Status,
+        status::{PinnedModel, Status},
         voice_model,
     },
     engine::{
-        DEFAULT_SAMPLING_RATE, IteratorExt as _, PhonemeCode,
+        DEFAULT_SAMPLING_RATE, IteratorExt as _, PhonemeCode, SamplingRate, s16le_from_f32_pcm,
         song::{
             self,
-            interpret::{ConsonantLengthsFeature, PhonemeFeature, SfDecoderFeature},
+            interpret::{
+                ConsonantLengthsFeature, PhonemeFeature, SF_DECODE_MARGIN, SfDecoderFeature,
+            },
+            mix::{SongTrack, StereoMixer},
             queries::{FrameAudioQuery, FramePhoneme, Score},
             validate::{ValidatedNote, ValidatedScore},
         },
+        stereo_to_s16le_pcm,
         talk::{
             DecoderFeature, LengthedPhoneme, ValidatedAccentPhrase, ValidatedAudioQuery,
             ValidatedMora, create_kana, initial_process, parse_kana, split_mora,
         },
-        to_s16le_pcm, wav_from_s16le,
+        to_f32_pcm, to_s16le_pcm, wav_from_s16le,
     },
-    error::ErrorRepr,
+    error::{ErrorRepr, InvalidQueryError, InvalidQueryErrorSource},
     future::FutureExt as _,
     numerics::{non_nan_finite_f32, positive_finite_f32},
 };
 
 pub const DEFAULT_CPU_NUM_THREADS: u16 = 0;
+pub const DEFAULT_SESSION_REPLICAS: NonZero<usize> = NonZero::<usize>::MIN;
 pub const DEFAULT_ENABLE_INTERROGATIVE_UPSPEAK: bool = true;
 pub const DEFAULT_HEAVY_INFERENCE_CANCELLABLE: bool =
     <BlockingThreadPool as infer::AsyncExt>::DEFAULT_HEAVY_INFERENCE_CANCELLABLE;
@@ -115,6 +125,22 @@ impl<A: infer::AsyncExt> Default for FrameSynthesisOptions<A> {
     }
 }
 
+#[derive(derive_more::Debug)]
+#[debug(bound(A::Cancellable: Debug))]
+struct RenderSongProjectOptions<A: infer::AsyncExt> {
+    output_sampling_rate: SamplingRate,
+    cancellable: A::Cancellable,
+}
+
+impl<A: infer::AsyncExt> Default for RenderSongProjectOptions<A> {
+    fn default() -> Self {
+        Self {
+            output_sampling_rate: SamplingRate::default(),
+            cancellable: A::DEFAULT_HEAVY_INFERENCE_CANCELLABLE,
+        }
+    }
+}
+
 /// ハードウェアアクセラレーションモードを設定する設定値。
 #[cfg_attr(doc, doc(alias = "VoicevoxAccelerationMode"))]
 #[expect(
@@ -138,6 +164,15 @@ pub enum AccelerationMode {
 struct InitializeOptions {
     acceleration_mode: AccelerationMode,
     cpu_num_threads: u16,
+    session_replicas: NonZero<usize>,
+    memory_budget: Option<u64>,
+    session_tuning: SessionTuning,
+    /// [`AccelerationMode::Auto`]および[`AccelerationMode::Gpu`]で試すExecution Provider。優先度順。
+    execution_providers: Vec<ExecutionProvider>,
+    /// 推論の操作ごとの配置。指定の無いものは[`OperationPlacement::default_for`]に従う。
+    operation_placements: BTreeMap<InferenceOperationKind, OperationPlacement>,
+    /// 登録された音声モデルを自動で読み込むときの署名の検証に用いる。`None`であれば署名を要求しない。
+    trusted_public_keys: Option<Vec<[u8; 32]>>,
 }
 
 impl Default for InitializeOptions {
@@ -145,13 +180,22 @@ impl Default for InitializeOptions {
         Self {
             acceleration_mode: Default::default(),
             cpu_num_threads: DEFAULT_CPU_NUM_THREADS,
+            session_replicas: DEFAULT_SESSION_REPLICAS,
+            memory_budget: None,
+            session_tuning: SessionTuning::default(),
+            execution_providers: ExecutionProvider::defaults(),
+            operation_placements: BTreeMap::new(),
+            trusted_public_keys: None,
         }
     }
 }
 
-#[derive(Default, Clone, Copy, Debug)]
+#[derive(Default, Clone, Debug)]
 struct LoadVoiceModelOptions {
     on_existing: OnExistingVoiceModelId,
+    style_filter: voice_model::StyleFilter,
+    /// `None`であれば、署名を要求しない。
+    trusted_public_keys: Option<Vec<[u8; 32]>>,
 }
 
 trait AsyncExt: infer::AsyncExt {
@@ -177,7 +221,7 @@ impl AsyncExt for BlockingThreadPool {
         F: FnOnce() -> T + Send + 'static,
         T: Send + 'static,
     {
-        ::blocking::unblock(f).await
+        crate::task::asyncify(f).await
     }
 }
 
@@ -191,18 +235,16 @@ pub const MARGIN: usize = 14;
 fn crop_with_margin(
     audio: &AudioFeature,
     range: std::ops::Range<usize>,
-) -> ndarray::ArrayView2<'_, f32> {
-    if range.start > audio.frame_length() || range.end > audio.frame_length() {
-        panic!(
-            "{range:?} is out of range for audio feature of length {frame_length}",
-            frame_length = audio.frame_length(),
-        );
-    }
-    if range.start > range.end {
-        panic!("{range:?} is invalid because start > end",);
+) -> std::result::Result<ndarray::ArrayView2<'_, f32>, InvalidQueryError> {
+    if range.start > range.end || range.end > audio.frame_length() {
+        return Err(InvalidQueryError {
+            what: "フレーム区間",
+            value: Some(Box::new(range)),
+            source: Some(InvalidQueryErrorSource::OutOfRangeFrames),
+        });
     }
     let range = range.start..range.end + 2 * MARGIN;
-    audio.internal_state.slice(ndarray::s![range, ..])
+    Ok(audio.internal_state.slice(ndarray::s![range, ..]))
 }
 /// 追加した安全マージンを生成音声から取り除く
 fn trim_margin_from_wave(wave_with_margin: ndarray::Array1<f32>) -> ndarray::Array1<f32> {
@@ -235,8 +277,8 @@ impl AudioFeature {
 }
 
 #[derive(derive_more::Debug)]
-struct Inner<T, A: Async> {
-    status: Arc<Status<crate::blocking::Onnxruntime>>,
+struct Inner<T, A: Async, R: InferenceRuntime> {
+    status: Arc<Status<R>>,
     text_analyzer: T,
     use_gpu: bool,
     #[debug(ignore)]
@@ -244,17 +286,17 @@ struct Inner<T, A: Async> {
 }
 
 #[derive(derive_more::Debug)]
-struct InnerRefWithoutTextAnalyzer<'a, A: Async> {
-    status: &'a Arc<Status<crate::blocking::Onnxruntime>>,
+struct InnerRefWithoutTextAnalyzer<'a, A: Async, R: InferenceRuntime> {
+    status: &'a Arc<Status<R>>,
     use_gpu: bool,
     #[debug(ignore)]
     _marker: PhantomData<fn(A) -> A>,
 }
 
-impl<T> From<Inner<T, BlockingThreadPool>>
-    for Inner<AssumeSingleTasked<AssumeBlockable<T>>, SingleTasked>
+impl<T, R: InferenceRuntime> From<Inner<T, BlockingThreadPool, R>>
+    for Inner<AssumeSingleTasked<AssumeBlockable<T>>, SingleTasked, R>
 {
-    fn from(from: Inner<T, BlockingThreadPool>) -> Self {
+    fn from(from: Inner<T, BlockingThreadPool, R>) -> Self {
         Self {
             status: from.status,
             text_analyzer: AssumeSingleTasked(AssumeBlockable(from.text_analyzer)),
@@ -264,9 +306,9 @@ impl<T> From<Inner<T, BlockingThreadPool>>
     }
 }
 
-#[derive(derive_more::Debug)]
+#[derive(Clone, derive_more::Debug)]
 #[debug("{_0:?}")]
-struct AssumeSingleTasked<T>(T);
+pub struct AssumeSingleTasked<T>(T);
 
 impl<T: crate::blocking::TextAnalyzer> crate::nonblocking::TextAnalyzer for AssumeSingleTasked<T> {
     async fn analyze(&self, text: &str) -> anyhow::Result<Vec<AccentPhrase>> {
@@ -282,22 +324,34 @@ impl<T: crate::nonblocking::TextAnalyzer> crate::blocking::TextAnalyzer for Assu
     }
 }
 
-impl<T, A: AsyncExt> Inner<T, A> {
-    fn new(
-        onnxruntime: &'static crate::blocking::Onnxruntime,
-        text_analyzer: T,
-        options: &InitializeOptions,
-    ) -> Result<Self> {
+impl<T: Clone, R: InferenceRuntime> Inner<T, SingleTasked, R> {
+    fn to_nonblocking(&self) -> Inner<T, BlockingThreadPool, R> {
+        Inner {
+            status: self.status.clone(),
+            text_analyzer: self.text_analyzer.clone(),
+            use_gpu: self.use_gpu,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, A: AsyncExt, R: InferenceRuntime> Inner<T, A, R> {
+    fn new(rt: &'static R, text_analyzer: T, options: &InitializeOptions) -> Result<Self> {
         #[cfg(windows)]
         list_windows_video_cards();
 
-        let test_gpus = || {
-            info!("GPUをテストします:");
-            let availabilities = devices::test_gpus(
-                GpuSpec::defaults(),
-                crate::blocking::Onnxruntime::DISPLAY_NAME,
-                onnxruntime.supported_devices()?,
-                |gpu| onnxruntime.test_gpu(gpu),
+        let test_execution_providers = |gpu_only: bool| {
+            info!("Execution Providerをテストします:");
+            let availabilities = devices::test_execution_providers(
+                options
+                    .execution_providers
+                    .iter()
+                    .copied()
+                    .filter(|ep| !gpu_only || ep.is_gpu()),
+                R::DISPLAY_NAME,
+                rt.supported_devices()
+                    .map_err(|err| ErrorRepr::GetSupportedDevices(err.into()))?,
+                |ep| rt.test_execution_provider(ep).map_err(Into::into),
             );
             for line in availabilities.to_string().lines() {
                 info!("  {line}");
@@ -306,16 +360,16 @@ impl<T, A: AsyncExt> Inner<T, A> {
         };
 
         let device_for_heavy = match options.acceleration_mode {
-            AccelerationMode::Auto => match *test_gpus()?.oks() {
+            AccelerationMode::Auto => match *test_execution_providers(false)?.oks() {
                 [] => DeviceSpec::Cpu,
-                [gpu, ..] => DeviceSpec::Gpu(gpu),
+                [ep, ..] => DeviceSpec::ExecutionProvider(ep),
             },
             AccelerationMode::Cpu => DeviceSpec::Cpu,
             AccelerationMode::Gpu => {
-                let availabilities = test_gpus()?;
+                let availabilities = test_execution_providers(true)?;
                 match *availabilities.oks() {
                     [] => return Err(ErrorRepr::GpuSupport(availabilities).into()),
-                    [gpu, ..] => DeviceSpec::Gpu(gpu),
+                    [ep, ..] => DeviceSpec::ExecutionProvider(ep),
                 }
             }
             AccelerationMode::__NonExhaustive => unreachable!(),
@@ -323,42 +377,49 @@ impl<T, A: AsyncExt> Inner<T, A> {
 
         info!("{device_for_heavy}を利用します");
 
-        // 軽いモデルはこちらを使う
-        let light_session_options =
-            InferenceSessionOptions::new(options.cpu_num_threads, DeviceSpec::Cpu);
+        let placement = |op: InferenceOperationKind| {
+            options
+                .operation_placements
+                .get(&op)
+                .copied()
+                .unwrap_or_else(|| OperationPlacement::default_for(op))
+        };
 
-        // 重いモデルはこちらを使う
-        let heavy_session_options =
-            InferenceSessionOptions::new(options.cpu_num_threads, device_for_heavy);
+        let session_options = |op: InferenceOperationKind| {
+            let OperationPlacement {
+                device,
+                cpu_num_threads,
+            } = placement(op);
+            let device = match device {
+                OperationDevice::Cpu => DeviceSpec::Cpu,
+                OperationDevice::Accelerator => device_for_heavy,
+                OperationDevice::__NonExhaustive => unreachable!(),
+            };
+            InferenceSessionOptions::new(
+                cpu_num_threads.unwrap_or(options.cpu_num_threads),
+                device,
+                options.session_tuning.clone(),
+            )
+        };
 
         let status = Status::new(
-            onnxruntime,
+            rt,
             InferenceDomainMap {
-                talk: enum_map! {
-                    TalkOperation::PredictDuration | TalkOperation::PredictIntonation => {
-                        light_session_options
-                    }
-                    TalkOperation::Decode => heavy_session_options,
-                },
-                streaming_talk: enum_map! {
-                    StreamingTalkOperation::PredictDuration
-                    | StreamingTalkOperation::PredictIntonation
-                    | StreamingTalkOperation::GenerateFullIntermediate => light_session_options,
-                    StreamingTalkOperation::RenderAudioSegment => heavy_session_options,
-                },
-                singing_teacher: enum_map! {
-                    SingingTeacherOperation::PredictSingConsonantLength
-                    | SingingTeacherOperation::PredictSingF0
-                    | SingingTeacherOperation::PredictSingVolume => light_session_options,
-                },
-                frame_decode: enum_map! {
-                    FrameDecodeOperation::SfDecode => heavy_session_options,
-                },
+                talk: enum_map! { op => session_options(op.into()) },
+                streaming_talk: enum_map! { op => session_options(op.into()) },
+                singing_teacher: enum_map! { op => session_options(op.into()) },
+                frame_decode: enum_map! { op => session_options(op.into()) },
             },
+            options.session_replicas,
+            options.memory_budget,
+            options.trusted_public_keys,
         )
         .into();
 
-        let use_gpu = matches!(device_for_heavy, DeviceSpec::Gpu(_));
+        let use_gpu = device_for_heavy.is_gpu()
+            && InferenceOperationKind::ALL
+                .iter()
+                .any(|&op| placement(op).device == OperationDevice::Accelerator);
 
         Ok(Self {
             status,
@@ -368,7 +429,7 @@ impl<T, A: AsyncExt> Inner<T, A> {
         })
     }
 
-    fn without_text_analyzer(&self) -> InnerRefWithoutTextAnalyzer<'_, A> {
+    fn without_text_analyzer(&self) -> InnerRefWithoutTextAnalyzer<'_, A, R> {
         InnerRefWithoutTextAnalyzer {
             status: &self.status,
             use_gpu: self.use_gpu,
@@ -397,11 +458,12 @@ impl<T, A: AsyncExt> Inner<T, A> {
 trait AsInner {
     type TextAnalyzer;
     type Async: AsyncExt;
-    fn status(&self) -> &Arc<Status<crate::blocking::Onnxruntime>>;
+    type Runtime: InferenceRuntime;
+    fn status(&self) -> &Arc<Status<Self::Runtime>>;
     fn text_analyzer(&self) -> &Self::TextAnalyzer;
     fn use_gpu(&self) -> bool;
 
-    fn onnxruntime(&self) -> &'static crate::blocking::Onnxruntime {
+    fn runtime(&self) -> &'static Self::Runtime {
         self.status().rt
     }
 
@@ -422,12 +484,22 @@ trait AsInner {
             return Ok(());
         }
 
-        let model_bytes = model.read_inference_models().await?;
+        let model_bytes = model
+            .read_inference_models(
+                &options.style_filter,
+                options.trusted_public_keys.as_deref(),
+            )
+            .await?;
 
         let status = self.status().clone();
         let header = model.header().clone();
         Self::Async::unblock(move || {
-            status.insert_model(&header, &model_bytes, options.on_existing)
+            status.insert_model(
+                &header,
+                &model_bytes,
+                &options.style_filter,
+                options.on_existing,
+            )
         })
         .await
     }
@@ -436,6 +508,33 @@ trait AsInner {
         self.status().unload_model(voice_model_id)
     }
 
+    fn register_voice_model(&self, model: voice_model::Inner<Self::Async>) {
+        self.status().register_model(Arc::new(model));
+    }
+
+    fn unregister_voice_model(&self, voice_model_id: VoiceModelId) -> Result<()> {
+        self.status().unregister_model(voice_model_id)
+    }
+
+    fn is_registered_voice_model(&self, voice_model_id: VoiceModelId) -> bool {
+        self.status().is_registered_model(voice_model_id)
+    }
+
+    /// `style_id`を持つ音声モデルが登録されていて、かつ読み込まれていなければ読み込む。
+    ///
+    /// `style_id`を持つ音声モデルが読み込まれていれば、それを`PinnedModel`として返す。返り値を
+    /// 保持している間、その音声モデルは`memory_budget`によってアンロードされない。
+    async fn autoload(&self, style_id: StyleId) -> Result<Option<PinnedModel<Self::Runtime>>> {
+        if let Some(pinned) = self.status().pin(style_id) {
+            return Ok(Some(pinned));
+        }
+        if !self.status().needs_autoload(style_id) {
+            return Ok(None);
+        }
+        let status = self.status().clone();
+        Self::Async::unblock(move || status.autoload(style_id)).await
+    }
+
     fn is_loaded_voice_model(&self, voice_model_id: VoiceModelId) -> bool {
         self.status().is_loaded_model(voice_model_id)
     }
@@ -477,18 +576,30 @@ trait AsInner {
     }
 
     async fn render(&self, audio: &AudioFeature, range: std::ops::Range<usize>) -> Result<Vec<u8>> {
+        let wave = self.render_wave(audio, range).await?;
+        Ok(s16le_from_f32_pcm(&wave))
+    }
+
+    /// [`render`]と同じことを行い、16bit PCMにする代わりに`[-1.0, 1.0]`の範囲の音声波形を返す。
+    ///
+    /// [`render`]: Self::render
+    async fn render_wave(
+        &self,
+        audio: &AudioFeature,
+        range: std::ops::Range<usize>,
+    ) -> Result<Vec<f32>> {
         // TODO: 44.1kHzなどの対応
-        if range.is_empty() {
-            // FIXME: `start>end`に対してパニックせずに正常に空を返してしまうのでは？
+        let is_empty = range.is_empty();
+        let spec_segment = crop_with_margin(audio, range)?;
+        if is_empty {
             // 指定区間が空のときは早期リターン
             return Ok(vec![]);
         }
-        let spec_segment = crop_with_margin(audio, range);
         let wave_with_margin = self
             .render_audio_segment(spec_segment.to_owned(), audio.style_id)
             .await?;
         let wave = trim_margin_from_wave(wave_with_margin);
-        Ok(to_s16le_pcm(
+        Ok(to_f32_pcm(
             wave.as_slice()
                 .expect("`trim_margin_from_wave` should just trim an array"),
             &audio.audio_query,
@@ -501,6 +612,25 @@ trait AsInner {
         style_id: StyleId,
         options: &SynthesisOptions<Self::Async>,
     ) -> Result<Vec<u8>> {
+        let wave = self.synthesis_wave(audio_query, style_id, options).await?;
+        Ok(wav_from_s16le(
+            &s16le_from_f32_pcm(&wave),
+            audio_query.output_sampling_rate.get().get(),
+            audio_query.output_stereo,
+        ))
+    }
+
+    /// [`synthesis`]と同じことを行い、WAVにする代わりに`[-1.0, 1.0]`の範囲の音声波形を返す。
+    ///
+    /// [`synthesis`]: Self::synthesis
+    async fn synthesis_wave(
+        &self,
+        audio_query: &AudioQuery,
+        style_id: StyleId,
+        options: &SynthesisOptions<Self::Async>,
+    ) -> Result<Vec<f32>> {
+        let _pinned = self.autoload(style_id).await?;
+
         if self.status().contains_domain::<TalkDomain>(style_id) {
             let audio_query = audio_query.to_validated()?;
             let DecoderFeature { f0, phoneme } =
@@ -515,21 +645,12 @@ trait AsInner {
                     options.cancellable,
                 )
                 .await?;
-            return Ok(wav_from_s16le(
-                &to_s16le_pcm(wave, &audio_query),
-                audio_query.output_sampling_rate.get().get(),
-                audio_query.output_stereo,
-            ));
+            return Ok(to_f32_pcm(wave, &audio_query));
         }
         let audio = self
             .precompute_render(audio_query, style_id, options)
             .await?;
-        let pcm = self.render(&audio, 0..audio.frame_length()).await?;
-        Ok(wav_from_s16le(
-            &pcm,
-            audio_query.output_sampling_rate.get().get(),
-            audio_query.output_stereo,
-        ))
+        self.render_wave(&audio, 0..audio.frame_length()).await
     }
 
     async fn create_accent_phrases_from_kana(
@@ -802,6 +923,8 @@ trait AsInner {
     ) -> Result<FrameAudioQuery> {
         let ValidatedScore { notes } = &score.try_into()?;
 
+        let _pinned = self.autoload(style_id).await?;
+
         let ConsonantLengthsFeature {
             note_lengths,
             note_constants,
@@ -860,18 +983,31 @@ trait AsInner {
             )
             .collect::<Vec<_>>();
 
-        let (phonemes_by_frame, keys_by_frame) =
+        let frame_phoneme_note_pairs =
             song::validate::frame_phoneme_note_pairs(&frame_phonemes, notes.as_ref())
-                .expect("`frame_phonemes`' phonemes should come from `notes`")
-                .flat_map(|(p, n)| song::interpret::repeat_phoneme_code_and_key(p, n))
-                .unzip_into_array1s();
+                .expect("`frame_phonemes`' phonemes should come from `notes`");
+
+        let (phonemes_by_frame, keys_by_frame) = frame_phoneme_note_pairs
+            .clone()
+            .flat_map(|(p, n)| song::interpret::repeat_phoneme_code_and_key(p, n))
+            .unzip_into_array1s();
+
+        let notes_by_frame = frame_phoneme_note_pairs
+            .flat_map(|(p, n)| song::interpret::repeat_note(p, n))
+            .collect::<Vec<_>>();
 
         let f0s = self
             .create_sing_frame_f0_(phonemes_by_frame.clone(), keys_by_frame.clone(), style_id)
             .await?;
 
         let volumes = self
-            .create_sing_frame_volume_(phonemes_by_frame, keys_by_frame, &f0s, style_id)
+            .create_sing_frame_volume_(
+                phonemes_by_frame,
+                keys_by_frame,
+                &f0s,
+                &notes_by_frame,
+                style_id,
+            )
             .await?;
 
         Ok(FrameAudioQuery {
@@ -908,6 +1044,8 @@ trait AsInner {
         keys_by_frame: ndarray::Array1<i64>,
         style_id: StyleId,
     ) -> Result<Vec<PositiveFinite<f32>>> {
+        let _pinned = self.autoload(style_id).await?;
+
         if phonemes_by_frame.is_empty() && keys_by_frame.is_empty() {
             // 一貫性の観点から、推論を行わない場合でも`StyleNotFound`エラーが出るようにする。
             self.status().ids_for::<SingingTeacherDomain>(style_id)?;
@@ -937,15 +1075,23 @@ trait AsInner {
 
         frame_audio_query.warn_for_f0_len();
 
-        let (phonemes_by_frame, keys_by_frame) =
-            song::validate::frame_phoneme_note_pairs(&frame_audio_query.phonemes, notes.as_ref())?
-                .flat_map(|(p, n)| song::interpret::repeat_phoneme_code_and_key(p, n))
-                .unzip_into_array1s();
+        let frame_phoneme_note_pairs =
+            song::validate::frame_phoneme_note_pairs(&frame_audio_query.phonemes, notes.as_ref())?;
+
+        let (phonemes_by_frame, keys_by_frame) = frame_phoneme_note_pairs
+            .clone()
+            .flat_map(|(p, n)| song::interpret::repeat_phoneme_code_and_key(p, n))
+            .unzip_into_array1s();
+
+        let notes_by_frame = frame_phoneme_note_pairs
+            .flat_map(|(p, n)| song::interpret::repeat_note(p, n))
+            .collect::<Vec<_>>();
 
         self.create_sing_frame_volume_(
             phonemes_by_frame,
             keys_by_frame,
             &frame_audio_query.f0,
+            &notes_by_frame,
             style_id,
         )
         .await
@@ -964,8 +1110,11 @@ trait AsInner {
         phonemes_by_frame: ndarray::Array1<i64>,
         keys_by_frame: ndarray::Array1<i64>,
         f0s: &[PositiveFinite<f32>],
+        notes_by_frame: &[&ValidatedNote],
         style_id: StyleId,
     ) -> Result<Vec<NonNaNFinite<f32>>> {
+        let _pinned = self.autoload(style_id).await?;
+
         if phonemes_by_frame.is_empty() && keys_by_frame.is_empty() && f0s.is_empty() {
             // 一貫性の観点から、推論を行わない場合でも`StyleNotFound`エラーが出るようにする。
             self.status().ids_for::<SingingTeacherDomain>(style_id)?;
@@ -976,12 +1125,15 @@ trait AsInner {
 
         let f0s = f0s.iter().copied().map(Into::into).collect();
 
-        let volumes = self
+        let mut volumes = self
             .status()
             .predict_sing_volume::<Self::Async>(phonemes_by_frame, keys_by_frame, f0s, style_id)
-            .await?;
+            .await?
+            .into_vec();
+
+        song::interpret::adjust_volumes(&mut volumes, notes_by_frame.iter().copied());
 
-        ensure_non_nan_finite(&volumes.into_vec(), |invalid| {
+        ensure_non_nan_finite(&volumes, |invalid| {
             anyhow!("`predict_sing_volume` returned an array that contains: {invalid}")
         })
     }
@@ -990,8 +1142,25 @@ trait AsInner {
         &self,
         frame_audio_query: &FrameAudioQuery,
         style_id: StyleId,
-        options: &SynthesisOptions<Self::Async>,
+        options: &FrameSynthesisOptions<Self::Async>,
     ) -> Result<Vec<u8>> {
+        let wave = &self
+            .frame_synthesis_wave(frame_audio_query, style_id, options.cancellable)
+            .await?;
+
+        Ok(wav_from_s16le(
+            &to_s16le_pcm(wave, frame_audio_query),
+            frame_audio_query.output_sampling_rate.get().get(),
+            frame_audio_query.output_stereo,
+        ))
+    }
+
+    async fn frame_synthesis_wave(
+        &self,
+        frame_audio_query: &FrameAudioQuery,
+        style_id: StyleId,
+        cancellable: <Self::Async as infer::AsyncExt>::Cancellable,
+    ) -> Result<Vec<f32>> {
         frame_audio_query.validate();
 
         frame_audio_query.warn_for_f0_len();
@@ -1003,19 +1172,16 @@ trait AsInner {
             volumes,
         } = frame_audio_query.into();
 
-        let wave = &if frame_phonemes.is_empty() && f0s.is_empty() && volumes.is_empty() {
+        let _pinned = self.autoload(style_id).await?;
+
+        if frame_phonemes.is_empty() && f0s.is_empty() && volumes.is_empty() {
             // 一貫性の観点から、推論を行わない場合でも`StyleNotFound`エラーが出るようにする。
             self.status().ids_for::<SingingTeacherDomain>(style_id)?;
-            vec![]
+            Ok(vec![])
         } else {
-            self.status()
-                .sf_decode::<Self::Async>(
-                    frame_phonemes,
-                    f0s,
-                    volumes,
-                    style_id,
-                    options.cancellable,
-                )
+            let wave = self
+                .status()
+                .sf_decode::<Self::Async>(frame_phonemes, f0s, volumes, style_id, cancellable)
                 .await
                 .map_err(|mut err| {
                     if let crate::Error(ErrorRepr::RunModel { note, .. }) = &mut err {
@@ -1031,13 +1197,79 @@ trait AsInner {
                     }
                     err
                 })?
-                .into_vec()
-        };
+                .into_vec();
+            Ok(wave)
+        }
+    }
+
+    async fn frame_render(
+        &self,
+        frame_audio_query: &FrameAudioQuery,
+        style_id: StyleId,
+        range: std::ops::Range<usize>,
+        options: &FrameSynthesisOptions<Self::Async>,
+    ) -> Result<Vec<u8>> {
+        frame_audio_query.validate();
+
+        let (
+            SfDecoderFeature {
+                frame_phonemes,
+                f0s,
+                volumes,
+            },
+            [leading_margin, trailing_margin],
+        ) = SfDecoderFeature::from(frame_audio_query)
+            .crop_with_margin(range.clone(), SF_DECODE_MARGIN)?;
+
+        let _pinned = self.autoload(style_id).await?;
+
+        if range.is_empty() {
+            // 一貫性の観点から、推論を行わない場合でも`StyleNotFound`エラーが出るようにする。
+            // エラーの条件は`frame_synthesis`に揃える。
+            self.status().ids_for::<SingingTeacherDomain>(style_id)?;
+            return Ok(vec![]);
+        }
+
+        let wave_with_margin = self
+            .status()
+            .sf_decode::<Self::Async>(frame_phonemes, f0s, volumes, style_id, options.cancellable)
+            .await?;
+        let len = wave_with_margin.len();
+        let wave = wave_with_margin.slice_move(ndarray::s![
+            leading_margin * 256..len - trailing_margin * 256
+        ]);
+
+        Ok(to_s16le_pcm(
+            wave.as_slice().expect("should just trim an array"),
+            frame_audio_query,
+        ))
+    }
+
+    async fn render_song_project(
+        &self,
+        tracks: &[SongTrack],
+        options: &RenderSongProjectOptions<Self::Async>,
+    ) -> Result<Vec<u8>> {
+        let channel_gains = tracks
+            .iter()
+            .map(SongTrack::channel_gains)
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let mut mixer = StereoMixer::default();
+        for (track, channel_gains) in itertools::zip_eq(tracks, channel_gains) {
+            let frame_audio_query = &self
+                .create_sing_frame_audio_query(&track.score, track.singing_teacher)
+                .await?;
+            let wave = &self
+                .frame_synthesis_wave(frame_audio_query, track.singer, options.cancellable)
+                .await?;
+            mixer.add(wave, channel_gains);
+        }
 
         Ok(wav_from_s16le(
-            &to_s16le_pcm(wave, frame_audio_query),
-            frame_audio_query.output_sampling_rate.get().get(),
-            frame_audio_query.output_stereo,
+            &stereo_to_s16le_pcm(&mixer.into_frames(), options.output_sampling_rate),
+            options.output_sampling_rate.get().get(),
+            true,
         ))
     }
 
@@ -1047,6 +1279,8 @@ trait AsInner {
         phoneme_vector: &[i64],
         style_id: StyleId,
     ) -> Result<Vec<PositiveFinite<f32>>> {
+        let _pinned = self.autoload(style_id).await?;
+
         let status = self.status().clone();
         let phoneme_vector = ndarray::arr1(phoneme_vector);
         status
@@ -1070,6 +1304,8 @@ trait AsInner {
         end_accent_phrase_vector: &[i64],
         style_id: StyleId,
     ) -> Result<Vec<NonNaNFinite<f32>>> {
+        let _pinned = self.autoload(style_id).await?;
+
         let status = self.status().clone();
         let vowel_phoneme_vector = ndarray::arr1(vowel_phoneme_vector);
         let consonant_phoneme_vector = ndarray::arr1(consonant_phoneme_vector);
@@ -1099,6 +1335,8 @@ trait AsInner {
         phoneme_vector: &[f32],
         style_id: StyleId,
     ) -> Result<ndarray::Array2<f32>> {
+        let _pinned = self.autoload(style_id).await?;
+
         let status = self.status().clone();
         let f0 = ndarray::arr1(f0);
         let phoneme_vector = ndarray::arr1(phoneme_vector);
@@ -1118,6 +1356,8 @@ trait AsInner {
         spec: ndarray::Array2<f32>,
         style_id: StyleId,
     ) -> Result<ndarray::Array1<f32>> {
+        let _pinned = self.autoload(style_id).await?;
+
         let status = self.status().clone();
         status
             .render_audio_segment::<Self::Async>(spec, style_id)
@@ -1133,6 +1373,8 @@ trait AsInner {
         style_id: StyleId,
         cancellable: <Self::Async as infer::AsyncExt>::Cancellable,
     ) -> Result<Vec<f32>> {
+        let _pinned = self.autoload(style_id).await?;
+
         let status = self.status().clone();
         let f0 = ndarray::arr1(f0);
         let phoneme_vector = ndarray::arr1(phoneme_vector);
@@ -1149,11 +1391,12 @@ trait AsInner {
     }
 }
 
-impl<T, A: AsyncExt> AsInner for Inner<T, A> {
+impl<T, A: AsyncExt, R: InferenceRuntime> AsInner for Inner<T, A, R> {
     type TextAnalyzer = T;
     type Async = A;
+    type Runtime = R;
 
-    fn status(&self) -> &Arc<Status<crate::blocking::Onnxruntime>> {
+    fn status(&self) -> &Arc<Status<R>> {
         &self.status
     }
 
@@ -1166,11 +1409,12 @@ impl<T, A: AsyncExt> AsInner for Inner<T, A> {
     }
 }
 
-impl<A: AsyncExt> AsInner for InnerRefWithoutTextAnalyzer<'_, A> {
+impl<A: AsyncExt, R: InferenceRuntime> AsInner for InnerRefWithoutTextAnalyzer<'_, A, R> {
     type TextAnalyzer = ();
     type Async = A;
+    type Runtime = R;
 
-    fn status(&self) -> &Arc<Status<crate::blocking::Onnxruntime>> {
+    fn status(&self) -> &Arc<Status<R>> {
         self.status
     }
 
@@ -1191,12 +1435,12 @@ impl<R: InferenceRuntime> Status<R> {
     ) -> Result<Vec<PositiveFinite<f32>>> {
         // `TalkDomain`と`StreamingTalkDomain`の両方がある場合、`TalkDomain`を優先
         if self.contains_domain::<TalkDomain>(style_id) {
-            let (model_id, inner_voice_id) = self.ids_for::<TalkDomain>(style_id)?;
+            let (model, inner_voice_id) = self.ids_for::<TalkDomain>(style_id)?;
             let talk::PredictDurationOutput {
                 phoneme_length: output,
             } = self
                 .run_session::<A, _>(
-                    model_id,
+                    &model,
                     talk::PredictDurationInput {
                         phoneme_list: phoneme_vector,
                         speaker_id: ndarray::arr1(&[inner_voice_id.raw_id().into()]),
@@ -1209,13 +1453,13 @@ impl<R: InferenceRuntime> Status<R> {
             })?;
             return Ok(ensure_minimum_phoneme_length(output));
         }
-        let (model_id, inner_voice_id) = self.ids_for::<StreamingTalkDomain>(style_id)?;
+        let (model, inner_voice_id) = self.ids_for::<StreamingTalkDomain>(style_id)?;
 
         let streaming_talk::PredictDurationOutput {
             phoneme_length: output,
         } = self
             .run_session::<A, _>(
-                model_id,
+                &model,
                 streaming_talk::PredictDurationInput {
                     phoneme_list: phoneme_vector,
                     speaker_id: ndarray::arr1(&[inner_voice_id.raw_id().into()]),
@@ -1247,10 +1491,10 @@ impl<R: InferenceRuntime> Status<R> {
     ) -> Result<Vec<NonNaNFinite<f32>>> {
         // `TalkDomain`と`StreamingTalkDomain`の両方がある場合、`TalkDomain`を優先
         if self.contains_domain::<TalkDomain>(style_id) {
-            let (model_id, inner_voice_id) = self.ids_for::<TalkDomain>(style_id)?;
+            let (model, inner_voice_id) = self.ids_for::<TalkDomain>(style_id)?;
             let talk::PredictIntonationOutput { f0_list: output } = self
                 .run_session::<A, _>(
-                    model_id,
+                    &model,
                     talk::PredictIntonationInput {
                         length: ndarray::arr0(length as i64),
                         vowel_phoneme_list: vowel_phoneme_vector,
@@ -1268,11 +1512,11 @@ impl<R: InferenceRuntime> Status<R> {
                 anyhow!("`predict_intonation` returned an array that contains: {invalid}")
             });
         }
-        let (model_id, inner_voice_id) = self.ids_for::<StreamingTalkDomain>(style_id)?;
+        let (model, inner_voice_id) = self.ids_for::<StreamingTalkDomain>(style_id)?;
 
         let streaming_talk::PredictIntonationOutput { f0_list: output } = self
             .run_session::<A, _>(
-                model_id,
+                &model,
                 streaming_talk::PredictIntonationInput {
                     length: ndarray::arr0(length as i64),
                     vowel_phoneme_list: vowel_phoneme_vector,
@@ -1303,7 +1547,7 @@ impl<R: InferenceRuntime> Status<R> {
         phoneme_vector: ndarray::Array1<f32>,
         style_id: StyleId,
     ) -> Result<ndarray::Array2<f32>> {
-        let (model_id, inner_voice_id) = self.ids_for::<StreamingTalkDomain>(style_id)?;
+        let (model, inner_voice_id) = self.ids_for::<StreamingTalkDomain>(style_id)?;
 
         let (length_with_padding, f0_with_padding, phoneme_with_padding) =
             pad_decoder_feature::<PADDING_FRAME_LENGTH>(
@@ -1319,7 +1563,7 @@ impl<R: InferenceRuntime> Status<R> {
             spec: spec_with_padding,
         } = self
             .run_session::<A, _>(
-                model_id,
+                &model,
                 GenerateFullIntermediateInput {
                     f0: f0_with_padding
                         .into_shape_with_order([length_with_padding, 1])
@@ -1367,10 +1611,10 @@ impl<R: InferenceRuntime> Status<R> {
         spec: ndarray::Array2<f32>,
         style_id: StyleId,
     ) -> Result<ndarray::Array1<f32>> {
-        let (model_id, _inner_voice_id) = self.ids_for::<StreamingTalkDomain>(style_id)?;
+        let (model, _inner_voice_id) = self.ids_for::<StreamingTalkDomain>(style_id)?;
         let RenderAudioSegmentOutput { wave } = self
             .run_session::<A, _>(
-                model_id,
+                &model,
                 RenderAudioSegmentInput { spec },
                 A::DEFAULT_HEAVY_INFERENCE_CANCELLABLE, // TODO: 外部から指定可能にする
             )
@@ -1389,7 +1633,7 @@ impl<R: InferenceRuntime> Status<R> {
     ) -> Result<Vec<f32>> {
         // `TalkDomain`と`StreamingTalkDomain`の両方がある場合、`TalkDomain`を優先
         if self.contains_domain::<TalkDomain>(style_id) {
-            let (model_id, inner_voice_id) = self.ids_for::<TalkDomain>(style_id)?;
+            let (model, inner_voice_id) = self.ids_for::<TalkDomain>(style_id)?;
             let (length_with_padding, f0_with_padding, phoneme_with_padding) =
                 pad_decoder_feature::<PADDING_FRAME_LENGTH>(
                     f0,
@@ -1399,7 +1643,7 @@ impl<R: InferenceRuntime> Status<R> {
                 );
             let DecodeOutput { wave: output } = self
                 .run_session::<A, _>(
-                    model_id,
+                    &model,
                     DecodeInput {
                         f0: f0_with_padding
                             .into_shape_with_order([length_with_padding, 1])
@@ -1436,11 +1680,11 @@ impl<R: InferenceRuntime> Status<R> {
         note_duration: ndarray::Array1<i64>,
         style_id: StyleId,
     ) -> Result<ndarray::Array1<i64>> {
-        let (model_id, inner_voice_id) = self.ids_for::<SingingTeacherDomain>(style_id)?;
+        let (model, inner_voice_id) = self.ids_for::<SingingTeacherDomain>(style_id)?;
 
         let PredictSingConsonantLengthOutput { consonant_lengths } = self
             .run_session::<A, _>(
-                model_id,
+                &model,
                 PredictSingConsonantLengthInput {
                     consonants: consonant.into_one_row(),
                     vowels: vowel.into_one_row(),
@@ -1460,11 +1704,11 @@ impl<R: InferenceRuntime> Status<R> {
         note: ndarray::Array1<i64>,
         style_id: StyleId,
     ) -> Result<ndarray::Array1<f32>> {
-        let (model_id, inner_voice_id) = self.ids_for::<SingingTeacherDomain>(style_id)?;
+        let (model, inner_voice_id) = self.ids_for::<SingingTeacherDomain>(style_id)?;
 
         let PredictSingF0Output { f0s } = self
             .run_session::<A, _>(
-                model_id,
+                &model,
                 PredictSingF0Input {
                     phonemes: phoneme.into_one_row(),
                     notes: note.into_one_row(),
@@ -1484,11 +1728,11 @@ impl<R: InferenceRuntime> Status<R> {
         f0: ndarray::Array1<f32>,
         style_id: StyleId,
     ) -> Result<ndarray::Array1<f32>> {
-        let (model_id, inner_voice_id) = self.ids_for::<SingingTeacherDomain>(style_id)?;
+        let (model, inner_voice_id) = self.ids_for::<SingingTeacherDomain>(style_id)?;
 
         let PredictSingVolumeOutput { volumes } = self
             .run_session::<A, _>(
-                model_id,
+                &model,
                 PredictSingVolumeInput {
                     phonemes: phoneme.into_one_row(),
                     notes: note.into_one_row(),
@@ -1510,11 +1754,11 @@ impl<R: InferenceRuntime> Status<R> {
         style_id: StyleId,
         cancellable: A::Cancellable,
     ) -> Result<ndarray::Array1<f32>> {
-        let (model_id, inner_voice_id) = self.ids_for::<FrameDecodeDomain>(style_id)?;
+        let (model, inner_voice_id) = self.ids_for::<FrameDecodeDomain>(style_id)?;
 
         let SfDecodeOutput { wav } = self
             .run_session::<A, _>(
-                model_id,
+                &model,
                 SfDecodeInput {
                     frame_phonemes: phoneme.into_one_row(),
                     frame_f0s: f0.into_one_row(),
@@ -1637,27 +1881,37 @@ impl From<Vec<AccentPhrase>> for AudioQuery {
               形を考えると、ここの引数を構造体にまとめたりしても可読性に寄与しない"
 )]
 pub(crate) mod blocking {
-    use std::fmt::{self, Debug};
+    use std::{
+        fmt::{self, Debug},
+        num::NonZero,
+        sync::Arc,
+    };
 
     use easy_ext::ext;
     use typed_floats::{NonNaNFinite, PositiveFinite};
 
     use crate::{
-        AccentPhrase, AudioQuery, FrameAudioQuery, OnExistingVoiceModelId, Score, StyleId,
-        VoiceModelId, VoiceModelMeta, asyncs::SingleTasked, future::FutureExt as _,
+        AccentPhrase, AudioQuery, ExecutionProvider, FrameAudioQuery, GraphOptimizationLevel,
+        InferenceOperationKind, OnExistingVoiceModelId, OperationPlacement, SamplingRate, Score,
+        SongTrack, StyleId, StyleType, VoiceModelId, VoiceModelMeta, asyncs::SingleTasked,
+        future::FutureExt as _,
     };
 
     use super::{
-        AccelerationMode, AsInner as _, AssumeSingleTasked, InitializeOptions, Inner,
-        InnerRefWithoutTextAnalyzer, LoadVoiceModelOptions, SynthesisOptions, TtsOptions,
+        AccelerationMode, AsInner as _, AssumeSingleTasked, DefaultInferenceRuntime,
+        InferenceRuntime, InitializeOptions, Inner, InnerRefWithoutTextAnalyzer,
+        LoadVoiceModelOptions, RenderSongProjectOptions, SynthesisOptions, TtsOptions,
     };
 
     pub use super::AudioFeature;
 
     /// 音声シンセサイザ。
     #[cfg_attr(doc, doc(alias = "VoicevoxSynthesizer"))]
-    pub struct Synthesizer<T>(pub(super) Inner<AssumeSingleTasked<T>, SingleTasked>);
+    pub struct Synthesizer<T, R: InferenceRuntime = DefaultInferenceRuntime>(
+        pub(super) Inner<AssumeSingleTasked<T>, SingleTasked, R>,
+    );
 
+    #[cfg(onnxruntime)]
     impl self::Synthesizer<()> {
         /// `Synthesizer`のビルダーをコンストラクトする。
         ///
@@ -1691,18 +1945,35 @@ pub(crate) mod blocking {
         /// ```
         #[cfg_attr(doc, doc(alias = "voicevox_synthesizer_new"))]
         pub fn builder(onnxruntime: &'static crate::blocking::Onnxruntime) -> Builder<()> {
+            Self::builder_with_runtime(onnxruntime)
+        }
+    }
+
+    impl<R: InferenceRuntime> self::Synthesizer<(), R> {
+        /// ONNX Runtime以外の[`InferenceRuntime`]を用いる、`Synthesizer`のビルダーをコンストラクトする。
+        ///
+        /// [`InferenceRuntime`]: crate::inference_runtime::InferenceRuntime
+        pub fn builder_with_runtime(runtime: &'static R) -> Builder<(), R> {
             Builder {
-                onnxruntime,
+                runtime,
                 text_analyzer: (),
                 options: Default::default(),
             }
         }
     }
 
+    #[cfg(onnxruntime)]
     impl<T> self::Synthesizer<T> {
         #[cfg_attr(doc, doc(alias = "voicevox_synthesizer_get_onnxruntime"))]
         pub fn onnxruntime(&self) -> &'static crate::blocking::Onnxruntime {
-            self.0.onnxruntime()
+            self.0.runtime()
+        }
+    }
+
+    impl<T, R: InferenceRuntime> self::Synthesizer<T, R> {
+        /// 推論ランタイム。
+        pub fn runtime(&self) -> &'static R {
+            self.0.runtime()
         }
 
         /// テキスト解析器。
@@ -1721,7 +1992,7 @@ pub(crate) mod blocking {
         pub fn load_voice_model<'a>(
             &'a self,
             model: &'a crate::blocking::VoiceModelFile,
-        ) -> LoadVoiceModel<'a> {
+        ) -> LoadVoiceModel<'a, R> {
             LoadVoiceModel {
                 synthesizer: self.0.without_text_analyzer(),
                 model,
@@ -1741,6 +2012,30 @@ pub(crate) mod blocking {
             self.0.is_loaded_voice_model(voice_model_id)
         }
 
+        /// 音声モデルを登録する。
+        ///
+        /// 登録された音声モデルはこの時点では読み込まれず、そのスタイルIDが音声合成などで初めて
+        /// 使われたときに自動で読み込まれる。[`Builder::memory_budget`]を指定した場合、上限を超えた
+        /// 分だけ登録された音声モデルが最も長い間使われていないものから順にアンロードされ、
+        /// 再び使われたときにまた読み込まれる。
+        ///
+        /// 同じIDの音声モデルが既に登録されていた場合は置き換える。
+        pub fn register_voice_model(&self, model: crate::blocking::VoiceModelFile) {
+            self.0.register_voice_model(model.into_inner());
+        }
+
+        /// 音声モデルの登録を解除する。
+        ///
+        /// 既に読み込まれている音声モデルはアンロードされない。
+        pub fn unregister_voice_model(&self, voice_model_id: VoiceModelId) -> crate::Result<()> {
+            self.0.unregister_voice_model(voice_model_id)
+        }
+
+        /// 指定したIDの音声モデルが登録されているか判定する。
+        pub fn is_registered_voice_model(&self, voice_model_id: VoiceModelId) -> bool {
+            self.0.is_registered_voice_model(voice_model_id)
+        }
+
         #[doc(hidden)]
         pub fn is_loaded_model_by_style_id(&self, style_id: StyleId) -> bool {
             self.0.is_loaded_model_by_style_id(style_id)
@@ -1760,7 +2055,7 @@ pub(crate) mod blocking {
             &'a self,
             audio_query: &'a AudioQuery,
             style_id: StyleId,
-        ) -> PrecomputeRender<'a> {
+        ) -> PrecomputeRender<'a, R> {
             PrecomputeRender {
                 synthesizer: self.0.without_text_analyzer(),
                 audio_query,
@@ -1770,6 +2065,12 @@ pub(crate) mod blocking {
         }
 
         /// 中間表現から16bit PCMで音声波形を生成する。
+        ///
+        /// # Errors
+        ///
+        /// `range`の始点が終点より後にあるとき、もしくは終点が[`AudioFeature::frame_length`]を超えるとき、[`ErrorKind::InvalidQuery`]を表わすエラーを返す。
+        ///
+        /// [`ErrorKind::InvalidQuery`]: crate::ErrorKind::InvalidQuery
         // TODO: 後で復活させる
         // https://github.com/VOICEVOX/voicevox_core/issues/970
         #[doc(hidden)]
@@ -1781,13 +2082,26 @@ pub(crate) mod blocking {
             self.0.render(audio, range.into()).block_on()
         }
 
+        /// 中間表現から`[-1.0, 1.0]`の範囲の音声波形を生成する。
+        ///
+        /// ステレオの場合はインターリーブされたものになる。
+        // Python APIのストリーミング用。
+        #[doc(hidden)]
+        pub fn __render_wave(
+            &self,
+            audio: &AudioFeature,
+            range: impl Into<std::ops::Range<usize>>,
+        ) -> crate::Result<Vec<f32>> {
+            self.0.render_wave(audio, range.into()).block_on()
+        }
+
         /// AudioQueryから直接WAVフォーマットで音声波形を生成する。
         #[cfg_attr(doc, doc(alias = "voicevox_synthesizer_synthesis"))]
         pub fn synthesis<'a>(
             &'a self,
             audio_query: &'a AudioQuery,
             style_id: StyleId,
-        ) -> Synthesis<'a> {
+        ) -> Synthesis<'a, R> {
             Synthesis {
                 synthesizer: self.0.without_text_analyzer(),
                 audio_query,
@@ -1918,7 +2232,7 @@ pub(crate) mod blocking {
 
         /// AquesTalk風記法から音声合成を行う。
         #[cfg_attr(doc, doc(alias = "voicevox_synthesizer_tts_from_kana"))]
-        pub fn tts_from_kana<'a>(&'a self, kana: &'a str, style_id: StyleId) -> TtsFromKana<'a> {
+        pub fn tts_from_kana<'a>(&'a self, kana: &'a str, style_id: StyleId) -> TtsFromKana<'a, R> {
             TtsFromKana {
                 synthesizer: self.0.without_text_analyzer(),
                 kana,
@@ -2192,19 +2506,23 @@ pub(crate) mod blocking {
             &'a self,
             frame_audio_query: &'a FrameAudioQuery,
             style_id: StyleId,
-        ) -> FrameSynthesis<'a> {
+        ) -> FrameSynthesis<'a, R> {
             FrameSynthesis {
                 synthesizer: self.0.without_text_analyzer(),
                 frame_audio_query,
                 style_id,
             }
         }
-    }
 
-    impl<T: crate::blocking::TextAnalyzer> self::Synthesizer<T> {
-        /// 日本語のテキストからAccentPhrase (アクセント句)の配列を生成する。
+        /// [歌唱音声合成用のクエリ]のうち、指定したフレーム区間のみを歌唱音声合成し、16bit PCMで返す。
         ///
-        /// [`TextAnalyzer::analyze`]と[`replace_mora_data`]が一体になったショートハンド。詳細は[音声の調整]の節。
+        /// 区間の前後には文脈として数フレームずつ余分に推論を行うため、結果は[`frame_synthesis`]で得られるWAVデータの該当部分とほぼ一致する。そのため一部のノートを編集したときに、その区間のみを再合成して差し替えることができる。ただしビット単位では一致せず、またクロスフェードも行わないため、継ぎ目でサンプル値がわずかに不連続になりうる。クリックノイズを避けたい場合は、呼び出し側で継ぎ目を数ミリ秒クロスフェードすること。
+        ///
+        /// 結果の先頭は、[`frame_synthesis`]で得られるWAVデータのデータ部の`range.start * 256 * (output_sampling_rate / 24000) * (1 + output_stereo) * 2`バイト目に対応する。
+        ///
+        /// # Errors
+        ///
+        /// `range`が[`phonemes`]、[`f0`]、[`volume`]のいずれかのフレーム数を超えるとき、[`ErrorKind::InvalidQuery`]を表わすエラーを返す。
         ///
         /// # Example
         ///
@@ -2224,29 +2542,60 @@ pub(crate) mod blocking {
         /// #
         /// use voicevox_core::StyleId;
         ///
-        /// let accent_phrases = synthesizer.create_accent_phrases("こんにちは", StyleId::new(302))?;
+        /// # const SINGING_TEACHER: StyleId = StyleId(6000);
+        /// const SINGER: StyleId = StyleId(3000);
+        ///
+        /// # let score = &serde_json::from_str::<voicevox_core::Score>(
+        /// #     r#"
+        /// # {
+        /// #   "notes": [
+        /// #     { "key": null, "frame_length": 15, "lyric": "" },
+        /// #     { "key": 60, "frame_length": 45, "lyric": "ド" },
+        /// #     { "key": 62, "frame_length": 45, "lyric": "レ" },
+        /// #     { "key": 64, "frame_length": 45, "lyric": "ミ" },
+        /// #     { "key": null, "frame_length": 15, "lyric": "" }
+        /// #   ]
+        /// # }
+        /// #     "#,
+        /// # )
+        /// # .unwrap();
+        /// #
+        /// # let frame_audio_query = &synthesizer.create_sing_frame_audio_query(score, SINGING_TEACHER)?;
+        /// #
+        /// // "レ"のノートの区間のみを合成する。
+        /// let pcm = synthesizer
+        ///     .frame_render(&frame_audio_query, SINGER, 60..105)
+        ///     .perform()?;
         /// #
         /// # Ok(())
         /// # }
         /// ```
         ///
-        /// [`TextAnalyzer::analyze`]: crate::blocking::TextAnalyzer::analyze
-        /// [`replace_mora_data`]: Self::replace_mora_data
-        /// [音声の調整]: ../index.html#音声の調整
-        #[cfg_attr(doc, doc(alias = "voicevox_synthesizer_create_accent_phrases"))]
-        pub fn create_accent_phrases(
-            &self,
-            text: &str,
+        /// [歌唱音声合成用のクエリ]: FrameAudioQuery
+        /// [`frame_synthesis`]: Self::frame_synthesis
+        /// [`phonemes`]: FrameAudioQuery::phonemes
+        /// [`f0`]: FrameAudioQuery::f0
+        /// [`volume`]: FrameAudioQuery::volume
+        /// [`ErrorKind::InvalidQuery`]: crate::ErrorKind::InvalidQuery
+        pub fn frame_render<'a>(
+            &'a self,
+            frame_audio_query: &'a FrameAudioQuery,
             style_id: StyleId,
-        ) -> crate::Result<Vec<AccentPhrase>> {
-            self.0.create_accent_phrases(text, style_id).block_on()
+            range: std::ops::Range<usize>,
+        ) -> FrameRender<'a, R> {
+            FrameRender {
+                synthesizer: self.0.without_text_analyzer(),
+                frame_audio_query,
+                style_id,
+                range,
+            }
         }
 
-        /// 日本語のテキストから[AudioQuery]を生成する。
+        /// 複数の[トラック]を歌唱音声合成し、ステレオのWAVデータとして重ね合わせる。
         ///
-        /// [`create_accent_phrases`]と[`AudioQuery::from_accent_phrases`]が一体になったショートハンド。詳細は[音声の調整]の節。
+        /// 各トラックは[`singing_teacher`]で[`FrameAudioQuery`]を生成した上で[`singer`]で合成され、[`gain`]と[`pan`]に従って左右のチャンネルに配置される。すべてのトラックは先頭が揃えられ、出力の長さは最も長いトラックに合わせられる。重ね合わせた結果のピークが約-1dBFSを超える場合、音割れを避けるため全体の音量が一律に下げられる。
         ///
-        /// # Examples
+        /// # Example
         ///
         /// ```
         /// # fn main() -> anyhow::Result<()> {
@@ -2262,13 +2611,124 @@ pub(crate) mod blocking {
         /// #     .block_on()?
         /// #     .into_blocking();
         /// #
-        /// use voicevox_core::StyleId;
+        /// use voicevox_core::{SongTrack, StyleId};
         ///
-        /// let audio_query = synthesizer.create_audio_query("こんにちは", StyleId::new(302))?;
-        /// #
-        /// # Ok(())
+        /// const SINGING_TEACHER: StyleId = StyleId(6000);
+        /// const SINGER: StyleId = StyleId(3000);
+        ///
+        /// # let score = serde_json::from_str::<voicevox_core::Score>(
+        /// #     r#"
+        /// # {
+        /// #   "notes": [
+        /// #     { "key": null, "frame_length": 15, "lyric": "" },
+        /// #     { "key": 60, "frame_length": 45, "lyric": "ド" },
+        /// #     { "key": 62, "frame_length": 45, "lyric": "レ" },
+        /// #     { "key": 64, "frame_length": 45, "lyric": "ミ" },
+        /// #     { "key": null, "frame_length": 15, "lyric": "" }
+        /// #   ]
         /// # }
-        /// ```
+        /// #     "#,
+        /// # )
+        /// # .unwrap();
+        /// #
+        /// let mut melody = SongTrack::new(score.clone(), SINGING_TEACHER, SINGER);
+        /// melody.pan = (-0.5_f32).try_into().unwrap();
+        /// let mut harmony = SongTrack::new(score, SINGING_TEACHER, SINGER);
+        /// harmony.pan = 0.5_f32.try_into().unwrap();
+        ///
+        /// let wav = synthesizer
+        ///     .render_song_project(&[melody, harmony])
+        ///     .perform()?;
+        /// #
+        /// # Ok(())
+        /// # }
+        /// ```
+        ///
+        /// [トラック]: SongTrack
+        /// [`singing_teacher`]: SongTrack::singing_teacher
+        /// [`singer`]: SongTrack::singer
+        /// [`gain`]: SongTrack::gain
+        /// [`pan`]: SongTrack::pan
+        pub fn render_song_project<'a>(
+            &'a self,
+            tracks: &'a [SongTrack],
+        ) -> RenderSongProject<'a, R> {
+            RenderSongProject {
+                synthesizer: self.0.without_text_analyzer(),
+                tracks,
+                options: Default::default(),
+            }
+        }
+    }
+
+    impl<T: crate::blocking::TextAnalyzer, R: InferenceRuntime> self::Synthesizer<T, R> {
+        /// 日本語のテキストからAccentPhrase (アクセント句)の配列を生成する。
+        ///
+        /// [`TextAnalyzer::analyze`]と[`replace_mora_data`]が一体になったショートハンド。詳細は[音声の調整]の節。
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// # fn main() -> anyhow::Result<()> {
+        /// # use pollster::FutureExt as _;
+        /// # use voicevox_core::__internal::doctest_fixtures::IntoBlocking as _;
+        /// #
+        /// # let synthesizer =
+        /// #     voicevox_core::__internal::doctest_fixtures::synthesizer_with_sample_voice_model(
+        /// #         test_util::SAMPLE_VOICE_MODEL_FILE_PATH,
+        /// #         test_util::ONNXRUNTIME_DYLIB_PATH,
+        /// #         test_util::OPEN_JTALK_DIC_DIR,
+        /// #     )
+        /// #     .block_on()?
+        /// #     .into_blocking();
+        /// #
+        /// use voicevox_core::StyleId;
+        ///
+        /// let accent_phrases = synthesizer.create_accent_phrases("こんにちは", StyleId::new(302))?;
+        /// #
+        /// # Ok(())
+        /// # }
+        /// ```
+        ///
+        /// [`TextAnalyzer::analyze`]: crate::blocking::TextAnalyzer::analyze
+        /// [`replace_mora_data`]: Self::replace_mora_data
+        /// [音声の調整]: ../index.html#音声の調整
+        #[cfg_attr(doc, doc(alias = "voicevox_synthesizer_create_accent_phrases"))]
+        pub fn create_accent_phrases(
+            &self,
+            text: &str,
+            style_id: StyleId,
+        ) -> crate::Result<Vec<AccentPhrase>> {
+            self.0.create_accent_phrases(text, style_id).block_on()
+        }
+
+        /// 日本語のテキストから[AudioQuery]を生成する。
+        ///
+        /// [`create_accent_phrases`]と[`AudioQuery::from_accent_phrases`]が一体になったショートハンド。詳細は[音声の調整]の節。
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # fn main() -> anyhow::Result<()> {
+        /// # use pollster::FutureExt as _;
+        /// # use voicevox_core::__internal::doctest_fixtures::IntoBlocking as _;
+        /// #
+        /// # let synthesizer =
+        /// #     voicevox_core::__internal::doctest_fixtures::synthesizer_with_sample_voice_model(
+        /// #         test_util::SAMPLE_VOICE_MODEL_FILE_PATH,
+        /// #         test_util::ONNXRUNTIME_DYLIB_PATH,
+        /// #         test_util::OPEN_JTALK_DIC_DIR,
+        /// #     )
+        /// #     .block_on()?
+        /// #     .into_blocking();
+        /// #
+        /// use voicevox_core::StyleId;
+        ///
+        /// let audio_query = synthesizer.create_audio_query("こんにちは", StyleId::new(302))?;
+        /// #
+        /// # Ok(())
+        /// # }
+        /// ```
         ///
         /// [AudioQuery]: crate::AudioQuery
         /// [`create_accent_phrases`]: Self::create_accent_phrases
@@ -2290,7 +2750,7 @@ pub(crate) mod blocking {
         /// [`synthesis`]: Self::synthesis
         /// [音声の調整]: ../index.html#音声の調整
         #[cfg_attr(doc, doc(alias = "voicevox_synthesizer_tts"))]
-        pub fn tts<'a>(&'a self, text: &'a str, style_id: StyleId) -> Tts<'a, T> {
+        pub fn tts<'a>(&'a self, text: &'a str, style_id: StyleId) -> Tts<'a, T, R> {
             Tts {
                 synthesizer: &self.0,
                 text,
@@ -2300,15 +2760,25 @@ pub(crate) mod blocking {
         }
     }
 
-    impl<T: Debug> Debug for self::Synthesizer<T> {
+    impl<T: Debug, R: InferenceRuntime> Debug for self::Synthesizer<T, R> {
         fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
             let fmt = fmt.debug_struct("Synthesizer");
             self.0.fill_debug_struct_body(fmt)
         }
     }
 
+    #[ext(ToNonblocking)]
+    impl<T: Clone, R: InferenceRuntime> self::Synthesizer<T, R> {
+        /// 読み込んでいる音声モデルを共有する[`nonblocking::Synthesizer`]を作る。
+        ///
+        /// [`nonblocking::Synthesizer`]: crate::nonblocking::Synthesizer
+        pub fn to_nonblocking(&self) -> crate::nonblocking::Synthesizer<AssumeSingleTasked<T>, R> {
+            crate::nonblocking::Synthesizer(self.0.to_nonblocking())
+        }
+    }
+
     #[ext(PerformInference)]
-    impl self::Synthesizer<()> {
+    impl<R: InferenceRuntime> self::Synthesizer<(), R> {
         pub fn predict_duration(
             &self,
             phoneme_vector: &[i64],
@@ -2441,18 +2911,18 @@ pub(crate) mod blocking {
 
     #[must_use]
     #[derive(Debug)]
-    pub struct Builder<T> {
-        onnxruntime: &'static crate::blocking::Onnxruntime,
+    pub struct Builder<T, R: InferenceRuntime = DefaultInferenceRuntime> {
+        runtime: &'static R,
         text_analyzer: T,
         options: InitializeOptions,
     }
 
-    impl<T> Builder<T> {
+    impl<T, R: InferenceRuntime> Builder<T, R> {
         /// テキスト解析器。
-        pub fn text_analyzer<T2>(self, text_analyzer: T2) -> Builder<T2> {
+        pub fn text_analyzer<T2>(self, text_analyzer: T2) -> Builder<T2, R> {
             Builder {
                 text_analyzer,
-                onnxruntime: self.onnxruntime,
+                runtime: self.runtime,
                 options: self.options,
             }
         }
@@ -2462,16 +2932,164 @@ pub(crate) mod blocking {
             self
         }
 
+        /// [`AccelerationMode::Auto`]および[`AccelerationMode::Gpu`]で試す[`ExecutionProvider`]を、
+        /// 優先度順に指定する。デフォルトは[CUDA], [DirectML]の順。
+        ///
+        /// 実際に利用可能なもののうち、最初のものが重い推論に使われる。ただし[`AccelerationMode::Gpu`]
+        /// では、[XNNPACK]のようなCPU上で動作するものは無視される。
+        ///
+        /// 現在はRust APIのみの機能であり、C API、Python API、Java APIからは指定できない。それらでは
+        /// 常にデフォルトのものが試される。
+        ///
+        /// [CUDA]: ExecutionProvider::Cuda
+        /// [DirectML]: ExecutionProvider::Dml
+        /// [XNNPACK]: ExecutionProvider::Xnnpack
+        pub fn execution_providers(
+            mut self,
+            execution_providers: impl IntoIterator<Item = ExecutionProvider>,
+        ) -> Self {
+            self.options.execution_providers = execution_providers.into_iter().collect();
+            self
+        }
+
+        /// 推論の操作ごとに、推論を行うデバイスとCPU利用数を指定する。
+        ///
+        /// 指定しなかった操作については、音素長や音高の推論といった軽いものはCPUで、波形の生成といった重い
+        /// ものは[`OperationDevice::Accelerator`]で行われる。
+        ///
+        /// [`OperationDevice::Accelerator`]: crate::OperationDevice::Accelerator
+        pub fn operation_placement(
+            mut self,
+            operation: InferenceOperationKind,
+            placement: OperationPlacement,
+        ) -> Self {
+            self.options
+                .operation_placements
+                .insert(operation, placement);
+            self
+        }
+
         /// CPU利用数を指定。0を指定すると環境に合わせたCPUが利用される。
         pub fn cpu_num_threads(mut self, cpu_num_threads: u16) -> Self {
             self.options.cpu_num_threads = cpu_num_threads;
             self
         }
 
+        /// 推論操作ごとに作成するセッションの数。デフォルトは1。
+        ///
+        /// 一つのセッションは同時に一つの推論しか実行できないため、同じスタイルに対する音声合成を
+        /// 並行して行うと順番待ちになる。2以上を指定すると、空いているセッションに推論を割り当てる
+        /// ようになる。
+        ///
+        /// # Performance
+        ///
+        /// 音声モデルの読み込み時間とメモリ（GPUメモリ）の使用量は、おおよそこの値に比例して増える。
+        ///
+        /// CPUで推論を行うセッションは、複製のそれぞれが[`cpu_num_threads`]個のスレッドを用いる。
+        /// そのため全ての複製が同時に推論を行うと、最大で`session_replicas`×[`cpu_num_threads`]個の
+        /// スレッドが動き、論理プロセッサの数を超えるとかえって遅くなることがある。CPUで複製を作る
+        /// 場合は、[`cpu_num_threads`]を論理プロセッサの数を`session_replicas`で割った程度にすること。
+        ///
+        /// [`cpu_num_threads`]: Self::cpu_num_threads
+        pub fn session_replicas(mut self, session_replicas: NonZero<usize>) -> Self {
+            self.options.session_replicas = session_replicas;
+            self
+        }
+
+        /// [`Synthesizer::register_voice_model`]で登録された音声モデルを自動で読み込むときに、VVMの署名を
+        /// 検証し、指定したEd25519公開鍵のいずれかで署名されたもののみを読み込む。
+        ///
+        /// 検証に失敗したときは、その音声モデルを必要とした音声合成などが[`VerifyModel`]エラーとなる。
+        /// [`Synthesizer::load_voice_model`]には影響しないため、そちらでは
+        /// [`LoadVoiceModel::trusted_public_keys`]を用いること。
+        ///
+        /// [`VerifyModel`]: crate::ErrorKind::VerifyModel
+        pub fn trusted_public_keys(
+            mut self,
+            trusted_public_keys: impl IntoIterator<Item = [u8; 32]>,
+        ) -> Self {
+            self.options.trusted_public_keys = Some(trusted_public_keys.into_iter().collect());
+            self
+        }
+
+        /// 読み込まれている音声モデルのメモリ使用量の上限（バイト）。デフォルトは無制限。
+        ///
+        /// 上限を超えると、[`Synthesizer::register_voice_model`]で登録された音声モデルが最も長い間
+        /// 使われていないものから順にアンロードされる。メモリ使用量はモデルのバイト列の大きさと
+        /// [`session_replicas`]から見積もった概算値であり、実際の使用量とは異なる。
+        ///
+        /// [`session_replicas`]: Self::session_replicas
+        pub fn memory_budget(mut self, memory_budget: u64) -> Self {
+            self.options.memory_budget = Some(memory_budget);
+            self
+        }
+
+        /// 推論の実行グラフの最適化レベル。デフォルトは[`GraphOptimizationLevel::Basic`]。
+        pub fn graph_optimization_level(
+            mut self,
+            graph_optimization_level: GraphOptimizationLevel,
+        ) -> Self {
+            self.options.session_tuning.graph_optimization_level = graph_optimization_level;
+            self
+        }
+
+        /// 推論グラフ内の独立したノードを並列に実行するためのスレッド数。デフォルトは0。
+        ///
+        /// 0以外を指定すると、ノードを並列に実行するようになる。0を指定するとノードを順番に実行する。
+        ///
+        /// [`cpu_num_threads`]は一つのノードの内部で使われるスレッド数である。
+        ///
+        /// [`cpu_num_threads`]: Self::cpu_num_threads
+        pub fn inter_op_num_threads(mut self, inter_op_num_threads: u16) -> Self {
+            self.options.session_tuning.inter_op_num_threads = inter_op_num_threads;
+            self
+        }
+
+        /// CPU上のメモリ確保にアリーナを用いるかどうか。デフォルトは`true`。
+        ///
+        /// `false`にすると推論ごとのメモリ確保が増える代わりに、使い終えたメモリがすぐに解放される。
+        pub fn cpu_memory_arena(mut self, cpu_memory_arena: bool) -> Self {
+            self.options.session_tuning.cpu_memory_arena = cpu_memory_arena;
+            self
+        }
+
+        /// 入力の形から推論に必要なメモリ量を予測し、事前に確保するかどうか。デフォルトは`true`。
+        ///
+        /// DirectMLを利用するときは、この設定によらず無効になる。
+        pub fn memory_pattern(mut self, memory_pattern: bool) -> Self {
+            self.options.session_tuning.memory_pattern = memory_pattern;
+            self
+        }
+
+        /// 決定的な計算を強制するかどうか。デフォルトは`false`。
+        ///
+        /// `true`にすると同じ入力に対して常に同じ出力が得られるようになる代わりに、推論が遅くなる
+        /// 可能性がある。
+        pub fn deterministic_compute(mut self, deterministic_compute: bool) -> Self {
+            self.options.session_tuning.deterministic_compute = deterministic_compute;
+            self
+        }
+
+        /// [`cpu_num_threads`]のスレッドを割り当てる論理プロセッサ。デフォルトでは指定しない。
+        ///
+        /// ONNX Runtimeの`session.intra_op_thread_affinities`の形式で指定する。すなわち呼び出し元の
+        /// スレッドを除いた各スレッドに対する論理プロセッサ（1始まり）を`;`で区切って並べたもので、
+        /// 例えば`"1,2;3-4"`のようになる。[`cpu_num_threads`]に0以外を指定したときのみ有効。
+        ///
+        /// [`cpu_num_threads`]: Self::cpu_num_threads
+        pub fn intra_op_thread_affinities(
+            mut self,
+            intra_op_thread_affinities: impl Into<Arc<str>>,
+        ) -> Self {
+            self.options.session_tuning.intra_op_thread_affinities =
+                Some(intra_op_thread_affinities.into());
+            self
+        }
+
         /// [`Synthesizer`]をコンストラクトする。
-        pub fn build(self) -> crate::Result<Synthesizer<T>> {
+        pub fn build(self) -> crate::Result<Synthesizer<T, R>> {
             Inner::new(
-                self.onnxruntime,
+                self.runtime,
                 AssumeSingleTasked(self.text_analyzer),
                 &self.options,
             )
@@ -2481,13 +3099,13 @@ pub(crate) mod blocking {
 
     #[must_use = "this is a builder. it does nothing until `perform`ed"]
     #[derive(Debug)]
-    pub struct LoadVoiceModel<'a> {
-        synthesizer: InnerRefWithoutTextAnalyzer<'a, SingleTasked>,
+    pub struct LoadVoiceModel<'a, R: InferenceRuntime = DefaultInferenceRuntime> {
+        synthesizer: InnerRefWithoutTextAnalyzer<'a, SingleTasked, R>,
         model: &'a crate::blocking::VoiceModelFile,
         options: LoadVoiceModelOptions,
     }
 
-    impl LoadVoiceModel<'_> {
+    impl<R: InferenceRuntime> LoadVoiceModel<'_, R> {
         /// 同じ`id`の[`VoiceModelFile`]が既に読み込まれていたときのふるまい。
         ///
         /// [`VoiceModelFile`]: crate::blocking::VoiceModelFile
@@ -2496,6 +3114,53 @@ pub(crate) mod blocking {
             self
         }
 
+        /// 読み込むスタイルを、指定した[`StyleType`]のものに限定する。
+        ///
+        /// 残ったスタイルのいずれにも必要とされない推論モデル（例えば、`Talk`のみを指定したときの
+        /// 歌唱用のモデル）は読み込まれない。読み込まれなかったスタイルは[`metas`]に含まれず、
+        /// 音声合成に用いようとすると[`StyleNotFound`]エラーとなる。
+        ///
+        /// [`style_ids`]と併用したときは、両方の条件を満たすスタイルのみが読み込まれる。条件を満たす
+        /// スタイルが一つも無いときは、読み込みは[`StyleNotFound`]エラーとなる。
+        ///
+        /// [`StyleType`]: crate::StyleType
+        /// [`metas`]: super::Synthesizer::metas
+        /// [`StyleNotFound`]: crate::ErrorKind::StyleNotFound
+        /// [`style_ids`]: Self::style_ids
+        pub fn style_types(mut self, style_types: impl IntoIterator<Item = StyleType>) -> Self {
+            self.options.style_filter.style_types = Some(style_types.into_iter().collect());
+            self
+        }
+
+        /// 読み込むスタイルを、指定したIDのものに限定する。
+        ///
+        /// VVMに含まれないIDが指定されていたときは、読み込みは[`StyleNotFound`]エラーとなる。それ以外は
+        /// [`style_types`]と同様。
+        ///
+        /// [`StyleNotFound`]: crate::ErrorKind::StyleNotFound
+        /// [`style_types`]: Self::style_types
+        pub fn style_ids(mut self, style_ids: impl IntoIterator<Item = StyleId>) -> Self {
+            self.options.style_filter.style_ids = Some(style_ids.into_iter().collect());
+            self
+        }
+
+        /// VVMの署名を検証し、指定したEd25519公開鍵のいずれかで署名されたもののみを読み込む。
+        ///
+        /// 署名されていないVVMや、manifestが参照するファイルのSHA-256ダイジェストが揃っていないVVMも
+        /// 拒否する。検証に失敗したときは[`VerifyModel`]エラーとなる。
+        ///
+        /// 指定しなかった場合は署名を検証しない。いずれの場合でも、manifestに記載されたダイジェストとの
+        /// 照合は行われる。
+        ///
+        /// [`VerifyModel`]: crate::ErrorKind::VerifyModel
+        pub fn trusted_public_keys(
+            mut self,
+            trusted_public_keys: impl IntoIterator<Item = [u8; 32]>,
+        ) -> Self {
+            self.options.trusted_public_keys = Some(trusted_public_keys.into_iter().collect());
+            self
+        }
+
         /// 実行する。
         pub fn perform(self) -> crate::Result<()> {
             self.synthesizer
@@ -2506,14 +3171,14 @@ pub(crate) mod blocking {
 
     #[must_use = "this is a builder. it does nothing until `perform`ed"]
     #[derive(Debug)]
-    pub struct PrecomputeRender<'a> {
-        synthesizer: InnerRefWithoutTextAnalyzer<'a, SingleTasked>,
+    pub struct PrecomputeRender<'a, R: InferenceRuntime = DefaultInferenceRuntime> {
+        synthesizer: InnerRefWithoutTextAnalyzer<'a, SingleTasked, R>,
         audio_query: &'a AudioQuery,
         style_id: StyleId,
         options: SynthesisOptions<SingleTasked>,
     }
 
-    impl PrecomputeRender<'_> {
+    impl<R: InferenceRuntime> PrecomputeRender<'_, R> {
         pub fn enable_interrogative_upspeak(mut self, enable_interrogative_upspeak: bool) -> Self {
             self.options.enable_interrogative_upspeak = enable_interrogative_upspeak;
             self
@@ -2529,14 +3194,14 @@ pub(crate) mod blocking {
 
     #[must_use = "this is a builder. it does nothing until `perform`ed"]
     #[derive(Debug)]
-    pub struct Synthesis<'a> {
-        synthesizer: InnerRefWithoutTextAnalyzer<'a, SingleTasked>,
+    pub struct Synthesis<'a, R: InferenceRuntime = DefaultInferenceRuntime> {
+        synthesizer: InnerRefWithoutTextAnalyzer<'a, SingleTasked, R>,
         audio_query: &'a AudioQuery,
         style_id: StyleId,
         options: SynthesisOptions<SingleTasked>,
     }
 
-    impl Synthesis<'_> {
+    impl<R: InferenceRuntime> Synthesis<'_, R> {
         pub fn enable_interrogative_upspeak(mut self, enable_interrogative_upspeak: bool) -> Self {
             self.options.enable_interrogative_upspeak = enable_interrogative_upspeak;
             self
@@ -2548,18 +3213,29 @@ pub(crate) mod blocking {
                 .synthesis(self.audio_query, self.style_id, &self.options)
                 .block_on()
         }
+
+        /// 実行し、WAVの代わりに`[-1.0, 1.0]`の範囲の音声波形を返す。
+        ///
+        /// ステレオの場合はインターリーブされたものになる。
+        // Python APIの`synthesis_array`用。
+        #[doc(hidden)]
+        pub fn __perform_wave(self) -> crate::Result<Vec<f32>> {
+            self.synthesizer
+                .synthesis_wave(self.audio_query, self.style_id, &self.options)
+                .block_on()
+        }
     }
 
     #[must_use = "this is a builder. it does nothing until `perform`ed"]
     #[derive(Debug)]
-    pub struct TtsFromKana<'a> {
-        synthesizer: InnerRefWithoutTextAnalyzer<'a, SingleTasked>,
+    pub struct TtsFromKana<'a, R: InferenceRuntime = DefaultInferenceRuntime> {
+        synthesizer: InnerRefWithoutTextAnalyzer<'a, SingleTasked, R>,
         kana: &'a str,
         style_id: StyleId,
         options: TtsOptions<SingleTasked>,
     }
 
-    impl TtsFromKana<'_> {
+    impl<R: InferenceRuntime> TtsFromKana<'_, R> {
         pub fn enable_interrogative_upspeak(mut self, enable_interrogative_upspeak: bool) -> Self {
             self.options.synthesis.enable_interrogative_upspeak = enable_interrogative_upspeak;
             self
@@ -2575,13 +3251,13 @@ pub(crate) mod blocking {
 
     #[must_use = "this is a builder. it does nothing until `perform`ed"]
     #[derive(Debug)]
-    pub struct FrameSynthesis<'a> {
-        synthesizer: InnerRefWithoutTextAnalyzer<'a, SingleTasked>,
+    pub struct FrameSynthesis<'a, R: InferenceRuntime = DefaultInferenceRuntime> {
+        synthesizer: InnerRefWithoutTextAnalyzer<'a, SingleTasked, R>,
         frame_audio_query: &'a FrameAudioQuery,
         style_id: StyleId,
     }
 
-    impl FrameSynthesis<'_> {
+    impl<R: InferenceRuntime> FrameSynthesis<'_, R> {
         /// 実行する。
         pub fn perform(self) -> crate::Result<Vec<u8>> {
             self.synthesizer
@@ -2592,14 +3268,60 @@ pub(crate) mod blocking {
 
     #[must_use = "this is a builder. it does nothing until `perform`ed"]
     #[derive(Debug)]
-    pub struct Tts<'a, T> {
-        synthesizer: &'a Inner<AssumeSingleTasked<T>, SingleTasked>,
+    pub struct FrameRender<'a, R: InferenceRuntime = DefaultInferenceRuntime> {
+        synthesizer: InnerRefWithoutTextAnalyzer<'a, SingleTasked, R>,
+        frame_audio_query: &'a FrameAudioQuery,
+        style_id: StyleId,
+        range: std::ops::Range<usize>,
+    }
+
+    impl<R: InferenceRuntime> FrameRender<'_, R> {
+        /// 実行する。
+        pub fn perform(self) -> crate::Result<Vec<u8>> {
+            self.synthesizer
+                .frame_render(
+                    self.frame_audio_query,
+                    self.style_id,
+                    self.range,
+                    &Default::default(),
+                )
+                .block_on()
+        }
+    }
+
+    #[must_use = "this is a builder. it does nothing until `perform`ed"]
+    #[derive(Debug)]
+    pub struct RenderSongProject<'a, R: InferenceRuntime = DefaultInferenceRuntime> {
+        synthesizer: InnerRefWithoutTextAnalyzer<'a, SingleTasked, R>,
+        tracks: &'a [SongTrack],
+        options: RenderSongProjectOptions<SingleTasked>,
+    }
+
+    impl<R: InferenceRuntime> RenderSongProject<'_, R> {
+        /// 出力のサンプリングレート。
+        pub fn output_sampling_rate(mut self, output_sampling_rate: SamplingRate) -> Self {
+            self.options.output_sampling_rate = output_sampling_rate;
+            self
+        }
+
+        /// 実行する。
+        pub fn perform(self) -> crate::Result<Vec<u8>> {
+            self.synthesizer
+                .render_song_project(self.tracks, &self.options)
+                .block_on()
+        }
+    }
+
+    #[must_use = "this is a builder. it does nothing until `perform`ed"]
+    #[derive(Debug)]
+    pub struct Tts<'a, T, R: InferenceRuntime = DefaultInferenceRuntime> {
+        synthesizer: &'a Inner<AssumeSingleTasked<T>, SingleTasked, R>,
         text: &'a str,
         style_id: StyleId,
         options: TtsOptions<SingleTasked>,
     }
 
-    impl<T: crate::blocking::TextAnalyzer> Tts<'_, T> {
+    impl<T: crate::blocking::TextAnalyzer, R: InferenceRuntime> Tts<'_, T, R> {
         pub fn enable_interrogative_upspeak(mut self, enable_interrogative_upspeak: bool) -> Self {
             self.options.synthesis.enable_interrogative_upspeak = enable_interrogative_upspeak;
             self
@@ -2615,21 +3337,31 @@ pub(crate) mod blocking {
 }
 
 pub(crate) mod nonblocking {
-    use std::fmt::{self, Debug};
+    use std::{
+        fmt::{self, Debug},
+        num::NonZero,
+        sync::Arc,
+    };
 
     use easy_ext::ext;
     use typed_floats::{NonNaNFinite, PositiveFinite};
 
     use crate::{
-        AccentPhrase, AudioQuery, FrameAudioQuery, OnExistingVoiceModelId, Result, Score, StyleId,
-        VoiceModelId, VoiceModelMeta, asyncs::BlockingThreadPool,
+        AccentPhrase, AudioQuery, ExecutionProvider, FrameAudioQuery, GraphOptimizationLevel,
+        InferenceOperationKind, OnExistingVoiceModelId, OperationPlacement, Result, SamplingRate,
+        Score, SongTrack, StyleId, StyleType, VoiceModelId, VoiceModelMeta,
+        asyncs::BlockingThreadPool,
     };
 
     use super::{
-        AccelerationMode, AsInner as _, AssumeBlockable, FrameSynthesisOptions, InitializeOptions,
-        Inner, InnerRefWithoutTextAnalyzer, LoadVoiceModelOptions, SynthesisOptions, TtsOptions,
+        AccelerationMode, AsInner as _, AssumeBlockable, DefaultInferenceRuntime,
+        FrameSynthesisOptions, InferenceRuntime, InitializeOptions, Inner,
+        InnerRefWithoutTextAnalyzer, LoadVoiceModelOptions, RenderSongProjectOptions,
+        SynthesisOptions, TtsOptions,
     };
 
+    pub use super::AudioFeature;
+
     /// 音声シンセサイザ。
     ///
     /// # Performance
@@ -2638,8 +3370,11 @@ pub(crate) mod nonblocking {
     ///
     /// [blocking]: https://docs.rs/crate/blocking
     /// [`nonblocking`モジュールのドキュメント]: crate::nonblocking
-    pub struct Synthesizer<T>(pub(super) Inner<T, BlockingThreadPool>);
+    pub struct Synthesizer<T, R: InferenceRuntime = DefaultInferenceRuntime>(
+        pub(super) Inner<T, BlockingThreadPool, R>,
+    );
 
+    #[cfg(onnxruntime)]
     impl self::Synthesizer<()> {
         /// `Synthesizer`のビルダーをコンストラクトする。
         ///
@@ -2673,17 +3408,34 @@ pub(crate) mod nonblocking {
         /// # }
         /// ```
         pub fn builder(onnxruntime: &'static crate::nonblocking::Onnxruntime) -> Builder<()> {
+            Self::builder_with_runtime(&onnxruntime.0)
+        }
+    }
+
+    impl<R: InferenceRuntime> self::Synthesizer<(), R> {
+        /// ONNX Runtime以外の[`InferenceRuntime`]を用いる、`Synthesizer`のビルダーをコンストラクトする。
+        ///
+        /// [`InferenceRuntime`]: crate::inference_runtime::InferenceRuntime
+        pub fn builder_with_runtime(runtime: &'static R) -> Builder<(), R> {
             Builder {
-                onnxruntime,
+                runtime,
                 text_analyzer: (),
                 options: Default::default(),
             }
         }
     }
 
+    #[cfg(onnxruntime)]
     impl<T: Send + Sync + 'static> self::Synthesizer<T> {
         pub fn onnxruntime(&self) -> &'static crate::nonblocking::Onnxruntime {
-            crate::nonblocking::Onnxruntime::from_blocking(self.0.onnxruntime())
+            crate::nonblocking::Onnxruntime::from_blocking(self.0.runtime())
+        }
+    }
+
+    impl<T: Send + Sync + 'static, R: InferenceRuntime> self::Synthesizer<T, R> {
+        /// 推論ランタイム。
+        pub fn runtime(&self) -> &'static R {
+            self.0.runtime()
         }
 
         /// テキスト解析器。
@@ -2700,7 +3452,7 @@ pub(crate) mod nonblocking {
         pub fn load_voice_model<'a>(
             &'a self,
             model: &'a crate::nonblocking::VoiceModelFile,
-        ) -> LoadVoiceModel<'a> {
+        ) -> LoadVoiceModel<'a, R> {
             LoadVoiceModel {
                 synthesizer: self.0.without_text_analyzer(),
                 model,
@@ -2718,6 +3470,30 @@ pub(crate) mod nonblocking {
             self.0.is_loaded_voice_model(voice_model_id)
         }
 
+        /// 音声モデルを登録する。
+        ///
+        /// 登録された音声モデルはこの時点では読み込まれず、そのスタイルIDが音声合成などで初めて
+        /// 使われたときに自動で読み込まれる。[`Builder::memory_budget`]を指定した場合、上限を超えた
+        /// 分だけ登録された音声モデルが最も長い間使われていないものから順にアンロードされ、
+        /// 再び使われたときにまた読み込まれる。
+        ///
+        /// 同じIDの音声モデルが既に登録されていた場合は置き換える。
+        pub fn register_voice_model(&self, model: crate::nonblocking::VoiceModelFile) {
+            self.0.register_voice_model(model.into_inner());
+        }
+
+        /// 音声モデルの登録を解除する。
+        ///
+        /// 既に読み込まれている音声モデルはアンロードされない。
+        pub fn unregister_voice_model(&self, voice_model_id: VoiceModelId) -> Result<()> {
+            self.0.unregister_voice_model(voice_model_id)
+        }
+
+        /// 指定したIDの音声モデルが登録されているか判定する。
+        pub fn is_registered_voice_model(&self, voice_model_id: VoiceModelId) -> bool {
+            self.0.is_registered_voice_model(voice_model_id)
+        }
+
         #[doc(hidden)]
         pub fn is_loaded_model_by_style_id(&self, style_id: StyleId) -> bool {
             self.0.is_loaded_model_by_style_id(style_id)
@@ -2728,6 +3504,54 @@ pub(crate) mod nonblocking {
             self.0.metas()
         }
 
+        /// AudioQueryから音声合成用の中間表現を生成する。
+        // TODO: 後で復活させる
+        // https://github.com/VOICEVOX/voicevox_core/issues/970
+        #[doc(hidden)]
+        pub fn __precompute_render<'a>(
+            &'a self,
+            audio_query: &'a AudioQuery,
+            style_id: StyleId,
+        ) -> PrecomputeRender<'a, R> {
+            PrecomputeRender {
+                synthesizer: self.0.without_text_analyzer(),
+                audio_query,
+                style_id,
+                options: Default::default(),
+            }
+        }
+
+        /// 中間表現から16bit PCMで音声波形を生成する。
+        ///
+        /// # Errors
+        ///
+        /// `range`の始点が終点より後にあるとき、もしくは終点が[`AudioFeature::frame_length`]を超えるとき、[`ErrorKind::InvalidQuery`]を表わすエラーを返す。
+        ///
+        /// [`ErrorKind::InvalidQuery`]: crate::ErrorKind::InvalidQuery
+        // TODO: 後で復活させる
+        // https://github.com/VOICEVOX/voicevox_core/issues/970
+        #[doc(hidden)]
+        pub async fn __render(
+            &self,
+            audio: &AudioFeature,
+            range: impl Into<std::ops::Range<usize>>,
+        ) -> Result<Vec<u8>> {
+            self.0.render(audio, range.into()).await
+        }
+
+        /// 中間表現から`[-1.0, 1.0]`の範囲の音声波形を生成する。
+        ///
+        /// ステレオの場合はインターリーブされたものになる。
+        // Python APIのストリーミング用。
+        #[doc(hidden)]
+        pub async fn __render_wave(
+            &self,
+            audio: &AudioFeature,
+            range: impl Into<std::ops::Range<usize>>,
+        ) -> Result<Vec<f32>> {
+            self.0.render_wave(audio, range.into()).await
+        }
+
         /// AudioQueryから音声合成を行う。
         ///
         /// # Caveats
@@ -2739,7 +3563,7 @@ pub(crate) mod nonblocking {
             &'a self,
             audio_query: &'a AudioQuery,
             style_id: StyleId,
-        ) -> Synthesis<'a> {
+        ) -> Synthesis<'a, R> {
             Synthesis {
                 synthesizer: self.0.without_text_analyzer(),
                 audio_query,
@@ -2856,7 +3680,7 @@ pub(crate) mod nonblocking {
         /// [`cancellable`]を有効化しない限り、非同期タスクとしてキャンセルしても終わるまで停止しない。
         ///
         /// [`cancellable`]: TtsFromKana::cancellable
-        pub fn tts_from_kana<'a>(&'a self, kana: &'a str, style_id: StyleId) -> TtsFromKana<'a> {
+        pub fn tts_from_kana<'a>(&'a self, kana: &'a str, style_id: StyleId) -> TtsFromKana<'a, R> {
             TtsFromKana {
                 synthesizer: self.0.without_text_analyzer(),
                 kana,
@@ -3129,7 +3953,7 @@ pub(crate) mod nonblocking {
             &'a self,
             frame_audio_query: &'a FrameAudioQuery,
             style_id: StyleId,
-        ) -> FrameSynthesis<'a> {
+        ) -> FrameSynthesis<'a, R> {
             FrameSynthesis {
                 synthesizer: self.0.without_text_analyzer(),
                 frame_audio_query,
@@ -3137,19 +3961,174 @@ pub(crate) mod nonblocking {
                 options: Default::default(),
             }
         }
-    }
 
-    impl<T: crate::nonblocking::TextAnalyzer> self::Synthesizer<T> {
-        /// 日本語のテキストからAccentPhrase (アクセント句)の配列を生成する。
+        /// [歌唱音声合成用のクエリ]のうち、指定したフレーム区間のみを歌唱音声合成し、16bit PCMで返す。
         ///
-        /// [`TextAnalyzer::analyze`]と[`replace_mora_data`]が一体になったショートハンド。詳細は[音声の調整]の節。
+        /// 区間の前後には文脈として数フレームずつ余分に推論を行うため、結果は[`frame_synthesis`]で得られるWAVデータの該当部分とほぼ一致する。そのため一部のノートを編集したときに、その区間のみを再合成して差し替えることができる。ただしビット単位では一致せず、またクロスフェードも行わないため、継ぎ目でサンプル値がわずかに不連続になりうる。クリックノイズを避けたい場合は、呼び出し側で継ぎ目を数ミリ秒クロスフェードすること。
         ///
-        /// # Example
+        /// 結果の先頭は、[`frame_synthesis`]で得られるWAVデータのデータ部の`range.start * 256 * (output_sampling_rate / 24000) * (1 + output_stereo) * 2`バイト目に対応する。
         ///
-        /// ```
-        /// # #[pollster::main]
-        /// # async fn main() -> anyhow::Result<()> {
-        /// # let synthesizer =
+        /// # Caveats
+        ///
+        /// [`cancellable`]を有効化しない限り、非同期タスクとしてキャンセルしても終わるまで停止しない。
+        ///
+        /// # Errors
+        ///
+        /// `range`が[`phonemes`]、[`f0`]、[`volume`]のいずれかのフレーム数を超えるとき、[`ErrorKind::InvalidQuery`]を表わすエラーを返す。
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// # #[pollster::main]
+        /// # async fn main() -> anyhow::Result<()> {
+        /// # let synthesizer =
+        /// #     voicevox_core::__internal::doctest_fixtures::synthesizer_with_sample_voice_model(
+        /// #         test_util::SAMPLE_VOICE_MODEL_FILE_PATH,
+        /// #         test_util::ONNXRUNTIME_DYLIB_PATH,
+        /// #         test_util::OPEN_JTALK_DIC_DIR,
+        /// #     )
+        /// #     .await?;
+        /// #
+        /// use voicevox_core::StyleId;
+        ///
+        /// # const SINGING_TEACHER: StyleId = StyleId(6000);
+        /// const SINGER: StyleId = StyleId(3000);
+        ///
+        /// # let score = &serde_json::from_str::<voicevox_core::Score>(
+        /// #     r#"
+        /// # {
+        /// #   "notes": [
+        /// #     { "key": null, "frame_length": 15, "lyric": "" },
+        /// #     { "key": 60, "frame_length": 45, "lyric": "ド" },
+        /// #     { "key": 62, "frame_length": 45, "lyric": "レ" },
+        /// #     { "key": 64, "frame_length": 45, "lyric": "ミ" },
+        /// #     { "key": null, "frame_length": 15, "lyric": "" }
+        /// #   ]
+        /// # }
+        /// #     "#,
+        /// # )
+        /// # .unwrap();
+        /// #
+        /// # let frame_audio_query = &synthesizer
+        /// #     .create_sing_frame_audio_query(score, SINGING_TEACHER)
+        /// #     .await?;
+        /// #
+        /// // "レ"のノートの区間のみを合成する。
+        /// let pcm = synthesizer
+        ///     .frame_render(&frame_audio_query, SINGER, 60..105)
+        ///     .perform()
+        ///     .await?;
+        /// #
+        /// # Ok(())
+        /// # }
+        /// ```
+        ///
+        /// [歌唱音声合成用のクエリ]: FrameAudioQuery
+        /// [`frame_synthesis`]: Self::frame_synthesis
+        /// [`phonemes`]: FrameAudioQuery::phonemes
+        /// [`f0`]: FrameAudioQuery::f0
+        /// [`volume`]: FrameAudioQuery::volume
+        /// [`ErrorKind::InvalidQuery`]: crate::ErrorKind::InvalidQuery
+        /// [`cancellable`]: FrameRender::cancellable
+        pub fn frame_render<'a>(
+            &'a self,
+            frame_audio_query: &'a FrameAudioQuery,
+            style_id: StyleId,
+            range: std::ops::Range<usize>,
+        ) -> FrameRender<'a, R> {
+            FrameRender {
+                synthesizer: self.0.without_text_analyzer(),
+                frame_audio_query,
+                style_id,
+                range,
+                options: Default::default(),
+            }
+        }
+
+        /// 複数の[トラック]を歌唱音声合成し、ステレオのWAVデータとして重ね合わせる。
+        ///
+        /// 各トラックは[`singing_teacher`]で[`FrameAudioQuery`]を生成した上で[`singer`]で合成され、[`gain`]と[`pan`]に従って左右のチャンネルに配置される。すべてのトラックは先頭が揃えられ、出力の長さは最も長いトラックに合わせられる。重ね合わせた結果のピークが約-1dBFSを超える場合、音割れを避けるため全体の音量が一律に下げられる。
+        ///
+        /// # Caveats
+        ///
+        /// [`cancellable`]を有効化しない限り、非同期タスクとしてキャンセルしても終わるまで停止しない。
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// # #[pollster::main]
+        /// # async fn main() -> anyhow::Result<()> {
+        /// # let synthesizer =
+        /// #     voicevox_core::__internal::doctest_fixtures::synthesizer_with_sample_voice_model(
+        /// #         test_util::SAMPLE_VOICE_MODEL_FILE_PATH,
+        /// #         test_util::ONNXRUNTIME_DYLIB_PATH,
+        /// #         test_util::OPEN_JTALK_DIC_DIR,
+        /// #     )
+        /// #     .await?;
+        /// #
+        /// use voicevox_core::{SongTrack, StyleId};
+        ///
+        /// const SINGING_TEACHER: StyleId = StyleId(6000);
+        /// const SINGER: StyleId = StyleId(3000);
+        ///
+        /// # let score = serde_json::from_str::<voicevox_core::Score>(
+        /// #     r#"
+        /// # {
+        /// #   "notes": [
+        /// #     { "key": null, "frame_length": 15, "lyric": "" },
+        /// #     { "key": 60, "frame_length": 45, "lyric": "ド" },
+        /// #     { "key": 62, "frame_length": 45, "lyric": "レ" },
+        /// #     { "key": 64, "frame_length": 45, "lyric": "ミ" },
+        /// #     { "key": null, "frame_length": 15, "lyric": "" }
+        /// #   ]
+        /// # }
+        /// #     "#,
+        /// # )
+        /// # .unwrap();
+        /// #
+        /// let mut melody = SongTrack::new(score.clone(), SINGING_TEACHER, SINGER);
+        /// melody.pan = (-0.5_f32).try_into().unwrap();
+        /// let mut harmony = SongTrack::new(score, SINGING_TEACHER, SINGER);
+        /// harmony.pan = 0.5_f32.try_into().unwrap();
+        ///
+        /// let wav = synthesizer
+        ///     .render_song_project(&[melody, harmony])
+        ///     .perform()
+        ///     .await?;
+        /// #
+        /// # Ok(())
+        /// # }
+        /// ```
+        ///
+        /// [トラック]: SongTrack
+        /// [`singing_teacher`]: SongTrack::singing_teacher
+        /// [`singer`]: SongTrack::singer
+        /// [`gain`]: SongTrack::gain
+        /// [`pan`]: SongTrack::pan
+        /// [`cancellable`]: RenderSongProject::cancellable
+        pub fn render_song_project<'a>(
+            &'a self,
+            tracks: &'a [SongTrack],
+        ) -> RenderSongProject<'a, R> {
+            RenderSongProject {
+                synthesizer: self.0.without_text_analyzer(),
+                tracks,
+                options: Default::default(),
+            }
+        }
+    }
+
+    impl<T: crate::nonblocking::TextAnalyzer, R: InferenceRuntime> self::Synthesizer<T, R> {
+        /// 日本語のテキストからAccentPhrase (アクセント句)の配列を生成する。
+        ///
+        /// [`TextAnalyzer::analyze`]と[`replace_mora_data`]が一体になったショートハンド。詳細は[音声の調整]の節。
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// # #[pollster::main]
+        /// # async fn main() -> anyhow::Result<()> {
+        /// # let synthesizer =
         /// #     voicevox_core::__internal::doctest_fixtures::synthesizer_with_sample_voice_model(
         /// #         test_util::SAMPLE_VOICE_MODEL_FILE_PATH,
         /// #         test_util::ONNXRUNTIME_DYLIB_PATH,
@@ -3228,7 +4207,7 @@ pub(crate) mod nonblocking {
         /// [`synthesis`]: Self::synthesis
         /// [音声の調整]: ../index.html#音声の調整
         /// [`cancellable`]: Tts::cancellable
-        pub fn tts<'a>(&'a self, text: &'a str, style_id: StyleId) -> Tts<'a, T> {
+        pub fn tts<'a>(&'a self, text: &'a str, style_id: StyleId) -> Tts<'a, T, R> {
             Tts {
                 synthesizer: &self.0,
                 text,
@@ -3238,7 +4217,7 @@ pub(crate) mod nonblocking {
         }
     }
 
-    impl<T: Debug> Debug for self::Synthesizer<T> {
+    impl<T: Debug, R: InferenceRuntime> Debug for self::Synthesizer<T, R> {
         fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
             let fmt = fmt.debug_struct("Synthesizer");
             self.0.fill_debug_struct_body(fmt)
@@ -3246,8 +4225,8 @@ pub(crate) mod nonblocking {
     }
 
     #[ext(IntoBlocking)]
-    impl<T> self::Synthesizer<T> {
-        pub fn into_blocking(self) -> super::blocking::Synthesizer<AssumeBlockable<T>> {
+    impl<T, R: InferenceRuntime> self::Synthesizer<T, R> {
+        pub fn into_blocking(self) -> super::blocking::Synthesizer<AssumeBlockable<T>, R> {
             let x = self.0;
             super::blocking::Synthesizer(x.into())
         }
@@ -3255,18 +4234,18 @@ pub(crate) mod nonblocking {
 
     #[must_use]
     #[derive(Debug)]
-    pub struct Builder<T> {
-        onnxruntime: &'static crate::nonblocking::Onnxruntime,
+    pub struct Builder<T, R: InferenceRuntime = DefaultInferenceRuntime> {
+        runtime: &'static R,
         text_analyzer: T,
         options: InitializeOptions,
     }
 
-    impl<T> Builder<T> {
+    impl<T, R: InferenceRuntime> Builder<T, R> {
         /// テキスト解析器。
-        pub fn text_analyzer<T2>(self, text_analyzer: T2) -> Builder<T2> {
+        pub fn text_analyzer<T2>(self, text_analyzer: T2) -> Builder<T2, R> {
             Builder {
                 text_analyzer,
-                onnxruntime: self.onnxruntime,
+                runtime: self.runtime,
                 options: self.options,
             }
         }
@@ -3276,6 +4255,43 @@ pub(crate) mod nonblocking {
             self
         }
 
+        /// [`AccelerationMode::Auto`]および[`AccelerationMode::Gpu`]で試す[`ExecutionProvider`]を、
+        /// 優先度順に指定する。デフォルトは[CUDA], [DirectML]の順。
+        ///
+        /// 実際に利用可能なもののうち、最初のものが重い推論に使われる。ただし[`AccelerationMode::Gpu`]
+        /// では、[XNNPACK]のようなCPU上で動作するものは無視される。
+        ///
+        /// 現在はRust APIのみの機能であり、C API、Python API、Java APIからは指定できない。それらでは
+        /// 常にデフォルトのものが試される。
+        ///
+        /// [CUDA]: ExecutionProvider::Cuda
+        /// [DirectML]: ExecutionProvider::Dml
+        /// [XNNPACK]: ExecutionProvider::Xnnpack
+        pub fn execution_providers(
+            mut self,
+            execution_providers: impl IntoIterator<Item = ExecutionProvider>,
+        ) -> Self {
+            self.options.execution_providers = execution_providers.into_iter().collect();
+            self
+        }
+
+        /// 推論の操作ごとに、推論を行うデバイスとCPU利用数を指定する。
+        ///
+        /// 指定しなかった操作については、音素長や音高の推論といった軽いものはCPUで、波形の生成といった重い
+        /// ものは[`OperationDevice::Accelerator`]で行われる。
+        ///
+        /// [`OperationDevice::Accelerator`]: crate::OperationDevice::Accelerator
+        pub fn operation_placement(
+            mut self,
+            operation: InferenceOperationKind,
+            placement: OperationPlacement,
+        ) -> Self {
+            self.options
+                .operation_placements
+                .insert(operation, placement);
+            self
+        }
+
         /// CPU利用数を指定。0を指定すると環境に合わせたCPUが利用される。
         ///
         /// # Performance
@@ -3289,21 +4305,132 @@ pub(crate) mod nonblocking {
             self
         }
 
+        /// 推論操作ごとに作成するセッションの数。デフォルトは1。
+        ///
+        /// 一つのセッションは同時に一つの推論しか実行できないため、同じスタイルに対する音声合成を
+        /// 並行して行うと順番待ちになる。2以上を指定すると、空いているセッションに推論を割り当てる
+        /// ようになる。
+        ///
+        /// # Performance
+        ///
+        /// 音声モデルの読み込み時間とメモリ（GPUメモリ）の使用量は、おおよそこの値に比例して増える。
+        ///
+        /// CPUで推論を行うセッションは、複製のそれぞれが[`cpu_num_threads`]個のスレッドを用いる。
+        /// そのため全ての複製が同時に推論を行うと、最大で`session_replicas`×[`cpu_num_threads`]個の
+        /// スレッドが動き、論理プロセッサの数を超えるとかえって遅くなることがある。CPUで複製を作る
+        /// 場合は、[`cpu_num_threads`]を論理プロセッサの数を`session_replicas`で割った程度にすること。
+        ///
+        /// [`cpu_num_threads`]: Self::cpu_num_threads
+        pub fn session_replicas(mut self, session_replicas: NonZero<usize>) -> Self {
+            self.options.session_replicas = session_replicas;
+            self
+        }
+
+        /// [`Synthesizer::register_voice_model`]で登録された音声モデルを自動で読み込むときに、VVMの署名を
+        /// 検証し、指定したEd25519公開鍵のいずれかで署名されたもののみを読み込む。
+        ///
+        /// 検証に失敗したときは、その音声モデルを必要とした音声合成などが[`VerifyModel`]エラーとなる。
+        /// [`Synthesizer::load_voice_model`]には影響しないため、そちらでは
+        /// [`LoadVoiceModel::trusted_public_keys`]を用いること。
+        ///
+        /// [`VerifyModel`]: crate::ErrorKind::VerifyModel
+        pub fn trusted_public_keys(
+            mut self,
+            trusted_public_keys: impl IntoIterator<Item = [u8; 32]>,
+        ) -> Self {
+            self.options.trusted_public_keys = Some(trusted_public_keys.into_iter().collect());
+            self
+        }
+
+        /// 読み込まれている音声モデルのメモリ使用量の上限（バイト）。デフォルトは無制限。
+        ///
+        /// 上限を超えると、[`Synthesizer::register_voice_model`]で登録された音声モデルが最も長い間
+        /// 使われていないものから順にアンロードされる。メモリ使用量はモデルのバイト列の大きさと
+        /// [`session_replicas`]から見積もった概算値であり、実際の使用量とは異なる。
+        ///
+        /// [`session_replicas`]: Self::session_replicas
+        pub fn memory_budget(mut self, memory_budget: u64) -> Self {
+            self.options.memory_budget = Some(memory_budget);
+            self
+        }
+
+        /// 推論の実行グラフの最適化レベル。デフォルトは[`GraphOptimizationLevel::Basic`]。
+        pub fn graph_optimization_level(
+            mut self,
+            graph_optimization_level: GraphOptimizationLevel,
+        ) -> Self {
+            self.options.session_tuning.graph_optimization_level = graph_optimization_level;
+            self
+        }
+
+        /// 推論グラフ内の独立したノードを並列に実行するためのスレッド数。デフォルトは0。
+        ///
+        /// 0以外を指定すると、ノードを並列に実行するようになる。0を指定するとノードを順番に実行する。
+        ///
+        /// [`cpu_num_threads`]は一つのノードの内部で使われるスレッド数である。
+        ///
+        /// [`cpu_num_threads`]: Self::cpu_num_threads
+        pub fn inter_op_num_threads(mut self, inter_op_num_threads: u16) -> Self {
+            self.options.session_tuning.inter_op_num_threads = inter_op_num_threads;
+            self
+        }
+
+        /// CPU上のメモリ確保にアリーナを用いるかどうか。デフォルトは`true`。
+        ///
+        /// `false`にすると推論ごとのメモリ確保が増える代わりに、使い終えたメモリがすぐに解放される。
+        pub fn cpu_memory_arena(mut self, cpu_memory_arena: bool) -> Self {
+            self.options.session_tuning.cpu_memory_arena = cpu_memory_arena;
+            self
+        }
+
+        /// 入力の形から推論に必要なメモリ量を予測し、事前に確保するかどうか。デフォルトは`true`。
+        ///
+        /// DirectMLを利用するときは、この設定によらず無効になる。
+        pub fn memory_pattern(mut self, memory_pattern: bool) -> Self {
+            self.options.session_tuning.memory_pattern = memory_pattern;
+            self
+        }
+
+        /// 決定的な計算を強制するかどうか。デフォルトは`false`。
+        ///
+        /// `true`にすると同じ入力に対して常に同じ出力が得られるようになる代わりに、推論が遅くなる
+        /// 可能性がある。
+        pub fn deterministic_compute(mut self, deterministic_compute: bool) -> Self {
+            self.options.session_tuning.deterministic_compute = deterministic_compute;
+            self
+        }
+
+        /// [`cpu_num_threads`]のスレッドを割り当てる論理プロセッサ。デフォルトでは指定しない。
+        ///
+        /// ONNX Runtimeの`session.intra_op_thread_affinities`の形式で指定する。すなわち呼び出し元の
+        /// スレッドを除いた各スレッドに対する論理プロセッサ（1始まり）を`;`で区切って並べたもので、
+        /// 例えば`"1,2;3-4"`のようになる。[`cpu_num_threads`]に0以外を指定したときのみ有効。
+        ///
+        /// [`cpu_num_threads`]: Self::cpu_num_threads
+        pub fn intra_op_thread_affinities(
+            mut self,
+            intra_op_thread_affinities: impl Into<Arc<str>>,
+        ) -> Self {
+            self.options.session_tuning.intra_op_thread_affinities =
+                Some(intra_op_thread_affinities.into());
+            self
+        }
+
         /// [`Synthesizer`]をコンストラクトする。
-        pub fn build(self) -> crate::Result<Synthesizer<T>> {
-            Inner::new(&self.onnxruntime.0, self.text_analyzer, &self.options).map(Synthesizer)
+        pub fn build(self) -> crate::Result<Synthesizer<T, R>> {
+            Inner::new(self.runtime, self.text_analyzer, &self.options).map(Synthesizer)
         }
     }
 
     #[must_use = "this is a builder. it does nothing until `perform`ed"]
     #[derive(Debug)]
-    pub struct LoadVoiceModel<'a> {
-        synthesizer: InnerRefWithoutTextAnalyzer<'a, BlockingThreadPool>,
+    pub struct LoadVoiceModel<'a, R: InferenceRuntime = DefaultInferenceRuntime> {
+        synthesizer: InnerRefWithoutTextAnalyzer<'a, BlockingThreadPool, R>,
         model: &'a crate::nonblocking::VoiceModelFile,
         options: LoadVoiceModelOptions,
     }
 
-    impl LoadVoiceModel<'_> {
+    impl<R: InferenceRuntime> LoadVoiceModel<'_, R> {
         /// 同じ`id`の[`VoiceModelFile`]が既に読み込まれていたときのふるまい。
         ///
         /// [`VoiceModelFile`]: crate::nonblocking::VoiceModelFile
@@ -3312,6 +4439,53 @@ pub(crate) mod nonblocking {
             self
         }
 
+        /// 読み込むスタイルを、指定した[`StyleType`]のものに限定する。
+        ///
+        /// 残ったスタイルのいずれにも必要とされない推論モデル（例えば、`Talk`のみを指定したときの
+        /// 歌唱用のモデル）は読み込まれない。読み込まれなかったスタイルは[`metas`]に含まれず、
+        /// 音声合成に用いようとすると[`StyleNotFound`]エラーとなる。
+        ///
+        /// [`style_ids`]と併用したときは、両方の条件を満たすスタイルのみが読み込まれる。条件を満たす
+        /// スタイルが一つも無いときは、読み込みは[`StyleNotFound`]エラーとなる。
+        ///
+        /// [`StyleType`]: crate::StyleType
+        /// [`metas`]: super::Synthesizer::metas
+        /// [`StyleNotFound`]: crate::ErrorKind::StyleNotFound
+        /// [`style_ids`]: Self::style_ids
+        pub fn style_types(mut self, style_types: impl IntoIterator<Item = StyleType>) -> Self {
+            self.options.style_filter.style_types = Some(style_types.into_iter().collect());
+            self
+        }
+
+        /// 読み込むスタイルを、指定したIDのものに限定する。
+        ///
+        /// VVMに含まれないIDが指定されていたときは、読み込みは[`StyleNotFound`]エラーとなる。それ以外は
+        /// [`style_types`]と同様。
+        ///
+        /// [`StyleNotFound`]: crate::ErrorKind::StyleNotFound
+        /// [`style_types`]: Self::style_types
+        pub fn style_ids(mut self, style_ids: impl IntoIterator<Item = StyleId>) -> Self {
+            self.options.style_filter.style_ids = Some(style_ids.into_iter().collect());
+            self
+        }
+
+        /// VVMの署名を検証し、指定したEd25519公開鍵のいずれかで署名されたもののみを読み込む。
+        ///
+        /// 署名されていないVVMや、manifestが参照するファイルのSHA-256ダイジェストが揃っていないVVMも
+        /// 拒否する。検証に失敗したときは[`VerifyModel`]エラーとなる。
+        ///
+        /// 指定しなかった場合は署名を検証しない。いずれの場合でも、manifestに記載されたダイジェストとの
+        /// 照合は行われる。
+        ///
+        /// [`VerifyModel`]: crate::ErrorKind::VerifyModel
+        pub fn trusted_public_keys(
+            mut self,
+            trusted_public_keys: impl IntoIterator<Item = [u8; 32]>,
+        ) -> Self {
+            self.options.trusted_public_keys = Some(trusted_public_keys.into_iter().collect());
+            self
+        }
+
         /// 実行する。
         pub async fn perform(self) -> crate::Result<()> {
             self.synthesizer
@@ -3322,14 +4496,37 @@ pub(crate) mod nonblocking {
 
     #[must_use = "this is a builder. it does nothing until `perform`ed"]
     #[derive(Debug)]
-    pub struct Synthesis<'a> {
-        synthesizer: InnerRefWithoutTextAnalyzer<'a, BlockingThreadPool>,
+    pub struct PrecomputeRender<'a, R: InferenceRuntime = DefaultInferenceRuntime> {
+        synthesizer: InnerRefWithoutTextAnalyzer<'a, BlockingThreadPool, R>,
+        audio_query: &'a AudioQuery,
+        style_id: StyleId,
+        options: SynthesisOptions<BlockingThreadPool>,
+    }
+
+    impl<R: InferenceRuntime> PrecomputeRender<'_, R> {
+        pub fn enable_interrogative_upspeak(mut self, enable_interrogative_upspeak: bool) -> Self {
+            self.options.enable_interrogative_upspeak = enable_interrogative_upspeak;
+            self
+        }
+
+        /// 実行する。
+        pub async fn perform(self) -> crate::Result<AudioFeature> {
+            self.synthesizer
+                .precompute_render(self.audio_query, self.style_id, &self.options)
+                .await
+        }
+    }
+
+    #[must_use = "this is a builder. it does nothing until `perform`ed"]
+    #[derive(Debug)]
+    pub struct Synthesis<'a, R: InferenceRuntime = DefaultInferenceRuntime> {
+        synthesizer: InnerRefWithoutTextAnalyzer<'a, BlockingThreadPool, R>,
         audio_query: &'a AudioQuery,
         style_id: StyleId,
         options: SynthesisOptions<BlockingThreadPool>,
     }
 
-    impl Synthesis<'_> {
+    impl<R: InferenceRuntime> Synthesis<'_, R> {
         pub fn enable_interrogative_upspeak(mut self, enable_interrogative_upspeak: bool) -> Self {
             self.options.enable_interrogative_upspeak = enable_interrogative_upspeak;
             self
@@ -3351,18 +4548,29 @@ pub(crate) mod nonblocking {
                 .synthesis(self.audio_query, self.style_id, &self.options)
                 .await
         }
+
+        /// 実行し、WAVの代わりに`[-1.0, 1.0]`の範囲の音声波形を返す。
+        ///
+        /// ステレオの場合はインターリーブされたものになる。
+        // Python APIの`synthesis_array`用。
+        #[doc(hidden)]
+        pub async fn __perform_wave(self) -> crate::Result<Vec<f32>> {
+            self.synthesizer
+                .synthesis_wave(self.audio_query, self.style_id, &self.options)
+                .await
+        }
     }
 
     #[must_use = "this is a builder. it does nothing until `perform`ed"]
     #[derive(Debug)]
-    pub struct TtsFromKana<'a> {
-        synthesizer: InnerRefWithoutTextAnalyzer<'a, BlockingThreadPool>,
+    pub struct TtsFromKana<'a, R: InferenceRuntime = DefaultInferenceRuntime> {
+        synthesizer: InnerRefWithoutTextAnalyzer<'a, BlockingThreadPool, R>,
         kana: &'a str,
         style_id: StyleId,
         options: TtsOptions<BlockingThreadPool>,
     }
 
-    impl TtsFromKana<'_> {
+    impl<R: InferenceRuntime> TtsFromKana<'_, R> {
         pub fn enable_interrogative_upspeak(mut self, enable_interrogative_upspeak: bool) -> Self {
             self.options.synthesis.enable_interrogative_upspeak = enable_interrogative_upspeak;
             self
@@ -3388,14 +4596,14 @@ pub(crate) mod nonblocking {
 
     #[must_use = "this is a builder. it does nothing until `perform`ed"]
     #[derive(Debug)]
-    pub struct FrameSynthesis<'a> {
-        synthesizer: InnerRefWithoutTextAnalyzer<'a, BlockingThreadPool>,
+    pub struct FrameSynthesis<'a, R: InferenceRuntime = DefaultInferenceRuntime> {
+        synthesizer: InnerRefWithoutTextAnalyzer<'a, BlockingThreadPool, R>,
         frame_audio_query: &'a FrameAudioQuery,
         style_id: StyleId,
         options: FrameSynthesisOptions<BlockingThreadPool>,
     }
 
-    impl FrameSynthesis<'_> {
+    impl<R: InferenceRuntime> FrameSynthesis<'_, R> {
         /// 音声モデルの実行をキャンセル可能にするかどうか。
         ///
         /// このオプションを有効にすると、負荷がかかっている状況下でハングする可能性がある。そのためデフォルトでは無効化されている。[VOICEVOX/voicevox_core#968]を参照。
@@ -3409,21 +4617,88 @@ pub(crate) mod nonblocking {
         /// 実行する。
         pub async fn perform(self) -> crate::Result<Vec<u8>> {
             self.synthesizer
-                .frame_synthesis(self.frame_audio_query, self.style_id, &Default::default())
+                .frame_synthesis(self.frame_audio_query, self.style_id, &self.options)
                 .await
         }
     }
 
     #[must_use = "this is a builder. it does nothing until `perform`ed"]
     #[derive(Debug)]
-    pub struct Tts<'a, T> {
-        synthesizer: &'a Inner<T, BlockingThreadPool>,
+    pub struct FrameRender<'a, R: InferenceRuntime = DefaultInferenceRuntime> {
+        synthesizer: InnerRefWithoutTextAnalyzer<'a, BlockingThreadPool, R>,
+        frame_audio_query: &'a FrameAudioQuery,
+        style_id: StyleId,
+        range: std::ops::Range<usize>,
+        options: FrameSynthesisOptions<BlockingThreadPool>,
+    }
+
+    impl<R: InferenceRuntime> FrameRender<'_, R> {
+        /// 音声モデルの実行をキャンセル可能にするかどうか。
+        ///
+        /// このオプションを有効にすると、負荷がかかっている状況下でハングする可能性がある。そのためデフォルトでは無効化されている。[VOICEVOX/voicevox_core#968]を参照。
+        ///
+        /// [VOICEVOX/voicevox_core#968]: https://github.com/VOICEVOX/voicevox_core/issues/968
+        pub fn cancellable(mut self, cancellable: bool) -> Self {
+            self.options.cancellable = cancellable;
+            self
+        }
+
+        /// 実行する。
+        pub async fn perform(self) -> crate::Result<Vec<u8>> {
+            self.synthesizer
+                .frame_render(
+                    self.frame_audio_query,
+                    self.style_id,
+                    self.range,
+                    &self.options,
+                )
+                .await
+        }
+    }
+
+    #[must_use = "this is a builder. it does nothing until `perform`ed"]
+    #[derive(Debug)]
+    pub struct RenderSongProject<'a, R: InferenceRuntime = DefaultInferenceRuntime> {
+        synthesizer: InnerRefWithoutTextAnalyzer<'a, BlockingThreadPool, R>,
+        tracks: &'a [SongTrack],
+        options: RenderSongProjectOptions<BlockingThreadPool>,
+    }
+
+    impl<R: InferenceRuntime> RenderSongProject<'_, R> {
+        /// 出力のサンプリングレート。
+        pub fn output_sampling_rate(mut self, output_sampling_rate: SamplingRate) -> Self {
+            self.options.output_sampling_rate = output_sampling_rate;
+            self
+        }
+
+        /// 音声モデルの実行をキャンセル可能にするかどうか。
+        ///
+        /// このオプションを有効にすると、負荷がかかっている状況下でハングする可能性がある。そのためデフォルトでは無効化されている。[VOICEVOX/voicevox_core#968]を参照。
+        ///
+        /// [VOICEVOX/voicevox_core#968]: https://github.com/VOICEVOX/voicevox_core/issues/968
+        pub fn cancellable(mut self, cancellable: bool) -> Self {
+            self.options.cancellable = cancellable;
+            self
+        }
+
+        /// 実行する。
+        pub async fn perform(self) -> crate::Result<Vec<u8>> {
+            self.synthesizer
+                .render_song_project(self.tracks, &self.options)
+                .await
+        }
+    }
+
+    #[must_use = "this is a builder. it does nothing until `perform`ed"]
+    #[derive(Debug)]
+    pub struct Tts<'a, T, R: InferenceRuntime = DefaultInferenceRuntime> {
+        synthesizer: &'a Inner<T, BlockingThreadPool, R>,
         text: &'a str,
         style_id: StyleId,
         options: TtsOptions<BlockingThreadPool>,
     }
 
-    impl<T: crate::nonblocking::TextAnalyzer> Tts<'_, T> {
+    impl<T: crate::nonblocking::TextAnalyzer, R: InferenceRuntime> Tts<'_, T, R> {
         pub fn enable_interrogative_upspeak(mut self, enable_interrogative_upspeak: bool) -> Self {
             self.options.synthesis.enable_interrogative_upspeak = enable_interrogative_upspeak;
             self
@@ -3448,17 +4723,25 @@ pub(crate) mod nonblocking {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, onnxruntime))]
 mod tests {
     use std::{mem, num::NonZero};
 
-    use super::{AccelerationMode, AsInner as _, DEFAULT_HEAVY_INFERENCE_CANCELLABLE};
+    use super::{
+        AccelerationMode, AsInner as _, DEFAULT_HEAVY_INFERENCE_CANCELLABLE, DeviceSpec,
+        InferenceRuntime as _, SessionTuning,
+    };
     use crate::{
-        AccentPhrase, FramePhoneme, Note, NoteId, Result, Score, StyleId,
-        asyncs::BlockingThreadPool, engine::talk::Mora, macros::tests::assert_debug_fmt_eq,
+        AccentPhrase, AudioQuery, ExecutionProvider, FrameAudioQuery, FramePhoneme,
+        GraphOptimizationLevel, InferenceOperationKind, Note, NoteId, OperationDevice,
+        OperationPlacement, Result, SamplingRate, Score, SongTrack, StyleId,
+        asyncs::BlockingThreadPool,
+        core::infer::domains::TalkOperation,
+        engine::{s16le_from_f32_pcm, talk::Mora},
+        macros::tests::assert_debug_fmt_eq,
         numerics::non_zero,
     };
-    use ::test_util::OPEN_JTALK_DIC_DIR;
+    use ::test_util::{OPEN_JTALK_DIC_DIR, SAMPLE_VOICE_MODEL_FILE_PATH};
     use itertools::Itertools as _;
     use rstest::rstest;
     use typed_floats::tf32;
@@ -3490,16 +4773,230 @@ mod tests {
 
     #[rstest]
     #[tokio::test]
-    async fn is_use_gpu_works() {
-        let syntesizer = super::nonblocking::Synthesizer::builder(
+    async fn autoload_verifies_signature_with_trusted_public_keys() {
+        let syntesizer = super::nonblocking::Synthesizer::builder(
+            crate::nonblocking::Onnxruntime::from_test_util_data()
+                .await
+                .unwrap(),
+        )
+        .acceleration_mode(AccelerationMode::Cpu)
+        .trusted_public_keys([crate::core::vvm::public_key(&[0; 32])])
+        .build()
+        .unwrap();
+        syntesizer
+            .register_voice_model(crate::nonblocking::VoiceModelFile::sample().await.unwrap());
+
+        // サンプルのVVMは署名されていない
+        let err = syntesizer
+            .synthesis(&AudioQuery::default(), StyleId::new(0))
+            .perform()
+            .await
+            .unwrap_err();
+        assert_eq!(crate::ErrorKind::VerifyModel, err.kind());
+        assert!(!syntesizer.is_loaded_model_by_style_id(StyleId::new(0)));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn is_use_gpu_works() {
+        let syntesizer = super::nonblocking::Synthesizer::builder(
+            crate::nonblocking::Onnxruntime::from_test_util_data()
+                .await
+                .unwrap(),
+        )
+        .acceleration_mode(AccelerationMode::Cpu)
+        .build()
+        .unwrap();
+        assert!(!syntesizer.is_gpu_mode());
+    }
+
+    #[rstest]
+    #[case(ExecutionProvider::Xnnpack)]
+    #[case(ExecutionProvider::OpenVinoCpu)]
+    #[case(ExecutionProvider::OneDnn)]
+    #[tokio::test]
+    async fn cpu_execution_providers_work(#[case] execution_provider: ExecutionProvider) {
+        let onnxruntime = crate::nonblocking::Onnxruntime::from_test_util_data()
+            .await
+            .unwrap();
+        let syntesizer = super::nonblocking::Synthesizer::builder(onnxruntime)
+            .acceleration_mode(AccelerationMode::Auto)
+            .execution_providers([execution_provider])
+            .build()
+            .unwrap();
+        assert!(!syntesizer.is_gpu_mode());
+
+        // 利用できないExecution ProviderであればCPUにフォールバックする
+        let rt = &onnxruntime.0;
+        let expected_device = if rt.supported_devices().unwrap()[execution_provider]
+            && rt.test_execution_provider(execution_provider).is_ok()
+        {
+            DeviceSpec::ExecutionProvider(execution_provider)
+        } else {
+            DeviceSpec::Cpu
+        };
+        assert_eq!(
+            expected_device,
+            syntesizer.0.status().session_options().talk[TalkOperation::Decode].device,
+        );
+
+        let model = &crate::nonblocking::VoiceModelFile::sample().await.unwrap();
+        syntesizer.load_voice_model(model).perform().await.unwrap();
+
+        let query = syntesizer
+            .create_audio_query_from_kana("コンニチワ'", StyleId::new(0))
+            .await
+            .unwrap();
+        let wav = syntesizer
+            .synthesis(&query, StyleId::new(0))
+            .perform()
+            .await
+            .unwrap();
+        assert!(!wav.is_empty());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn gpu_mode_ignores_cpu_execution_providers() {
+        let result = super::nonblocking::Synthesizer::builder(
+            crate::nonblocking::Onnxruntime::from_test_util_data()
+                .await
+                .unwrap(),
+        )
+        .acceleration_mode(AccelerationMode::Gpu)
+        .execution_providers([ExecutionProvider::Xnnpack])
+        .build();
+        assert_eq!(
+            crate::ErrorKind::GpuSupport,
+            result.map(|_| ()).unwrap_err().kind(),
+        );
+    }
+
+    #[rstest]
+    #[case(AccelerationMode::Cpu, OperationDevice::Accelerator, Some(1))]
+    #[case(AccelerationMode::Auto, OperationDevice::Cpu, None)]
+    #[case(AccelerationMode::Auto, OperationDevice::Cpu, Some(2))]
+    #[tokio::test]
+    async fn operation_placement_works(
+        #[case] acceleration_mode: AccelerationMode,
+        #[case] device: OperationDevice,
+        #[case] cpu_num_threads: Option<u16>,
+    ) {
+        let syntesizer = InferenceOperationKind::ALL
+            .iter()
+            .fold(
+                super::nonblocking::Synthesizer::builder(
+                    crate::nonblocking::Onnxruntime::from_test_util_data()
+                        .await
+                        .unwrap(),
+                )
+                .acceleration_mode(acceleration_mode),
+                |builder, &op| {
+                    builder.operation_placement(
+                        op,
+                        OperationPlacement {
+                            device,
+                            cpu_num_threads,
+                        },
+                    )
+                },
+            )
+            .build()
+            .unwrap();
+        if device == OperationDevice::Cpu {
+            assert!(!syntesizer.is_gpu_mode());
+        }
+
+        // いずれのケースも、`OperationDevice::Cpu`か`AccelerationMode::Cpu`のどちらかを指定している
+        assert!(device == OperationDevice::Cpu || acceleration_mode == AccelerationMode::Cpu);
+        let expected_cpu_num_threads = cpu_num_threads.unwrap_or(super::DEFAULT_CPU_NUM_THREADS);
+        let session_options = syntesizer.0.status().session_options();
+        for options in itertools::chain!(
+            session_options.talk.values(),
+            session_options.streaming_talk.values(),
+            session_options.singing_teacher.values(),
+            session_options.frame_decode.values(),
+        ) {
+            assert_eq!(DeviceSpec::Cpu, options.device);
+            assert_eq!(expected_cpu_num_threads, options.cpu_num_threads);
+        }
+
+        let model = &crate::nonblocking::VoiceModelFile::sample().await.unwrap();
+        syntesizer.load_voice_model(model).perform().await.unwrap();
+
+        let query = syntesizer
+            .create_audio_query_from_kana("コンニチワ'", StyleId::new(0))
+            .await
+            .unwrap();
+        let wav = syntesizer
+            .synthesis(&query, StyleId::new(0))
+            .perform()
+            .await
+            .unwrap();
+        assert!(!wav.is_empty());
+    }
+
+    #[rstest]
+    #[case(GraphOptimizationLevel::Disable, 0, true, true, false, None)]
+    #[case(GraphOptimizationLevel::Extended, 2, false, true, false, None)]
+    #[case(GraphOptimizationLevel::All, 0, true, false, true, Some("1"))]
+    #[tokio::test]
+    async fn session_tuning_works(
+        #[case] graph_optimization_level: GraphOptimizationLevel,
+        #[case] inter_op_num_threads: u16,
+        #[case] cpu_memory_arena: bool,
+        #[case] memory_pattern: bool,
+        #[case] deterministic_compute: bool,
+        #[case] intra_op_thread_affinities: Option<&str>,
+    ) {
+        let mut builder = super::nonblocking::Synthesizer::builder(
             crate::nonblocking::Onnxruntime::from_test_util_data()
                 .await
                 .unwrap(),
         )
         .acceleration_mode(AccelerationMode::Cpu)
-        .build()
-        .unwrap();
-        assert!(!syntesizer.is_gpu_mode());
+        .cpu_num_threads(2)
+        .graph_optimization_level(graph_optimization_level)
+        .inter_op_num_threads(inter_op_num_threads)
+        .cpu_memory_arena(cpu_memory_arena)
+        .memory_pattern(memory_pattern)
+        .deterministic_compute(deterministic_compute);
+        if let Some(intra_op_thread_affinities) = intra_op_thread_affinities {
+            builder = builder.intra_op_thread_affinities(intra_op_thread_affinities);
+        }
+        let syntesizer = builder.build().unwrap();
+
+        let expected = SessionTuning {
+            graph_optimization_level,
+            inter_op_num_threads,
+            cpu_memory_arena,
+            memory_pattern,
+            deterministic_compute,
+            intra_op_thread_affinities: intra_op_thread_affinities.map(Into::into),
+        };
+        let session_options = syntesizer.0.status().session_options();
+        for options in itertools::chain!(
+            session_options.talk.values(),
+            session_options.streaming_talk.values(),
+            session_options.singing_teacher.values(),
+            session_options.frame_decode.values(),
+        ) {
+            assert_eq!(expected, options.tuning);
+        }
+
+        let model = &crate::nonblocking::VoiceModelFile::sample().await.unwrap();
+        syntesizer.load_voice_model(model).perform().await.unwrap();
+
+        let query = syntesizer
+            .create_audio_query_from_kana("コンニチワ'", StyleId::new(0))
+            .await
+            .unwrap();
+        let wav = syntesizer
+            .synthesis(&query, StyleId::new(0))
+            .perform()
+            .await
+            .unwrap();
+        assert!(!wav.is_empty());
     }
 
     #[rstest]
@@ -3533,6 +5030,28 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[tokio::test]
+    async fn to_nonblocking_works() {
+        use super::blocking::ToNonblocking as _;
+
+        let style_id = StyleId::new(1);
+        let syntesizer = super::blocking::Synthesizer::builder(
+            crate::blocking::Onnxruntime::from_test_util_data().unwrap(),
+        )
+        .acceleration_mode(AccelerationMode::Cpu)
+        .build()
+        .unwrap();
+        let nonblocking = syntesizer.to_nonblocking();
+
+        let model = crate::blocking::VoiceModelFile::open(SAMPLE_VOICE_MODEL_FILE_PATH).unwrap();
+        syntesizer.load_voice_model(&model).perform().unwrap();
+        assert!(nonblocking.is_loaded_model_by_style_id(style_id));
+
+        nonblocking.unload_voice_model(model.id()).unwrap();
+        assert!(!syntesizer.is_loaded_model_by_style_id(style_id));
+    }
+
     #[rstest]
     #[tokio::test]
     async fn predict_duration_works() {
@@ -4201,6 +5720,250 @@ mod tests {
         Kana(&'static str),
     }
 
+    #[rstest]
+    #[case(0..15)]
+    #[case(60..105)]
+    #[case(100..165)]
+    #[case(165..165)]
+    #[tokio::test]
+    async fn frame_render_matches_frame_synthesis(#[case] range: std::ops::Range<usize>) {
+        let synthesizer = super::nonblocking::Synthesizer::builder(
+            crate::nonblocking::Onnxruntime::from_test_util_data()
+                .await
+                .unwrap(),
+        )
+        .acceleration_mode(AccelerationMode::Cpu)
+        .build()
+        .unwrap();
+
+        let model = &crate::nonblocking::VoiceModelFile::sample().await.unwrap();
+        synthesizer.load_voice_model(model).perform().await.unwrap();
+
+        let score = &serde_json::from_str::<Score>(
+            r#"
+{
+  "notes": [
+    { "key": null, "frame_length": 15, "lyric": "" },
+    { "key": 60, "frame_length": 45, "lyric": "ド" },
+    { "key": 62, "frame_length": 45, "lyric": "レ" },
+    { "key": 64, "frame_length": 45, "lyric": "ミ" },
+    { "key": null, "frame_length": 15, "lyric": "" }
+  ]
+}
+            "#,
+        )
+        .unwrap();
+
+        let frame_audio_query = &synthesizer
+            .create_sing_frame_audio_query(score, 6000.into())
+            .await
+            .unwrap();
+
+        let wav = synthesizer
+            .frame_synthesis(frame_audio_query, 3000.into())
+            .perform()
+            .await
+            .unwrap();
+        let pcm = synthesizer
+            .frame_render(frame_audio_query, 3000.into(), range.clone())
+            .perform()
+            .await
+            .unwrap();
+
+        let expected = &wav[44..][range.start * 256 * 2..range.end * 256 * 2];
+        assert_eq!(expected.len(), pcm.len());
+
+        // 区間の外側の推論は有限のマージンの分しか行わないため、ビット単位では一致しない。差し替えたときに
+        // 継ぎ目が目立たない程度に一致していること
+        let max_diff = itertools::zip_eq(samples(expected), samples(&pcm))
+            .map(|(expected, actual)| (i32::from(expected) - i32::from(actual)).abs())
+            .max()
+            .unwrap_or_default();
+        assert!(max_diff <= 0x7fff / 1000, "max_diff = {max_diff}");
+
+        fn samples(pcm: &[u8]) -> impl Iterator<Item = i16> + '_ {
+            pcm.chunks_exact(2)
+                .map(|sample| i16::from_le_bytes([sample[0], sample[1]]))
+        }
+    }
+
+    #[rstest]
+    #[case(StyleId::new(0), false)]
+    #[case(StyleId::new(0), true)]
+    #[case(StyleId::new(302), false)]
+    #[case(StyleId::new(302), true)]
+    #[tokio::test]
+    async fn perform_wave_matches_perform(#[case] style_id: StyleId, #[case] output_stereo: bool) {
+        let synthesizer = super::nonblocking::Synthesizer::builder(
+            crate::nonblocking::Onnxruntime::from_test_util_data()
+                .await
+                .unwrap(),
+        )
+        .acceleration_mode(AccelerationMode::Cpu)
+        .build()
+        .unwrap();
+
+        let model = &crate::nonblocking::VoiceModelFile::sample().await.unwrap();
+        synthesizer.load_voice_model(model).perform().await.unwrap();
+
+        let audio_query = &AudioQuery {
+            output_stereo,
+            ..synthesizer
+                .create_audio_query_from_kana("コンニチワ'", style_id)
+                .await
+                .unwrap()
+        };
+
+        let wav = synthesizer
+            .synthesis(audio_query, style_id)
+            .perform()
+            .await
+            .unwrap();
+        let wave = synthesizer
+            .synthesis(audio_query, style_id)
+            .__perform_wave()
+            .await
+            .unwrap();
+
+        // `voicevox_core`が出力するWAVデータのヘッダは常に44バイト
+        assert_eq!(wav[44..], *s16le_from_f32_pcm(&wave));
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(10)]
+    #[case(94)]
+    #[tokio::test]
+    async fn render_in_chunks_matches_render_at_once(#[case] chunk_length: usize) {
+        let synthesizer = super::nonblocking::Synthesizer::builder(
+            crate::nonblocking::Onnxruntime::from_test_util_data()
+                .await
+                .unwrap(),
+        )
+        .acceleration_mode(AccelerationMode::Cpu)
+        .build()
+        .unwrap();
+
+        let model = &crate::nonblocking::VoiceModelFile::sample().await.unwrap();
+        synthesizer.load_voice_model(model).perform().await.unwrap();
+
+        let audio_query = &synthesizer
+            .create_audio_query_from_kana("コンニチワ'", StyleId::new(302))
+            .await
+            .unwrap();
+        let audio = &synthesizer
+            .__precompute_render(audio_query, StyleId::new(302))
+            .perform()
+            .await
+            .unwrap();
+
+        let expected = synthesizer
+            .__render(audio, 0..audio.frame_length())
+            .await
+            .unwrap();
+
+        let mut actual = vec![];
+        for start in (0..audio.frame_length()).step_by(chunk_length) {
+            let end = usize::min(start + chunk_length, audio.frame_length());
+            actual.extend(synthesizer.__render(audio, start..end).await.unwrap());
+        }
+        assert_eq!(expected.len(), actual.len());
+
+        // 区間の外側の推論は有限のマージンの分しか行わないため、ビット単位では一致しない。継ぎ目が目立たない
+        // 程度に一致していること
+        let max_diff = itertools::zip_eq(samples(&expected), samples(&actual))
+            .map(|(expected, actual)| (i32::from(expected) - i32::from(actual)).abs())
+            .max()
+            .unwrap_or_default();
+        assert!(max_diff <= 0x7fff / 1000, "max_diff = {max_diff}");
+
+        let err = synthesizer
+            .__render(audio, 0..audio.frame_length() + 1)
+            .await
+            .unwrap_err();
+        assert_eq!(crate::ErrorKind::InvalidQuery, err.kind());
+
+        fn samples(pcm: &[u8]) -> impl Iterator<Item = i16> + '_ {
+            pcm.chunks_exact(2)
+                .map(|sample| i16::from_le_bytes([sample[0], sample[1]]))
+        }
+    }
+
+    #[tokio::test]
+    async fn frame_render_fails_for_out_of_range() {
+        let synthesizer = super::nonblocking::Synthesizer::builder(
+            crate::nonblocking::Onnxruntime::from_test_util_data()
+                .await
+                .unwrap(),
+        )
+        .acceleration_mode(AccelerationMode::Cpu)
+        .build()
+        .unwrap();
+
+        let frame_audio_query = &serde_json::from_value::<FrameAudioQuery>(serde_json::json!({
+            "f0": [0., 0.],
+            "volume": [0., 0.],
+            "phonemes": [{ "phoneme": "pau", "frame_length": 2 }],
+            "volumeScale": 1.,
+            "outputSamplingRate": 24000,
+            "outputStereo": false,
+        }))
+        .unwrap();
+
+        let err = synthesizer
+            .frame_render(frame_audio_query, 3000.into(), 1..3)
+            .perform()
+            .await
+            .unwrap_err();
+        assert_eq!(crate::ErrorKind::InvalidQuery, err.kind());
+    }
+
+    #[rstest]
+    #[case(6000, Ok(()))]
+    #[case(3000, Err(crate::ErrorKind::StyleNotFound))]
+    #[tokio::test]
+    async fn frame_render_agrees_with_frame_synthesis_for_empty_query(
+        #[case] style_id: u32,
+        #[case] expected: std::result::Result<(), crate::ErrorKind>,
+    ) {
+        let synthesizer = super::nonblocking::Synthesizer::builder(
+            crate::nonblocking::Onnxruntime::from_test_util_data()
+                .await
+                .unwrap(),
+        )
+        .acceleration_mode(AccelerationMode::Cpu)
+        .build()
+        .unwrap();
+
+        let model = &crate::nonblocking::VoiceModelFile::sample().await.unwrap();
+        synthesizer.load_voice_model(model).perform().await.unwrap();
+
+        let frame_audio_query = &serde_json::from_value::<FrameAudioQuery>(serde_json::json!({
+            "f0": [],
+            "volume": [],
+            "phonemes": [],
+            "volumeScale": 1.,
+            "outputSamplingRate": 24000,
+            "outputStereo": false,
+        }))
+        .unwrap();
+
+        let synthesis = synthesizer
+            .frame_synthesis(frame_audio_query, style_id.into())
+            .perform()
+            .await
+            .map(|_| ())
+            .map_err(|e| e.kind());
+        let render = synthesizer
+            .frame_render(frame_audio_query, style_id.into(), 0..0)
+            .perform()
+            .await
+            .map(|_| ())
+            .map_err(|e| e.kind());
+        assert_eq!(expected, synthesis);
+        assert_eq!(expected, render);
+    }
+
     #[tokio::test]
     async fn create_sing_methods_works() {
         let synthesizer = super::nonblocking::Synthesizer::builder(
@@ -4303,12 +6066,152 @@ mod tests {
         );
         assert_eq!(*b"WAVEfmt ", wav[8..16]);
 
+        let shorter_score = Score {
+            notes: score.notes[..3].to_owned(),
+        };
+        let tracks = &[
+            SongTrack::new(score.clone(), 6000.into(), 3000.into()),
+            SongTrack::new(shorter_score, 6000.into(), 3000.into()),
+        ];
+
+        let wav = synthesizer
+            .render_song_project(tracks)
+            .output_sampling_rate(SamplingRate::new(48000).unwrap())
+            .perform()
+            .await
+            .unwrap();
+
+        assert!(wav.starts_with(b"RIFF"));
+        assert_eq!(
+            num_total_frames * 256 * 2 * mem::size_of::<u16>() * 2,
+            u32::from_le_bytes(*wav[4..].first_chunk().unwrap()) as usize - 36,
+        );
+
+        fn note(id: &str, key: Option<u8>, frame_length: u32, lyric: &str) -> Note {
+            Note {
+                id: Some(NoteId(id.into())),
+                key: key.map(|key| key.try_into().unwrap()),
+                frame_length: frame_length.into(),
+                lyric: lyric.parse().unwrap(),
+                dynamics: None,
+                breath: false,
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "tract"))]
+mod tract_tests {
+    use std::mem;
+
+    use ::test_util::SAMPLE_VOICE_MODEL_FILE_PATH;
+    use itertools::Itertools as _;
+    use rstest::rstest;
+
+    use crate::{
+        AccelerationMode, FramePhoneme, Note, NoteId, Score, StyleId, inference_runtime::Tract,
+    };
+
+    use super::{AsInner as _, DeviceSpec};
+
+    #[rstest]
+    fn synthesis_works() {
+        let synthesizer = super::blocking::Synthesizer::builder_with_runtime(Tract::get())
+            .acceleration_mode(AccelerationMode::Auto)
+            .build()
+            .unwrap();
+        assert!(!synthesizer.is_gpu_mode());
+
+        // tractはCPUのみに対応するため、`Auto`でもすべての操作がCPUに置かれる
+        let session_options = synthesizer.0.status().session_options();
+        for options in itertools::chain!(
+            session_options.talk.values(),
+            session_options.streaming_talk.values(),
+            session_options.singing_teacher.values(),
+            session_options.frame_decode.values(),
+        ) {
+            assert_eq!(DeviceSpec::Cpu, options.device);
+        }
+
+        let model = &crate::blocking::VoiceModelFile::open(SAMPLE_VOICE_MODEL_FILE_PATH).unwrap();
+        synthesizer.load_voice_model(model).perform().unwrap();
+
+        let query = synthesizer
+            .create_audio_query_from_kana("コンニチワ'", StyleId::new(0))
+            .unwrap();
+        let moras = query
+            .accent_phrases
+            .iter()
+            .flat_map(|accent_phrase| &accent_phrase.moras)
+            .collect::<Vec<_>>();
+        assert_eq!(5, moras.len());
+        assert!(moras.iter().all(|mora| mora.vowel_length != 0.));
+        assert!(moras.iter().any(|mora| mora.pitch != 0.));
+
+        let wav = synthesizer
+            .synthesis(&query, StyleId::new(0))
+            .perform()
+            .unwrap();
+        assert!(wav.starts_with(b"RIFF"));
+        assert!(wav.len() > 44);
+
+        let score = &Score {
+            notes: vec![
+                note("①", None, 15, ""),
+                note("②", Some(60), 45, "ド"),
+                note("③", Some(62), 45, "レ"),
+                note("④", None, 15, ""),
+            ],
+        };
+        let num_total_frames = score
+            .notes
+            .iter()
+            .map(|&Note { frame_length, .. }| typeshare::usize_from_u53_saturated(frame_length))
+            .sum::<usize>();
+
+        let frame_audio_query = synthesizer
+            .create_sing_frame_audio_query(score, 6000.into())
+            .unwrap();
+        assert!(
+            [
+                num_total_frames,
+                frame_audio_query
+                    .phonemes
+                    .iter()
+                    .map(
+                        |&FramePhoneme { frame_length, .. }| typeshare::usize_from_u53_saturated(
+                            frame_length
+                        )
+                    )
+                    .sum::<usize>(),
+                frame_audio_query.f0.len(),
+                frame_audio_query.volume.len(),
+            ]
+            .into_iter()
+            .all_equal()
+        );
+
+        let wav = synthesizer
+            .frame_synthesis(&frame_audio_query, 3000.into())
+            .perform()
+            .unwrap();
+        assert!(wav.starts_with(b"RIFF"));
+        assert_eq!(
+            num_total_frames
+                * 256
+                * mem::size_of::<u16>()
+                * (1 + usize::from(frame_audio_query.output_stereo)),
+            u32::from_le_bytes(*wav[4..].first_chunk().unwrap()) as usize - 36,
+        );
+
         fn note(id: &str, key: Option<u8>, frame_length: u32, lyric: &str) -> Note {
             Note {
                 id: Some(NoteId(id.into())),
                 key: key.map(|key| key.try_into().unwrap()),
                 frame_length: frame_length.into(),
                 lyric: lyric.parse().unwrap(),
+                dynamics: None,
+                breath: false,
             }
         }
     }
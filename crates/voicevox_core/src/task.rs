@@ -1,6 +1,12 @@
 // TODO: `Async::unblock`として取り回す
 
 /// ブロッキング操作を非同期化する。
+///
+/// WASMではスレッドが使えないため、その場で実行する。そのため`f`が終わるまでJavaScriptのイベントループはブロック
+/// される。WASM版のAPIのドキュメントではこのことを明記し、Web Worker内での利用を勧めている。
 pub(crate) async fn asyncify<F: FnOnce() -> R + Send + 'static, R: Send + 'static>(f: F) -> R {
+    if cfg!(target_family = "wasm") {
+        return f();
+    }
     blocking::unblock(f).await
 }
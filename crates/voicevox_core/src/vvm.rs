@@ -0,0 +1,7 @@
+//! VVMの作成と検査。
+//!
+//! VVMの形式は[ドキュメント]を参照。`vvm`コマンドはこのモジュールの薄いラッパーである。
+//!
+//! [ドキュメント]: https://github.com/VOICEVOX/voicevox_core/blob/main/docs/guide/dev/vvm.md
+
+pub use crate::core::vvm::{Diagnostic, Problem, Severity, lint, pack, public_key, sign, upgrade};
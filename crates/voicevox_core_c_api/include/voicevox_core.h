@@ -117,6 +117,160 @@ enum VoicevoxAccelerationMode
 typedef int32_t VoicevoxAccelerationMode;
 #endif // __cplusplus
 
+/**
+ * 推論の実行グラフに対して行う最適化のレベル。
+ *
+ * 高いレベルほどセッションの作成（音声モデルの読み込み）に時間がかかる代わりに、推論が速くなる可能性がある。
+ *
+ * \orig-impl{VoicevoxGraphOptimizationLevel}
+ */
+enum VoicevoxGraphOptimizationLevel
+#ifdef __cplusplus
+  : int32_t
+#endif // __cplusplus
+ {
+  /**
+   * 最適化を行わない
+   */
+  VOICEVOX_GRAPH_OPTIMIZATION_LEVEL_DISABLE = 0,
+  /**
+   * 定数畳み込みや冗長なノードの除去といった、基本的な最適化のみを行う
+   */
+  VOICEVOX_GRAPH_OPTIMIZATION_LEVEL_BASIC = 1,
+  /**
+   * ノードの融合などの、より進んだ最適化も行う
+   */
+  VOICEVOX_GRAPH_OPTIMIZATION_LEVEL_EXTENDED = 2,
+  /**
+   * 実行環境に合わせたメモリレイアウトの変更を含む、すべての最適化を行う
+   */
+  VOICEVOX_GRAPH_OPTIMIZATION_LEVEL_ALL = 3,
+};
+#ifndef __cplusplus
+typedef int32_t VoicevoxGraphOptimizationLevel;
+#endif // __cplusplus
+
+/**
+ * 推論の操作。 ::VoicevoxOperationPlacement の指定に用いる。
+ *
+ * \orig-impl{VoicevoxInferenceOperationKind}
+ */
+enum VoicevoxInferenceOperationKind
+#ifdef __cplusplus
+  : int32_t
+#endif // __cplusplus
+ {
+  /**
+   * トーク: 音素長の推論
+   */
+  VOICEVOX_INFERENCE_OPERATION_KIND_TALK_PREDICT_DURATION = 0,
+  /**
+   * トーク: 音高の推論
+   */
+  VOICEVOX_INFERENCE_OPERATION_KIND_TALK_PREDICT_INTONATION = 1,
+  /**
+   * トーク: 波形の生成
+   */
+  VOICEVOX_INFERENCE_OPERATION_KIND_TALK_DECODE = 2,
+  /**
+   * ストリーミングトーク: 音素長の推論
+   */
+  VOICEVOX_INFERENCE_OPERATION_KIND_STREAMING_TALK_PREDICT_DURATION = 3,
+  /**
+   * ストリーミングトーク: 音高の推論
+   */
+  VOICEVOX_INFERENCE_OPERATION_KIND_STREAMING_TALK_PREDICT_INTONATION = 4,
+  /**
+   * ストリーミングトーク: 中間表現の生成
+   */
+  VOICEVOX_INFERENCE_OPERATION_KIND_STREAMING_TALK_GENERATE_FULL_INTERMEDIATE = 5,
+  /**
+   * ストリーミングトーク: 中間表現からの波形の生成
+   */
+  VOICEVOX_INFERENCE_OPERATION_KIND_STREAMING_TALK_RENDER_AUDIO_SEGMENT = 6,
+  /**
+   * ソング: 子音長の推論
+   */
+  VOICEVOX_INFERENCE_OPERATION_KIND_SINGING_TEACHER_PREDICT_SING_CONSONANT_LENGTH = 7,
+  /**
+   * ソング: 音高の推論
+   */
+  VOICEVOX_INFERENCE_OPERATION_KIND_SINGING_TEACHER_PREDICT_SING_F0 = 8,
+  /**
+   * ソング: 音量の推論
+   */
+  VOICEVOX_INFERENCE_OPERATION_KIND_SINGING_TEACHER_PREDICT_SING_VOLUME = 9,
+  /**
+   * ソング: 波形の生成
+   */
+  VOICEVOX_INFERENCE_OPERATION_KIND_FRAME_DECODE_SF_DECODE = 10,
+};
+#ifndef __cplusplus
+typedef int32_t VoicevoxInferenceOperationKind;
+#endif // __cplusplus
+
+/**
+ * 推論の操作を行うデバイス。
+ *
+ * \orig-impl{VoicevoxOperationDevice}
+ */
+enum VoicevoxOperationDevice
+#ifdef __cplusplus
+  : int32_t
+#endif // __cplusplus
+ {
+  /**
+   * CPU
+   */
+  VOICEVOX_OPERATION_DEVICE_CPU = 0,
+  /**
+   * ::VoicevoxAccelerationMode によって選ばれたデバイス。 ::VOICEVOX_ACCELERATION_MODE_CPU のときや、利用可能なものが無かったときはCPUとなる
+   */
+  VOICEVOX_OPERATION_DEVICE_ACCELERATOR = 1,
+};
+#ifndef __cplusplus
+typedef int32_t VoicevoxOperationDevice;
+#endif // __cplusplus
+
+/**
+ * ログのレベル。
+ *
+ * \no-orig-impl{VoicevoxLogLevel}
+ */
+enum VoicevoxLogLevel
+#ifdef __cplusplus
+  : int32_t
+#endif // __cplusplus
+ {
+  /**
+   * 何も出力しない
+   */
+  VOICEVOX_LOG_LEVEL_OFF = 0,
+  /**
+   * エラー
+   */
+  VOICEVOX_LOG_LEVEL_ERROR = 1,
+  /**
+   * 警告
+   */
+  VOICEVOX_LOG_LEVEL_WARN = 2,
+  /**
+   * 情報
+   */
+  VOICEVOX_LOG_LEVEL_INFO = 3,
+  /**
+   * デバッグ情報
+   */
+  VOICEVOX_LOG_LEVEL_DEBUG = 4,
+  /**
+   * 詳細なトレース
+   */
+  VOICEVOX_LOG_LEVEL_TRACE = 5,
+};
+#ifndef __cplusplus
+typedef int32_t VoicevoxLogLevel;
+#endif // __cplusplus
+
 /**
  * ::voicevox_synthesizer_load_voice_model の実行時に、同じIDの ::VoicevoxVoiceModelFile が既に読み込まれていたときのふるまい。
  *
@@ -230,6 +384,10 @@ enum VoicevoxResultCode
    * 無効なモデルデータ
    */
   VOICEVOX_RESULT_INVALID_MODEL_DATA_ERROR = 27,
+  /**
+   * 音声モデルの検証に失敗した
+   */
+  VOICEVOX_RESULT_VERIFY_MODEL_ERROR = 38,
   /**
    * ユーザー辞書を読み込めなかった
    */
@@ -278,6 +436,14 @@ enum VoicevoxResultCode
    * 楽譜とFrameAudioQueryの組み合わせが不正
    */
   VOICEVOX_RESULT_INCOMPATIBLE_QUERIES_ERROR = 35,
+  /**
+   * 処理がキャンセルされた
+   */
+  VOICEVOX_RESULT_CANCELLED = 36,
+  /**
+   * 配列のインデックスが範囲外
+   */
+  VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR = 37,
 };
 #ifndef __cplusplus
 typedef int32_t VoicevoxResultCode;
@@ -339,6 +505,41 @@ typedef int32_t VoicevoxUserDictWordType;
  */
 typedef struct OpenJtalkRc OpenJtalkRc;
 
+/**
+ * AudioQuery。
+ *
+ * AudioQueryのJSON文字列の代わりに用いることができ、JSONを介さずにフィールドを読み書きできる。
+ *
+ * <b>構築</b>(_construction_)は ::voicevox_audio_query_new_from_json もしくは ::voicevox_synthesizer_create_audio_query_handle で行い、<b>破棄</b>(_destruction_)は ::voicevox_audio_query_delete で行う。
+ *
+ * \orig-impl{VoicevoxAudioQuery}
+ */
+typedef struct VoicevoxAudioQuery VoicevoxAudioQuery;
+
+/**
+ * FrameAudioQuery。
+ *
+ * FrameAudioQueryのJSON文字列の代わりに用いることができ、JSONを介さずにフィールドを読み書きできる。
+ *
+ * <b>構築</b>(_construction_)は ::voicevox_frame_audio_query_new_from_json もしくは ::voicevox_synthesizer_create_sing_frame_audio_query_handle で行い、<b>破棄</b>(_destruction_)は ::voicevox_frame_audio_query_delete で行う。
+ *
+ * \orig-impl{VoicevoxFrameAudioQuery}
+ */
+typedef struct VoicevoxFrameAudioQuery VoicevoxFrameAudioQuery;
+
+/**
+ * ::voicevox_synthesizer_tts_async などで開始した、非同期の処理。
+ *
+ * ::voicevox_job_cancel でキャンセルでき、 ::voicevox_job_delete で<b>破棄</b>(_destruction_)する。
+ *
+ * 処理は、プロセス全体で共有される固定数のワーカースレッドで実行される。スレッド数は利用可能なCPUの論理コア数であり、それを超える数の処理を開始した場合、後から開始したものは先に開始したものが終わるまで待たされる。
+ *
+ * 処理は開始元の ::VoicevoxSynthesizer に紐付けられる。 ::voicevox_synthesizer_delete は、紐付けられた処理をキャンセルし、それらが終わるのを待ってから破棄を行う。
+ *
+ * \no-orig-impl{VoicevoxJob}
+ */
+typedef struct VoicevoxJob VoicevoxJob;
+
 /**
  * ONNX Runtime。
  *
@@ -356,6 +557,17 @@ typedef struct OpenJtalkRc OpenJtalkRc;
  */
 typedef struct VoicevoxOnnxruntime VoicevoxOnnxruntime;
 
+/**
+ * 楽譜。
+ *
+ * 楽譜のJSON文字列の代わりに用いることができる。
+ *
+ * <b>構築</b>(_construction_)は ::voicevox_score_new_from_json で行い、<b>破棄</b>(_destruction_)は ::voicevox_score_delete で行う。
+ *
+ * \orig-impl{VoicevoxScore}
+ */
+typedef struct VoicevoxScore VoicevoxScore;
+
 /**
  * 音声シンセサイザ。
  *
@@ -402,6 +614,27 @@ typedef struct VoicevoxLoadOnnxruntimeOptions {
 } VoicevoxLoadOnnxruntimeOptions;
 #endif
 
+/**
+ * 推論の操作の配置先。
+ *
+ * \orig-impl{VoicevoxOperationPlacement}
+ */
+typedef struct VoicevoxOperationPlacement {
+  /**
+   * 配置する推論の操作
+   */
+  VoicevoxInferenceOperationKind operation;
+  /**
+   * 推論を行うデバイス
+   */
+  VoicevoxOperationDevice device;
+  /**
+   * CPU利用数
+   * 負の値を指定するとシンセサイザ全体の設定( ::VoicevoxInitializeOptions の`cpu_num_threads`)に従う。0を指定すると環境に合わせたCPUが利用される。65535を超える値を指定するとパニックする
+   */
+  int32_t cpu_num_threads;
+} VoicevoxOperationPlacement;
+
 /**
  * ::voicevox_synthesizer_new のオプション。
  *
@@ -417,8 +650,63 @@ typedef struct VoicevoxInitializeOptions {
    * 0を指定すると環境に合わせたCPUが利用される
    */
   uint16_t cpu_num_threads;
+  /**
+   * 推論の実行グラフの最適化レベル
+   */
+  VoicevoxGraphOptimizationLevel graph_optimization_level;
+  /**
+   * 推論の操作ごとの配置先の配列。NULLのときは`operation_placements_length`を0として扱う
+   * 指定されなかった操作は、波形の生成をアクセラレータに、それ以外をCPUに置く
+   */
+  const struct VoicevoxOperationPlacement *operation_placements;
+  /**
+   * `operation_placements`の要素数
+   */
+  uintptr_t operation_placements_length;
 } VoicevoxInitializeOptions;
 
+/**
+ * ::voicevox_set_log_callback で登録するコールバック。
+ *
+ * 引数は順に、ログのレベル、ログの出力元のモジュールパス、ログのメッセージ、 ::voicevox_set_log_callback に渡した`user_data`。文字列はいずれもヌル終端かつUTF-8であり、コールバックの呼び出しの間のみ有効である。
+ *
+ * \no-orig-impl{VoicevoxLogCallback}
+ */
+typedef void (*VoicevoxLogCallback)(VoicevoxLogLevel level,
+                                    const char *target,
+                                    const char *message,
+                                    void *user_data);
+
+/**
+ * ユーザー定義のテキスト解析器。
+ *
+ * ::voicevox_synthesizer_new_with_text_analyzer で ::OpenJtalkRc の代わりに用いることができる。
+ *
+ * \no-orig-impl{VoicevoxTextAnalyzer}
+ */
+typedef struct VoicevoxTextAnalyzer {
+  /**
+   * 日本語のテキストを解析し、AccentPhraseの配列のJSON文字列を返す。失敗した場合はNULLを返す。
+   *
+   * 第一引数は解析対象のテキストで、ヌル終端かつUTF-8である。第二引数は`user_data`である。
+   *
+   * NULLであってはならない。
+   */
+  char *(*analyze)(const char *text, void *user_data);
+  /**
+   * `analyze`が返したJSON文字列を解放する。
+   *
+   * 第二引数は`user_data`である。
+   *
+   * NULLであってはならない。
+   */
+  void (*free_json)(char *json, void *user_data);
+  /**
+   * `analyze`と`free_json`に渡されるポインタ。
+   */
+  void *user_data;
+} VoicevoxTextAnalyzer;
+
 /**
  * ::voicevox_synthesizer_load_voice_model のオプション。
  *
@@ -483,6 +771,128 @@ typedef struct VoicevoxTtsOptions {
   bool enable_interrogative_upspeak;
 } VoicevoxTtsOptions;
 
+/**
+ * ::VoicevoxAudioQuery 内のAccentPhraseの内容。
+ *
+ * \no-orig-impl{VoicevoxAccentPhraseView}
+ */
+typedef struct VoicevoxAccentPhraseView {
+  /**
+   * モーラの数。`pause_mora`は含まない
+   */
+  uintptr_t moras_length;
+  /**
+   * アクセント箇所
+   */
+  uintptr_t accent;
+  /**
+   * 後ろに無音を付けるかどうか
+   */
+  bool has_pause_mora;
+  /**
+   * 疑問系かどうか
+   */
+  bool is_interrogative;
+} VoicevoxAccentPhraseView;
+
+/**
+ * ::VoicevoxAudioQuery 内のモーラの、数値で表される内容。
+ *
+ * 文字列で表されるフィールドは ::voicevox_audio_query_create_json で得る。
+ *
+ * \no-orig-impl{VoicevoxMoraView}
+ */
+typedef struct VoicevoxMoraView {
+  /**
+   * 子音があるかどうか
+   */
+  bool has_consonant;
+  /**
+   * 子音の音長。子音が無いときは`0.0`
+   */
+  float consonant_length;
+  /**
+   * 母音の音長
+   */
+  float vowel_length;
+  /**
+   * 音高
+   */
+  float pitch;
+} VoicevoxMoraView;
+
+/**
+ * ::VoicevoxScore 内の音符の、数値で表される内容。
+ *
+ * 文字列で表されるフィールドは ::voicevox_score_create_json で得る。
+ *
+ * \no-orig-impl{VoicevoxNoteView}
+ */
+typedef struct VoicevoxNoteView {
+  /**
+   * 音階を持つかどうか。休符のときは`false`
+   */
+  bool has_key;
+  /**
+   * 音階。休符のときは`0`
+   */
+  uint8_t key;
+  /**
+   * 音符のフレーム長
+   */
+  uint64_t frame_length;
+  /**
+   * ブレスであるかどうか
+   */
+  bool breath;
+} VoicevoxNoteView;
+
+/**
+ * ::VoicevoxFrameAudioQuery 内の音素の、数値で表される内容。
+ *
+ * 文字列で表されるフィールドは ::voicevox_frame_audio_query_create_json で得る。
+ *
+ * \no-orig-impl{VoicevoxFramePhonemeView}
+ */
+typedef struct VoicevoxFramePhonemeView {
+  /**
+   * 音素のフレーム長
+   */
+  uint64_t frame_length;
+  /**
+   * 音符のIDを持つかどうか
+   */
+  bool has_note_id;
+} VoicevoxFramePhonemeView;
+
+/**
+ * ::voicevox_synthesizer_tts_async などの処理が終わったときに呼ばれるコールバック。
+ *
+ * 引数は順に、結果コード、生成したWAVデータのバイト長、生成したWAVデータ、開始時に渡した`user_data`。WAVデータは成功したときのみ非NULLであり、 ::voicevox_wav_free で解放する。
+ *
+ * キャンセルされたときは ::VOICEVOX_RESULT_CANCELLED が渡される。失敗したときは、コールバックの中で ::voicevox_last_error_message などを用いてエラーの詳細を得ることができる。
+ *
+ * \no-orig-impl{VoicevoxWavCallback}
+ */
+typedef void (*VoicevoxWavCallback)(VoicevoxResultCode result_code,
+                                    uintptr_t wav_length,
+                                    uint8_t *wav,
+                                    void *user_data);
+
+/**
+ * ::voicevox_synthesizer_tts_async などの、非同期の処理のオプション。
+ *
+ * \no-orig-impl{VoicevoxJobOptions}
+ */
+typedef struct VoicevoxJobOptions {
+  /**
+   * 音声モデルの実行をキャンセル可能にするかどうか。
+   *
+   * 無効の場合でも ::voicevox_job_cancel によって処理は終わるが、実行中の音声モデルの推論はバックグラウンドで最後まで続く。有効にすると推論そのものを中断するが、負荷がかかっている状況下でハングする可能性がある。そのためデフォルトでは無効化されている。<a href="https://github.com/VOICEVOX/voicevox_core/issues/968">VOICEVOX/voicevox_core#968</a>を参照。
+   */
+  bool cancellable;
+} VoicevoxJobOptions;
+
 /**
  * ユーザー辞書の単語。
  *
@@ -772,6 +1182,55 @@ __declspec(dllimport)
 #endif
 const char *voicevox_get_version(void);
 
+/**
+ * ログを受け取るコールバックを登録する。
+ *
+ * コールバックが登録されている間、ログは標準エラー出力には出されず、`level`以下のレベルのログがコールバックに渡される。`callback`にNULLを渡すとコールバックの登録が解除され、標準エラー出力への出力に戻る。
+ *
+ * 既にコールバックが登録されている場合、それを置き換える。この関数は、実行中のコールバックの呼び出しが終わるのを待ってから帰る。
+ *
+ * @param [in] level コールバックに渡すログのレベルの上限
+ * @param [in] callback コールバック。NULLも可
+ * @param [in] user_data コールバックに渡されるポインタ
+ *
+ * \example{
+ * ```c
+ * static void on_log(VoicevoxLogLevel level, const char *target,
+ *                    const char *message, void *user_data) {
+ *   fprintf(stderr, "[%d] %s: %s\n", level, target, message);
+ * }
+ *
+ * voicevox_set_log_callback(VOICEVOX_LOG_LEVEL_INFO, on_log, NULL);
+ * ```
+ * }
+ *
+ * \safety{
+ * - `callback`は、任意のスレッドから同時に呼び出されても問題が無いものでなければならない。
+ * - `user_data`は、コールバックの登録が解除されるか置き換えられるまで、`callback`にとって有効でなければならない。
+ * - `callback`の中で、この関数を呼んではならない。
+ * }
+ *
+ * \no-orig-impl{voicevox_set_log_callback}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+void voicevox_set_log_callback(VoicevoxLogLevel level,
+                               VoicevoxLogCallback callback,
+                               void *user_data);
+
+/**
+ * ::voicevox_set_log_callback で登録したコールバックに渡すログのレベルの上限を変更する。
+ *
+ * @param [in] level コールバックに渡すログのレベルの上限
+ *
+ * \no-orig-impl{voicevox_set_log_level}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+void voicevox_set_log_level(VoicevoxLogLevel level);
+
 /**
  * AccentPhraseの配列からAudioQueryを作る。
  *
@@ -908,6 +1367,8 @@ VoicevoxResultCode voicevox_score_validate(const char *score_json);
  * - [Rust APIの`Note`型]としてデシリアライズ不可、もしくはJSONとして不正。
  * - `key`が`null`かつ`lyric`が`""`以外。
  * - `key`が非`null`かつ`lyric`が`""`。
+ * - `breath`が`true`かつ音符である。
+ * - `lyric`が複数のモーラから成り、かつ`frame_length`がそのモーラ数より小さい。
  *
  * [Rust APIの`Note`型]: ../rust_api/voicevox_core/struct.Note.html
  *
@@ -1025,16 +1486,41 @@ VoicevoxResultCode voicevox_voice_model_file_open(const char *path,
                                                   struct VoicevoxVoiceModelFile **out_model);
 
 /**
- * ::VoicevoxVoiceModelFile からIDを取得する。
+ * メモリ上のVVMを開く。
  *
- * @param [in] model 音声モデル
- * @param [out] output_voice_model_id 音声モデルID。詳細は ::VoicevoxVoiceModelId
+ * `data`の内容はコピーされるため、この関数から返った後は解放してよい。
+ *
+ * @param [in] data VVMのバイト列
+ * @param [in] data_length `data`のバイト長
+ * @param [out] out_model 構築先
+ *
+ * @returns 結果コード
  *
  * \safety{
- * - `output_voice_model_id`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * - `data`は<a href="#voicevox-core-safety">読み込みについて有効</a>な`data_length`バイトの領域を指していなければならない。ただし`data_length`が0のときに限りNULLでもよい。`data_length`が非0なのに`data`がNULLであるときはパニックする。
+ * - `out_model`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
  * }
  *
- * \orig-impl{voicevox_voice_model_file_id}
+ * \orig-impl{voicevox_voice_model_file_open_from_memory}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_voice_model_file_open_from_memory(const uint8_t *data,
+                                                              uintptr_t data_length,
+                                                              struct VoicevoxVoiceModelFile **out_model);
+
+/**
+ * ::VoicevoxVoiceModelFile からIDを取得する。
+ *
+ * @param [in] model 音声モデル
+ * @param [out] output_voice_model_id 音声モデルID。詳細は ::VoicevoxVoiceModelId
+ *
+ * \safety{
+ * - `output_voice_model_id`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ *
+ * \orig-impl{voicevox_voice_model_file_id}
  */
 #ifdef _WIN32
 __declspec(dllimport)
@@ -1086,6 +1572,7 @@ void voicevox_voice_model_file_delete(struct VoicevoxVoiceModelFile *model);
  *
  * \safety{
  * - `onnxruntime`は ::voicevox_onnxruntime_load_once または ::voicevox_onnxruntime_init_once で得たものでなければならない。
+ * - `options.operation_placements`はNULLであるか、<a href="#voicevox-core-safety">読み込みについて有効</a>な`options.operation_placements_length`要素の領域を指していなければならない。
  * - `out_synthesizer`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
  * }
  *
@@ -1099,11 +1586,68 @@ VoicevoxResultCode voicevox_synthesizer_new(const struct VoicevoxOnnxruntime *on
                                             struct VoicevoxInitializeOptions options,
                                             struct VoicevoxSynthesizer **out_synthesizer);
 
+/**
+ * ユーザー定義のテキスト解析器を用いて、 ::VoicevoxSynthesizer を<b>構築</b>(_construct_)する。
+ *
+ * ::voicevox_synthesizer_new と同じだが、 ::OpenJtalkRc の代わりに ::VoicevoxTextAnalyzer を用いる。
+ *
+ * `text_analyzer.analyze`が返したJSONは、AccentPhraseの配列としてバリデートされる。不正なJSONが返されたとき、もしくはNULLが返されたとき、テキスト解析を伴う関数は ::VOICEVOX_RESULT_ANALYZE_TEXT_ERROR を返す。
+ *
+ * `text_analyzer.analyze`もしくは`text_analyzer.free_json`がNULLの場合、プロセスを異常終了する。
+ *
+ * @param [in] onnxruntime
+ * @param [in] text_analyzer テキスト解析器
+ * @param [in] options オプション
+ * @param [out] out_synthesizer 構築先
+ *
+ * @returns 結果コード
+ *
+ * \example{
+ * ```c
+ * static char *analyze(const char *text, void *user_data) {
+ *   return my_analyzer_analyze((MyAnalyzer *)user_data, text);
+ * }
+ *
+ * static void free_json(char *json, void *user_data) { free(json); }
+ *
+ * VoicevoxSynthesizer *synthesizer;
+ * voicevox_synthesizer_new_with_text_analyzer(
+ *     onnxruntime,
+ *     (VoicevoxTextAnalyzer){
+ *         .analyze = analyze,
+ *         .free_json = free_json,
+ *         .user_data = my_analyzer,
+ *     },
+ *     voicevox_make_default_initialize_options(), &synthesizer);
+ * ```
+ * }
+ *
+ * \safety{
+ * - `onnxruntime`は ::voicevox_onnxruntime_load_once または ::voicevox_onnxruntime_init_once で得たものでなければならない。
+ * - `text_analyzer.analyze`と`text_analyzer.free_json`は、任意のスレッドから同時に呼び出されても問題が無いものでなければならない。
+ * - `text_analyzer.analyze`は、NULLもしくはヌル終端文字列を返さなければならない。返した文字列は、`text_analyzer.free_json`に渡されるまで有効でなければならない。
+ * - `text_analyzer.user_data`は、構築した ::VoicevoxSynthesizer が破棄されるまで有効でなければならない。
+ * - `options.operation_placements`はNULLであるか、<a href="#voicevox-core-safety">読み込みについて有効</a>な`options.operation_placements_length`要素の領域を指していなければならない。
+ * - `out_synthesizer`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ *
+ * \no-orig-impl{voicevox_synthesizer_new_with_text_analyzer}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_synthesizer_new_with_text_analyzer(const struct VoicevoxOnnxruntime *onnxruntime,
+                                                               struct VoicevoxTextAnalyzer text_analyzer,
+                                                               struct VoicevoxInitializeOptions options,
+                                                               struct VoicevoxSynthesizer **out_synthesizer);
+
 /**
  * ::VoicevoxSynthesizer を<b>破棄</b>(_destruct_)する。
  *
  * 破棄対象への他スレッドでのアクセスが存在する場合、それらがすべて終わるのを待ってから破棄する。
  *
+ * 破棄対象から ::voicevox_synthesizer_tts_async などで開始した処理が終わっていない場合、それらをキャンセルし、終わるのを待ってから破棄する。そのためこの関数から帰った後は、 ::voicevox_synthesizer_new_with_text_analyzer で渡したテキスト解析器が呼ばれることはない。ただしキャンセルされた処理の`callback`の呼び出しは待たない。
+ *
  * この関数の呼び出し後に破棄し終えた対象にアクセスすると、プロセスを異常終了する。
  *
  * @param [in] synthesizer 破棄対象。nullable
@@ -1798,90 +2342,1234 @@ VoicevoxResultCode voicevox_synthesizer_frame_synthesis(const struct VoicevoxSyn
                                                         uint8_t **output_wav);
 
 /**
- * JSON文字列を解放する。
+ * AudioQueryのJSON文字列から ::VoicevoxAudioQuery を<b>構築</b>(_construct_)する。
  *
- * @param [in] json 解放するJSON文字列。nullable
+ * @param [in] audio_query_json AudioQueryのJSON文字列
+ * @param [out] out_audio_query 構築先
+ *
+ * @returns 結果コード
  *
  * \safety{
- * - `json`がヌルポインタでないならば、以下のAPIで得られたポインタでなくてはいけない。
- *     - ::voicevox_audio_query_create_from_accent_phrases
- *     - ::voicevox_onnxruntime_create_supported_devices_json
- *     - ::voicevox_voice_model_file_create_metas_json
- *     - ::voicevox_open_jtalk_rc_analyze
- *     - ::voicevox_synthesizer_create_metas_json
- *     - ::voicevox_synthesizer_create_audio_query
- *     - ::voicevox_synthesizer_create_audio_query_from_kana
- *     - ::voicevox_synthesizer_create_accent_phrases
- *     - ::voicevox_synthesizer_create_accent_phrases_from_kana
- *     - ::voicevox_synthesizer_replace_mora_data
- *     - ::voicevox_synthesizer_replace_phoneme_length
- *     - ::voicevox_synthesizer_replace_mora_pitch
- *     - ::voicevox_synthesizer_create_sing_frame_audio_query
- *     - ::voicevox_synthesizer_create_sing_frame_f0
- *     - ::voicevox_synthesizer_create_sing_frame_volume
- *     - ::voicevox_user_dict_to_json
- * - 文字列の長さは生成時より変更されていてはならない。
- * - `json`がヌルポインタでないならば、<a href="#voicevox-core-safety">読み込みと書き込みについて有効</a>でなければならない。
- * - `json`がヌルポインタでないならば、以後<b>ダングリングポインタ</b>(_dangling pointer_)として扱われなくてはならない。
+ * - `audio_query_json`はヌル終端文字列を指し、かつ<a href="#voicevox-core-safety">読み込みについて有効</a>でなければならない。
+ * - `out_audio_query`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
  * }
  *
- * \no-orig-impl{voicevox_json_free}
+ * \no-orig-impl{voicevox_audio_query_new_from_json}
  */
 #ifdef _WIN32
 __declspec(dllimport)
 #endif
-void voicevox_json_free(char *json);
+VoicevoxResultCode voicevox_audio_query_new_from_json(const char *audio_query_json,
+                                                      struct VoicevoxAudioQuery **out_audio_query);
 
 /**
- * WAVデータを解放する。
+ * 日本語のテキストから、 ::VoicevoxAudioQuery を生成する。
  *
- * @param [in] wav 解放するWAVデータ。nullable
+ * ::voicevox_synthesizer_create_audio_query と同じだが、JSON文字列の代わりに ::VoicevoxAudioQuery を出力する。
+ *
+ * @param [in] synthesizer 音声シンセサイザ
+ * @param [in] text UTF-8の日本語テキスト
+ * @param [in] style_id スタイルID
+ * @param [out] out_audio_query 生成先
+ *
+ * @returns 結果コード
  *
  * \safety{
- * - `wav`がヌルポインタでないならば、以下のAPIで得られたポインタでなくてはいけない。
- *     - ::voicevox_synthesizer_synthesis
- *     - ::voicevox_synthesizer_tts
- *     - ::voicevox_synthesizer_tts_from_kana
- *     - ::voicevox_synthesizer_frame_synthesis
- * - `wav`がヌルポインタでないならば、<a href="#voicevox-core-safety">読み込みと書き込みについて有効</a>でなければならない。
- * - `wav`がヌルポインタでないならば、以後<b>ダングリングポインタ</b>(_dangling pointer_)として扱われなくてはならない。
+ * - `text`はヌル終端文字列を指し、かつ<a href="#voicevox-core-safety">読み込みについて有効</a>でなければならない。
+ * - `out_audio_query`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
  * }
  *
- * \no-orig-impl{voicevox_wav_free}
+ * \no-orig-impl{voicevox_synthesizer_create_audio_query_handle}
  */
 #ifdef _WIN32
 __declspec(dllimport)
 #endif
-void voicevox_wav_free(uint8_t *wav);
+VoicevoxResultCode voicevox_synthesizer_create_audio_query_handle(const struct VoicevoxSynthesizer *synthesizer,
+                                                                  const char *text,
+                                                                  VoicevoxStyleId style_id,
+                                                                  struct VoicevoxAudioQuery **out_audio_query);
 
 /**
- * 結果コードに対応したメッセージ文字列を取得する。
+ * ::VoicevoxAudioQuery から音声合成を行う。
  *
- * @param [in] result_code 結果コード
+ * ::voicevox_synthesizer_synthesis と同じだが、JSON文字列の代わりに ::VoicevoxAudioQuery を用いる。
  *
- * @returns 結果コードに対応したメッセージ文字列
+ * 生成したWAVデータを解放するには ::voicevox_wav_free を使う。
  *
- * \examples{
- * ```c
- * const char *actual = voicevox_error_result_to_message(VOICEVOX_RESULT_OK);
- * const char *EXPECTED = "エラーが発生しませんでした";
- * assert(strcmp(actual, EXPECTED) == 0);
- * ```
+ * @param [in] synthesizer 音声シンセサイザ
+ * @param [in] audio_query AudioQuery
+ * @param [in] style_id スタイルID
+ * @param [in] options オプション
+ * @param [out] output_wav_length 出力のバイト長
+ * @param [out] output_wav 出力先
  *
- * ```c
- * const char *actual =
- *     voicevox_error_result_to_message(VOICEVOX_RESULT_LOAD_MODEL_ERROR);
- * const char *EXPECTED = "modelデータ読み込みに失敗しました";
- * assert(strcmp(actual, EXPECTED) == 0);
- * ```
+ * @returns 結果コード
+ *
+ * \safety{
+ * - `output_wav_length`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * - `output_wav`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
  * }
  *
- * \no-orig-impl{voicevox_error_result_to_message}
+ * \no-orig-impl{voicevox_synthesizer_synthesis_from_handle}
  */
 #ifdef _WIN32
 __declspec(dllimport)
 #endif
-const char *voicevox_error_result_to_message(VoicevoxResultCode result_code);
+VoicevoxResultCode voicevox_synthesizer_synthesis_from_handle(const struct VoicevoxSynthesizer *synthesizer,
+                                                              const struct VoicevoxAudioQuery *audio_query,
+                                                              VoicevoxStyleId style_id,
+                                                              struct VoicevoxSynthesisOptions options,
+                                                              uintptr_t *output_wav_length,
+                                                              uint8_t **output_wav);
+
+/**
+ * ::VoicevoxAudioQuery をJSON文字列にする。
+ *
+ * JSONの解放は ::voicevox_json_free で行う。
+ *
+ * @param [in] audio_query AudioQuery
+ *
+ * @returns AudioQueryのJSON文字列
+ *
+ * \no-orig-impl{voicevox_audio_query_create_json}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+char *voicevox_audio_query_create_json(const struct VoicevoxAudioQuery *audio_query);
+
+/**
+ * ::VoicevoxAudioQuery の`speedScale`(全体の話速)を取得する。
+ *
+ * @param [in] audio_query AudioQuery
+ *
+ * @returns 全体の話速
+ *
+ * \no-orig-impl{voicevox_audio_query_get_speed_scale}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+float voicevox_audio_query_get_speed_scale(const struct VoicevoxAudioQuery *audio_query);
+
+/**
+ * ::VoicevoxAudioQuery の`speedScale`(全体の話速)を設定する。
+ *
+ * @param [in] audio_query AudioQuery
+ * @param [in] speed_scale 全体の話速
+ *
+ * @returns 成功時には ::VOICEVOX_RESULT_OK 、値が不正なときは ::VOICEVOX_RESULT_INVALID_AUDIO_QUERY_ERROR
+ *
+ * \no-orig-impl{voicevox_audio_query_set_speed_scale}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_audio_query_set_speed_scale(const struct VoicevoxAudioQuery *audio_query,
+                                                        float speed_scale);
+
+/**
+ * ::VoicevoxAudioQuery の`pitchScale`(全体の音高)を取得する。
+ *
+ * @param [in] audio_query AudioQuery
+ *
+ * @returns 全体の音高
+ *
+ * \no-orig-impl{voicevox_audio_query_get_pitch_scale}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+float voicevox_audio_query_get_pitch_scale(const struct VoicevoxAudioQuery *audio_query);
+
+/**
+ * ::VoicevoxAudioQuery の`pitchScale`(全体の音高)を設定する。
+ *
+ * @param [in] audio_query AudioQuery
+ * @param [in] pitch_scale 全体の音高
+ *
+ * @returns 成功時には ::VOICEVOX_RESULT_OK 、値が不正なときは ::VOICEVOX_RESULT_INVALID_AUDIO_QUERY_ERROR
+ *
+ * \no-orig-impl{voicevox_audio_query_set_pitch_scale}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_audio_query_set_pitch_scale(const struct VoicevoxAudioQuery *audio_query,
+                                                        float pitch_scale);
+
+/**
+ * ::VoicevoxAudioQuery の`intonationScale`(全体の抑揚)を取得する。
+ *
+ * @param [in] audio_query AudioQuery
+ *
+ * @returns 全体の抑揚
+ *
+ * \no-orig-impl{voicevox_audio_query_get_intonation_scale}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+float voicevox_audio_query_get_intonation_scale(const struct VoicevoxAudioQuery *audio_query);
+
+/**
+ * ::VoicevoxAudioQuery の`intonationScale`(全体の抑揚)を設定する。
+ *
+ * @param [in] audio_query AudioQuery
+ * @param [in] intonation_scale 全体の抑揚
+ *
+ * @returns 成功時には ::VOICEVOX_RESULT_OK 、値が不正なときは ::VOICEVOX_RESULT_INVALID_AUDIO_QUERY_ERROR
+ *
+ * \no-orig-impl{voicevox_audio_query_set_intonation_scale}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_audio_query_set_intonation_scale(const struct VoicevoxAudioQuery *audio_query,
+                                                             float intonation_scale);
+
+/**
+ * ::VoicevoxAudioQuery の`volumeScale`(全体の音量)を取得する。
+ *
+ * @param [in] audio_query AudioQuery
+ *
+ * @returns 全体の音量
+ *
+ * \no-orig-impl{voicevox_audio_query_get_volume_scale}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+float voicevox_audio_query_get_volume_scale(const struct VoicevoxAudioQuery *audio_query);
+
+/**
+ * ::VoicevoxAudioQuery の`volumeScale`(全体の音量)を設定する。
+ *
+ * @param [in] audio_query AudioQuery
+ * @param [in] volume_scale 全体の音量
+ *
+ * @returns 成功時には ::VOICEVOX_RESULT_OK 、値が不正なときは ::VOICEVOX_RESULT_INVALID_AUDIO_QUERY_ERROR
+ *
+ * \no-orig-impl{voicevox_audio_query_set_volume_scale}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_audio_query_set_volume_scale(const struct VoicevoxAudioQuery *audio_query,
+                                                         float volume_scale);
+
+/**
+ * ::VoicevoxAudioQuery の`prePhonemeLength`(音声の前の無音時間)を取得する。
+ *
+ * @param [in] audio_query AudioQuery
+ *
+ * @returns 音声の前の無音時間
+ *
+ * \no-orig-impl{voicevox_audio_query_get_pre_phoneme_length}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+float voicevox_audio_query_get_pre_phoneme_length(const struct VoicevoxAudioQuery *audio_query);
+
+/**
+ * ::VoicevoxAudioQuery の`prePhonemeLength`(音声の前の無音時間)を設定する。
+ *
+ * @param [in] audio_query AudioQuery
+ * @param [in] pre_phoneme_length 音声の前の無音時間
+ *
+ * @returns 成功時には ::VOICEVOX_RESULT_OK 、値が不正なときは ::VOICEVOX_RESULT_INVALID_AUDIO_QUERY_ERROR
+ *
+ * \no-orig-impl{voicevox_audio_query_set_pre_phoneme_length}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_audio_query_set_pre_phoneme_length(const struct VoicevoxAudioQuery *audio_query,
+                                                               float pre_phoneme_length);
+
+/**
+ * ::VoicevoxAudioQuery の`postPhonemeLength`(音声の後の無音時間)を取得する。
+ *
+ * @param [in] audio_query AudioQuery
+ *
+ * @returns 音声の後の無音時間
+ *
+ * \no-orig-impl{voicevox_audio_query_get_post_phoneme_length}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+float voicevox_audio_query_get_post_phoneme_length(const struct VoicevoxAudioQuery *audio_query);
+
+/**
+ * ::VoicevoxAudioQuery の`postPhonemeLength`(音声の後の無音時間)を設定する。
+ *
+ * @param [in] audio_query AudioQuery
+ * @param [in] post_phoneme_length 音声の後の無音時間
+ *
+ * @returns 成功時には ::VOICEVOX_RESULT_OK 、値が不正なときは ::VOICEVOX_RESULT_INVALID_AUDIO_QUERY_ERROR
+ *
+ * \no-orig-impl{voicevox_audio_query_set_post_phoneme_length}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_audio_query_set_post_phoneme_length(const struct VoicevoxAudioQuery *audio_query,
+                                                                float post_phoneme_length);
+
+/**
+ * ::VoicevoxAudioQuery の`outputSamplingRate`(音声データの出力サンプリングレート)を取得する。
+ *
+ * @param [in] audio_query AudioQuery
+ *
+ * @returns 音声データの出力サンプリングレート
+ *
+ * \no-orig-impl{voicevox_audio_query_get_output_sampling_rate}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+uint32_t voicevox_audio_query_get_output_sampling_rate(const struct VoicevoxAudioQuery *audio_query);
+
+/**
+ * ::VoicevoxAudioQuery の`outputSamplingRate`(音声データの出力サンプリングレート)を設定する。
+ *
+ * @param [in] audio_query AudioQuery
+ * @param [in] output_sampling_rate 音声データの出力サンプリングレート
+ *
+ * @returns 成功時には ::VOICEVOX_RESULT_OK 、値が不正なときは ::VOICEVOX_RESULT_INVALID_AUDIO_QUERY_ERROR
+ *
+ * \no-orig-impl{voicevox_audio_query_set_output_sampling_rate}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_audio_query_set_output_sampling_rate(const struct VoicevoxAudioQuery *audio_query,
+                                                                 uint32_t output_sampling_rate);
+
+/**
+ * ::VoicevoxAudioQuery の`outputStereo`(音声データをステレオ出力するか否か)を取得する。
+ *
+ * @param [in] audio_query AudioQuery
+ *
+ * @returns 音声データをステレオ出力するか否か
+ *
+ * \no-orig-impl{voicevox_audio_query_get_output_stereo}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+bool voicevox_audio_query_get_output_stereo(const struct VoicevoxAudioQuery *audio_query);
+
+/**
+ * ::VoicevoxAudioQuery の`outputStereo`(音声データをステレオ出力するか否か)を設定する。
+ *
+ * @param [in] audio_query AudioQuery
+ * @param [in] output_stereo 音声データをステレオ出力するか否か
+ *
+ * \no-orig-impl{voicevox_audio_query_set_output_stereo}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+void voicevox_audio_query_set_output_stereo(const struct VoicevoxAudioQuery *audio_query,
+                                            bool output_stereo);
+
+/**
+ * ::VoicevoxAudioQuery が持つAccentPhraseの数を取得する。
+ *
+ * @param [in] audio_query AudioQuery
+ *
+ * @returns AccentPhraseの数
+ *
+ * \no-orig-impl{voicevox_audio_query_get_accent_phrases_length}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+uintptr_t voicevox_audio_query_get_accent_phrases_length(const struct VoicevoxAudioQuery *audio_query);
+
+/**
+ * ::VoicevoxAudioQuery の`accent_phrase_index`番目のAccentPhraseの内容を取得する。
+ *
+ * @param [in] audio_query AudioQuery
+ * @param [in] accent_phrase_index AccentPhraseのインデックス
+ * @param [out] output_accent_phrase 出力先
+ *
+ * @returns 成功時には ::VOICEVOX_RESULT_OK 、インデックスが範囲外のときは ::VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR
+ *
+ * \safety{
+ * - `output_accent_phrase`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ *
+ * \no-orig-impl{voicevox_audio_query_get_accent_phrase}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_audio_query_get_accent_phrase(const struct VoicevoxAudioQuery *audio_query,
+                                                          uintptr_t accent_phrase_index,
+                                                          struct VoicevoxAccentPhraseView *output_accent_phrase);
+
+/**
+ * ::VoicevoxAudioQuery の`accent_phrase_index`番目のAccentPhraseについて、疑問系かどうかを設定する。
+ *
+ * @param [in] audio_query AudioQuery
+ * @param [in] accent_phrase_index AccentPhraseのインデックス
+ * @param [in] is_interrogative 疑問系かどうか
+ *
+ * @returns 成功時には ::VOICEVOX_RESULT_OK 、インデックスが範囲外のときは ::VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR
+ *
+ * \no-orig-impl{voicevox_audio_query_set_is_interrogative}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_audio_query_set_is_interrogative(const struct VoicevoxAudioQuery *audio_query,
+                                                             uintptr_t accent_phrase_index,
+                                                             bool is_interrogative);
+
+/**
+ * ::VoicevoxAudioQuery の`accent_phrase_index`番目のAccentPhraseの、`mora_index`番目のモーラの内容を取得する。
+ *
+ * `mora_index`が ::VoicevoxAccentPhraseView の`moras_length`と等しいときは、`pause_mora`を対象とする。
+ *
+ * @param [in] audio_query AudioQuery
+ * @param [in] accent_phrase_index AccentPhraseのインデックス
+ * @param [in] mora_index モーラのインデックス
+ * @param [out] output_mora 出力先
+ *
+ * @returns 成功時には ::VOICEVOX_RESULT_OK 、インデックスが範囲外のときは ::VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR
+ *
+ * \safety{
+ * - `output_mora`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ *
+ * \no-orig-impl{voicevox_audio_query_get_mora}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_audio_query_get_mora(const struct VoicevoxAudioQuery *audio_query,
+                                                 uintptr_t accent_phrase_index,
+                                                 uintptr_t mora_index,
+                                                 struct VoicevoxMoraView *output_mora);
+
+/**
+ * ::VoicevoxAudioQuery の`accent_phrase_index`番目のAccentPhraseの、`mora_index`番目のモーラの内容を設定する。
+ *
+ * `mora_index`の扱いは ::voicevox_audio_query_get_mora と同じ。
+ *
+ * 子音の有無は変更できず、`mora.has_consonant`は無視される。子音が無いモーラに対しては、`mora.consonant_length`も無視される。
+ *
+ * @param [in] audio_query AudioQuery
+ * @param [in] accent_phrase_index AccentPhraseのインデックス
+ * @param [in] mora_index モーラのインデックス
+ * @param [in] mora 設定する内容
+ *
+ * @returns 結果コード。インデックスが範囲外のときは ::VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR 、値が不正なときは ::VOICEVOX_RESULT_INVALID_MORA_ERROR
+ *
+ * \no-orig-impl{voicevox_audio_query_set_mora}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_audio_query_set_mora(const struct VoicevoxAudioQuery *audio_query,
+                                                 uintptr_t accent_phrase_index,
+                                                 uintptr_t mora_index,
+                                                 struct VoicevoxMoraView mora);
+
+/**
+ * ::VoicevoxAudioQuery を<b>破棄</b>(_destruct_)する。
+ *
+ * 破棄対象への他スレッドでのアクセスが存在する場合、それらがすべて終わるのを待ってから破棄する。
+ *
+ * この関数の呼び出し後に破棄し終えた対象にアクセスすると、プロセスを異常終了する。
+ *
+ * @param [in] audio_query 破棄対象。nullable
+ *
+ * \no-orig-impl{voicevox_audio_query_delete}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+void voicevox_audio_query_delete(struct VoicevoxAudioQuery *audio_query);
+
+/**
+ * 楽譜のJSON文字列から ::VoicevoxScore を<b>構築</b>(_construct_)する。
+ *
+ * @param [in] score_json 楽譜のJSON文字列
+ * @param [out] out_score 構築先
+ *
+ * @returns 結果コード
+ *
+ * \safety{
+ * - `score_json`はヌル終端文字列を指し、かつ<a href="#voicevox-core-safety">読み込みについて有効</a>でなければならない。
+ * - `out_score`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ *
+ * \no-orig-impl{voicevox_score_new_from_json}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_score_new_from_json(const char *score_json,
+                                                struct VoicevoxScore **out_score);
+
+/**
+ * ::VoicevoxScore をJSON文字列にする。
+ *
+ * JSONの解放は ::voicevox_json_free で行う。
+ *
+ * @param [in] score 楽譜
+ *
+ * @returns 楽譜のJSON文字列
+ *
+ * \no-orig-impl{voicevox_score_create_json}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+char *voicevox_score_create_json(const struct VoicevoxScore *score);
+
+/**
+ * ::VoicevoxScore が持つ音符の数を取得する。
+ *
+ * @param [in] score 楽譜
+ *
+ * @returns 音符の数
+ *
+ * \no-orig-impl{voicevox_score_get_notes_length}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+uintptr_t voicevox_score_get_notes_length(const struct VoicevoxScore *score);
+
+/**
+ * ::VoicevoxScore の`note_index`番目の音符の内容を取得する。
+ *
+ * @param [in] score 楽譜
+ * @param [in] note_index 音符のインデックス
+ * @param [out] output_note 出力先
+ *
+ * @returns 成功時には ::VOICEVOX_RESULT_OK 、インデックスが範囲外のときは ::VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR
+ *
+ * \safety{
+ * - `output_note`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ *
+ * \no-orig-impl{voicevox_score_get_note}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_score_get_note(const struct VoicevoxScore *score,
+                                           uintptr_t note_index,
+                                           struct VoicevoxNoteView *output_note);
+
+/**
+ * ::VoicevoxScore から、 ::VoicevoxFrameAudioQuery を生成する。
+ *
+ * ::voicevox_synthesizer_create_sing_frame_audio_query と同じだが、JSON文字列の代わりに ::VoicevoxScore と ::VoicevoxFrameAudioQuery を用いる。
+ *
+ * @param [in] synthesizer 音声シンセサイザ
+ * @param [in] score 楽譜
+ * @param [in] style_id スタイルID
+ * @param [out] out_frame_audio_query 生成先
+ *
+ * @returns 結果コード
+ *
+ * \safety{
+ * - `out_frame_audio_query`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ *
+ * \no-orig-impl{voicevox_synthesizer_create_sing_frame_audio_query_handle}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_synthesizer_create_sing_frame_audio_query_handle(const struct VoicevoxSynthesizer *synthesizer,
+                                                                             const struct VoicevoxScore *score,
+                                                                             VoicevoxStyleId style_id,
+                                                                             struct VoicevoxFrameAudioQuery **out_frame_audio_query);
+
+/**
+ * ::VoicevoxScore を<b>破棄</b>(_destruct_)する。
+ *
+ * 破棄対象への他スレッドでのアクセスが存在する場合、それらがすべて終わるのを待ってから破棄する。
+ *
+ * この関数の呼び出し後に破棄し終えた対象にアクセスすると、プロセスを異常終了する。
+ *
+ * @param [in] score 破棄対象。nullable
+ *
+ * \no-orig-impl{voicevox_score_delete}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+void voicevox_score_delete(struct VoicevoxScore *score);
+
+/**
+ * FrameAudioQueryのJSON文字列から ::VoicevoxFrameAudioQuery を<b>構築</b>(_construct_)する。
+ *
+ * @param [in] frame_audio_query_json FrameAudioQueryのJSON文字列
+ * @param [out] out_frame_audio_query 構築先
+ *
+ * @returns 結果コード
+ *
+ * \safety{
+ * - `frame_audio_query_json`はヌル終端文字列を指し、かつ<a href="#voicevox-core-safety">読み込みについて有効</a>でなければならない。
+ * - `out_frame_audio_query`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ *
+ * \no-orig-impl{voicevox_frame_audio_query_new_from_json}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_frame_audio_query_new_from_json(const char *frame_audio_query_json,
+                                                            struct VoicevoxFrameAudioQuery **out_frame_audio_query);
+
+/**
+ * ::VoicevoxFrameAudioQuery から音声合成を行う。
+ *
+ * ::voicevox_synthesizer_frame_synthesis と同じだが、JSON文字列の代わりに ::VoicevoxFrameAudioQuery を用いる。
+ *
+ * 生成したWAVデータを解放するには ::voicevox_wav_free を使う。
+ *
+ * @param [in] synthesizer 音声シンセサイザ
+ * @param [in] frame_audio_query FrameAudioQuery
+ * @param [in] style_id スタイルID
+ * @param [out] output_wav_length 出力のバイト長
+ * @param [out] output_wav 出力先
+ *
+ * @returns 結果コード
+ *
+ * \safety{
+ * - `output_wav_length`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * - `output_wav`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ *
+ * \no-orig-impl{voicevox_synthesizer_frame_synthesis_from_handle}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_synthesizer_frame_synthesis_from_handle(const struct VoicevoxSynthesizer *synthesizer,
+                                                                    const struct VoicevoxFrameAudioQuery *frame_audio_query,
+                                                                    VoicevoxStyleId style_id,
+                                                                    uintptr_t *output_wav_length,
+                                                                    uint8_t **output_wav);
+
+/**
+ * ::VoicevoxFrameAudioQuery をJSON文字列にする。
+ *
+ * JSONの解放は ::voicevox_json_free で行う。
+ *
+ * @param [in] frame_audio_query FrameAudioQuery
+ *
+ * @returns FrameAudioQueryのJSON文字列
+ *
+ * \no-orig-impl{voicevox_frame_audio_query_create_json}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+char *voicevox_frame_audio_query_create_json(const struct VoicevoxFrameAudioQuery *frame_audio_query);
+
+/**
+ * ::VoicevoxFrameAudioQuery のフレーム数、すなわち`f0`と`volume`の長さを取得する。
+ *
+ * @param [in] frame_audio_query FrameAudioQuery
+ *
+ * @returns フレーム数
+ *
+ * \no-orig-impl{voicevox_frame_audio_query_get_frames_length}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+uintptr_t voicevox_frame_audio_query_get_frames_length(const struct VoicevoxFrameAudioQuery *frame_audio_query);
+
+/**
+ * ::VoicevoxFrameAudioQuery の`frame_index`番目のフレームの基本周波数を取得する。
+ *
+ * @param [in] frame_audio_query FrameAudioQuery
+ * @param [in] frame_index フレームのインデックス
+ * @param [out] output_f0 出力先
+ *
+ * @returns 成功時には ::VOICEVOX_RESULT_OK 、インデックスが範囲外のときは ::VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR
+ *
+ * \safety{
+ * - `output_f0`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ *
+ * \no-orig-impl{voicevox_frame_audio_query_get_f0}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_frame_audio_query_get_f0(const struct VoicevoxFrameAudioQuery *frame_audio_query,
+                                                     uintptr_t frame_index,
+                                                     float *output_f0);
+
+/**
+ * ::VoicevoxFrameAudioQuery の`frame_index`番目のフレームの基本周波数を設定する。
+ *
+ * @param [in] frame_audio_query FrameAudioQuery
+ * @param [in] frame_index フレームのインデックス
+ * @param [in] f0 基本周波数
+ *
+ * @returns 結果コード。インデックスが範囲外のときは ::VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR 、値が不正なときは ::VOICEVOX_RESULT_INVALID_FRAME_AUDIO_QUERY_ERROR
+ *
+ * \no-orig-impl{voicevox_frame_audio_query_set_f0}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_frame_audio_query_set_f0(const struct VoicevoxFrameAudioQuery *frame_audio_query,
+                                                     uintptr_t frame_index,
+                                                     float f0);
+
+/**
+ * ::VoicevoxFrameAudioQuery の`frame_index`番目のフレームの音量を取得する。
+ *
+ * @param [in] frame_audio_query FrameAudioQuery
+ * @param [in] frame_index フレームのインデックス
+ * @param [out] output_volume 出力先
+ *
+ * @returns 成功時には ::VOICEVOX_RESULT_OK 、インデックスが範囲外のときは ::VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR
+ *
+ * \safety{
+ * - `output_volume`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ *
+ * \no-orig-impl{voicevox_frame_audio_query_get_volume}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_frame_audio_query_get_volume(const struct VoicevoxFrameAudioQuery *frame_audio_query,
+                                                         uintptr_t frame_index,
+                                                         float *output_volume);
+
+/**
+ * ::VoicevoxFrameAudioQuery の`frame_index`番目のフレームの音量を設定する。
+ *
+ * @param [in] frame_audio_query FrameAudioQuery
+ * @param [in] frame_index フレームのインデックス
+ * @param [in] volume 音量
+ *
+ * @returns 結果コード。インデックスが範囲外のときは ::VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR 、値が不正なときは ::VOICEVOX_RESULT_INVALID_FRAME_AUDIO_QUERY_ERROR
+ *
+ * \no-orig-impl{voicevox_frame_audio_query_set_volume}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_frame_audio_query_set_volume(const struct VoicevoxFrameAudioQuery *frame_audio_query,
+                                                         uintptr_t frame_index,
+                                                         float volume);
+
+/**
+ * ::VoicevoxFrameAudioQuery が持つ音素の数を取得する。
+ *
+ * 各音素の内容は ::voicevox_frame_audio_query_get_phoneme で得る。
+ *
+ * @param [in] frame_audio_query FrameAudioQuery
+ *
+ * @returns 音素の数
+ *
+ * \no-orig-impl{voicevox_frame_audio_query_get_phonemes_length}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+uintptr_t voicevox_frame_audio_query_get_phonemes_length(const struct VoicevoxFrameAudioQuery *frame_audio_query);
+
+/**
+ * ::VoicevoxFrameAudioQuery の`phoneme_index`番目の音素の内容を取得する。
+ *
+ * @param [in] frame_audio_query FrameAudioQuery
+ * @param [in] phoneme_index 音素のインデックス
+ * @param [out] output_phoneme 出力先
+ *
+ * @returns 成功時には ::VOICEVOX_RESULT_OK 、インデックスが範囲外のときは ::VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR
+ *
+ * \safety{
+ * - `output_phoneme`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ *
+ * \no-orig-impl{voicevox_frame_audio_query_get_phoneme}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_frame_audio_query_get_phoneme(const struct VoicevoxFrameAudioQuery *frame_audio_query,
+                                                          uintptr_t phoneme_index,
+                                                          struct VoicevoxFramePhonemeView *output_phoneme);
+
+/**
+ * ::VoicevoxFrameAudioQuery の`volumeScale`(全体の音量)を取得する。
+ *
+ * @param [in] frame_audio_query FrameAudioQuery
+ *
+ * @returns 全体の音量
+ *
+ * \no-orig-impl{voicevox_frame_audio_query_get_volume_scale}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+float voicevox_frame_audio_query_get_volume_scale(const struct VoicevoxFrameAudioQuery *frame_audio_query);
+
+/**
+ * ::VoicevoxFrameAudioQuery の`volumeScale`(全体の音量)を設定する。
+ *
+ * @param [in] frame_audio_query FrameAudioQuery
+ * @param [in] volume_scale 全体の音量
+ *
+ * @returns 成功時には ::VOICEVOX_RESULT_OK 、値が不正なときは ::VOICEVOX_RESULT_INVALID_FRAME_AUDIO_QUERY_ERROR
+ *
+ * \no-orig-impl{voicevox_frame_audio_query_set_volume_scale}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_frame_audio_query_set_volume_scale(const struct VoicevoxFrameAudioQuery *frame_audio_query,
+                                                               float volume_scale);
+
+/**
+ * ::VoicevoxFrameAudioQuery の`outputSamplingRate`(音声データの出力サンプリングレート)を取得する。
+ *
+ * @param [in] frame_audio_query FrameAudioQuery
+ *
+ * @returns 音声データの出力サンプリングレート
+ *
+ * \no-orig-impl{voicevox_frame_audio_query_get_output_sampling_rate}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+uint32_t voicevox_frame_audio_query_get_output_sampling_rate(const struct VoicevoxFrameAudioQuery *frame_audio_query);
+
+/**
+ * ::VoicevoxFrameAudioQuery の`outputSamplingRate`(音声データの出力サンプリングレート)を設定する。
+ *
+ * @param [in] frame_audio_query FrameAudioQuery
+ * @param [in] output_sampling_rate 音声データの出力サンプリングレート
+ *
+ * @returns 成功時には ::VOICEVOX_RESULT_OK 、値が不正なときは ::VOICEVOX_RESULT_INVALID_FRAME_AUDIO_QUERY_ERROR
+ *
+ * \no-orig-impl{voicevox_frame_audio_query_set_output_sampling_rate}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_frame_audio_query_set_output_sampling_rate(const struct VoicevoxFrameAudioQuery *frame_audio_query,
+                                                                       uint32_t output_sampling_rate);
+
+/**
+ * ::VoicevoxFrameAudioQuery の`outputStereo`(音声データをステレオ出力するか否か)を取得する。
+ *
+ * @param [in] frame_audio_query FrameAudioQuery
+ *
+ * @returns 音声データをステレオ出力するか否か
+ *
+ * \no-orig-impl{voicevox_frame_audio_query_get_output_stereo}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+bool voicevox_frame_audio_query_get_output_stereo(const struct VoicevoxFrameAudioQuery *frame_audio_query);
+
+/**
+ * ::VoicevoxFrameAudioQuery の`outputStereo`(音声データをステレオ出力するか否か)を設定する。
+ *
+ * @param [in] frame_audio_query FrameAudioQuery
+ * @param [in] output_stereo 音声データをステレオ出力するか否か
+ *
+ * \no-orig-impl{voicevox_frame_audio_query_set_output_stereo}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+void voicevox_frame_audio_query_set_output_stereo(const struct VoicevoxFrameAudioQuery *frame_audio_query,
+                                                  bool output_stereo);
+
+/**
+ * ::VoicevoxFrameAudioQuery を<b>破棄</b>(_destruct_)する。
+ *
+ * 破棄対象への他スレッドでのアクセスが存在する場合、それらがすべて終わるのを待ってから破棄する。
+ *
+ * この関数の呼び出し後に破棄し終えた対象にアクセスすると、プロセスを異常終了する。
+ *
+ * @param [in] frame_audio_query 破棄対象。nullable
+ *
+ * \no-orig-impl{voicevox_frame_audio_query_delete}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+void voicevox_frame_audio_query_delete(struct VoicevoxFrameAudioQuery *frame_audio_query);
+
+/**
+ * デフォルトの非同期の処理のオプションを生成する
+ * @return 非同期の処理のオプション
+ *
+ * \no-orig-impl{voicevox_make_default_job_options}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+struct VoicevoxJobOptions voicevox_make_default_job_options(void);
+
+/**
+ * AudioQueryから音声合成を行う処理を、非同期に開始する。
+ *
+ * ::voicevox_synthesizer_synthesis の非同期版。処理はバックグラウンドのワーカースレッド( ::VoicevoxJob を参照)で行われ、終わったら`callback`がそのスレッドから呼ばれる。`callback`は、この関数から帰る前に呼ばれることもある。
+ *
+ * この関数が ::VOICEVOX_RESULT_OK 以外を返したときは、処理は開始されず`callback`は呼ばれない。
+ *
+ * @param [in] synthesizer 音声シンセサイザ
+ * @param [in] audio_query_json AudioQueryのJSON文字列
+ * @param [in] style_id スタイルID
+ * @param [in] options オプション
+ * @param [in] job_options 非同期の処理のオプション
+ * @param [in] callback 処理が終わったときに呼ばれるコールバック
+ * @param [in] user_data `callback`に渡されるポインタ
+ * @param [out] out_job 開始した処理
+ *
+ * @returns 結果コード
+ *
+ * \safety{
+ * - `audio_query_json`はヌル終端文字列を指し、かつ<a href="#voicevox-core-safety">読み込みについて有効</a>でなければならない。
+ * - `callback`は、任意のスレッドから呼び出されても問題が無いものでなければならない。
+ * - `user_data`は、`callback`が呼ばれるまで`callback`にとって有効でなければならない。
+ * - `out_job`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ *
+ * \no-orig-impl{voicevox_synthesizer_synthesis_async}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_synthesizer_synthesis_async(const struct VoicevoxSynthesizer *synthesizer,
+                                                        const char *audio_query_json,
+                                                        VoicevoxStyleId style_id,
+                                                        struct VoicevoxSynthesisOptions options,
+                                                        struct VoicevoxJobOptions job_options,
+                                                        VoicevoxWavCallback callback,
+                                                        void *user_data,
+                                                        struct VoicevoxJob **out_job);
+
+/**
+ * AquesTalk風記法から音声合成を行う処理を、非同期に開始する。
+ *
+ * ::voicevox_synthesizer_tts_from_kana の非同期版。処理はバックグラウンドのワーカースレッド( ::VoicevoxJob を参照)で行われ、終わったら`callback`がそのスレッドから呼ばれる。`callback`は、この関数から帰る前に呼ばれることもある。
+ *
+ * この関数が ::VOICEVOX_RESULT_OK 以外を返したときは、処理は開始されず`callback`は呼ばれない。
+ *
+ * @param [in] synthesizer
+ * @param [in] kana AquesTalk風記法
+ * @param [in] style_id スタイルID
+ * @param [in] options オプション
+ * @param [in] job_options 非同期の処理のオプション
+ * @param [in] callback 処理が終わったときに呼ばれるコールバック
+ * @param [in] user_data `callback`に渡されるポインタ
+ * @param [out] out_job 開始した処理
+ *
+ * @returns 結果コード
+ *
+ * \safety{
+ * - `kana`はヌル終端文字列を指し、かつ<a href="#voicevox-core-safety">読み込みについて有効</a>でなければならない。
+ * - `callback`は、任意のスレッドから呼び出されても問題が無いものでなければならない。
+ * - `user_data`は、`callback`が呼ばれるまで`callback`にとって有効でなければならない。
+ * - `out_job`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ *
+ * \no-orig-impl{voicevox_synthesizer_tts_from_kana_async}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_synthesizer_tts_from_kana_async(const struct VoicevoxSynthesizer *synthesizer,
+                                                            const char *kana,
+                                                            VoicevoxStyleId style_id,
+                                                            struct VoicevoxTtsOptions options,
+                                                            struct VoicevoxJobOptions job_options,
+                                                            VoicevoxWavCallback callback,
+                                                            void *user_data,
+                                                            struct VoicevoxJob **out_job);
+
+/**
+ * 日本語テキストから音声合成を行う処理を、非同期に開始する。
+ *
+ * ::voicevox_synthesizer_tts の非同期版。処理はバックグラウンドのワーカースレッド( ::VoicevoxJob を参照)で行われ、終わったら`callback`がそのスレッドから呼ばれる。`callback`は、この関数から帰る前に呼ばれることもある。
+ *
+ * この関数が ::VOICEVOX_RESULT_OK 以外を返したときは、処理は開始されず`callback`は呼ばれない。
+ *
+ * @param [in] synthesizer
+ * @param [in] text UTF-8の日本語テキスト
+ * @param [in] style_id スタイルID
+ * @param [in] options オプション
+ * @param [in] job_options 非同期の処理のオプション
+ * @param [in] callback 処理が終わったときに呼ばれるコールバック
+ * @param [in] user_data `callback`に渡されるポインタ
+ * @param [out] out_job 開始した処理
+ *
+ * @returns 結果コード
+ *
+ * \example{
+ * ```c
+ * static void on_finished(VoicevoxResultCode result_code, uintptr_t wav_length,
+ *                         uint8_t *wav, void *user_data) {
+ *   if (result_code == VOICEVOX_RESULT_OK) {
+ *     play((Player *)user_data, wav, wav_length);
+ *   }
+ *   voicevox_wav_free(wav);
+ * }
+ *
+ * VoicevoxJob *job;
+ * voicevox_synthesizer_tts_async(synthesizer, "こんにちは", 0,
+ *                                voicevox_make_default_tts_options(),
+ *                                voicevox_make_default_job_options(),
+ *                                on_finished, player, &job);
+ *
+ * // 不要になったらキャンセルする
+ * voicevox_job_cancel(job);
+ * voicevox_job_delete(job);
+ * ```
+ * }
+ *
+ * \safety{
+ * - `text`はヌル終端文字列を指し、かつ<a href="#voicevox-core-safety">読み込みについて有効</a>でなければならない。
+ * - `callback`は、任意のスレッドから呼び出されても問題が無いものでなければならない。
+ * - `user_data`は、`callback`が呼ばれるまで`callback`にとって有効でなければならない。
+ * - `out_job`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ *
+ * \no-orig-impl{voicevox_synthesizer_tts_async}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_synthesizer_tts_async(const struct VoicevoxSynthesizer *synthesizer,
+                                                  const char *text,
+                                                  VoicevoxStyleId style_id,
+                                                  struct VoicevoxTtsOptions options,
+                                                  struct VoicevoxJobOptions job_options,
+                                                  VoicevoxWavCallback callback,
+                                                  void *user_data,
+                                                  struct VoicevoxJob **out_job);
+
+/**
+ * 歌唱音声合成を行う処理を、非同期に開始する。
+ *
+ * ::voicevox_synthesizer_frame_synthesis の非同期版。処理はバックグラウンドのワーカースレッド( ::VoicevoxJob を参照)で行われ、終わったら`callback`がそのスレッドから呼ばれる。`callback`は、この関数から帰る前に呼ばれることもある。
+ *
+ * この関数が ::VOICEVOX_RESULT_OK 以外を返したときは、処理は開始されず`callback`は呼ばれない。
+ *
+ * @param [in] synthesizer 音声シンセサイザ
+ * @param [in] frame_audio_query_json FrameAudioQueryのJSON文字列
+ * @param [in] style_id スタイルID
+ * @param [in] job_options 非同期の処理のオプション
+ * @param [in] callback 処理が終わったときに呼ばれるコールバック
+ * @param [in] user_data `callback`に渡されるポインタ
+ * @param [out] out_job 開始した処理
+ *
+ * @returns 結果コード
+ *
+ * \safety{
+ * - `frame_audio_query_json`はヌル終端文字列を指し、かつ<a href="#voicevox-core-safety">読み込みについて有効</a>でなければならない。
+ * - `callback`は、任意のスレッドから呼び出されても問題が無いものでなければならない。
+ * - `user_data`は、`callback`が呼ばれるまで`callback`にとって有効でなければならない。
+ * - `out_job`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+ * }
+ *
+ * \no-orig-impl{voicevox_synthesizer_frame_synthesis_async}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+VoicevoxResultCode voicevox_synthesizer_frame_synthesis_async(const struct VoicevoxSynthesizer *synthesizer,
+                                                              const char *frame_audio_query_json,
+                                                              VoicevoxStyleId style_id,
+                                                              struct VoicevoxJobOptions job_options,
+                                                              VoicevoxWavCallback callback,
+                                                              void *user_data,
+                                                              struct VoicevoxJob **out_job);
+
+/**
+ * 非同期の処理をキャンセルする。
+ *
+ * キャンセルされた処理は、`callback`に ::VOICEVOX_RESULT_CANCELLED を渡して終わる。既に終わっている処理に対しては何もしない。
+ *
+ * 音声モデルの推論を中断するかどうかは ::VoicevoxJobOptions::cancellable による。
+ *
+ * この関数は処理が止まるのを待たずに帰る。
+ *
+ * @param [in] job 対象
+ *
+ * \no-orig-impl{voicevox_job_cancel}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+void voicevox_job_cancel(const struct VoicevoxJob *job);
+
+/**
+ * ::VoicevoxJob を<b>破棄</b>(_destruct_)する。
+ *
+ * 処理そのものはキャンセルされず、終わった際には`callback`が呼ばれる。キャンセルするには、先に ::voicevox_job_cancel を呼ぶ。
+ *
+ * この関数の呼び出し後に破棄し終えた対象にアクセスすると、プロセスを異常終了する。
+ *
+ * @param [in] job 破棄対象。nullable
+ *
+ * \no-orig-impl{voicevox_job_delete}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+void voicevox_job_delete(struct VoicevoxJob *job);
+
+/**
+ * JSON文字列を解放する。
+ *
+ * @param [in] json 解放するJSON文字列。nullable
+ *
+ * \safety{
+ * - `json`がヌルポインタでないならば、以下のAPIで得られたポインタでなくてはいけない。
+ *     - ::voicevox_audio_query_create_from_accent_phrases
+ *     - ::voicevox_onnxruntime_create_supported_devices_json
+ *     - ::voicevox_voice_model_file_create_metas_json
+ *     - ::voicevox_open_jtalk_rc_analyze
+ *     - ::voicevox_synthesizer_create_metas_json
+ *     - ::voicevox_synthesizer_create_audio_query
+ *     - ::voicevox_synthesizer_create_audio_query_from_kana
+ *     - ::voicevox_synthesizer_create_accent_phrases
+ *     - ::voicevox_synthesizer_create_accent_phrases_from_kana
+ *     - ::voicevox_synthesizer_replace_mora_data
+ *     - ::voicevox_synthesizer_replace_phoneme_length
+ *     - ::voicevox_synthesizer_replace_mora_pitch
+ *     - ::voicevox_synthesizer_create_sing_frame_audio_query
+ *     - ::voicevox_synthesizer_create_sing_frame_f0
+ *     - ::voicevox_synthesizer_create_sing_frame_volume
+ *     - ::voicevox_user_dict_to_json
+ * - 文字列の長さは生成時より変更されていてはならない。
+ * - `json`がヌルポインタでないならば、<a href="#voicevox-core-safety">読み込みと書き込みについて有効</a>でなければならない。
+ * - `json`がヌルポインタでないならば、以後<b>ダングリングポインタ</b>(_dangling pointer_)として扱われなくてはならない。
+ * }
+ *
+ * \no-orig-impl{voicevox_json_free}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+void voicevox_json_free(char *json);
+
+/**
+ * WAVデータを解放する。
+ *
+ * @param [in] wav 解放するWAVデータ。nullable
+ *
+ * \safety{
+ * - `wav`がヌルポインタでないならば、以下のAPIで得られたポインタでなくてはいけない。
+ *     - ::voicevox_synthesizer_synthesis
+ *     - ::voicevox_synthesizer_tts
+ *     - ::voicevox_synthesizer_tts_from_kana
+ *     - ::voicevox_synthesizer_frame_synthesis
+ *     - ::VoicevoxWavCallback
+ * - `wav`がヌルポインタでないならば、<a href="#voicevox-core-safety">読み込みと書き込みについて有効</a>でなければならない。
+ * - `wav`がヌルポインタでないならば、以後<b>ダングリングポインタ</b>(_dangling pointer_)として扱われなくてはならない。
+ * }
+ *
+ * \no-orig-impl{voicevox_wav_free}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+void voicevox_wav_free(uint8_t *wav);
+
+/**
+ * 結果コードに対応したメッセージ文字列を取得する。
+ *
+ * @param [in] result_code 結果コード
+ *
+ * @returns 結果コードに対応したメッセージ文字列
+ *
+ * \examples{
+ * ```c
+ * const char *actual = voicevox_error_result_to_message(VOICEVOX_RESULT_OK);
+ * const char *EXPECTED = "エラーが発生しませんでした";
+ * assert(strcmp(actual, EXPECTED) == 0);
+ * ```
+ *
+ * ```c
+ * const char *actual =
+ *     voicevox_error_result_to_message(VOICEVOX_RESULT_LOAD_MODEL_ERROR);
+ * const char *EXPECTED = "modelデータ読み込みに失敗しました";
+ * assert(strcmp(actual, EXPECTED) == 0);
+ * ```
+ * }
+ *
+ * \no-orig-impl{voicevox_error_result_to_message}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+const char *voicevox_error_result_to_message(VoicevoxResultCode result_code);
+
+/**
+ * このスレッドで最後に失敗した関数のエラーメッセージを取得する。
+ *
+ * ::voicevox_error_result_to_message とは異なり、エラーの原因となったエラーも`Caused by: `に続けて一行ずつ含まれる。
+ *
+ * 返される文字列は、このスレッドで次に関数が失敗するか、スレッドが終了するまで有効である。解放は不要であり、してはならない。
+ *
+ * @returns エラーメッセージ。このスレッドで失敗した関数が無い場合はNULL
+ *
+ * \no-orig-impl{voicevox_last_error_message}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+const char *voicevox_last_error_message(void);
+
+/**
+ * このスレッドで最後に失敗した関数のエラーを、構造化されたJSONとして取得する。
+ *
+ * JSONは次のフィールドを持つオブジェクトである。
+ *
+ * - `result_code`: 結果コード
+ * - `message`: エラーメッセージ
+ * - `sources`: 原因となったエラーのメッセージの配列
+ * - `details`: スタイルID（`style_id`）、音声モデルID（`model_id`）、VVMファイルのパス（`path`）、不正なクエリの部分の名前（`what`）など、エラーに関連する値。含まれるフィールドはエラーによって異なる
+ *
+ * 返される文字列は、このスレッドで次に関数が失敗するか、スレッドが終了するまで有効である。解放は不要であり、してはならない。
+ *
+ * @returns エラーのJSON。このスレッドで失敗した関数が無い場合はNULL
+ *
+ * \example{
+ * ```c
+ * if (voicevox_synthesizer_tts(synthesizer, text, style_id,
+ *                              voicevox_make_default_tts_options(),
+ *                              &wav_length, &wav) != VOICEVOX_RESULT_OK) {
+ *   fprintf(stderr, "%s\n", voicevox_last_error_json());
+ *   // {"result_code":6,"message":"`999` ([talk])に対するスタイルが見つかりませんでした。…","sources":[],"details":{"style_id":999}}
+ * }
+ * ```
+ * }
+ *
+ * \no-orig-impl{voicevox_last_error_json}
+ */
+#ifdef _WIN32
+__declspec(dllimport)
+#endif
+const char *voicevox_last_error_json(void);
 
 /**
  * ::VoicevoxUserDictWord を最低限のパラメータで作成する。
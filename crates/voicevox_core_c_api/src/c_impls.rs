@@ -1,5 +1,5 @@
 use std::{
-    collections::{HashMap, HashSet},
+    collections::HashMap,
     ffi::CString,
     num::NonZero,
     path::Path,
@@ -11,13 +11,17 @@ use camino::Utf8Path;
 use duplicate::duplicate_item;
 use easy_ext::ext;
 use ref_cast::ref_cast_custom;
-use voicevox_core::{CharacterMeta, Result, VoiceModelId};
+use voicevox_core::{
+    AccentPhrase, AudioQuery, CharacterMeta, FrameAudioQuery, Mora, Result, Score, VoiceModelId,
+};
 
 use crate::{
-    OpenJtalkRc, VoicevoxInitializeOptions, VoicevoxOnnxruntime, VoicevoxSynthesizer,
-    VoicevoxUserDict, VoicevoxVoiceModelFile,
-    helpers::CApiResult,
+    OpenJtalkRc, VoicevoxAudioQuery, VoicevoxFrameAudioQuery, VoicevoxInitializeOptions,
+    VoicevoxJob, VoicevoxOnnxruntime, VoicevoxScore, VoicevoxSynthesizer, VoicevoxUserDict,
+    VoicevoxVoiceModelFile,
+    helpers::{CApiError, CApiResult},
     object::{CApiObject, CApiObjectPtrExt as _},
+    text_analyzer::CApiTextAnalyzer,
 };
 
 // FIXME: 中身(Rust API)を直接操作するかラッパーメソッド越しにするのかが混在していて、一貫性を
@@ -83,19 +87,33 @@ impl OpenJtalkRc {
 }
 
 impl VoicevoxSynthesizer {
-    pub(crate) fn new(
+    /// # Safety
+    ///
+    /// `options.operation_placements`はNULLであるか、読み込みについて有効な
+    /// `options.operation_placements_length`要素の領域を指していなければならない。
+    pub(crate) unsafe fn new(
         onnxruntime: &'static VoicevoxOnnxruntime,
-        open_jtalk: *const OpenJtalkRc,
-        VoicevoxInitializeOptions {
+        text_analyzer: CApiTextAnalyzer,
+        options: VoicevoxInitializeOptions,
+    ) -> Result<NonNull<Self>> {
+        // SAFETY: The safety contract must be upheld by the caller.
+        let operation_placements = unsafe { options.operation_placements() };
+        let VoicevoxInitializeOptions {
             acceleration_mode,
             cpu_num_threads,
-        }: VoicevoxInitializeOptions,
-    ) -> Result<NonNull<Self>> {
-        let body = voicevox_core::blocking::Synthesizer::builder(&onnxruntime.0)
-            .text_analyzer(open_jtalk.body().clone())
+            graph_optimization_level,
+            ..
+        } = options;
+        let mut builder = voicevox_core::blocking::Synthesizer::builder(&onnxruntime.0)
+            .text_analyzer(text_analyzer)
             .acceleration_mode(acceleration_mode.into())
             .cpu_num_threads(cpu_num_threads)
-            .build()?;
+            .graph_optimization_level(graph_optimization_level.into());
+        for &placement in operation_placements {
+            let (op, placement) = placement.into();
+            builder = builder.operation_placement(op, placement);
+        }
+        let body = builder.build()?;
         Ok(<Self as CApiObject>::new(body))
     }
 }
@@ -133,6 +151,11 @@ impl VoicevoxVoiceModelFile {
         let model = voicevox_core::blocking::VoiceModelFile::open(path)?;
         Ok(Self::new(model))
     }
+
+    pub(crate) fn from_bytes(bytes: Vec<u8>) -> Result<NonNull<Self>> {
+        let model = voicevox_core::blocking::VoiceModelFile::from_bytes(bytes)?;
+        Ok(Self::new(model))
+    }
 }
 
 #[ext(VoicevoxVoiceModelFilePtrExt)]
@@ -142,23 +165,187 @@ impl *const VoicevoxVoiceModelFile {
     }
 }
 
+impl VoicevoxAudioQuery {
+    pub(crate) fn new(audio_query: AudioQuery) -> NonNull<Self> {
+        <Self as CApiObject>::new(audio_query.into())
+    }
+}
+
+#[ext(VoicevoxAudioQueryPtrExt)]
+impl *const VoicevoxAudioQuery {
+    pub(crate) fn get<R>(self, f: impl FnOnce(&AudioQuery) -> R) -> R {
+        f(&self.body().lock())
+    }
+
+    pub(crate) fn modify<R>(self, f: impl FnOnce(&mut AudioQuery) -> R) -> R {
+        f(&mut self.body().lock())
+    }
+
+    pub(crate) fn get_accent_phrase<R>(
+        self,
+        accent_phrase_index: usize,
+        f: impl FnOnce(&AccentPhrase) -> R,
+    ) -> CApiResult<R> {
+        self.get(|audio_query| {
+            let accent_phrases = &audio_query.accent_phrases;
+            let accent_phrase =
+                accent_phrases
+                    .get(accent_phrase_index)
+                    .ok_or(CApiError::IndexOutOfRange {
+                        index: accent_phrase_index,
+                        len: accent_phrases.len(),
+                    })?;
+            Ok(f(accent_phrase))
+        })
+    }
+
+    pub(crate) fn modify_accent_phrase<R>(
+        self,
+        accent_phrase_index: usize,
+        f: impl FnOnce(&mut AccentPhrase) -> CApiResult<R>,
+    ) -> CApiResult<R> {
+        self.modify(|audio_query| {
+            let len = audio_query.accent_phrases.len();
+            let accent_phrase = audio_query
+                .accent_phrases
+                .get_mut(accent_phrase_index)
+                .ok_or(CApiError::IndexOutOfRange {
+                    index: accent_phrase_index,
+                    len,
+                })?;
+            f(accent_phrase)
+        })
+    }
+
+    /// `mora_index`が`moras`の長さと等しい場合、`pause_mora`を対象とする。
+    pub(crate) fn get_mora<R>(
+        self,
+        accent_phrase_index: usize,
+        mora_index: usize,
+        f: impl FnOnce(&Mora) -> R,
+    ) -> CApiResult<R> {
+        self.get_accent_phrase(accent_phrase_index, |accent_phrase| {
+            let len = accent_phrase.moras.len();
+            let mora = if mora_index == len {
+                accent_phrase.pause_mora.as_ref()
+            } else {
+                accent_phrase.moras.get(mora_index)
+            }
+            .ok_or_else(|| mora_index_out_of_range(accent_phrase, mora_index))?;
+            Ok(f(mora))
+        })?
+    }
+
+    /// `mora_index`が`moras`の長さと等しい場合、`pause_mora`を対象とする。
+    pub(crate) fn modify_mora<R>(
+        self,
+        accent_phrase_index: usize,
+        mora_index: usize,
+        f: impl FnOnce(&mut Mora) -> CApiResult<R>,
+    ) -> CApiResult<R> {
+        self.modify_accent_phrase(accent_phrase_index, |accent_phrase| {
+            let err = mora_index_out_of_range(accent_phrase, mora_index);
+            let len = accent_phrase.moras.len();
+            let mora = if mora_index == len {
+                accent_phrase.pause_mora.as_mut()
+            } else {
+                accent_phrase.moras.get_mut(mora_index)
+            }
+            .ok_or(err)?;
+            f(mora)
+        })
+    }
+}
+
+impl VoicevoxScore {
+    pub(crate) fn new(score: Score) -> NonNull<Self> {
+        <Self as CApiObject>::new(score)
+    }
+}
+
+impl VoicevoxFrameAudioQuery {
+    pub(crate) fn new(frame_audio_query: FrameAudioQuery) -> NonNull<Self> {
+        <Self as CApiObject>::new(frame_audio_query.into())
+    }
+}
+
+#[ext(VoicevoxFrameAudioQueryPtrExt)]
+impl *const VoicevoxFrameAudioQuery {
+    pub(crate) fn get<R>(self, f: impl FnOnce(&FrameAudioQuery) -> R) -> R {
+        f(&self.body().lock())
+    }
+
+    pub(crate) fn modify<R>(self, f: impl FnOnce(&mut FrameAudioQuery) -> R) -> R {
+        f(&mut self.body().lock())
+    }
+
+    /// `f0`や`volume`といった、フレームごとの値を取得する。
+    pub(crate) fn get_frame<T: Copy>(
+        self,
+        frame_index: usize,
+        field: impl FnOnce(&FrameAudioQuery) -> &[T],
+    ) -> CApiResult<T> {
+        self.get(|frame_audio_query| {
+            let values = field(frame_audio_query);
+            values
+                .get(frame_index)
+                .copied()
+                .ok_or(CApiError::IndexOutOfRange {
+                    index: frame_index,
+                    len: values.len(),
+                })
+        })
+    }
+
+    /// `f0`や`volume`といった、フレームごとの値を設定する。
+    pub(crate) fn set_frame<T>(
+        self,
+        frame_index: usize,
+        value: T,
+        field: impl FnOnce(&mut FrameAudioQuery) -> &mut [T],
+    ) -> CApiResult<()> {
+        self.modify(|frame_audio_query| {
+            let values = field(frame_audio_query);
+            let len = values.len();
+            *values
+                .get_mut(frame_index)
+                .ok_or(CApiError::IndexOutOfRange {
+                    index: frame_index,
+                    len,
+                })? = value;
+            Ok(())
+        })
+    }
+}
+
+fn mora_index_out_of_range(accent_phrase: &AccentPhrase, mora_index: usize) -> CApiError {
+    CApiError::IndexOutOfRange {
+        index: mora_index,
+        len: accent_phrase.moras.len() + usize::from(accent_phrase.pause_mora.is_some()),
+    }
+}
+
 fn metas_to_json(metas: &[CharacterMeta]) -> CString {
     let metas = serde_json::to_string(metas).expect("should not fail");
     CString::new(metas).expect("should not contain NUL")
 }
 
 #[duplicate_item(
-    H                          B;
-    [ OpenJtalkRc ]            [ voicevox_core::blocking::OpenJtalk ];
-    [ VoicevoxUserDict ]       [ voicevox_core::blocking::UserDict ];
-    [ VoicevoxSynthesizer ]    [ voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk> ];
-    [ VoicevoxVoiceModelFile ] [ voicevox_core::blocking::VoiceModelFile ];
+    H                           B;
+    [ OpenJtalkRc ]             [ voicevox_core::blocking::OpenJtalk ];
+    [ VoicevoxUserDict ]        [ voicevox_core::blocking::UserDict ];
+    [ VoicevoxSynthesizer ]     [ voicevox_core::blocking::Synthesizer<CApiTextAnalyzer> ];
+    [ VoicevoxVoiceModelFile ]  [ voicevox_core::blocking::VoiceModelFile ];
+    [ VoicevoxJob ]             [ crate::job::CApiJob ];
+    [ VoicevoxAudioQuery ]      [ parking_lot::Mutex<AudioQuery> ];
+    [ VoicevoxScore ]           [ Score ];
+    [ VoicevoxFrameAudioQuery ] [ parking_lot::Mutex<FrameAudioQuery> ];
 )]
 impl CApiObject for H {
     type RustApiObject = B;
 
-    fn known_addrs() -> &'static std::sync::Mutex<HashSet<NonZero<usize>>> {
-        static KNOWN_ADDRS: LazyLock<std::sync::Mutex<HashSet<NonZero<usize>>>> =
+    fn known_addrs() -> &'static std::sync::Mutex<HashMap<NonZero<usize>, usize>> {
+        static KNOWN_ADDRS: LazyLock<std::sync::Mutex<HashMap<NonZero<usize>, usize>>> =
             LazyLock::new(Default::default);
         &KNOWN_ADDRS
     }
@@ -2,42 +2,52 @@ use easy_ext::ext;
 use std::{
     error::Error as _,
     ffi::{CStr, CString},
-    fmt::Debug,
-    iter,
+    fmt::{Debug, Display},
+    iter, ptr,
 };
 use typed_floats::{NonNaNFinite, PositiveFinite};
 use uuid::Uuid;
 use voicevox_core::{
     __internal::interop::{InvalidWordError, Validate},
-    AccelerationMode, AccentPhrase, AudioQuery, FrameAudioQuery, FramePhoneme, Mora, Note, Score,
-    UserDictWord, VoiceModelId,
+    AccelerationMode, AccentPhrase, AudioQuery, FrameAudioQuery, FramePhoneme, Key, Mora, Note,
+    Score, UserDictWord, VoiceModelId,
 };
 
 use duplicate::duplicate_item;
 use either::Either;
 use thiserror::Error;
-use tracing::error;
+use tracing::{error, info};
 
 use crate::{
-    VoicevoxAccelerationMode, VoicevoxInitializeOptions, VoicevoxLoadVoiceModelOptions,
-    VoicevoxOnExistingVoiceModelId, VoicevoxSynthesisOptions, VoicevoxTtsOptions,
-    VoicevoxUserDictWord, VoicevoxUserDictWordType, result_code::VoicevoxResultCode,
+    VoicevoxAccelerationMode, VoicevoxAccentPhraseView, VoicevoxFramePhonemeView,
+    VoicevoxGraphOptimizationLevel, VoicevoxInferenceOperationKind, VoicevoxInitializeOptions,
+    VoicevoxJobOptions, VoicevoxLoadVoiceModelOptions, VoicevoxMoraView, VoicevoxNoteView,
+    VoicevoxOnExistingVoiceModelId, VoicevoxOperationDevice, VoicevoxOperationPlacement,
+    VoicevoxSynthesisOptions, VoicevoxTtsOptions, VoicevoxUserDictWord, VoicevoxUserDictWordType,
+    last_error, result_code::VoicevoxResultCode,
 };
 
 pub(crate) fn into_result_code_with_error(result: CApiResult<()>) -> VoicevoxResultCode {
-    if let Err(err) = &result {
-        display_error(err);
+    let Err(err) = result else {
+        return VoicevoxResultCode::VOICEVOX_RESULT_OK;
+    };
+    if let CApiError::Cancelled = err {
+        // キャンセルはユーザーが要求したものであり、エラーとしては報告しない
+        info!("{err}");
+    } else {
+        display_error(&err);
     }
-    return into_result_code(result);
+    let result_code = into_result_code(&err);
+    last_error::set(&err, result_code);
+    return result_code;
 
-    fn into_result_code(result: CApiResult<()>) -> VoicevoxResultCode {
+    fn into_result_code(err: &CApiError) -> VoicevoxResultCode {
         use CApiError::*;
         use VoicevoxResultCode::*;
         use voicevox_core::ErrorKind::*;
 
-        match result {
-            Ok(()) => VOICEVOX_RESULT_OK,
-            Err(RustApi(err)) => match err.kind() {
+        match err {
+            RustApi(err) => match err.kind() {
                 NotLoadedOpenjtalkDict => VOICEVOX_RESULT_NOT_LOADED_OPENJTALK_DICT_ERROR,
                 GpuSupport => VOICEVOX_RESULT_GPU_SUPPORT_ERROR,
                 InitInferenceRuntime => VOICEVOX_RESULT_INIT_INFERENCE_RUNTIME_ERROR,
@@ -47,6 +57,7 @@ pub(crate) fn into_result_code_with_error(result: CApiResult<()>) -> VoicevoxRes
                 ModelAlreadyLoaded => VOICEVOX_RESULT_MODEL_ALREADY_LOADED_ERROR,
                 StyleAlreadyLoaded => VOICEVOX_RESULT_STYLE_ALREADY_LOADED_ERROR,
                 InvalidModelData => VOICEVOX_RESULT_INVALID_MODEL_DATA_ERROR,
+                VerifyModel => VOICEVOX_RESULT_VERIFY_MODEL_ERROR,
                 GetSupportedDevices => VOICEVOX_RESULT_GET_SUPPORTED_DEVICES_ERROR,
                 StyleNotFound => VOICEVOX_RESULT_STYLE_NOT_FOUND_ERROR,
                 ModelNotFound => VOICEVOX_RESULT_MODEL_NOT_FOUND_ERROR,
@@ -67,15 +78,17 @@ pub(crate) fn into_result_code_with_error(result: CApiResult<()>) -> VoicevoxRes
                 IncompatibleQueries => VOICEVOX_RESULT_INCOMPATIBLE_QUERIES_ERROR,
                 __NonExhaustive => unreachable!(),
             },
-            Err(InvalidUtf8Input) => VOICEVOX_RESULT_INVALID_UTF8_INPUT_ERROR,
-            Err(InvalidAudioQuery(_)) => VOICEVOX_RESULT_INVALID_AUDIO_QUERY_ERROR,
-            Err(InvalidAccentPhrase(_)) => VOICEVOX_RESULT_INVALID_ACCENT_PHRASE_ERROR,
-            Err(InvalidMora(_)) => VOICEVOX_RESULT_INVALID_MORA_ERROR,
-            Err(InvalidScore(_)) => VOICEVOX_RESULT_INVALID_SCORE_ERROR,
-            Err(InvalidNote(_)) => VOICEVOX_RESULT_INVALID_NOTE_ERROR,
-            Err(InvalidFrameAudioQuery(_)) => VOICEVOX_RESULT_INVALID_FRAME_AUDIO_QUERY_ERROR,
-            Err(InvalidFramePhoneme(_)) => VOICEVOX_RESULT_INVALID_FRAME_PHONEME_ERROR,
-            Err(InvalidUuid(_)) => VOICEVOX_RESULT_INVALID_UUID_ERROR,
+            InvalidUtf8Input => VOICEVOX_RESULT_INVALID_UTF8_INPUT_ERROR,
+            InvalidAudioQuery(_) => VOICEVOX_RESULT_INVALID_AUDIO_QUERY_ERROR,
+            InvalidAccentPhrase(_) => VOICEVOX_RESULT_INVALID_ACCENT_PHRASE_ERROR,
+            InvalidMora(_) => VOICEVOX_RESULT_INVALID_MORA_ERROR,
+            InvalidScore(_) => VOICEVOX_RESULT_INVALID_SCORE_ERROR,
+            InvalidNote(_) => VOICEVOX_RESULT_INVALID_NOTE_ERROR,
+            InvalidFrameAudioQuery(_) => VOICEVOX_RESULT_INVALID_FRAME_AUDIO_QUERY_ERROR,
+            InvalidFramePhoneme(_) => VOICEVOX_RESULT_INVALID_FRAME_PHONEME_ERROR,
+            InvalidUuid(_) => VOICEVOX_RESULT_INVALID_UUID_ERROR,
+            Cancelled => VOICEVOX_RESULT_CANCELLED,
+            IndexOutOfRange { .. } => VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR,
         }
     }
 }
@@ -112,6 +125,10 @@ pub(crate) enum CApiError {
     InvalidFramePhoneme(Either<serde_json::Error, String>),
     #[error("無効なUUIDです: {0}")]
     InvalidUuid(uuid::Error),
+    #[error("処理がキャンセルされました")]
+    Cancelled,
+    #[error("インデックスが範囲外です: 長さは{len}ですが、インデックスは{index}です")]
+    IndexOutOfRange { index: usize, len: usize },
 }
 
 impl From<InvalidWordError> for CApiError {
@@ -128,13 +145,7 @@ pub(crate) trait ValidateJson: Validate {
         res.validate()
             .map_err(|e| Either::Right(unwrap_invalid_query_error_kind(&e)))
             .map_err(Self::error)?;
-        return Ok(res);
-
-        fn unwrap_invalid_query_error_kind(err: &voicevox_core::Error) -> String {
-            err.source()
-                .expect("the error is expected to be `InvalidQuery`, which has `source`")
-                .to_string()
-        }
+        Ok(res)
     }
 
     fn from_json_without_validation(json: &CStr) -> CApiResult<Self> {
@@ -161,6 +172,25 @@ impl ValidateJson for T {
     }
 }
 
+pub(crate) fn unwrap_invalid_query_error_kind(err: &voicevox_core::Error) -> String {
+    err.source()
+        .expect("the error is expected to be `InvalidQuery`, which has `source`")
+        .to_string()
+}
+
+/// `Q`のフィールドに書き込もうとしている値を検証し、不正であれば`Q`に対応するエラーを返す。
+pub(crate) fn validate_field<Q: ValidateJson, V: Debug + Copy, T, E: Display>(
+    field: &str,
+    value: V,
+    new: impl FnOnce(V) -> Result<T, E>,
+) -> CApiResult<T> {
+    new(value).map_err(|e| {
+        Q::error(Either::Right(format!(
+            "`{field}`が不正です: {value:?}: {e}"
+        )))
+    })
+}
+
 pub(crate) fn audio_query_model_to_json(audio_query_model: &AudioQuery) -> String {
     serde_json::to_string(audio_query_model).expect("should be always valid")
 }
@@ -224,6 +254,120 @@ impl From<VoicevoxAccelerationMode> for voicevox_core::AccelerationMode {
     }
 }
 
+impl From<voicevox_core::GraphOptimizationLevel> for VoicevoxGraphOptimizationLevel {
+    fn from(level: voicevox_core::GraphOptimizationLevel) -> Self {
+        use voicevox_core::GraphOptimizationLevel::*;
+        match level {
+            Disable => Self::VOICEVOX_GRAPH_OPTIMIZATION_LEVEL_DISABLE,
+            Basic => Self::VOICEVOX_GRAPH_OPTIMIZATION_LEVEL_BASIC,
+            Extended => Self::VOICEVOX_GRAPH_OPTIMIZATION_LEVEL_EXTENDED,
+            All => Self::VOICEVOX_GRAPH_OPTIMIZATION_LEVEL_ALL,
+            __NonExhaustive => unreachable!(),
+        }
+    }
+}
+
+impl From<VoicevoxGraphOptimizationLevel> for voicevox_core::GraphOptimizationLevel {
+    fn from(level: VoicevoxGraphOptimizationLevel) -> Self {
+        use VoicevoxGraphOptimizationLevel::*;
+        match level {
+            VOICEVOX_GRAPH_OPTIMIZATION_LEVEL_DISABLE => Self::Disable,
+            VOICEVOX_GRAPH_OPTIMIZATION_LEVEL_BASIC => Self::Basic,
+            VOICEVOX_GRAPH_OPTIMIZATION_LEVEL_EXTENDED => Self::Extended,
+            VOICEVOX_GRAPH_OPTIMIZATION_LEVEL_ALL => Self::All,
+        }
+    }
+}
+
+impl From<VoicevoxInferenceOperationKind> for voicevox_core::InferenceOperationKind {
+    fn from(op: VoicevoxInferenceOperationKind) -> Self {
+        use VoicevoxInferenceOperationKind::*;
+        match op {
+            VOICEVOX_INFERENCE_OPERATION_KIND_TALK_PREDICT_DURATION => Self::TalkPredictDuration,
+            VOICEVOX_INFERENCE_OPERATION_KIND_TALK_PREDICT_INTONATION => {
+                Self::TalkPredictIntonation
+            }
+            VOICEVOX_INFERENCE_OPERATION_KIND_TALK_DECODE => Self::TalkDecode,
+            VOICEVOX_INFERENCE_OPERATION_KIND_STREAMING_TALK_PREDICT_DURATION => {
+                Self::StreamingTalkPredictDuration
+            }
+            VOICEVOX_INFERENCE_OPERATION_KIND_STREAMING_TALK_PREDICT_INTONATION => {
+                Self::StreamingTalkPredictIntonation
+            }
+            VOICEVOX_INFERENCE_OPERATION_KIND_STREAMING_TALK_GENERATE_FULL_INTERMEDIATE => {
+                Self::StreamingTalkGenerateFullIntermediate
+            }
+            VOICEVOX_INFERENCE_OPERATION_KIND_STREAMING_TALK_RENDER_AUDIO_SEGMENT => {
+                Self::StreamingTalkRenderAudioSegment
+            }
+            VOICEVOX_INFERENCE_OPERATION_KIND_SINGING_TEACHER_PREDICT_SING_CONSONANT_LENGTH => {
+                Self::SingingTeacherPredictSingConsonantLength
+            }
+            VOICEVOX_INFERENCE_OPERATION_KIND_SINGING_TEACHER_PREDICT_SING_F0 => {
+                Self::SingingTeacherPredictSingF0
+            }
+            VOICEVOX_INFERENCE_OPERATION_KIND_SINGING_TEACHER_PREDICT_SING_VOLUME => {
+                Self::SingingTeacherPredictSingVolume
+            }
+            VOICEVOX_INFERENCE_OPERATION_KIND_FRAME_DECODE_SF_DECODE => Self::FrameDecodeSfDecode,
+        }
+    }
+}
+
+impl From<VoicevoxOperationDevice> for voicevox_core::OperationDevice {
+    fn from(device: VoicevoxOperationDevice) -> Self {
+        use VoicevoxOperationDevice::*;
+        match device {
+            VOICEVOX_OPERATION_DEVICE_CPU => Self::Cpu,
+            VOICEVOX_OPERATION_DEVICE_ACCELERATOR => Self::Accelerator,
+        }
+    }
+}
+
+impl From<VoicevoxOperationPlacement>
+    for (
+        voicevox_core::InferenceOperationKind,
+        voicevox_core::OperationPlacement,
+    )
+{
+    fn from(
+        VoicevoxOperationPlacement {
+            operation,
+            device,
+            cpu_num_threads,
+        }: VoicevoxOperationPlacement,
+    ) -> Self {
+        let cpu_num_threads = (cpu_num_threads >= 0).then(|| {
+            cpu_num_threads
+                .try_into()
+                .unwrap_or_else(|_| panic!("`cpu_num_threads` is too large: {cpu_num_threads}"))
+        });
+        (
+            operation.into(),
+            voicevox_core::OperationPlacement {
+                device: device.into(),
+                cpu_num_threads,
+            },
+        )
+    }
+}
+
+impl VoicevoxInitializeOptions {
+    /// # Safety
+    ///
+    /// `operation_placements`はNULLであるか、読み込みについて有効な`operation_placements_length`要素の
+    /// 領域を指していなければならない。
+    pub(crate) unsafe fn operation_placements(&self) -> &[VoicevoxOperationPlacement] {
+        if self.operation_placements.is_null() {
+            return &[];
+        }
+        // SAFETY: The safety contract must be upheld by the caller.
+        unsafe {
+            std::slice::from_raw_parts(self.operation_placements, self.operation_placements_length)
+        }
+    }
+}
+
 impl From<VoicevoxOnExistingVoiceModelId> for voicevox_core::OnExistingVoiceModelId {
     fn from(value: VoicevoxOnExistingVoiceModelId) -> Self {
         use VoicevoxOnExistingVoiceModelId::*;
@@ -260,6 +404,9 @@ impl Default for VoicevoxInitializeOptions {
         Self {
             acceleration_mode: AccelerationMode::default().into(),
             cpu_num_threads: voicevox_core::__internal::interop::DEFAULT_CPU_NUM_THREADS,
+            graph_optimization_level: voicevox_core::GraphOptimizationLevel::default().into(),
+            operation_placements: ptr::null(),
+            operation_placements_length: 0,
         }
     }
 }
@@ -273,6 +420,15 @@ impl Default for VoicevoxSynthesisOptions {
     }
 }
 
+impl Default for VoicevoxJobOptions {
+    fn default() -> Self {
+        Self {
+            // Rust APIの`cancellable`のデフォルトと同じ
+            cancellable: false,
+        }
+    }
+}
+
 impl Default for VoicevoxTtsOptions {
     fn default() -> Self {
         Self {
@@ -346,3 +502,75 @@ impl From<voicevox_core::UserDictWordType> for VoicevoxUserDictWordType {
         }
     }
 }
+
+impl From<&AccentPhrase> for VoicevoxAccentPhraseView {
+    fn from(accent_phrase: &AccentPhrase) -> Self {
+        Self {
+            moras_length: accent_phrase.moras.len(),
+            accent: accent_phrase.accent.get(),
+            has_pause_mora: accent_phrase.pause_mora.is_some(),
+            is_interrogative: accent_phrase.is_interrogative,
+        }
+    }
+}
+
+impl From<&Mora> for VoicevoxMoraView {
+    fn from(mora: &Mora) -> Self {
+        Self {
+            has_consonant: mora.consonant.is_some(),
+            consonant_length: mora.consonant_length.map_or(0., PositiveFinite::get),
+            vowel_length: mora.vowel_length.get(),
+            pitch: mora.pitch.get(),
+        }
+    }
+}
+
+impl From<&Note> for VoicevoxNoteView {
+    fn from(note: &Note) -> Self {
+        Self {
+            has_key: note.key.is_some(),
+            key: note.key.map_or(0, Key::get),
+            frame_length: note.frame_length.into(),
+            breath: note.breath,
+        }
+    }
+}
+
+impl From<&FramePhoneme> for VoicevoxFramePhonemeView {
+    fn from(phoneme: &FramePhoneme) -> Self {
+        Self {
+            frame_length: phoneme.frame_length.into(),
+            has_note_id: phoneme.note_id.is_some(),
+        }
+    }
+}
+
+impl VoicevoxMoraView {
+    /// `mora`の数値を書き換える。子音の有無は変えず、`has_consonant`は無視する。
+    ///
+    /// いずれかの値が不正であれば、`mora`は変更しない。
+    pub(crate) fn write_to(&self, mora: &mut Mora) -> CApiResult<()> {
+        let consonant_length = mora
+            .consonant
+            .is_some()
+            .then(|| {
+                validate_field::<Mora, _, _, _>(
+                    "consonant_length",
+                    self.consonant_length,
+                    PositiveFinite::<f32>::new,
+                )
+            })
+            .transpose()?;
+        let vowel_length = validate_field::<Mora, _, _, _>(
+            "vowel_length",
+            self.vowel_length,
+            PositiveFinite::<f32>::new,
+        )?;
+        let pitch = validate_field::<Mora, _, _, _>("pitch", self.pitch, NonNaNFinite::<f32>::new)?;
+
+        mora.consonant_length = consonant_length;
+        mora.vowel_length = vowel_length;
+        mora.pitch = pitch;
+        Ok(())
+    }
+}
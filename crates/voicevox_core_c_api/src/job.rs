@@ -0,0 +1,207 @@
+//! ::voicevox_synthesizer_tts_async などで開始される、非同期の処理。
+
+use std::{
+    collections::HashMap,
+    ffi::c_void,
+    num::NonZero,
+    ptr::{self, NonNull},
+    sync::{
+        Arc, Condvar, LazyLock, Mutex, MutexGuard,
+        atomic::{AtomicU64, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+use futures_util::future::{self, AbortHandle};
+
+use crate::{
+    VoicevoxJob, VoicevoxSynthesizer, VoicevoxWavCallback,
+    helpers::{CApiError, CApiResult, into_result_code_with_error},
+    object::{CApiObject, CApiObjectPtrExt as _},
+    result_code::VoicevoxResultCode,
+    slice_owner::U8_SLICE_OWNER,
+};
+
+pub(crate) struct CApiJob {
+    abort_handle: AbortHandle,
+}
+
+impl CApiJob {
+    pub(crate) fn cancel(&self) {
+        self.abort_handle.abort();
+    }
+}
+
+impl VoicevoxJob {
+    /// WAVデータを生成する処理を[ワーカースレッド]で開始し、終わったら`callback`を呼ぶ。
+    ///
+    /// 処理は`synthesizer`に紐付けられ、[`cancel_and_wait_for_jobs`]の対象となる。
+    ///
+    /// # Safety
+    ///
+    /// `callback`と`user_data`は、別スレッドから`callback(…, user_data)`として呼んでも問題が無いもの
+    /// でなければならない。
+    ///
+    /// [ワーカースレッド]: WORKERS
+    pub(crate) unsafe fn spawn_wav(
+        synthesizer: *const VoicevoxSynthesizer,
+        task: impl Future<Output = CApiResult<Vec<u8>>> + Send + 'static,
+        callback: VoicevoxWavCallback,
+        user_data: *mut c_void,
+    ) -> NonNull<Self> {
+        let (task, abort_handle) = future::abortable(task);
+        let user_data = UserData(user_data);
+        let running = RunningJob::register(synthesizer.id(), abort_handle.clone());
+
+        WORKERS.spawn(move || {
+            let result = pollster::block_on(task).unwrap_or(Err(CApiError::Cancelled));
+
+            // `task`は既にdropされている。ユーザー定義のテキスト解析器はもう呼ばれないため、
+            // `voicevox_synthesizer_delete`を先に進めてよい
+            drop(running);
+
+            let (result_code, wav_length, wav) = match result {
+                Ok(wav) => {
+                    let (mut wav_ptr, mut wav_length) = (NonNull::dangling(), 0);
+                    // SAFETY: `wav_ptr` and `wav_length` are valid for writes.
+                    unsafe {
+                        U8_SLICE_OWNER.own_and_lend(
+                            wav,
+                            NonNull::from(&mut wav_ptr),
+                            NonNull::from(&mut wav_length),
+                        );
+                    }
+                    (
+                        VoicevoxResultCode::VOICEVOX_RESULT_OK,
+                        wav_length,
+                        wav_ptr.as_ptr(),
+                    )
+                }
+                Err(err) => (into_result_code_with_error(Err(err)), 0, ptr::null_mut()),
+            };
+
+            // SAFETY: The safety contract must be upheld by the caller.
+            unsafe { callback(result_code, wav_length, wav, user_data.into_inner()) };
+        });
+
+        <Self as CApiObject>::new(CApiJob { abort_handle })
+    }
+}
+
+/// `synthesizer`から開始された処理を全てキャンセルし、それらが終わるのを待つ。
+///
+/// 待つのは処理そのものであり、`callback`の呼び出しは待たない。
+pub(crate) fn cancel_and_wait_for_jobs(synthesizer: *const VoicevoxSynthesizer) {
+    if synthesizer.is_null() {
+        return;
+    }
+    let synthesizer = synthesizer.id();
+    let running_jobs = lock_running_jobs();
+    for abort_handle in running_jobs
+        .get(&synthesizer)
+        .into_iter()
+        .flat_map(HashMap::values)
+    {
+        abort_handle.abort();
+    }
+    drop(
+        JOB_FINISHED
+            .wait_while(running_jobs, |running_jobs| {
+                running_jobs.contains_key(&synthesizer)
+            })
+            .unwrap_or_else(|e| panic!("{e}")),
+    );
+}
+
+/// 終わっていない処理。シンセサイザの[ID]ごとに、処理のIDと[`AbortHandle`]を持つ。
+///
+/// [ID]: crate::object::CApiObjectPtrExt::id
+static RUNNING_JOBS: LazyLock<Mutex<HashMap<usize, HashMap<u64, AbortHandle>>>> =
+    LazyLock::new(Default::default);
+
+/// [`RUNNING_JOBS`]から処理が取り除かれたことを通知する。
+static JOB_FINISHED: Condvar = Condvar::new();
+
+fn lock_running_jobs() -> MutexGuard<'static, HashMap<usize, HashMap<u64, AbortHandle>>> {
+    RUNNING_JOBS.lock().unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// [`RUNNING_JOBS`]への登録。dropすると登録を解除する。
+struct RunningJob {
+    synthesizer: usize,
+    id: u64,
+}
+
+impl RunningJob {
+    fn register(synthesizer: usize, abort_handle: AbortHandle) -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        lock_running_jobs()
+            .entry(synthesizer)
+            .or_default()
+            .insert(id, abort_handle);
+        Self { synthesizer, id }
+    }
+}
+
+impl Drop for RunningJob {
+    fn drop(&mut self) {
+        let mut running_jobs = lock_running_jobs();
+        if let Some(jobs) = running_jobs.get_mut(&self.synthesizer) {
+            jobs.remove(&self.id);
+            if jobs.is_empty() {
+                running_jobs.remove(&self.synthesizer);
+            }
+        }
+        JOB_FINISHED.notify_all();
+    }
+}
+
+/// 処理を実行する、固定数のスレッド。
+///
+/// スレッド数は[`thread::available_parallelism`]であり、それを超える数の処理は先に開始されたものが終わる
+/// まで待たされる。
+static WORKERS: LazyLock<Workers> = LazyLock::new(|| {
+    let (tx, rx) = mpsc::channel::<Box<dyn FnOnce() + Send>>();
+    let rx = Arc::new(Mutex::new(rx));
+    let num_workers = thread::available_parallelism().map_or(1, NonZero::get);
+    for i in 0..num_workers {
+        let rx = rx.clone();
+        thread::Builder::new()
+            .name(format!("voicevox-job-{i}"))
+            .spawn(move || {
+                loop {
+                    // 処理の実行中は`rx`をロックしない
+                    let job = rx.lock().unwrap_or_else(|e| panic!("{e}")).recv();
+                    let Ok(job) = job else { break };
+                    job();
+                }
+            })
+            .expect("should be able to spawn a thread");
+    }
+    Workers(tx)
+});
+
+struct Workers(mpsc::Sender<Box<dyn FnOnce() + Send>>);
+
+impl Workers {
+    fn spawn(&self, job: impl FnOnce() + Send + 'static) {
+        self.0
+            .send(Box::new(job))
+            .expect("the workers should live until the end of the process");
+    }
+}
+
+struct UserData(*mut c_void);
+
+// SAFETY: `VoicevoxJob::spawn_wav`の安全性要件として、呼び出し元に要求している
+unsafe impl Send for UserData {}
+
+impl UserData {
+    // クロージャが`self.0`のみをキャプチャしてしまわないように、メソッドとして取り出す
+    fn into_inner(self) -> *mut c_void {
+        self.0
+    }
+}
@@ -0,0 +1,73 @@
+//! ::voicevox_last_error_message と ::voicevox_last_error_json で取得できる、スレッドごとの最後のエラー。
+
+use std::{
+    cell::RefCell,
+    error::Error as _,
+    ffi::{CString, c_char},
+    iter, ptr,
+};
+
+use serde_json::json;
+use voicevox_core::__internal::interop::error_details;
+
+use crate::{helpers::CApiError, result_code::VoicevoxResultCode};
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<LastError>> = const { RefCell::new(None) };
+}
+
+struct LastError {
+    message: CString,
+    json: CString,
+}
+
+/// このスレッドの最後のエラーとして記録する。
+pub(crate) fn set(err: &CApiError, result_code: VoicevoxResultCode) {
+    let sources = iter::successors(err.source(), |&e| e.source())
+        .map(|e| e.to_string())
+        .collect::<Vec<_>>();
+
+    let message = iter::chain(
+        [err.to_string()],
+        sources.iter().map(|e| format!("Caused by: {e}")),
+    )
+    .collect::<Vec<_>>()
+    .join("\n");
+
+    let details = match err {
+        CApiError::RustApi(err) => {
+            serde_json::to_value(error_details(err)).expect("should not fail")
+        }
+        _ => json!({}),
+    };
+
+    let json = json!({
+        "result_code": result_code as i32,
+        "message": err.to_string(),
+        "sources": sources,
+        "details": details,
+    })
+    .to_string();
+
+    let last_error = LastError {
+        message: CString::new(message.replace('\0', "\\0")).expect("should not contain '\\0'"),
+        json: CString::new(json).expect("`serde_json` should escape '\\0'"),
+    };
+    LAST_ERROR.set(Some(last_error));
+}
+
+pub(crate) fn message() -> *const c_char {
+    LAST_ERROR.with_borrow(|last_error| {
+        last_error
+            .as_ref()
+            .map_or(ptr::null(), |LastError { message, .. }| message.as_ptr())
+    })
+}
+
+pub(crate) fn json() -> *const c_char {
+    LAST_ERROR.with_borrow(|last_error| {
+        last_error
+            .as_ref()
+            .map_or(ptr::null(), |LastError { json, .. }| json.as_ptr())
+    })
+}
@@ -10,26 +10,35 @@ mod c_impls;
 mod compatible_engine;
 mod drop_check;
 mod helpers;
+mod job;
+mod last_error;
+mod log_callback;
 mod object;
 mod result_code;
 mod slice_owner;
+mod text_analyzer;
 
 use self::drop_check::C_STRING_DROP_CHECKER;
 use self::helpers::{
     CApiError, ToCJson as _, UuidBytesExt as _, ValidateJson, accent_phrases_to_json,
     audio_query_model_to_json, ensure_utf8, into_result_code_with_error,
+    unwrap_invalid_query_error_kind, validate_field,
 };
 use self::object::{CApiObject as _, CApiObjectPtrExt as _};
 use self::result_code::VoicevoxResultCode;
 use self::slice_owner::U8_SLICE_OWNER;
+use self::text_analyzer::CApiTextAnalyzer;
 use anstream::{AutoStream, stream::RawStream};
-use c_impls::{VoicevoxSynthesizerPtrExt as _, VoicevoxVoiceModelFilePtrExt as _};
+use c_impls::{
+    VoicevoxAudioQueryPtrExt as _, VoicevoxFrameAudioQueryPtrExt as _,
+    VoicevoxSynthesizerPtrExt as _, VoicevoxVoiceModelFilePtrExt as _,
+};
 use chrono::SecondsFormat;
 use colorchoice::ColorChoice;
 use educe::Educe;
 use ref_cast::RefCastCustom;
 use std::env;
-use std::ffi::{CStr, CString};
+use std::ffi::{CStr, CString, c_void};
 use std::fmt;
 use std::io;
 use std::mem::MaybeUninit;
@@ -37,13 +46,17 @@ use std::os::raw::c_char;
 use std::ptr::NonNull;
 use std::sync::Once;
 use tracing_subscriber::EnvFilter;
+use tracing_subscriber::filter::{FilterExt as _, filter_fn};
 use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::prelude::*;
+use typed_floats::{NonNaNFinite, PositiveFinite};
 use uuid::Uuid;
 use voicevox_core::__internal::interop::{
-    BlockingTextAnalyzerExt as _, DEFAULT_WORD_TYPE, ToJsonValue as _,
+    BlockingTextAnalyzerExt as _, DEFAULT_WORD_TYPE, ToJsonValue as _, ToNonblocking as _,
 };
 use voicevox_core::{
-    AccentPhrase, AudioQuery, FrameAudioQuery, FramePhoneme, Mora, Note, Score, StyleId,
+    AccentPhrase, AudioQuery, FrameAudioQuery, FramePhoneme, Mora, Note, SamplingRate, Score,
+    StyleId,
 };
 
 fn init_logger_once() {
@@ -67,15 +80,22 @@ fn init_logger_once() {
                 && anstyle_query::windows::enable_ansi_colors().unwrap_or(true)
         };
 
-        tracing_subscriber::fmt()
-            .with_env_filter(if env::var_os(EnvFilter::DEFAULT_ENV).is_some() {
-                EnvFilter::from_default_env()
-            } else {
-                "error,voicevox_core=info,voicevox_core_c_api=info,ort=warn".into()
-            })
-            .with_timer(local_time as fn(&mut Writer<'_>) -> _)
-            .with_ansi(ansi)
-            .with_writer(out)
+        let env_filter = if env::var_os(EnvFilter::DEFAULT_ENV).is_some() {
+            EnvFilter::from_default_env()
+        } else {
+            "error,voicevox_core=info,voicevox_core_c_api=info,ort=warn".into()
+        };
+
+        tracing_subscriber::registry()
+            .with(
+                tracing_subscriber::fmt::layer()
+                    .with_timer(local_time as fn(&mut Writer<'_>) -> _)
+                    .with_ansi(ansi)
+                    .with_writer(out)
+                    // コールバックが登録されている間は、標準エラー出力には出さない
+                    .with_filter(env_filter.and(filter_fn(|_| !log_callback::is_set()))),
+            )
+            .with(log_callback::layer())
             .init();
     });
 
@@ -479,6 +499,93 @@ pub enum VoicevoxAccelerationMode {
     VOICEVOX_ACCELERATION_MODE_GPU = 2,
 }
 
+/// 推論の実行グラフに対して行う最適化のレベル。
+///
+/// 高いレベルほどセッションの作成（音声モデルの読み込み）に時間がかかる代わりに、推論が速くなる可能性がある。
+///
+/// \orig-impl{VoicevoxGraphOptimizationLevel}
+#[repr(i32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[allow(
+    non_camel_case_types,
+    reason = "実際に公開するC APIとの差異をできるだけ少なくするため"
+)]
+pub enum VoicevoxGraphOptimizationLevel {
+    /// 最適化を行わない
+    VOICEVOX_GRAPH_OPTIMIZATION_LEVEL_DISABLE = 0,
+    /// 定数畳み込みや冗長なノードの除去といった、基本的な最適化のみを行う
+    VOICEVOX_GRAPH_OPTIMIZATION_LEVEL_BASIC = 1,
+    /// ノードの融合などの、より進んだ最適化も行う
+    VOICEVOX_GRAPH_OPTIMIZATION_LEVEL_EXTENDED = 2,
+    /// 実行環境に合わせたメモリレイアウトの変更を含む、すべての最適化を行う
+    VOICEVOX_GRAPH_OPTIMIZATION_LEVEL_ALL = 3,
+}
+
+/// 推論の操作。 ::VoicevoxOperationPlacement の指定に用いる。
+///
+/// \orig-impl{VoicevoxInferenceOperationKind}
+#[repr(i32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[allow(
+    non_camel_case_types,
+    reason = "実際に公開するC APIとの差異をできるだけ少なくするため"
+)]
+pub enum VoicevoxInferenceOperationKind {
+    /// トーク: 音素長の推論
+    VOICEVOX_INFERENCE_OPERATION_KIND_TALK_PREDICT_DURATION = 0,
+    /// トーク: 音高の推論
+    VOICEVOX_INFERENCE_OPERATION_KIND_TALK_PREDICT_INTONATION = 1,
+    /// トーク: 波形の生成
+    VOICEVOX_INFERENCE_OPERATION_KIND_TALK_DECODE = 2,
+    /// ストリーミングトーク: 音素長の推論
+    VOICEVOX_INFERENCE_OPERATION_KIND_STREAMING_TALK_PREDICT_DURATION = 3,
+    /// ストリーミングトーク: 音高の推論
+    VOICEVOX_INFERENCE_OPERATION_KIND_STREAMING_TALK_PREDICT_INTONATION = 4,
+    /// ストリーミングトーク: 中間表現の生成
+    VOICEVOX_INFERENCE_OPERATION_KIND_STREAMING_TALK_GENERATE_FULL_INTERMEDIATE = 5,
+    /// ストリーミングトーク: 中間表現からの波形の生成
+    VOICEVOX_INFERENCE_OPERATION_KIND_STREAMING_TALK_RENDER_AUDIO_SEGMENT = 6,
+    /// ソング: 子音長の推論
+    VOICEVOX_INFERENCE_OPERATION_KIND_SINGING_TEACHER_PREDICT_SING_CONSONANT_LENGTH = 7,
+    /// ソング: 音高の推論
+    VOICEVOX_INFERENCE_OPERATION_KIND_SINGING_TEACHER_PREDICT_SING_F0 = 8,
+    /// ソング: 音量の推論
+    VOICEVOX_INFERENCE_OPERATION_KIND_SINGING_TEACHER_PREDICT_SING_VOLUME = 9,
+    /// ソング: 波形の生成
+    VOICEVOX_INFERENCE_OPERATION_KIND_FRAME_DECODE_SF_DECODE = 10,
+}
+
+/// 推論の操作を行うデバイス。
+///
+/// \orig-impl{VoicevoxOperationDevice}
+#[repr(i32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[allow(
+    non_camel_case_types,
+    reason = "実際に公開するC APIとの差異をできるだけ少なくするため"
+)]
+pub enum VoicevoxOperationDevice {
+    /// CPU
+    VOICEVOX_OPERATION_DEVICE_CPU = 0,
+    /// ::VoicevoxAccelerationMode によって選ばれたデバイス。 ::VOICEVOX_ACCELERATION_MODE_CPU のときや、利用可能なものが無かったときはCPUとなる
+    VOICEVOX_OPERATION_DEVICE_ACCELERATOR = 1,
+}
+
+/// 推論の操作の配置先。
+///
+/// \orig-impl{VoicevoxOperationPlacement}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct VoicevoxOperationPlacement {
+    /// 配置する推論の操作
+    operation: VoicevoxInferenceOperationKind,
+    /// 推論を行うデバイス
+    device: VoicevoxOperationDevice,
+    /// CPU利用数
+    /// 負の値を指定するとシンセサイザ全体の設定( ::VoicevoxInitializeOptions の`cpu_num_threads`)に従う。0を指定すると環境に合わせたCPUが利用される。65535を超える値を指定するとパニックする
+    cpu_num_threads: i32,
+}
+
 /// ::voicevox_synthesizer_new のオプション。
 ///
 /// \no-orig-impl{VoicevoxInitializeOptions}
@@ -489,6 +596,13 @@ pub struct VoicevoxInitializeOptions {
     /// CPU利用数を指定
     /// 0を指定すると環境に合わせたCPUが利用される
     cpu_num_threads: u16,
+    /// 推論の実行グラフの最適化レベル
+    graph_optimization_level: VoicevoxGraphOptimizationLevel,
+    /// 推論の操作ごとの配置先の配列。NULLのときは`operation_placements_length`を0として扱う
+    /// 指定されなかった操作は、波形の生成をアクセラレータに、それ以外をCPUに置く
+    operation_placements: *const VoicevoxOperationPlacement,
+    /// `operation_placements`の要素数
+    operation_placements_length: usize,
 }
 
 // SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
@@ -521,6 +635,101 @@ pub extern "C" fn voicevox_get_version() -> *const c_char {
     };
 }
 
+/// ログのレベル。
+///
+/// \no-orig-impl{VoicevoxLogLevel}
+#[repr(i32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[allow(
+    non_camel_case_types,
+    reason = "実際に公開するC APIとの差異をできるだけ少なくするため"
+)]
+pub enum VoicevoxLogLevel {
+    /// 何も出力しない
+    VOICEVOX_LOG_LEVEL_OFF = 0,
+    /// エラー
+    VOICEVOX_LOG_LEVEL_ERROR = 1,
+    /// 警告
+    VOICEVOX_LOG_LEVEL_WARN = 2,
+    /// 情報
+    VOICEVOX_LOG_LEVEL_INFO = 3,
+    /// デバッグ情報
+    VOICEVOX_LOG_LEVEL_DEBUG = 4,
+    /// 詳細なトレース
+    VOICEVOX_LOG_LEVEL_TRACE = 5,
+}
+
+/// ::voicevox_set_log_callback で登録するコールバック。
+///
+/// 引数は順に、ログのレベル、ログの出力元のモジュールパス、ログのメッセージ、 ::voicevox_set_log_callback に渡した`user_data`。文字列はいずれもヌル終端かつUTF-8であり、コールバックの呼び出しの間のみ有効である。
+///
+/// \no-orig-impl{VoicevoxLogCallback}
+pub type VoicevoxLogCallback = Option<
+    unsafe extern "C" fn(
+        level: VoicevoxLogLevel,
+        target: *const c_char,
+        message: *const c_char,
+        user_data: *mut c_void,
+    ),
+>;
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ログを受け取るコールバックを登録する。
+///
+/// コールバックが登録されている間、ログは標準エラー出力には出されず、`level`以下のレベルのログがコールバックに渡される。`callback`にNULLを渡すとコールバックの登録が解除され、標準エラー出力への出力に戻る。
+///
+/// 既にコールバックが登録されている場合、それを置き換える。この関数は、実行中のコールバックの呼び出しが終わるのを待ってから帰る。
+///
+/// @param [in] level コールバックに渡すログのレベルの上限
+/// @param [in] callback コールバック。NULLも可
+/// @param [in] user_data コールバックに渡されるポインタ
+///
+/// \example{
+/// ```c
+/// static void on_log(VoicevoxLogLevel level, const char *target,
+///                    const char *message, void *user_data) {
+///   fprintf(stderr, "[%d] %s: %s\n", level, target, message);
+/// }
+///
+/// voicevox_set_log_callback(VOICEVOX_LOG_LEVEL_INFO, on_log, NULL);
+/// ```
+/// }
+///
+/// \safety{
+/// - `callback`は、任意のスレッドから同時に呼び出されても問題が無いものでなければならない。
+/// - `user_data`は、コールバックの登録が解除されるか置き換えられるまで、`callback`にとって有効でなければならない。
+/// - `callback`の中で、この関数を呼んではならない。
+/// }
+///
+/// \no-orig-impl{voicevox_set_log_callback}
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn voicevox_set_log_callback(
+    level: VoicevoxLogLevel,
+    callback: VoicevoxLogCallback,
+    user_data: *mut c_void,
+) {
+    init_logger_once();
+    log_callback::set(
+        level,
+        callback.map(|callback| log_callback::LogCallback {
+            callback,
+            user_data,
+        }),
+    );
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::voicevox_set_log_callback で登録したコールバックに渡すログのレベルの上限を変更する。
+///
+/// @param [in] level コールバックに渡すログのレベルの上限
+///
+/// \no-orig-impl{voicevox_set_log_level}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_set_log_level(level: VoicevoxLogLevel) {
+    init_logger_once();
+    log_callback::set_level(level);
+}
+
 // SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
 /// AccentPhraseの配列からAudioQueryを作る。
 ///
@@ -686,6 +895,8 @@ pub unsafe extern "C" fn voicevox_score_validate(score_json: *const c_char) -> V
 /// - [Rust APIの`Note`型]としてデシリアライズ不可、もしくはJSONとして不正。
 /// - `key`が`null`かつ`lyric`が`""`以外。
 /// - `key`が非`null`かつ`lyric`が`""`。
+/// - `breath`が`true`かつ音符である。
+/// - `lyric`が複数のモーラから成り、かつ`frame_length`がそのモーラ数より小さい。
 ///
 /// [Rust APIの`Note`型]: ../rust_api/voicevox_core/struct.Note.html
 ///
@@ -868,6 +1079,45 @@ pub unsafe extern "C" fn voicevox_voice_model_file_open(
     })())
 }
 
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// メモリ上のVVMを開く。
+///
+/// `data`の内容はコピーされるため、この関数から返った後は解放してよい。
+///
+/// @param [in] data VVMのバイト列
+/// @param [in] data_length `data`のバイト長
+/// @param [out] out_model 構築先
+///
+/// @returns 結果コード
+///
+/// \safety{
+/// - `data`は<a href="#voicevox-core-safety">読み込みについて有効</a>な`data_length`バイトの領域を指していなければならない。ただし`data_length`が0のときに限りNULLでもよい。`data_length`が非0なのに`data`がNULLであるときはパニックする。
+/// - `out_model`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+/// }
+///
+/// \orig-impl{voicevox_voice_model_file_open_from_memory}
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn voicevox_voice_model_file_open_from_memory(
+    data: *const u8,
+    data_length: usize,
+    out_model: NonNull<NonNull<VoicevoxVoiceModelFile>>,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        let data = if data_length == 0 {
+            &[][..]
+        } else {
+            assert!(!data.is_null(), "`data_length`が非0なのに`data`がNULLです");
+            // SAFETY: The safety contract must be upheld by the caller.
+            unsafe { std::slice::from_raw_parts(data, data_length) }
+        };
+        let model = VoicevoxVoiceModelFile::from_bytes(data.to_owned())?;
+        // SAFETY: The safety contract must be upheld by the caller.
+        unsafe { out_model.write_unaligned(model) };
+        Ok(())
+    })())
+}
+
 // SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
 /// ::VoicevoxVoiceModelFile からIDを取得する。
 ///
@@ -946,6 +1196,7 @@ pub struct VoicevoxSynthesizer {
 ///
 /// \safety{
 /// - `onnxruntime`は ::voicevox_onnxruntime_load_once または ::voicevox_onnxruntime_init_once で得たものでなければならない。
+/// - `options.operation_placements`はNULLであるか、<a href="#voicevox-core-safety">読み込みについて有効</a>な`options.operation_placements_length`要素の領域を指していなければならない。
 /// - `out_synthesizer`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
 /// }
 ///
@@ -959,7 +1210,98 @@ pub unsafe extern "C" fn voicevox_synthesizer_new(
 ) -> VoicevoxResultCode {
     init_logger_once();
     into_result_code_with_error((|| {
-        let synthesizer = VoicevoxSynthesizer::new(onnxruntime, open_jtalk, options)?;
+        let text_analyzer = CApiTextAnalyzer::OpenJtalk(open_jtalk.body().clone());
+        // SAFETY: The safety contract must be upheld by the caller.
+        let synthesizer = unsafe { VoicevoxSynthesizer::new(onnxruntime, text_analyzer, options) }?;
+        // SAFETY: The safety contract must be upheld by the caller.
+        unsafe { out_synthesizer.write_unaligned(synthesizer) };
+        Ok(())
+    })())
+}
+
+/// ユーザー定義のテキスト解析器。
+///
+/// ::voicevox_synthesizer_new_with_text_analyzer で ::OpenJtalkRc の代わりに用いることができる。
+///
+/// \no-orig-impl{VoicevoxTextAnalyzer}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct VoicevoxTextAnalyzer {
+    /// 日本語のテキストを解析し、AccentPhraseの配列のJSON文字列を返す。失敗した場合はNULLを返す。
+    ///
+    /// 第一引数は解析対象のテキストで、ヌル終端かつUTF-8である。第二引数は`user_data`である。
+    ///
+    /// NULLであってはならない。
+    analyze:
+        Option<unsafe extern "C" fn(text: *const c_char, user_data: *mut c_void) -> *mut c_char>,
+    /// `analyze`が返したJSON文字列を解放する。
+    ///
+    /// 第二引数は`user_data`である。
+    ///
+    /// NULLであってはならない。
+    free_json: Option<unsafe extern "C" fn(json: *mut c_char, user_data: *mut c_void)>,
+    /// `analyze`と`free_json`に渡されるポインタ。
+    user_data: *mut c_void,
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ユーザー定義のテキスト解析器を用いて、 ::VoicevoxSynthesizer を<b>構築</b>(_construct_)する。
+///
+/// ::voicevox_synthesizer_new と同じだが、 ::OpenJtalkRc の代わりに ::VoicevoxTextAnalyzer を用いる。
+///
+/// `text_analyzer.analyze`が返したJSONは、AccentPhraseの配列としてバリデートされる。不正なJSONが返されたとき、もしくはNULLが返されたとき、テキスト解析を伴う関数は ::VOICEVOX_RESULT_ANALYZE_TEXT_ERROR を返す。
+///
+/// `text_analyzer.analyze`もしくは`text_analyzer.free_json`がNULLの場合、プロセスを異常終了する。
+///
+/// @param [in] onnxruntime
+/// @param [in] text_analyzer テキスト解析器
+/// @param [in] options オプション
+/// @param [out] out_synthesizer 構築先
+///
+/// @returns 結果コード
+///
+/// \example{
+/// ```c
+/// static char *analyze(const char *text, void *user_data) {
+///   return my_analyzer_analyze((MyAnalyzer *)user_data, text);
+/// }
+///
+/// static void free_json(char *json, void *user_data) { free(json); }
+///
+/// VoicevoxSynthesizer *synthesizer;
+/// voicevox_synthesizer_new_with_text_analyzer(
+///     onnxruntime,
+///     (VoicevoxTextAnalyzer){
+///         .analyze = analyze,
+///         .free_json = free_json,
+///         .user_data = my_analyzer,
+///     },
+///     voicevox_make_default_initialize_options(), &synthesizer);
+/// ```
+/// }
+///
+/// \safety{
+/// - `onnxruntime`は ::voicevox_onnxruntime_load_once または ::voicevox_onnxruntime_init_once で得たものでなければならない。
+/// - `text_analyzer.analyze`と`text_analyzer.free_json`は、任意のスレッドから同時に呼び出されても問題が無いものでなければならない。
+/// - `text_analyzer.analyze`は、NULLもしくはヌル終端文字列を返さなければならない。返した文字列は、`text_analyzer.free_json`に渡されるまで有効でなければならない。
+/// - `text_analyzer.user_data`は、構築した ::VoicevoxSynthesizer が破棄されるまで有効でなければならない。
+/// - `options.operation_placements`はNULLであるか、<a href="#voicevox-core-safety">読み込みについて有効</a>な`options.operation_placements_length`要素の領域を指していなければならない。
+/// - `out_synthesizer`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+/// }
+///
+/// \no-orig-impl{voicevox_synthesizer_new_with_text_analyzer}
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn voicevox_synthesizer_new_with_text_analyzer(
+    onnxruntime: &'static VoicevoxOnnxruntime,
+    text_analyzer: VoicevoxTextAnalyzer,
+    options: VoicevoxInitializeOptions,
+    out_synthesizer: NonNull<NonNull<VoicevoxSynthesizer>>,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        let text_analyzer = CApiTextAnalyzer::Foreign(text_analyzer.into());
+        // SAFETY: The safety contract must be upheld by the caller.
+        let synthesizer = unsafe { VoicevoxSynthesizer::new(onnxruntime, text_analyzer, options) }?;
         // SAFETY: The safety contract must be upheld by the caller.
         unsafe { out_synthesizer.write_unaligned(synthesizer) };
         Ok(())
@@ -971,6 +1313,8 @@ pub unsafe extern "C" fn voicevox_synthesizer_new(
 ///
 /// 破棄対象への他スレッドでのアクセスが存在する場合、それらがすべて終わるのを待ってから破棄する。
 ///
+/// 破棄対象から ::voicevox_synthesizer_tts_async などで開始した処理が終わっていない場合、それらをキャンセルし、終わるのを待ってから破棄する。そのためこの関数から帰った後は、 ::voicevox_synthesizer_new_with_text_analyzer で渡したテキスト解析器が呼ばれることはない。ただしキャンセルされた処理の`callback`の呼び出しは待たない。
+///
 /// この関数の呼び出し後に破棄し終えた対象にアクセスすると、プロセスを異常終了する。
 ///
 /// @param [in] synthesizer 破棄対象。nullable
@@ -979,6 +1323,7 @@ pub unsafe extern "C" fn voicevox_synthesizer_new(
 #[unsafe(no_mangle)]
 pub extern "C" fn voicevox_synthesizer_delete(synthesizer: *mut VoicevoxSynthesizer) {
     init_logger_once();
+    job::cancel_and_wait_for_jobs(synthesizer);
     synthesizer.drop_body();
 }
 
@@ -1968,86 +2313,1719 @@ pub unsafe extern "C" fn voicevox_synthesizer_frame_synthesis(
     })())
 }
 
+/// AudioQuery。
+///
+/// AudioQueryのJSON文字列の代わりに用いることができ、JSONを介さずにフィールドを読み書きできる。
+///
+/// <b>構築</b>(_construction_)は ::voicevox_audio_query_new_from_json もしくは ::voicevox_synthesizer_create_audio_query_handle で行い、<b>破棄</b>(_destruction_)は ::voicevox_audio_query_delete で行う。
+///
+/// \orig-impl{VoicevoxAudioQuery}
+#[derive(Debug, Educe)]
+#[educe(Default(expression = "Self { _padding: MaybeUninit::uninit() }"))]
+pub struct VoicevoxAudioQuery {
+    _padding: MaybeUninit<[u8; 1]>,
+}
+
+/// ::VoicevoxAudioQuery 内のAccentPhraseの内容。
+///
+/// \no-orig-impl{VoicevoxAccentPhraseView}
+#[repr(C)]
+pub struct VoicevoxAccentPhraseView {
+    /// モーラの数。`pause_mora`は含まない
+    moras_length: usize,
+    /// アクセント箇所
+    accent: usize,
+    /// 後ろに無音を付けるかどうか
+    has_pause_mora: bool,
+    /// 疑問系かどうか
+    is_interrogative: bool,
+}
+
+/// ::VoicevoxAudioQuery 内のモーラの、数値で表される内容。
+///
+/// 文字列で表されるフィールドは ::voicevox_audio_query_create_json で得る。
+///
+/// \no-orig-impl{VoicevoxMoraView}
+#[repr(C)]
+pub struct VoicevoxMoraView {
+    /// 子音があるかどうか
+    has_consonant: bool,
+    /// 子音の音長。子音が無いときは`0.0`
+    consonant_length: f32,
+    /// 母音の音長
+    vowel_length: f32,
+    /// 音高
+    pitch: f32,
+}
+
 // SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
-/// JSON文字列を解放する。
+/// AudioQueryのJSON文字列から ::VoicevoxAudioQuery を<b>構築</b>(_construct_)する。
 ///
-/// @param [in] json 解放するJSON文字列。nullable
+/// @param [in] audio_query_json AudioQueryのJSON文字列
+/// @param [out] out_audio_query 構築先
+///
+/// @returns 結果コード
 ///
 /// \safety{
-/// - `json`がヌルポインタでないならば、以下のAPIで得られたポインタでなくてはいけない。
-///     - ::voicevox_audio_query_create_from_accent_phrases
-///     - ::voicevox_onnxruntime_create_supported_devices_json
-///     - ::voicevox_voice_model_file_create_metas_json
-///     - ::voicevox_open_jtalk_rc_analyze
-///     - ::voicevox_synthesizer_create_metas_json
-///     - ::voicevox_synthesizer_create_audio_query
-///     - ::voicevox_synthesizer_create_audio_query_from_kana
-///     - ::voicevox_synthesizer_create_accent_phrases
-///     - ::voicevox_synthesizer_create_accent_phrases_from_kana
-///     - ::voicevox_synthesizer_replace_mora_data
-///     - ::voicevox_synthesizer_replace_phoneme_length
-///     - ::voicevox_synthesizer_replace_mora_pitch
-///     - ::voicevox_synthesizer_create_sing_frame_audio_query
-///     - ::voicevox_synthesizer_create_sing_frame_f0
-///     - ::voicevox_synthesizer_create_sing_frame_volume
-///     - ::voicevox_user_dict_to_json
-/// - 文字列の長さは生成時より変更されていてはならない。
-/// - `json`がヌルポインタでないならば、<a href="#voicevox-core-safety">読み込みと書き込みについて有効</a>でなければならない。
-/// - `json`がヌルポインタでないならば、以後<b>ダングリングポインタ</b>(_dangling pointer_)として扱われなくてはならない。
+/// - `audio_query_json`はヌル終端文字列を指し、かつ<a href="#voicevox-core-safety">読み込みについて有効</a>でなければならない。
+/// - `out_audio_query`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
 /// }
 ///
-/// \no-orig-impl{voicevox_json_free}
+/// \no-orig-impl{voicevox_audio_query_new_from_json}
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn voicevox_json_free(json: *mut c_char) {
+pub unsafe extern "C" fn voicevox_audio_query_new_from_json(
+    audio_query_json: *const c_char,
+    out_audio_query: NonNull<NonNull<VoicevoxAudioQuery>>,
+) -> VoicevoxResultCode {
     init_logger_once();
-    if let Some(json) = C_STRING_DROP_CHECKER.check(json) {
+    into_result_code_with_error((|| {
         // SAFETY: The safety contract must be upheld by the caller.
-        drop(unsafe { CString::from_raw(json.as_ptr()) });
-    }
+        let audio_query_json = unsafe { CStr::from_ptr(audio_query_json) };
+        let audio_query = AudioQuery::validate_json(audio_query_json)?;
+        let audio_query = VoicevoxAudioQuery::new(audio_query);
+        // SAFETY: The safety contract must be upheld by the caller.
+        unsafe { out_audio_query.write_unaligned(audio_query) };
+        Ok(())
+    })())
 }
 
 // SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
-/// WAVデータを解放する。
+/// 日本語のテキストから、 ::VoicevoxAudioQuery を生成する。
 ///
-/// @param [in] wav 解放するWAVデータ。nullable
+/// ::voicevox_synthesizer_create_audio_query と同じだが、JSON文字列の代わりに ::VoicevoxAudioQuery を出力する。
+///
+/// @param [in] synthesizer 音声シンセサイザ
+/// @param [in] text UTF-8の日本語テキスト
+/// @param [in] style_id スタイルID
+/// @param [out] out_audio_query 生成先
+///
+/// @returns 結果コード
 ///
 /// \safety{
-/// - `wav`がヌルポインタでないならば、以下のAPIで得られたポインタでなくてはいけない。
-///     - ::voicevox_synthesizer_synthesis
-///     - ::voicevox_synthesizer_tts
-///     - ::voicevox_synthesizer_tts_from_kana
-///     - ::voicevox_synthesizer_frame_synthesis
-/// - `wav`がヌルポインタでないならば、<a href="#voicevox-core-safety">読み込みと書き込みについて有効</a>でなければならない。
-/// - `wav`がヌルポインタでないならば、以後<b>ダングリングポインタ</b>(_dangling pointer_)として扱われなくてはならない。
+/// - `text`はヌル終端文字列を指し、かつ<a href="#voicevox-core-safety">読み込みについて有効</a>でなければならない。
+/// - `out_audio_query`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
 /// }
 ///
-/// \no-orig-impl{voicevox_wav_free}
+/// \no-orig-impl{voicevox_synthesizer_create_audio_query_handle}
 #[unsafe(no_mangle)]
-pub extern "C" fn voicevox_wav_free(wav: *mut u8) {
+pub unsafe extern "C" fn voicevox_synthesizer_create_audio_query_handle(
+    synthesizer: *const VoicevoxSynthesizer,
+    text: *const c_char,
+    style_id: VoicevoxStyleId,
+    out_audio_query: NonNull<NonNull<VoicevoxAudioQuery>>,
+) -> VoicevoxResultCode {
     init_logger_once();
-    U8_SLICE_OWNER.drop_for(wav);
+    into_result_code_with_error((|| {
+        // SAFETY: The safety contract must be upheld by the caller.
+        let text = unsafe { CStr::from_ptr(text) };
+        let text = ensure_utf8(text)?;
+
+        let audio_query = synthesizer
+            .body()
+            .create_audio_query(text, StyleId::new(style_id))?;
+        let audio_query = VoicevoxAudioQuery::new(audio_query);
+        // SAFETY: The safety contract must be upheld by the caller.
+        unsafe { out_audio_query.write_unaligned(audio_query) };
+        Ok(())
+    })())
 }
 
 // SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
-/// 結果コードに対応したメッセージ文字列を取得する。
+/// ::VoicevoxAudioQuery から音声合成を行う。
 ///
-/// @param [in] result_code 結果コード
+/// ::voicevox_synthesizer_synthesis と同じだが、JSON文字列の代わりに ::VoicevoxAudioQuery を用いる。
 ///
-/// @returns 結果コードに対応したメッセージ文字列
+/// 生成したWAVデータを解放するには ::voicevox_wav_free を使う。
 ///
-/// \examples{
-/// ```c
-/// const char *actual = voicevox_error_result_to_message(VOICEVOX_RESULT_OK);
-/// const char *EXPECTED = "エラーが発生しませんでした";
-/// assert(strcmp(actual, EXPECTED) == 0);
-/// ```
+/// @param [in] synthesizer 音声シンセサイザ
+/// @param [in] audio_query AudioQuery
+/// @param [in] style_id スタイルID
+/// @param [in] options オプション
+/// @param [out] output_wav_length 出力のバイト長
+/// @param [out] output_wav 出力先
 ///
-/// ```c
-/// const char *actual =
-///     voicevox_error_result_to_message(VOICEVOX_RESULT_LOAD_MODEL_ERROR);
-/// const char *EXPECTED = "modelデータ読み込みに失敗しました";
-/// assert(strcmp(actual, EXPECTED) == 0);
-/// ```
+/// @returns 結果コード
+///
+/// \safety{
+/// - `output_wav_length`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+/// - `output_wav`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+/// }
+///
+/// \no-orig-impl{voicevox_synthesizer_synthesis_from_handle}
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn voicevox_synthesizer_synthesis_from_handle(
+    synthesizer: *const VoicevoxSynthesizer,
+    audio_query: *const VoicevoxAudioQuery,
+    style_id: VoicevoxStyleId,
+    options: VoicevoxSynthesisOptions,
+    output_wav_length: NonNull<usize>,
+    output_wav: NonNull<NonNull<u8>>,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        let VoicevoxSynthesisOptions {
+            enable_interrogative_upspeak,
+        } = options;
+        let wav = audio_query.get(|audio_query| {
+            synthesizer
+                .body()
+                .synthesis(audio_query, StyleId::new(style_id))
+                .enable_interrogative_upspeak(enable_interrogative_upspeak)
+                .perform()
+        })?;
+        // SAFETY: The safety contract must be upheld by the caller.
+        unsafe { U8_SLICE_OWNER.own_and_lend(wav, output_wav, output_wav_length) };
+        Ok(())
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxAudioQuery をJSON文字列にする。
+///
+/// JSONの解放は ::voicevox_json_free で行う。
+///
+/// @param [in] audio_query AudioQuery
+///
+/// @returns AudioQueryのJSON文字列
+///
+/// \no-orig-impl{voicevox_audio_query_create_json}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_audio_query_create_json(
+    audio_query: *const VoicevoxAudioQuery,
+) -> *mut c_char {
+    init_logger_once();
+    let json = audio_query.get(audio_query_model_to_json);
+    let json = CString::new(json).expect("should not contain '\\0'");
+    C_STRING_DROP_CHECKER.whitelist(json).into_raw()
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxAudioQuery の`speedScale`(全体の話速)を取得する。
+///
+/// @param [in] audio_query AudioQuery
+///
+/// @returns 全体の話速
+///
+/// \no-orig-impl{voicevox_audio_query_get_speed_scale}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_audio_query_get_speed_scale(
+    audio_query: *const VoicevoxAudioQuery,
+) -> f32 {
+    init_logger_once();
+    audio_query.get(|audio_query| audio_query.speed_scale.get())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxAudioQuery の`speedScale`(全体の話速)を設定する。
+///
+/// @param [in] audio_query AudioQuery
+/// @param [in] speed_scale 全体の話速
+///
+/// @returns 成功時には ::VOICEVOX_RESULT_OK 、値が不正なときは ::VOICEVOX_RESULT_INVALID_AUDIO_QUERY_ERROR
+///
+/// \no-orig-impl{voicevox_audio_query_set_speed_scale}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_audio_query_set_speed_scale(
+    audio_query: *const VoicevoxAudioQuery,
+    speed_scale: f32,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        let speed_scale = validate_field::<AudioQuery, _, _, _>(
+            "speedScale",
+            speed_scale,
+            PositiveFinite::<f32>::new,
+        )?;
+        audio_query.modify(|audio_query| audio_query.speed_scale = speed_scale);
+        Ok(())
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxAudioQuery の`pitchScale`(全体の音高)を取得する。
+///
+/// @param [in] audio_query AudioQuery
+///
+/// @returns 全体の音高
+///
+/// \no-orig-impl{voicevox_audio_query_get_pitch_scale}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_audio_query_get_pitch_scale(
+    audio_query: *const VoicevoxAudioQuery,
+) -> f32 {
+    init_logger_once();
+    audio_query.get(|audio_query| audio_query.pitch_scale.get())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxAudioQuery の`pitchScale`(全体の音高)を設定する。
+///
+/// @param [in] audio_query AudioQuery
+/// @param [in] pitch_scale 全体の音高
+///
+/// @returns 成功時には ::VOICEVOX_RESULT_OK 、値が不正なときは ::VOICEVOX_RESULT_INVALID_AUDIO_QUERY_ERROR
+///
+/// \no-orig-impl{voicevox_audio_query_set_pitch_scale}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_audio_query_set_pitch_scale(
+    audio_query: *const VoicevoxAudioQuery,
+    pitch_scale: f32,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        let pitch_scale = validate_field::<AudioQuery, _, _, _>(
+            "pitchScale",
+            pitch_scale,
+            NonNaNFinite::<f32>::new,
+        )?;
+        audio_query.modify(|audio_query| audio_query.pitch_scale = pitch_scale);
+        Ok(())
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxAudioQuery の`intonationScale`(全体の抑揚)を取得する。
+///
+/// @param [in] audio_query AudioQuery
+///
+/// @returns 全体の抑揚
+///
+/// \no-orig-impl{voicevox_audio_query_get_intonation_scale}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_audio_query_get_intonation_scale(
+    audio_query: *const VoicevoxAudioQuery,
+) -> f32 {
+    init_logger_once();
+    audio_query.get(|audio_query| audio_query.intonation_scale.get())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxAudioQuery の`intonationScale`(全体の抑揚)を設定する。
+///
+/// @param [in] audio_query AudioQuery
+/// @param [in] intonation_scale 全体の抑揚
+///
+/// @returns 成功時には ::VOICEVOX_RESULT_OK 、値が不正なときは ::VOICEVOX_RESULT_INVALID_AUDIO_QUERY_ERROR
+///
+/// \no-orig-impl{voicevox_audio_query_set_intonation_scale}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_audio_query_set_intonation_scale(
+    audio_query: *const VoicevoxAudioQuery,
+    intonation_scale: f32,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        let intonation_scale = validate_field::<AudioQuery, _, _, _>(
+            "intonationScale",
+            intonation_scale,
+            NonNaNFinite::<f32>::new,
+        )?;
+        audio_query.modify(|audio_query| audio_query.intonation_scale = intonation_scale);
+        Ok(())
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxAudioQuery の`volumeScale`(全体の音量)を取得する。
+///
+/// @param [in] audio_query AudioQuery
+///
+/// @returns 全体の音量
+///
+/// \no-orig-impl{voicevox_audio_query_get_volume_scale}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_audio_query_get_volume_scale(
+    audio_query: *const VoicevoxAudioQuery,
+) -> f32 {
+    init_logger_once();
+    audio_query.get(|audio_query| audio_query.volume_scale.get())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxAudioQuery の`volumeScale`(全体の音量)を設定する。
+///
+/// @param [in] audio_query AudioQuery
+/// @param [in] volume_scale 全体の音量
+///
+/// @returns 成功時には ::VOICEVOX_RESULT_OK 、値が不正なときは ::VOICEVOX_RESULT_INVALID_AUDIO_QUERY_ERROR
+///
+/// \no-orig-impl{voicevox_audio_query_set_volume_scale}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_audio_query_set_volume_scale(
+    audio_query: *const VoicevoxAudioQuery,
+    volume_scale: f32,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        let volume_scale = validate_field::<AudioQuery, _, _, _>(
+            "volumeScale",
+            volume_scale,
+            PositiveFinite::<f32>::new,
+        )?;
+        audio_query.modify(|audio_query| audio_query.volume_scale = volume_scale);
+        Ok(())
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxAudioQuery の`prePhonemeLength`(音声の前の無音時間)を取得する。
+///
+/// @param [in] audio_query AudioQuery
+///
+/// @returns 音声の前の無音時間
+///
+/// \no-orig-impl{voicevox_audio_query_get_pre_phoneme_length}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_audio_query_get_pre_phoneme_length(
+    audio_query: *const VoicevoxAudioQuery,
+) -> f32 {
+    init_logger_once();
+    audio_query.get(|audio_query| audio_query.pre_phoneme_length.get())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxAudioQuery の`prePhonemeLength`(音声の前の無音時間)を設定する。
+///
+/// @param [in] audio_query AudioQuery
+/// @param [in] pre_phoneme_length 音声の前の無音時間
+///
+/// @returns 成功時には ::VOICEVOX_RESULT_OK 、値が不正なときは ::VOICEVOX_RESULT_INVALID_AUDIO_QUERY_ERROR
+///
+/// \no-orig-impl{voicevox_audio_query_set_pre_phoneme_length}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_audio_query_set_pre_phoneme_length(
+    audio_query: *const VoicevoxAudioQuery,
+    pre_phoneme_length: f32,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        let pre_phoneme_length = validate_field::<AudioQuery, _, _, _>(
+            "prePhonemeLength",
+            pre_phoneme_length,
+            PositiveFinite::<f32>::new,
+        )?;
+        audio_query.modify(|audio_query| audio_query.pre_phoneme_length = pre_phoneme_length);
+        Ok(())
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxAudioQuery の`postPhonemeLength`(音声の後の無音時間)を取得する。
+///
+/// @param [in] audio_query AudioQuery
+///
+/// @returns 音声の後の無音時間
+///
+/// \no-orig-impl{voicevox_audio_query_get_post_phoneme_length}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_audio_query_get_post_phoneme_length(
+    audio_query: *const VoicevoxAudioQuery,
+) -> f32 {
+    init_logger_once();
+    audio_query.get(|audio_query| audio_query.post_phoneme_length.get())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxAudioQuery の`postPhonemeLength`(音声の後の無音時間)を設定する。
+///
+/// @param [in] audio_query AudioQuery
+/// @param [in] post_phoneme_length 音声の後の無音時間
+///
+/// @returns 成功時には ::VOICEVOX_RESULT_OK 、値が不正なときは ::VOICEVOX_RESULT_INVALID_AUDIO_QUERY_ERROR
+///
+/// \no-orig-impl{voicevox_audio_query_set_post_phoneme_length}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_audio_query_set_post_phoneme_length(
+    audio_query: *const VoicevoxAudioQuery,
+    post_phoneme_length: f32,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        let post_phoneme_length = validate_field::<AudioQuery, _, _, _>(
+            "postPhonemeLength",
+            post_phoneme_length,
+            PositiveFinite::<f32>::new,
+        )?;
+        audio_query.modify(|audio_query| audio_query.post_phoneme_length = post_phoneme_length);
+        Ok(())
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxAudioQuery の`outputSamplingRate`(音声データの出力サンプリングレート)を取得する。
+///
+/// @param [in] audio_query AudioQuery
+///
+/// @returns 音声データの出力サンプリングレート
+///
+/// \no-orig-impl{voicevox_audio_query_get_output_sampling_rate}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_audio_query_get_output_sampling_rate(
+    audio_query: *const VoicevoxAudioQuery,
+) -> u32 {
+    init_logger_once();
+    audio_query.get(|audio_query| audio_query.output_sampling_rate.get().get())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxAudioQuery の`outputSamplingRate`(音声データの出力サンプリングレート)を設定する。
+///
+/// @param [in] audio_query AudioQuery
+/// @param [in] output_sampling_rate 音声データの出力サンプリングレート
+///
+/// @returns 成功時には ::VOICEVOX_RESULT_OK 、値が不正なときは ::VOICEVOX_RESULT_INVALID_AUDIO_QUERY_ERROR
+///
+/// \no-orig-impl{voicevox_audio_query_set_output_sampling_rate}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_audio_query_set_output_sampling_rate(
+    audio_query: *const VoicevoxAudioQuery,
+    output_sampling_rate: u32,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        let output_sampling_rate = validate_field::<AudioQuery, _, _, _>(
+            "outputSamplingRate",
+            output_sampling_rate,
+            |n| SamplingRate::new(n).map_err(|e| unwrap_invalid_query_error_kind(&e)),
+        )?;
+        audio_query.modify(|audio_query| audio_query.output_sampling_rate = output_sampling_rate);
+        Ok(())
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxAudioQuery の`outputStereo`(音声データをステレオ出力するか否か)を取得する。
+///
+/// @param [in] audio_query AudioQuery
+///
+/// @returns 音声データをステレオ出力するか否か
+///
+/// \no-orig-impl{voicevox_audio_query_get_output_stereo}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_audio_query_get_output_stereo(
+    audio_query: *const VoicevoxAudioQuery,
+) -> bool {
+    init_logger_once();
+    audio_query.get(|audio_query| audio_query.output_stereo)
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxAudioQuery の`outputStereo`(音声データをステレオ出力するか否か)を設定する。
+///
+/// @param [in] audio_query AudioQuery
+/// @param [in] output_stereo 音声データをステレオ出力するか否か
+///
+/// \no-orig-impl{voicevox_audio_query_set_output_stereo}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_audio_query_set_output_stereo(
+    audio_query: *const VoicevoxAudioQuery,
+    output_stereo: bool,
+) {
+    init_logger_once();
+    audio_query.modify(|audio_query| audio_query.output_stereo = output_stereo);
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxAudioQuery が持つAccentPhraseの数を取得する。
+///
+/// @param [in] audio_query AudioQuery
+///
+/// @returns AccentPhraseの数
+///
+/// \no-orig-impl{voicevox_audio_query_get_accent_phrases_length}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_audio_query_get_accent_phrases_length(
+    audio_query: *const VoicevoxAudioQuery,
+) -> usize {
+    init_logger_once();
+    audio_query.get(|audio_query| audio_query.accent_phrases.len())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxAudioQuery の`accent_phrase_index`番目のAccentPhraseの内容を取得する。
+///
+/// @param [in] audio_query AudioQuery
+/// @param [in] accent_phrase_index AccentPhraseのインデックス
+/// @param [out] output_accent_phrase 出力先
+///
+/// @returns 成功時には ::VOICEVOX_RESULT_OK 、インデックスが範囲外のときは ::VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR
+///
+/// \safety{
+/// - `output_accent_phrase`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+/// }
+///
+/// \no-orig-impl{voicevox_audio_query_get_accent_phrase}
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn voicevox_audio_query_get_accent_phrase(
+    audio_query: *const VoicevoxAudioQuery,
+    accent_phrase_index: usize,
+    output_accent_phrase: NonNull<VoicevoxAccentPhraseView>,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        let accent_phrase =
+            audio_query.get_accent_phrase(accent_phrase_index, VoicevoxAccentPhraseView::from)?;
+        // SAFETY: The safety contract must be upheld by the caller.
+        unsafe { output_accent_phrase.write_unaligned(accent_phrase) };
+        Ok(())
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxAudioQuery の`accent_phrase_index`番目のAccentPhraseについて、疑問系かどうかを設定する。
+///
+/// @param [in] audio_query AudioQuery
+/// @param [in] accent_phrase_index AccentPhraseのインデックス
+/// @param [in] is_interrogative 疑問系かどうか
+///
+/// @returns 成功時には ::VOICEVOX_RESULT_OK 、インデックスが範囲外のときは ::VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR
+///
+/// \no-orig-impl{voicevox_audio_query_set_is_interrogative}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_audio_query_set_is_interrogative(
+    audio_query: *const VoicevoxAudioQuery,
+    accent_phrase_index: usize,
+    is_interrogative: bool,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error(audio_query.modify_accent_phrase(
+        accent_phrase_index,
+        |accent_phrase| {
+            accent_phrase.is_interrogative = is_interrogative;
+            Ok(())
+        },
+    ))
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxAudioQuery の`accent_phrase_index`番目のAccentPhraseの、`mora_index`番目のモーラの内容を取得する。
+///
+/// `mora_index`が ::VoicevoxAccentPhraseView の`moras_length`と等しいときは、`pause_mora`を対象とする。
+///
+/// @param [in] audio_query AudioQuery
+/// @param [in] accent_phrase_index AccentPhraseのインデックス
+/// @param [in] mora_index モーラのインデックス
+/// @param [out] output_mora 出力先
+///
+/// @returns 成功時には ::VOICEVOX_RESULT_OK 、インデックスが範囲外のときは ::VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR
+///
+/// \safety{
+/// - `output_mora`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+/// }
+///
+/// \no-orig-impl{voicevox_audio_query_get_mora}
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn voicevox_audio_query_get_mora(
+    audio_query: *const VoicevoxAudioQuery,
+    accent_phrase_index: usize,
+    mora_index: usize,
+    output_mora: NonNull<VoicevoxMoraView>,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        let mora = audio_query.get_mora(accent_phrase_index, mora_index, VoicevoxMoraView::from)?;
+        // SAFETY: The safety contract must be upheld by the caller.
+        unsafe { output_mora.write_unaligned(mora) };
+        Ok(())
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxAudioQuery の`accent_phrase_index`番目のAccentPhraseの、`mora_index`番目のモーラの内容を設定する。
+///
+/// `mora_index`の扱いは ::voicevox_audio_query_get_mora と同じ。
+///
+/// 子音の有無は変更できず、`mora.has_consonant`は無視される。子音が無いモーラに対しては、`mora.consonant_length`も無視される。
+///
+/// @param [in] audio_query AudioQuery
+/// @param [in] accent_phrase_index AccentPhraseのインデックス
+/// @param [in] mora_index モーラのインデックス
+/// @param [in] mora 設定する内容
+///
+/// @returns 結果コード。インデックスが範囲外のときは ::VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR 、値が不正なときは ::VOICEVOX_RESULT_INVALID_MORA_ERROR
+///
+/// \no-orig-impl{voicevox_audio_query_set_mora}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_audio_query_set_mora(
+    audio_query: *const VoicevoxAudioQuery,
+    accent_phrase_index: usize,
+    mora_index: usize,
+    mora: VoicevoxMoraView,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error(audio_query.modify_mora(
+        accent_phrase_index,
+        mora_index,
+        |target| mora.write_to(target),
+    ))
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxAudioQuery を<b>破棄</b>(_destruct_)する。
+///
+/// 破棄対象への他スレッドでのアクセスが存在する場合、それらがすべて終わるのを待ってから破棄する。
+///
+/// この関数の呼び出し後に破棄し終えた対象にアクセスすると、プロセスを異常終了する。
+///
+/// @param [in] audio_query 破棄対象。nullable
+///
+/// \no-orig-impl{voicevox_audio_query_delete}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_audio_query_delete(audio_query: *mut VoicevoxAudioQuery) {
+    init_logger_once();
+    audio_query.drop_body();
+}
+
+/// 楽譜。
+///
+/// 楽譜のJSON文字列の代わりに用いることができる。
+///
+/// <b>構築</b>(_construction_)は ::voicevox_score_new_from_json で行い、<b>破棄</b>(_destruction_)は ::voicevox_score_delete で行う。
+///
+/// \orig-impl{VoicevoxScore}
+#[derive(Debug, Educe)]
+#[educe(Default(expression = "Self { _padding: MaybeUninit::uninit() }"))]
+pub struct VoicevoxScore {
+    _padding: MaybeUninit<[u8; 1]>,
+}
+
+/// ::VoicevoxScore 内の音符の、数値で表される内容。
+///
+/// 文字列で表されるフィールドは ::voicevox_score_create_json で得る。
+///
+/// \no-orig-impl{VoicevoxNoteView}
+#[repr(C)]
+pub struct VoicevoxNoteView {
+    /// 音階を持つかどうか。休符のときは`false`
+    has_key: bool,
+    /// 音階。休符のときは`0`
+    key: u8,
+    /// 音符のフレーム長
+    frame_length: u64,
+    /// ブレスであるかどうか
+    breath: bool,
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// 楽譜のJSON文字列から ::VoicevoxScore を<b>構築</b>(_construct_)する。
+///
+/// @param [in] score_json 楽譜のJSON文字列
+/// @param [out] out_score 構築先
+///
+/// @returns 結果コード
+///
+/// \safety{
+/// - `score_json`はヌル終端文字列を指し、かつ<a href="#voicevox-core-safety">読み込みについて有効</a>でなければならない。
+/// - `out_score`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+/// }
+///
+/// \no-orig-impl{voicevox_score_new_from_json}
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn voicevox_score_new_from_json(
+    score_json: *const c_char,
+    out_score: NonNull<NonNull<VoicevoxScore>>,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        // SAFETY: The safety contract must be upheld by the caller.
+        let score_json = unsafe { CStr::from_ptr(score_json) };
+        let score = Score::validate_json(score_json)?;
+        let score = VoicevoxScore::new(score);
+        // SAFETY: The safety contract must be upheld by the caller.
+        unsafe { out_score.write_unaligned(score) };
+        Ok(())
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxScore をJSON文字列にする。
+///
+/// JSONの解放は ::voicevox_json_free で行う。
+///
+/// @param [in] score 楽譜
+///
+/// @returns 楽譜のJSON文字列
+///
+/// \no-orig-impl{voicevox_score_create_json}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_score_create_json(score: *const VoicevoxScore) -> *mut c_char {
+    init_logger_once();
+    let json = Score::to_c_json(&score.body());
+    C_STRING_DROP_CHECKER.whitelist(json).into_raw()
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxScore が持つ音符の数を取得する。
+///
+/// @param [in] score 楽譜
+///
+/// @returns 音符の数
+///
+/// \no-orig-impl{voicevox_score_get_notes_length}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_score_get_notes_length(score: *const VoicevoxScore) -> usize {
+    init_logger_once();
+    score.body().notes.len()
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxScore の`note_index`番目の音符の内容を取得する。
+///
+/// @param [in] score 楽譜
+/// @param [in] note_index 音符のインデックス
+/// @param [out] output_note 出力先
+///
+/// @returns 成功時には ::VOICEVOX_RESULT_OK 、インデックスが範囲外のときは ::VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR
+///
+/// \safety{
+/// - `output_note`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+/// }
+///
+/// \no-orig-impl{voicevox_score_get_note}
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn voicevox_score_get_note(
+    score: *const VoicevoxScore,
+    note_index: usize,
+    output_note: NonNull<VoicevoxNoteView>,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        let score = score.body();
+        let note = score
+            .notes
+            .get(note_index)
+            .ok_or(CApiError::IndexOutOfRange {
+                index: note_index,
+                len: score.notes.len(),
+            })?;
+        // SAFETY: The safety contract must be upheld by the caller.
+        unsafe { output_note.write_unaligned(note.into()) };
+        Ok(())
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxScore から、 ::VoicevoxFrameAudioQuery を生成する。
+///
+/// ::voicevox_synthesizer_create_sing_frame_audio_query と同じだが、JSON文字列の代わりに ::VoicevoxScore と ::VoicevoxFrameAudioQuery を用いる。
+///
+/// @param [in] synthesizer 音声シンセサイザ
+/// @param [in] score 楽譜
+/// @param [in] style_id スタイルID
+/// @param [out] out_frame_audio_query 生成先
+///
+/// @returns 結果コード
+///
+/// \safety{
+/// - `out_frame_audio_query`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+/// }
+///
+/// \no-orig-impl{voicevox_synthesizer_create_sing_frame_audio_query_handle}
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn voicevox_synthesizer_create_sing_frame_audio_query_handle(
+    synthesizer: *const VoicevoxSynthesizer,
+    score: *const VoicevoxScore,
+    style_id: VoicevoxStyleId,
+    out_frame_audio_query: NonNull<NonNull<VoicevoxFrameAudioQuery>>,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        let frame_audio_query = synthesizer
+            .body()
+            .create_sing_frame_audio_query(&score.body(), StyleId::new(style_id))?;
+        let frame_audio_query = VoicevoxFrameAudioQuery::new(frame_audio_query);
+        // SAFETY: The safety contract must be upheld by the caller.
+        unsafe { out_frame_audio_query.write_unaligned(frame_audio_query) };
+        Ok(())
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxScore を<b>破棄</b>(_destruct_)する。
+///
+/// 破棄対象への他スレッドでのアクセスが存在する場合、それらがすべて終わるのを待ってから破棄する。
+///
+/// この関数の呼び出し後に破棄し終えた対象にアクセスすると、プロセスを異常終了する。
+///
+/// @param [in] score 破棄対象。nullable
+///
+/// \no-orig-impl{voicevox_score_delete}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_score_delete(score: *mut VoicevoxScore) {
+    init_logger_once();
+    score.drop_body();
+}
+
+/// FrameAudioQuery。
+///
+/// FrameAudioQueryのJSON文字列の代わりに用いることができ、JSONを介さずにフィールドを読み書きできる。
+///
+/// <b>構築</b>(_construction_)は ::voicevox_frame_audio_query_new_from_json もしくは ::voicevox_synthesizer_create_sing_frame_audio_query_handle で行い、<b>破棄</b>(_destruction_)は ::voicevox_frame_audio_query_delete で行う。
+///
+/// \orig-impl{VoicevoxFrameAudioQuery}
+#[derive(Debug, Educe)]
+#[educe(Default(expression = "Self { _padding: MaybeUninit::uninit() }"))]
+pub struct VoicevoxFrameAudioQuery {
+    _padding: MaybeUninit<[u8; 1]>,
+}
+
+/// ::VoicevoxFrameAudioQuery 内の音素の、数値で表される内容。
+///
+/// 文字列で表されるフィールドは ::voicevox_frame_audio_query_create_json で得る。
+///
+/// \no-orig-impl{VoicevoxFramePhonemeView}
+#[repr(C)]
+pub struct VoicevoxFramePhonemeView {
+    /// 音素のフレーム長
+    frame_length: u64,
+    /// 音符のIDを持つかどうか
+    has_note_id: bool,
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// FrameAudioQueryのJSON文字列から ::VoicevoxFrameAudioQuery を<b>構築</b>(_construct_)する。
+///
+/// @param [in] frame_audio_query_json FrameAudioQueryのJSON文字列
+/// @param [out] out_frame_audio_query 構築先
+///
+/// @returns 結果コード
+///
+/// \safety{
+/// - `frame_audio_query_json`はヌル終端文字列を指し、かつ<a href="#voicevox-core-safety">読み込みについて有効</a>でなければならない。
+/// - `out_frame_audio_query`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+/// }
+///
+/// \no-orig-impl{voicevox_frame_audio_query_new_from_json}
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn voicevox_frame_audio_query_new_from_json(
+    frame_audio_query_json: *const c_char,
+    out_frame_audio_query: NonNull<NonNull<VoicevoxFrameAudioQuery>>,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        // SAFETY: The safety contract must be upheld by the caller.
+        let frame_audio_query_json = unsafe { CStr::from_ptr(frame_audio_query_json) };
+        let frame_audio_query = FrameAudioQuery::validate_json(frame_audio_query_json)?;
+        let frame_audio_query = VoicevoxFrameAudioQuery::new(frame_audio_query);
+        // SAFETY: The safety contract must be upheld by the caller.
+        unsafe { out_frame_audio_query.write_unaligned(frame_audio_query) };
+        Ok(())
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxFrameAudioQuery から音声合成を行う。
+///
+/// ::voicevox_synthesizer_frame_synthesis と同じだが、JSON文字列の代わりに ::VoicevoxFrameAudioQuery を用いる。
+///
+/// 生成したWAVデータを解放するには ::voicevox_wav_free を使う。
+///
+/// @param [in] synthesizer 音声シンセサイザ
+/// @param [in] frame_audio_query FrameAudioQuery
+/// @param [in] style_id スタイルID
+/// @param [out] output_wav_length 出力のバイト長
+/// @param [out] output_wav 出力先
+///
+/// @returns 結果コード
+///
+/// \safety{
+/// - `output_wav_length`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+/// - `output_wav`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+/// }
+///
+/// \no-orig-impl{voicevox_synthesizer_frame_synthesis_from_handle}
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn voicevox_synthesizer_frame_synthesis_from_handle(
+    synthesizer: *const VoicevoxSynthesizer,
+    frame_audio_query: *const VoicevoxFrameAudioQuery,
+    style_id: VoicevoxStyleId,
+    output_wav_length: NonNull<usize>,
+    output_wav: NonNull<NonNull<u8>>,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        let wav = frame_audio_query.get(|frame_audio_query| {
+            synthesizer
+                .body()
+                .frame_synthesis(frame_audio_query, StyleId::new(style_id))
+                .perform()
+        })?;
+        // SAFETY: The safety contract must be upheld by the caller.
+        unsafe { U8_SLICE_OWNER.own_and_lend(wav, output_wav, output_wav_length) };
+        Ok(())
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxFrameAudioQuery をJSON文字列にする。
+///
+/// JSONの解放は ::voicevox_json_free で行う。
+///
+/// @param [in] frame_audio_query FrameAudioQuery
+///
+/// @returns FrameAudioQueryのJSON文字列
+///
+/// \no-orig-impl{voicevox_frame_audio_query_create_json}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_frame_audio_query_create_json(
+    frame_audio_query: *const VoicevoxFrameAudioQuery,
+) -> *mut c_char {
+    init_logger_once();
+    let json = frame_audio_query.get(FrameAudioQuery::to_c_json);
+    C_STRING_DROP_CHECKER.whitelist(json).into_raw()
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxFrameAudioQuery のフレーム数、すなわち`f0`と`volume`の長さを取得する。
+///
+/// @param [in] frame_audio_query FrameAudioQuery
+///
+/// @returns フレーム数
+///
+/// \no-orig-impl{voicevox_frame_audio_query_get_frames_length}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_frame_audio_query_get_frames_length(
+    frame_audio_query: *const VoicevoxFrameAudioQuery,
+) -> usize {
+    init_logger_once();
+    frame_audio_query.get(|frame_audio_query| frame_audio_query.f0.len())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxFrameAudioQuery の`frame_index`番目のフレームの基本周波数を取得する。
+///
+/// @param [in] frame_audio_query FrameAudioQuery
+/// @param [in] frame_index フレームのインデックス
+/// @param [out] output_f0 出力先
+///
+/// @returns 成功時には ::VOICEVOX_RESULT_OK 、インデックスが範囲外のときは ::VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR
+///
+/// \safety{
+/// - `output_f0`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+/// }
+///
+/// \no-orig-impl{voicevox_frame_audio_query_get_f0}
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn voicevox_frame_audio_query_get_f0(
+    frame_audio_query: *const VoicevoxFrameAudioQuery,
+    frame_index: usize,
+    output_f0: NonNull<f32>,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        let f0 = frame_audio_query.get_frame(frame_index, |q| &q.f0[..])?;
+        // SAFETY: The safety contract must be upheld by the caller.
+        unsafe { output_f0.write_unaligned(f0.get()) };
+        Ok(())
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxFrameAudioQuery の`frame_index`番目のフレームの基本周波数を設定する。
+///
+/// @param [in] frame_audio_query FrameAudioQuery
+/// @param [in] frame_index フレームのインデックス
+/// @param [in] f0 基本周波数
+///
+/// @returns 結果コード。インデックスが範囲外のときは ::VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR 、値が不正なときは ::VOICEVOX_RESULT_INVALID_FRAME_AUDIO_QUERY_ERROR
+///
+/// \no-orig-impl{voicevox_frame_audio_query_set_f0}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_frame_audio_query_set_f0(
+    frame_audio_query: *const VoicevoxFrameAudioQuery,
+    frame_index: usize,
+    f0: f32,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        let f0 = validate_field::<FrameAudioQuery, _, _, _>("f0", f0, PositiveFinite::<f32>::new)?;
+        frame_audio_query.set_frame(frame_index, f0, |q| &mut q.f0[..])
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxFrameAudioQuery の`frame_index`番目のフレームの音量を取得する。
+///
+/// @param [in] frame_audio_query FrameAudioQuery
+/// @param [in] frame_index フレームのインデックス
+/// @param [out] output_volume 出力先
+///
+/// @returns 成功時には ::VOICEVOX_RESULT_OK 、インデックスが範囲外のときは ::VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR
+///
+/// \safety{
+/// - `output_volume`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+/// }
+///
+/// \no-orig-impl{voicevox_frame_audio_query_get_volume}
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn voicevox_frame_audio_query_get_volume(
+    frame_audio_query: *const VoicevoxFrameAudioQuery,
+    frame_index: usize,
+    output_volume: NonNull<f32>,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        let volume = frame_audio_query.get_frame(frame_index, |q| &q.volume[..])?;
+        // SAFETY: The safety contract must be upheld by the caller.
+        unsafe { output_volume.write_unaligned(volume.get()) };
+        Ok(())
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxFrameAudioQuery の`frame_index`番目のフレームの音量を設定する。
+///
+/// @param [in] frame_audio_query FrameAudioQuery
+/// @param [in] frame_index フレームのインデックス
+/// @param [in] volume 音量
+///
+/// @returns 結果コード。インデックスが範囲外のときは ::VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR 、値が不正なときは ::VOICEVOX_RESULT_INVALID_FRAME_AUDIO_QUERY_ERROR
+///
+/// \no-orig-impl{voicevox_frame_audio_query_set_volume}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_frame_audio_query_set_volume(
+    frame_audio_query: *const VoicevoxFrameAudioQuery,
+    frame_index: usize,
+    volume: f32,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        let volume =
+            validate_field::<FrameAudioQuery, _, _, _>("volume", volume, NonNaNFinite::<f32>::new)?;
+        frame_audio_query.set_frame(frame_index, volume, |q| &mut q.volume[..])
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxFrameAudioQuery が持つ音素の数を取得する。
+///
+/// 各音素の内容は ::voicevox_frame_audio_query_get_phoneme で得る。
+///
+/// @param [in] frame_audio_query FrameAudioQuery
+///
+/// @returns 音素の数
+///
+/// \no-orig-impl{voicevox_frame_audio_query_get_phonemes_length}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_frame_audio_query_get_phonemes_length(
+    frame_audio_query: *const VoicevoxFrameAudioQuery,
+) -> usize {
+    init_logger_once();
+    frame_audio_query.get(|frame_audio_query| frame_audio_query.phonemes.len())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxFrameAudioQuery の`phoneme_index`番目の音素の内容を取得する。
+///
+/// @param [in] frame_audio_query FrameAudioQuery
+/// @param [in] phoneme_index 音素のインデックス
+/// @param [out] output_phoneme 出力先
+///
+/// @returns 成功時には ::VOICEVOX_RESULT_OK 、インデックスが範囲外のときは ::VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR
+///
+/// \safety{
+/// - `output_phoneme`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+/// }
+///
+/// \no-orig-impl{voicevox_frame_audio_query_get_phoneme}
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn voicevox_frame_audio_query_get_phoneme(
+    frame_audio_query: *const VoicevoxFrameAudioQuery,
+    phoneme_index: usize,
+    output_phoneme: NonNull<VoicevoxFramePhonemeView>,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        let phoneme = frame_audio_query.get(|frame_audio_query| {
+            let phonemes = &frame_audio_query.phonemes;
+            phonemes
+                .get(phoneme_index)
+                .map(VoicevoxFramePhonemeView::from)
+                .ok_or(CApiError::IndexOutOfRange {
+                    index: phoneme_index,
+                    len: phonemes.len(),
+                })
+        })?;
+        // SAFETY: The safety contract must be upheld by the caller.
+        unsafe { output_phoneme.write_unaligned(phoneme) };
+        Ok(())
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxFrameAudioQuery の`volumeScale`(全体の音量)を取得する。
+///
+/// @param [in] frame_audio_query FrameAudioQuery
+///
+/// @returns 全体の音量
+///
+/// \no-orig-impl{voicevox_frame_audio_query_get_volume_scale}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_frame_audio_query_get_volume_scale(
+    frame_audio_query: *const VoicevoxFrameAudioQuery,
+) -> f32 {
+    init_logger_once();
+    frame_audio_query.get(|frame_audio_query| frame_audio_query.volume_scale.get())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxFrameAudioQuery の`volumeScale`(全体の音量)を設定する。
+///
+/// @param [in] frame_audio_query FrameAudioQuery
+/// @param [in] volume_scale 全体の音量
+///
+/// @returns 成功時には ::VOICEVOX_RESULT_OK 、値が不正なときは ::VOICEVOX_RESULT_INVALID_FRAME_AUDIO_QUERY_ERROR
+///
+/// \no-orig-impl{voicevox_frame_audio_query_set_volume_scale}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_frame_audio_query_set_volume_scale(
+    frame_audio_query: *const VoicevoxFrameAudioQuery,
+    volume_scale: f32,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        let volume_scale = validate_field::<FrameAudioQuery, _, _, _>(
+            "volumeScale",
+            volume_scale,
+            PositiveFinite::<f32>::new,
+        )?;
+        frame_audio_query.modify(|frame_audio_query| {
+            frame_audio_query.volume_scale = volume_scale;
+        });
+        Ok(())
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxFrameAudioQuery の`outputSamplingRate`(音声データの出力サンプリングレート)を取得する。
+///
+/// @param [in] frame_audio_query FrameAudioQuery
+///
+/// @returns 音声データの出力サンプリングレート
+///
+/// \no-orig-impl{voicevox_frame_audio_query_get_output_sampling_rate}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_frame_audio_query_get_output_sampling_rate(
+    frame_audio_query: *const VoicevoxFrameAudioQuery,
+) -> u32 {
+    init_logger_once();
+    frame_audio_query.get(|frame_audio_query| frame_audio_query.output_sampling_rate.get().get())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxFrameAudioQuery の`outputSamplingRate`(音声データの出力サンプリングレート)を設定する。
+///
+/// @param [in] frame_audio_query FrameAudioQuery
+/// @param [in] output_sampling_rate 音声データの出力サンプリングレート
+///
+/// @returns 成功時には ::VOICEVOX_RESULT_OK 、値が不正なときは ::VOICEVOX_RESULT_INVALID_FRAME_AUDIO_QUERY_ERROR
+///
+/// \no-orig-impl{voicevox_frame_audio_query_set_output_sampling_rate}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_frame_audio_query_set_output_sampling_rate(
+    frame_audio_query: *const VoicevoxFrameAudioQuery,
+    output_sampling_rate: u32,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        let output_sampling_rate = validate_field::<FrameAudioQuery, _, _, _>(
+            "outputSamplingRate",
+            output_sampling_rate,
+            |n| SamplingRate::new(n).map_err(|e| unwrap_invalid_query_error_kind(&e)),
+        )?;
+        frame_audio_query.modify(|frame_audio_query| {
+            frame_audio_query.output_sampling_rate = output_sampling_rate;
+        });
+        Ok(())
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxFrameAudioQuery の`outputStereo`(音声データをステレオ出力するか否か)を取得する。
+///
+/// @param [in] frame_audio_query FrameAudioQuery
+///
+/// @returns 音声データをステレオ出力するか否か
+///
+/// \no-orig-impl{voicevox_frame_audio_query_get_output_stereo}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_frame_audio_query_get_output_stereo(
+    frame_audio_query: *const VoicevoxFrameAudioQuery,
+) -> bool {
+    init_logger_once();
+    frame_audio_query.get(|frame_audio_query| frame_audio_query.output_stereo)
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxFrameAudioQuery の`outputStereo`(音声データをステレオ出力するか否か)を設定する。
+///
+/// @param [in] frame_audio_query FrameAudioQuery
+/// @param [in] output_stereo 音声データをステレオ出力するか否か
+///
+/// \no-orig-impl{voicevox_frame_audio_query_set_output_stereo}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_frame_audio_query_set_output_stereo(
+    frame_audio_query: *const VoicevoxFrameAudioQuery,
+    output_stereo: bool,
+) {
+    init_logger_once();
+    frame_audio_query.modify(|frame_audio_query| frame_audio_query.output_stereo = output_stereo);
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxFrameAudioQuery を<b>破棄</b>(_destruct_)する。
+///
+/// 破棄対象への他スレッドでのアクセスが存在する場合、それらがすべて終わるのを待ってから破棄する。
+///
+/// この関数の呼び出し後に破棄し終えた対象にアクセスすると、プロセスを異常終了する。
+///
+/// @param [in] frame_audio_query 破棄対象。nullable
+///
+/// \no-orig-impl{voicevox_frame_audio_query_delete}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_frame_audio_query_delete(
+    frame_audio_query: *mut VoicevoxFrameAudioQuery,
+) {
+    init_logger_once();
+    frame_audio_query.drop_body();
+}
+
+/// ::voicevox_synthesizer_tts_async などで開始した、非同期の処理。
+///
+/// ::voicevox_job_cancel でキャンセルでき、 ::voicevox_job_delete で<b>破棄</b>(_destruction_)する。
+///
+/// 処理は、プロセス全体で共有される固定数のワーカースレッドで実行される。スレッド数は利用可能なCPUの論理コア数であり、それを超える数の処理を開始した場合、後から開始したものは先に開始したものが終わるまで待たされる。
+///
+/// 処理は開始元の ::VoicevoxSynthesizer に紐付けられる。 ::voicevox_synthesizer_delete は、紐付けられた処理をキャンセルし、それらが終わるのを待ってから破棄を行う。
+///
+/// \no-orig-impl{VoicevoxJob}
+#[derive(Debug, Educe)]
+#[educe(Default(expression = "Self { _padding: MaybeUninit::uninit() }"))]
+pub struct VoicevoxJob {
+    _padding: MaybeUninit<[u8; 1]>,
+}
+
+/// ::voicevox_synthesizer_tts_async などの処理が終わったときに呼ばれるコールバック。
+///
+/// 引数は順に、結果コード、生成したWAVデータのバイト長、生成したWAVデータ、開始時に渡した`user_data`。WAVデータは成功したときのみ非NULLであり、 ::voicevox_wav_free で解放する。
+///
+/// キャンセルされたときは ::VOICEVOX_RESULT_CANCELLED が渡される。失敗したときは、コールバックの中で ::voicevox_last_error_message などを用いてエラーの詳細を得ることができる。
+///
+/// \no-orig-impl{VoicevoxWavCallback}
+pub type VoicevoxWavCallback = unsafe extern "C" fn(
+    result_code: VoicevoxResultCode,
+    wav_length: usize,
+    wav: *mut u8,
+    user_data: *mut c_void,
+);
+
+/// ::voicevox_synthesizer_tts_async などの、非同期の処理のオプション。
+///
+/// \no-orig-impl{VoicevoxJobOptions}
+#[repr(C)]
+pub struct VoicevoxJobOptions {
+    /// 音声モデルの実行をキャンセル可能にするかどうか。
+    ///
+    /// 無効の場合でも ::voicevox_job_cancel によって処理は終わるが、実行中の音声モデルの推論はバックグラウンドで最後まで続く。有効にすると推論そのものを中断するが、負荷がかかっている状況下でハングする可能性がある。そのためデフォルトでは無効化されている。<a href="https://github.com/VOICEVOX/voicevox_core/issues/968">VOICEVOX/voicevox_core#968</a>を参照。
+    cancellable: bool,
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// デフォルトの非同期の処理のオプションを生成する
+/// @return 非同期の処理のオプション
+///
+/// \no-orig-impl{voicevox_make_default_job_options}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_make_default_job_options() -> VoicevoxJobOptions {
+    init_logger_once();
+    VoicevoxJobOptions::default()
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// AudioQueryから音声合成を行う処理を、非同期に開始する。
+///
+/// ::voicevox_synthesizer_synthesis の非同期版。処理はバックグラウンドのワーカースレッド( ::VoicevoxJob を参照)で行われ、終わったら`callback`がそのスレッドから呼ばれる。`callback`は、この関数から帰る前に呼ばれることもある。
+///
+/// この関数が ::VOICEVOX_RESULT_OK 以外を返したときは、処理は開始されず`callback`は呼ばれない。
+///
+/// @param [in] synthesizer 音声シンセサイザ
+/// @param [in] audio_query_json AudioQueryのJSON文字列
+/// @param [in] style_id スタイルID
+/// @param [in] options オプション
+/// @param [in] job_options 非同期の処理のオプション
+/// @param [in] callback 処理が終わったときに呼ばれるコールバック
+/// @param [in] user_data `callback`に渡されるポインタ
+/// @param [out] out_job 開始した処理
+///
+/// @returns 結果コード
+///
+/// \safety{
+/// - `audio_query_json`はヌル終端文字列を指し、かつ<a href="#voicevox-core-safety">読み込みについて有効</a>でなければならない。
+/// - `callback`は、任意のスレッドから呼び出されても問題が無いものでなければならない。
+/// - `user_data`は、`callback`が呼ばれるまで`callback`にとって有効でなければならない。
+/// - `out_job`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+/// }
+///
+/// \no-orig-impl{voicevox_synthesizer_synthesis_async}
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn voicevox_synthesizer_synthesis_async(
+    synthesizer: *const VoicevoxSynthesizer,
+    audio_query_json: *const c_char,
+    style_id: VoicevoxStyleId,
+    options: VoicevoxSynthesisOptions,
+    job_options: VoicevoxJobOptions,
+    callback: VoicevoxWavCallback,
+    user_data: *mut c_void,
+    out_job: NonNull<NonNull<VoicevoxJob>>,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        // SAFETY: The safety contract must be upheld by the caller.
+        let audio_query_json = unsafe { CStr::from_ptr(audio_query_json) };
+        let audio_query = AudioQuery::validate_json(audio_query_json)?;
+        let VoicevoxSynthesisOptions {
+            enable_interrogative_upspeak,
+        } = options;
+        let VoicevoxJobOptions { cancellable } = job_options;
+        let nonblocking = synthesizer.body().to_nonblocking();
+        let task = async move {
+            let wav = nonblocking
+                .synthesis(&audio_query, StyleId::new(style_id))
+                .enable_interrogative_upspeak(enable_interrogative_upspeak)
+                .cancellable(cancellable)
+                .perform()
+                .await?;
+            Ok::<_, CApiError>(wav)
+        };
+        // SAFETY: The safety contract must be upheld by the caller.
+        let job = unsafe { VoicevoxJob::spawn_wav(synthesizer, task, callback, user_data) };
+        // SAFETY: The safety contract must be upheld by the caller.
+        unsafe { out_job.write_unaligned(job) };
+        Ok(())
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// AquesTalk風記法から音声合成を行う処理を、非同期に開始する。
+///
+/// ::voicevox_synthesizer_tts_from_kana の非同期版。処理はバックグラウンドのワーカースレッド( ::VoicevoxJob を参照)で行われ、終わったら`callback`がそのスレッドから呼ばれる。`callback`は、この関数から帰る前に呼ばれることもある。
+///
+/// この関数が ::VOICEVOX_RESULT_OK 以外を返したときは、処理は開始されず`callback`は呼ばれない。
+///
+/// @param [in] synthesizer
+/// @param [in] kana AquesTalk風記法
+/// @param [in] style_id スタイルID
+/// @param [in] options オプション
+/// @param [in] job_options 非同期の処理のオプション
+/// @param [in] callback 処理が終わったときに呼ばれるコールバック
+/// @param [in] user_data `callback`に渡されるポインタ
+/// @param [out] out_job 開始した処理
+///
+/// @returns 結果コード
+///
+/// \safety{
+/// - `kana`はヌル終端文字列を指し、かつ<a href="#voicevox-core-safety">読み込みについて有効</a>でなければならない。
+/// - `callback`は、任意のスレッドから呼び出されても問題が無いものでなければならない。
+/// - `user_data`は、`callback`が呼ばれるまで`callback`にとって有効でなければならない。
+/// - `out_job`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+/// }
+///
+/// \no-orig-impl{voicevox_synthesizer_tts_from_kana_async}
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn voicevox_synthesizer_tts_from_kana_async(
+    synthesizer: *const VoicevoxSynthesizer,
+    kana: *const c_char,
+    style_id: VoicevoxStyleId,
+    options: VoicevoxTtsOptions,
+    job_options: VoicevoxJobOptions,
+    callback: VoicevoxWavCallback,
+    user_data: *mut c_void,
+    out_job: NonNull<NonNull<VoicevoxJob>>,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        // SAFETY: The safety contract must be upheld by the caller.
+        let kana = ensure_utf8(unsafe { CStr::from_ptr(kana) })?.to_owned();
+        let VoicevoxTtsOptions {
+            enable_interrogative_upspeak,
+        } = options;
+        let VoicevoxJobOptions { cancellable } = job_options;
+        let nonblocking = synthesizer.body().to_nonblocking();
+        let task = async move {
+            let wav = nonblocking
+                .tts_from_kana(&kana, StyleId::new(style_id))
+                .enable_interrogative_upspeak(enable_interrogative_upspeak)
+                .cancellable(cancellable)
+                .perform()
+                .await?;
+            Ok::<_, CApiError>(wav)
+        };
+        // SAFETY: The safety contract must be upheld by the caller.
+        let job = unsafe { VoicevoxJob::spawn_wav(synthesizer, task, callback, user_data) };
+        // SAFETY: The safety contract must be upheld by the caller.
+        unsafe { out_job.write_unaligned(job) };
+        Ok(())
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// 日本語テキストから音声合成を行う処理を、非同期に開始する。
+///
+/// ::voicevox_synthesizer_tts の非同期版。処理はバックグラウンドのワーカースレッド( ::VoicevoxJob を参照)で行われ、終わったら`callback`がそのスレッドから呼ばれる。`callback`は、この関数から帰る前に呼ばれることもある。
+///
+/// この関数が ::VOICEVOX_RESULT_OK 以外を返したときは、処理は開始されず`callback`は呼ばれない。
+///
+/// @param [in] synthesizer
+/// @param [in] text UTF-8の日本語テキスト
+/// @param [in] style_id スタイルID
+/// @param [in] options オプション
+/// @param [in] job_options 非同期の処理のオプション
+/// @param [in] callback 処理が終わったときに呼ばれるコールバック
+/// @param [in] user_data `callback`に渡されるポインタ
+/// @param [out] out_job 開始した処理
+///
+/// @returns 結果コード
+///
+/// \example{
+/// ```c
+/// static void on_finished(VoicevoxResultCode result_code, uintptr_t wav_length,
+///                         uint8_t *wav, void *user_data) {
+///   if (result_code == VOICEVOX_RESULT_OK) {
+///     play((Player *)user_data, wav, wav_length);
+///   }
+///   voicevox_wav_free(wav);
+/// }
+///
+/// VoicevoxJob *job;
+/// voicevox_synthesizer_tts_async(synthesizer, "こんにちは", 0,
+///                                voicevox_make_default_tts_options(),
+///                                voicevox_make_default_job_options(),
+///                                on_finished, player, &job);
+///
+/// // 不要になったらキャンセルする
+/// voicevox_job_cancel(job);
+/// voicevox_job_delete(job);
+/// ```
+/// }
+///
+/// \safety{
+/// - `text`はヌル終端文字列を指し、かつ<a href="#voicevox-core-safety">読み込みについて有効</a>でなければならない。
+/// - `callback`は、任意のスレッドから呼び出されても問題が無いものでなければならない。
+/// - `user_data`は、`callback`が呼ばれるまで`callback`にとって有効でなければならない。
+/// - `out_job`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+/// }
+///
+/// \no-orig-impl{voicevox_synthesizer_tts_async}
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn voicevox_synthesizer_tts_async(
+    synthesizer: *const VoicevoxSynthesizer,
+    text: *const c_char,
+    style_id: VoicevoxStyleId,
+    options: VoicevoxTtsOptions,
+    job_options: VoicevoxJobOptions,
+    callback: VoicevoxWavCallback,
+    user_data: *mut c_void,
+    out_job: NonNull<NonNull<VoicevoxJob>>,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        // SAFETY: The safety contract must be upheld by the caller.
+        let text = ensure_utf8(unsafe { CStr::from_ptr(text) })?.to_owned();
+        let VoicevoxTtsOptions {
+            enable_interrogative_upspeak,
+        } = options;
+        let VoicevoxJobOptions { cancellable } = job_options;
+        let nonblocking = synthesizer.body().to_nonblocking();
+        let task = async move {
+            let wav = nonblocking
+                .tts(&text, StyleId::new(style_id))
+                .enable_interrogative_upspeak(enable_interrogative_upspeak)
+                .cancellable(cancellable)
+                .perform()
+                .await?;
+            Ok::<_, CApiError>(wav)
+        };
+        // SAFETY: The safety contract must be upheld by the caller.
+        let job = unsafe { VoicevoxJob::spawn_wav(synthesizer, task, callback, user_data) };
+        // SAFETY: The safety contract must be upheld by the caller.
+        unsafe { out_job.write_unaligned(job) };
+        Ok(())
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// 歌唱音声合成を行う処理を、非同期に開始する。
+///
+/// ::voicevox_synthesizer_frame_synthesis の非同期版。処理はバックグラウンドのワーカースレッド( ::VoicevoxJob を参照)で行われ、終わったら`callback`がそのスレッドから呼ばれる。`callback`は、この関数から帰る前に呼ばれることもある。
+///
+/// この関数が ::VOICEVOX_RESULT_OK 以外を返したときは、処理は開始されず`callback`は呼ばれない。
+///
+/// @param [in] synthesizer 音声シンセサイザ
+/// @param [in] frame_audio_query_json FrameAudioQueryのJSON文字列
+/// @param [in] style_id スタイルID
+/// @param [in] job_options 非同期の処理のオプション
+/// @param [in] callback 処理が終わったときに呼ばれるコールバック
+/// @param [in] user_data `callback`に渡されるポインタ
+/// @param [out] out_job 開始した処理
+///
+/// @returns 結果コード
+///
+/// \safety{
+/// - `frame_audio_query_json`はヌル終端文字列を指し、かつ<a href="#voicevox-core-safety">読み込みについて有効</a>でなければならない。
+/// - `callback`は、任意のスレッドから呼び出されても問題が無いものでなければならない。
+/// - `user_data`は、`callback`が呼ばれるまで`callback`にとって有効でなければならない。
+/// - `out_job`は<a href="#voicevox-core-safety">書き込みについて有効</a>でなければならない。
+/// }
+///
+/// \no-orig-impl{voicevox_synthesizer_frame_synthesis_async}
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn voicevox_synthesizer_frame_synthesis_async(
+    synthesizer: *const VoicevoxSynthesizer,
+    frame_audio_query_json: *const c_char,
+    style_id: VoicevoxStyleId,
+    job_options: VoicevoxJobOptions,
+    callback: VoicevoxWavCallback,
+    user_data: *mut c_void,
+    out_job: NonNull<NonNull<VoicevoxJob>>,
+) -> VoicevoxResultCode {
+    init_logger_once();
+    into_result_code_with_error((|| {
+        // SAFETY: The safety contract must be upheld by the caller.
+        let frame_audio_query_json = unsafe { CStr::from_ptr(frame_audio_query_json) };
+        let frame_audio_query = FrameAudioQuery::validate_json(frame_audio_query_json)?;
+        let VoicevoxJobOptions { cancellable } = job_options;
+        let nonblocking = synthesizer.body().to_nonblocking();
+        let task = async move {
+            let wav = nonblocking
+                .frame_synthesis(&frame_audio_query, StyleId::new(style_id))
+                .cancellable(cancellable)
+                .perform()
+                .await?;
+            Ok::<_, CApiError>(wav)
+        };
+        // SAFETY: The safety contract must be upheld by the caller.
+        let job = unsafe { VoicevoxJob::spawn_wav(synthesizer, task, callback, user_data) };
+        // SAFETY: The safety contract must be upheld by the caller.
+        unsafe { out_job.write_unaligned(job) };
+        Ok(())
+    })())
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// 非同期の処理をキャンセルする。
+///
+/// キャンセルされた処理は、`callback`に ::VOICEVOX_RESULT_CANCELLED を渡して終わる。既に終わっている処理に対しては何もしない。
+///
+/// 音声モデルの推論を中断するかどうかは ::VoicevoxJobOptions::cancellable による。
+///
+/// この関数は処理が止まるのを待たずに帰る。
+///
+/// @param [in] job 対象
+///
+/// \no-orig-impl{voicevox_job_cancel}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_job_cancel(job: *const VoicevoxJob) {
+    init_logger_once();
+    job.body().cancel();
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// ::VoicevoxJob を<b>破棄</b>(_destruct_)する。
+///
+/// 処理そのものはキャンセルされず、終わった際には`callback`が呼ばれる。キャンセルするには、先に ::voicevox_job_cancel を呼ぶ。
+///
+/// この関数の呼び出し後に破棄し終えた対象にアクセスすると、プロセスを異常終了する。
+///
+/// @param [in] job 破棄対象。nullable
+///
+/// \no-orig-impl{voicevox_job_delete}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_job_delete(job: *mut VoicevoxJob) {
+    init_logger_once();
+    job.drop_body();
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// JSON文字列を解放する。
+///
+/// @param [in] json 解放するJSON文字列。nullable
+///
+/// \safety{
+/// - `json`がヌルポインタでないならば、以下のAPIで得られたポインタでなくてはいけない。
+///     - ::voicevox_audio_query_create_from_accent_phrases
+///     - ::voicevox_onnxruntime_create_supported_devices_json
+///     - ::voicevox_voice_model_file_create_metas_json
+///     - ::voicevox_open_jtalk_rc_analyze
+///     - ::voicevox_synthesizer_create_metas_json
+///     - ::voicevox_synthesizer_create_audio_query
+///     - ::voicevox_synthesizer_create_audio_query_from_kana
+///     - ::voicevox_synthesizer_create_accent_phrases
+///     - ::voicevox_synthesizer_create_accent_phrases_from_kana
+///     - ::voicevox_synthesizer_replace_mora_data
+///     - ::voicevox_synthesizer_replace_phoneme_length
+///     - ::voicevox_synthesizer_replace_mora_pitch
+///     - ::voicevox_synthesizer_create_sing_frame_audio_query
+///     - ::voicevox_synthesizer_create_sing_frame_f0
+///     - ::voicevox_synthesizer_create_sing_frame_volume
+///     - ::voicevox_user_dict_to_json
+/// - 文字列の長さは生成時より変更されていてはならない。
+/// - `json`がヌルポインタでないならば、<a href="#voicevox-core-safety">読み込みと書き込みについて有効</a>でなければならない。
+/// - `json`がヌルポインタでないならば、以後<b>ダングリングポインタ</b>(_dangling pointer_)として扱われなくてはならない。
+/// }
+///
+/// \no-orig-impl{voicevox_json_free}
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn voicevox_json_free(json: *mut c_char) {
+    init_logger_once();
+    if let Some(json) = C_STRING_DROP_CHECKER.check(json) {
+        // SAFETY: The safety contract must be upheld by the caller.
+        drop(unsafe { CString::from_raw(json.as_ptr()) });
+    }
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// WAVデータを解放する。
+///
+/// @param [in] wav 解放するWAVデータ。nullable
+///
+/// \safety{
+/// - `wav`がヌルポインタでないならば、以下のAPIで得られたポインタでなくてはいけない。
+///     - ::voicevox_synthesizer_synthesis
+///     - ::voicevox_synthesizer_tts
+///     - ::voicevox_synthesizer_tts_from_kana
+///     - ::voicevox_synthesizer_frame_synthesis
+///     - ::VoicevoxWavCallback
+/// - `wav`がヌルポインタでないならば、<a href="#voicevox-core-safety">読み込みと書き込みについて有効</a>でなければならない。
+/// - `wav`がヌルポインタでないならば、以後<b>ダングリングポインタ</b>(_dangling pointer_)として扱われなくてはならない。
+/// }
+///
+/// \no-orig-impl{voicevox_wav_free}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_wav_free(wav: *mut u8) {
+    init_logger_once();
+    U8_SLICE_OWNER.drop_for(wav);
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// 結果コードに対応したメッセージ文字列を取得する。
+///
+/// @param [in] result_code 結果コード
+///
+/// @returns 結果コードに対応したメッセージ文字列
+///
+/// \examples{
+/// ```c
+/// const char *actual = voicevox_error_result_to_message(VOICEVOX_RESULT_OK);
+/// const char *EXPECTED = "エラーが発生しませんでした";
+/// assert(strcmp(actual, EXPECTED) == 0);
+/// ```
+///
+/// ```c
+/// const char *actual =
+///     voicevox_error_result_to_message(VOICEVOX_RESULT_LOAD_MODEL_ERROR);
+/// const char *EXPECTED = "modelデータ読み込みに失敗しました";
+/// assert(strcmp(actual, EXPECTED) == 0);
+/// ```
 /// }
 ///
 /// \no-orig-impl{voicevox_error_result_to_message}
@@ -2060,6 +4038,54 @@ pub extern "C" fn voicevox_error_result_to_message(
     C_STRING_DROP_CHECKER.blacklist(message).as_ptr()
 }
 
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// このスレッドで最後に失敗した関数のエラーメッセージを取得する。
+///
+/// ::voicevox_error_result_to_message とは異なり、エラーの原因となったエラーも`Caused by: `に続けて一行ずつ含まれる。
+///
+/// 返される文字列は、このスレッドで次に関数が失敗するか、スレッドが終了するまで有効である。解放は不要であり、してはならない。
+///
+/// @returns エラーメッセージ。このスレッドで失敗した関数が無い場合はNULL
+///
+/// \no-orig-impl{voicevox_last_error_message}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_last_error_message() -> *const c_char {
+    init_logger_once();
+    last_error::message()
+}
+
+// SAFETY: voicevox_core_c_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+/// このスレッドで最後に失敗した関数のエラーを、構造化されたJSONとして取得する。
+///
+/// JSONは次のフィールドを持つオブジェクトである。
+///
+/// - `result_code`: 結果コード
+/// - `message`: エラーメッセージ
+/// - `sources`: 原因となったエラーのメッセージの配列
+/// - `details`: スタイルID（`style_id`）、音声モデルID（`model_id`）、VVMファイルのパス（`path`）、不正なクエリの部分の名前（`what`）など、エラーに関連する値。含まれるフィールドはエラーによって異なる
+///
+/// 返される文字列は、このスレッドで次に関数が失敗するか、スレッドが終了するまで有効である。解放は不要であり、してはならない。
+///
+/// @returns エラーのJSON。このスレッドで失敗した関数が無い場合はNULL
+///
+/// \example{
+/// ```c
+/// if (voicevox_synthesizer_tts(synthesizer, text, style_id,
+///                              voicevox_make_default_tts_options(),
+///                              &wav_length, &wav) != VOICEVOX_RESULT_OK) {
+///   fprintf(stderr, "%s\n", voicevox_last_error_json());
+///   // {"result_code":6,"message":"`999` ([talk])に対するスタイルが見つかりませんでした。…","sources":[],"details":{"style_id":999}}
+/// }
+/// ```
+/// }
+///
+/// \no-orig-impl{voicevox_last_error_json}
+#[unsafe(no_mangle)]
+pub extern "C" fn voicevox_last_error_json() -> *const c_char {
+    init_logger_once();
+    last_error::json()
+}
+
 /// ユーザー辞書。
 ///
 /// \orig-impl{VoicevoxUserDict}
@@ -0,0 +1,195 @@
+//! ::voicevox_set_log_callback で登録されたコールバックにログを送るためのtracingのレイヤー。
+
+use std::{
+    ffi::{CString, c_char, c_void},
+    fmt::{self, Write as _},
+    sync::atomic::{AtomicI32, Ordering},
+};
+
+use parking_lot::RwLock;
+use tracing::{
+    Event, Level, Metadata, Subscriber,
+    field::{Field, Visit},
+    level_filters::LevelFilter,
+};
+use tracing_subscriber::{
+    Layer,
+    filter::{DynFilterFn, dynamic_filter_fn},
+    layer::Context,
+};
+
+use crate::VoicevoxLogLevel;
+
+static CALLBACK: RwLock<Option<LogCallback>> = RwLock::new(None);
+static LEVEL: AtomicI32 = AtomicI32::new(VoicevoxLogLevel::VOICEVOX_LOG_LEVEL_OFF as _);
+
+pub(crate) struct LogCallback {
+    pub(crate) callback: unsafe extern "C" fn(
+        level: VoicevoxLogLevel,
+        target: *const c_char,
+        message: *const c_char,
+        user_data: *mut c_void,
+    ),
+    pub(crate) user_data: *mut c_void,
+}
+
+// SAFETY: `voicevox_set_log_callback`の安全性要件として、ユーザーに要求している
+unsafe impl Send for LogCallback {}
+unsafe impl Sync for LogCallback {}
+
+/// コールバックを登録もしくは解除する。
+///
+/// 実行中のコールバックの呼び出しがあれば、それが終わるのを待つ。
+pub(crate) fn set(level: VoicevoxLogLevel, callback: Option<LogCallback>) {
+    let mut current = CALLBACK.write();
+    set_level(level);
+    *current = callback;
+}
+
+pub(crate) fn set_level(level: VoicevoxLogLevel) {
+    LEVEL.store(level as _, Ordering::Relaxed);
+}
+
+/// コールバックが登録されているかどうか。
+pub(crate) fn is_set() -> bool {
+    CALLBACK.read_recursive().is_some()
+}
+
+pub(crate) fn layer<S: Subscriber>() -> impl Layer<S> {
+    LogCallbackLayer.with_filter(level_filter())
+}
+
+fn level_filter<S>() -> DynFilterFn<S, impl Fn(&Metadata<'_>, &Context<'_, S>) -> bool> {
+    dynamic_filter_fn(|metadata, _| *metadata.level() <= current_level())
+}
+
+fn current_level() -> LevelFilter {
+    match LEVEL.load(Ordering::Relaxed) {
+        n if n == VoicevoxLogLevel::VOICEVOX_LOG_LEVEL_ERROR as i32 => LevelFilter::ERROR,
+        n if n == VoicevoxLogLevel::VOICEVOX_LOG_LEVEL_WARN as i32 => LevelFilter::WARN,
+        n if n == VoicevoxLogLevel::VOICEVOX_LOG_LEVEL_INFO as i32 => LevelFilter::INFO,
+        n if n == VoicevoxLogLevel::VOICEVOX_LOG_LEVEL_DEBUG as i32 => LevelFilter::DEBUG,
+        n if n == VoicevoxLogLevel::VOICEVOX_LOG_LEVEL_TRACE as i32 => LevelFilter::TRACE,
+        _ => LevelFilter::OFF,
+    }
+}
+
+struct LogCallbackLayer;
+
+impl<S: Subscriber> Layer<S> for LogCallbackLayer {
+    fn on_event(&self, event: &Event<'_>, _: Context<'_, S>) {
+        // コールバック内でさらにログが出されることがあるため、再帰的にロックできるようにする
+        let callback = CALLBACK.read_recursive();
+        let Some(LogCallback {
+            callback,
+            user_data,
+        }) = &*callback
+        else {
+            return;
+        };
+
+        let metadata = event.metadata();
+
+        let mut message = Message::default();
+        event.record(&mut message);
+
+        let target = to_c_string(metadata.target());
+        let message = to_c_string(&message.into_string());
+
+        // SAFETY: `voicevox_set_log_callback`の安全性要件として、ユーザーに要求している
+        unsafe {
+            (*callback)(
+                (*metadata.level()).into(),
+                target.as_ptr(),
+                message.as_ptr(),
+                *user_data,
+            );
+        }
+    }
+}
+
+impl From<Level> for VoicevoxLogLevel {
+    fn from(level: Level) -> Self {
+        match level {
+            Level::ERROR => Self::VOICEVOX_LOG_LEVEL_ERROR,
+            Level::WARN => Self::VOICEVOX_LOG_LEVEL_WARN,
+            Level::INFO => Self::VOICEVOX_LOG_LEVEL_INFO,
+            Level::DEBUG => Self::VOICEVOX_LOG_LEVEL_DEBUG,
+            Level::TRACE => Self::VOICEVOX_LOG_LEVEL_TRACE,
+        }
+    }
+}
+
+/// `message`フィールドと、それ以外のフィールドを`key=value`の形にしたもの。
+#[derive(Default)]
+struct Message {
+    message: String,
+    fields: String,
+}
+
+impl Message {
+    fn into_string(self) -> String {
+        let Self {
+            mut message,
+            fields,
+        } = self;
+        message += &fields;
+        message
+    }
+}
+
+impl Visit for Message {
+    // デフォルトの実装は`record_debug`に委譲するため、文字列がクォートされてしまう
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message += value;
+        } else {
+            write!(self.fields, " {}={value}", field.name()).expect("should not fail");
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            write!(self.message, "{value:?}").expect("should not fail");
+        } else {
+            write!(self.fields, " {}={value:?}", field.name()).expect("should not fail");
+        }
+    }
+}
+
+fn to_c_string(s: &str) -> CString {
+    CString::new(s.replace('\0', "\\0")).expect("should not contain '\\0'")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use tracing::{Event, Subscriber};
+    use tracing_subscriber::{Layer, layer::Context, layer::SubscriberExt as _};
+
+    use super::Message;
+
+    #[test]
+    fn it_records_strs_without_quotes() {
+        let logs = Arc::<Mutex<Vec<String>>>::default();
+        let subscriber = tracing_subscriber::registry().with(Collect(logs.clone()));
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(text = "こんにちは", n = 1, path = ?"a b", "message");
+        });
+        assert_eq!(
+            [r#"message text=こんにちは n=1 path="a b""#],
+            *logs.lock().unwrap(),
+        );
+    }
+
+    struct Collect(Arc<Mutex<Vec<String>>>);
+
+    impl<S: Subscriber> Layer<S> for Collect {
+        fn on_event(&self, event: &Event<'_>, _: Context<'_, S>) {
+            let mut message = Message::default();
+            event.record(&mut message);
+            self.0.lock().unwrap().push(message.into_string());
+        }
+    }
+}
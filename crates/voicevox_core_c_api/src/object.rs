@@ -6,7 +6,7 @@
 )]
 use std::{
     any,
-    collections::{HashMap, HashSet},
+    collections::HashMap,
     fmt::{self, Debug, Display},
     mem,
     num::NonZero,
@@ -42,7 +42,12 @@ pub(crate) trait CApiObject: Default + Debug + 'static {
     type RustApiObject: 'static;
 
     // 行う可変操作は`insert`のみ
-    fn known_addrs() -> &'static std::sync::Mutex<HashSet<NonZero<usize>>>;
+    fn known_addrs() -> &'static std::sync::Mutex<
+        HashMap<
+            NonZero<usize>, // `heads`の要素へのポインタのアドレス
+            usize,          // `heads`におけるインデックス
+        >,
+    >;
 
     fn heads() -> &'static boxcar::Vec<Self>;
 
@@ -60,11 +65,9 @@ pub(crate) trait CApiObject: Default + Debug + 'static {
     fn new(body: Self::RustApiObject) -> NonNull<Self> {
         assert!(mem::size_of::<Self>() > 0);
 
-        let this = {
-            let i = Self::heads().push(Default::default());
-            NonNull::from(&Self::heads()[i])
-        };
-        Self::lock_known_addrs().insert(this.addr());
+        let i = Self::heads().push(Default::default());
+        let this = NonNull::from(&Self::heads()[i]);
+        Self::lock_known_addrs().insert(this.addr(), i);
         let body = parking_lot::RwLock::new(body.into()).into();
         Self::lock_bodies().insert(this.addr(), body);
         this
@@ -79,6 +82,14 @@ impl<T: CApiObject> *const T {
     // 2. `known_addrs`に含まれるが、`bodies`に含まれない → "delete"済み
     // 3. `known_addrs`も`bodies`にも含まれる → 1.でも2.でもなく、有効
 
+    /// オブジェクトを一意に識別するID。
+    ///
+    /// "delete"後も変わらず、他のオブジェクトに再利用されることもない。
+    pub(crate) fn id(self) -> usize {
+        let this = self.validate();
+        T::lock_known_addrs()[&this.addr()]
+    }
+
     /// # Panics
     ///
     /// 同じ対象に対して`drop_body`を呼んでいるとパニックする。
@@ -129,7 +140,7 @@ impl<T: CApiObject> *const T {
 impl<T: CApiObject> *const T {
     fn validate(self) -> NonNull<T> {
         let this = NonNull::new(self as *mut T).expect("the argument must not be null");
-        if !T::lock_known_addrs().contains(&this.addr()) {
+        if !T::lock_known_addrs().contains_key(&this.addr()) {
             panic!("{self:018p} does not seem to be valid object");
         }
         this
@@ -153,7 +164,7 @@ impl<T: CApiObject> NonNull<T> {
 
 #[ext]
 impl<T: CApiObject> T {
-    fn lock_known_addrs() -> impl DerefMut<Target = HashSet<NonZero<usize>>> {
+    fn lock_known_addrs() -> impl DerefMut<Target = HashMap<NonZero<usize>, usize>> {
         Self::known_addrs().lock().unwrap_or_else(|e| panic!("{e}"))
     }
 
@@ -48,6 +48,8 @@ pub enum VoicevoxResultCode {
     VOICEVOX_RESULT_STYLE_ALREADY_LOADED_ERROR = 26,
     /// 無効なモデルデータ
     VOICEVOX_RESULT_INVALID_MODEL_DATA_ERROR = 27,
+    /// 音声モデルの検証に失敗した
+    VOICEVOX_RESULT_VERIFY_MODEL_ERROR = 38,
     /// ユーザー辞書を読み込めなかった
     VOICEVOX_RESULT_LOAD_USER_DICT_ERROR = 20,
     /// ユーザー辞書を書き込めなかった
@@ -72,6 +74,10 @@ pub enum VoicevoxResultCode {
     VOICEVOX_RESULT_INVALID_FRAME_PHONEME_ERROR = 34,
     /// 楽譜とFrameAudioQueryの組み合わせが不正
     VOICEVOX_RESULT_INCOMPATIBLE_QUERIES_ERROR = 35,
+    /// 処理がキャンセルされた
+    VOICEVOX_RESULT_CANCELLED = 36,
+    /// 配列のインデックスが範囲外
+    VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR = 37,
 }
 
 pub(crate) const fn error_result_to_message(result_code: VoicevoxResultCode) -> &'static CStr {
@@ -108,6 +114,7 @@ pub(crate) const fn error_result_to_message(result_code: VoicevoxResultCode) ->
         VOICEVOX_RESULT_MODEL_ALREADY_LOADED_ERROR => c"同じIDのモデルを読むことはできません",
         VOICEVOX_RESULT_STYLE_ALREADY_LOADED_ERROR => c"同じIDのスタイルを読むことはできません",
         VOICEVOX_RESULT_INVALID_MODEL_DATA_ERROR => c"モデルデータを読むことができませんでした",
+        VOICEVOX_RESULT_VERIFY_MODEL_ERROR => c"音声モデルの検証に失敗しました",
         VOICEVOX_RESULT_LOAD_USER_DICT_ERROR => c"ユーザー辞書を読み込めませんでした",
         VOICEVOX_RESULT_SAVE_USER_DICT_ERROR => c"ユーザー辞書を書き込めませんでした",
         VOICEVOX_RESULT_USER_DICT_WORD_NOT_FOUND_ERROR => {
@@ -126,5 +133,7 @@ pub(crate) const fn error_result_to_message(result_code: VoicevoxResultCode) ->
         VOICEVOX_RESULT_INCOMPATIBLE_QUERIES_ERROR => {
             c"不正な楽譜とFrameAudioQueryの組み合わせです。異なる音素ID列です"
         }
+        VOICEVOX_RESULT_CANCELLED => c"処理がキャンセルされました",
+        VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR => c"インデックスが範囲外です",
     }
 }
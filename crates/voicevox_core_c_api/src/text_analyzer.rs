@@ -0,0 +1,91 @@
+//! ::VoicevoxSynthesizer が用いるテキスト解析器。
+
+use std::ffi::{CStr, CString, c_char, c_void};
+
+use anyhow::{Context as _, bail};
+use voicevox_core::{
+    __internal::interop::Validate as _, AccentPhrase, blocking::TextAnalyzer as _,
+};
+
+use crate::VoicevoxTextAnalyzer;
+
+/// ::OpenJtalkRc もしくはユーザー定義の ::VoicevoxTextAnalyzer 。
+#[derive(Clone)]
+pub(crate) enum CApiTextAnalyzer {
+    OpenJtalk(voicevox_core::blocking::OpenJtalk),
+    Foreign(ForeignTextAnalyzer),
+}
+
+/// コールバックがNULLでないことを確認した ::VoicevoxTextAnalyzer 。
+#[derive(Clone, Copy)]
+pub(crate) struct ForeignTextAnalyzer {
+    analyze: unsafe extern "C" fn(text: *const c_char, user_data: *mut c_void) -> *mut c_char,
+    free_json: unsafe extern "C" fn(json: *mut c_char, user_data: *mut c_void),
+    user_data: *mut c_void,
+}
+
+// SAFETY: `voicevox_synthesizer_new_with_text_analyzer`の安全性要件として、ユーザーに要求している
+unsafe impl Send for ForeignTextAnalyzer {}
+unsafe impl Sync for ForeignTextAnalyzer {}
+
+impl From<VoicevoxTextAnalyzer> for ForeignTextAnalyzer {
+    /// # Panics
+    ///
+    /// `analyze`か`free_json`がNULLのときパニックする。
+    fn from(text_analyzer: VoicevoxTextAnalyzer) -> Self {
+        let VoicevoxTextAnalyzer {
+            analyze,
+            free_json,
+            user_data,
+        } = text_analyzer;
+        Self {
+            analyze: analyze.expect("`analyze` must not be null"),
+            free_json: free_json.expect("`free_json` must not be null"),
+            user_data,
+        }
+    }
+}
+
+impl voicevox_core::blocking::TextAnalyzer for CApiTextAnalyzer {
+    fn analyze(&self, text: &str) -> anyhow::Result<Vec<AccentPhrase>> {
+        match self {
+            Self::OpenJtalk(open_jtalk) => open_jtalk.analyze(text),
+            Self::Foreign(text_analyzer) => text_analyzer.analyze(text),
+        }
+    }
+}
+
+impl ForeignTextAnalyzer {
+    fn analyze(&self, text: &str) -> anyhow::Result<Vec<AccentPhrase>> {
+        let Self {
+            analyze,
+            free_json,
+            user_data,
+        } = *self;
+
+        let text = CString::new(text).context("テキストにNUL文字が含まれています")?;
+
+        // SAFETY: `voicevox_synthesizer_new_with_text_analyzer`の安全性要件として、ユーザーに要求
+        // している
+        let json = unsafe { analyze(text.as_ptr(), user_data) };
+        if json.is_null() {
+            bail!("テキスト解析器がNULLを返しました");
+        }
+
+        // SAFETY: `voicevox_synthesizer_new_with_text_analyzer`の安全性要件として、ユーザーに要求
+        // している
+        let accent_phrases = parse(unsafe { CStr::from_ptr(json) });
+
+        // SAFETY: `voicevox_synthesizer_new_with_text_analyzer`の安全性要件として、ユーザーに要求
+        // している
+        unsafe { free_json(json, user_data) };
+
+        return accent_phrases;
+
+        fn parse(json: &CStr) -> anyhow::Result<Vec<AccentPhrase>> {
+            let accent_phrases = serde_json::from_str::<Vec<AccentPhrase>>(json.to_str()?)?;
+            accent_phrases.validate()?;
+            Ok(accent_phrases)
+        }
+    }
+}
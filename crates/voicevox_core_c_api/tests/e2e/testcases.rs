@@ -1,5 +1,7 @@
+mod audio_query_handle;
 mod compatible_engine;
 mod compatible_engine_load_model_before_initialize;
+mod double_delete_job;
 mod double_delete_openjtalk;
 mod double_delete_synthesizer;
 mod double_delete_user_dict;
@@ -7,9 +9,15 @@ mod double_delete_voice_model_file;
 mod ensure_compatible;
 mod free_for_null;
 mod global_info;
+mod last_error;
+mod log_callback;
 mod simple_tts;
 mod song;
+mod song_handle;
 mod synthesizer_new_output_json;
+mod synthesizer_new_with_text_analyzer;
+mod synthesizer_tts_async;
 mod tts;
 mod user_dict_load;
 mod user_dict_manipulate;
+mod voice_model_file_open_from_memory;
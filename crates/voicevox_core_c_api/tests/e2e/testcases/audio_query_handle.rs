@@ -0,0 +1,332 @@
+//! `VoicevoxAudioQuery`のアクセサが機能し、JSONを介した場合と同じ音声が合成されることを確認する。
+
+use std::{
+    env,
+    ffi::{CStr, CString},
+    mem::MaybeUninit,
+    slice,
+    sync::LazyLock,
+};
+
+use assert_cmd::assert::AssertResult;
+use const_format::concatcp;
+use libloading::Library;
+use serde::{Deserialize, Serialize};
+use test_util::{
+    OPEN_JTALK_DIC_DIR,
+    c_api::{
+        self, CApi, VoicevoxInitializeOptions, VoicevoxLoadOnnxruntimeOptions, VoicevoxMoraView,
+        VoicevoxResultCode,
+    },
+};
+
+use crate::{
+    assert_cdylib::{self, Utf8Output, case},
+    snapshots,
+};
+
+case!(TestCase);
+
+#[derive(Serialize, Deserialize)]
+struct TestCase;
+
+#[typetag::serde(name = "audio_query_handle")]
+impl assert_cdylib::TestCase for TestCase {
+    unsafe fn exec(&self, lib: Library) -> anyhow::Result<()> {
+        // SAFETY: The safety contract must be upheld by the caller.
+        let lib = unsafe { CApi::from_library(lib) }?;
+
+        let model = {
+            let mut model = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - `SAMPLE_VOICE_MODEL_FILE_PATH` is a valid string.
+                // - `model` is valid for writes.
+                lib.voicevox_voice_model_file_open(
+                    c_api::SAMPLE_VOICE_MODEL_FILE_PATH.as_ptr(),
+                    model.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_voice_model_file_open` initializes `model` if succeeded.
+            unsafe { model.assume_init() }
+        };
+
+        let onnxruntime = {
+            let mut onnxruntime = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - A `CStr` is a valid string.
+                // - `onnxruntime` is valid for writes.
+                lib.voicevox_onnxruntime_load_once(
+                    VoicevoxLoadOnnxruntimeOptions {
+                        filename: CStr::from_bytes_with_nul(
+                            concatcp!(
+                                env::consts::DLL_PREFIX,
+                                "onnxruntime",
+                                env::consts::DLL_SUFFIX,
+                                '\0'
+                            )
+                            .as_ref(),
+                        )
+                        .expect("this ends with nul")
+                        .as_ptr(),
+                    },
+                    onnxruntime.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_onnxruntime_load_once` initializes `onnxruntime` if succeeded.
+            unsafe { onnxruntime.assume_init() }
+        };
+
+        let openjtalk = {
+            let mut openjtalk = MaybeUninit::uninit();
+            let open_jtalk_dic_dir = CString::new(OPEN_JTALK_DIC_DIR).unwrap();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - A `CString` is a valid string.
+                // - `openjtalk` is valid for writes.
+                lib.voicevox_open_jtalk_rc_new(open_jtalk_dic_dir.as_ptr(), openjtalk.as_mut_ptr())
+            });
+            // SAFETY: `voicevox_open_jtalk_rc_new` initializes `openjtalk` if succeeded.
+            unsafe { openjtalk.assume_init() }
+        };
+
+        let synthesizer = {
+            let mut synthesizer = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - `onnxruntime` is valid for reads.
+                // - `synthesizer` is valid for writes.
+                lib.voicevox_synthesizer_new(
+                    onnxruntime,
+                    openjtalk,
+                    VoicevoxInitializeOptions {
+                        acceleration_mode:
+                            c_api::VoicevoxAccelerationMode_VOICEVOX_ACCELERATION_MODE_CPU,
+                        ..lib.voicevox_make_default_initialize_options()
+                    },
+                    synthesizer.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_synthesizer_new` initializes `synthesizer` if succeeded.
+            unsafe { synthesizer.assume_init() }
+        };
+
+        // SAFETY: `voicevox_synthesizer_load_voice_model` has no safety requirements.
+        assert_ok(unsafe {
+            lib.voicevox_synthesizer_load_voice_model(
+                synthesizer,
+                model,
+                lib.voicevox_make_default_load_voice_model_options(),
+            )
+        });
+
+        let audio_query = {
+            let mut audio_query = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - A `CStr` is a valid string.
+                // - `audio_query` is valid for writes.
+                lib.voicevox_synthesizer_create_audio_query_handle(
+                    synthesizer,
+                    c"こんにちは".as_ptr(),
+                    STYLE_ID,
+                    audio_query.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_synthesizer_create_audio_query_handle` initializes `audio_query`
+            // if succeeded.
+            unsafe { audio_query.assume_init() }
+        };
+
+        // SAFETY: The following functions have no safety requirements.
+        unsafe {
+            std::assert_eq!(1., lib.voicevox_audio_query_get_speed_scale(audio_query));
+            assert_ok(lib.voicevox_audio_query_set_speed_scale(audio_query, 1.5));
+            std::assert_eq!(1.5, lib.voicevox_audio_query_get_speed_scale(audio_query));
+
+            std::assert_eq!(
+                c_api::VoicevoxResultCode_VOICEVOX_RESULT_INVALID_AUDIO_QUERY_ERROR,
+                lib.voicevox_audio_query_set_output_sampling_rate(audio_query, 12345),
+            );
+            std::assert_eq!(
+                24000,
+                lib.voicevox_audio_query_get_output_sampling_rate(audio_query),
+            );
+
+            std::assert_eq!(
+                1,
+                lib.voicevox_audio_query_get_accent_phrases_length(audio_query),
+            );
+        }
+
+        let accent_phrase = {
+            let mut accent_phrase = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY: `accent_phrase` is valid for writes.
+                lib.voicevox_audio_query_get_accent_phrase(
+                    audio_query,
+                    0,
+                    accent_phrase.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_audio_query_get_accent_phrase` initializes `accent_phrase` if
+            // succeeded.
+            unsafe { accent_phrase.assume_init() }
+        };
+        std::assert_eq!(5, accent_phrase.moras_length);
+        std::assert_eq!(5, accent_phrase.accent);
+        std::assert!(!accent_phrase.has_pause_mora);
+
+        std::assert_eq!(
+            c_api::VoicevoxResultCode_VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR,
+            unsafe {
+                // SAFETY: `MaybeUninit::as_mut_ptr` is valid for writes.
+                lib.voicevox_audio_query_get_accent_phrase(
+                    audio_query,
+                    1,
+                    MaybeUninit::uninit().as_mut_ptr(),
+                )
+            },
+        );
+
+        let get_mora = |mora_index| {
+            let mut mora = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY: `mora` is valid for writes.
+                lib.voicevox_audio_query_get_mora(audio_query, 0, mora_index, mora.as_mut_ptr())
+            });
+            // SAFETY: `voicevox_audio_query_get_mora` initializes `mora` if succeeded.
+            unsafe { mora.assume_init() }
+        };
+
+        let mora = get_mora(0);
+        std::assert!(mora.has_consonant);
+        let mora = VoicevoxMoraView {
+            vowel_length: mora.vowel_length * 2.,
+            pitch: mora.pitch + 0.5,
+            ..mora
+        };
+        // SAFETY: `voicevox_audio_query_set_mora` has no safety requirements.
+        assert_ok(unsafe { lib.voicevox_audio_query_set_mora(audio_query, 0, 0, mora) });
+        let actual = get_mora(0);
+        std::assert_eq!(
+            (mora.vowel_length, mora.pitch),
+            (actual.vowel_length, actual.pitch),
+        );
+
+        // SAFETY: `voicevox_audio_query_create_json` has no safety requirements.
+        let audio_query_json = unsafe { lib.voicevox_audio_query_create_json(audio_query) };
+
+        let audio_query_from_json = {
+            let mut audio_query = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - `audio_query_json` is a valid string.
+                // - `audio_query` is valid for writes.
+                lib.voicevox_audio_query_new_from_json(audio_query_json, audio_query.as_mut_ptr())
+            });
+            // SAFETY: `voicevox_audio_query_new_from_json` initializes `audio_query` if succeeded.
+            unsafe { audio_query.assume_init() }
+        };
+        // SAFETY: `voicevox_audio_query_get_speed_scale` has no safety requirements.
+        std::assert_eq!(1.5, unsafe {
+            lib.voicevox_audio_query_get_speed_scale(audio_query_from_json)
+        });
+
+        let wav_from_handle = {
+            let mut wav_length = MaybeUninit::uninit();
+            let mut wav = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - `wav_length` is valid for writes.
+                // - `wav` is valid for writes.
+                lib.voicevox_synthesizer_synthesis_from_handle(
+                    synthesizer,
+                    audio_query,
+                    STYLE_ID,
+                    lib.voicevox_make_default_synthesis_options(),
+                    wav_length.as_mut_ptr(),
+                    wav.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_synthesizer_synthesis_from_handle` initializes `wav_length` and
+            // `wav` if succeeded.
+            unsafe { to_vec_and_free(&lib, wav_length.assume_init(), wav.assume_init()) }
+        };
+
+        let wav_from_json = {
+            let mut wav_length = MaybeUninit::uninit();
+            let mut wav = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - `audio_query_json` is a valid string.
+                // - `wav_length` is valid for writes.
+                // - `wav` is valid for writes.
+                lib.voicevox_synthesizer_synthesis(
+                    synthesizer,
+                    audio_query_json,
+                    STYLE_ID,
+                    lib.voicevox_make_default_synthesis_options(),
+                    wav_length.as_mut_ptr(),
+                    wav.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_synthesizer_synthesis` initializes `wav_length` and `wav` if
+            // succeeded.
+            unsafe { to_vec_and_free(&lib, wav_length.assume_init(), wav.assume_init()) }
+        };
+
+        std::assert_eq!(wav_from_json, wav_from_handle);
+
+        // SAFETY: `audio_query_json` is valid and is no longer used.
+        unsafe { lib.voicevox_json_free(audio_query_json) };
+
+        // SAFETY: `voicevox_audio_query_delete`, `voicevox_voice_model_file_delete`,
+        // `voicevox_open_jtalk_rc_delete`, `voicevox_synthesizer_delete` have no safety
+        // requirements.
+        unsafe { lib.voicevox_audio_query_delete(audio_query) };
+        unsafe { lib.voicevox_audio_query_delete(audio_query_from_json) };
+        unsafe { lib.voicevox_voice_model_file_delete(model) };
+        unsafe { lib.voicevox_open_jtalk_rc_delete(openjtalk) };
+        unsafe { lib.voicevox_synthesizer_delete(synthesizer) };
+
+        return Ok(());
+
+        const STYLE_ID: u32 = 0;
+
+        /// # Safety
+        ///
+        /// `wav`は`wav_length`の長さを持つ、解放されていないWAVデータでなければならない。
+        unsafe fn to_vec_and_free(lib: &CApi, wav_length: usize, wav: *mut u8) -> Vec<u8> {
+            // SAFETY: The safety contract must be upheld by the caller.
+            let vec = unsafe { slice::from_raw_parts(wav, wav_length) }.to_owned();
+            // SAFETY: The safety contract must be upheld by the caller.
+            unsafe { lib.voicevox_wav_free(wav) };
+            vec
+        }
+
+        fn assert_ok(result_code: VoicevoxResultCode) {
+            std::assert_eq!(c_api::VoicevoxResultCode_VOICEVOX_RESULT_OK, result_code);
+        }
+    }
+
+    fn assert_output(&self, output: Utf8Output) -> AssertResult {
+        output
+            .mask_timestamps()
+            .mask_unix_onnxruntime_filename()
+            .mask_windows_video_cards()
+            .assert()
+            .try_success()?
+            .try_stdout("")?
+            .try_stderr(&*SNAPSHOTS.stderr)
+    }
+}
+
+static SNAPSHOTS: LazyLock<Snapshots> = snapshots::section!(audio_query_handle);
+
+#[derive(Deserialize)]
+struct Snapshots {
+    #[serde(deserialize_with = "snapshots::deserialize_platform_specific_snapshot")]
+    stderr: String,
+}
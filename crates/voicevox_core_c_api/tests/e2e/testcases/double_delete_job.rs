@@ -0,0 +1,157 @@
+//! `voicevox_job_delete`を二度呼ぶとクラッシュすることを確認する。
+
+use std::{
+    env,
+    ffi::{CStr, CString, c_void},
+    mem::MaybeUninit,
+    ptr,
+    sync::LazyLock,
+};
+
+use assert_cmd::assert::AssertResult;
+use const_format::concatcp;
+use indexmap::IndexSet;
+use libloading::Library;
+use serde::{Deserialize, Serialize};
+use test_util::{
+    OPEN_JTALK_DIC_DIR,
+    c_api::{
+        self, CApi, VoicevoxInitializeOptions, VoicevoxLoadOnnxruntimeOptions, VoicevoxResultCode,
+    },
+};
+
+use crate::{
+    assert_cdylib::{self, Utf8Output, case},
+    snapshots,
+};
+
+case!(TestCase);
+
+#[derive(Serialize, Deserialize)]
+struct TestCase;
+
+#[typetag::serde(name = "double_delete_job")]
+impl assert_cdylib::TestCase for TestCase {
+    unsafe fn exec(&self, lib: Library) -> anyhow::Result<()> {
+        let lib = unsafe {
+            // SAFETY: The safety contract must be upheld by the caller.
+            CApi::from_library(lib)
+        }?;
+
+        let onnxruntime = {
+            let mut onnxruntime = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - A `CStr` is a valid string.
+                // - `onnxruntime` is valid for writes.
+                lib.voicevox_onnxruntime_load_once(
+                    VoicevoxLoadOnnxruntimeOptions {
+                        filename: CStr::from_bytes_with_nul(
+                            concatcp!(
+                                env::consts::DLL_PREFIX,
+                                "onnxruntime",
+                                env::consts::DLL_SUFFIX,
+                                '\0'
+                            )
+                            .as_ref(),
+                        )
+                        .expect("this ends with nul")
+                        .as_ptr(),
+                    },
+                    onnxruntime.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_onnxruntime_load_once` initializes `onnxruntime` if succeeded.
+            unsafe { onnxruntime.assume_init() }
+        };
+
+        let openjtalk = {
+            let mut openjtalk = MaybeUninit::uninit();
+            let open_jtalk_dic_dir = CString::new(OPEN_JTALK_DIC_DIR).unwrap();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - A `CString` is a valid string.
+                // - `openjtalk` is valid for writes.
+                lib.voicevox_open_jtalk_rc_new(open_jtalk_dic_dir.as_ptr(), openjtalk.as_mut_ptr())
+            });
+            // SAFETY: `voicevox_open_jtalk_rc_new` initializes `openjtalk` if succeeded.
+            unsafe { openjtalk.assume_init() }
+        };
+
+        let synthesizer = {
+            let mut synthesizer = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - `onnxruntime` is valid for reads.
+                // - `synthesizer` is valid for writes.
+                lib.voicevox_synthesizer_new(
+                    onnxruntime,
+                    openjtalk,
+                    VoicevoxInitializeOptions {
+                        acceleration_mode:
+                            c_api::VoicevoxAccelerationMode_VOICEVOX_ACCELERATION_MODE_CPU,
+                        ..lib.voicevox_make_default_initialize_options()
+                    },
+                    synthesizer.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_synthesizer_new` initializes `synthesizer` if succeeded.
+            unsafe { synthesizer.assume_init() }
+        };
+
+        let job = {
+            let mut job = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - A `CStr` is a valid string.
+                // - `on_complete` does not touch `user_data`.
+                // - `job` is valid for writes.
+                lib.voicevox_synthesizer_tts_async(
+                    synthesizer,
+                    c"こんにちは".as_ptr(),
+                    0,
+                    lib.voicevox_make_default_tts_options(),
+                    lib.voicevox_make_default_job_options(),
+                    Some(on_complete),
+                    ptr::null_mut(),
+                    job.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_synthesizer_tts_async` initializes `job` if succeeded.
+            unsafe { job.assume_init() }
+        };
+
+        // SAFETY: `voicevox_job_delete` has no safety requirements.
+        unsafe { lib.voicevox_job_delete(job) };
+        unsafe { lib.voicevox_job_delete(job) };
+        unreachable!();
+
+        unsafe extern "C" fn on_complete(
+            _: VoicevoxResultCode,
+            _: usize,
+            _: *mut u8,
+            _: *mut c_void,
+        ) {
+        }
+
+        fn assert_ok(result_code: VoicevoxResultCode) {
+            std::assert_eq!(c_api::VoicevoxResultCode_VOICEVOX_RESULT_OK, result_code);
+        }
+    }
+
+    fn assert_output(&self, output: Utf8Output) -> AssertResult {
+        let mut assert = output.assert().try_failure()?.try_stdout("")?;
+        for s in &SNAPSHOTS.stderr_matches_all {
+            let p = predicates::str::is_match(s).unwrap_or_else(|e| panic!("{e}"));
+            assert = assert.try_stderr(p)?;
+        }
+        Ok(assert)
+    }
+}
+
+static SNAPSHOTS: LazyLock<Snapshots> = snapshots::section!(double_delete_job);
+
+#[derive(Deserialize)]
+struct Snapshots {
+    stderr_matches_all: IndexSet<String>,
+}
@@ -0,0 +1,113 @@
+// スレッドごとの最後のエラーの取得をテストする。
+
+use std::{
+    ffi::{CStr, c_char},
+    sync::LazyLock,
+    thread,
+};
+
+use assert_cmd::assert::AssertResult;
+use libloading::Library;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use test_util::c_api::{self, CApi};
+use uuid::Uuid;
+
+use crate::{
+    assert_cdylib::{self, Utf8Output, case},
+    snapshots,
+};
+
+case!(TestCase);
+
+#[derive(Serialize, Deserialize)]
+struct TestCase;
+
+#[typetag::serde(name = "last_error")]
+impl assert_cdylib::TestCase for TestCase {
+    unsafe fn exec(&self, lib: Library) -> anyhow::Result<()> {
+        // SAFETY: The safety contract must be upheld by the caller.
+        let lib = unsafe { CApi::from_library(lib) }?;
+
+        // SAFETY: `voicevox_last_error_message` and `voicevox_last_error_json` have no safety
+        // requirements.
+        std::assert!(unsafe { lib.voicevox_last_error_message() }.is_null());
+        std::assert!(unsafe { lib.voicevox_last_error_json() }.is_null());
+
+        // SAFETY: `voicevox_user_dict_new` has no safety requirements.
+        let dict = unsafe { lib.voicevox_user_dict_new() };
+
+        // SAFETY: `word_uuid` is valid for reads.
+        let result_code =
+            unsafe { lib.voicevox_user_dict_remove_word(dict, Uuid::nil().as_bytes()) };
+        std::assert_eq!(
+            c_api::VoicevoxResultCode_VOICEVOX_RESULT_USER_DICT_WORD_NOT_FOUND_ERROR,
+            result_code,
+        );
+
+        let (message, json) = last_error(&lib);
+        std::assert_eq!(
+            "ユーザー辞書に単語が見つかりませんでした: 00000000-0000-0000-0000-000000000000",
+            message.unwrap(),
+        );
+        std::assert_eq!(
+            json!({
+                "result_code": result_code,
+                "message": "ユーザー辞書に単語が見つかりませんでした: 00000000-0000-0000-0000-000000000000",
+                "sources": [],
+                "details": {
+                    "word_uuid": "00000000-0000-0000-0000-000000000000",
+                },
+            }),
+            serde_json::from_str::<serde_json::Value>(&json.unwrap())?,
+        );
+
+        // 他のスレッドからは見えない
+        thread::scope(|s| {
+            s.spawn(|| std::assert_eq!((None, None), last_error(&lib)))
+                .join()
+                .unwrap();
+        });
+
+        // SAFETY: `voicevox_user_dict_delete` has no safety requirements.
+        unsafe { lib.voicevox_user_dict_delete(dict) };
+
+        // 成功した関数の呼び出しでは消えない
+        std::assert!(last_error(&lib).0.is_some());
+
+        return Ok(());
+
+        fn last_error(lib: &CApi) -> (Option<String>, Option<String>) {
+            // SAFETY: `voicevox_last_error_message` and `voicevox_last_error_json` have no safety
+            // requirements.
+            let (message, json) = unsafe {
+                (
+                    lib.voicevox_last_error_message(),
+                    lib.voicevox_last_error_json(),
+                )
+            };
+            let to_string = |s: *const c_char| {
+                // SAFETY: A non-null pointer from them is a valid string until the next failure in
+                // this thread.
+                (!s.is_null()).then(|| unsafe { CStr::from_ptr(s) }.to_str().unwrap().to_owned())
+            };
+            (to_string(message), to_string(json))
+        }
+    }
+
+    fn assert_output(&self, output: Utf8Output) -> AssertResult {
+        output
+            .mask_timestamps()
+            .assert()
+            .try_success()?
+            .try_stdout("")?
+            .try_stderr(&*SNAPSHOTS.stderr)
+    }
+}
+
+static SNAPSHOTS: LazyLock<Snapshots> = snapshots::section!(last_error);
+
+#[derive(Deserialize)]
+struct Snapshots {
+    stderr: String,
+}
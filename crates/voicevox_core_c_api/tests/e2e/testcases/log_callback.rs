@@ -0,0 +1,193 @@
+use std::{
+    env,
+    ffi::{CStr, CString, c_char, c_void},
+    mem::MaybeUninit,
+    ptr,
+    sync::{LazyLock, Mutex},
+};
+
+use assert_cmd::assert::AssertResult;
+use const_format::concatcp;
+use libloading::Library;
+use serde::{Deserialize, Serialize};
+use test_util::{
+    OPEN_JTALK_DIC_DIR,
+    c_api::{
+        self, CApi, OpenJtalkRc, VoicevoxInitializeOptions, VoicevoxLoadOnnxruntimeOptions,
+        VoicevoxLogLevel, VoicevoxOnnxruntime, VoicevoxResultCode,
+    },
+};
+
+use crate::{
+    assert_cdylib::{self, Utf8Output, case},
+    snapshots,
+};
+
+case!(TestCase);
+
+#[derive(Serialize, Deserialize)]
+struct TestCase;
+
+#[typetag::serde(name = "log_callback")]
+impl assert_cdylib::TestCase for TestCase {
+    unsafe fn exec(&self, lib: Library) -> anyhow::Result<()> {
+        // SAFETY: The safety contract must be upheld by the caller.
+        let lib = unsafe { CApi::from_library(lib) }?;
+
+        let onnxruntime = {
+            let mut onnxruntime = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - A `CStr` is a valid string.
+                // - `onnxruntime` is valid for writes.
+                lib.voicevox_onnxruntime_load_once(
+                    VoicevoxLoadOnnxruntimeOptions {
+                        filename: CStr::from_bytes_with_nul(
+                            concatcp!(
+                                env::consts::DLL_PREFIX,
+                                "onnxruntime",
+                                env::consts::DLL_SUFFIX,
+                                '\0'
+                            )
+                            .as_ref(),
+                        )
+                        .expect("this ends with nul")
+                        .as_ptr(),
+                    },
+                    onnxruntime.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_onnxruntime_load_once` initializes `onnxruntime` if succeeded.
+            unsafe { onnxruntime.assume_init() }
+        };
+
+        let openjtalk = {
+            let mut openjtalk = MaybeUninit::uninit();
+            let open_jtalk_dic_dir = CString::new(OPEN_JTALK_DIC_DIR).unwrap();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - A `CString` is a valid string.
+                // - `openjtalk` is valid for writes.
+                lib.voicevox_open_jtalk_rc_new(open_jtalk_dic_dir.as_ptr(), openjtalk.as_mut_ptr())
+            });
+            // SAFETY: `voicevox_open_jtalk_rc_new` initializes `openjtalk` if succeeded.
+            unsafe { openjtalk.assume_init() }
+        };
+
+        let logs = Mutex::new(vec![]);
+
+        // SAFETY:
+        // - `collect` can be called from any thread.
+        // - `logs` outlives the registration.
+        // - `collect` does not call `voicevox_set_log_callback`.
+        unsafe {
+            lib.voicevox_set_log_callback(
+                c_api::VoicevoxLogLevel_VOICEVOX_LOG_LEVEL_INFO,
+                Some(collect),
+                &raw const logs as *mut c_void,
+            );
+        }
+
+        new_synthesizer(&lib, onnxruntime, openjtalk);
+        std::assert!(logs.lock().unwrap().contains(&(
+            c_api::VoicevoxLogLevel_VOICEVOX_LOG_LEVEL_INFO,
+            "voicevox_core::synthesizer".to_owned(),
+            "CPUを利用します".to_owned(),
+        )));
+
+        // SAFETY: `voicevox_set_log_level` has no safety requirements.
+        unsafe { lib.voicevox_set_log_level(c_api::VoicevoxLogLevel_VOICEVOX_LOG_LEVEL_WARN) };
+        let num_logs = logs.lock().unwrap().len();
+        new_synthesizer(&lib, onnxruntime, openjtalk);
+        std::assert_eq!(num_logs, logs.lock().unwrap().len());
+
+        // SAFETY: Unregistering has no safety requirements.
+        unsafe {
+            lib.voicevox_set_log_callback(
+                c_api::VoicevoxLogLevel_VOICEVOX_LOG_LEVEL_OFF,
+                None,
+                ptr::null_mut(),
+            );
+        }
+        let num_logs = logs.lock().unwrap().len();
+        new_synthesizer(&lib, onnxruntime, openjtalk);
+        std::assert_eq!(num_logs, logs.lock().unwrap().len());
+
+        // SAFETY: `voicevox_open_jtalk_rc_delete` has no safety requirements.
+        unsafe { lib.voicevox_open_jtalk_rc_delete(openjtalk) };
+
+        return Ok(());
+
+        fn new_synthesizer(
+            lib: &CApi,
+            onnxruntime: *const VoicevoxOnnxruntime,
+            openjtalk: *mut OpenJtalkRc,
+        ) {
+            let mut synthesizer = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - `onnxruntime` is valid for reads.
+                // - `synthesizer` is valid for writes.
+                lib.voicevox_synthesizer_new(
+                    onnxruntime,
+                    openjtalk,
+                    VoicevoxInitializeOptions {
+                        acceleration_mode:
+                            c_api::VoicevoxAccelerationMode_VOICEVOX_ACCELERATION_MODE_CPU,
+                        ..lib.voicevox_make_default_initialize_options()
+                    },
+                    synthesizer.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_synthesizer_new` initializes `synthesizer` if succeeded.
+            let synthesizer = unsafe { synthesizer.assume_init() };
+            // SAFETY: `voicevox_synthesizer_delete` has no safety requirements.
+            unsafe { lib.voicevox_synthesizer_delete(synthesizer) };
+        }
+
+        unsafe extern "C" fn collect(
+            level: VoicevoxLogLevel,
+            target: *const c_char,
+            message: *const c_char,
+            user_data: *mut c_void,
+        ) {
+            // SAFETY: `user_data` is `&Mutex<Vec<_>>`, and `target` and `message` are valid
+            // strings during the call.
+            let (logs, target, message) = unsafe {
+                (
+                    &*(user_data as *const Mutex<Vec<(VoicevoxLogLevel, String, String)>>),
+                    CStr::from_ptr(target),
+                    CStr::from_ptr(message),
+                )
+            };
+            logs.lock().unwrap().push((
+                level,
+                target.to_str().unwrap().to_owned(),
+                message.to_str().unwrap().to_owned(),
+            ));
+        }
+
+        fn assert_ok(result_code: VoicevoxResultCode) {
+            std::assert_eq!(c_api::VoicevoxResultCode_VOICEVOX_RESULT_OK, result_code);
+        }
+    }
+
+    fn assert_output(&self, output: Utf8Output) -> AssertResult {
+        output
+            .mask_timestamps()
+            .mask_unix_onnxruntime_filename()
+            .mask_windows_video_cards()
+            .assert()
+            .try_success()?
+            .try_stdout("")?
+            .try_stderr(&*SNAPSHOTS.stderr)
+    }
+}
+
+static SNAPSHOTS: LazyLock<Snapshots> = snapshots::section!(log_callback);
+
+#[derive(Deserialize)]
+struct Snapshots {
+    #[serde(deserialize_with = "snapshots::deserialize_platform_specific_snapshot")]
+    stderr: String,
+}
@@ -0,0 +1,397 @@
+//! `VoicevoxScore`と`VoicevoxFrameAudioQuery`のアクセサが機能し、JSONを介した場合と同じ音声が合成されることを確認する。
+
+use std::{
+    env,
+    ffi::{CStr, CString},
+    mem::MaybeUninit,
+    slice,
+    sync::LazyLock,
+};
+
+use assert_cmd::assert::AssertResult;
+use const_format::concatcp;
+use libloading::Library;
+use serde::{Deserialize, Serialize};
+use test_util::{
+    OPEN_JTALK_DIC_DIR,
+    c_api::{
+        self, CApi, VoicevoxInitializeOptions, VoicevoxLoadOnnxruntimeOptions, VoicevoxResultCode,
+    },
+};
+
+use crate::{
+    assert_cdylib::{self, Utf8Output, case},
+    snapshots,
+};
+
+case!(TestCase);
+
+#[derive(Serialize, Deserialize)]
+struct TestCase;
+
+#[typetag::serde(name = "song_handle")]
+impl assert_cdylib::TestCase for TestCase {
+    unsafe fn exec(&self, lib: Library) -> anyhow::Result<()> {
+        // SAFETY: The safety contract must be upheld by the caller.
+        let lib = unsafe { CApi::from_library(lib) }?;
+
+        let model = {
+            let mut model = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - `SAMPLE_VOICE_MODEL_FILE_PATH` is a valid string.
+                // - `model` is valid for writes.
+                lib.voicevox_voice_model_file_open(
+                    c_api::SAMPLE_VOICE_MODEL_FILE_PATH.as_ptr(),
+                    model.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_voice_model_file_open` initializes `model` if succeeded.
+            unsafe { model.assume_init() }
+        };
+
+        let onnxruntime = {
+            let mut onnxruntime = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - A `CStr` is a valid string.
+                // - `onnxruntime` is valid for writes.
+                lib.voicevox_onnxruntime_load_once(
+                    VoicevoxLoadOnnxruntimeOptions {
+                        filename: CStr::from_bytes_with_nul(
+                            concatcp!(
+                                env::consts::DLL_PREFIX,
+                                "onnxruntime",
+                                env::consts::DLL_SUFFIX,
+                                '\0'
+                            )
+                            .as_ref(),
+                        )
+                        .expect("this ends with nul")
+                        .as_ptr(),
+                    },
+                    onnxruntime.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_onnxruntime_load_once` initializes `onnxruntime` if succeeded.
+            unsafe { onnxruntime.assume_init() }
+        };
+
+        let openjtalk = {
+            let mut openjtalk = MaybeUninit::uninit();
+            let open_jtalk_dic_dir = CString::new(OPEN_JTALK_DIC_DIR).unwrap();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - A `CString` is a valid string.
+                // - `openjtalk` is valid for writes.
+                lib.voicevox_open_jtalk_rc_new(open_jtalk_dic_dir.as_ptr(), openjtalk.as_mut_ptr())
+            });
+            // SAFETY: `voicevox_open_jtalk_rc_new` initializes `openjtalk` if succeeded.
+            unsafe { openjtalk.assume_init() }
+        };
+
+        let synthesizer = {
+            let mut synthesizer = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - `onnxruntime` is valid for reads.
+                // - `synthesizer` is valid for writes.
+                lib.voicevox_synthesizer_new(
+                    onnxruntime,
+                    openjtalk,
+                    VoicevoxInitializeOptions {
+                        acceleration_mode:
+                            c_api::VoicevoxAccelerationMode_VOICEVOX_ACCELERATION_MODE_CPU,
+                        ..lib.voicevox_make_default_initialize_options()
+                    },
+                    synthesizer.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_synthesizer_new` initializes `synthesizer` if succeeded.
+            unsafe { synthesizer.assume_init() }
+        };
+
+        // SAFETY: `voicevox_synthesizer_load_voice_model` has no safety requirements.
+        assert_ok(unsafe {
+            lib.voicevox_synthesizer_load_voice_model(
+                synthesizer,
+                model,
+                lib.voicevox_make_default_load_voice_model_options(),
+            )
+        });
+
+        let score = {
+            let mut score = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - A `CStr` is a valid string.
+                // - `score` is valid for writes.
+                lib.voicevox_score_new_from_json(SCORE.as_ptr(), score.as_mut_ptr())
+            });
+            // SAFETY: `voicevox_score_new_from_json` initializes `score` if succeeded.
+            unsafe { score.assume_init() }
+        };
+        // SAFETY: `voicevox_score_get_notes_length` has no safety requirements.
+        std::assert_eq!(5, unsafe { lib.voicevox_score_get_notes_length(score) });
+
+        let get_note = |note_index| {
+            let mut note = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY: `note` is valid for writes.
+                lib.voicevox_score_get_note(score, note_index, note.as_mut_ptr())
+            });
+            // SAFETY: `voicevox_score_get_note` initializes `note` if succeeded.
+            unsafe { note.assume_init() }
+        };
+        let rest = get_note(0);
+        std::assert!(!rest.has_key);
+        std::assert_eq!(15, rest.frame_length);
+        let note = get_note(1);
+        std::assert!(note.has_key);
+        std::assert_eq!(60, note.key);
+        std::assert_eq!(45, note.frame_length);
+        std::assert!(!note.breath);
+        std::assert_eq!(
+            c_api::VoicevoxResultCode_VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR,
+            unsafe {
+                // SAFETY: `MaybeUninit::as_mut_ptr` is valid for writes.
+                lib.voicevox_score_get_note(score, 5, MaybeUninit::uninit().as_mut_ptr())
+            },
+        );
+
+        let frame_audio_query = {
+            let mut frame_audio_query = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY: `frame_audio_query` is valid for writes.
+                lib.voicevox_synthesizer_create_sing_frame_audio_query_handle(
+                    synthesizer,
+                    score,
+                    SINGING_TEACHER,
+                    frame_audio_query.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_synthesizer_create_sing_frame_audio_query_handle` initializes
+            // `frame_audio_query` if succeeded.
+            unsafe { frame_audio_query.assume_init() }
+        };
+
+        // SAFETY: The following functions have no safety requirements.
+        unsafe {
+            std::assert_eq!(
+                NUM_TOTAL_FRAMES,
+                lib.voicevox_frame_audio_query_get_frames_length(frame_audio_query),
+            );
+            std::assert_eq!(
+                8,
+                lib.voicevox_frame_audio_query_get_phonemes_length(frame_audio_query),
+            );
+
+            assert_ok(lib.voicevox_frame_audio_query_set_f0(frame_audio_query, 20, 440.));
+            assert_ok(lib.voicevox_frame_audio_query_set_volume_scale(frame_audio_query, 1.5));
+            std::assert_eq!(
+                1.5,
+                lib.voicevox_frame_audio_query_get_volume_scale(frame_audio_query),
+            );
+
+            std::assert_eq!(
+                c_api::VoicevoxResultCode_VOICEVOX_RESULT_INVALID_FRAME_AUDIO_QUERY_ERROR,
+                lib.voicevox_frame_audio_query_set_output_sampling_rate(frame_audio_query, 12345),
+            );
+            std::assert_eq!(
+                24000,
+                lib.voicevox_frame_audio_query_get_output_sampling_rate(frame_audio_query),
+            );
+        }
+
+        let get_f0 = |frame_index| {
+            let mut f0 = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY: `f0` is valid for writes.
+                lib.voicevox_frame_audio_query_get_f0(
+                    frame_audio_query,
+                    frame_index,
+                    f0.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_frame_audio_query_get_f0` initializes `f0` if succeeded.
+            unsafe { f0.assume_init() }
+        };
+        std::assert_eq!(440., get_f0(20));
+
+        let phonemes = (0..8)
+            .map(|phoneme_index| {
+                let mut phoneme = MaybeUninit::uninit();
+                assert_ok(unsafe {
+                    // SAFETY: `phoneme` is valid for writes.
+                    lib.voicevox_frame_audio_query_get_phoneme(
+                        frame_audio_query,
+                        phoneme_index,
+                        phoneme.as_mut_ptr(),
+                    )
+                });
+                // SAFETY: `voicevox_frame_audio_query_get_phoneme` initializes `phoneme` if
+                // succeeded.
+                unsafe { phoneme.assume_init() }
+            })
+            .collect::<Vec<_>>();
+        std::assert_eq!(
+            NUM_TOTAL_FRAMES as u64,
+            phonemes
+                .iter()
+                .map(|phoneme| phoneme.frame_length)
+                .sum::<u64>(),
+        );
+        std::assert!(phonemes.iter().all(|phoneme| !phoneme.has_note_id));
+        std::assert_eq!(
+            c_api::VoicevoxResultCode_VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR,
+            unsafe {
+                // SAFETY: `MaybeUninit::as_mut_ptr` is valid for writes.
+                lib.voicevox_frame_audio_query_get_phoneme(
+                    frame_audio_query,
+                    8,
+                    MaybeUninit::uninit().as_mut_ptr(),
+                )
+            },
+        );
+
+        std::assert_eq!(
+            c_api::VoicevoxResultCode_VOICEVOX_RESULT_INDEX_OUT_OF_RANGE_ERROR,
+            unsafe {
+                // SAFETY: `MaybeUninit::as_mut_ptr` is valid for writes.
+                lib.voicevox_frame_audio_query_get_volume(
+                    frame_audio_query,
+                    NUM_TOTAL_FRAMES,
+                    MaybeUninit::uninit().as_mut_ptr(),
+                )
+            },
+        );
+
+        // SAFETY: `voicevox_frame_audio_query_create_json` has no safety requirements.
+        let frame_audio_query_json =
+            unsafe { lib.voicevox_frame_audio_query_create_json(frame_audio_query) };
+
+        let frame_audio_query_from_json = {
+            let mut frame_audio_query = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - `frame_audio_query_json` is a valid string.
+                // - `frame_audio_query` is valid for writes.
+                lib.voicevox_frame_audio_query_new_from_json(
+                    frame_audio_query_json,
+                    frame_audio_query.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_frame_audio_query_new_from_json` initializes `frame_audio_query`
+            // if succeeded.
+            unsafe { frame_audio_query.assume_init() }
+        };
+        // SAFETY: `voicevox_frame_audio_query_get_volume_scale` has no safety requirements.
+        std::assert_eq!(1.5, unsafe {
+            lib.voicevox_frame_audio_query_get_volume_scale(frame_audio_query_from_json)
+        });
+
+        let wav_from_handle = {
+            let mut wav_length = MaybeUninit::uninit();
+            let mut wav = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - `wav_length` is valid for writes.
+                // - `wav` is valid for writes.
+                lib.voicevox_synthesizer_frame_synthesis_from_handle(
+                    synthesizer,
+                    frame_audio_query,
+                    SINGER,
+                    wav_length.as_mut_ptr(),
+                    wav.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_synthesizer_frame_synthesis_from_handle` initializes `wav_length`
+            // and `wav` if succeeded.
+            unsafe { to_vec_and_free(&lib, wav_length.assume_init(), wav.assume_init()) }
+        };
+
+        let wav_from_json = {
+            let mut wav_length = MaybeUninit::uninit();
+            let mut wav = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - `frame_audio_query_json` is a valid string.
+                // - `wav_length` is valid for writes.
+                // - `wav` is valid for writes.
+                lib.voicevox_synthesizer_frame_synthesis(
+                    synthesizer,
+                    frame_audio_query_json,
+                    SINGER,
+                    wav_length.as_mut_ptr(),
+                    wav.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_synthesizer_frame_synthesis` initializes `wav_length` and `wav` if
+            // succeeded.
+            unsafe { to_vec_and_free(&lib, wav_length.assume_init(), wav.assume_init()) }
+        };
+
+        std::assert_eq!(wav_from_json, wav_from_handle);
+
+        // SAFETY: `frame_audio_query_json` is valid and is no longer used.
+        unsafe { lib.voicevox_json_free(frame_audio_query_json) };
+
+        // SAFETY: These functions have no safety requirements.
+        unsafe { lib.voicevox_score_delete(score) };
+        unsafe { lib.voicevox_frame_audio_query_delete(frame_audio_query) };
+        unsafe { lib.voicevox_frame_audio_query_delete(frame_audio_query_from_json) };
+        unsafe { lib.voicevox_voice_model_file_delete(model) };
+        unsafe { lib.voicevox_open_jtalk_rc_delete(openjtalk) };
+        unsafe { lib.voicevox_synthesizer_delete(synthesizer) };
+
+        return Ok(());
+
+        const SINGING_TEACHER: u32 = 6000;
+        const SINGER: u32 = 3000;
+
+        const SCORE: &CStr = cr#"{
+  "notes": [
+    { "lyric": "", "frame_length": 15 },
+    { "key": 60, "lyric": "ド", "frame_length": 45 },
+    { "key": 62, "lyric": "レ", "frame_length": 45 },
+    { "key": 64, "lyric": "ミ", "frame_length": 45 },
+    { "lyric": "", "frame_length": 15 }
+  ]
+}"#;
+        const NUM_TOTAL_FRAMES: usize = 15 + 45 + 45 + 45 + 15;
+
+        /// # Safety
+        ///
+        /// `wav`は`wav_length`の長さを持つ、解放されていないWAVデータでなければならない。
+        unsafe fn to_vec_and_free(lib: &CApi, wav_length: usize, wav: *mut u8) -> Vec<u8> {
+            // SAFETY: The safety contract must be upheld by the caller.
+            let vec = unsafe { slice::from_raw_parts(wav, wav_length) }.to_owned();
+            // SAFETY: The safety contract must be upheld by the caller.
+            unsafe { lib.voicevox_wav_free(wav) };
+            vec
+        }
+
+        fn assert_ok(result_code: VoicevoxResultCode) {
+            std::assert_eq!(c_api::VoicevoxResultCode_VOICEVOX_RESULT_OK, result_code);
+        }
+    }
+
+    fn assert_output(&self, output: Utf8Output) -> AssertResult {
+        output
+            .mask_timestamps()
+            .mask_unix_onnxruntime_filename()
+            .mask_windows_video_cards()
+            .assert()
+            .try_success()?
+            .try_stdout("")?
+            .try_stderr(&*SNAPSHOTS.stderr)
+    }
+}
+
+static SNAPSHOTS: LazyLock<Snapshots> = snapshots::section!(song_handle);
+
+#[derive(Deserialize)]
+struct Snapshots {
+    #[serde(deserialize_with = "snapshots::deserialize_platform_specific_snapshot")]
+    stderr: String,
+}
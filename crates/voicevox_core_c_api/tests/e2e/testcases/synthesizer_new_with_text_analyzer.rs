@@ -0,0 +1,328 @@
+//! `voicevox_synthesizer_new_with_text_analyzer`で構築したシンセサイザが、ユーザー定義のテキスト解析器を
+//! 用いて音声合成すること、およびテキスト解析器の失敗を`VOICEVOX_RESULT_ANALYZE_TEXT_ERROR`として
+//! 返すことを確認する。
+
+use std::{
+    env,
+    ffi::{CStr, CString, c_char, c_void},
+    mem::MaybeUninit,
+    ptr, slice,
+    sync::{
+        LazyLock,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+use assert_cmd::assert::AssertResult;
+use const_format::concatcp;
+use libloading::Library;
+use serde::{Deserialize, Serialize};
+use test_util::{
+    OPEN_JTALK_DIC_DIR,
+    c_api::{
+        self, CApi, OpenJtalkRc, VoicevoxInitializeOptions, VoicevoxLoadOnnxruntimeOptions,
+        VoicevoxResultCode, VoicevoxSynthesizer, VoicevoxTextAnalyzer, VoicevoxVoiceModelFile,
+    },
+};
+
+use crate::{
+    assert_cdylib::{self, Utf8Output, case},
+    snapshots,
+};
+
+case!(TestCase {
+    text: "こんにちは、音声合成の世界へようこそ".to_owned()
+});
+
+#[derive(Serialize, Deserialize)]
+struct TestCase {
+    text: String,
+}
+
+#[typetag::serde(name = "synthesizer_new_with_text_analyzer")]
+impl assert_cdylib::TestCase for TestCase {
+    unsafe fn exec(&self, lib: Library) -> anyhow::Result<()> {
+        // SAFETY: The safety contract must be upheld by the caller.
+        let lib = unsafe { CApi::from_library(lib) }?;
+
+        let model = {
+            let mut model = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - `SAMPLE_VOICE_MODEL_FILE_PATH` is a valid string.
+                // - `model` is valid for writes.
+                lib.voicevox_voice_model_file_open(
+                    c_api::SAMPLE_VOICE_MODEL_FILE_PATH.as_ptr(),
+                    model.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_voice_model_file_open` initializes `model` if succeeded.
+            unsafe { model.assume_init() }
+        };
+
+        let onnxruntime = {
+            let mut onnxruntime = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - A `CStr` is a valid string.
+                // - `onnxruntime` is valid for writes.
+                lib.voicevox_onnxruntime_load_once(
+                    VoicevoxLoadOnnxruntimeOptions {
+                        filename: CStr::from_bytes_with_nul(
+                            concatcp!(
+                                env::consts::DLL_PREFIX,
+                                "onnxruntime",
+                                env::consts::DLL_SUFFIX,
+                                '\0'
+                            )
+                            .as_ref(),
+                        )
+                        .expect("this ends with nul")
+                        .as_ptr(),
+                    },
+                    onnxruntime.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_onnxruntime_load_once` initializes `onnxruntime` if succeeded.
+            unsafe { onnxruntime.assume_init() }
+        };
+
+        let openjtalk = {
+            let mut openjtalk = MaybeUninit::uninit();
+            let open_jtalk_dic_dir = CString::new(OPEN_JTALK_DIC_DIR).unwrap();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - A `CString` is a valid string.
+                // - `openjtalk` is valid for writes.
+                lib.voicevox_open_jtalk_rc_new(open_jtalk_dic_dir.as_ptr(), openjtalk.as_mut_ptr())
+            });
+            // SAFETY: `voicevox_open_jtalk_rc_new` initializes `openjtalk` if succeeded.
+            unsafe { openjtalk.assume_init() }
+        };
+
+        let text = CString::new(&*self.text).unwrap();
+
+        let expected = {
+            let synthesizer = {
+                let mut synthesizer = MaybeUninit::uninit();
+                assert_ok(unsafe {
+                    // SAFETY:
+                    // - `onnxruntime` is valid for reads.
+                    // - `synthesizer` is valid for writes.
+                    lib.voicevox_synthesizer_new(
+                        onnxruntime,
+                        openjtalk,
+                        initialize_options(&lib),
+                        synthesizer.as_mut_ptr(),
+                    )
+                });
+                // SAFETY: `voicevox_synthesizer_new` initializes `synthesizer` if succeeded.
+                unsafe { synthesizer.assume_init() }
+            };
+            let wav = tts(&lib, synthesizer, model, &text).unwrap();
+            // SAFETY: `voicevox_synthesizer_delete` has no safety requirements.
+            unsafe { lib.voicevox_synthesizer_delete(synthesizer) };
+            wav
+        };
+
+        for (behavior, expected) in [
+            (Behavior::DelegateToOpenJtalk, Ok(expected)),
+            (
+                Behavior::ReturnNull,
+                Err(c_api::VoicevoxResultCode_VOICEVOX_RESULT_ANALYZE_TEXT_ERROR),
+            ),
+            (
+                Behavior::ReturnInvalidJson,
+                Err(c_api::VoicevoxResultCode_VOICEVOX_RESULT_ANALYZE_TEXT_ERROR),
+            ),
+        ] {
+            let analyzer = Analyzer {
+                lib: &lib,
+                openjtalk,
+                behavior,
+                num_returned: AtomicUsize::new(0),
+                num_freed: AtomicUsize::new(0),
+            };
+
+            let synthesizer = {
+                let mut synthesizer = MaybeUninit::uninit();
+                assert_ok(unsafe {
+                    // SAFETY:
+                    // - `onnxruntime` is valid for reads.
+                    // - `analyzer` outlives the synthesizer.
+                    // - `synthesizer` is valid for writes.
+                    lib.voicevox_synthesizer_new_with_text_analyzer(
+                        onnxruntime,
+                        VoicevoxTextAnalyzer {
+                            analyze: Some(analyze),
+                            free_json: Some(free_json),
+                            user_data: &raw const analyzer as *mut c_void,
+                        },
+                        initialize_options(&lib),
+                        synthesizer.as_mut_ptr(),
+                    )
+                });
+                // SAFETY: `voicevox_synthesizer_new_with_text_analyzer` initializes
+                // `synthesizer` if succeeded.
+                unsafe { synthesizer.assume_init() }
+            };
+
+            std::assert_eq!(expected, tts(&lib, synthesizer, model, &text));
+
+            // SAFETY: `voicevox_synthesizer_delete` has no safety requirements.
+            unsafe { lib.voicevox_synthesizer_delete(synthesizer) };
+
+            // 返されたJSONは全て`free_json`で解放されていること
+            std::assert_eq!(
+                analyzer.num_returned.load(Ordering::SeqCst),
+                analyzer.num_freed.load(Ordering::SeqCst),
+            );
+        }
+
+        // SAFETY: `voicevox_voice_model_file_delete` and `voicevox_open_jtalk_rc_delete` have no
+        // safety requirements.
+        unsafe { lib.voicevox_voice_model_file_delete(model) };
+        unsafe { lib.voicevox_open_jtalk_rc_delete(openjtalk) };
+
+        return Ok(());
+
+        const STYLE_ID: u32 = 0;
+
+        fn initialize_options(lib: &CApi) -> VoicevoxInitializeOptions {
+            VoicevoxInitializeOptions {
+                acceleration_mode: c_api::VoicevoxAccelerationMode_VOICEVOX_ACCELERATION_MODE_CPU,
+                // SAFETY: `voicevox_make_default_initialize_options` has no safety requirements.
+                ..unsafe { lib.voicevox_make_default_initialize_options() }
+            }
+        }
+
+        fn tts(
+            lib: &CApi,
+            synthesizer: *const VoicevoxSynthesizer,
+            model: *const VoicevoxVoiceModelFile,
+            text: &CStr,
+        ) -> Result<Vec<u8>, VoicevoxResultCode> {
+            // SAFETY: `voicevox_synthesizer_load_voice_model` has no safety requirements.
+            assert_ok(unsafe {
+                lib.voicevox_synthesizer_load_voice_model(
+                    synthesizer,
+                    model,
+                    lib.voicevox_make_default_load_voice_model_options(),
+                )
+            });
+
+            let mut wav_length = MaybeUninit::uninit();
+            let mut wav = MaybeUninit::uninit();
+            let result_code = unsafe {
+                // SAFETY:
+                // - A `CStr` is a valid string.
+                // - `wav_length` is valid for writes.
+                // - `wav` is valid for writes.
+                lib.voicevox_synthesizer_tts(
+                    synthesizer,
+                    text.as_ptr(),
+                    STYLE_ID,
+                    lib.voicevox_make_default_tts_options(),
+                    wav_length.as_mut_ptr(),
+                    wav.as_mut_ptr(),
+                )
+            };
+            if result_code != c_api::VoicevoxResultCode_VOICEVOX_RESULT_OK {
+                return Err(result_code);
+            }
+            // SAFETY: `voicevox_synthesizer_tts` initializes `wav_length` and `wav` if succeeded.
+            let wav_length = unsafe { wav_length.assume_init() };
+            let wav = unsafe { wav.assume_init() };
+            // SAFETY: `voicevox_synthesizer_tts` lends a valid slice.
+            let copied = unsafe { slice::from_raw_parts(wav, wav_length) }.to_owned();
+            // SAFETY: `wav` is valid and is no longer used.
+            unsafe { lib.voicevox_wav_free(wav) };
+            Ok(copied)
+        }
+
+        #[derive(Clone, Copy)]
+        enum Behavior {
+            DelegateToOpenJtalk,
+            ReturnNull,
+            ReturnInvalidJson,
+        }
+
+        struct Analyzer<'a> {
+            lib: &'a CApi,
+            openjtalk: *const OpenJtalkRc,
+            behavior: Behavior,
+            num_returned: AtomicUsize,
+            num_freed: AtomicUsize,
+        }
+
+        unsafe extern "C" fn analyze(text: *const c_char, user_data: *mut c_void) -> *mut c_char {
+            // SAFETY: `user_data` points to an `Analyzer`.
+            let Analyzer {
+                lib,
+                openjtalk,
+                behavior,
+                num_returned,
+                ..
+            } = unsafe { &*(user_data as *const Analyzer<'_>) };
+
+            let json = match behavior {
+                Behavior::DelegateToOpenJtalk => {
+                    let mut json = ptr::null_mut();
+                    // SAFETY:
+                    // - `text` is a valid string.
+                    // - `json` is valid for writes.
+                    assert_ok(unsafe {
+                        lib.voicevox_open_jtalk_rc_analyze(*openjtalk, text, &mut json)
+                    });
+                    json
+                }
+                Behavior::ReturnNull => return ptr::null_mut(),
+                Behavior::ReturnInvalidJson => c"{}".to_owned().into_raw(),
+            };
+            num_returned.fetch_add(1, Ordering::SeqCst);
+            json
+        }
+
+        unsafe extern "C" fn free_json(json: *mut c_char, user_data: *mut c_void) {
+            // SAFETY: `user_data` points to an `Analyzer`.
+            let Analyzer {
+                lib,
+                behavior,
+                num_freed,
+                ..
+            } = unsafe { &*(user_data as *const Analyzer<'_>) };
+
+            match behavior {
+                // SAFETY: `json` comes from `voicevox_open_jtalk_rc_analyze`.
+                Behavior::DelegateToOpenJtalk => unsafe { lib.voicevox_json_free(json) },
+                Behavior::ReturnNull => panic!("`analyze` should not have returned a string"),
+                // SAFETY: `json` comes from `CString::into_raw`.
+                Behavior::ReturnInvalidJson => drop(unsafe { CString::from_raw(json) }),
+            }
+            num_freed.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn assert_ok(result_code: VoicevoxResultCode) {
+            std::assert_eq!(c_api::VoicevoxResultCode_VOICEVOX_RESULT_OK, result_code);
+        }
+    }
+
+    fn assert_output(&self, output: Utf8Output) -> AssertResult {
+        output
+            .mask_timestamps()
+            .mask_unix_onnxruntime_filename()
+            .mask_windows_video_cards()
+            .assert()
+            .try_success()?
+            .try_stdout("")?
+            .try_stderr(&*SNAPSHOTS.stderr)
+    }
+}
+
+static SNAPSHOTS: LazyLock<Snapshots> = snapshots::section!(synthesizer_new_with_text_analyzer);
+
+#[derive(Deserialize)]
+struct Snapshots {
+    #[serde(deserialize_with = "snapshots::deserialize_platform_specific_snapshot")]
+    stderr: String,
+}
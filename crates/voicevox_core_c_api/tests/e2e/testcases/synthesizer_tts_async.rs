@@ -0,0 +1,604 @@
+//! `voicevox_synthesizer_tts_async`が`voicevox_synthesizer_tts`と同じ結果を返すこと、
+//! `voicevox_job_cancel`でキャンセルできること、および`voicevox_synthesizer_delete`が処理をキャンセル
+//! して終わるのを待つことを確認する。
+
+use std::{
+    env,
+    ffi::{CStr, CString, c_char, c_void},
+    mem::MaybeUninit,
+    ptr, slice,
+    sync::{
+        LazyLock, Mutex,
+        mpsc::{self, Receiver, SyncSender},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use assert_cmd::assert::AssertResult;
+use const_format::concatcp;
+use libloading::Library;
+use serde::{Deserialize, Serialize};
+use test_util::{
+    OPEN_JTALK_DIC_DIR,
+    c_api::{
+        self, CApi, OpenJtalkRc, VoicevoxInitializeOptions, VoicevoxJobOptions,
+        VoicevoxLoadOnnxruntimeOptions, VoicevoxResultCode, VoicevoxSynthesizer,
+        VoicevoxTextAnalyzer,
+    },
+};
+
+use crate::{
+    assert_cdylib::{self, Utf8Output, case},
+    snapshots,
+};
+
+case!(TestCase {
+    text: "こんにちは、音声合成の世界へようこそ".to_owned()
+});
+
+#[derive(Serialize, Deserialize)]
+struct TestCase {
+    text: String,
+}
+
+#[typetag::serde(name = "synthesizer_tts_async")]
+impl assert_cdylib::TestCase for TestCase {
+    unsafe fn exec(&self, lib: Library) -> anyhow::Result<()> {
+        // SAFETY: The safety contract must be upheld by the caller.
+        let lib = unsafe { CApi::from_library(lib) }?;
+
+        let model = {
+            let mut model = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - `SAMPLE_VOICE_MODEL_FILE_PATH` is a valid string.
+                // - `model` is valid for writes.
+                lib.voicevox_voice_model_file_open(
+                    c_api::SAMPLE_VOICE_MODEL_FILE_PATH.as_ptr(),
+                    model.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_voice_model_file_open` initializes `model` if succeeded.
+            unsafe { model.assume_init() }
+        };
+
+        let onnxruntime = {
+            let mut onnxruntime = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - A `CStr` is a valid string.
+                // - `onnxruntime` is valid for writes.
+                lib.voicevox_onnxruntime_load_once(
+                    VoicevoxLoadOnnxruntimeOptions {
+                        filename: CStr::from_bytes_with_nul(
+                            concatcp!(
+                                env::consts::DLL_PREFIX,
+                                "onnxruntime",
+                                env::consts::DLL_SUFFIX,
+                                '\0'
+                            )
+                            .as_ref(),
+                        )
+                        .expect("this ends with nul")
+                        .as_ptr(),
+                    },
+                    onnxruntime.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_onnxruntime_load_once` initializes `onnxruntime` if succeeded.
+            unsafe { onnxruntime.assume_init() }
+        };
+
+        let openjtalk = {
+            let mut openjtalk = MaybeUninit::uninit();
+            let open_jtalk_dic_dir = CString::new(OPEN_JTALK_DIC_DIR).unwrap();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - A `CString` is a valid string.
+                // - `openjtalk` is valid for writes.
+                lib.voicevox_open_jtalk_rc_new(open_jtalk_dic_dir.as_ptr(), openjtalk.as_mut_ptr())
+            });
+            // SAFETY: `voicevox_open_jtalk_rc_new` initializes `openjtalk` if succeeded.
+            unsafe { openjtalk.assume_init() }
+        };
+
+        let synthesizer = {
+            let mut synthesizer = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - `onnxruntime` is valid for reads.
+                // - `synthesizer` is valid for writes.
+                lib.voicevox_synthesizer_new(
+                    onnxruntime,
+                    openjtalk,
+                    VoicevoxInitializeOptions {
+                        acceleration_mode:
+                            c_api::VoicevoxAccelerationMode_VOICEVOX_ACCELERATION_MODE_CPU,
+                        ..lib.voicevox_make_default_initialize_options()
+                    },
+                    synthesizer.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_synthesizer_new` initializes `synthesizer` if succeeded.
+            unsafe { synthesizer.assume_init() }
+        };
+
+        // SAFETY: `voicevox_synthesizer_load_voice_model` has no safety requirements.
+        assert_ok(unsafe {
+            lib.voicevox_synthesizer_load_voice_model(
+                synthesizer,
+                model,
+                lib.voicevox_make_default_load_voice_model_options(),
+            )
+        });
+
+        let text = CString::new(&*self.text).unwrap();
+
+        let expected = {
+            let mut wav_length = MaybeUninit::uninit();
+            let mut wav = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - A `CString` is a valid string.
+                // - `wav_length` is valid for writes.
+                // - `wav` is valid for writes.
+                lib.voicevox_synthesizer_tts(
+                    synthesizer,
+                    text.as_ptr(),
+                    STYLE_ID,
+                    lib.voicevox_make_default_tts_options(),
+                    wav_length.as_mut_ptr(),
+                    wav.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_synthesizer_tts` initializes `wav_length` and `wav` if succeeded.
+            let wav_length = unsafe { wav_length.assume_init() };
+            let wav = unsafe { wav.assume_init() };
+            // SAFETY: `voicevox_synthesizer_tts` lends a valid slice.
+            let expected = unsafe { slice::from_raw_parts(wav, wav_length) }.to_owned();
+            // SAFETY: `wav` is valid and is no longer used.
+            unsafe { lib.voicevox_wav_free(wav) };
+            expected
+        };
+
+        // 完了まで待つ
+        {
+            let (tx, rx) = mpsc::sync_channel(1);
+            let completion = Completion { lib: &lib, tx };
+
+            let job = {
+                let mut job = MaybeUninit::uninit();
+                assert_ok(unsafe {
+                    // SAFETY:
+                    // - A `CString` is a valid string.
+                    // - `completion` outlives the job, since we wait for `rx` below.
+                    // - `job` is valid for writes.
+                    lib.voicevox_synthesizer_tts_async(
+                        synthesizer,
+                        text.as_ptr(),
+                        STYLE_ID,
+                        lib.voicevox_make_default_tts_options(),
+                        lib.voicevox_make_default_job_options(),
+                        Some(on_complete),
+                        &raw const completion as *mut c_void,
+                        job.as_mut_ptr(),
+                    )
+                });
+                // SAFETY: `voicevox_synthesizer_tts_async` initializes `job` if succeeded.
+                unsafe { job.assume_init() }
+            };
+
+            let (result_code, wav) = rx.recv().unwrap();
+            std::assert_eq!(c_api::VoicevoxResultCode_VOICEVOX_RESULT_OK, result_code);
+            std::assert_eq!(Some(&expected), wav.as_ref());
+
+            // SAFETY: `voicevox_job_delete` has no safety requirements.
+            unsafe { lib.voicevox_job_delete(job) };
+        }
+
+        // テキスト解析の途中でキャンセルする
+        {
+            let (entered_tx, entered_rx) = mpsc::sync_channel(1);
+            let (release_tx, release_rx) = mpsc::sync_channel(1);
+            let gate = Gate {
+                lib: &lib,
+                openjtalk,
+                entered: entered_tx,
+                release: Mutex::new(release_rx),
+            };
+
+            let gated_synthesizer = {
+                let mut synthesizer = MaybeUninit::uninit();
+                assert_ok(unsafe {
+                    // SAFETY:
+                    // - `onnxruntime` is valid for reads.
+                    // - `gate` outlives the synthesizer.
+                    // - `synthesizer` is valid for writes.
+                    lib.voicevox_synthesizer_new_with_text_analyzer(
+                        onnxruntime,
+                        VoicevoxTextAnalyzer {
+                            analyze: Some(analyze),
+                            free_json: Some(free_json),
+                            user_data: &raw const gate as *mut c_void,
+                        },
+                        VoicevoxInitializeOptions {
+                            acceleration_mode:
+                                c_api::VoicevoxAccelerationMode_VOICEVOX_ACCELERATION_MODE_CPU,
+                            ..lib.voicevox_make_default_initialize_options()
+                        },
+                        synthesizer.as_mut_ptr(),
+                    )
+                });
+                // SAFETY: `voicevox_synthesizer_new_with_text_analyzer` initializes
+                // `synthesizer` if succeeded.
+                unsafe { synthesizer.assume_init() }
+            };
+
+            // SAFETY: `voicevox_synthesizer_load_voice_model` has no safety requirements.
+            assert_ok(unsafe {
+                lib.voicevox_synthesizer_load_voice_model(
+                    gated_synthesizer,
+                    model,
+                    lib.voicevox_make_default_load_voice_model_options(),
+                )
+            });
+
+            let (tx, rx) = mpsc::sync_channel(1);
+            let completion = Completion { lib: &lib, tx };
+
+            let job = {
+                let mut job = MaybeUninit::uninit();
+                assert_ok(unsafe {
+                    // SAFETY:
+                    // - A `CString` is a valid string.
+                    // - `completion` outlives the job, since we wait for `rx` below.
+                    // - `job` is valid for writes.
+                    lib.voicevox_synthesizer_tts_async(
+                        gated_synthesizer,
+                        text.as_ptr(),
+                        STYLE_ID,
+                        lib.voicevox_make_default_tts_options(),
+                        lib.voicevox_make_default_job_options(),
+                        Some(on_complete),
+                        &raw const completion as *mut c_void,
+                        job.as_mut_ptr(),
+                    )
+                });
+                // SAFETY: `voicevox_synthesizer_tts_async` initializes `job` if succeeded.
+                unsafe { job.assume_init() }
+            };
+
+            entered_rx.recv().unwrap();
+            // SAFETY: `voicevox_job_cancel` has no safety requirements.
+            unsafe { lib.voicevox_job_cancel(job) };
+            release_tx.send(()).unwrap();
+
+            let (result_code, wav) = rx.recv().unwrap();
+            std::assert_eq!(
+                c_api::VoicevoxResultCode_VOICEVOX_RESULT_CANCELLED,
+                result_code,
+            );
+            std::assert_eq!(None, wav);
+
+            // SAFETY: `voicevox_job_delete` and `voicevox_synthesizer_delete` have no safety
+            // requirements.
+            unsafe { lib.voicevox_job_delete(job) };
+            unsafe { lib.voicevox_synthesizer_delete(gated_synthesizer) };
+        }
+
+        // 重い推論の途中でキャンセルし、推論そのものが止まることを確認する
+        {
+            let long_text = CString::new(self.text.repeat(8)).unwrap();
+
+            let audio_query_json = {
+                let mut json = MaybeUninit::uninit();
+                assert_ok(unsafe {
+                    // SAFETY:
+                    // - A `CString` is a valid string.
+                    // - `json` is valid for writes.
+                    lib.voicevox_synthesizer_create_audio_query(
+                        synthesizer,
+                        long_text.as_ptr(),
+                        STYLE_ID,
+                        json.as_mut_ptr(),
+                    )
+                });
+                // SAFETY: `voicevox_synthesizer_create_audio_query` initializes `json` if
+                // succeeded.
+                unsafe { json.assume_init() }
+            };
+
+            // 推論が一回で何秒かかるのかを測る
+            let (elapsed, ()) = timed(|| synthesis(&lib, synthesizer, audio_query_json));
+
+            let (tx, rx) = mpsc::sync_channel(1);
+            let completion = Completion { lib: &lib, tx };
+
+            let job = {
+                let mut job = MaybeUninit::uninit();
+                assert_ok(unsafe {
+                    // SAFETY:
+                    // - `audio_query_json` is a valid string.
+                    // - `completion` outlives the job, since we wait for `rx` below.
+                    // - `job` is valid for writes.
+                    lib.voicevox_synthesizer_synthesis_async(
+                        synthesizer,
+                        audio_query_json,
+                        STYLE_ID,
+                        lib.voicevox_make_default_synthesis_options(),
+                        VoicevoxJobOptions { cancellable: true },
+                        Some(on_complete),
+                        &raw const completion as *mut c_void,
+                        job.as_mut_ptr(),
+                    )
+                });
+                // SAFETY: `voicevox_synthesizer_synthesis_async` initializes `job` if succeeded.
+                unsafe { job.assume_init() }
+            };
+
+            // 推論の大半はデコードであるため、この時点ではデコードの途中であるはず
+            thread::sleep(elapsed / 8);
+
+            let (elapsed_after_cancel, ()) = timed(|| {
+                // SAFETY: `voicevox_job_cancel` has no safety requirements.
+                unsafe { lib.voicevox_job_cancel(job) };
+
+                let (result_code, wav) = rx.recv().unwrap();
+                std::assert_eq!(
+                    c_api::VoicevoxResultCode_VOICEVOX_RESULT_CANCELLED,
+                    result_code,
+                );
+                std::assert_eq!(None, wav);
+
+                // 推論がバックグラウンドで続いているのなら、同じモデルの推論は残りの推論が終わるまで
+                // 待たされる
+                synthesis(&lib, synthesizer, audio_query_json);
+            });
+            std::assert!(
+                elapsed_after_cancel < elapsed * 3 / 2,
+                "the inference should have been terminated: {elapsed_after_cancel:?} >= 1.5 × \
+                 {elapsed:?}",
+            );
+
+            // SAFETY: `voicevox_job_delete` and `voicevox_json_free` have no safety requirements.
+            unsafe { lib.voicevox_job_delete(job) };
+            unsafe { lib.voicevox_json_free(audio_query_json) };
+        }
+
+        // 処理の途中で`voicevox_synthesizer_delete`を呼ぶ
+        {
+            let (entered_tx, entered_rx) = mpsc::sync_channel(1);
+            let (release_tx, release_rx) = mpsc::sync_channel(1);
+            let gate = Gate {
+                lib: &lib,
+                openjtalk,
+                entered: entered_tx,
+                release: Mutex::new(release_rx),
+            };
+
+            let gated_synthesizer = {
+                let mut synthesizer = MaybeUninit::uninit();
+                assert_ok(unsafe {
+                    // SAFETY:
+                    // - `onnxruntime` is valid for reads.
+                    // - `gate` outlives the synthesizer.
+                    // - `synthesizer` is valid for writes.
+                    lib.voicevox_synthesizer_new_with_text_analyzer(
+                        onnxruntime,
+                        VoicevoxTextAnalyzer {
+                            analyze: Some(analyze),
+                            free_json: Some(free_json),
+                            user_data: &raw const gate as *mut c_void,
+                        },
+                        VoicevoxInitializeOptions {
+                            acceleration_mode:
+                                c_api::VoicevoxAccelerationMode_VOICEVOX_ACCELERATION_MODE_CPU,
+                            ..lib.voicevox_make_default_initialize_options()
+                        },
+                        synthesizer.as_mut_ptr(),
+                    )
+                });
+                // SAFETY: `voicevox_synthesizer_new_with_text_analyzer` initializes
+                // `synthesizer` if succeeded.
+                unsafe { synthesizer.assume_init() }
+            };
+
+            // SAFETY: `voicevox_synthesizer_load_voice_model` has no safety requirements.
+            assert_ok(unsafe {
+                lib.voicevox_synthesizer_load_voice_model(
+                    gated_synthesizer,
+                    model,
+                    lib.voicevox_make_default_load_voice_model_options(),
+                )
+            });
+
+            let (tx, rx) = mpsc::sync_channel(1);
+            let completion = Completion { lib: &lib, tx };
+
+            let job = {
+                let mut job = MaybeUninit::uninit();
+                assert_ok(unsafe {
+                    // SAFETY:
+                    // - A `CString` is a valid string.
+                    // - `completion` outlives the job, since we wait for `rx` below.
+                    // - `job` is valid for writes.
+                    lib.voicevox_synthesizer_tts_async(
+                        gated_synthesizer,
+                        text.as_ptr(),
+                        STYLE_ID,
+                        lib.voicevox_make_default_tts_options(),
+                        lib.voicevox_make_default_job_options(),
+                        Some(on_complete),
+                        &raw const completion as *mut c_void,
+                        job.as_mut_ptr(),
+                    )
+                });
+                // SAFETY: `voicevox_synthesizer_tts_async` initializes `job` if succeeded.
+                unsafe { job.assume_init() }
+            };
+
+            entered_rx.recv().unwrap();
+
+            thread::scope(|scope| {
+                let (lib, gated_synthesizer) = (&lib, SendPtr(gated_synthesizer));
+                let deleting = scope.spawn(move || {
+                    // SAFETY: `voicevox_synthesizer_delete` has no safety requirements.
+                    unsafe { lib.voicevox_synthesizer_delete(gated_synthesizer.into_inner()) };
+                });
+
+                // `voicevox_synthesizer_delete`は、テキスト解析が終わるまで帰らないはず
+                thread::sleep(Duration::from_millis(500));
+                std::assert!(!deleting.is_finished());
+
+                release_tx.send(()).unwrap();
+                deleting.join().unwrap();
+            });
+            // ここで`gate`がdropされても、テキスト解析器が呼ばれることはもう無い
+
+            let (result_code, wav) = rx.recv().unwrap();
+            std::assert_eq!(
+                c_api::VoicevoxResultCode_VOICEVOX_RESULT_CANCELLED,
+                result_code,
+            );
+            std::assert_eq!(None, wav);
+
+            // SAFETY: `voicevox_job_delete` has no safety requirements.
+            unsafe { lib.voicevox_job_delete(job) };
+        }
+
+        // SAFETY: `voicevox_voice_model_file_delete`, `voicevox_open_jtalk_rc_delete`,
+        // `voicevox_synthesizer_delete` have no safety requirements.
+        unsafe { lib.voicevox_voice_model_file_delete(model) };
+        unsafe { lib.voicevox_synthesizer_delete(synthesizer) };
+        unsafe { lib.voicevox_open_jtalk_rc_delete(openjtalk) };
+
+        return Ok(());
+
+        const STYLE_ID: u32 = 0;
+
+        struct SendPtr(*mut VoicevoxSynthesizer);
+
+        // SAFETY: `VoicevoxSynthesizer` can be deleted from any thread.
+        unsafe impl Send for SendPtr {}
+
+        impl SendPtr {
+            // クロージャが`self.0`のみをキャプチャしてしまわないように、メソッドとして取り出す
+            fn into_inner(self) -> *mut VoicevoxSynthesizer {
+                self.0
+            }
+        }
+
+        fn timed<T>(f: impl FnOnce() -> T) -> (Duration, T) {
+            let start = Instant::now();
+            let output = f();
+            (start.elapsed(), output)
+        }
+
+        fn synthesis(lib: &CApi, synthesizer: *const VoicevoxSynthesizer, json: *const c_char) {
+            let mut wav_length = MaybeUninit::uninit();
+            let mut wav = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - `json` is a valid string.
+                // - `wav_length` is valid for writes.
+                // - `wav` is valid for writes.
+                lib.voicevox_synthesizer_synthesis(
+                    synthesizer,
+                    json,
+                    STYLE_ID,
+                    lib.voicevox_make_default_synthesis_options(),
+                    wav_length.as_mut_ptr(),
+                    wav.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_synthesizer_synthesis` initializes `wav` if succeeded.
+            unsafe { lib.voicevox_wav_free(wav.assume_init()) };
+        }
+
+        struct Completion<'a> {
+            lib: &'a CApi,
+            tx: SyncSender<(VoicevoxResultCode, Option<Vec<u8>>)>,
+        }
+
+        unsafe extern "C" fn on_complete(
+            result_code: VoicevoxResultCode,
+            wav_length: usize,
+            wav: *mut u8,
+            user_data: *mut c_void,
+        ) {
+            // SAFETY: `user_data` points to a `Completion`.
+            let Completion { lib, tx } = unsafe { &*(user_data as *const Completion<'_>) };
+            let wav = (!wav.is_null()).then(|| {
+                // SAFETY: `voicevox_synthesizer_tts_async` lends a valid slice.
+                let copied = unsafe { slice::from_raw_parts(wav, wav_length) }.to_owned();
+                // SAFETY: `wav` is valid and is no longer used.
+                unsafe { lib.voicevox_wav_free(wav) };
+                copied
+            });
+            tx.send((result_code, wav)).unwrap();
+        }
+
+        /// `release`を受け取るまでテキスト解析をブロックする、OpenJTalkのラッパー。
+        struct Gate<'a> {
+            lib: &'a CApi,
+            openjtalk: *const OpenJtalkRc,
+            entered: SyncSender<()>,
+            release: Mutex<Receiver<()>>,
+        }
+
+        unsafe extern "C" fn analyze(text: *const c_char, user_data: *mut c_void) -> *mut c_char {
+            // SAFETY: `user_data` points to a `Gate`.
+            let Gate {
+                lib,
+                openjtalk,
+                entered,
+                release,
+            } = unsafe { &*(user_data as *const Gate<'_>) };
+
+            entered.send(()).unwrap();
+            release.lock().unwrap().recv().unwrap();
+
+            let mut json = ptr::null_mut();
+            // SAFETY:
+            // - `text` is a valid string.
+            // - `json` is valid for writes.
+            let result_code =
+                unsafe { lib.voicevox_open_jtalk_rc_analyze(*openjtalk, text, &mut json) };
+            if result_code != c_api::VoicevoxResultCode_VOICEVOX_RESULT_OK {
+                return ptr::null_mut();
+            }
+            json
+        }
+
+        unsafe extern "C" fn free_json(json: *mut c_char, user_data: *mut c_void) {
+            // SAFETY: `user_data` points to a `Gate`.
+            let Gate { lib, .. } = unsafe { &*(user_data as *const Gate<'_>) };
+            // SAFETY: `json` comes from `voicevox_open_jtalk_rc_analyze`.
+            unsafe { lib.voicevox_json_free(json) };
+        }
+
+        fn assert_ok(result_code: VoicevoxResultCode) {
+            std::assert_eq!(c_api::VoicevoxResultCode_VOICEVOX_RESULT_OK, result_code);
+        }
+    }
+
+    fn assert_output(&self, output: Utf8Output) -> AssertResult {
+        output
+            .mask_timestamps()
+            .mask_unix_onnxruntime_filename()
+            .mask_windows_video_cards()
+            .assert()
+            .try_success()?
+            .try_stdout("")?
+            .try_stderr(&*SNAPSHOTS.stderr)
+    }
+}
+
+static SNAPSHOTS: LazyLock<Snapshots> = snapshots::section!(synthesizer_tts_async);
+
+#[derive(Deserialize)]
+struct Snapshots {
+    #[serde(deserialize_with = "snapshots::deserialize_platform_specific_snapshot")]
+    stderr: String,
+}
@@ -14,7 +14,8 @@ use serde::{Deserialize, Serialize};
 use test_util::{
     OPEN_JTALK_DIC_DIR,
     c_api::{
-        self, CApi, VoicevoxInitializeOptions, VoicevoxLoadOnnxruntimeOptions, VoicevoxResultCode,
+        self, CApi, VoicevoxInitializeOptions, VoicevoxLoadOnnxruntimeOptions,
+        VoicevoxOperationPlacement, VoicevoxResultCode,
     },
 };
 
@@ -93,11 +94,27 @@ impl assert_cdylib::TestCase for TestCase {
             unsafe { openjtalk.assume_init() }
         };
 
+        let operation_placements = [
+            VoicevoxOperationPlacement {
+                operation:
+                    c_api::VoicevoxInferenceOperationKind_VOICEVOX_INFERENCE_OPERATION_KIND_TALK_PREDICT_DURATION,
+                device: c_api::VoicevoxOperationDevice_VOICEVOX_OPERATION_DEVICE_CPU,
+                cpu_num_threads: -1,
+            },
+            VoicevoxOperationPlacement {
+                operation:
+                    c_api::VoicevoxInferenceOperationKind_VOICEVOX_INFERENCE_OPERATION_KIND_TALK_DECODE,
+                device: c_api::VoicevoxOperationDevice_VOICEVOX_OPERATION_DEVICE_CPU,
+                cpu_num_threads: 1,
+            },
+        ];
+
         let synthesizer = {
             let mut synthesizer = MaybeUninit::uninit();
             assert_ok(unsafe {
                 // SAFETY:
                 // - `onnxruntime` is valid for reads.
+                // - `operation_placements` is valid for reads.
                 // - `synthesizer` is valid for writes.
                 lib.voicevox_synthesizer_new(
                     onnxruntime,
@@ -105,6 +122,8 @@ impl assert_cdylib::TestCase for TestCase {
                     VoicevoxInitializeOptions {
                         acceleration_mode:
                             c_api::VoicevoxAccelerationMode_VOICEVOX_ACCELERATION_MODE_CPU,
+                        operation_placements: operation_placements.as_ptr(),
+                        operation_placements_length: operation_placements.len(),
                         ..lib.voicevox_make_default_initialize_options()
                     },
                     synthesizer.as_mut_ptr(),
@@ -0,0 +1,134 @@
+//! `voicevox_voice_model_file_open_from_memory`で、ファイルパスから開いたときと同じ音声モデルが得られ
+//! ることを確認する。また長さ0のNULLを渡したときに、パニックせずにエラーになることを確認する。
+
+use std::{mem::MaybeUninit, sync::LazyLock};
+
+use assert_cmd::assert::AssertResult;
+use indexmap::IndexSet;
+use libloading::Library;
+use serde::{Deserialize, Serialize};
+use test_util::{
+    SAMPLE_VOICE_MODEL_FILE_PATH,
+    c_api::{self, CApi, VoicevoxResultCode},
+};
+
+use crate::{
+    assert_cdylib::{self, Utf8Output, case},
+    snapshots,
+};
+
+case!(TestCase);
+
+#[derive(Serialize, Deserialize)]
+struct TestCase;
+
+#[typetag::serde(name = "voice_model_file_open_from_memory")]
+impl assert_cdylib::TestCase for TestCase {
+    unsafe fn exec(&self, lib: Library) -> anyhow::Result<()> {
+        // SAFETY: The safety contract must be upheld by the caller.
+        let lib = unsafe { CApi::from_library(lib) }?;
+
+        let from_path = {
+            let mut model = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - `SAMPLE_VOICE_MODEL_FILE_PATH` is a valid string.
+                // - `model` is valid for writes.
+                lib.voicevox_voice_model_file_open(
+                    c_api::SAMPLE_VOICE_MODEL_FILE_PATH.as_ptr(),
+                    model.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_voice_model_file_open` initializes `model` if succeeded.
+            unsafe { model.assume_init() }
+        };
+
+        let from_memory = {
+            let bytes = std::fs::read(SAMPLE_VOICE_MODEL_FILE_PATH)?;
+            let mut model = MaybeUninit::uninit();
+            assert_ok(unsafe {
+                // SAFETY:
+                // - `bytes` is valid for reads of `bytes.len()` bytes.
+                // - `model` is valid for writes.
+                lib.voicevox_voice_model_file_open_from_memory(
+                    bytes.as_ptr(),
+                    bytes.len(),
+                    model.as_mut_ptr(),
+                )
+            });
+            // SAFETY: `voicevox_voice_model_file_open_from_memory` initializes `model` if
+            // succeeded.
+            unsafe { model.assume_init() }
+        };
+
+        let id = |model| {
+            let mut id = [0; 16];
+            // SAFETY: `id` is valid for writes.
+            unsafe { lib.voicevox_voice_model_file_id(model, &mut id) };
+            id
+        };
+        std::assert_eq!(id(from_path), id(from_memory));
+
+        {
+            let bytes = b"not a zip";
+            let mut model = MaybeUninit::uninit();
+            // SAFETY:
+            // - `bytes` is valid for reads of `bytes.len()` bytes.
+            // - `model` is valid for writes.
+            let result = unsafe {
+                lib.voicevox_voice_model_file_open_from_memory(
+                    bytes.as_ptr(),
+                    bytes.len(),
+                    model.as_mut_ptr(),
+                )
+            };
+            std::assert_eq!(
+                c_api::VoicevoxResultCode_VOICEVOX_RESULT_OPEN_ZIP_FILE_ERROR,
+                result,
+            );
+        }
+
+        {
+            let mut model = MaybeUninit::uninit();
+            // SAFETY:
+            // - `data` may be NULL since `data_length` is 0.
+            // - `model` is valid for writes.
+            let result = unsafe {
+                lib.voicevox_voice_model_file_open_from_memory(
+                    std::ptr::null(),
+                    0,
+                    model.as_mut_ptr(),
+                )
+            };
+            std::assert_eq!(
+                c_api::VoicevoxResultCode_VOICEVOX_RESULT_OPEN_ZIP_FILE_ERROR,
+                result,
+            );
+        }
+
+        // SAFETY: `voicevox_voice_model_file_delete` has no safety requirement.
+        unsafe { lib.voicevox_voice_model_file_delete(from_path) };
+        unsafe { lib.voicevox_voice_model_file_delete(from_memory) };
+        return Ok(());
+
+        fn assert_ok(result_code: VoicevoxResultCode) {
+            std::assert_eq!(c_api::VoicevoxResultCode_VOICEVOX_RESULT_OK, result_code);
+        }
+    }
+
+    fn assert_output(&self, output: Utf8Output) -> AssertResult {
+        let mut assert = output.assert().try_success()?.try_stdout("")?;
+        for s in &SNAPSHOTS.stderr_matches_all {
+            let p = predicates::str::is_match(s).unwrap_or_else(|e| panic!("{e}"));
+            assert = assert.try_stderr(p)?;
+        }
+        Ok(assert)
+    }
+}
+
+static SNAPSHOTS: LazyLock<Snapshots> = snapshots::section!(voice_model_file_open_from_memory);
+
+#[derive(Deserialize)]
+struct Snapshots {
+    stderr_matches_all: IndexSet<String>,
+}
@@ -1,5 +1,6 @@
 package jp.hiroshiba.voicevoxcore;
 
+import com.google.gson.Gson;
 import com.google.gson.annotations.Expose;
 import jakarta.annotation.Nonnull;
 import java.util.ArrayList;
@@ -110,7 +111,44 @@ public final class FrameAudioQuery {
     rsValidate();
   }
 
+  /**
+   * {@link #f0}を2<sup>semitones/12</sup>倍することで、{@code semitones}半音だけ移調する。
+   *
+   * <p>{@link Score#transpose}を行った楽譜から生成しなおすのとは異なり、音素やフレーム長、音量は変化しない。
+   *
+   * @param semitones 半音の数。{@code -128}以上{@code 127}以下。
+   * @throws InvalidQueryException このインスタンスに対する{@link #validate}が失敗する場合
+   */
+  public void transpose(int semitones) {
+    FrameAudioQuery transposed =
+        new Gson().fromJson(rsTranspose(semitones), FrameAudioQuery.class);
+    f0 = transposed.f0;
+  }
+
+  /**
+   * 全ての音素のフレーム長を{@code ratio}倍し、それに合わせて{@link #f0}と{@link #volume}を線形補間する。
+   *
+   * <p>端数の扱いについては{@link Score#stretch}と同じ。{@link
+   * #f0}については無声（{@code 0}）のフレームと有声のフレームの間は補間されず、近い方のフレームの値が用いられる。
+   *
+   * @param ratio 倍率。正の有限値。
+   * @throws InvalidQueryException このインスタンスに対する{@link #validate}が失敗する場合、もしくは{@link
+   *     Score#stretch}と同じ条件を満たす場合
+   */
+  public void stretch(float ratio) {
+    FrameAudioQuery stretched = new Gson().fromJson(rsStretch(ratio), FrameAudioQuery.class);
+    f0 = stretched.f0;
+    volume = stretched.volume;
+    phonemes = stretched.phonemes;
+  }
+
   // `phonemes`の型が`List`のため、`clone`は実装できない
 
   private native void rsValidate();
+
+  @Nonnull
+  private native String rsTranspose(int semitones);
+
+  @Nonnull
+  private native String rsStretch(float ratio);
 }
@@ -0,0 +1,63 @@
+package jp.hiroshiba.voicevoxcore;
+
+/**
+ * 推論の操作。{@link OperationPlacement} の指定に用いる。
+ *
+ * <p>{@link #toString} は {@code "talk.decode"} のように、ドメインと操作の名前を {@code .} で繋げたものを返す。
+ */
+public final class InferenceOperationKind {
+  /** トーク: 音素長の推論。 */
+  public static final InferenceOperationKind TALK_PREDICT_DURATION =
+      new InferenceOperationKind("talk.predict_duration");
+
+  /** トーク: 音高の推論。 */
+  public static final InferenceOperationKind TALK_PREDICT_INTONATION =
+      new InferenceOperationKind("talk.predict_intonation");
+
+  /** トーク: 波形の生成。 */
+  public static final InferenceOperationKind TALK_DECODE =
+      new InferenceOperationKind("talk.decode");
+
+  /** ストリーミングトーク: 音素長の推論。 */
+  public static final InferenceOperationKind STREAMING_TALK_PREDICT_DURATION =
+      new InferenceOperationKind("streaming_talk.predict_duration");
+
+  /** ストリーミングトーク: 音高の推論。 */
+  public static final InferenceOperationKind STREAMING_TALK_PREDICT_INTONATION =
+      new InferenceOperationKind("streaming_talk.predict_intonation");
+
+  /** ストリーミングトーク: 中間表現の生成。 */
+  public static final InferenceOperationKind STREAMING_TALK_GENERATE_FULL_INTERMEDIATE =
+      new InferenceOperationKind("streaming_talk.generate_full_intermediate");
+
+  /** ストリーミングトーク: 中間表現からの波形の生成。 */
+  public static final InferenceOperationKind STREAMING_TALK_RENDER_AUDIO_SEGMENT =
+      new InferenceOperationKind("streaming_talk.render_audio_segment");
+
+  /** ソング: 子音長の推論。 */
+  public static final InferenceOperationKind SINGING_TEACHER_PREDICT_SING_CONSONANT_LENGTH =
+      new InferenceOperationKind("singing_teacher.predict_sing_consonant_length");
+
+  /** ソング: 音高の推論。 */
+  public static final InferenceOperationKind SINGING_TEACHER_PREDICT_SING_F0 =
+      new InferenceOperationKind("singing_teacher.predict_sing_f0");
+
+  /** ソング: 音量の推論。 */
+  public static final InferenceOperationKind SINGING_TEACHER_PREDICT_SING_VOLUME =
+      new InferenceOperationKind("singing_teacher.predict_sing_volume");
+
+  /** ソング: 波形の生成。 */
+  public static final InferenceOperationKind FRAME_DECODE_SF_DECODE =
+      new InferenceOperationKind("frame_decode.sf_decode");
+
+  private final String identifier;
+
+  private InferenceOperationKind(String identifier) {
+    this.identifier = identifier;
+  }
+
+  @Override
+  public String toString() {
+    return identifier;
+  }
+}
@@ -63,7 +63,8 @@ public final class Note implements Cloneable {
    * 歌詞。
    *
    * <ul>
-   *   <li>音符の場合、一つのモーラを表すひらがな/カタカナ（例: {@code "ド"}, {@code "ファ"}）。
+   *   <li>音符の場合、一つ以上のモーラを表すひらがな/カタカナ（例: {@code "ド"}, {@code "ファ"}, {@code
+   *       "きゃっ"}）。{@code "ー"}は直前のモーラの母音を延ばす。
    *   <li>休符の場合、空文字列。
    * </ul>
    */
@@ -84,6 +85,22 @@ public final class Note implements Cloneable {
   @Expose
   public long frameLength;
 
+  /**
+   * 強弱。
+   *
+   * <p>{@code "pp"}, {@code "p"}, {@code "mp"}, {@code "mf"}, {@code "f"}, {@code
+   * "ff"}のいずれか。非{@code null}の場合、この音符の区間の音量が{@link #volumeScaleOf}の倍率で拡大または縮小される。{@code
+   * null}は{@code "mf"}と同じ。
+   */
+  @Expose @Nullable public String dynamics;
+
+  /**
+   * ブレスであるかどうか。
+   *
+   * <p>{@code true}の場合、休符の区間を無音ではなく息継ぎの音にする。休符でのみ{@code true}にできる。
+   */
+  @Expose public boolean breath;
+
   private Note() {
     lyric = "";
     frameLength = 0;
@@ -110,15 +127,29 @@ public final class Note implements Cloneable {
    *             <li>{@link #key}が負。
    *             <li>{@link #lyric}が空文字列以外の、モーラとして不正な文字列。
    *             <li>{@link #frameLength}が負であるか、もしくは2<sup>53</sup>-1を超過する。
+   *             <li>{@link #dynamics}が{@code null}以外の、強弱として不正な文字列。
    *           </ul>
    *       <li>{@link #key}が{@code null}かつ{@link #lyric}が空文字列以外。
    *       <li>{@link #key}が非{@code null}かつ{@link #lyric}が空文字列。
+   *       <li>{@link #breath}が{@code true}かつ音符である。
+   *       <li>{@link #lyric}が複数のモーラから成り、かつ{@link #frameLength}がそのモーラ数より小さい。
    *     </ul>
    */
   public void validate() {
     rsValidate();
   }
 
+  /**
+   * 強弱に応じて音量に掛ける倍率を得る。{@code "mf"}を{@code 1}とする。
+   *
+   * @param dynamics {@link #dynamics}に設定する値。{@code null}は{@code "mf"}と同じ。
+   * @return 倍率。
+   * @throws IllegalArgumentException {@code dynamics}が強弱として不正な文字列の場合。
+   */
+  public static float volumeScaleOf(@Nullable String dynamics) {
+    return rsVolumeScaleOf(dynamics);
+  }
+
   @Override
   public boolean equals(Object obj) {
     if (!(obj instanceof Note)) return false;
@@ -127,7 +158,9 @@ public final class Note implements Cloneable {
     return (id == null ? other.id == null : id.equals(other.id))
         && (key == null ? other.key == null : key.equals(other.key))
         && lyric.equals(other.lyric)
-        && frameLength == other.frameLength;
+        && frameLength == other.frameLength
+        && (dynamics == null ? other.dynamics == null : dynamics.equals(other.dynamics))
+        && breath == other.breath;
   }
 
   @Override
@@ -137,8 +170,12 @@ public final class Note implements Cloneable {
     ret.key = key;
     ret.lyric = lyric;
     ret.frameLength = frameLength;
+    ret.dynamics = dynamics;
+    ret.breath = breath;
     return ret;
   }
 
   private native void rsValidate();
+
+  private static native float rsVolumeScaleOf(@Nullable String dynamics);
 }
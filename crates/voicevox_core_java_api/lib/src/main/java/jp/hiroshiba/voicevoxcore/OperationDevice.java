@@ -0,0 +1,23 @@
+package jp.hiroshiba.voicevoxcore;
+
+/** 推論の操作を行うデバイス。 */
+public final class OperationDevice {
+  /** CPU。 */
+  public static final OperationDevice CPU = new OperationDevice("CPU");
+
+  /**
+   * {@link AccelerationMode} によって選ばれたデバイス。{@link AccelerationMode#CPU} のときや、利用可能なものが無かったときはCPUとなる。
+   */
+  public static final OperationDevice ACCELERATOR = new OperationDevice("ACCELERATOR");
+
+  private final String identifier;
+
+  private OperationDevice(String identifier) {
+    this.identifier = identifier;
+  }
+
+  @Override
+  public String toString() {
+    return identifier;
+  }
+}
@@ -0,0 +1,38 @@
+package jp.hiroshiba.voicevoxcore;
+
+import jakarta.annotation.Nonnull;
+import jakarta.annotation.Nullable;
+import jp.hiroshiba.voicevoxcore.internal.Utils;
+
+/** 推論の操作の配置先。 */
+public final class OperationPlacement {
+  /** 推論を行うデバイス。 */
+  @Nonnull public final OperationDevice device;
+
+  /** CPU利用数。{@code null} のときはシンセサイザ全体の設定に従う。0を指定すると環境に合わせたCPUが利用される。 */
+  @Nullable public final Integer cpuNumThreads;
+
+  /**
+   * CPU利用数をシンセサイザ全体の設定に従わせる。
+   *
+   * @param device 推論を行うデバイス。
+   */
+  public OperationPlacement(OperationDevice device) {
+    this.device = device;
+    this.cpuNumThreads = null;
+  }
+
+  /**
+   * CPU利用数を指定する。
+   *
+   * @param device 推論を行うデバイス。
+   * @param cpuNumThreads CPU利用数。
+   */
+  public OperationPlacement(OperationDevice device, int cpuNumThreads) {
+    if (!Utils.isU16(cpuNumThreads)) {
+      throw new IllegalArgumentException("cpuNumThreads");
+    }
+    this.device = device;
+    this.cpuNumThreads = cpuNumThreads;
+  }
+}
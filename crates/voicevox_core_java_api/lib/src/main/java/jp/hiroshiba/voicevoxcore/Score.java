@@ -1,5 +1,6 @@
 package jp.hiroshiba.voicevoxcore;
 
+import com.google.gson.Gson;
 import com.google.gson.annotations.Expose;
 import jakarta.annotation.Nonnull;
 import java.util.ArrayList;
@@ -70,7 +71,39 @@ public final class Score {
     rsValidate();
   }
 
+  /**
+   * 全ての音符を{@code semitones}半音だけ移調する。
+   *
+   * <p>{@code 0}から{@code 127}の範囲を超える音符は、{@code 0}または{@code 127}に丸められる。休符は変化しない。
+   *
+   * @param semitones 半音の数。{@code -128}以上{@code 127}以下。
+   * @throws InvalidQueryException このインスタンスに対する{@link #validate}が失敗する場合
+   */
+  public void transpose(int semitones) {
+    notes = new Gson().fromJson(rsTranspose(semitones), Score.class).notes;
+  }
+
+  /**
+   * 全ての音符と休符のフレーム長を{@code ratio}倍する。
+   *
+   * <p>端数は各音符の終了位置が四捨五入されるように調整される。同じ{@code ratio}で{@link
+   * FrameAudioQuery#stretch}を行ったものとは、{@link Queries#ensureCompatible}を満たしたままとなる。
+   *
+   * @param ratio 倍率。正の有限値。
+   * @throws InvalidQueryException このインスタンスに対する{@link #validate}が失敗する場合、{@code
+   *     ratio}が1/16以上16以下ではない場合、もしくは伸縮後のフレーム長の合計が2<sup>53</sup>以上になる場合
+   */
+  public void stretch(float ratio) {
+    notes = new Gson().fromJson(rsStretch(ratio), Score.class).notes;
+  }
+
   // `notes`の型が`List`のため、`clone`は実装できない
 
   private native void rsValidate();
+
+  @Nonnull
+  private native String rsTranspose(int semitones);
+
+  @Nonnull
+  private native String rsStretch(float ratio);
 }
@@ -0,0 +1,61 @@
+package jp.hiroshiba.voicevoxcore;
+
+import com.google.gson.annotations.Expose;
+import com.google.gson.annotations.SerializedName;
+import jakarta.annotation.Nonnull;
+
+/**
+ * 複数トラックの歌唱音声合成における、一つのトラック。
+ *
+ * <pre>{@code
+ * SongTrack track = new SongTrack(score, 6000, 3000);
+ * track.gain = 0.8f;
+ * track.pan = -0.5f;
+ * }</pre>
+ *
+ * <p>Gsonにおいてはフィールド名はsnake_caseとなる。<a
+ * href="https://github.com/VOICEVOX/voicevox_core/blob/main/docs/guide/user/serialization.md"
+ * target="_blank">データのシリアライゼーション</a>を参照。
+ *
+ * <p>Gsonについては将来的には <a href="https://github.com/VOICEVOX/voicevox_core/issues/984"
+ * target="_blank">Jacksonに切り替わる予定</a> 。
+ *
+ * @see jp.hiroshiba.voicevoxcore.blocking.Synthesizer#renderSongProject
+ */
+public final class SongTrack {
+  /** 楽譜。 */
+  @Expose @Nonnull public Score score;
+
+  /** {@link #score}から{@link FrameAudioQuery}を生成するためのスタイルID。 */
+  @SerializedName("singing_teacher")
+  @Expose
+  public int singingTeacher;
+
+  /** 歌唱音声合成に用いるスタイルID。 */
+  @Expose public int singer;
+
+  /** 音量の倍率。正の有限値。 */
+  @Expose public float gain;
+
+  /**
+   * 定位。{@code -1}が左、{@code 0}が中央、{@code 1}が右。
+   *
+   * <p>{@code 0}以外の場合、反対側のチャンネルの音量が{@code 1 - |pan|}倍される。
+   */
+  @Expose public float pan;
+
+  /**
+   * {@code SongTrack}をコンストラクトする。{@link #gain}は{@code 1}、{@link #pan}は{@code 0}となる。
+   *
+   * @param score 楽譜。
+   * @param singingTeacher {@code score}から{@link FrameAudioQuery}を生成するためのスタイルID。
+   * @param singer 歌唱音声合成に用いるスタイルID。
+   */
+  public SongTrack(@Nonnull Score score, int singingTeacher, int singer) {
+    this.score = score;
+    this.singingTeacher = singingTeacher;
+    this.singer = singer;
+    this.gain = 1f;
+    this.pan = 0f;
+  }
+}
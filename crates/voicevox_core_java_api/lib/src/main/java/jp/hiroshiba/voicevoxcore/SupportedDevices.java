@@ -50,14 +50,101 @@ public final class SupportedDevices implements Cloneable {
   @Nonnull
   public final boolean dml;
 
+  /**
+   * TensorRTが利用可能。
+   *
+   * <p>ONNX Runtimeの <a href=
+   * "https://onnxruntime.ai/docs/execution-providers/TensorRT-ExecutionProvider.html"
+   * target="_blank">TensorrtExecutionProvider</a>に対応する。 必要な環境についてはそちらを参照。
+   */
+  @SerializedName("tensorrt")
+  @Expose
+  @Nonnull
+  public final boolean tensorrt;
+
+  /**
+   * ROCmが利用可能。
+   *
+   * <p>ONNX Runtimeの <a href=
+   * "https://onnxruntime.ai/docs/execution-providers/ROCm-ExecutionProvider.html"
+   * target="_blank">ROCMExecutionProvider</a>に対応する。 必要な環境についてはそちらを参照。
+   */
+  @SerializedName("rocm")
+  @Expose
+  @Nonnull
+  public final boolean rocm;
+
+  /**
+   * Core MLが利用可能。
+   *
+   * <p>ONNX Runtimeの <a href=
+   * "https://onnxruntime.ai/docs/execution-providers/CoreML-ExecutionProvider.html"
+   * target="_blank">CoreMLExecutionProvider</a>に対応する。 必要な環境についてはそちらを参照。
+   */
+  @SerializedName("coreml")
+  @Expose
+  @Nonnull
+  public final boolean coreml;
+
+  /**
+   * XNNPACKが利用可能。
+   *
+   * <p>ONNX Runtimeの <a href=
+   * "https://onnxruntime.ai/docs/execution-providers/Xnnpack-ExecutionProvider.html"
+   * target="_blank">XnnpackExecutionProvider</a>に対応する。 GPUを必要とせず、CPU上で動作する。
+   */
+  @SerializedName("xnnpack")
+  @Expose
+  @Nonnull
+  public final boolean xnnpack;
+
+  /**
+   * OpenVINOが利用可能。
+   *
+   * <p>ONNX Runtimeの <a href=
+   * "https://onnxruntime.ai/docs/execution-providers/OpenVINO-ExecutionProvider.html"
+   * target="_blank">OpenVINOExecutionProvider</a>に対応する。 本ライブラリではCPUデバイスとして利用する。
+   */
+  @SerializedName("openvino")
+  @Expose
+  @Nonnull
+  public final boolean openvino;
+
+  /**
+   * oneDNNが利用可能。
+   *
+   * <p>ONNX Runtimeの <a href=
+   * "https://onnxruntime.ai/docs/execution-providers/oneDNN-ExecutionProvider.html"
+   * target="_blank">DnnlExecutionProvider</a>に対応する。 GPUを必要とせず、CPU上で動作する。
+   */
+  @SerializedName("onednn")
+  @Expose
+  @Nonnull
+  public final boolean onednn;
+
   private SupportedDevices() {
     throw new UnsupportedOperationException("You cannot deserialize `SupportedDevices`");
   }
 
-  private SupportedDevices(boolean cpu, boolean cuda, boolean dml) {
+  private SupportedDevices(
+      boolean cpu,
+      boolean cuda,
+      boolean dml,
+      boolean tensorrt,
+      boolean rocm,
+      boolean coreml,
+      boolean xnnpack,
+      boolean openvino,
+      boolean onednn) {
     this.cpu = cpu;
     this.cuda = cuda;
     this.dml = dml;
+    this.tensorrt = tensorrt;
+    this.rocm = rocm;
+    this.coreml = coreml;
+    this.xnnpack = xnnpack;
+    this.openvino = openvino;
+    this.onednn = onednn;
   }
 
   @Override
@@ -66,11 +153,20 @@ public final class SupportedDevices implements Cloneable {
       return false;
     }
     SupportedDevices other = (SupportedDevices) obj;
-    return cpu == other.cpu && cuda == other.cuda && dml == other.dml;
+    return cpu == other.cpu
+        && cuda == other.cuda
+        && dml == other.dml
+        && tensorrt == other.tensorrt
+        && rocm == other.rocm
+        && coreml == other.coreml
+        && xnnpack == other.xnnpack
+        && openvino == other.openvino
+        && onednn == other.onednn;
   }
 
   @Override
   public SupportedDevices clone() {
-    return new SupportedDevices(cpu, cuda, dml);
+    return new SupportedDevices(
+        cpu, cuda, dml, tensorrt, rocm, coreml, xnnpack, openvino, onednn);
   }
 }
@@ -1,6 +1,7 @@
 package jp.hiroshiba.voicevoxcore.blocking;
 
 import com.google.gson.Gson;
+import jakarta.annotation.Nonnull;
 import java.util.ArrayList;
 import java.util.Arrays;
 import java.util.List;
@@ -8,7 +9,7 @@ import jp.hiroshiba.voicevoxcore.AccentPhrase;
 import jp.hiroshiba.voicevoxcore.internal.Dll;
 
 /** テキスト解析機としてのOpen JTalk。 */
-public final class OpenJtalk {
+public final class OpenJtalk implements TextAnalyzer {
   static {
     Dll.loadLibrary();
   }
@@ -47,6 +48,14 @@ public final class OpenJtalk {
     rsUseUserDict(userDict);
   }
 
+  /**
+   * 日本語のテキストを解析する。
+   *
+   * @param text 日本語のテキスト。
+   * @return {@link AccentPhrase} のリスト。
+   */
+  @Override
+  @Nonnull
   public List<AccentPhrase> analyze(String text) {
     Gson gson = new Gson();
     String accentPhrasesJson = rsAnalyze(text);
@@ -2,18 +2,24 @@ package jp.hiroshiba.voicevoxcore.blocking;
 
 import com.google.gson.Gson;
 import com.google.gson.GsonBuilder;
+import com.google.gson.JsonObject;
 import jakarta.annotation.Nonnull;
 import java.util.ArrayList;
 import java.util.Arrays;
+import java.util.LinkedHashMap;
 import java.util.List;
+import java.util.Map;
 import java.util.UUID;
 import jp.hiroshiba.voicevoxcore.AccelerationMode;
 import jp.hiroshiba.voicevoxcore.AccentPhrase;
 import jp.hiroshiba.voicevoxcore.AudioQuery;
 import jp.hiroshiba.voicevoxcore.CharacterMeta;
 import jp.hiroshiba.voicevoxcore.FrameAudioQuery;
+import jp.hiroshiba.voicevoxcore.InferenceOperationKind;
 import jp.hiroshiba.voicevoxcore.OnExistingVoiceModelId;
+import jp.hiroshiba.voicevoxcore.OperationPlacement;
 import jp.hiroshiba.voicevoxcore.Score;
+import jp.hiroshiba.voicevoxcore.SongTrack;
 import jp.hiroshiba.voicevoxcore.StyleType;
 import jp.hiroshiba.voicevoxcore.exceptions.IncompatibleQueriesException;
 import jp.hiroshiba.voicevoxcore.exceptions.InvalidModelDataException;
@@ -22,6 +28,7 @@ import jp.hiroshiba.voicevoxcore.exceptions.RunModelException;
 import jp.hiroshiba.voicevoxcore.exceptions.StyleNotFoundException;
 import jp.hiroshiba.voicevoxcore.internal.Convert;
 import jp.hiroshiba.voicevoxcore.internal.Dll;
+import jp.hiroshiba.voicevoxcore.internal.Utils;
 
 /**
  * 音声シンセサイザ。
@@ -35,12 +42,12 @@ public final class Synthesizer {
 
   private long handle;
   @Nonnull private final Onnxruntime onnxruntime;
-  @Nonnull private final OpenJtalk openJtalk;
+  @Nonnull private final TextAnalyzer textAnalyzer;
 
-  private Synthesizer(Onnxruntime onnxruntime, OpenJtalk openJtalk, Builder builder) {
-    rsNew(onnxruntime, openJtalk, builder);
+  private Synthesizer(Onnxruntime onnxruntime, TextAnalyzer textAnalyzer, Builder builder) {
+    rsNew(onnxruntime, textAnalyzer, builder);
     this.onnxruntime = onnxruntime;
-    this.openJtalk = openJtalk;
+    this.textAnalyzer = textAnalyzer;
   }
 
   protected void finalize() throws Throwable {
@@ -59,13 +66,13 @@ public final class Synthesizer {
   }
 
   /**
-   * Open JTalk。
+   * テキスト解析器。
    *
-   * @return {@link OpenJtalk}。
+   * @return {@link OpenJtalk}、もしくはユーザー定義の {@link TextAnalyzer}。
    */
   @Nonnull
-  public OpenJtalk getOpenJtalk() {
-    return openJtalk;
+  public TextAnalyzer getOpenJtalk() {
+    return textAnalyzer;
   }
 
   /**
@@ -77,6 +84,16 @@ public final class Synthesizer {
     return rsIsGpuMode();
   }
 
+  /**
+   * 読み込んでいる音声モデルを共有する、非同期版の音声シンセサイザを作る。
+   *
+   * @return {@link jp.hiroshiba.voicevoxcore.nonblocking.Synthesizer}。
+   */
+  @Nonnull
+  public jp.hiroshiba.voicevoxcore.nonblocking.Synthesizer toNonblocking() {
+    return new jp.hiroshiba.voicevoxcore.nonblocking.Synthesizer(this);
+  }
+
   /**
    * メタ情報を取得する。
    *
@@ -436,7 +453,53 @@ public final class Synthesizer {
     return rsFrameSynthesis(frameAudioQueryJson, styleId);
   }
 
-  private native void rsNew(Onnxruntime onnxruntime, OpenJtalk openJtalk, Builder builder);
+  /**
+   * 歌唱音声合成用のクエリのうち、指定したフレーム区間のみを歌唱音声合成し、16bit PCMで返す。
+   *
+   * <p>区間の前後には文脈として数フレームずつ余分に推論を行うため、結果は{@link
+   * #frameSynthesis}で得られるWAVデータの該当部分とほぼ一致する。そのため一部のノートを編集したときに、その区間のみを再合成して差し替えることができる。ただしビット単位では一致せず、またクロスフェードも行わないため、継ぎ目でサンプル値がわずかに不連続になりうる。クリックノイズを避けたい場合は、呼び出し側で継ぎ目を数ミリ秒クロスフェードすること。
+   *
+   * <p>詳細はユーザーガイド<a
+   * href="https://github.com/VOICEVOX/voicevox_core/blob/main/docs/guide/user/song.md">歌唱音声合成</a>を参照。
+   *
+   * @param frameAudioQuery 歌唱音声合成用のクエリ
+   * @param styleId スタイルID
+   * @param start 区間の開始フレーム（含む）
+   * @param end 区間の終了フレーム（含まない）
+   * @return 16bit PCM
+   * @throws InvalidQueryException {@code frameAudioQuery}に対する{@link
+   *     FrameAudioQuery#validate}が失敗する場合、もしくは区間がクエリのフレーム数を超える場合
+   * @throws StyleNotFoundException {@code styleId}に対応するスタイルが見つからなかった場合
+   * @throws RunModelException 推論に失敗した場合
+   */
+  @Nonnull
+  public byte[] frameRender(FrameAudioQuery frameAudioQuery, int styleId, int start, int end)
+      throws RunModelException {
+    if (!Utils.isU32(styleId)) {
+      throw new IllegalArgumentException("styleId");
+    }
+
+    String frameAudioQueryJson = Convert.jsonFromQueryLike(frameAudioQuery, "不正なFrameAudioQueryです");
+
+    return rsFrameRender(frameAudioQueryJson, styleId, start, end);
+  }
+
+  /**
+   * 複数のトラックを歌唱音声合成し、ステレオのWAVデータとして重ね合わせるためのオブジェクトを生成する。
+   *
+   * <p>詳細はユーザーガイド<a
+   * href="https://github.com/VOICEVOX/voicevox_core/blob/main/docs/guide/user/song.md">歌唱音声合成</a>を参照。
+   *
+   * @param tracks トラックのリスト
+   * @return {@link RenderSongProjectConfigurator}。
+   * @see RenderSongProjectConfigurator#perform
+   */
+  @Nonnull
+  public RenderSongProjectConfigurator renderSongProject(List<SongTrack> tracks) {
+    return new RenderSongProjectConfigurator(this, tracks);
+  }
+
+  private native void rsNew(Onnxruntime onnxruntime, TextAnalyzer textAnalyzer, Builder builder);
 
   private native boolean rsIsGpuMode();
 
@@ -503,10 +566,25 @@ public final class Synthesizer {
   private native byte[] rsFrameSynthesis(String frameAudioQuery, int styleId)
       throws RunModelException;
 
+  @Nonnull
+  private native byte[] rsFrameRender(String frameAudioQuery, int styleId, int start, int end)
+      throws RunModelException;
+
+  @Nonnull
+  private native byte[] rsRenderSongProject(String tracks, int outputSamplingRate)
+      throws RunModelException;
+
   private native void rsDrop();
 
-  public static Builder builder(Onnxruntime onnxruntime, OpenJtalk openJtalk) {
-    return new Builder(onnxruntime, openJtalk);
+  /**
+   * {@link Synthesizer} のビルダーを作成する。
+   *
+   * @param onnxruntime ONNX Runtime。
+   * @param textAnalyzer テキスト解析器。{@link OpenJtalk} もしくはユーザー定義の {@link TextAnalyzer}。
+   * @return ビルダー。
+   */
+  public static Builder builder(Onnxruntime onnxruntime, TextAnalyzer textAnalyzer) {
+    return new Builder(onnxruntime, textAnalyzer);
   }
 
   /**
@@ -516,7 +594,7 @@ public final class Synthesizer {
    */
   public static final class Builder {
     private Onnxruntime onnxruntime;
-    private OpenJtalk openJtalk;
+    private TextAnalyzer textAnalyzer;
 
     @SuppressWarnings("unused")
     private AccelerationMode accelerationMode;
@@ -524,9 +602,15 @@ public final class Synthesizer {
     @SuppressWarnings("unused")
     private int cpuNumThreads;
 
-    public Builder(Onnxruntime onnxruntime, OpenJtalk openJtalk) {
+    private final Map<InferenceOperationKind, OperationPlacement> operationPlacements =
+        new LinkedHashMap<>();
+
+    @SuppressWarnings("unused")
+    private String operationPlacementsJson = "{}";
+
+    public Builder(Onnxruntime onnxruntime, TextAnalyzer textAnalyzer) {
       this.onnxruntime = onnxruntime;
-      this.openJtalk = openJtalk;
+      this.textAnalyzer = textAnalyzer;
     }
 
     /**
@@ -554,13 +638,41 @@ public final class Synthesizer {
       return this;
     }
 
+    /**
+     * 推論の操作ごとに、推論を行うデバイスとCPU利用数を指定する。
+     *
+     * <p>指定しなかった操作については、音素長や音高の推論といった軽いものはCPUで、波形の生成といった重いものは {@link
+     * jp.hiroshiba.voicevoxcore.OperationDevice#ACCELERATOR} で行われる。
+     *
+     * @param operation 推論の操作。
+     * @param placement 配置先。
+     * @return ビルダー。
+     */
+    public Builder operationPlacement(
+        InferenceOperationKind operation, OperationPlacement placement) {
+      operationPlacements.put(operation, placement);
+
+      JsonObject json = new JsonObject();
+      for (Map.Entry<InferenceOperationKind, OperationPlacement> entry :
+          operationPlacements.entrySet()) {
+        JsonObject placementJson = new JsonObject();
+        placementJson.addProperty("device", entry.getValue().device.toString());
+        if (entry.getValue().cpuNumThreads != null) {
+          placementJson.addProperty("cpu_num_threads", entry.getValue().cpuNumThreads);
+        }
+        json.add(entry.getKey().toString(), placementJson);
+      }
+      operationPlacementsJson = json.toString();
+      return this;
+    }
+
     /**
      * {@link Synthesizer} を構築する。
      *
      * @return {@link Synthesizer}。
      */
     public Synthesizer build() {
-      Synthesizer synthesizer = new Synthesizer(onnxruntime, openJtalk, this);
+      Synthesizer synthesizer = new Synthesizer(onnxruntime, textAnalyzer, this);
       return synthesizer;
     }
   }
@@ -729,4 +841,45 @@ public final class Synthesizer {
       return synthesizer.rsTts(this.text, this.styleId, this.interrogativeUpspeak);
     }
   }
+
+  /** {@link Synthesizer#renderSongProject} のオプション。 */
+  public final class RenderSongProjectConfigurator {
+    private Synthesizer synthesizer;
+    private List<SongTrack> tracks;
+    private int outputSamplingRate;
+
+    private RenderSongProjectConfigurator(Synthesizer synthesizer, List<SongTrack> tracks) {
+      this.synthesizer = synthesizer;
+      this.tracks = tracks;
+      this.outputSamplingRate = 24000;
+    }
+
+    /**
+     * 出力のサンプリングレート。
+     *
+     * @param outputSamplingRate 出力のサンプリングレート。{@code 24000}の倍数。
+     * @return {@link RenderSongProjectConfigurator}。
+     */
+    @Nonnull
+    public RenderSongProjectConfigurator outputSamplingRate(int outputSamplingRate) {
+      this.outputSamplingRate = outputSamplingRate;
+      return this;
+    }
+
+    /**
+     * 実行する。
+     *
+     * @return ステレオのWAVデータ。
+     * @throws InvalidQueryException いずれかのトラックの楽譜に対する{@link Score#validate}が失敗する場合、{@link
+     *     SongTrack#pan}が{@code -1}以上{@code 1}以下ではない場合、もしくは{@code
+     *     outputSamplingRate}が{@code 24000}の倍数ではない場合
+     * @throws StyleNotFoundException いずれかのトラックのスタイルが見つからなかった場合
+     * @throws RunModelException 推論に失敗した場合
+     */
+    @Nonnull
+    public byte[] perform() throws RunModelException {
+      String tracksJson = Convert.jsonFromQueryLike(this.tracks, "不正なトラックの列です");
+      return synthesizer.rsRenderSongProject(tracksJson, this.outputSamplingRate);
+    }
+  }
 }
@@ -0,0 +1,24 @@
+package jp.hiroshiba.voicevoxcore.blocking;
+
+import jakarta.annotation.Nonnull;
+import java.util.List;
+import jp.hiroshiba.voicevoxcore.AccentPhrase;
+
+/**
+ * テキスト解析器。
+ *
+ * <p>{@link Synthesizer} に {@link OpenJtalk} 以外のものを用いる場合に実装する。このメソッドは {@link Synthesizer}
+ * を操作しているスレッドから呼ばれる。
+ *
+ * @see Synthesizer#builder
+ */
+public interface TextAnalyzer {
+  /**
+   * 日本語のテキストを解析する。
+   *
+   * @param text 日本語のテキスト。
+   * @return {@link AccentPhrase} のリスト。
+   */
+  @Nonnull
+  List<AccentPhrase> analyze(String text);
+}
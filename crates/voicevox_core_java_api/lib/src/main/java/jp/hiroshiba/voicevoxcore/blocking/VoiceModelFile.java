@@ -47,7 +47,24 @@ public final class VoiceModelFile implements Closeable {
   public VoiceModelFile(String modelPath) {
     rsOpen(modelPath);
     id = rsGetId();
-    String metasJson = rsGetMetasJson();
+    metas = parseMetas(rsGetMetasJson());
+  }
+
+  /**
+   * メモリ上のVVMを開く。
+   *
+   * <p>{@code data}はコピーされる。
+   *
+   * @param data VVMファイルの中身。
+   */
+  public VoiceModelFile(@Nonnull byte[] data) {
+    rsFromBytes(data);
+    id = rsGetId();
+    metas = parseMetas(rsGetMetasJson());
+  }
+
+  @Nonnull
+  private static CharacterMeta[] parseMetas(String metasJson) {
     GsonBuilder gsonBuilder = new GsonBuilder();
     gsonBuilder.registerTypeAdapter(StyleType.class, new StyleType.Deserializer());
     Gson gson = gsonBuilder.create();
@@ -55,7 +72,7 @@ public final class VoiceModelFile implements Closeable {
     if (rawMetas == null) {
       throw new RuntimeException("Failed to parse metasJson");
     }
-    metas = rawMetas;
+    return rawMetas;
   }
 
   /**
@@ -76,6 +93,8 @@ public final class VoiceModelFile implements Closeable {
 
   private native void rsOpen(String modelPath);
 
+  private native void rsFromBytes(byte[] data);
+
   @Nonnull
   private native UUID rsGetId();
 
@@ -0,0 +1,14 @@
+package jp.hiroshiba.voicevoxcore.exceptions;
+
+import java.io.IOException;
+
+/** 音声モデルの検証に失敗した。 */
+public final class VerifyModelException extends IOException {
+  public VerifyModelException(String message) {
+    super(message);
+  }
+
+  public VerifyModelException(String message, Throwable cause) {
+    super(message, cause);
+  }
+}
@@ -1,6 +1,9 @@
 package jp.hiroshiba.voicevoxcore.internal;
 
 import com.google.gson.Gson;
+import java.util.List;
+import jp.hiroshiba.voicevoxcore.AccentPhrase;
+import jp.hiroshiba.voicevoxcore.blocking.TextAnalyzer;
 import jp.hiroshiba.voicevoxcore.exceptions.InvalidQueryException;
 
 public final class Convert {
@@ -14,4 +17,12 @@ public final class Convert {
       throw new InvalidQueryException(description, e);
     }
   }
+
+  public static String jsonFromTextAnalyzer(TextAnalyzer textAnalyzer, String text) {
+    List<AccentPhrase> accentPhrases = textAnalyzer.analyze(text);
+    if (accentPhrases == null) {
+      return null;
+    }
+    return new Gson().toJson(accentPhrases);
+  }
 }
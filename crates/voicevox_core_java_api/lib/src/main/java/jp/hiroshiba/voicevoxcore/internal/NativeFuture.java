@@ -0,0 +1,119 @@
+package jp.hiroshiba.voicevoxcore.internal;
+
+import java.util.concurrent.CompletableFuture;
+import java.util.concurrent.CountDownLatch;
+import java.util.concurrent.TimeUnit;
+import java.util.concurrent.TimeoutException;
+import java.util.function.Consumer;
+import java.util.function.Function;
+
+/**
+ * Rust側で実行される処理の結果を受け取る{@link CompletableFuture}。
+ *
+ * <p>Rust側の処理は{@code R}型の値を返し、それを{@code convert}で{@code T}型に変換したもので完了する。{@link #cancel}
+ * されるとRust側の処理は中断される。
+ */
+public final class NativeFuture<R, T> extends CompletableFuture<T> {
+  static {
+    Dll.loadLibrary();
+  }
+
+  /**
+   * Rust側の{@code AbortHandle}。
+   *
+   * <p>Rust側からの読み書きも含め、{@code this}のモニタを取った状態でのみアクセスする。Rust側の処理が終わったときに解放する。
+   */
+  private long handle;
+
+  private final Function<R, T> convert;
+  private final CountDownLatch nativeStopped = new CountDownLatch(1);
+  private volatile boolean nativeAborted = false;
+
+  private NativeFuture(Function<R, T> convert) {
+    this.convert = convert;
+  }
+
+  /**
+   * {@code start}でRust側の処理を開始し、その結果を待つ{@link NativeFuture}を返す。
+   *
+   * <p>{@code start}が例外を投げた場合、その例外で失敗した{@link NativeFuture}を返す。
+   */
+  public static <R, T> NativeFuture<R, T> spawn(
+      Function<R, T> convert, Consumer<NativeFuture<R, T>> start) {
+    NativeFuture<R, T> future = new NativeFuture<>(convert);
+    try {
+      start.accept(future);
+    } catch (Throwable e) {
+      future.completeExceptionally(e);
+      future.nativeStopped.countDown();
+    }
+    return future;
+  }
+
+  @Override
+  public boolean cancel(boolean mayInterruptIfRunning) {
+    boolean cancelled = super.cancel(mayInterruptIfRunning);
+    if (cancelled) {
+      synchronized (this) {
+        if (handle != 0) {
+          rsCancel();
+        }
+      }
+    }
+    return cancelled;
+  }
+
+  /**
+   * Rust側の処理が終わるまで待ち、それが{@link #cancel}によって中断されたかどうかを返す。
+   *
+   * @param timeout 待つ時間。
+   * @param unit {@code timeout}の単位。
+   * @return Rust側の処理が中断された場合は{@code true}、最後まで実行された場合は{@code false}。
+   * @throws TimeoutException {@code timeout}の間にRust側の処理が終わらなかった場合。
+   */
+  public boolean awaitNativeStop(long timeout, TimeUnit unit)
+      throws InterruptedException, TimeoutException {
+    if (!nativeStopped.await(timeout, unit)) {
+      throw new TimeoutException();
+    }
+    return nativeAborted;
+  }
+
+  private synchronized void releaseHandle() {
+    if (handle != 0) {
+      rsDrop();
+    }
+  }
+
+  @SuppressWarnings({"unchecked", "unused"})
+  private void onSuccess(Object raw) {
+    releaseHandle();
+    nativeStopped.countDown();
+    T output;
+    try {
+      output = convert.apply((R) raw);
+    } catch (Throwable e) {
+      completeExceptionally(e);
+      return;
+    }
+    complete(output);
+  }
+
+  @SuppressWarnings("unused")
+  private void onFailure(Throwable e) {
+    releaseHandle();
+    nativeStopped.countDown();
+    completeExceptionally(e);
+  }
+
+  @SuppressWarnings("unused")
+  private void onAbort() {
+    releaseHandle();
+    nativeAborted = true;
+    nativeStopped.countDown();
+  }
+
+  private native void rsCancel();
+
+  private native void rsDrop();
+}
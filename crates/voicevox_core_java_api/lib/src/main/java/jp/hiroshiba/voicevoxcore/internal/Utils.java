@@ -1,18 +1,20 @@
-package jp.hiroshiba.voicevoxcore.blocking;
+package jp.hiroshiba.voicevoxcore.internal;
 
 // TODO: `IllegalArgumentException`はRustコード内で`throw`する
 
-class Utils {
+public final class Utils {
+  private Utils() {}
+
   // FIXME: dead code
-  static boolean isU8(int value) {
+  public static boolean isU8(int value) {
     return value >= 0 && value <= 255;
   }
 
-  static boolean isU16(int value) {
+  public static boolean isU16(int value) {
     return value >= 0 && value <= 65535;
   }
 
-  static boolean isU32(long value) {
+  public static boolean isU32(long value) {
     return value >= 0 && value <= 4294967295L;
   }
 }
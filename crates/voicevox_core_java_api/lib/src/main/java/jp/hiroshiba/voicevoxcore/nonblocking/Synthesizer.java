@@ -0,0 +1,775 @@
+package jp.hiroshiba.voicevoxcore.nonblocking;
+
+import com.google.gson.Gson;
+import jakarta.annotation.Nonnull;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+import java.util.concurrent.CompletableFuture;
+import jp.hiroshiba.voicevoxcore.AccentPhrase;
+import jp.hiroshiba.voicevoxcore.AudioQuery;
+import jp.hiroshiba.voicevoxcore.FrameAudioQuery;
+import jp.hiroshiba.voicevoxcore.Score;
+import jp.hiroshiba.voicevoxcore.SongTrack;
+import jp.hiroshiba.voicevoxcore.exceptions.IncompatibleQueriesException;
+import jp.hiroshiba.voicevoxcore.exceptions.InvalidQueryException;
+import jp.hiroshiba.voicevoxcore.exceptions.RunModelException;
+import jp.hiroshiba.voicevoxcore.exceptions.StyleNotFoundException;
+import jp.hiroshiba.voicevoxcore.internal.Convert;
+import jp.hiroshiba.voicevoxcore.internal.Dll;
+import jp.hiroshiba.voicevoxcore.internal.NativeFuture;
+import jp.hiroshiba.voicevoxcore.internal.Utils;
+
+/**
+ * 音声シンセサイザの非同期版。
+ *
+ * <p>{@link jp.hiroshiba.voicevoxcore.blocking.Synthesizer}と音声モデルを共有し、各メソッドは結果を{@link
+ * CompletableFuture}で返す。処理はCPUのコア数と同じ数のバックグラウンドのスレッドで行われ、それを超える数の処理は先に開始されたものが終わるまで待たされる。
+ * Kotlinからはkotlinx-coroutines-jdk8の{@code await()}で待つことができる。
+ *
+ * <p>音声モデルの読み込みと解除は{@link #getBlocking}を通して行う。
+ *
+ * <p>返された{@link CompletableFuture}を{@link CompletableFuture#cancel}すると処理は中断される。ただし音声波形の生成など重い推論は、
+ * {@code cancellable(true)}を指定したときのみ中断される。指定しなかった場合、その推論は終わるまでバックグラウンドで続く。
+ *
+ * @see jp.hiroshiba.voicevoxcore.blocking.Synthesizer#toNonblocking
+ */
+public final class Synthesizer {
+  static {
+    Dll.loadLibrary();
+  }
+
+  private long handle;
+  @Nonnull private final jp.hiroshiba.voicevoxcore.blocking.Synthesizer blocking;
+
+  /**
+   * {@link jp.hiroshiba.voicevoxcore.blocking.Synthesizer}と音声モデルを共有する{@link Synthesizer}を作る。
+   *
+   * @param blocking ブロッキング版の音声シンセサイザ。
+   */
+  public Synthesizer(jp.hiroshiba.voicevoxcore.blocking.Synthesizer blocking) {
+    rsNew(blocking);
+    this.blocking = blocking;
+  }
+
+  protected void finalize() throws Throwable {
+    rsDrop();
+    super.finalize();
+  }
+
+  /**
+   * 音声モデルを共有しているブロッキング版の音声シンセサイザ。
+   *
+   * @return {@link jp.hiroshiba.voicevoxcore.blocking.Synthesizer}。
+   */
+  @Nonnull
+  public jp.hiroshiba.voicevoxcore.blocking.Synthesizer getBlocking() {
+    return blocking;
+  }
+
+  /**
+   * AquesTalk風記法から {@link AudioQuery} を生成する。
+   *
+   * @param kana AquesTalk風記法。
+   * @param styleId スタイルID。
+   * @return {@link AudioQuery}。推論に失敗した場合は{@link RunModelException}で失敗する。
+   */
+  @Nonnull
+  public CompletableFuture<AudioQuery> createAudioQueryFromKana(String kana, int styleId) {
+    if (!Utils.isU32(styleId)) {
+      throw new IllegalArgumentException("styleId");
+    }
+    return NativeFuture.spawn(
+        Synthesizer::audioQueryFromJson,
+        future -> rsCreateAudioQueryFromKana(future, kana, styleId));
+  }
+
+  /**
+   * 日本語のテキストから {@link AudioQuery} を生成する。
+   *
+   * @param text 日本語のテキスト。
+   * @param styleId スタイルID。
+   * @return {@link AudioQuery}。推論に失敗した場合は{@link RunModelException}で失敗する。
+   * @see jp.hiroshiba.voicevoxcore.blocking.Synthesizer#createAudioQuery
+   */
+  @Nonnull
+  public CompletableFuture<AudioQuery> createAudioQuery(String text, int styleId) {
+    if (!Utils.isU32(styleId)) {
+      throw new IllegalArgumentException("styleId");
+    }
+    return NativeFuture.spawn(
+        Synthesizer::audioQueryFromJson, future -> rsCreateAudioQuery(future, text, styleId));
+  }
+
+  /**
+   * AquesTalk風記法から {@link AccentPhrase} のリストを生成する。
+   *
+   * @param kana AquesTalk風記法。
+   * @param styleId スタイルID。
+   * @return {@link AccentPhrase} のリスト。推論に失敗した場合は{@link RunModelException}で失敗する。
+   */
+  @Nonnull
+  public CompletableFuture<List<AccentPhrase>> createAccentPhrasesFromKana(
+      String kana, int styleId) {
+    if (!Utils.isU32(styleId)) {
+      throw new IllegalArgumentException("styleId");
+    }
+    return NativeFuture.spawn(
+        Synthesizer::accentPhrasesFromJson,
+        future -> rsAccentPhrasesFromKana(future, kana, styleId));
+  }
+
+  /**
+   * 日本語のテキストから {@link AccentPhrase} のリストを生成する。
+   *
+   * @param text 日本語のテキスト。
+   * @param styleId スタイルID。
+   * @return {@link AccentPhrase} のリスト。推論に失敗した場合は{@link RunModelException}で失敗する。
+   * @see jp.hiroshiba.voicevoxcore.blocking.Synthesizer#createAccentPhrases
+   */
+  @Nonnull
+  public CompletableFuture<List<AccentPhrase>> createAccentPhrases(String text, int styleId) {
+    if (!Utils.isU32(styleId)) {
+      throw new IllegalArgumentException("styleId");
+    }
+    return NativeFuture.spawn(
+        Synthesizer::accentPhrasesFromJson, future -> rsAccentPhrases(future, text, styleId));
+  }
+
+  /**
+   * アクセント句の音高・音素長を変更する。
+   *
+   * @param accentPhrases 変更元のアクセント句の配列。
+   * @param styleId スタイルID。
+   * @return 変更後のアクセント句の配列。推論に失敗した場合は{@link RunModelException}で失敗する。
+   * @see jp.hiroshiba.voicevoxcore.blocking.Synthesizer#replaceMoraData
+   */
+  @Nonnull
+  public CompletableFuture<List<AccentPhrase>> replaceMoraData(
+      List<AccentPhrase> accentPhrases, int styleId) {
+    if (!Utils.isU32(styleId)) {
+      throw new IllegalArgumentException("styleId");
+    }
+    String accentPhrasesJson = Convert.jsonFromQueryLike(accentPhrases, "不正なアクセント句の列です");
+    return NativeFuture.spawn(
+        Synthesizer::accentPhrasesFromJson,
+        future -> rsReplaceMoraData(future, accentPhrasesJson, styleId));
+  }
+
+  /**
+   * アクセント句の音素長を変更する。
+   *
+   * @param accentPhrases 変更元のアクセント句の配列。
+   * @param styleId スタイルID。
+   * @return 変更後のアクセント句の配列。推論に失敗した場合は{@link RunModelException}で失敗する。
+   */
+  @Nonnull
+  public CompletableFuture<List<AccentPhrase>> replacePhonemeLength(
+      List<AccentPhrase> accentPhrases, int styleId) {
+    if (!Utils.isU32(styleId)) {
+      throw new IllegalArgumentException("styleId");
+    }
+    String accentPhrasesJson = Convert.jsonFromQueryLike(accentPhrases, "不正なアクセント句の列です");
+    return NativeFuture.spawn(
+        Synthesizer::accentPhrasesFromJson,
+        future -> rsReplacePhonemeLength(future, accentPhrasesJson, styleId));
+  }
+
+  /**
+   * アクセント句の音高を変更する。
+   *
+   * @param accentPhrases 変更元のアクセント句の配列。
+   * @param styleId スタイルID。
+   * @return 変更後のアクセント句の配列。推論に失敗した場合は{@link RunModelException}で失敗する。
+   */
+  @Nonnull
+  public CompletableFuture<List<AccentPhrase>> replaceMoraPitch(
+      List<AccentPhrase> accentPhrases, int styleId) {
+    if (!Utils.isU32(styleId)) {
+      throw new IllegalArgumentException("styleId");
+    }
+    String accentPhrasesJson = Convert.jsonFromQueryLike(accentPhrases, "不正なアクセント句の列です");
+    return NativeFuture.spawn(
+        Synthesizer::accentPhrasesFromJson,
+        future -> rsReplaceMoraPitch(future, accentPhrasesJson, styleId));
+  }
+
+  /**
+   * {@link AudioQuery} から音声合成するためのオブジェクトを生成する。
+   *
+   * @param audioQuery {@link AudioQuery}。
+   * @param styleId スタイルID。
+   * @return {@link SynthesisConfigurator}。
+   * @see SynthesisConfigurator#perform
+   */
+  @Nonnull
+  public SynthesisConfigurator synthesis(AudioQuery audioQuery, int styleId) {
+    return new SynthesisConfigurator(this, audioQuery, styleId);
+  }
+
+  /**
+   * AquesTalk風記法をもとに音声合成を実行するためのオブジェクトを生成する。
+   *
+   * @param kana AquesTalk風記法。
+   * @param styleId スタイルID。
+   * @return {@link TtsFromKanaConfigurator}。
+   * @see TtsFromKanaConfigurator#perform
+   */
+  @Nonnull
+  public TtsFromKanaConfigurator ttsFromKana(String kana, int styleId) {
+    return new TtsFromKanaConfigurator(this, kana, styleId);
+  }
+
+  /**
+   * 日本語のテキストをもとに音声合成を実行するためのオブジェクトを生成する。
+   *
+   * @param text 日本語のテキスト。
+   * @param styleId スタイルID。
+   * @return {@link TtsConfigurator}。
+   * @see TtsConfigurator#perform
+   */
+  @Nonnull
+  public TtsConfigurator tts(String text, int styleId) {
+    return new TtsConfigurator(this, text, styleId);
+  }
+
+  /**
+   * 楽譜から歌唱音声合成用のクエリを作成する。
+   *
+   * @param score 楽譜
+   * @param styleId スタイルID
+   * @return 歌唱音声合成用のクエリ。{@code styleId}に対応するスタイルが見つからなかった場合は{@link
+   *     StyleNotFoundException}で、推論に失敗した場合は{@link RunModelException}で失敗する
+   * @throws InvalidQueryException {@code score}に対する{@link Score#validate}が失敗する場合
+   * @see jp.hiroshiba.voicevoxcore.blocking.Synthesizer#createSingFrameAudioQuery
+   */
+  @Nonnull
+  public CompletableFuture<FrameAudioQuery> createSingFrameAudioQuery(Score score, int styleId) {
+    if (!Utils.isU32(styleId)) {
+      throw new IllegalArgumentException("styleId");
+    }
+    String scoreJson = Convert.jsonFromQueryLike(score, "不正な楽譜です");
+    return NativeFuture.spawn(
+        (String json) -> {
+          FrameAudioQuery frameAudioQuery = new Gson().fromJson(json, FrameAudioQuery.class);
+          if (frameAudioQuery == null) {
+            throw new NullPointerException("frame_audio_query");
+          }
+          return frameAudioQuery;
+        },
+        future -> rsCreateSingFrameAudioQuery(future, scoreJson, styleId));
+  }
+
+  /**
+   * 楽譜と歌唱音声合成用のクエリから、フレームごとの基本周波数を生成する。
+   *
+   * @param score 楽譜
+   * @param frameAudioQuery 歌唱音声合成用のクエリ
+   * @param styleId スタイルID
+   * @return フレームごとの基本周波数。{@code score}と{@code frameAudioQuery}の組み合わせが不正である場合は{@link
+   *     IncompatibleQueriesException}で失敗する
+   * @throws InvalidQueryException {@code score}に対する{@link Score#validate}、もしくは{@code
+   *     frameAudioQuery}に対する{@link FrameAudioQuery#validate}が失敗する場合
+   * @see jp.hiroshiba.voicevoxcore.blocking.Synthesizer#createSingFrameF0
+   */
+  @Nonnull
+  public CompletableFuture<float[]> createSingFrameF0(
+      Score score, FrameAudioQuery frameAudioQuery, int styleId) {
+    if (!Utils.isU32(styleId)) {
+      throw new IllegalArgumentException("styleId");
+    }
+    String scoreJson = Convert.jsonFromQueryLike(score, "不正な楽譜です");
+    String frameAudioQueryJson = Convert.jsonFromQueryLike(frameAudioQuery, "不正なFrameAudioQueryです");
+    return NativeFuture.spawn(
+        (float[] f0s) -> f0s,
+        future -> rsCreateSingFrameF0(future, scoreJson, frameAudioQueryJson, styleId));
+  }
+
+  /**
+   * 楽譜と歌唱音声合成用のクエリから、フレームごとの音量を生成する。
+   *
+   * @param score 楽譜
+   * @param frameAudioQuery 歌唱音声合成用のクエリ
+   * @param styleId スタイルID
+   * @return フレームごとの音量。{@code score}と{@code frameAudioQuery}の組み合わせが不正である場合は{@link
+   *     IncompatibleQueriesException}で失敗する
+   * @throws InvalidQueryException {@code score}に対する{@link Score#validate}、もしくは{@code
+   *     frameAudioQuery}に対する{@link FrameAudioQuery#validate}が失敗する場合
+   * @see jp.hiroshiba.voicevoxcore.blocking.Synthesizer#createSingFrameVolume
+   */
+  @Nonnull
+  public CompletableFuture<float[]> createSingFrameVolume(
+      Score score, FrameAudioQuery frameAudioQuery, int styleId) {
+    if (!Utils.isU32(styleId)) {
+      throw new IllegalArgumentException("styleId");
+    }
+    String scoreJson = Convert.jsonFromQueryLike(score, "不正な楽譜です");
+    String frameAudioQueryJson = Convert.jsonFromQueryLike(frameAudioQuery, "不正なFrameAudioQueryです");
+    return NativeFuture.spawn(
+        (float[] volumes) -> volumes,
+        future -> rsCreateSingFrameVolume(future, scoreJson, frameAudioQueryJson, styleId));
+  }
+
+  /**
+   * 歌唱音声合成を実行するためのオブジェクトを生成する。
+   *
+   * @param frameAudioQuery 歌唱音声合成用のクエリ
+   * @param styleId スタイルID
+   * @return {@link FrameSynthesisConfigurator}。
+   * @see FrameSynthesisConfigurator#perform
+   */
+  @Nonnull
+  public FrameSynthesisConfigurator frameSynthesis(FrameAudioQuery frameAudioQuery, int styleId) {
+    return new FrameSynthesisConfigurator(this, frameAudioQuery, styleId);
+  }
+
+  /**
+   * 歌唱音声合成用のクエリのうち、指定したフレーム区間のみを歌唱音声合成するためのオブジェクトを生成する。
+   *
+   * @param frameAudioQuery 歌唱音声合成用のクエリ
+   * @param styleId スタイルID
+   * @param start 区間の開始フレーム（含む）
+   * @param end 区間の終了フレーム（含まない）
+   * @return {@link FrameRenderConfigurator}。
+   * @see FrameRenderConfigurator#perform
+   * @see jp.hiroshiba.voicevoxcore.blocking.Synthesizer#frameRender
+   */
+  @Nonnull
+  public FrameRenderConfigurator frameRender(
+      FrameAudioQuery frameAudioQuery, int styleId, int start, int end) {
+    return new FrameRenderConfigurator(this, frameAudioQuery, styleId, start, end);
+  }
+
+  /**
+   * 複数のトラックを歌唱音声合成し、ステレオのWAVデータとして重ね合わせるためのオブジェクトを生成する。
+   *
+   * @param tracks トラックのリスト
+   * @return {@link RenderSongProjectConfigurator}。
+   * @see RenderSongProjectConfigurator#perform
+   * @see jp.hiroshiba.voicevoxcore.blocking.Synthesizer#renderSongProject
+   */
+  @Nonnull
+  public RenderSongProjectConfigurator renderSongProject(List<SongTrack> tracks) {
+    return new RenderSongProjectConfigurator(this, tracks);
+  }
+
+  private static AudioQuery audioQueryFromJson(String json) {
+    AudioQuery audioQuery = new Gson().fromJson(json, AudioQuery.class);
+    if (audioQuery == null) {
+      throw new NullPointerException("audio_query");
+    }
+    return audioQuery;
+  }
+
+  private static List<AccentPhrase> accentPhrasesFromJson(String json) {
+    AccentPhrase[] rawAccentPhrases = new Gson().fromJson(json, AccentPhrase[].class);
+    if (rawAccentPhrases == null) {
+      throw new NullPointerException("accent_phrases");
+    }
+    return new ArrayList<AccentPhrase>(Arrays.asList(rawAccentPhrases));
+  }
+
+  private native void rsNew(jp.hiroshiba.voicevoxcore.blocking.Synthesizer blocking);
+
+  private native void rsCreateAudioQueryFromKana(
+      NativeFuture<String, AudioQuery> future, String kana, int styleId);
+
+  private native void rsCreateAudioQuery(
+      NativeFuture<String, AudioQuery> future, String text, int styleId);
+
+  private native void rsAccentPhrasesFromKana(
+      NativeFuture<String, List<AccentPhrase>> future, String kana, int styleId);
+
+  private native void rsAccentPhrases(
+      NativeFuture<String, List<AccentPhrase>> future, String text, int styleId);
+
+  private native void rsReplaceMoraData(
+      NativeFuture<String, List<AccentPhrase>> future, String accentPhrasesJson, int styleId);
+
+  private native void rsReplacePhonemeLength(
+      NativeFuture<String, List<AccentPhrase>> future, String accentPhrasesJson, int styleId);
+
+  private native void rsReplaceMoraPitch(
+      NativeFuture<String, List<AccentPhrase>> future, String accentPhrasesJson, int styleId);
+
+  private native void rsSynthesis(
+      NativeFuture<byte[], byte[]> future,
+      String queryJson,
+      int styleId,
+      boolean enableInterrogativeUpspeak,
+      boolean cancellable);
+
+  private native void rsTtsFromKana(
+      NativeFuture<byte[], byte[]> future,
+      String kana,
+      int styleId,
+      boolean enableInterrogativeUpspeak,
+      boolean cancellable);
+
+  private native void rsTts(
+      NativeFuture<byte[], byte[]> future,
+      String text,
+      int styleId,
+      boolean enableInterrogativeUpspeak,
+      boolean cancellable);
+
+  private native void rsCreateSingFrameAudioQuery(
+      NativeFuture<String, FrameAudioQuery> future, String score, int styleId);
+
+  private native void rsCreateSingFrameF0(
+      NativeFuture<float[], float[]> future, String score, String frameAudioQuery, int styleId);
+
+  private native void rsCreateSingFrameVolume(
+      NativeFuture<float[], float[]> future, String score, String frameAudioQuery, int styleId);
+
+  private native void rsFrameSynthesis(
+      NativeFuture<byte[], byte[]> future,
+      String frameAudioQuery,
+      int styleId,
+      boolean cancellable);
+
+  private native void rsFrameRender(
+      NativeFuture<byte[], byte[]> future,
+      String frameAudioQuery,
+      int styleId,
+      int start,
+      int end,
+      boolean cancellable);
+
+  private native void rsRenderSongProject(
+      NativeFuture<byte[], byte[]> future,
+      String tracks,
+      int outputSamplingRate,
+      boolean cancellable);
+
+  private native void rsDrop();
+
+  /** {@link Synthesizer#synthesis} のオプション。 */
+  public final class SynthesisConfigurator {
+    private Synthesizer synthesizer;
+    private AudioQuery audioQuery;
+    private int styleId;
+    private boolean interrogativeUpspeak;
+    private boolean cancellable;
+
+    private SynthesisConfigurator(Synthesizer synthesizer, AudioQuery audioQuery, int styleId) {
+      if (!Utils.isU32(styleId)) {
+        throw new IllegalArgumentException("styleId");
+      }
+      this.synthesizer = synthesizer;
+      this.audioQuery = audioQuery;
+      this.styleId = styleId;
+    }
+
+    /**
+     * 疑問文の調整を有効にするかどうか。
+     *
+     * @param interrogativeUpspeak 疑問文の調整を有効にするかどうか。
+     * @return {@link SynthesisConfigurator}。
+     */
+    @Nonnull
+    public SynthesisConfigurator interrogativeUpspeak(boolean interrogativeUpspeak) {
+      this.interrogativeUpspeak = interrogativeUpspeak;
+      return this;
+    }
+
+    /**
+     * {@link CompletableFuture#cancel}で音声波形の生成を中断できるようにするかどうか。
+     *
+     * @param cancellable 中断できるようにするかどうか。
+     * @return {@link SynthesisConfigurator}。
+     */
+    @Nonnull
+    public SynthesisConfigurator cancellable(boolean cancellable) {
+      this.cancellable = cancellable;
+      return this;
+    }
+
+    /**
+     * {@link AudioQuery} から音声合成する。
+     *
+     * @return 音声データ。推論に失敗した場合は{@link RunModelException}で失敗する。
+     */
+    @Nonnull
+    public CompletableFuture<byte[]> perform() {
+      String queryJson = Convert.jsonFromQueryLike(this.audioQuery, "不正なAudioQueryです");
+      return NativeFuture.spawn(
+          (byte[] wav) -> wav,
+          future ->
+              synthesizer.rsSynthesis(
+                  future, queryJson, this.styleId, this.interrogativeUpspeak, this.cancellable));
+    }
+  }
+
+  /** {@link Synthesizer#ttsFromKana} のオプション。 */
+  public final class TtsFromKanaConfigurator {
+    private Synthesizer synthesizer;
+    private String kana;
+    private int styleId;
+    private boolean interrogativeUpspeak;
+    private boolean cancellable;
+
+    private TtsFromKanaConfigurator(Synthesizer synthesizer, String kana, int styleId) {
+      if (!Utils.isU32(styleId)) {
+        throw new IllegalArgumentException("styleId");
+      }
+      this.synthesizer = synthesizer;
+      this.kana = kana;
+      this.styleId = styleId;
+    }
+
+    /**
+     * 疑問文の調整を有効にするかどうか。
+     *
+     * @param interrogativeUpspeak 疑問文の調整を有効にするかどうか。
+     * @return {@link TtsFromKanaConfigurator}。
+     */
+    @Nonnull
+    public TtsFromKanaConfigurator interrogativeUpspeak(boolean interrogativeUpspeak) {
+      this.interrogativeUpspeak = interrogativeUpspeak;
+      return this;
+    }
+
+    /**
+     * {@link CompletableFuture#cancel}で音声波形の生成を中断できるようにするかどうか。
+     *
+     * @param cancellable 中断できるようにするかどうか。
+     * @return {@link TtsFromKanaConfigurator}。
+     */
+    @Nonnull
+    public TtsFromKanaConfigurator cancellable(boolean cancellable) {
+      this.cancellable = cancellable;
+      return this;
+    }
+
+    /**
+     * AquesTalk風記法をもとに音声合成する。
+     *
+     * @return 音声データ。推論に失敗した場合は{@link RunModelException}で失敗する。
+     */
+    @Nonnull
+    public CompletableFuture<byte[]> perform() {
+      return NativeFuture.spawn(
+          (byte[] wav) -> wav,
+          future ->
+              synthesizer.rsTtsFromKana(
+                  future, this.kana, this.styleId, this.interrogativeUpspeak, this.cancellable));
+    }
+  }
+
+  /** {@link Synthesizer#tts} のオプション。 */
+  public final class TtsConfigurator {
+    private Synthesizer synthesizer;
+    private String text;
+    private int styleId;
+    private boolean interrogativeUpspeak;
+    private boolean cancellable;
+
+    private TtsConfigurator(Synthesizer synthesizer, String text, int styleId) {
+      if (!Utils.isU32(styleId)) {
+        throw new IllegalArgumentException("styleId");
+      }
+      this.synthesizer = synthesizer;
+      this.text = text;
+      this.styleId = styleId;
+    }
+
+    /**
+     * 疑問文の調整を有効にするかどうか。
+     *
+     * @param interrogativeUpspeak 疑問文の調整を有効にするかどうか。
+     * @return {@link TtsConfigurator}。
+     */
+    @Nonnull
+    public TtsConfigurator interrogativeUpspeak(boolean interrogativeUpspeak) {
+      this.interrogativeUpspeak = interrogativeUpspeak;
+      return this;
+    }
+
+    /**
+     * {@link CompletableFuture#cancel}で音声波形の生成を中断できるようにするかどうか。
+     *
+     * @param cancellable 中断できるようにするかどうか。
+     * @return {@link TtsConfigurator}。
+     */
+    @Nonnull
+    public TtsConfigurator cancellable(boolean cancellable) {
+      this.cancellable = cancellable;
+      return this;
+    }
+
+    /**
+     * 日本語のテキストをもとに音声合成する。
+     *
+     * @return 音声データ。推論に失敗した場合は{@link RunModelException}で失敗する。
+     */
+    @Nonnull
+    public CompletableFuture<byte[]> perform() {
+      return NativeFuture.spawn(
+          (byte[] wav) -> wav,
+          future ->
+              synthesizer.rsTts(
+                  future, this.text, this.styleId, this.interrogativeUpspeak, this.cancellable));
+    }
+  }
+
+  /** {@link Synthesizer#frameSynthesis} のオプション。 */
+  public final class FrameSynthesisConfigurator {
+    private Synthesizer synthesizer;
+    private FrameAudioQuery frameAudioQuery;
+    private int styleId;
+    private boolean cancellable;
+
+    private FrameSynthesisConfigurator(
+        Synthesizer synthesizer, FrameAudioQuery frameAudioQuery, int styleId) {
+      if (!Utils.isU32(styleId)) {
+        throw new IllegalArgumentException("styleId");
+      }
+      this.synthesizer = synthesizer;
+      this.frameAudioQuery = frameAudioQuery;
+      this.styleId = styleId;
+    }
+
+    /**
+     * {@link CompletableFuture#cancel}で音声波形の生成を中断できるようにするかどうか。
+     *
+     * @param cancellable 中断できるようにするかどうか。
+     * @return {@link FrameSynthesisConfigurator}。
+     */
+    @Nonnull
+    public FrameSynthesisConfigurator cancellable(boolean cancellable) {
+      this.cancellable = cancellable;
+      return this;
+    }
+
+    /**
+     * 歌唱音声合成を行う。
+     *
+     * @return WAVデータ。{@code styleId}に対応するスタイルが見つからなかった場合は{@link
+     *     StyleNotFoundException}で、推論に失敗した場合は{@link RunModelException}で失敗する。
+     * @throws InvalidQueryException {@code frameAudioQuery}に対する{@link
+     *     FrameAudioQuery#validate}が失敗する場合
+     */
+    @Nonnull
+    public CompletableFuture<byte[]> perform() {
+      String frameAudioQueryJson =
+          Convert.jsonFromQueryLike(this.frameAudioQuery, "不正なFrameAudioQueryです");
+      return NativeFuture.spawn(
+          (byte[] wav) -> wav,
+          future ->
+              synthesizer.rsFrameSynthesis(
+                  future, frameAudioQueryJson, this.styleId, this.cancellable));
+    }
+  }
+
+  /** {@link Synthesizer#frameRender} のオプション。 */
+  public final class FrameRenderConfigurator {
+    private Synthesizer synthesizer;
+    private FrameAudioQuery frameAudioQuery;
+    private int styleId;
+    private int start;
+    private int end;
+    private boolean cancellable;
+
+    private FrameRenderConfigurator(
+        Synthesizer synthesizer, FrameAudioQuery frameAudioQuery, int styleId, int start, int end) {
+      if (!Utils.isU32(styleId)) {
+        throw new IllegalArgumentException("styleId");
+      }
+      this.synthesizer = synthesizer;
+      this.frameAudioQuery = frameAudioQuery;
+      this.styleId = styleId;
+      this.start = start;
+      this.end = end;
+    }
+
+    /**
+     * {@link CompletableFuture#cancel}で音声波形の生成を中断できるようにするかどうか。
+     *
+     * @param cancellable 中断できるようにするかどうか。
+     * @return {@link FrameRenderConfigurator}。
+     */
+    @Nonnull
+    public FrameRenderConfigurator cancellable(boolean cancellable) {
+      this.cancellable = cancellable;
+      return this;
+    }
+
+    /**
+     * 指定したフレーム区間のみを歌唱音声合成する。
+     *
+     * @return 16bit PCM。区間がクエリのフレーム数を超える場合は{@link InvalidQueryException}で、推論に失敗した場合は{@link
+     *     RunModelException}で失敗する。
+     * @throws InvalidQueryException {@code frameAudioQuery}に対する{@link
+     *     FrameAudioQuery#validate}が失敗する場合
+     */
+    @Nonnull
+    public CompletableFuture<byte[]> perform() {
+      String frameAudioQueryJson =
+          Convert.jsonFromQueryLike(this.frameAudioQuery, "不正なFrameAudioQueryです");
+      return NativeFuture.spawn(
+          (byte[] pcm) -> pcm,
+          future ->
+              synthesizer.rsFrameRender(
+                  future,
+                  frameAudioQueryJson,
+                  this.styleId,
+                  this.start,
+                  this.end,
+                  this.cancellable));
+    }
+  }
+
+  /** {@link Synthesizer#renderSongProject} のオプション。 */
+  public final class RenderSongProjectConfigurator {
+    private Synthesizer synthesizer;
+    private List<SongTrack> tracks;
+    private int outputSamplingRate;
+    private boolean cancellable;
+
+    private RenderSongProjectConfigurator(Synthesizer synthesizer, List<SongTrack> tracks) {
+      this.synthesizer = synthesizer;
+      this.tracks = tracks;
+      this.outputSamplingRate = 24000;
+    }
+
+    /**
+     * 出力のサンプリングレート。
+     *
+     * @param outputSamplingRate 出力のサンプリングレート。{@code 24000}の倍数。
+     * @return {@link RenderSongProjectConfigurator}。
+     */
+    @Nonnull
+    public RenderSongProjectConfigurator outputSamplingRate(int outputSamplingRate) {
+      this.outputSamplingRate = outputSamplingRate;
+      return this;
+    }
+
+    /**
+     * {@link CompletableFuture#cancel}で音声波形の生成を中断できるようにするかどうか。
+     *
+     * @param cancellable 中断できるようにするかどうか。
+     * @return {@link RenderSongProjectConfigurator}。
+     */
+    @Nonnull
+    public RenderSongProjectConfigurator cancellable(boolean cancellable) {
+      this.cancellable = cancellable;
+      return this;
+    }
+
+    /**
+     * 実行する。
+     *
+     * @return ステレオのWAVデータ。推論に失敗した場合は{@link RunModelException}で失敗する。
+     */
+    @Nonnull
+    public CompletableFuture<byte[]> perform() {
+      String tracksJson = Convert.jsonFromQueryLike(this.tracks, "不正なトラックの列です");
+      return NativeFuture.spawn(
+          (byte[] wav) -> wav,
+          future ->
+              synthesizer.rsRenderSongProject(
+                  future, tracksJson, this.outputSamplingRate, this.cancellable));
+    }
+  }
+}
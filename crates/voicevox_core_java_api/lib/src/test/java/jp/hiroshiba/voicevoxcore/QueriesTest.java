@@ -1,6 +1,8 @@
 package jp.hiroshiba.voicevoxcore;
 
+import static org.junit.jupiter.api.Assertions.assertEquals;
 import static org.junit.jupiter.api.Assertions.assertThrowsExactly;
+import static org.junit.jupiter.api.Assertions.assertTrue;
 
 import java.util.Arrays;
 import jp.hiroshiba.voicevoxcore.exceptions.IncompatibleQueriesException;
@@ -47,4 +49,13 @@ class QueriesTest extends TestUtils {
                     24000,
                     true)));
   }
+
+  @Test
+  void checkVolumeScaleOf() {
+    assertEquals(1f, Note.volumeScaleOf("mf"));
+    assertEquals(1f, Note.volumeScaleOf(null));
+    assertTrue(Note.volumeScaleOf("pp") < Note.volumeScaleOf("p"));
+    assertTrue(Note.volumeScaleOf("f") < Note.volumeScaleOf("ff"));
+    assertThrowsExactly(IllegalArgumentException.class, () -> Note.volumeScaleOf("fff"));
+  }
 }
@@ -0,0 +1,191 @@
+package jp.hiroshiba.voicevoxcore;
+
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+import java.io.IOException;
+import java.lang.reflect.Constructor;
+import java.lang.reflect.Method;
+import java.lang.reflect.Modifier;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Files;
+import java.nio.file.Paths;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.HashMap;
+import java.util.HashSet;
+import java.util.List;
+import java.util.Map;
+import java.util.Set;
+import java.util.stream.Collectors;
+import org.junit.jupiter.api.Test;
+
+/**
+ * voicevox_core/song-public-apis.txtに列挙されたRustの公開APIが、同じ引数を取る形でJava APIにもあることを確かめる。
+ *
+ * <p>Rustの引数の型は{@link #JAVA_TYPES}でJavaの型に対応付ける。対応付けの無い型が現れた場合、テストは失敗する。
+ *
+ * <p>Rustの型そのものは、同名のJavaのクラスがあるか、{@link #JAVA_TYPES}で対応付けられていればよい。
+ */
+class SongApiParityTest {
+  /** Rustの引数の型と、それに対応するJavaの引数の型の並び。 */
+  private static final Map<String, List<Class<?>>> JAVA_TYPES = new HashMap<>();
+
+  /** Javaでの名前がRustから機械的に決まらないもの。Rustのパスと、Javaのクラスとメソッド名。 */
+  private static final Map<String, String[]> RENAMED = new HashMap<>();
+
+  /** Javaでは標準の型で表すため、メソッドに対応するものを持たないRustの型。 */
+  private static final Set<String> WITHOUT_METHODS = new HashSet<>();
+
+  static {
+    JAVA_TYPES.put("Score", Arrays.asList(Score.class));
+    JAVA_TYPES.put("FrameAudioQuery", Arrays.asList(FrameAudioQuery.class));
+    JAVA_TYPES.put("StyleId", Arrays.asList(int.class));
+    JAVA_TYPES.put("i8", Arrays.asList(int.class));
+    JAVA_TYPES.put("StrictlyPositiveFinite<f32>", Arrays.asList(float.class));
+    JAVA_TYPES.put("Range<usize>", Arrays.asList(int.class, int.class));
+    JAVA_TYPES.put("[SongTrack]", Arrays.asList(List.class));
+    JAVA_TYPES.put("Dynamics", Arrays.asList(String.class));
+    JAVA_TYPES.put("Key", Arrays.asList(Byte.class));
+    JAVA_TYPES.put("NoteId", Arrays.asList(String.class));
+    JAVA_TYPES.put("OptionalLyric", Arrays.asList(String.class));
+
+    // Javaでは強弱記号を文字列で表すため、静的メソッドとして`Note`に置いている
+    RENAMED.put("Dynamics::volume_scale", new String[] {"Note", "volumeScaleOf"});
+
+    // 音高の加減算などは、Javaでは`Byte`に対して直接行う
+    WITHOUT_METHODS.add("Key");
+  }
+
+  @Test
+  void everyRustSongApiHasJavaCounterpart() throws IOException, ClassNotFoundException {
+    // cwdはvoicevox_core/crates/voicevox_core_java_api/lib
+    List<String> rustApis =
+        Files.readAllLines(
+                Paths.get("../../voicevox_core/song-public-apis.txt"), StandardCharsets.UTF_8)
+            .stream()
+            .map(String::trim)
+            .filter(line -> !line.isEmpty() && !line.startsWith("#"))
+            .collect(Collectors.toList());
+
+    List<String> missing = new ArrayList<>();
+    for (String rustApi : rustApis) {
+      if (!hasJavaCounterpart(rustApi)) {
+        missing.add(rustApi);
+      }
+    }
+    assertTrue(missing.isEmpty(), () -> "Java APIに存在しないもの: " + missing);
+  }
+
+  private static boolean hasJavaCounterpart(String rustApi) throws ClassNotFoundException {
+    int paren = rustApi.indexOf('(');
+    if (paren == -1) {
+      return JAVA_TYPES.containsKey(rustApi)
+          || classExists("jp.hiroshiba.voicevoxcore." + rustApi);
+    }
+    if (WITHOUT_METHODS.contains(rustApi.substring(0, paren).split("::")[0])) {
+      return true;
+    }
+    String path = rustApi.substring(0, paren);
+    List<String> rustParams = splitParams(rustApi.substring(paren + 1, rustApi.length() - 1));
+
+    List<Class<?>> javaParams = new ArrayList<>();
+    for (String rustParam : rustParams) {
+      List<Class<?>> javaParam = JAVA_TYPES.get(rustParam);
+      if (javaParam == null) {
+        throw new AssertionError("Javaの型への対応付けが無い: " + rustParam + " (" + rustApi + ")");
+      }
+      javaParams.addAll(javaParam);
+    }
+
+    if (RENAMED.containsKey(path)) {
+      String[] renamed = RENAMED.get(path);
+      Class<?> cls = Class.forName("jp.hiroshiba.voicevoxcore." + renamed[0]);
+      // レシーバーを第一引数として取る静的メソッド
+      String receiver = path.split("::")[0];
+      List<Class<?>> params = new ArrayList<>(JAVA_TYPES.get(receiver));
+      params.addAll(javaParams);
+      return hasPublicMethod(cls, renamed[1], params, true);
+    }
+
+    List<String> segments = Arrays.asList(path.split("::"));
+    String member = segments.get(segments.size() - 1);
+
+    if (segments.size() == 1) {
+      return hasPublicMethod(Queries.class, camelCase(member), javaParams, true);
+    }
+
+    String className =
+        segments.size() == 3
+            ? "jp.hiroshiba.voicevoxcore." + segments.get(0) + "." + segments.get(1)
+            : "jp.hiroshiba.voicevoxcore." + segments.get(0);
+    Class<?> cls = Class.forName(className);
+    if (member.equals("new")) {
+      for (Constructor<?> constructor : cls.getConstructors()) {
+        if (Arrays.asList(constructor.getParameterTypes()).equals(javaParams)) {
+          return true;
+        }
+      }
+      return false;
+    }
+    return hasPublicMethod(cls, camelCase(member), javaParams, false);
+  }
+
+  private static boolean classExists(String name) {
+    try {
+      Class.forName(name);
+      return true;
+    } catch (ClassNotFoundException e) {
+      return false;
+    }
+  }
+
+  private static boolean hasPublicMethod(
+      Class<?> cls, String name, List<Class<?>> params, boolean isStatic) {
+    for (Method method : cls.getMethods()) {
+      if (method.getName().equals(name)
+          && Modifier.isPublic(method.getModifiers())
+          && Modifier.isStatic(method.getModifiers()) == isStatic
+          && Arrays.asList(method.getParameterTypes()).equals(params)) {
+        return true;
+      }
+    }
+    return false;
+  }
+
+  /** 括弧の中のカンマでは区切らずに、引数の型を分ける。 */
+  private static List<String> splitParams(String params) {
+    List<String> ret = new ArrayList<>();
+    if (params.isEmpty()) {
+      return ret;
+    }
+    int depth = 0;
+    int start = 0;
+    for (int i = 0; i < params.length(); i++) {
+      char c = params.charAt(i);
+      if (c == '<' || c == '[' || c == '(') {
+        depth++;
+      } else if (c == '>' || c == ']' || c == ')') {
+        depth--;
+      } else if (c == ',' && depth == 0) {
+        ret.add(params.substring(start, i).trim());
+        start = i + 1;
+      }
+    }
+    ret.add(params.substring(start).trim());
+    return ret;
+  }
+
+  private static String camelCase(String snakeCase) {
+    StringBuilder ret = new StringBuilder();
+    boolean upper = false;
+    for (char c : snakeCase.toCharArray()) {
+      if (c == '_') {
+        upper = true;
+      } else {
+        ret.append(upper ? Character.toUpperCase(c) : c);
+        upper = false;
+      }
+    }
+    return ret.toString();
+  }
+}
@@ -16,11 +16,16 @@ import jp.hiroshiba.voicevoxcore.AccelerationMode;
 import jp.hiroshiba.voicevoxcore.AccentPhrase;
 import jp.hiroshiba.voicevoxcore.AudioQuery;
 import jp.hiroshiba.voicevoxcore.FrameAudioQuery;
+import jp.hiroshiba.voicevoxcore.InferenceOperationKind;
 import jp.hiroshiba.voicevoxcore.Mora;
 import jp.hiroshiba.voicevoxcore.Note;
+import jp.hiroshiba.voicevoxcore.OperationDevice;
+import jp.hiroshiba.voicevoxcore.OperationPlacement;
 import jp.hiroshiba.voicevoxcore.Score;
+import jp.hiroshiba.voicevoxcore.SongTrack;
 import jp.hiroshiba.voicevoxcore.TestUtils;
 import jp.hiroshiba.voicevoxcore.exceptions.InvalidModelDataException;
+import jp.hiroshiba.voicevoxcore.exceptions.InvalidQueryException;
 import jp.hiroshiba.voicevoxcore.exceptions.RunModelException;
 import org.junit.jupiter.api.Test;
 
@@ -67,6 +72,29 @@ class SynthesizerTest extends TestUtils {
     assertEquals("cpuNumThreads", e.getMessage());
   }
 
+  @Test
+  void checkOperationPlacement() throws RunModelException, InvalidModelDataException {
+    Onnxruntime onnxruntime = loadOnnxruntime();
+    OpenJtalk openJtalk = loadOpenJtalk();
+    Synthesizer synthesizer =
+        Synthesizer.builder(onnxruntime, openJtalk)
+            .accelerationMode(AccelerationMode.CPU)
+            .operationPlacement(
+                InferenceOperationKind.TALK_PREDICT_DURATION,
+                new OperationPlacement(OperationDevice.ACCELERATOR))
+            .operationPlacement(
+                InferenceOperationKind.TALK_DECODE, new OperationPlacement(OperationDevice.CPU, 1))
+            .build();
+    assertFalse(synthesizer.isGpuMode());
+
+    try (VoiceModelFile model = openModel()) {
+      synthesizer.loadVoiceModel(model).perform();
+    }
+
+    int styleId = synthesizer.metas()[0].styles[0].id;
+    assertTrue(synthesizer.tts("こんにちは", styleId).perform().length > 0);
+  }
+
   boolean checkAllMoras(
       List<AccentPhrase> accentPhrases,
       List<AccentPhrase> otherAccentPhrases,
@@ -265,5 +293,39 @@ class SynthesizerTest extends TestUtils {
     assertEquals((byte) 'I', wav[1]);
     assertEquals((byte) 'F', wav[2]);
     assertEquals((byte) 'F', wav[3]);
+
+    byte[] pcm = synthesizer.frameRender(frameAudioQuery, SINGER, 60, 105);
+    assertEquals((105 - 60) * 256 * 2, pcm.length);
+
+    SongTrack track = new SongTrack(SCORE, SINGING_TEACHER, SINGER);
+    track.pan = -0.5f;
+    byte[] mixed = synthesizer.renderSongProject(Arrays.asList(track)).perform();
+
+    assertEquals((byte) 'R', mixed[0]);
+    assertEquals((byte) 'I', mixed[1]);
+    assertEquals((byte) 'F', mixed[2]);
+    assertEquals((byte) 'F', mixed[3]);
+  }
+
+  @Test
+  void transposeAndStretch() {
+    Score score =
+        new Score(
+            Arrays.asList(
+                new Note(null, null, "", 10),
+                new Note(null, (byte) 60, "ド", 20),
+                new Note(null, null, "", 10)));
+
+    score.transpose(2);
+    assertEquals((byte) 62, (byte) score.notes.get(1).key);
+
+    score.stretch(1.5f);
+    assertArrayEquals(
+        new long[] {15, 30, 15},
+        score.notes.stream().mapToLong(note -> note.frameLength).toArray());
+
+    assertThrowsExactly(IllegalArgumentException.class, () -> score.transpose(128));
+    assertThrowsExactly(IllegalArgumentException.class, () -> score.stretch(0f));
+    assertThrowsExactly(InvalidQueryException.class, () -> score.stretch(17f));
   }
 }
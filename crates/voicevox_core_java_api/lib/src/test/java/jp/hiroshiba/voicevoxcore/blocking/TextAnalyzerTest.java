@@ -0,0 +1,78 @@
+/*
+ * OpenJtalk以外のテキスト解析器のテスト。
+ */
+package jp.hiroshiba.voicevoxcore.blocking;
+
+import static org.junit.jupiter.api.Assertions.assertArrayEquals;
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertSame;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+
+import java.util.ArrayList;
+import java.util.List;
+import jp.hiroshiba.voicevoxcore.AccelerationMode;
+import jp.hiroshiba.voicevoxcore.AccentPhrase;
+import jp.hiroshiba.voicevoxcore.TestUtils;
+import jp.hiroshiba.voicevoxcore.exceptions.AnalyzeTextException;
+import jp.hiroshiba.voicevoxcore.exceptions.InvalidModelDataException;
+import jp.hiroshiba.voicevoxcore.exceptions.RunModelException;
+import org.junit.jupiter.api.Test;
+
+class TextAnalyzerTest extends TestUtils {
+  static final class RecordingTextAnalyzer implements TextAnalyzer {
+    final OpenJtalk openJtalk;
+    final List<String> texts = new ArrayList<>();
+
+    RecordingTextAnalyzer(OpenJtalk openJtalk) {
+      this.openJtalk = openJtalk;
+    }
+
+    @Override
+    public List<AccentPhrase> analyze(String text) {
+      texts.add(text);
+      return openJtalk.analyze(text);
+    }
+  }
+
+  @Test
+  void checkForeignTextAnalyzer() throws InvalidModelDataException, RunModelException {
+    final String TEXT = "こんにちは";
+
+    Onnxruntime onnxruntime = loadOnnxruntime();
+    OpenJtalk openJtalk = loadOpenJtalk();
+    RecordingTextAnalyzer textAnalyzer = new RecordingTextAnalyzer(openJtalk);
+
+    Synthesizer synthesizer1 =
+        Synthesizer.builder(onnxruntime, openJtalk).accelerationMode(AccelerationMode.CPU).build();
+    Synthesizer synthesizer2 =
+        Synthesizer.builder(onnxruntime, textAnalyzer)
+            .accelerationMode(AccelerationMode.CPU)
+            .build();
+    assertSame(textAnalyzer, synthesizer2.getOpenJtalk());
+
+    try (VoiceModelFile model = openModel()) {
+      synthesizer1.loadVoiceModel(model).perform();
+      synthesizer2.loadVoiceModel(model).perform();
+    }
+    int styleId = synthesizer1.metas()[0].styles[0].id;
+
+    byte[] wav1 = synthesizer1.tts(TEXT, styleId).perform();
+    byte[] wav2 = synthesizer2.tts(TEXT, styleId).perform();
+    assertArrayEquals(wav1, wav2);
+    assertEquals(List.of(TEXT), textAnalyzer.texts);
+  }
+
+  @Test
+  void checkExceptionFromForeignTextAnalyzer() {
+    Onnxruntime onnxruntime = loadOnnxruntime();
+    TextAnalyzer textAnalyzer =
+        text -> {
+          throw new IllegalStateException("failed");
+        };
+    Synthesizer synthesizer =
+        Synthesizer.builder(onnxruntime, textAnalyzer)
+            .accelerationMode(AccelerationMode.CPU)
+            .build();
+    assertThrows(AnalyzeTextException.class, () -> synthesizer.createAccentPhrases("あ", 0));
+  }
+}
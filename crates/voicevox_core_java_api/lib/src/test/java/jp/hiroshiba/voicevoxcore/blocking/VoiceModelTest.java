@@ -1,6 +1,7 @@
 package jp.hiroshiba.voicevoxcore.blocking;
 
 import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertThrows;
 
 import com.google.gson.Gson;
 import com.google.gson.annotations.Expose;
@@ -12,6 +13,7 @@ import java.nio.file.Files;
 import java.nio.file.Path;
 import java.util.UUID;
 import jp.hiroshiba.voicevoxcore.TestUtils;
+import jp.hiroshiba.voicevoxcore.exceptions.OpenZipFileException;
 import org.junit.jupiter.api.Test;
 
 class VoiceModelTest extends TestUtils {
@@ -25,6 +27,24 @@ class VoiceModelTest extends TestUtils {
     assertEquals(expected, actual);
   }
 
+  @Test
+  void fromBytesShouldMatchFile() throws IOException {
+    // cwdはvoicevox_core/crates/voicevox_core_java_api/lib
+    String cwd = System.getProperty("user.dir");
+    Path path = new File(cwd + "/../../test_util/data/model/sample.vvm").toPath();
+    byte[] data = Files.readAllBytes(path);
+    try (VoiceModelFile expected = openModel();
+        VoiceModelFile actual = new VoiceModelFile(data)) {
+      assertEquals(expected.id, actual.id);
+      assertEquals(new Gson().toJson(expected.metas), new Gson().toJson(actual.metas));
+    }
+  }
+
+  @Test
+  void fromBytesShouldRejectNonZip() {
+    assertThrows(OpenZipFileException.class, () -> new VoiceModelFile(new byte[] {1, 2, 3}));
+  }
+
   private static class Manifest {
     @SerializedName("id")
     @Expose
@@ -0,0 +1,110 @@
+/*
+ * 非同期版の音声合成のテスト。
+ * ブロッキング版と同じ結果になることと、キャンセルできることを確認する。
+ */
+package jp.hiroshiba.voicevoxcore.nonblocking;
+
+import static org.junit.jupiter.api.Assertions.assertArrayEquals;
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertInstanceOf;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+import java.util.List;
+import java.util.concurrent.CancellationException;
+import java.util.concurrent.CompletableFuture;
+import java.util.concurrent.ExecutionException;
+import java.util.concurrent.TimeUnit;
+import java.util.concurrent.TimeoutException;
+import jp.hiroshiba.voicevoxcore.AccentPhrase;
+import jp.hiroshiba.voicevoxcore.AudioQuery;
+import jp.hiroshiba.voicevoxcore.TestUtils;
+import jp.hiroshiba.voicevoxcore.blocking.Onnxruntime;
+import jp.hiroshiba.voicevoxcore.blocking.OpenJtalk;
+import jp.hiroshiba.voicevoxcore.blocking.VoiceModelFile;
+import jp.hiroshiba.voicevoxcore.exceptions.InvalidModelDataException;
+import jp.hiroshiba.voicevoxcore.exceptions.ParseKanaException;
+import jp.hiroshiba.voicevoxcore.exceptions.RunModelException;
+import jp.hiroshiba.voicevoxcore.internal.NativeFuture;
+import org.junit.jupiter.api.Test;
+
+class SynthesizerTest extends TestUtils {
+  jp.hiroshiba.voicevoxcore.blocking.Synthesizer loadBlocking() throws InvalidModelDataException {
+    Onnxruntime onnxruntime = loadOnnxruntime();
+    OpenJtalk openJtalk = loadOpenJtalk();
+    jp.hiroshiba.voicevoxcore.blocking.Synthesizer synthesizer =
+        jp.hiroshiba.voicevoxcore.blocking.Synthesizer.builder(onnxruntime, openJtalk).build();
+    try (VoiceModelFile model = openModel()) {
+      synthesizer.loadVoiceModel(model).perform();
+    }
+    return synthesizer;
+  }
+
+  @Test
+  void checkTtsMatchesBlocking()
+      throws InvalidModelDataException, RunModelException, InterruptedException,
+          ExecutionException {
+    jp.hiroshiba.voicevoxcore.blocking.Synthesizer blocking = loadBlocking();
+    Synthesizer synthesizer = blocking.toNonblocking();
+
+    final String TEXT = "こんにちは？";
+    int styleId = blocking.metas()[0].styles[0].id;
+
+    byte[] expected = blocking.tts(TEXT, styleId).perform();
+
+    assertArrayEquals(expected, synthesizer.tts(TEXT, styleId).perform().get());
+
+    AudioQuery query = synthesizer.createAudioQuery(TEXT, styleId).get();
+    assertArrayEquals(expected, synthesizer.synthesis(query, styleId).perform().get());
+
+    List<AccentPhrase> phrases =
+        synthesizer
+            .createAccentPhrases(TEXT, styleId)
+            .thenCompose(p -> synthesizer.replaceMoraData(p, styleId))
+            .get();
+    query = AudioQuery.fromAccentPhrases(phrases);
+    assertArrayEquals(expected, synthesizer.synthesis(query, styleId).perform().get());
+  }
+
+  @Test
+  void checkFailure() throws InvalidModelDataException, InterruptedException {
+    Synthesizer synthesizer = loadBlocking().toNonblocking();
+
+    CompletableFuture<AudioQuery> future = synthesizer.createAudioQueryFromKana("?", 0);
+    ExecutionException e = assertThrows(ExecutionException.class, future::get);
+    assertInstanceOf(ParseKanaException.class, e.getCause());
+  }
+
+  @Test
+  void checkCancel()
+      throws InvalidModelDataException, InterruptedException, TimeoutException {
+    Synthesizer synthesizer = loadBlocking().toNonblocking();
+
+    StringBuilder text = new StringBuilder();
+    for (int i = 0; i < 100; i++) {
+      text.append("こんにちは。");
+    }
+    CompletableFuture<byte[]> future =
+        synthesizer.tts(text.toString(), 0).cancellable(true).perform();
+
+    assertTrue(future.cancel(true));
+    assertTrue(future.isCancelled());
+    assertThrows(CancellationException.class, future::get);
+    assertEquals(true, future.isCompletedExceptionally());
+
+    // Rust側の処理が最後まで実行されたのではなく、中断されたこと
+    assertTrue(((NativeFuture<?, ?>) future).awaitNativeStop(1, TimeUnit.MINUTES));
+  }
+
+  @Test
+  void checkNotAbortedWithoutCancel()
+      throws InvalidModelDataException, InterruptedException, TimeoutException,
+          ExecutionException {
+    Synthesizer synthesizer = loadBlocking().toNonblocking();
+
+    CompletableFuture<AudioQuery> future = synthesizer.createAudioQueryFromKana("コンニチワ'", 0);
+    future.get();
+    assertFalse(((NativeFuture<?, ?>) future).awaitNativeStop(1, TimeUnit.MINUTES));
+  }
+}
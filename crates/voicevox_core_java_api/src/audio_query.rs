@@ -1,15 +1,16 @@
 use std::{borrow::Cow, ptr};
 
-use crate::common::{JavaApiResult, query_from_json, throw_if_err};
+use crate::common::{JavaApiError, JavaApiResult, query_from_json, throw_if_err};
+use duplicate::duplicate_item;
 use easy_ext::ext;
 use jni::{
     JNIEnv,
     objects::{JClass, JObject, JString, JValueGen},
-    sys::jstring,
+    sys::{jfloat, jint, jstring},
 };
 use voicevox_core::{
-    __internal::interop::Validate, AccentPhrase, AudioQuery, FrameAudioQuery, FramePhoneme, Mora,
-    Note, Score,
+    __internal::interop::Validate, AccentPhrase, AudioQuery, Dynamics, FrameAudioQuery,
+    FramePhoneme, Mora, Note, Score,
 };
 
 // SAFETY: voicevox_core_java_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
@@ -74,6 +75,24 @@ extern "system" fn Java_jp_hiroshiba_voicevoxcore_Note_rsValidate(
     throw_if_err(env, (), |env| Note::validate_json(env, this))
 }
 
+// SAFETY: voicevox_core_java_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+#[unsafe(no_mangle)]
+extern "system" fn Java_jp_hiroshiba_voicevoxcore_Note_rsVolumeScaleOf(
+    env: JNIEnv<'_>,
+    _: JClass<'_>,
+    dynamics: JString<'_>,
+) -> jfloat {
+    throw_if_err(env, 0., |env| {
+        if dynamics.is_null() {
+            return Ok(Dynamics::Mf.volume_scale());
+        }
+        let dynamics = String::from(env.get_string(&dynamics)?);
+        let dynamics = serde_json::from_value::<Dynamics>(dynamics.into())
+            .map_err(|e| JavaApiError::IllegalArgument(e.to_string()))?;
+        Ok(dynamics.volume_scale())
+    })
+}
+
 // SAFETY: voicevox_core_java_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
 #[unsafe(no_mangle)]
 extern "system" fn Java_jp_hiroshiba_voicevoxcore_FrameAudioQuery_rsValidate(
@@ -108,6 +127,44 @@ extern "system" fn Java_jp_hiroshiba_voicevoxcore_Queries_rsEnsureCompatible(
     })
 }
 
+// SAFETY: voicevox_core_java_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+#[duplicate_item(
+    f T;
+    [ Java_jp_hiroshiba_voicevoxcore_Score_rsTranspose ] [ Score ];
+    [ Java_jp_hiroshiba_voicevoxcore_FrameAudioQuery_rsTranspose ] [ FrameAudioQuery ];
+)]
+#[unsafe(no_mangle)]
+extern "system" fn f(env: JNIEnv<'_>, this: JObject<'_>, semitones: jint) -> jstring {
+    throw_if_err(env, ptr::null_mut(), |env| {
+        let semitones = i8::try_from(semitones)
+            .map_err(|_| JavaApiError::IllegalArgument("semitones".to_owned()))?;
+        let mut query = T::from_java(env, this)?;
+        query.transpose(semitones);
+        let query = serde_json::to_string(&query).expect("should not fail");
+        Ok(env.new_string(query)?.into_raw())
+    })
+}
+
+// SAFETY: voicevox_core_java_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+#[duplicate_item(
+    f T;
+    [ Java_jp_hiroshiba_voicevoxcore_Score_rsStretch ] [ Score ];
+    [ Java_jp_hiroshiba_voicevoxcore_FrameAudioQuery_rsStretch ] [ FrameAudioQuery ];
+)]
+#[unsafe(no_mangle)]
+extern "system" fn f(env: JNIEnv<'_>, this: JObject<'_>, ratio: jfloat) -> jstring {
+    throw_if_err(env, ptr::null_mut(), |env| {
+        let mut query = T::from_java(env, this)?;
+        query.stretch(
+            ratio
+                .try_into()
+                .map_err(|_| JavaApiError::IllegalArgument("ratio".to_owned()))?,
+        )?;
+        let query = serde_json::to_string(&query).expect("should not fail");
+        Ok(env.new_string(query)?.into_raw())
+    })
+}
+
 #[ext]
 impl<T: Validate> T {
     fn validate_json(env: &mut JNIEnv<'_>, this: JObject<'_>) -> JavaApiResult<()> {
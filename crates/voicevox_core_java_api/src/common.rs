@@ -43,139 +43,145 @@ where
     match inner(&mut env) {
         Ok(value) => value as _,
         Err(error) => {
-            // Java側の例外は無視する。
-            // env.exception_clear()してもいいが、errorのメッセージは"Java exception was thrown"
-            // となり、デバッグが困難になるため、そのままにしておく。
-            if !env.exception_check().unwrap_or(false) {
-                macro_rules! or_panic {
-                    ($result:expr) => {
-                        $result.unwrap_or_else(|_| {
-                            panic!("Failed to throw exception, original error: {error:?}")
-                        })
-                    };
-                }
+            throw(&mut env, error);
+            fallback
+        }
+    }
+}
 
-                match &error {
-                    JavaApiError::RustApi(error) => {
-                        macro_rules! class {
-                            ($($variant:ident),* $(,)?) => {
-                                match error.kind() {
-                                    $(
-                                        voicevox_core::ErrorKind::$variant => concat!(
-                                            "jp/hiroshiba/voicevoxcore/exceptions/",
-                                            stringify!($variant),
-                                            "Exception",
-                                        ),
-                                    )*
-                                    voicevox_core::ErrorKind::__NonExhaustive => unreachable!(),
-                                }
-                            };
+/// `error`をJavaの例外として投げる。
+pub(crate) fn throw(env: &mut JNIEnv<'_>, error: JavaApiError) {
+    // Java側の例外は無視する。
+    // env.exception_clear()してもいいが、errorのメッセージは"Java exception was thrown"
+    // となり、デバッグが困難になるため、そのままにしておく。
+    if !env.exception_check().unwrap_or(false) {
+        macro_rules! or_panic {
+            ($result:expr) => {
+                $result.unwrap_or_else(|_| {
+                    panic!("Failed to throw exception, original error: {error:?}")
+                })
+            };
+        }
+
+        match &error {
+            JavaApiError::RustApi(error) => {
+                macro_rules! class {
+                    ($($variant:ident),* $(,)?) => {
+                        match error.kind() {
+                            $(
+                                voicevox_core::ErrorKind::$variant => concat!(
+                                    "jp/hiroshiba/voicevoxcore/exceptions/",
+                                    stringify!($variant),
+                                    "Exception",
+                                ),
+                            )*
+                            voicevox_core::ErrorKind::__NonExhaustive => unreachable!(),
                         }
+                    };
+                }
 
-                        let class = class!(
-                            NotLoadedOpenjtalkDict,
-                            GpuSupport,
-                            InitInferenceRuntime,
-                            OpenZipFile,
-                            ReadZipEntry,
-                            InvalidModelFormat,
-                            ModelAlreadyLoaded,
-                            StyleAlreadyLoaded,
-                            InvalidModelData,
-                            GetSupportedDevices,
-                            StyleNotFound,
-                            ModelNotFound,
-                            RunModel,
-                            AnalyzeText,
-                            ParseKana,
-                            LoadUserDict,
-                            SaveUserDict,
-                            WordNotFound,
-                            UseUserDict,
-                            InvalidWord,
-                            InvalidQuery,
-                            IncompatibleQueries,
-                        );
-
-                        let mut sources =
-                            iter::successors(error.source(), |&source| source.source())
-                                .collect::<Vec<_>>()
-                                .into_iter()
-                                .rev();
-
-                        // FIXME: `.unwrap()`ではなく、ちゃんと`.expect()`とかを書く
-
-                        let exc = JThrowable::from(if let Some(innermost) = sources.next() {
-                            let innermost = env
-                                .new_object(
-                                    "java/lang/RuntimeException",
-                                    "(Ljava/lang/String;)V",
-                                    &[(&env.new_string(innermost.to_string()).unwrap()).into()],
-                                )
-                                .unwrap();
-
-                            let cause = sources.fold(innermost, |cause, source| {
-                                env.new_object(
-                                    "java/lang/RuntimeException",
-                                    "(Ljava/lang/String;Ljava/lang/Throwable;)V",
-                                    &[
-                                        (&env.new_string(source.to_string()).unwrap()).into(),
-                                        (&cause).into(),
-                                    ],
-                                )
-                                .unwrap()
-                            });
-
-                            env.new_object(
-                                class,
-                                "(Ljava/lang/String;Ljava/lang/Throwable;)V",
-                                &[
-                                    (&env.new_string(error.to_string()).unwrap()).into(),
-                                    (&cause).into(),
-                                ],
-                            )
-                            .unwrap()
-                        } else {
-                            env.new_object(
-                                class,
-                                "(Ljava/lang/String;)V",
-                                &[(&env.new_string(error.to_string()).unwrap()).into()],
-                            )
-                            .unwrap()
-                        });
-
-                        or_panic!(env.throw(exc));
-                    }
-                    JavaApiError::Jni(error) => {
-                        or_panic!(env.throw_new("java/lang/RuntimeException", error.to_string()))
-                    }
-                    JavaApiError::Uuid(error) => {
-                        or_panic!(
-                            env.throw_new("java/lang/IllegalArgumentException", error.to_string())
-                        )
-                    }
-                    JavaApiError::DeQuery(description, cause) => {
-                        let description = &or_panic!(env.new_string(description));
-                        let cause = &or_panic!(env.new_string(cause.to_string()));
-                        let cause = &or_panic!(env.new_object(
-                            "java/lang/IllegalArgumentException",
+                let class = class!(
+                    NotLoadedOpenjtalkDict,
+                    GpuSupport,
+                    InitInferenceRuntime,
+                    OpenZipFile,
+                    ReadZipEntry,
+                    InvalidModelFormat,
+                    ModelAlreadyLoaded,
+                    StyleAlreadyLoaded,
+                    InvalidModelData,
+                    VerifyModel,
+                    GetSupportedDevices,
+                    StyleNotFound,
+                    ModelNotFound,
+                    RunModel,
+                    AnalyzeText,
+                    ParseKana,
+                    LoadUserDict,
+                    SaveUserDict,
+                    WordNotFound,
+                    UseUserDict,
+                    InvalidWord,
+                    InvalidQuery,
+                    IncompatibleQueries,
+                );
+
+                let mut sources = iter::successors(error.source(), |&source| source.source())
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .rev();
+
+                // FIXME: `.unwrap()`ではなく、ちゃんと`.expect()`とかを書く
+
+                let exc = JThrowable::from(if let Some(innermost) = sources.next() {
+                    let innermost = env
+                        .new_object(
+                            "java/lang/RuntimeException",
                             "(Ljava/lang/String;)V",
-                            &[cause.into()],
-                        ));
-                        let exc = JThrowable::from(or_panic!(env.new_object(
-                            "jp/hiroshiba/voicevoxcore/exceptions/InvalidQueryException",
+                            &[(&env.new_string(innermost.to_string()).unwrap()).into()],
+                        )
+                        .unwrap();
+
+                    let cause = sources.fold(innermost, |cause, source| {
+                        env.new_object(
+                            "java/lang/RuntimeException",
                             "(Ljava/lang/String;Ljava/lang/Throwable;)V",
-                            &[description.into(), cause.into()],
-                        )));
-                        or_panic!(env.throw(exc));
-                    }
-                    JavaApiError::IllegalState(msg) => {
-                        or_panic!(env.throw_new("java/lang/IllegalStateException", msg))
-                    }
-                };
+                            &[
+                                (&env.new_string(source.to_string()).unwrap()).into(),
+                                (&cause).into(),
+                            ],
+                        )
+                        .unwrap()
+                    });
+
+                    env.new_object(
+                        class,
+                        "(Ljava/lang/String;Ljava/lang/Throwable;)V",
+                        &[
+                            (&env.new_string(error.to_string()).unwrap()).into(),
+                            (&cause).into(),
+                        ],
+                    )
+                    .unwrap()
+                } else {
+                    env.new_object(
+                        class,
+                        "(Ljava/lang/String;)V",
+                        &[(&env.new_string(error.to_string()).unwrap()).into()],
+                    )
+                    .unwrap()
+                });
+
+                or_panic!(env.throw(exc));
             }
-            fallback
-        }
+            JavaApiError::Jni(error) => {
+                or_panic!(env.throw_new("java/lang/RuntimeException", error.to_string()))
+            }
+            JavaApiError::Uuid(error) => {
+                or_panic!(env.throw_new("java/lang/IllegalArgumentException", error.to_string()))
+            }
+            JavaApiError::DeQuery(description, cause) => {
+                let description = &or_panic!(env.new_string(description));
+                let cause = &or_panic!(env.new_string(cause.to_string()));
+                let cause = &or_panic!(env.new_object(
+                    "java/lang/IllegalArgumentException",
+                    "(Ljava/lang/String;)V",
+                    &[cause.into()],
+                ));
+                let exc = JThrowable::from(or_panic!(env.new_object(
+                    "jp/hiroshiba/voicevoxcore/exceptions/InvalidQueryException",
+                    "(Ljava/lang/String;Ljava/lang/Throwable;)V",
+                    &[description.into(), cause.into()],
+                )));
+                or_panic!(env.throw(exc));
+            }
+            JavaApiError::IllegalState(msg) => {
+                or_panic!(env.throw_new("java/lang/IllegalStateException", msg))
+            }
+            JavaApiError::IllegalArgument(msg) => {
+                or_panic!(env.throw_new("java/lang/IllegalArgumentException", msg))
+            }
+        };
     }
 }
 
@@ -200,6 +206,8 @@ pub(crate) enum JavaApiError {
     DeQuery(String, serde_json::Error),
 
     IllegalState(String),
+
+    IllegalArgument(String),
 }
 
 pub(crate) struct Closable<T: HasJavaClassIdent>(std::sync::RwLock<MaybeClosed<T>>);
@@ -0,0 +1,153 @@
+//! `jp.hiroshiba.voicevoxcore.nonblocking`の各メソッドが返す`CompletableFuture`を完了させる仕組み。
+
+use std::{
+    num::NonZero,
+    sync::{Arc, LazyLock, Mutex, mpsc},
+    thread,
+};
+
+use futures_util::future::{AbortHandle, abortable};
+use jni::{
+    JNIEnv,
+    objects::{GlobalRef, JObject, JValue},
+};
+
+use crate::common::{JavaApiResult, throw, throw_if_err};
+
+/// `task`を[ワーカースレッド]で実行し、終わったら`future`
+/// (`jp.hiroshiba.voicevoxcore.internal.NativeFuture`)を完了させる。
+///
+/// `future`がJava側でキャンセルされたときは`task`をdropし、`future`にそのことを通知する。
+///
+/// [ワーカースレッド]: WORKERS
+pub(crate) fn spawn<T: Send + 'static>(
+    env: &mut JNIEnv<'_>,
+    future: &JObject<'_>,
+    task: impl Future<Output = JavaApiResult<T>> + Send + 'static,
+    into_java: impl for<'local> FnOnce(&mut JNIEnv<'local>, T) -> JavaApiResult<JObject<'local>>
+    + Send
+    + 'static,
+) -> JavaApiResult<()> {
+    let (task, abort_handle) = abortable(task);
+
+    // SAFETY:
+    // - The safety contract must be upheld by the caller.
+    // - `jp.hiroshiba.voicevoxcore.internal.NativeFuture.handle` must correspond to
+    //   `AbortHandle`.
+    unsafe { env.set_rust_field(future, "handle", abort_handle) }?;
+
+    let vm = env.get_java_vm()?;
+    let future = env.new_global_ref(future)?;
+
+    WORKERS.spawn(move || {
+        let result = pollster::block_on(task);
+        let env = &mut vm
+            .attach_current_thread()
+            .unwrap_or_else(|e| panic!("could not attach a thread to the JVM: {e}"));
+        let Ok(result) = result else {
+            // Java側で既にキャンセルされている
+            env.call_method(&future, "onAbort", "()V", &[])
+                .unwrap_or_else(|e| panic!("could not notify a `NativeFuture` of abortion: {e}"));
+            return;
+        };
+        let result = result.and_then(|output| into_java(env, output));
+        complete(env, &future, result);
+    });
+    Ok(())
+}
+
+/// 処理を実行する、固定数のスレッド。
+///
+/// スレッド数は[`thread::available_parallelism`]であり、それを超える数の処理は先に開始されたものが終わる
+/// まで待たされる。
+static WORKERS: LazyLock<Workers> = LazyLock::new(|| {
+    let (tx, rx) = mpsc::channel::<Box<dyn FnOnce() + Send>>();
+    let rx = Arc::new(Mutex::new(rx));
+    let num_workers = thread::available_parallelism().map_or(1, NonZero::get);
+    for i in 0..num_workers {
+        let rx = rx.clone();
+        thread::Builder::new()
+            .name(format!("voicevox-future-{i}"))
+            .spawn(move || {
+                loop {
+                    // 処理の実行中は`rx`をロックしない
+                    let job = rx.lock().unwrap_or_else(|e| panic!("{e}")).recv();
+                    let Ok(job) = job else { break };
+                    job();
+                }
+            })
+            .expect("should be able to spawn a thread");
+    }
+    Workers(tx)
+});
+
+struct Workers(mpsc::Sender<Box<dyn FnOnce() + Send>>);
+
+impl Workers {
+    fn spawn(&self, job: impl FnOnce() + Send + 'static) {
+        self.0
+            .send(Box::new(job))
+            .expect("the workers should live until the end of the process");
+    }
+}
+
+fn complete(env: &mut JNIEnv<'_>, future: &GlobalRef, result: JavaApiResult<JObject<'_>>) {
+    let result = match result {
+        Ok(output) => env.call_method(
+            future,
+            "onSuccess",
+            "(Ljava/lang/Object;)V",
+            &[JValue::Object(&output)],
+        ),
+        Err(err) => {
+            throw(env, err);
+            env.exception_occurred().and_then(|exc| {
+                env.exception_clear()?;
+                env.call_method(
+                    future,
+                    "onFailure",
+                    "(Ljava/lang/Throwable;)V",
+                    &[JValue::Object(&exc)],
+                )
+            })
+        }
+    };
+    result.unwrap_or_else(|e| panic!("could not complete a `NativeFuture`: {e}"));
+}
+
+// SAFETY: voicevox_core_java_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+#[unsafe(no_mangle)]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_internal_NativeFuture_rsCancel<'local>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+) {
+    throw_if_err(env, (), |env| {
+        unsafe {
+            // SAFETY:
+            // - The safety contract must be upheld by the caller.
+            // - `jp.hiroshiba.voicevoxcore.internal.NativeFuture.handle` must correspond to
+            //   `AbortHandle`.
+            env.get_rust_field::<_, _, AbortHandle>(&this, "handle")
+        }?
+        .abort();
+        Ok(())
+    })
+}
+
+// SAFETY: voicevox_core_java_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+#[unsafe(no_mangle)]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_internal_NativeFuture_rsDrop<'local>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+) {
+    throw_if_err(env, (), |env| {
+        unsafe {
+            // SAFETY:
+            // - The safety contract must be upheld by the caller.
+            // - `jp.hiroshiba.voicevoxcore.internal.NativeFuture.handle` must correspond to
+            //   `AbortHandle`.
+            env.take_rust_field::<_, _, AbortHandle>(&this, "handle")
+        }?;
+        Ok(())
+    })
+}
@@ -1,9 +1,11 @@
 mod audio_query;
 mod common;
+mod future;
 mod info;
 mod logger;
 mod onnxruntime;
 mod open_jtalk;
 mod synthesizer;
+mod text_analyzer;
 mod user_dict;
 mod voice_model;
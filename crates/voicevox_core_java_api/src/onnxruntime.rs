@@ -81,14 +81,24 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Onnxruntime_rs
         let devices = this.supported_devices()?;
 
         assert!(match devices.to_json_value() {
-            serde_json::Value::Object(o) => o.len() == 3, // `cpu`, `cuda`, `dml`
+            serde_json::Value::Object(o) => o.len() == 9, // `cpu`, `cuda`, `dml`, `tensorrt`, `rocm`, `coreml`, `xnnpack`, `openvino`, `onednn`
             _ => false,
         });
 
         let devices = env.new_object(
             object!("SupportedDevices"),
-            "(ZZZ)V",
-            &[devices.cpu.into(), devices.cuda.into(), devices.dml.into()],
+            "(ZZZZZZZZZ)V",
+            &[
+                devices.cpu.into(),
+                devices.cuda.into(),
+                devices.dml.into(),
+                devices.tensorrt.into(),
+                devices.rocm.into(),
+                devices.coreml.into(),
+                devices.xnnpack.into(),
+                devices.openvino.into(),
+                devices.onednn.into(),
+            ],
         )?;
         Ok(devices.into_raw())
     })
@@ -1,14 +1,17 @@
 use crate::{
-    common::{JNIEnvExt as _, query_from_json, throw_if_err},
+    common::{JNIEnvExt as _, JavaApiError, JavaApiResult, query_from_json, throw_if_err},
     object, object_type, static_field,
+    text_analyzer::JavaTextAnalyzer,
 };
 
+use duplicate::duplicate_item;
 use jni::{
     JNIEnv,
     objects::{JObject, JString},
     sys::{jboolean, jint, jobject},
 };
-use std::{borrow::Cow, sync::Arc};
+use std::{borrow::Cow, collections::BTreeMap, ops::Range, sync::Arc};
+use voicevox_core::__internal::interop::ToNonblocking as _;
 
 // SAFETY: voicevox_core_java_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
 #[unsafe(no_mangle)]
@@ -16,7 +19,7 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rs
     env: JNIEnv<'local>,
     this: JObject<'local>,
     onnxruntime: JObject<'local>,
-    open_jtalk: JObject<'local>,
+    text_analyzer: JObject<'local>,
     builder: JObject<'local>,
 ) {
     throw_if_err(env, (), |env| {
@@ -48,6 +51,15 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rs
             .get_field(&builder, "cpuNumThreads", "I")?
             .i()
             .expect("cpuNumThreads is not integer") as u16;
+        let operation_placements = &env
+            .get_field(&builder, "operationPlacementsJson", "Ljava/lang/String;")?
+            .l()?
+            .into();
+        let operation_placements = String::from(env.get_string(operation_placements)?);
+        let operation_placements = serde_json::from_str::<
+            BTreeMap<voicevox_core::InferenceOperationKind, voicevox_core::OperationPlacement>,
+        >(&operation_placements)
+        .map_err(|err| JavaApiError::IllegalArgument(err.to_string()))?;
 
         let onnxruntime = *unsafe {
             // SAFETY:
@@ -59,25 +71,40 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rs
                 "handle",
             )
         }?;
-        let open_jtalk = unsafe {
-            // SAFETY:
-            // - The safety contract must be upheld by the caller.
-            // - `jp.hiroshiba.voicevoxcore.blocking.OpenJtalk.handle` must correspond to
-            //   `voicevox_core::blocking::OpenJtalk`.
-            env.get_rust_field::<_, _, voicevox_core::blocking::OpenJtalk>(&open_jtalk, "handle")
-        }?
-        .clone();
+        let text_analyzer = if env.is_instance_of(&text_analyzer, object!("blocking/OpenJtalk"))? {
+            let open_jtalk = unsafe {
+                // SAFETY:
+                // - The safety contract must be upheld by the caller.
+                // - `jp.hiroshiba.voicevoxcore.blocking.OpenJtalk.handle` must correspond to
+                //   `voicevox_core::blocking::OpenJtalk`.
+                env.get_rust_field::<_, _, voicevox_core::blocking::OpenJtalk>(
+                    &text_analyzer,
+                    "handle",
+                )
+            }?
+            .clone();
+            JavaTextAnalyzer::OpenJtalk(open_jtalk)
+        } else {
+            JavaTextAnalyzer::foreign(env, &text_analyzer)?
+        };
         let internal = Arc::new(
-            voicevox_core::blocking::Synthesizer::builder(onnxruntime)
-                .text_analyzer(open_jtalk)
-                .acceleration_mode(acceleration_mode)
-                .cpu_num_threads(cpu_num_threads)
+            operation_placements
+                .into_iter()
+                .fold(
+                    voicevox_core::blocking::Synthesizer::builder(onnxruntime)
+                        .text_analyzer(text_analyzer)
+                        .acceleration_mode(acceleration_mode)
+                        .cpu_num_threads(cpu_num_threads),
+                    |builder, (operation, placement)| {
+                        builder.operation_placement(operation, placement)
+                    },
+                )
                 .build()?,
         );
         // SAFETY:
         // - The safety contract must be upheld by the caller.
         // - `jp.hiroshiba.voicevoxcore.blocking.Synthesizer.handle` must correspond to
-        //   `Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>`.
+        //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
         unsafe { env.set_rust_field(&this, "handle", internal) }?;
         Ok(())
     })
@@ -96,8 +123,8 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rs
             // SAFETY:
             // - The safety contract must be upheld by the caller.
             // - `jp.hiroshiba.voicevoxcore.blocking.Synthesizer.handle` must correspond to
-            //   `Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>`.
-            env.get_rust_field::<_, _, Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>>(
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            env.get_rust_field::<_, _, Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>>(
                 &this, "handle",
             )
         }?
@@ -121,9 +148,8 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rs
             // SAFETY:
             // - The safety contract must be upheld by the caller.
             // - `jp.hiroshiba.voicevoxcore.blocking.Synthesizer.handle` must correspond to
-            //   `Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>`.
-            type RustField =
-                Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>;
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
             env.get_rust_field::<_, _, RustField>(&this, "handle")
         }?
         .clone();
@@ -176,9 +202,8 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rs
             // SAFETY:
             // - The safety contract must be upheld by the caller.
             // - `jp.hiroshiba.voicevoxcore.blocking.Synthesizer.handle` must correspond to
-            //   `Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>`.
-            type RustField =
-                Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>;
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
             env.get_rust_field::<_, _, RustField>(&this, "handle")
         }?
         .clone();
@@ -206,9 +231,8 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rs
             // SAFETY:
             // - The safety contract must be upheld by the caller.
             // - `jp.hiroshiba.voicevoxcore.blocking.Synthesizer.handle` must correspond to
-            //   `Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>`.
-            type RustField =
-                Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>;
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
             env.get_rust_field::<_, _, RustField>(&this, "handle")
         }?
         .clone();
@@ -235,9 +259,8 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rs
             // SAFETY:
             // - The safety contract must be upheld by the caller.
             // - `jp.hiroshiba.voicevoxcore.blocking.Synthesizer.handle` must correspond to
-            //   `Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>`.
-            type RustField =
-                Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>;
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
             env.get_rust_field::<_, _, RustField>(&this, "handle")
         }?
         .clone();
@@ -267,9 +290,8 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rs
             // SAFETY:
             // - The safety contract must be upheld by the caller.
             // - `jp.hiroshiba.voicevoxcore.blocking.Synthesizer.handle` must correspond to
-            //   `Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>`.
-            type RustField =
-                Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>;
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
             env.get_rust_field::<_, _, RustField>(&this, "handle")
         }?
         .clone();
@@ -303,9 +325,8 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rs
             // SAFETY:
             // - The safety contract must be upheld by the caller.
             // - `jp.hiroshiba.voicevoxcore.blocking.Synthesizer.handle` must correspond to
-            //   `Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>`.
-            type RustField =
-                Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>;
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
             env.get_rust_field::<_, _, RustField>(&this, "handle")
         }?
         .clone();
@@ -339,9 +360,8 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rs
             // SAFETY:
             // - The safety contract must be upheld by the caller.
             // - `jp.hiroshiba.voicevoxcore.blocking.Synthesizer.handle` must correspond to
-            //   `Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>`.
-            type RustField =
-                Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>;
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
             env.get_rust_field::<_, _, RustField>(&this, "handle")
         }?
         .clone();
@@ -375,9 +395,8 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rs
             // SAFETY:
             // - The safety contract must be upheld by the caller.
             // - `jp.hiroshiba.voicevoxcore.blocking.Synthesizer.handle` must correspond to
-            //   `Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>`.
-            type RustField =
-                Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>;
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
             env.get_rust_field::<_, _, RustField>(&this, "handle")
         }?
         .clone();
@@ -413,9 +432,8 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rs
             // SAFETY:
             // - The safety contract must be upheld by the caller.
             // - `jp.hiroshiba.voicevoxcore.blocking.Synthesizer.handle` must correspond to
-            //   `Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>`.
-            type RustField =
-                Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>;
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
             env.get_rust_field::<_, _, RustField>(&this, "handle")
         }?
         .clone();
@@ -450,9 +468,8 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rs
             // SAFETY:
             // - The safety contract must be upheld by the caller.
             // - `jp.hiroshiba.voicevoxcore.blocking.Synthesizer.handle` must correspond to
-            //   `Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>`.
-            type RustField =
-                Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>;
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
             env.get_rust_field::<_, _, RustField>(&this, "handle")
         }?
         .clone();
@@ -487,9 +504,8 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rs
             // SAFETY:
             // - The safety contract must be upheld by the caller.
             // - `jp.hiroshiba.voicevoxcore.blocking.Synthesizer.handle` must correspond to
-            //   `Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>`.
-            type RustField =
-                Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>;
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
             env.get_rust_field::<_, _, RustField>(&this, "handle")
         }?
         .clone();
@@ -524,9 +540,8 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rs
             // SAFETY:
             // - The safety contract must be upheld by the caller.
             // - `jp.hiroshiba.voicevoxcore.blocking.Synthesizer.handle` must correspond to
-            //   `Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>`.
-            type RustField =
-                Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>;
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
             env.get_rust_field::<_, _, RustField>(&this, "handle")
         }?
         .clone();
@@ -561,9 +576,8 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rs
             // SAFETY:
             // - The safety contract must be upheld by the caller.
             // - `jp.hiroshiba.voicevoxcore.blocking.Synthesizer.handle` must correspond to
-            //   `Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>`.
-            type RustField =
-                Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>;
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
             env.get_rust_field::<_, _, RustField>(&this, "handle")
         }?
         .clone();
@@ -596,9 +610,8 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rs
             // SAFETY:
             // - The safety contract must be upheld by the caller.
             // - `jp.hiroshiba.voicevoxcore.blocking.Synthesizer.handle` must correspond to
-            //   `Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>`.
-            type RustField =
-                Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>;
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
             env.get_rust_field::<_, _, RustField>(&this, "handle")
         }?
         .clone();
@@ -634,9 +647,8 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rs
             // SAFETY:
             // - The safety contract must be upheld by the caller.
             // - `jp.hiroshiba.voicevoxcore.blocking.Synthesizer.handle` must correspond to
-            //   `Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>`.
-            type RustField =
-                Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>;
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
             env.get_rust_field::<_, _, RustField>(&this, "handle")
         }?
         .clone();
@@ -673,9 +685,8 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rs
             // SAFETY:
             // - The safety contract must be upheld by the caller.
             // - `jp.hiroshiba.voicevoxcore.blocking.Synthesizer.handle` must correspond to
-            //   `Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>`.
-            type RustField =
-                Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>;
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
             env.get_rust_field::<_, _, RustField>(&this, "handle")
         }?
         .clone();
@@ -718,9 +729,8 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rs
             // SAFETY:
             // - The safety contract must be upheld by the caller.
             // - `jp.hiroshiba.voicevoxcore.blocking.Synthesizer.handle` must correspond to
-            //   `Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>`.
-            type RustField =
-                Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>;
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
             env.get_rust_field::<_, _, RustField>(&this, "handle")
         }?
         .clone();
@@ -759,9 +769,8 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rs
             // SAFETY:
             // - The safety contract must be upheld by the caller.
             // - `jp.hiroshiba.voicevoxcore.blocking.Synthesizer.handle` must correspond to
-            //   `Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>`.
-            type RustField =
-                Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>;
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
             env.get_rust_field::<_, _, RustField>(&this, "handle")
         }?
         .clone();
@@ -774,6 +783,81 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rs
     })
 }
 
+// SAFETY: voicevox_core_java_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+#[unsafe(no_mangle)]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rsFrameRender<
+    'local,
+>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    frame_audio_query: JString<'local>,
+    style_id: jint,
+    start: jint,
+    end: jint,
+) -> jobject {
+    throw_if_err(env, std::ptr::null_mut(), |env| {
+        let frame_audio_query = &env.get_string(&frame_audio_query)?;
+        let frame_audio_query = &query_from_json(&Cow::from(frame_audio_query))?;
+
+        let style_id = style_id as u32;
+        let range = frame_range(start, end)?;
+
+        let internal = unsafe {
+            // SAFETY:
+            // - The safety contract must be upheld by the caller.
+            // - `jp.hiroshiba.voicevoxcore.blocking.Synthesizer.handle` must correspond to
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
+            env.get_rust_field::<_, _, RustField>(&this, "handle")
+        }?
+        .clone();
+
+        let pcm = &internal
+            .frame_render(
+                frame_audio_query,
+                voicevox_core::StyleId::new(style_id),
+                range,
+            )
+            .perform()?;
+        let pcm = env.byte_array_from_slice(pcm)?;
+        Ok(pcm.into_raw())
+    })
+}
+
+// SAFETY: voicevox_core_java_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+#[unsafe(no_mangle)]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rsRenderSongProject<
+    'local,
+>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    tracks: JString<'local>,
+    output_sampling_rate: jint,
+) -> jobject {
+    throw_if_err(env, std::ptr::null_mut(), |env| {
+        let tracks = &env.get_string(&tracks)?;
+        let tracks = &song_tracks_from_json(&Cow::from(tracks))?;
+        let output_sampling_rate = voicevox_core::SamplingRate::new(output_sampling_rate as u32)?;
+
+        let internal = unsafe {
+            // SAFETY:
+            // - The safety contract must be upheld by the caller.
+            // - `jp.hiroshiba.voicevoxcore.blocking.Synthesizer.handle` must correspond to
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
+            env.get_rust_field::<_, _, RustField>(&this, "handle")
+        }?
+        .clone();
+
+        let wav = &internal
+            .render_song_project(tracks)
+            .output_sampling_rate(output_sampling_rate)
+            .perform()?;
+        let wav = env.byte_array_from_slice(wav)?;
+        Ok(wav.into_raw())
+    })
+}
+
 // SAFETY: voicevox_core_java_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
 #[unsafe(no_mangle)]
 unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rsDrop<'local>(
@@ -785,11 +869,476 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_Synthesizer_rs
             // SAFETY:
             // - The safety contract must be upheld by the caller.
             // - `jp.hiroshiba.voicevoxcore.blocking.Synthesizer.handle` must correspond to
-            //   `Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>`.
-            type RustField =
-                Arc<voicevox_core::blocking::Synthesizer<voicevox_core::blocking::OpenJtalk>>;
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
             env.take_rust_field::<_, _, RustField>(&this, "handle")
         }?;
         Ok(())
     })
 }
+
+// SAFETY: voicevox_core_java_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+#[unsafe(no_mangle)]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_nonblocking_Synthesizer_rsNew<'local>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    blocking: JObject<'local>,
+) {
+    throw_if_err(env, (), |env| {
+        let internal = unsafe {
+            // SAFETY:
+            // - The safety contract must be upheld by the caller.
+            // - `jp.hiroshiba.voicevoxcore.blocking.Synthesizer.handle` must correspond to
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
+            env.get_rust_field::<_, _, RustField>(&blocking, "handle")
+        }?
+        .clone();
+        // SAFETY:
+        // - The safety contract must be upheld by the caller.
+        // - `jp.hiroshiba.voicevoxcore.nonblocking.Synthesizer.handle` must correspond to
+        //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+        unsafe { env.set_rust_field(&this, "handle", internal) }?;
+        Ok(())
+    })
+}
+
+// SAFETY: voicevox_core_java_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+#[duplicate_item(
+    f method;
+    [ Java_jp_hiroshiba_voicevoxcore_nonblocking_Synthesizer_rsCreateAudioQueryFromKana ]  [ create_audio_query_from_kana ];
+    [ Java_jp_hiroshiba_voicevoxcore_nonblocking_Synthesizer_rsCreateAudioQuery ]          [ create_audio_query ];
+    [ Java_jp_hiroshiba_voicevoxcore_nonblocking_Synthesizer_rsAccentPhrasesFromKana ]     [ create_accent_phrases_from_kana ];
+    [ Java_jp_hiroshiba_voicevoxcore_nonblocking_Synthesizer_rsAccentPhrases ]             [ create_accent_phrases ];
+)]
+#[unsafe(no_mangle)]
+unsafe extern "system" fn f<'local>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    future: JObject<'local>,
+    text: JString<'local>,
+    style_id: jint,
+) {
+    throw_if_err(env, (), |env| {
+        let text: String = env.get_string(&text)?.into();
+        let style_id = voicevox_core::StyleId::new(style_id as u32);
+
+        let synthesizer = unsafe {
+            // SAFETY:
+            // - The safety contract must be upheld by the caller.
+            // - `jp.hiroshiba.voicevoxcore.nonblocking.Synthesizer.handle` must correspond to
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
+            env.get_rust_field::<_, _, RustField>(&this, "handle")
+        }?
+        .to_nonblocking();
+
+        crate::future::spawn(
+            env,
+            &future,
+            async move {
+                let output = synthesizer.method(&text, style_id).await?;
+                Ok(serde_json::to_string(&output).expect("should not fail"))
+            },
+            |env, json| Ok(env.new_string(json)?.into()),
+        )
+    })
+}
+
+// SAFETY: voicevox_core_java_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+#[duplicate_item(
+    f method;
+    [ Java_jp_hiroshiba_voicevoxcore_nonblocking_Synthesizer_rsReplaceMoraData ]      [ replace_mora_data ];
+    [ Java_jp_hiroshiba_voicevoxcore_nonblocking_Synthesizer_rsReplacePhonemeLength ] [ replace_phoneme_length ];
+    [ Java_jp_hiroshiba_voicevoxcore_nonblocking_Synthesizer_rsReplaceMoraPitch ]     [ replace_mora_pitch ];
+)]
+#[unsafe(no_mangle)]
+unsafe extern "system" fn f<'local>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    future: JObject<'local>,
+    accent_phrases_json: JString<'local>,
+    style_id: jint,
+) {
+    throw_if_err(env, (), |env| {
+        let accent_phrases_json: String = env.get_string(&accent_phrases_json)?.into();
+        let accent_phrases: Vec<voicevox_core::AccentPhrase> =
+            query_from_json(&accent_phrases_json)?;
+        let style_id = voicevox_core::StyleId::new(style_id as u32);
+
+        let synthesizer = unsafe {
+            // SAFETY:
+            // - The safety contract must be upheld by the caller.
+            // - `jp.hiroshiba.voicevoxcore.nonblocking.Synthesizer.handle` must correspond to
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
+            env.get_rust_field::<_, _, RustField>(&this, "handle")
+        }?
+        .to_nonblocking();
+
+        crate::future::spawn(
+            env,
+            &future,
+            async move {
+                let replaced = synthesizer.method(&accent_phrases, style_id).await?;
+                Ok(serde_json::to_string(&replaced).expect("should not fail"))
+            },
+            |env, json| Ok(env.new_string(json)?.into()),
+        )
+    })
+}
+
+// SAFETY: voicevox_core_java_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+#[unsafe(no_mangle)]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_nonblocking_Synthesizer_rsSynthesis<
+    'local,
+>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    future: JObject<'local>,
+    query_json: JString<'local>,
+    style_id: jint,
+    enable_interrogative_upspeak: jboolean,
+    cancellable: jboolean,
+) {
+    throw_if_err(env, (), |env| {
+        let audio_query: String = env.get_string(&query_json)?.into();
+        let audio_query: voicevox_core::AudioQuery = query_from_json(&audio_query)?;
+        let style_id = voicevox_core::StyleId::new(style_id as u32);
+
+        let synthesizer = unsafe {
+            // SAFETY:
+            // - The safety contract must be upheld by the caller.
+            // - `jp.hiroshiba.voicevoxcore.nonblocking.Synthesizer.handle` must correspond to
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
+            env.get_rust_field::<_, _, RustField>(&this, "handle")
+        }?
+        .to_nonblocking();
+
+        crate::future::spawn(
+            env,
+            &future,
+            async move {
+                let wave = synthesizer
+                    .synthesis(&audio_query, style_id)
+                    .enable_interrogative_upspeak(enable_interrogative_upspeak != 0)
+                    .cancellable(cancellable != 0)
+                    .perform()
+                    .await?;
+                Ok(wave)
+            },
+            |env, wave| Ok(env.byte_array_from_slice(&wave)?.into()),
+        )
+    })
+}
+
+// SAFETY: voicevox_core_java_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+#[duplicate_item(
+    f method;
+    [ Java_jp_hiroshiba_voicevoxcore_nonblocking_Synthesizer_rsTtsFromKana ] [ tts_from_kana ];
+    [ Java_jp_hiroshiba_voicevoxcore_nonblocking_Synthesizer_rsTts ]         [ tts ];
+)]
+#[unsafe(no_mangle)]
+unsafe extern "system" fn f<'local>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    future: JObject<'local>,
+    text: JString<'local>,
+    style_id: jint,
+    enable_interrogative_upspeak: jboolean,
+    cancellable: jboolean,
+) {
+    throw_if_err(env, (), |env| {
+        let text: String = env.get_string(&text)?.into();
+        let style_id = voicevox_core::StyleId::new(style_id as u32);
+
+        let synthesizer = unsafe {
+            // SAFETY:
+            // - The safety contract must be upheld by the caller.
+            // - `jp.hiroshiba.voicevoxcore.nonblocking.Synthesizer.handle` must correspond to
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
+            env.get_rust_field::<_, _, RustField>(&this, "handle")
+        }?
+        .to_nonblocking();
+
+        crate::future::spawn(
+            env,
+            &future,
+            async move {
+                let wave = synthesizer
+                    .method(&text, style_id)
+                    .enable_interrogative_upspeak(enable_interrogative_upspeak != 0)
+                    .cancellable(cancellable != 0)
+                    .perform()
+                    .await?;
+                Ok(wave)
+            },
+            |env, wave| Ok(env.byte_array_from_slice(&wave)?.into()),
+        )
+    })
+}
+
+// SAFETY: voicevox_core_java_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+#[unsafe(no_mangle)]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_nonblocking_Synthesizer_rsCreateSingFrameAudioQuery<
+    'local,
+>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    future: JObject<'local>,
+    score: JString<'local>,
+    style_id: jint,
+) {
+    throw_if_err(env, (), |env| {
+        let score = &env.get_string(&score)?;
+        let score: voicevox_core::Score = query_from_json(&Cow::from(score))?;
+        let style_id = voicevox_core::StyleId::new(style_id as u32);
+
+        let synthesizer = unsafe {
+            // SAFETY:
+            // - The safety contract must be upheld by the caller.
+            // - `jp.hiroshiba.voicevoxcore.nonblocking.Synthesizer.handle` must correspond to
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
+            env.get_rust_field::<_, _, RustField>(&this, "handle")
+        }?
+        .to_nonblocking();
+
+        crate::future::spawn(
+            env,
+            &future,
+            async move {
+                let frame_audio_query = &synthesizer
+                    .create_sing_frame_audio_query(&score, style_id)
+                    .await?;
+                Ok(serde_json::Value::from(frame_audio_query).to_string())
+            },
+            |env, json| Ok(env.new_string(json)?.into()),
+        )
+    })
+}
+
+// SAFETY: voicevox_core_java_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+#[duplicate_item(
+    f method;
+    [ Java_jp_hiroshiba_voicevoxcore_nonblocking_Synthesizer_rsCreateSingFrameF0 ]     [ create_sing_frame_f0 ];
+    [ Java_jp_hiroshiba_voicevoxcore_nonblocking_Synthesizer_rsCreateSingFrameVolume ] [ create_sing_frame_volume ];
+)]
+#[unsafe(no_mangle)]
+unsafe extern "system" fn f<'local>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    future: JObject<'local>,
+    score: JString<'local>,
+    frame_audio_query: JString<'local>,
+    style_id: jint,
+) {
+    throw_if_err(env, (), |env| {
+        let score = &env.get_string(&score)?;
+        let score: voicevox_core::Score = query_from_json(&Cow::from(score))?;
+
+        let frame_audio_query = &env.get_string(&frame_audio_query)?;
+        let frame_audio_query: voicevox_core::FrameAudioQuery =
+            query_from_json(&Cow::from(frame_audio_query))?;
+
+        let style_id = voicevox_core::StyleId::new(style_id as u32);
+
+        let synthesizer = unsafe {
+            // SAFETY:
+            // - The safety contract must be upheld by the caller.
+            // - `jp.hiroshiba.voicevoxcore.nonblocking.Synthesizer.handle` must correspond to
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
+            env.get_rust_field::<_, _, RustField>(&this, "handle")
+        }?
+        .to_nonblocking();
+
+        crate::future::spawn(
+            env,
+            &future,
+            async move {
+                let values = synthesizer
+                    .method(&score, &frame_audio_query, style_id)
+                    .await?;
+                // TODO: typed_floatsにissueかPRを出しに行き、スライス変換かbytemuck対応を入れてもらう
+                Ok(values.into_iter().map(Into::into).collect::<Vec<f32>>())
+            },
+            |env, values| {
+                let ret = env.new_float_array(values.len().try_into().unwrap())?;
+                env.set_float_array_region(&ret, 0, &values)?;
+                Ok(ret.into())
+            },
+        )
+    })
+}
+
+// SAFETY: voicevox_core_java_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+#[unsafe(no_mangle)]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_nonblocking_Synthesizer_rsFrameSynthesis<
+    'local,
+>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    future: JObject<'local>,
+    frame_audio_query: JString<'local>,
+    style_id: jint,
+    cancellable: jboolean,
+) {
+    throw_if_err(env, (), |env| {
+        let frame_audio_query = &env.get_string(&frame_audio_query)?;
+        let frame_audio_query: voicevox_core::FrameAudioQuery =
+            query_from_json(&Cow::from(frame_audio_query))?;
+        let style_id = voicevox_core::StyleId::new(style_id as u32);
+
+        let synthesizer = unsafe {
+            // SAFETY:
+            // - The safety contract must be upheld by the caller.
+            // - `jp.hiroshiba.voicevoxcore.nonblocking.Synthesizer.handle` must correspond to
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
+            env.get_rust_field::<_, _, RustField>(&this, "handle")
+        }?
+        .to_nonblocking();
+
+        crate::future::spawn(
+            env,
+            &future,
+            async move {
+                let wave = synthesizer
+                    .frame_synthesis(&frame_audio_query, style_id)
+                    .cancellable(cancellable != 0)
+                    .perform()
+                    .await?;
+                Ok(wave)
+            },
+            |env, wave| Ok(env.byte_array_from_slice(&wave)?.into()),
+        )
+    })
+}
+
+// SAFETY: voicevox_core_java_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+#[unsafe(no_mangle)]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_nonblocking_Synthesizer_rsFrameRender<
+    'local,
+>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    future: JObject<'local>,
+    frame_audio_query: JString<'local>,
+    style_id: jint,
+    start: jint,
+    end: jint,
+    cancellable: jboolean,
+) {
+    throw_if_err(env, (), |env| {
+        let frame_audio_query = &env.get_string(&frame_audio_query)?;
+        let frame_audio_query: voicevox_core::FrameAudioQuery =
+            query_from_json(&Cow::from(frame_audio_query))?;
+        let style_id = voicevox_core::StyleId::new(style_id as u32);
+        let range = frame_range(start, end)?;
+
+        let synthesizer = unsafe {
+            // SAFETY:
+            // - The safety contract must be upheld by the caller.
+            // - `jp.hiroshiba.voicevoxcore.nonblocking.Synthesizer.handle` must correspond to
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
+            env.get_rust_field::<_, _, RustField>(&this, "handle")
+        }?
+        .to_nonblocking();
+
+        crate::future::spawn(
+            env,
+            &future,
+            async move {
+                let pcm = synthesizer
+                    .frame_render(&frame_audio_query, style_id, range)
+                    .cancellable(cancellable != 0)
+                    .perform()
+                    .await?;
+                Ok(pcm)
+            },
+            |env, pcm| Ok(env.byte_array_from_slice(&pcm)?.into()),
+        )
+    })
+}
+
+// SAFETY: voicevox_core_java_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+#[unsafe(no_mangle)]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_nonblocking_Synthesizer_rsRenderSongProject<
+    'local,
+>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    future: JObject<'local>,
+    tracks: JString<'local>,
+    output_sampling_rate: jint,
+    cancellable: jboolean,
+) {
+    throw_if_err(env, (), |env| {
+        let tracks = &env.get_string(&tracks)?;
+        let tracks = song_tracks_from_json(&Cow::from(tracks))?;
+        let output_sampling_rate = voicevox_core::SamplingRate::new(output_sampling_rate as u32)?;
+
+        let synthesizer = unsafe {
+            // SAFETY:
+            // - The safety contract must be upheld by the caller.
+            // - `jp.hiroshiba.voicevoxcore.nonblocking.Synthesizer.handle` must correspond to
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
+            env.get_rust_field::<_, _, RustField>(&this, "handle")
+        }?
+        .to_nonblocking();
+
+        crate::future::spawn(
+            env,
+            &future,
+            async move {
+                let wav = synthesizer
+                    .render_song_project(&tracks)
+                    .output_sampling_rate(output_sampling_rate)
+                    .cancellable(cancellable != 0)
+                    .perform()
+                    .await?;
+                Ok(wav)
+            },
+            |env, wav| Ok(env.byte_array_from_slice(&wav)?.into()),
+        )
+    })
+}
+
+// SAFETY: voicevox_core_java_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+#[unsafe(no_mangle)]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_nonblocking_Synthesizer_rsDrop<'local>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+) {
+    throw_if_err(env, (), |env| {
+        unsafe {
+            // SAFETY:
+            // - The safety contract must be upheld by the caller.
+            // - `jp.hiroshiba.voicevoxcore.nonblocking.Synthesizer.handle` must correspond to
+            //   `Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>`.
+            type RustField = Arc<voicevox_core::blocking::Synthesizer<JavaTextAnalyzer>>;
+            env.take_rust_field::<_, _, RustField>(&this, "handle")
+        }?;
+        Ok(())
+    })
+}
+
+fn frame_range(start: jint, end: jint) -> JavaApiResult<Range<usize>> {
+    let start =
+        usize::try_from(start).map_err(|_| JavaApiError::IllegalArgument("start".to_owned()))?;
+    let end = usize::try_from(end)
+        .ok()
+        .filter(|&end| start <= end)
+        .ok_or_else(|| JavaApiError::IllegalArgument("end".to_owned()))?;
+    Ok(start..end)
+}
+
+fn song_tracks_from_json(json: &str) -> JavaApiResult<Vec<voicevox_core::SongTrack>> {
+    serde_json::from_str(json)
+        .map_err(|e| JavaApiError::DeQuery("不正なトラックの列です".to_owned(), e))
+}
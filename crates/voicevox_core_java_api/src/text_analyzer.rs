@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use anyhow::{anyhow, bail};
+use jni::{
+    JNIEnv, JavaVM,
+    objects::{GlobalRef, JObject, JString},
+};
+use voicevox_core::{
+    __internal::interop::Validate as _, AccentPhrase, blocking::TextAnalyzer as _,
+};
+
+use crate::{common::JavaApiResult, object, object_type};
+
+/// `jp.hiroshiba.voicevoxcore.blocking.OpenJtalk`、もしくはユーザー定義の
+/// `jp.hiroshiba.voicevoxcore.blocking.TextAnalyzer`。
+#[derive(Clone)]
+pub(crate) enum JavaTextAnalyzer {
+    OpenJtalk(voicevox_core::blocking::OpenJtalk),
+    Foreign {
+        vm: Arc<JavaVM>,
+        text_analyzer: GlobalRef,
+    },
+}
+
+impl JavaTextAnalyzer {
+    pub(crate) fn foreign(
+        env: &mut JNIEnv<'_>,
+        text_analyzer: &JObject<'_>,
+    ) -> JavaApiResult<Self> {
+        Ok(Self::Foreign {
+            vm: env.get_java_vm()?.into(),
+            text_analyzer: env.new_global_ref(text_analyzer)?,
+        })
+    }
+}
+
+impl voicevox_core::blocking::TextAnalyzer for JavaTextAnalyzer {
+    fn analyze(&self, text: &str) -> anyhow::Result<Vec<AccentPhrase>> {
+        match self {
+            Self::OpenJtalk(open_jtalk) => open_jtalk.analyze(text),
+            Self::Foreign { vm, text_analyzer } => {
+                let env = &mut vm.attach_current_thread()?;
+                let json = analyze_as_json(env, text_analyzer, text)?;
+                let accent_phrases = serde_json::from_str::<Vec<AccentPhrase>>(&json)?;
+                accent_phrases.validate()?;
+                Ok(accent_phrases)
+            }
+        }
+    }
+}
+
+fn analyze_as_json(
+    env: &mut JNIEnv<'_>,
+    text_analyzer: &GlobalRef,
+    text: &str,
+) -> anyhow::Result<String> {
+    let text = env.new_string(text)?;
+    let result = env.call_static_method(
+        object!("internal/Convert"),
+        "jsonFromTextAnalyzer",
+        concat!(
+            "(",
+            object_type!("blocking/TextAnalyzer"),
+            "Ljava/lang/String;)Ljava/lang/String;",
+        ),
+        &[text_analyzer.as_obj().into(), (&text).into()],
+    );
+
+    // Java側で投げられた例外は、メッセージを取り出した上でRust側のエラーとする
+    if env.exception_check()? {
+        let exc = env.exception_occurred()?;
+        env.exception_clear()?;
+        let msg = env
+            .call_method(&exc, "toString", "()Ljava/lang/String;", &[])?
+            .l()?;
+        let msg = String::from(env.get_string(&JString::from(msg))?);
+        return Err(anyhow!(msg).context("テキスト解析器が例外を投げました"));
+    }
+
+    let json = result?.l()?;
+    if json.is_null() {
+        bail!("テキスト解析器がnullを返しました");
+    }
+    Ok(env.get_string(&JString::from(json))?.into())
+}
@@ -3,7 +3,7 @@ use std::{borrow::Cow, sync::Arc};
 use crate::common::{Closable, HasJavaClassIdent, JNIEnvExt as _, throw_if_err};
 use jni::{
     JNIEnv,
-    objects::{JObject, JString},
+    objects::{JByteArray, JObject, JString},
     sys::jobject,
 };
 
@@ -37,6 +37,31 @@ unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_VoiceModelFile
     })
 }
 
+// SAFETY: voicevox_core_java_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
+#[unsafe(no_mangle)]
+unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_VoiceModelFile_rsFromBytes<
+    'local,
+>(
+    env: JNIEnv<'local>,
+    this: JObject<'local>,
+    data: JByteArray<'local>,
+) {
+    throw_if_err(env, (), |env| {
+        let data = env.convert_byte_array(&data)?;
+
+        let internal = voicevox_core::blocking::VoiceModelFile::from_bytes(data)?;
+        let internal = Arc::new(Closable::new(internal));
+
+        // SAFETY:
+        // - The safety contract must be upheld by the caller.
+        // - `jp.hiroshiba.voicevoxcore.blocking.VoiceModelFile.handle` must correspond to
+        //   `self::VoiceModelFile`.
+        unsafe { env.set_rust_field(&this, "handle", internal) }?;
+
+        Ok(())
+    })
+}
+
 // SAFETY: voicevox_core_java_apiを構成するライブラリの中に、これと同名のシンボルは存在しない
 #[unsafe(no_mangle)]
 unsafe extern "system" fn Java_jp_hiroshiba_voicevoxcore_blocking_VoiceModelFile_rsGetId<'local>(
@@ -10,6 +10,7 @@ mod inference_domains;
 mod mora_mappings;
 mod numerics;
 mod python_api;
+mod song_public_api;
 
 use syn::parse_macro_input;
 
@@ -148,6 +149,21 @@ pub fn pyproject_project_version(input: proc_macro::TokenStream) -> proc_macro::
     from_syn(python_api::pyproject_project_version(input))
 }
 
+/// `$CARGO_MANIFEST_DIR/src`以下から歌唱音声合成に関する公開APIを集め、文字列の配列として列挙する。
+///
+/// 対象は次の通り。`#[doc(hidden)]`なもの、`#[cfg(test)]`なモジュール、`__internal`は除く。
+///
+/// - `src/engine/song`以下の`pub`な構造体と列挙型。`"型"`の形になる。
+/// - `src/engine/song`以下の`pub`な関数およびメソッド。
+/// - それ以外の場所の`pub`なメソッドのうち、引数か返り値に上記の型が現れるもの。
+///
+/// 関数とメソッドは`"[モジュール::][型::]関数(引数の型, …)"`の形になる。引数の型は、参照・ライフタイム・パスの修飾を取り除いたものになる。
+#[proc_macro]
+pub fn song_public_apis(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input);
+    from_syn(song_public_api::song_public_apis(input))
+}
+
 fn from_syn(result: syn::Result<proc_macro2::TokenStream>) -> proc_macro::TokenStream {
     result.unwrap_or_else(|e| e.to_compile_error()).into()
 }
@@ -0,0 +1,284 @@
+use std::{
+    collections::BTreeSet,
+    env,
+    path::{Path, PathBuf},
+};
+
+use derive_syn_parse::Parse;
+use quote::{ToTokens as _, quote};
+use syn::{
+    FnArg, ImplItem, Item, ItemFn, LitStr, ReturnType, Signature, Type, Visibility,
+    visit::{self, Visit},
+    visit_mut::{self, VisitMut},
+};
+
+/// 歌唱音声合成のモジュール。`$CARGO_MANIFEST_DIR/src`からの相対パス。
+const SONG_MODULE_DIR: &str = "engine/song";
+
+pub(crate) fn song_public_apis(_: Input) -> syn::Result<proc_macro2::TokenStream> {
+    let span = proc_macro2::Span::call_site();
+    let error = |e| syn::Error::new(span, e);
+
+    let src_dir = &env::var("CARGO_MANIFEST_DIR")
+        .map_err(|e| error(format!("could not get `$CARGO_MANIFEST_DIR`: {e}")))?;
+    let src_dir = Path::new(src_dir).join("src");
+
+    let mut files = vec![];
+    collect_files(&src_dir, &mut files).map_err(error)?;
+    let (song_files, other_files) = files
+        .into_iter()
+        .partition::<Vec<_>, _>(|(path, _)| path.starts_with(src_dir.join(SONG_MODULE_DIR)));
+
+    let mut public_types = BTreeSet::new();
+    let mut song_types = BTreeSet::new();
+    for (_, file) in &song_files {
+        visit_types(&file.items, &mut song_types);
+    }
+    for (_, file) in &other_files {
+        visit_types(&file.items, &mut public_types);
+    }
+    public_types.extend(song_types.iter().cloned());
+
+    let mut apis = vec![];
+    for (_, file) in &song_files {
+        visit_items(&file.items, &[], &public_types, &|_| true, &mut apis);
+    }
+    for (_, file) in &other_files {
+        visit_items(
+            &file.items,
+            &[],
+            &public_types,
+            &|sig| mentions_any(sig, &song_types),
+            &mut apis,
+        );
+    }
+    apis.extend(song_types);
+    apis.sort();
+
+    let apis = apis.iter().map(|api| LitStr::new(api, span));
+    Ok(quote!([#(#apis),*]))
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<(PathBuf, syn::File)>) -> Result<(), String> {
+    let mut entries = fs_err::read_dir(dir)
+        .and_then(|entries| entries.collect::<Result<Vec<_>, _>>())
+        .map_err(|e| e.to_string())?;
+    entries.sort_by_key(|entry| entry.path());
+    for entry in entries {
+        let path = entry.path();
+        // `#[doc(hidden)]`な`voicevox_core::__internal`は公開APIではない
+        if path.file_stem().is_some_and(|stem| stem == "__internal") {
+            continue;
+        }
+        if path.is_dir() {
+            collect_files(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            let file = fs_err::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|s| {
+                    syn::parse_file(&s).map_err(|e| format!("{}: {e}", path.display()))
+                })?;
+            files.push((path, file));
+        }
+    }
+    Ok(())
+}
+
+/// `pub`な構造体と列挙型の名前を集める。
+fn visit_types(items: &[Item], types: &mut BTreeSet<String>) {
+    for item in items {
+        match item {
+            Item::Struct(item) if is_public(&item.vis, &item.attrs) => {
+                types.insert(item.ident.to_string());
+            }
+            Item::Enum(item) if is_public(&item.vis, &item.attrs) => {
+                types.insert(item.ident.to_string());
+            }
+            Item::Mod(item_mod) if !is_cfg_test(&item_mod.attrs) => {
+                if let Some((_, items)) = &item_mod.content {
+                    visit_types(items, types);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// `pub`な関数と、`public_types`に対する`pub`なメソッドのうち、`filter`を満たすものを
+/// `[インラインのモジュール::…][型::]関数(引数の型, …)`の形で列挙する。
+///
+/// ファイル単位のモジュール、およびプライベートなインラインのモジュールは再エクスポートされているものとみなし、
+/// パスに含めない。トレイトの実装は対象としない。
+fn visit_items(
+    items: &[Item],
+    mods: &[String],
+    public_types: &BTreeSet<String>,
+    filter: &dyn Fn(&Signature) -> bool,
+    apis: &mut Vec<String>,
+) {
+    for item in items {
+        match item {
+            Item::Fn(ItemFn {
+                attrs, vis, sig, ..
+            }) if is_public(vis, attrs) && filter(sig) => {
+                apis.push(api(mods, None, sig));
+            }
+            Item::Impl(item_impl) if item_impl.trait_.is_none() => {
+                let Type::Path(self_ty) = &*item_impl.self_ty else {
+                    continue;
+                };
+                let self_ty = self_ty
+                    .path
+                    .segments
+                    .last()
+                    .expect("should not be empty")
+                    .ident
+                    .to_string();
+                if !public_types.contains(&self_ty) {
+                    continue;
+                }
+                for impl_item in &item_impl.items {
+                    if let ImplItem::Fn(impl_item_fn) = impl_item
+                        && is_public(&impl_item_fn.vis, &impl_item_fn.attrs)
+                        && filter(&impl_item_fn.sig)
+                    {
+                        apis.push(api(mods, Some(&self_ty), &impl_item_fn.sig));
+                    }
+                }
+            }
+            Item::Mod(item_mod) if !is_cfg_test(&item_mod.attrs) => {
+                if let Some((_, items)) = &item_mod.content {
+                    let mods = &match item_mod.vis {
+                        Visibility::Inherited => mods.to_vec(),
+                        _ => [mods.to_vec(), vec![item_mod.ident.to_string()]].concat(),
+                    };
+                    visit_items(items, mods, public_types, filter, apis);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn is_public(vis: &Visibility, attrs: &[syn::Attribute]) -> bool {
+    matches!(vis, Visibility::Public(_)) && !is_doc_hidden(attrs)
+}
+
+fn is_doc_hidden(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("doc")
+            && attr
+                .parse_args::<syn::Ident>()
+                .is_ok_and(|ident| ident == "hidden")
+    })
+}
+
+fn is_cfg_test(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("cfg")
+            && attr
+                .parse_args::<syn::Ident>()
+                .is_ok_and(|ident| ident == "test")
+    })
+}
+
+/// 引数か返り値の型に、`types`のいずれかが現れるかどうか。
+fn mentions_any(sig: &Signature, types: &BTreeSet<String>) -> bool {
+    let mut visitor = MentionsAny {
+        types,
+        found: false,
+    };
+    for input in &sig.inputs {
+        if let FnArg::Typed(pat_type) = input {
+            visitor.visit_type(&pat_type.ty);
+        }
+    }
+    if let ReturnType::Type(_, ty) = &sig.output {
+        visitor.visit_type(ty);
+    }
+    return visitor.found;
+
+    struct MentionsAny<'a> {
+        types: &'a BTreeSet<String>,
+        found: bool,
+    }
+
+    impl Visit<'_> for MentionsAny<'_> {
+        fn visit_path_segment(&mut self, segment: &syn::PathSegment) {
+            if self.types.contains(&segment.ident.to_string()) {
+                self.found = true;
+            }
+            visit::visit_path_segment(self, segment);
+        }
+    }
+}
+
+fn api(mods: &[String], self_ty: Option<&str>, sig: &Signature) -> String {
+    let path = mods
+        .iter()
+        .map(String::as_str)
+        .chain(self_ty)
+        .chain([&*sig.ident.to_string()])
+        .collect::<Vec<_>>()
+        .join("::");
+
+    let params = sig
+        .inputs
+        .iter()
+        .filter_map(|input| match input {
+            FnArg::Receiver(_) => None,
+            FnArg::Typed(pat_type) => Some(normalize_type(&pat_type.ty)),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!("{path}({params})")
+}
+
+/// 言語間で比較できるように、参照とライフタイム、およびパスの修飾を取り除く。
+fn normalize_type(ty: &Type) -> String {
+    let mut ty = ty.clone();
+    Normalize.visit_type_mut(&mut ty);
+    return ty
+        .to_token_stream()
+        .to_string()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .replace(',', ", ");
+
+    struct Normalize;
+
+    impl VisitMut for Normalize {
+        fn visit_type_mut(&mut self, ty: &mut Type) {
+            if let Type::Reference(reference) = ty {
+                *ty = (*reference.elem).clone();
+            }
+            visit_mut::visit_type_mut(self, ty);
+        }
+
+        fn visit_path_mut(&mut self, path: &mut syn::Path) {
+            path.leading_colon = None;
+            if let Some(last) = path.segments.pop() {
+                path.segments = [last.into_value()].into_iter().collect();
+            }
+            visit_mut::visit_path_mut(self, path);
+        }
+
+        fn visit_angle_bracketed_generic_arguments_mut(
+            &mut self,
+            args: &mut syn::AngleBracketedGenericArguments,
+        ) {
+            args.args = args
+                .args
+                .iter()
+                .filter(|arg| !matches!(arg, syn::GenericArgument::Lifetime(_)))
+                .cloned()
+                .collect();
+            visit_mut::visit_angle_bracketed_generic_arguments_mut(self, args);
+        }
+    }
+}
+
+#[derive(Parse)]
+pub(crate) struct Input {}
@@ -0,0 +1,148 @@
+use std::{error::Error as _, fmt, iter};
+
+use easy_ext::ext;
+use napi::Status;
+use serde::{Serialize, de::DeserializeOwned};
+use uuid::Uuid;
+use voicevox_core::{
+    __internal::interop, StyleId, UserDictWordPriority, UserDictWordType, VoiceModelId,
+};
+
+use crate::objects::{AccelerationMode, OnExistingVoiceModelId, OperationDevice, UserDictWord};
+
+/// `voicevox_core::Error`をJavaScriptのエラーに変換する。
+///
+/// エラーメッセージは`"{ErrorKind}Error: {メッセージ}"`の形になり、`source`の連鎖は`": "`で繋げる。
+/// [Python API]の例外クラス名と揃えてある。
+///
+/// [Python API]: https://voicevox.github.io/voicevox_core/apis/python_api/
+#[ext(VoicevoxCoreResultExt)]
+pub(crate) impl<T> voicevox_core::Result<T> {
+    fn into_napi_result(self) -> napi::Result<T> {
+        self.map_err(|err| {
+            let msg = iter::successors(Some(&err as &dyn std::error::Error), |e| e.source())
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(": ");
+            napi::Error::new(
+                Status::GenericFailure,
+                format!("{kind:?}Error: {msg}", kind = err.kind()),
+            )
+        })
+    }
+}
+
+pub(crate) fn invalid_arg(what: &str, err: impl fmt::Display) -> napi::Error {
+    napi::Error::new(Status::InvalidArg, format!("不正な{what}です: {err}"))
+}
+
+/// JavaScriptのオブジェクトから`voicevox_core`の型に、JSON表現を経由して変換する。
+pub(crate) fn to_rust<T: DeserializeOwned>(obj: impl Serialize, what: &str) -> napi::Result<T> {
+    serde_json::to_value(obj)
+        .and_then(serde_json::from_value)
+        .map_err(|err| {
+            napi::Error::new(
+                Status::InvalidArg,
+                format!("InvalidQueryError: 不正な{what}です: {err}"),
+            )
+        })
+}
+
+/// `voicevox_core`の型からJavaScriptのオブジェクトに、JSON表現を経由して変換する。
+pub(crate) fn to_js<T: DeserializeOwned>(value: &impl Serialize) -> T {
+    serde_json::to_value(value)
+        .and_then(serde_json::from_value)
+        .expect("`objects`の型は`voicevox_core`のJSON表現と互換であるべき")
+}
+
+pub(crate) fn to_rust_uuid(s: &str) -> napi::Result<Uuid> {
+    Uuid::parse_str(s).map_err(|err| invalid_arg("UUID", err))
+}
+
+pub(crate) fn to_rust_voice_model_id(s: &str) -> napi::Result<VoiceModelId> {
+    to_rust_uuid(s).map(VoiceModelId)
+}
+
+pub(crate) fn to_rust_style_id(style_id: u32) -> StyleId {
+    StyleId(style_id)
+}
+
+pub(crate) fn to_rust_user_dict_word(
+    word: UserDictWord,
+) -> napi::Result<voicevox_core::UserDictWord> {
+    let UserDictWord {
+        surface,
+        pronunciation,
+        accent_type,
+        word_type,
+        priority,
+    } = word;
+
+    let mut builder = voicevox_core::UserDictWord::builder();
+    if let Some(word_type) = word_type {
+        builder = builder.word_type(
+            serde_json::from_value::<UserDictWordType>(word_type.into())
+                .map_err(|err| invalid_arg("単語の種類", err))?,
+        );
+    }
+    if let Some(priority) = priority {
+        let priority = u8::try_from(priority)
+            .ok()
+            .and_then(|priority| UserDictWordPriority::new(priority).ok())
+            .ok_or(interop::InvalidWordError::PriorityOutOfBounds {
+                is_validation_of_whole_word: true,
+                actual: priority.into(),
+            })
+            .map_err(voicevox_core::Error::from)
+            .into_napi_result()?;
+        builder = builder.priority(priority);
+    }
+    builder
+        .build(&surface, pronunciation, accent_type as _)
+        .into_napi_result()
+}
+
+pub(crate) fn to_js_user_dict_word(word: &voicevox_core::UserDictWord) -> UserDictWord {
+    UserDictWord {
+        surface: word.surface().to_owned(),
+        pronunciation: word.pronunciation().to_owned(),
+        accent_type: word.accent_type() as _,
+        word_type: Some(
+            serde_json::to_value(word.word_type())
+                .expect("should success")
+                .as_str()
+                .expect("should be a string")
+                .to_owned(),
+        ),
+        priority: Some(word.priority().get().into()),
+    }
+}
+
+impl From<AccelerationMode> for voicevox_core::AccelerationMode {
+    fn from(mode: AccelerationMode) -> Self {
+        match mode {
+            AccelerationMode::Auto => Self::Auto,
+            AccelerationMode::Cpu => Self::Cpu,
+            AccelerationMode::Gpu => Self::Gpu,
+        }
+    }
+}
+
+impl From<OperationDevice> for voicevox_core::OperationDevice {
+    fn from(device: OperationDevice) -> Self {
+        match device {
+            OperationDevice::Cpu => Self::Cpu,
+            OperationDevice::Accelerator => Self::Accelerator,
+        }
+    }
+}
+
+impl From<OnExistingVoiceModelId> for voicevox_core::OnExistingVoiceModelId {
+    fn from(on_existing: OnExistingVoiceModelId) -> Self {
+        match on_existing {
+            OnExistingVoiceModelId::Error => Self::Error,
+            OnExistingVoiceModelId::Reload => Self::Reload,
+            OnExistingVoiceModelId::Skip => Self::Skip,
+        }
+    }
+}
@@ -0,0 +1,683 @@
+//! VOICEVOX COREのNode.jsバインディング。
+//!
+//! [Python API]の`voicevox_core.asyncio`と同等のAPIを、[napi-rs]を用いて提供する。非同期のメソッドは
+//! すべて`Promise`を返し、内部では`voicevox_core::nonblocking`が用いられる。
+//!
+//! TypeScriptの型定義（`index.d.ts`）は`napi build`によってRustの型から生成される。
+//!
+//! ```js
+//! const { Onnxruntime, OpenJtalk, Synthesizer, VoiceModelFile } = require("voicevox_core");
+//!
+//! const onnxruntime = await Onnxruntime.loadOnce({ filename: "…" });
+//! const openJtalk = await OpenJtalk.create("./open_jtalk_dic_utf_8-1.11");
+//! const synthesizer = new Synthesizer(onnxruntime, openJtalk);
+//!
+//! const model = await VoiceModelFile.open("./sample.vvm");
+//! await synthesizer.loadVoiceModel(model);
+//! await model.close();
+//!
+//! const wav = await synthesizer.tts("こんにちは", 0);
+//! ```
+//!
+//! # エラー
+//!
+//! `voicevox_core::Error`は、メッセージが`"{ErrorKind}Error: "`で始まる`Error`として投げられる
+//! （例: `"ModelAlreadyLoadedError: …"`）。
+//!
+//! [Python API]: https://voicevox.github.io/voicevox_core/apis/python_api/
+//! [napi-rs]: https://napi.rs
+
+mod convert;
+mod objects;
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use napi::{
+    Env, Status,
+    bindgen_prelude::{Buffer, PromiseRaw},
+};
+use napi_derive::napi;
+use voicevox_core::{__internal::interop::NonblockingTextAnalyzerExt as _, nonblocking};
+
+use crate::{
+    convert::{
+        VoicevoxCoreResultExt as _, invalid_arg, to_js, to_js_user_dict_word, to_rust,
+        to_rust_style_id, to_rust_user_dict_word, to_rust_uuid, to_rust_voice_model_id,
+    },
+    objects::{
+        AccentPhrase, AudioQuery, CharacterMeta, FrameAudioQuery, FrameSynthesisOptions,
+        LoadOnceOptions, LoadVoiceModelOptions, OperationPlacement, Score, SupportedDevices,
+        SynthesisOptions, SynthesizerOptions, UserDictWord,
+    },
+};
+
+/// ONNX Runtime。
+///
+/// シングルトンであり、インスタンスは`Onnxruntime.get`もしくは`Onnxruntime.loadOnce`で得る。
+#[napi]
+pub struct Onnxruntime(&'static nonblocking::Onnxruntime);
+
+#[napi]
+impl Onnxruntime {
+    /// {@link Onnxruntime.loadOnce}で用いられるデフォルトのファイル名。
+    #[napi]
+    pub fn lib_recommended_versioned_filename() -> String {
+        nonblocking::Onnxruntime::LIB_RECOMMENDED_VERSIONED_FILENAME.to_owned()
+    }
+
+    /// `LIB_RECOMMENDED_VERSIONED_FILENAME`からバージョンを除いたもの。
+    #[napi]
+    pub fn lib_recommended_unversioned_filename() -> String {
+        nonblocking::Onnxruntime::LIB_RECOMMENDED_UNVERSIONED_FILENAME.to_owned()
+    }
+
+    /// インスタンスが既に作られているならそれを得る。
+    ///
+    /// 作られていなければ`undefined`を返す。
+    #[napi]
+    pub fn get() -> Option<Onnxruntime> {
+        nonblocking::Onnxruntime::get().map(Self)
+    }
+
+    /// ONNX Runtimeをロードして初期化する。
+    ///
+    /// 一度成功したら、以後は引数を無視して同じインスタンスを返す。
+    #[napi]
+    pub async fn load_once(options: Option<LoadOnceOptions>) -> napi::Result<Onnxruntime> {
+        let mut load_once = nonblocking::Onnxruntime::load_once();
+        if let Some(LoadOnceOptions {
+            filename: Some(filename),
+        }) = options
+        {
+            load_once = load_once.filename(filename);
+        }
+        load_once.perform().await.map(Self).into_napi_result()
+    }
+
+    /// ONNX Runtimeとして利用可能なデバイスの情報を取得する。
+    #[napi]
+    pub fn supported_devices(&self) -> napi::Result<SupportedDevices> {
+        let supported_devices = self.0.supported_devices().into_napi_result()?;
+        Ok(to_js(&supported_devices))
+    }
+}
+
+/// テキスト解析器としてのOpen JTalk。
+#[napi]
+pub struct OpenJtalk(nonblocking::OpenJtalk);
+
+#[napi]
+impl OpenJtalk {
+    /// Open JTalkの辞書ディレクトリを読み込み、`OpenJtalk`を作る。
+    #[napi]
+    pub async fn create(open_jtalk_dict_dir: String) -> napi::Result<OpenJtalk> {
+        nonblocking::OpenJtalk::new(open_jtalk_dict_dir)
+            .await
+            .map(Self)
+            .into_napi_result()
+    }
+
+    /// ユーザー辞書を設定する。
+    ///
+    /// この関数を呼び出した後にユーザー辞書を変更した場合は、再度この関数を呼ぶ必要がある。
+    #[napi(ts_return_type = "Promise<void>")]
+    pub fn use_user_dict<'env>(
+        &self,
+        env: &'env Env,
+        user_dict: &UserDict,
+    ) -> napi::Result<PromiseRaw<'env, ()>> {
+        let open_jtalk = self.0.clone();
+        let user_dict = user_dict.0.clone();
+        env.spawn_future(async move {
+            open_jtalk
+                .use_user_dict(&user_dict)
+                .await
+                .into_napi_result()
+        })
+    }
+
+    /// 日本語のテキストを解析する。
+    #[napi]
+    pub async fn analyze(&self, text: String) -> napi::Result<Vec<AccentPhrase>> {
+        let accent_phrases = self.0.analyze_(&text).await.into_napi_result()?;
+        Ok(to_js(&accent_phrases))
+    }
+}
+
+/// 音声モデルファイル。
+#[napi]
+pub struct VoiceModelFile {
+    model: Mutex<Option<Arc<nonblocking::VoiceModelFile>>>,
+    id: voicevox_core::VoiceModelId,
+    metas: voicevox_core::VoiceModelMeta,
+}
+
+#[napi]
+impl VoiceModelFile {
+    /// VVMファイルを開く。
+    #[napi]
+    pub async fn open(path: String) -> napi::Result<VoiceModelFile> {
+        let model = nonblocking::VoiceModelFile::open(path)
+            .await
+            .into_napi_result()?;
+        Ok(Self {
+            id: model.id(),
+            metas: model.metas().clone(),
+            model: Mutex::new(Some(Arc::new(model))),
+        })
+    }
+
+    /// VVMファイルを閉じる。
+    ///
+    /// 既に閉じられている場合は何もしない。
+    #[napi]
+    pub async fn close(&self) {
+        let model = self.model.lock().unwrap_or_else(|e| e.into_inner()).take();
+        if let Some(model) = model.and_then(Arc::into_inner) {
+            model.close().await;
+        }
+    }
+
+    /// ID。
+    #[napi(getter)]
+    pub fn id(&self) -> String {
+        self.id.to_string()
+    }
+
+    /// メタ情報。
+    ///
+    /// これから読み込もうとしているVVMファイルのメタ情報であり、音声合成に用いられるとは限らない。
+    #[napi(getter)]
+    pub fn metas(&self) -> Vec<CharacterMeta> {
+        to_js(&self.metas)
+    }
+
+    fn get(&self) -> napi::Result<Arc<nonblocking::VoiceModelFile>> {
+        self.model
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+            .ok_or_else(|| already_closed("VoiceModelFile"))
+    }
+}
+
+/// 音声シンセサイザ。
+#[napi]
+pub struct Synthesizer {
+    synthesizer: Mutex<Option<Arc<nonblocking::Synthesizer<nonblocking::OpenJtalk>>>>,
+}
+
+#[napi]
+impl Synthesizer {
+    #[napi(constructor)]
+    pub fn new(
+        onnxruntime: &Onnxruntime,
+        open_jtalk: &OpenJtalk,
+        options: Option<SynthesizerOptions>,
+    ) -> napi::Result<Self> {
+        let mut builder =
+            nonblocking::Synthesizer::builder(onnxruntime.0).text_analyzer(open_jtalk.0.clone());
+        if let Some(SynthesizerOptions {
+            acceleration_mode,
+            cpu_num_threads,
+            operation_placements,
+        }) = options
+        {
+            if let Some(acceleration_mode) = acceleration_mode {
+                builder = builder.acceleration_mode(acceleration_mode.into());
+            }
+            if let Some(cpu_num_threads) = cpu_num_threads {
+                builder = builder.cpu_num_threads(cpu_num_threads);
+            }
+            for (
+                operation,
+                OperationPlacement {
+                    device,
+                    cpu_num_threads,
+                },
+            ) in operation_placements.into_iter().flatten()
+            {
+                let operation = operation
+                    .parse()
+                    .map_err(|err| invalid_arg("推論の操作", err))?;
+                builder = builder.operation_placement(
+                    operation,
+                    voicevox_core::OperationPlacement {
+                        device: device.into(),
+                        cpu_num_threads,
+                    },
+                );
+            }
+        }
+        let synthesizer = builder.build().into_napi_result()?;
+        Ok(Self {
+            synthesizer: Mutex::new(Some(Arc::new(synthesizer))),
+        })
+    }
+
+    /// `Synthesizer`を破棄する。
+    #[napi]
+    pub fn close(&self) {
+        drop(
+            self.synthesizer
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .take(),
+        );
+    }
+
+    /// ONNX Runtime。
+    #[napi(getter)]
+    pub fn onnxruntime(&self) -> napi::Result<Onnxruntime> {
+        Ok(Onnxruntime(self.get()?.onnxruntime()))
+    }
+
+    /// Open JTalk。
+    #[napi(getter)]
+    pub fn open_jtalk(&self) -> napi::Result<OpenJtalk> {
+        Ok(OpenJtalk(self.get()?.text_analyzer().clone()))
+    }
+
+    /// ハードウェアアクセラレーションがGPUモードか判定する。
+    #[napi(getter)]
+    pub fn is_gpu_mode(&self) -> napi::Result<bool> {
+        Ok(self.get()?.is_gpu_mode())
+    }
+
+    /// メタ情報。
+    #[napi(getter)]
+    pub fn metas(&self) -> napi::Result<Vec<CharacterMeta>> {
+        Ok(to_js(&self.get()?.metas()))
+    }
+
+    /// 音声モデルを読み込む。
+    #[napi(ts_return_type = "Promise<void>")]
+    pub fn load_voice_model<'env>(
+        &self,
+        env: &'env Env,
+        model: &VoiceModelFile,
+        options: Option<LoadVoiceModelOptions>,
+    ) -> napi::Result<PromiseRaw<'env, ()>> {
+        let synthesizer = self.get()?;
+        let model = model.get()?;
+        let on_existing = options.and_then(|LoadVoiceModelOptions { on_existing }| on_existing);
+        env.spawn_future(async move {
+            let mut load_voice_model = synthesizer.load_voice_model(&model);
+            if let Some(on_existing) = on_existing {
+                load_voice_model = load_voice_model.on_existing(on_existing.into());
+            }
+            load_voice_model.perform().await.into_napi_result()
+        })
+    }
+
+    /// 音声モデルの読み込みを解除する。
+    #[napi]
+    pub fn unload_voice_model(&self, voice_model_id: String) -> napi::Result<()> {
+        let voice_model_id = to_rust_voice_model_id(&voice_model_id)?;
+        self.get()?
+            .unload_voice_model(voice_model_id)
+            .into_napi_result()
+    }
+
+    /// 指定したIDの音声モデルが読み込まれているか判定する。
+    #[napi]
+    pub fn is_loaded_voice_model(&self, voice_model_id: String) -> napi::Result<bool> {
+        let voice_model_id = to_rust_voice_model_id(&voice_model_id)?;
+        Ok(self.get()?.is_loaded_voice_model(voice_model_id))
+    }
+
+    /// AquesTalk風記法から`AudioQuery`を生成する。
+    #[napi]
+    pub async fn create_audio_query_from_kana(
+        &self,
+        kana: String,
+        style_id: u32,
+    ) -> napi::Result<AudioQuery> {
+        let audio_query = self
+            .get()?
+            .create_audio_query_from_kana(&kana, to_rust_style_id(style_id))
+            .await
+            .into_napi_result()?;
+        Ok(to_js(&audio_query))
+    }
+
+    /// 日本語のテキストから`AudioQuery`を生成する。
+    #[napi]
+    pub async fn create_audio_query(
+        &self,
+        text: String,
+        style_id: u32,
+    ) -> napi::Result<AudioQuery> {
+        let audio_query = self
+            .get()?
+            .create_audio_query(&text, to_rust_style_id(style_id))
+            .await
+            .into_napi_result()?;
+        Ok(to_js(&audio_query))
+    }
+
+    /// AquesTalk風記法からアクセント句の配列を生成する。
+    #[napi]
+    pub async fn create_accent_phrases_from_kana(
+        &self,
+        kana: String,
+        style_id: u32,
+    ) -> napi::Result<Vec<AccentPhrase>> {
+        let accent_phrases = self
+            .get()?
+            .create_accent_phrases_from_kana(&kana, to_rust_style_id(style_id))
+            .await
+            .into_napi_result()?;
+        Ok(to_js(&accent_phrases))
+    }
+
+    /// 日本語のテキストからアクセント句の配列を生成する。
+    #[napi]
+    pub async fn create_accent_phrases(
+        &self,
+        text: String,
+        style_id: u32,
+    ) -> napi::Result<Vec<AccentPhrase>> {
+        let accent_phrases = self
+            .get()?
+            .create_accent_phrases(&text, to_rust_style_id(style_id))
+            .await
+            .into_napi_result()?;
+        Ok(to_js(&accent_phrases))
+    }
+
+    /// アクセント句の音高・音素長を変更した新しいアクセント句の配列を生成する。
+    #[napi]
+    pub async fn replace_mora_data(
+        &self,
+        accent_phrases: Vec<AccentPhrase>,
+        style_id: u32,
+    ) -> napi::Result<Vec<AccentPhrase>> {
+        let accent_phrases = to_rust::<Vec<_>>(accent_phrases, "アクセント句")?;
+        let accent_phrases = self
+            .get()?
+            .replace_mora_data(&accent_phrases, to_rust_style_id(style_id))
+            .await
+            .into_napi_result()?;
+        Ok(to_js(&accent_phrases))
+    }
+
+    /// アクセント句の音素長を変更した新しいアクセント句の配列を生成する。
+    #[napi]
+    pub async fn replace_phoneme_length(
+        &self,
+        accent_phrases: Vec<AccentPhrase>,
+        style_id: u32,
+    ) -> napi::Result<Vec<AccentPhrase>> {
+        let accent_phrases = to_rust::<Vec<_>>(accent_phrases, "アクセント句")?;
+        let accent_phrases = self
+            .get()?
+            .replace_phoneme_length(&accent_phrases, to_rust_style_id(style_id))
+            .await
+            .into_napi_result()?;
+        Ok(to_js(&accent_phrases))
+    }
+
+    /// アクセント句の音高を変更した新しいアクセント句の配列を生成する。
+    #[napi]
+    pub async fn replace_mora_pitch(
+        &self,
+        accent_phrases: Vec<AccentPhrase>,
+        style_id: u32,
+    ) -> napi::Result<Vec<AccentPhrase>> {
+        let accent_phrases = to_rust::<Vec<_>>(accent_phrases, "アクセント句")?;
+        let accent_phrases = self
+            .get()?
+            .replace_mora_pitch(&accent_phrases, to_rust_style_id(style_id))
+            .await
+            .into_napi_result()?;
+        Ok(to_js(&accent_phrases))
+    }
+
+    /// `AudioQuery`から音声合成を行い、WAVデータを返す。
+    #[napi]
+    pub async fn synthesis(
+        &self,
+        audio_query: AudioQuery,
+        style_id: u32,
+        options: Option<SynthesisOptions>,
+    ) -> napi::Result<Buffer> {
+        let audio_query = to_rust(audio_query, "AudioQuery")?;
+        let synthesizer = self.get()?;
+        let mut synthesis = synthesizer.synthesis(&audio_query, to_rust_style_id(style_id));
+        if let Some(SynthesisOptions {
+            enable_interrogative_upspeak,
+            cancellable,
+        }) = options
+        {
+            if let Some(enable_interrogative_upspeak) = enable_interrogative_upspeak {
+                synthesis = synthesis.enable_interrogative_upspeak(enable_interrogative_upspeak);
+            }
+            if let Some(cancellable) = cancellable {
+                synthesis = synthesis.cancellable(cancellable);
+            }
+        }
+        let wav = synthesis.perform().await.into_napi_result()?;
+        Ok(wav.into())
+    }
+
+    /// AquesTalk風記法から音声合成を行い、WAVデータを返す。
+    #[napi]
+    pub async fn tts_from_kana(
+        &self,
+        kana: String,
+        style_id: u32,
+        options: Option<SynthesisOptions>,
+    ) -> napi::Result<Buffer> {
+        let synthesizer = self.get()?;
+        let mut tts = synthesizer.tts_from_kana(&kana, to_rust_style_id(style_id));
+        if let Some(SynthesisOptions {
+            enable_interrogative_upspeak,
+            cancellable,
+        }) = options
+        {
+            if let Some(enable_interrogative_upspeak) = enable_interrogative_upspeak {
+                tts = tts.enable_interrogative_upspeak(enable_interrogative_upspeak);
+            }
+            if let Some(cancellable) = cancellable {
+                tts = tts.cancellable(cancellable);
+            }
+        }
+        let wav = tts.perform().await.into_napi_result()?;
+        Ok(wav.into())
+    }
+
+    /// 日本語のテキストから音声合成を行い、WAVデータを返す。
+    #[napi]
+    pub async fn tts(
+        &self,
+        text: String,
+        style_id: u32,
+        options: Option<SynthesisOptions>,
+    ) -> napi::Result<Buffer> {
+        let synthesizer = self.get()?;
+        let mut tts = synthesizer.tts(&text, to_rust_style_id(style_id));
+        if let Some(SynthesisOptions {
+            enable_interrogative_upspeak,
+            cancellable,
+        }) = options
+        {
+            if let Some(enable_interrogative_upspeak) = enable_interrogative_upspeak {
+                tts = tts.enable_interrogative_upspeak(enable_interrogative_upspeak);
+            }
+            if let Some(cancellable) = cancellable {
+                tts = tts.cancellable(cancellable);
+            }
+        }
+        let wav = tts.perform().await.into_napi_result()?;
+        Ok(wav.into())
+    }
+
+    /// 楽譜から歌唱音声合成用のクエリを作成する。
+    #[napi]
+    pub async fn create_sing_frame_audio_query(
+        &self,
+        score: Score,
+        style_id: u32,
+    ) -> napi::Result<FrameAudioQuery> {
+        let score = to_rust(score, "楽譜")?;
+        let frame_audio_query = self
+            .get()?
+            .create_sing_frame_audio_query(&score, to_rust_style_id(style_id))
+            .await
+            .into_napi_result()?;
+        Ok(to_js(&frame_audio_query))
+    }
+
+    /// 楽譜と歌唱音声合成用のクエリから、フレームごとの基本周波数を生成する。
+    #[napi]
+    pub async fn create_sing_frame_f0(
+        &self,
+        score: Score,
+        frame_audio_query: FrameAudioQuery,
+        style_id: u32,
+    ) -> napi::Result<Vec<f64>> {
+        let score = to_rust(score, "楽譜")?;
+        let frame_audio_query = to_rust(frame_audio_query, "FrameAudioQuery")?;
+        let f0 = self
+            .get()?
+            .create_sing_frame_f0(&score, &frame_audio_query, to_rust_style_id(style_id))
+            .await
+            .into_napi_result()?;
+        Ok(f0.into_iter().map(|f0| f32::from(f0).into()).collect())
+    }
+
+    /// 楽譜と歌唱音声合成用のクエリから、フレームごとの音量を生成する。
+    #[napi]
+    pub async fn create_sing_frame_volume(
+        &self,
+        score: Score,
+        frame_audio_query: FrameAudioQuery,
+        style_id: u32,
+    ) -> napi::Result<Vec<f64>> {
+        let score = to_rust(score, "楽譜")?;
+        let frame_audio_query = to_rust(frame_audio_query, "FrameAudioQuery")?;
+        let volume = self
+            .get()?
+            .create_sing_frame_volume(&score, &frame_audio_query, to_rust_style_id(style_id))
+            .await
+            .into_napi_result()?;
+        Ok(volume.into_iter().map(|v| f32::from(v).into()).collect())
+    }
+
+    /// 歌唱音声合成を行う。
+    #[napi]
+    pub async fn frame_synthesis(
+        &self,
+        frame_audio_query: FrameAudioQuery,
+        style_id: u32,
+        options: Option<FrameSynthesisOptions>,
+    ) -> napi::Result<Buffer> {
+        let frame_audio_query = to_rust(frame_audio_query, "FrameAudioQuery")?;
+        let synthesizer = self.get()?;
+        let mut frame_synthesis =
+            synthesizer.frame_synthesis(&frame_audio_query, to_rust_style_id(style_id));
+        if let Some(FrameSynthesisOptions {
+            cancellable: Some(cancellable),
+        }) = options
+        {
+            frame_synthesis = frame_synthesis.cancellable(cancellable);
+        }
+        let wav = frame_synthesis.perform().await.into_napi_result()?;
+        Ok(wav.into())
+    }
+
+    fn get(&self) -> napi::Result<Arc<nonblocking::Synthesizer<nonblocking::OpenJtalk>>> {
+        self.synthesizer
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+            .ok_or_else(|| already_closed("Synthesizer"))
+    }
+}
+
+/// ユーザー辞書。
+#[napi]
+pub struct UserDict(Arc<nonblocking::UserDict>);
+
+#[napi]
+impl UserDict {
+    #[napi(constructor)]
+    #[allow(
+        clippy::new_without_default,
+        reason = "JavaScriptのコンストラクタとなる"
+    )]
+    pub fn new() -> Self {
+        Self(nonblocking::UserDict::new().into())
+    }
+
+    /// ファイルに保存されたユーザー辞書を読み込む。
+    #[napi]
+    pub async fn load(&self, path: String) -> napi::Result<()> {
+        self.0.load(path).await.into_napi_result()
+    }
+
+    /// ユーザー辞書をファイルに保存する。
+    #[napi]
+    pub async fn save(&self, path: String) -> napi::Result<()> {
+        self.0.save(path).await.into_napi_result()
+    }
+
+    /// 単語を追加し、その単語のUUIDを返す。
+    #[napi]
+    pub fn add_word(&self, word: UserDictWord) -> napi::Result<String> {
+        let word = to_rust_user_dict_word(word)?;
+        let uuid = self.0.add_word(word).into_napi_result()?;
+        Ok(uuid.to_string())
+    }
+
+    /// 単語を更新する。
+    #[napi]
+    pub fn update_word(&self, word_uuid: String, word: UserDictWord) -> napi::Result<()> {
+        let word_uuid = to_rust_uuid(&word_uuid)?;
+        let word = to_rust_user_dict_word(word)?;
+        self.0.update_word(word_uuid, word).into_napi_result()
+    }
+
+    /// 単語を削除する。
+    #[napi]
+    pub fn remove_word(&self, word_uuid: String) -> napi::Result<UserDictWord> {
+        let word_uuid = to_rust_uuid(&word_uuid)?;
+        let word = self.0.remove_word(word_uuid).into_napi_result()?;
+        Ok(to_js_user_dict_word(&word))
+    }
+
+    /// ユーザー辞書をインポートする。
+    #[napi]
+    pub fn import_dict(&self, other: &UserDict) -> napi::Result<()> {
+        self.0.import(&other.0).into_napi_result()
+    }
+
+    /// ユーザー辞書の単語を、UUIDをキーとするオブジェクトとして得る。
+    #[napi]
+    pub fn to_dict(&self) -> HashMap<String, UserDictWord> {
+        self.0.with_words(|words| {
+            words
+                .iter()
+                .map(|(uuid, word)| (uuid.to_string(), to_js_user_dict_word(word)))
+                .collect()
+        })
+    }
+}
+
+/// 強弱に応じて音量に掛ける倍率を得る。`"mf"`を`1`とする。
+#[napi]
+pub fn dynamics_volume_scale(
+    #[napi(ts_arg_type = "'pp' | 'p' | 'mp' | 'mf' | 'f' | 'ff'")] dynamics: String,
+) -> napi::Result<f64> {
+    let dynamics = serde_json::from_value::<voicevox_core::Dynamics>(dynamics.into())
+        .map_err(|err| invalid_arg("強弱", err))?;
+    Ok(dynamics.volume_scale().into())
+}
+
+fn already_closed(name: &str) -> napi::Error {
+    napi::Error::new(Status::GenericFailure, format!("The `{name}` is closed"))
+}
@@ -0,0 +1,304 @@
+//! JavaScriptのオブジェクトとして受け渡しするデータ型。
+//!
+//! それぞれ`voicevox_core`の同名の型と対応する。TypeScriptの型定義はここから生成される。
+//!
+//! JavaScript側ではフィールド名はcamelCaseになる。`voicevox_core`との変換は、各フィールドに付けた
+//! `#[serde]`属性によって`voicevox_core`側のJSON表現を経由して行う。
+
+use std::collections::HashMap;
+
+use napi_derive::napi;
+use serde::{Deserialize, Serialize};
+
+/// モーラ（子音＋母音）ごとの情報。
+#[napi(object)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Mora {
+    /// 文字。
+    pub text: String,
+    /// 子音の音素。
+    pub consonant: Option<String>,
+    /// 子音の音長。
+    pub consonant_length: Option<f64>,
+    /// 母音の音素。
+    pub vowel: String,
+    /// 母音の音長。
+    pub vowel_length: f64,
+    /// 音高。
+    pub pitch: f64,
+}
+
+/// AccentPhrase (アクセント句ごとの情報)。
+#[napi(object)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct AccentPhrase {
+    /// モーラの配列。
+    pub moras: Vec<Mora>,
+    /// アクセント箇所。
+    pub accent: u32,
+    /// 後ろに無音を付けるかどうか。
+    pub pause_mora: Option<Mora>,
+    /// 疑問系かどうか。
+    pub is_interrogative: bool,
+}
+
+/// AudioQuery (音声合成用のクエリ)。
+#[napi(object)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct AudioQuery {
+    /// アクセント句の配列。
+    pub accent_phrases: Vec<AccentPhrase>,
+    /// 全体の話速。
+    #[serde(rename = "speedScale")]
+    pub speed_scale: f64,
+    /// 全体の音高。
+    #[serde(rename = "pitchScale")]
+    pub pitch_scale: f64,
+    /// 全体の抑揚。
+    #[serde(rename = "intonationScale")]
+    pub intonation_scale: f64,
+    /// 全体の音量。
+    #[serde(rename = "volumeScale")]
+    pub volume_scale: f64,
+    /// 音声の前の無音時間。
+    #[serde(rename = "prePhonemeLength")]
+    pub pre_phoneme_length: f64,
+    /// 音声の後の無音時間。
+    #[serde(rename = "postPhonemeLength")]
+    pub post_phoneme_length: f64,
+    /// 音声データの出力サンプリングレート。
+    #[serde(rename = "outputSamplingRate")]
+    pub output_sampling_rate: u32,
+    /// 音声データをステレオ出力するか否か。
+    #[serde(rename = "outputStereo")]
+    pub output_stereo: bool,
+    /// \[読み取り専用\] AquesTalk風記法。
+    pub kana: Option<String>,
+}
+
+/// 楽譜情報。
+#[napi(object)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Score {
+    /// 音符のリスト。
+    pub notes: Vec<Note>,
+}
+
+/// 音符ごとの情報。
+#[napi(object)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Note {
+    /// ID。
+    pub id: Option<String>,
+    /// 音階。休符であれば`undefined`。
+    pub key: Option<u32>,
+    /// 歌詞。休符であれば空文字列。
+    pub lyric: String,
+    /// 音符のフレーム長。
+    pub frame_length: i64,
+    /// 強弱。音量に掛ける倍率は`dynamicsVolumeScale`で得られる。
+    #[napi(ts_type = "'pp' | 'p' | 'mp' | 'mf' | 'f' | 'ff'")]
+    pub dynamics: Option<String>,
+    /// ブレスかどうか。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub breath: Option<bool>,
+}
+
+/// 歌唱音声合成用のクエリ。
+#[napi(object)]
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FrameAudioQuery {
+    /// フレームごとの基本周波数。
+    pub f0: Vec<f64>,
+    /// フレームごとの音量。
+    pub volume: Vec<f64>,
+    /// 音素のリスト。
+    pub phonemes: Vec<FramePhoneme>,
+    /// 全体の音量。
+    pub volume_scale: f64,
+    /// 音声データの出力サンプリングレート。
+    pub output_sampling_rate: u32,
+    /// 音声データをステレオ出力するか否か。
+    pub output_stereo: bool,
+}
+
+/// 音素の情報。
+#[napi(object)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FramePhoneme {
+    /// 音素。
+    pub phoneme: String,
+    /// 音素のフレーム長。
+    pub frame_length: i64,
+    /// 音符のID。
+    pub note_id: Option<String>,
+}
+
+/// キャラクターのメタ情報。
+#[napi(object)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CharacterMeta {
+    /// キャラクター名。
+    pub name: String,
+    /// キャラクターに属するスタイル。
+    pub styles: Vec<StyleMeta>,
+    /// キャラクターのバージョン。
+    pub version: String,
+    /// キャラクターのUUID。
+    pub speaker_uuid: String,
+    /// キャラクターの順番。
+    pub order: Option<u32>,
+}
+
+/// スタイルのメタ情報。
+#[napi(object)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct StyleMeta {
+    /// スタイルID。
+    pub id: u32,
+    /// スタイル名。
+    pub name: String,
+    /// スタイルに対応するモデルの種類。
+    #[napi(
+        js_name = "type",
+        ts_type = "'talk' | 'singing_teacher' | 'frame_decode' | 'sing' | 'streaming_talk'"
+    )]
+    #[serde(rename = "type")]
+    pub r#type: String,
+    /// スタイルの順番。
+    pub order: Option<u32>,
+}
+
+/// ユーザー辞書の単語。
+#[napi(object)]
+#[derive(Clone)]
+pub struct UserDictWord {
+    /// 単語の表記。
+    pub surface: String,
+    /// 単語の読み。
+    pub pronunciation: String,
+    /// アクセント型。
+    pub accent_type: u32,
+    /// 単語の種類。
+    #[napi(ts_type = "'PROPER_NOUN' | 'COMMON_NOUN' | 'VERB' | 'ADJECTIVE' | 'SUFFIX'")]
+    pub word_type: Option<String>,
+    /// 単語の優先度。
+    pub priority: Option<u32>,
+}
+
+/// このライブラリで利用可能なデバイスの情報。
+#[napi(object)]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SupportedDevices {
+    /// CPUが利用可能。
+    pub cpu: bool,
+    /// CUDAが利用可能。
+    pub cuda: bool,
+    /// DirectMLが利用可能。
+    pub dml: bool,
+    /// TensorRTが利用可能。
+    pub tensorrt: bool,
+    /// ROCmが利用可能。
+    pub rocm: bool,
+    /// Core MLが利用可能。
+    pub coreml: bool,
+    /// XNNPACKが利用可能。
+    pub xnnpack: bool,
+    /// OpenVINOが利用可能。
+    pub openvino: bool,
+    /// oneDNNが利用可能。
+    pub onednn: bool,
+}
+
+/// ハードウェアアクセラレーションモード。
+#[napi(string_enum)]
+pub enum AccelerationMode {
+    /// 実行環境に合った適切なハードウェアアクセラレーションモードを選択する。
+    #[napi(value = "AUTO")]
+    Auto,
+    /// ハードウェアアクセラレーションモードを"CPU"に設定する。
+    #[napi(value = "CPU")]
+    Cpu,
+    /// ハードウェアアクセラレーションモードを"GPU"に設定する。
+    #[napi(value = "GPU")]
+    Gpu,
+}
+
+/// 推論の操作を行うデバイス。
+#[napi(string_enum)]
+pub enum OperationDevice {
+    /// CPU。
+    #[napi(value = "CPU")]
+    Cpu,
+    /// `accelerationMode`によって選ばれたデバイス。`"CPU"`のときや、利用可能なものが無かったときはCPUとなる。
+    #[napi(value = "ACCELERATOR")]
+    Accelerator,
+}
+
+/// 推論の操作の配置先。
+#[napi(object)]
+pub struct OperationPlacement {
+    /// 推論を行うデバイス。
+    pub device: OperationDevice,
+    /// CPU利用数。省略したときはシンセサイザ全体の設定に従う。0を指定すると環境に合わせたCPUが利用される。
+    pub cpu_num_threads: Option<u16>,
+}
+
+/// 音声モデルIDの重複時の挙動。
+#[napi(string_enum)]
+pub enum OnExistingVoiceModelId {
+    /// エラーとする。
+    #[napi(value = "ERROR")]
+    Error,
+    /// 読み込み直す。
+    #[napi(value = "RELOAD")]
+    Reload,
+    /// 何もしない。
+    #[napi(value = "SKIP")]
+    Skip,
+}
+
+/// `Onnxruntime.loadOnce`のオプション。
+#[napi(object)]
+pub struct LoadOnceOptions {
+    /// ONNX Runtimeのファイル名（モジュール名）もしくはファイルパス。
+    pub filename: Option<String>,
+}
+
+/// `Synthesizer`のコンストラクタのオプション。
+#[napi(object)]
+pub struct SynthesizerOptions {
+    /// ハードウェアアクセラレーションモード。
+    pub acceleration_mode: Option<AccelerationMode>,
+    /// CPU利用数を指定。0を指定すると環境に合わせたCPUが利用される。
+    pub cpu_num_threads: Option<u16>,
+    /// 推論の操作ごとの、推論を行うデバイスとCPU利用数。
+    ///
+    /// キーは`"talk.decode"`のように、ドメインと操作の名前を`.`で繋げたもの。指定しなかった操作については、
+    /// 音素長や音高の推論といった軽いものはCPUで、波形の生成といった重いものは`"ACCELERATOR"`で行われる。
+    pub operation_placements: Option<HashMap<String, OperationPlacement>>,
+}
+
+/// `Synthesizer.loadVoiceModel`のオプション。
+#[napi(object)]
+pub struct LoadVoiceModelOptions {
+    /// 音声モデルIDが重複していたときの挙動。
+    pub on_existing: Option<OnExistingVoiceModelId>,
+}
+
+/// `Synthesizer.synthesis`などのオプション。
+#[napi(object)]
+pub struct SynthesisOptions {
+    /// 疑問文の調整を有効にするかどうか。
+    pub enable_interrogative_upspeak: Option<bool>,
+    /// 音声モデルの実行をキャンセル可能にするかどうか。
+    pub cancellable: Option<bool>,
+}
+
+/// `Synthesizer.frameSynthesis`のオプション。
+#[napi(object)]
+pub struct FrameSynthesisOptions {
+    /// 音声モデルの実行をキャンセル可能にするかどうか。
+    pub cancellable: Option<bool>,
+}
@@ -0,0 +1,24 @@
+import { createRequire } from "node:module";
+import path from "node:path";
+import { fileURLToPath } from "node:url";
+
+export const voicevoxCore = createRequire(import.meta.url)("../index.js");
+
+const testUtilData = path.join(
+  path.dirname(fileURLToPath(import.meta.url)),
+  "..",
+  "..",
+  "test_util",
+  "data",
+);
+
+export const onnxruntimeFilename = path.join(
+  testUtilData,
+  "lib",
+  voicevoxCore.Onnxruntime.libRecommendedUnversionedFilename().replace(
+    "voicevox_onnxruntime",
+    "onnxruntime",
+  ),
+);
+export const openJtalkDicDir = path.join(testUtilData, "open_jtalk_dic_utf_8-1.11");
+export const modelFile = path.join(testUtilData, "model", "sample.vvm");
@@ -0,0 +1,16 @@
+// クエリに関する関数を確かめる。
+
+import assert from "node:assert/strict";
+import { test } from "node:test";
+
+import { voicevoxCore } from "./common.mjs";
+
+const { dynamicsVolumeScale } = voicevoxCore;
+
+test("強弱の倍率が得られる", () => {
+  const scales = ["pp", "p", "mp", "mf", "f", "ff"].map(dynamicsVolumeScale);
+
+  assert.equal(scales[3], 1);
+  assert.deepEqual(scales, [...scales].sort((a, b) => a - b));
+  assert.throws(() => dynamicsVolumeScale("fff"));
+});
@@ -0,0 +1,102 @@
+// 音声合成を行う。
+
+import assert from "node:assert/strict";
+import { before, test } from "node:test";
+
+import { modelFile, onnxruntimeFilename, openJtalkDicDir, voicevoxCore } from "./common.mjs";
+
+const { Onnxruntime, OpenJtalk, Synthesizer, VoiceModelFile } = voicevoxCore;
+
+const STYLE_ID = 0;
+
+let synthesizer;
+
+before(async () => {
+  const onnxruntime = await Onnxruntime.loadOnce({ filename: onnxruntimeFilename });
+  const openJtalk = await OpenJtalk.create(openJtalkDicDir);
+  synthesizer = new Synthesizer(onnxruntime, openJtalk, { accelerationMode: "CPU" });
+
+  const model = await VoiceModelFile.open(modelFile);
+  await synthesizer.loadVoiceModel(model);
+  await model.close();
+});
+
+test("音声合成ができる", async () => {
+  const TEXT = "こんにちは？";
+
+  const wav1 = await synthesizer.tts(TEXT, STYLE_ID);
+
+  const query = await synthesizer.createAudioQuery(TEXT, STYLE_ID);
+  const wav2 = await synthesizer.synthesis(query, STYLE_ID);
+
+  let phrases = await synthesizer.openJtalk.analyze(TEXT);
+  phrases = await synthesizer.replaceMoraData(phrases, STYLE_ID);
+  const wav3 = await synthesizer.synthesis({ ...query, accentPhrases: phrases }, STYLE_ID);
+
+  assert.deepEqual(wav1, wav2);
+  assert.deepEqual(wav1, wav3);
+  assert.equal(wav1.subarray(0, 4).toString(), "RIFF");
+
+  const wav4 = await synthesizer.tts(TEXT, STYLE_ID, { enableInterrogativeUpspeak: false });
+  assert.notDeepEqual(wav1, wav4);
+});
+
+test("`VoiceModelFile`のメタ情報が得られる", async () => {
+  const model = await VoiceModelFile.open(modelFile);
+  try {
+    assert.ok(synthesizer.isLoadedVoiceModel(model.id));
+    assert.ok(model.metas.length > 0);
+    assert.deepEqual(
+      synthesizer.metas.flatMap((c) => c.styles.map((s) => s.id)).includes(STYLE_ID),
+      true,
+    );
+  } finally {
+    await model.close();
+  }
+});
+
+test("同じ音声モデルを読み込もうとするとエラーになる", async () => {
+  const model = await VoiceModelFile.open(modelFile);
+  try {
+    await assert.rejects(synthesizer.loadVoiceModel(model), /^Error: ModelAlreadyLoadedError: /);
+    await synthesizer.loadVoiceModel(model, { onExisting: "SKIP" });
+  } finally {
+    await model.close();
+  }
+});
+
+test("推論の操作ごとに配置を指定できる", async () => {
+  const placed = new Synthesizer(Onnxruntime.get(), synthesizer.openJtalk, {
+    accelerationMode: "CPU",
+    operationPlacements: {
+      "talk.predict_duration": { device: "ACCELERATOR" },
+      "talk.decode": { device: "CPU", cpuNumThreads: 1 },
+    },
+  });
+  assert.equal(placed.isGpuMode, false);
+
+  const model = await VoiceModelFile.open(modelFile);
+  try {
+    await placed.loadVoiceModel(model);
+  } finally {
+    await model.close();
+  }
+  const wav = await placed.tts("こんにちは", STYLE_ID);
+  assert.equal(wav.subarray(0, 4).toString(), "RIFF");
+  placed.close();
+});
+
+test("未知の推論の操作は指定できない", () => {
+  assert.throws(
+    () =>
+      new Synthesizer(Onnxruntime.get(), synthesizer.openJtalk, {
+        operationPlacements: { "talk.sf_decode": { device: "CPU" } },
+      }),
+  );
+});
+
+test("閉じた`Synthesizer`は使えない", async () => {
+  const closed = new Synthesizer(Onnxruntime.get(), synthesizer.openJtalk);
+  closed.close();
+  await assert.rejects(closed.tts("こんにちは", STYLE_ID));
+});
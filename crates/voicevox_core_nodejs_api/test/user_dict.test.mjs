@@ -0,0 +1,67 @@
+// ユーザー辞書の操作をテストする。
+
+import assert from "node:assert/strict";
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { test } from "node:test";
+
+import { voicevoxCore } from "./common.mjs";
+
+const { UserDict } = voicevoxCore;
+
+test("単語の追加・更新・削除ができる", async () => {
+  const dict = new UserDict();
+  assert.deepEqual(dict.toDict(), {});
+
+  const uuid = dict.addWord({ surface: "hoge", pronunciation: "ホゲ", accentType: 0 });
+  assert.deepEqual(dict.toDict()[uuid], {
+    surface: "ｈｏｇｅ",
+    pronunciation: "ホゲ",
+    accentType: 0,
+    wordType: "COMMON_NOUN",
+    priority: 5,
+  });
+
+  dict.updateWord(uuid, {
+    surface: "fuga",
+    pronunciation: "フガ",
+    accentType: 1,
+    wordType: "PROPER_NOUN",
+    priority: 8,
+  });
+  assert.equal(dict.toDict()[uuid].surface, "ｆｕｇａ");
+
+  const other = new UserDict();
+  other.addWord({ surface: "piyo", pronunciation: "ピヨ", accentType: 0 });
+  dict.importDict(other);
+  assert.equal(Object.keys(dict.toDict()).length, 2);
+
+  const removed = dict.removeWord(uuid);
+  assert.equal(removed.pronunciation, "フガ");
+  assert.equal(Object.keys(dict.toDict()).length, 1);
+
+  assert.throws(() => dict.removeWord(uuid), /^Error: WordNotFoundError: /);
+  assert.throws(
+    () => dict.addWord({ surface: "hoge", pronunciation: "ホゲ", accentType: 0, priority: 11 }),
+    /^Error: InvalidWordError: /,
+  );
+});
+
+test("保存と読み込みができる", async () => {
+  const dir = await fs.mkdtemp(path.join(os.tmpdir(), "voicevox_core-"));
+  try {
+    const file = path.join(dir, "user_dict.json");
+
+    const dict = new UserDict();
+    const uuid = dict.addWord({ surface: "hoge", pronunciation: "ホゲ", accentType: 0 });
+    await dict.save(file);
+
+    const loaded = new UserDict();
+    await loaded.load(file);
+    assert.deepEqual(loaded.toDict(), dict.toDict());
+    assert.ok(uuid in loaded.toDict());
+  } finally {
+    await fs.rm(dir, { recursive: true });
+  }
+});
@@ -7,6 +7,7 @@
 import conftest
 import pytest
 import pytest_asyncio
+from voicevox_core import StyleNotFoundError, VerifyModelError
 from voicevox_core.asyncio import Onnxruntime, OpenJtalk, Synthesizer, VoiceModelFile
 
 
@@ -24,6 +25,38 @@ async def test_synthesizer_metas_works(voice_model: VoiceModelFile) -> None:
     _ = synthesizer.metas()
 
 
+@pytest.mark.asyncio
+async def test_synthesizer_metas_reports_only_loaded_styles(
+    voice_model: VoiceModelFile,
+) -> None:
+    synthesizer = Synthesizer(
+        await Onnxruntime.load_once(filename=conftest.onnxruntime_filename),
+        await OpenJtalk.new(conftest.open_jtalk_dic_dir),
+    )
+    await synthesizer.load_voice_model(
+        voice_model, style_types=["talk"], style_ids=[0]
+    )
+    assert [style.id for meta in synthesizer.metas() for style in meta.styles] == [0]
+
+    with pytest.raises(StyleNotFoundError):
+        await synthesizer.create_audio_query("こんにちは", 1)
+
+
+@pytest.mark.asyncio
+async def test_load_voice_model_rejects_unsigned_model_with_trusted_public_keys(
+    voice_model: VoiceModelFile,
+) -> None:
+    synthesizer = Synthesizer(
+        await Onnxruntime.load_once(filename=conftest.onnxruntime_filename),
+        await OpenJtalk.new(conftest.open_jtalk_dic_dir),
+    )
+    with pytest.raises(VerifyModelError):
+        await synthesizer.load_voice_model(
+            voice_model, trusted_public_keys=[bytes(32)]
+        )
+    assert synthesizer.metas() == []
+
+
 @pytest_asyncio.fixture
 async def voice_model() -> VoiceModelFile:
     return await VoiceModelFile.open(conftest.model_dir)
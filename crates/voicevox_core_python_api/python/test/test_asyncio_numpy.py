@@ -0,0 +1,118 @@
+"""
+NumPyの配列による音声合成を行う。
+
+``test_blocking_numpy`` と対になる。
+"""
+
+import dataclasses
+import multiprocessing
+import platform
+
+import conftest
+import numpy as np
+import pytest
+import pytest_asyncio
+from voicevox_core import Note, Score, StyleId
+from voicevox_core.asyncio import Onnxruntime, OpenJtalk, Synthesizer, VoiceModelFile
+
+
+@pytest.mark.asyncio
+async def test_synthesis_array(synthesizer: Synthesizer) -> None:
+    TEXT = "こんにちは、音声合成の世界へようこそ。"
+    STYLE_ID = StyleId(302)
+
+    query = await synthesizer.create_audio_query(TEXT, STYLE_ID)
+    wave = await synthesizer.synthesis_array(query, STYLE_ID)
+    assert wave.dtype == np.float32
+    assert wave.ndim == 1
+    assert np.all((-1.0 <= wave) & (wave <= 1.0))
+
+    chunks = await synthesizer.synthesis_stream(query, STYLE_ID, chunk_length=10)
+    assert wave.size == chunks.frame_length * 256
+    waves = [w async for w in chunks]
+    assert len(waves) == -(-chunks.frame_length // 10)
+    assert all(w.dtype == np.float32 for w in waves)
+    assert np.allclose(np.concatenate(waves), wave, atol=1e-3)
+    with pytest.raises(StopAsyncIteration):
+        await chunks.__anext__()
+
+    query = dataclasses.replace(query, output_stereo=True)
+    wave = await synthesizer.synthesis_array(query, STYLE_ID)
+    assert wave.shape == (chunks.frame_length * 256, 2)
+    assert np.array_equal(wave[:, 0], wave[:, 1])
+
+    with pytest.raises(ValueError):
+        await synthesizer.synthesis_stream(query, STYLE_ID, chunk_length=0)
+
+    audio = await synthesizer.precompute_render(query, STYLE_ID)
+    assert audio.frame_length == chunks.frame_length
+    pcm = await synthesizer.render(audio, 0, audio.frame_length)
+    assert len(pcm) == audio.frame_length * 256 * 2 * 2
+    with pytest.raises(IndexError):
+        await synthesizer.render(audio, 0, audio.frame_length + 1)
+
+
+@pytest.mark.asyncio
+async def test_frame_audio_query_arrays(synthesizer: Synthesizer) -> None:
+    SINGING_TEACHER = StyleId(6000)
+    SINGER = StyleId(3000)
+
+    SCORE = Score(
+        [
+            Note(15, ""),
+            Note(45, "ド", key=60),
+            Note(45, "レ", key=62),
+            Note(45, "ミ", key=64),
+            Note(15, ""),
+        ],
+    )
+
+    frame_audio_query = await synthesizer.create_sing_frame_audio_query(
+        SCORE, SINGING_TEACHER
+    )
+
+    assert isinstance(frame_audio_query.f0, np.ndarray)
+    assert frame_audio_query.f0.dtype == np.float32
+    assert isinstance(frame_audio_query.volume, np.ndarray)
+    assert frame_audio_query.volume.dtype == np.float32
+
+    f0s = np.array(
+        await synthesizer.create_sing_frame_f0(SCORE, frame_audio_query, SINGING_TEACHER),
+        dtype=np.float32,
+    )
+    volumes = np.array(
+        await synthesizer.create_sing_frame_volume(
+            SCORE, frame_audio_query, SINGING_TEACHER
+        ),
+        dtype=np.float32,
+    )
+
+    wav1 = await synthesizer.frame_synthesis(
+        dataclasses.replace(frame_audio_query, f0=f0s, volume=volumes), SINGER
+    )
+    wav2 = await synthesizer.frame_synthesis(
+        dataclasses.replace(
+            frame_audio_query, f0=f0s.tolist(), volume=volumes.tolist()
+        ),
+        SINGER,
+    )
+    assert wav1 == wav2
+
+
+@pytest_asyncio.fixture
+async def synthesizer() -> Synthesizer:
+    onnxruntime = await Onnxruntime.load_once(filename=conftest.onnxruntime_filename)
+    open_jtalk = await OpenJtalk.new(conftest.open_jtalk_dic_dir)
+    synthesizer = Synthesizer(
+        onnxruntime,
+        open_jtalk,
+        acceleration_mode="CPU",
+        cpu_num_threads=max(
+            multiprocessing.cpu_count(), 2
+        )  # https://github.com/VOICEVOX/voicevox_core/issues/888
+        if platform.system() == "Darwin"
+        else 0,  # default
+    )
+    async with await VoiceModelFile.open(conftest.model_dir) as model:
+        await synthesizer.load_voice_model(model)
+    return synthesizer
@@ -0,0 +1,48 @@
+"""
+``OpenJtalk`` 以外のテキスト解析器を用いる。
+
+``test_blocking_text_analyzer`` と対になる。
+"""
+
+import conftest
+import pytest
+import voicevox_core
+from voicevox_core import AccentPhrase
+from voicevox_core.asyncio import Onnxruntime, OpenJtalk, Synthesizer, VoiceModelFile
+
+
+class _RecordingTextAnalyzer:
+    def __init__(self, open_jtalk: voicevox_core.blocking.OpenJtalk) -> None:
+        self._open_jtalk = open_jtalk
+        self.texts: list[str] = []
+
+    def analyze(self, text: str) -> list[AccentPhrase]:
+        self.texts.append(text)
+        return self._open_jtalk.analyze(text)
+
+
+@pytest.mark.asyncio
+async def test_foreign_text_analyzer() -> None:
+    TEXT = "こんにちは"
+    STYLE_ID = 0
+
+    onnxruntime = await Onnxruntime.load_once(filename=conftest.onnxruntime_filename)
+    open_jtalk = await OpenJtalk.new(conftest.open_jtalk_dic_dir)
+    text_analyzer = _RecordingTextAnalyzer(
+        voicevox_core.blocking.OpenJtalk(conftest.open_jtalk_dic_dir)
+    )
+
+    synthesizer1 = await load_model(Synthesizer(onnxruntime, open_jtalk))
+    synthesizer2 = await load_model(Synthesizer(onnxruntime, text_analyzer))
+    assert synthesizer2.open_jtalk is text_analyzer
+
+    wav1 = await synthesizer1.tts(TEXT, STYLE_ID)
+    wav2 = await synthesizer2.tts(TEXT, STYLE_ID)
+    assert wav1 == wav2
+    assert text_analyzer.texts == [TEXT]
+
+
+async def load_model(synthesizer: Synthesizer) -> Synthesizer:
+    async with await VoiceModelFile.open(conftest.model_dir) as model:
+        await synthesizer.load_voice_model(model)
+    return synthesizer
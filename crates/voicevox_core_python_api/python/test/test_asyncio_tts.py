@@ -10,7 +10,7 @@ import platform
 import conftest
 import pytest
 import pytest_asyncio
-from voicevox_core import AudioQuery
+from voicevox_core import AudioQuery, OperationPlacement
 from voicevox_core.asyncio import Onnxruntime, OpenJtalk, Synthesizer, VoiceModelFile
 
 
@@ -74,6 +74,38 @@ async def test(synthesizer: Synthesizer) -> None:
     assert len({wav6, wav7, wav8, wav9, wav10}) == 1
 
 
+@pytest.mark.asyncio
+async def test_operation_placements() -> None:
+    onnxruntime = await Onnxruntime.load_once(filename=conftest.onnxruntime_filename)
+    open_jtalk = await OpenJtalk.new(conftest.open_jtalk_dic_dir)
+    synthesizer = Synthesizer(
+        onnxruntime,
+        open_jtalk,
+        acceleration_mode="CPU",
+        operation_placements={
+            "talk.predict_duration": OperationPlacement("ACCELERATOR"),
+            "talk.decode": OperationPlacement("CPU", cpu_num_threads=1),
+        },
+    )
+    async with await VoiceModelFile.open(conftest.model_dir) as model:
+        await synthesizer.load_voice_model(model)
+    assert await synthesizer.tts("こんにちは", 0)
+
+
+@pytest.mark.asyncio
+async def test_operation_placements_rejects_unknown_operations() -> None:
+    onnxruntime = await Onnxruntime.load_once(filename=conftest.onnxruntime_filename)
+    open_jtalk = await OpenJtalk.new(conftest.open_jtalk_dic_dir)
+    with pytest.raises(ValueError):
+        Synthesizer(
+            onnxruntime,
+            open_jtalk,
+            acceleration_mode="CPU",
+            operation_placements={
+                "talk.sf_decode": OperationPlacement("CPU"),  # pyright: ignore[reportArgumentType]
+            },
+        )
+
 @pytest_asyncio.fixture
 async def synthesizer() -> Synthesizer:
     onnxruntime = await Onnxruntime.load_once(filename=conftest.onnxruntime_filename)
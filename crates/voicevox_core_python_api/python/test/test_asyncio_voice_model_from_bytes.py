@@ -0,0 +1,26 @@
+"""
+メモリ上のVVMを開けるかどうかをテストする。
+
+``test_blocking_voice_model_from_bytes`` と対になる。
+"""
+
+import conftest
+import pytest
+from voicevox_core import OpenZipFileError
+from voicevox_core.asyncio import VoiceModelFile
+
+
+@pytest.mark.asyncio
+async def test_from_bytes_works() -> None:
+    async with await VoiceModelFile.open(conftest.model_dir) as expected:
+        async with await VoiceModelFile.from_bytes(
+            conftest.model_dir.read_bytes()
+        ) as actual:
+            assert actual.id == expected.id
+            assert actual.metas == expected.metas
+
+
+@pytest.mark.asyncio
+async def test_from_bytes_rejects_non_zip() -> None:
+    with pytest.raises(OpenZipFileError):
+        await VoiceModelFile.from_bytes(b"not a zip")
@@ -6,6 +6,7 @@
 
 import conftest
 import pytest
+from voicevox_core import StyleNotFoundError, VerifyModelError
 from voicevox_core.blocking import Onnxruntime, OpenJtalk, Synthesizer, VoiceModelFile
 
 
@@ -22,6 +23,32 @@ def test_synthesizer_metas_works(voice_model: VoiceModelFile) -> None:
     _ = synthesizer.metas()
 
 
+def test_synthesizer_metas_reports_only_loaded_styles(
+    voice_model: VoiceModelFile,
+) -> None:
+    synthesizer = Synthesizer(
+        Onnxruntime.load_once(filename=conftest.onnxruntime_filename),
+        OpenJtalk(conftest.open_jtalk_dic_dir),
+    )
+    synthesizer.load_voice_model(voice_model, style_types=["talk"], style_ids=[0])
+    assert [style.id for meta in synthesizer.metas() for style in meta.styles] == [0]
+
+    with pytest.raises(StyleNotFoundError):
+        synthesizer.create_audio_query("こんにちは", 1)
+
+
+def test_load_voice_model_rejects_unsigned_model_with_trusted_public_keys(
+    voice_model: VoiceModelFile,
+) -> None:
+    synthesizer = Synthesizer(
+        Onnxruntime.load_once(filename=conftest.onnxruntime_filename),
+        OpenJtalk(conftest.open_jtalk_dic_dir),
+    )
+    with pytest.raises(VerifyModelError):
+        synthesizer.load_voice_model(voice_model, trusted_public_keys=[bytes(32)])
+    assert synthesizer.metas() == []
+
+
 @pytest.fixture
 def voice_model() -> VoiceModelFile:
     return VoiceModelFile.open(conftest.model_dir)
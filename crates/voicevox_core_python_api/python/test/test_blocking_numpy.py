@@ -0,0 +1,103 @@
+"""
+NumPyの配列による音声合成を行う。
+
+``test_asyncio_numpy`` と対になる。
+"""
+
+import dataclasses
+
+import conftest
+import numpy as np
+import pytest
+from voicevox_core import Note, Score, StyleId
+from voicevox_core.blocking import Onnxruntime, OpenJtalk, Synthesizer, VoiceModelFile
+
+
+def test_synthesis_array(synthesizer: Synthesizer) -> None:
+    TEXT = "こんにちは、音声合成の世界へようこそ。"
+    STYLE_ID = StyleId(302)
+
+    query = synthesizer.create_audio_query(TEXT, STYLE_ID)
+    wave = synthesizer.synthesis_array(query, STYLE_ID)
+    assert wave.dtype == np.float32
+    assert wave.ndim == 1
+    assert np.all((-1.0 <= wave) & (wave <= 1.0))
+
+    chunks = synthesizer.synthesis_stream(query, STYLE_ID, chunk_length=10)
+    assert wave.size == chunks.frame_length * 256
+    waves = list(chunks)
+    assert len(waves) == -(-chunks.frame_length // 10)
+    assert all(w.dtype == np.float32 for w in waves)
+    assert np.allclose(np.concatenate(waves), wave, atol=1e-3)
+    assert next(chunks, None) is None
+
+    query = dataclasses.replace(query, output_stereo=True)
+    wave = synthesizer.synthesis_array(query, STYLE_ID)
+    assert wave.shape == (chunks.frame_length * 256, 2)
+    assert np.array_equal(wave[:, 0], wave[:, 1])
+
+    with pytest.raises(ValueError):
+        synthesizer.synthesis_stream(query, STYLE_ID, chunk_length=0)
+
+    audio = synthesizer.precompute_render(query, STYLE_ID)
+    assert audio.frame_length == chunks.frame_length
+    pcm = synthesizer.render(audio, 0, audio.frame_length)
+    assert len(pcm) == audio.frame_length * 256 * 2 * 2
+    with pytest.raises(IndexError):
+        synthesizer.render(audio, 0, audio.frame_length + 1)
+
+
+def test_frame_audio_query_arrays(synthesizer: Synthesizer) -> None:
+    SINGING_TEACHER = StyleId(6000)
+    SINGER = StyleId(3000)
+
+    SCORE = Score(
+        [
+            Note(15, ""),
+            Note(45, "ド", key=60),
+            Note(45, "レ", key=62),
+            Note(45, "ミ", key=64),
+            Note(15, ""),
+        ],
+    )
+
+    frame_audio_query = synthesizer.create_sing_frame_audio_query(
+        SCORE, SINGING_TEACHER
+    )
+
+    assert isinstance(frame_audio_query.f0, np.ndarray)
+    assert frame_audio_query.f0.dtype == np.float32
+    assert isinstance(frame_audio_query.volume, np.ndarray)
+    assert frame_audio_query.volume.dtype == np.float32
+
+    f0s = np.array(
+        synthesizer.create_sing_frame_f0(SCORE, frame_audio_query, SINGING_TEACHER),
+        dtype=np.float32,
+    )
+    volumes = np.array(
+        synthesizer.create_sing_frame_volume(
+            SCORE, frame_audio_query, SINGING_TEACHER
+        ),
+        dtype=np.float32,
+    )
+
+    wav1 = synthesizer.frame_synthesis(
+        dataclasses.replace(frame_audio_query, f0=f0s, volume=volumes), SINGER
+    )
+    wav2 = synthesizer.frame_synthesis(
+        dataclasses.replace(
+            frame_audio_query, f0=f0s.tolist(), volume=volumes.tolist()
+        ),
+        SINGER,
+    )
+    assert wav1 == wav2
+
+
+@pytest.fixture
+def synthesizer() -> Synthesizer:
+    onnxruntime = Onnxruntime.load_once(filename=conftest.onnxruntime_filename)
+    open_jtalk = OpenJtalk(conftest.open_jtalk_dic_dir)
+    synthesizer = Synthesizer(onnxruntime, open_jtalk, acceleration_mode="CPU")
+    with VoiceModelFile.open(conftest.model_dir) as model:
+        synthesizer.load_voice_model(model)
+    return synthesizer
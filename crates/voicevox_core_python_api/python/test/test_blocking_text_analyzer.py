@@ -0,0 +1,68 @@
+"""
+``OpenJtalk`` 以外のテキスト解析器を用いる。
+
+``test_asyncio_text_analyzer`` と対になる。
+"""
+
+import conftest
+import pytest
+from voicevox_core import AccentPhrase, AnalyzeTextError, Mora
+from voicevox_core.blocking import Onnxruntime, OpenJtalk, Synthesizer, VoiceModelFile
+
+
+class _RecordingTextAnalyzer:
+    def __init__(self, open_jtalk: OpenJtalk) -> None:
+        self._open_jtalk = open_jtalk
+        self.texts: list[str] = []
+
+    def analyze(self, text: str) -> list[AccentPhrase]:
+        self.texts.append(text)
+        return self._open_jtalk.analyze(text)
+
+
+class _BrokenTextAnalyzer:
+    def analyze(self, text: str) -> list[AccentPhrase]:
+        mora = Mora(
+            text="ア",
+            consonant=None,
+            consonant_length=None,
+            vowel="a",
+            vowel_length=0.0,
+            pitch=0.0,
+        )
+        return [AccentPhrase(moras=[mora], accent=2)]
+
+
+def test_foreign_text_analyzer() -> None:
+    TEXT = "こんにちは"
+    STYLE_ID = 0
+
+    onnxruntime = Onnxruntime.load_once(filename=conftest.onnxruntime_filename)
+    open_jtalk = OpenJtalk(conftest.open_jtalk_dic_dir)
+    text_analyzer = _RecordingTextAnalyzer(open_jtalk)
+
+    synthesizer1 = load_model(Synthesizer(onnxruntime, open_jtalk))
+    synthesizer2 = load_model(Synthesizer(onnxruntime, text_analyzer))
+    assert synthesizer2.open_jtalk is text_analyzer
+
+    assert synthesizer1.tts(TEXT, STYLE_ID) == synthesizer2.tts(TEXT, STYLE_ID)
+    assert text_analyzer.texts == [TEXT]
+
+
+def test_invalid_output() -> None:
+    onnxruntime = Onnxruntime.load_once(filename=conftest.onnxruntime_filename)
+    synthesizer = Synthesizer(onnxruntime, _BrokenTextAnalyzer())
+    with pytest.raises(AnalyzeTextError):
+        synthesizer.create_accent_phrases("あ", 0)
+
+
+def test_not_a_text_analyzer() -> None:
+    onnxruntime = Onnxruntime.load_once(filename=conftest.onnxruntime_filename)
+    with pytest.raises(TypeError):
+        Synthesizer(onnxruntime, object())  # pyright: ignore[reportArgumentType]
+
+
+def load_model(synthesizer: Synthesizer) -> Synthesizer:
+    with VoiceModelFile.open(conftest.model_dir) as model:
+        synthesizer.load_voice_model(model)
+    return synthesizer
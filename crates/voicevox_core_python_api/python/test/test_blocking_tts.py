@@ -6,7 +6,7 @@
 
 import conftest
 import pytest
-from voicevox_core import AudioQuery
+from voicevox_core import AudioQuery, OperationPlacement
 from voicevox_core.blocking import Onnxruntime, OpenJtalk, Synthesizer, VoiceModelFile
 
 
@@ -59,6 +59,36 @@ def test(synthesizer: Synthesizer) -> None:
     assert len({wav6, wav7, wav8, wav9, wav10}) == 1
 
 
+def test_operation_placements() -> None:
+    onnxruntime = Onnxruntime.load_once(filename=conftest.onnxruntime_filename)
+    open_jtalk = OpenJtalk(conftest.open_jtalk_dic_dir)
+    synthesizer = Synthesizer(
+        onnxruntime,
+        open_jtalk,
+        acceleration_mode="CPU",
+        operation_placements={
+            "talk.predict_duration": OperationPlacement("ACCELERATOR"),
+            "talk.decode": OperationPlacement("CPU", cpu_num_threads=1),
+        },
+    )
+    with VoiceModelFile.open(conftest.model_dir) as model:
+        synthesizer.load_voice_model(model)
+    assert synthesizer.tts("こんにちは", 0)
+
+
+def test_operation_placements_rejects_unknown_operations() -> None:
+    onnxruntime = Onnxruntime.load_once(filename=conftest.onnxruntime_filename)
+    open_jtalk = OpenJtalk(conftest.open_jtalk_dic_dir)
+    with pytest.raises(ValueError):
+        Synthesizer(
+            onnxruntime,
+            open_jtalk,
+            acceleration_mode="CPU",
+            operation_placements={
+                "talk.sf_decode": OperationPlacement("CPU"),  # pyright: ignore[reportArgumentType]
+            },
+        )
+
 @pytest.fixture
 def synthesizer() -> Synthesizer:
     onnxruntime = Onnxruntime.load_once(filename=conftest.onnxruntime_filename)
@@ -0,0 +1,22 @@
+"""
+メモリ上のVVMを開けるかどうかをテストする。
+
+``test_asyncio_voice_model_from_bytes`` と対になる。
+"""
+
+import conftest
+import pytest
+from voicevox_core import OpenZipFileError
+from voicevox_core.blocking import VoiceModelFile
+
+
+def test_from_bytes_works() -> None:
+    with VoiceModelFile.open(conftest.model_dir) as expected:
+        with VoiceModelFile.from_bytes(conftest.model_dir.read_bytes()) as actual:
+            assert actual.id == expected.id
+            assert actual.metas == expected.metas
+
+
+def test_from_bytes_rejects_non_zip() -> None:
+    with pytest.raises(OpenZipFileError):
+        VoiceModelFile.from_bytes(b"not a zip")
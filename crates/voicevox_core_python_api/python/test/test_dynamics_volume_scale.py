@@ -0,0 +1,16 @@
+import pytest
+import voicevox_core
+from voicevox_core import Dynamics
+
+
+def test_dynamics_volume_scale() -> None:
+    dynamics: list[Dynamics] = ["pp", "p", "mp", "mf", "f", "ff"]
+    scales = [voicevox_core.dynamics_volume_scale(d) for d in dynamics]
+
+    assert scales[3] == 1
+    assert scales == sorted(scales)
+
+
+def test_dynamics_volume_scale_rejects_unknown_value() -> None:
+    with pytest.raises(ValueError):
+        voicevox_core.dynamics_volume_scale("fff")  # pyright: ignore[reportArgumentType]
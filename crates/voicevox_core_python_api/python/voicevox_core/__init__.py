@@ -9,17 +9,22 @@ from ._python import (  # noqa: F401
     AudioQuery,
     CharacterMeta,
     CharacterVersion,
+    Dynamics,
     FrameAudioQuery,
     FramePhoneme,
+    InferenceOperationKind,
     Mora,
     Note,
     NoteId,
     OnExistingVoiceModelId,
+    OperationDevice,
+    OperationPlacement,
     Score,
     StyleId,
     StyleMeta,
     StyleType,
     SupportedDevices,
+    TextAnalyzer,
     UserDictWord,
     UserDictWordType,
     VoiceModelId,
@@ -45,8 +50,10 @@ from ._rust import (  # noqa: F401
     StyleAlreadyLoadedError,
     StyleNotFoundError,
     UseUserDictError,
+    VerifyModelError,
     WordNotFoundError,
     __version__,
+    dynamics_volume_scale,
     ensure_compatible,
 )
 
@@ -62,11 +69,13 @@ __all__ = [
     "blocking",
     "CharacterMeta",
     "CharacterVersion",
+    "Dynamics",
     "FrameAudioQuery",
     "FramePhoneme",
     "GetSupportedDevicesError",
     "GpuSupportError",
     "IncompatibleQueriesError",
+    "InferenceOperationKind",
     "InitInferenceRuntimeError",
     "InvalidModelDataError",
     "InvalidQueryError",
@@ -80,6 +89,8 @@ __all__ = [
     "NoteId",
     "OnExistingVoiceModelId",
     "OpenZipFileError",
+    "OperationDevice",
+    "OperationPlacement",
     "ParseKanaError",
     "ReadZipEntryError",
     "RunModelError",
@@ -91,10 +102,13 @@ __all__ = [
     "StyleNotFoundError",
     "StyleType",
     "SupportedDevices",
+    "TextAnalyzer",
     "UseUserDictError",
     "UserDictWord",
     "UserDictWordType",
+    "VerifyModelError",
     "VoiceModelId",
     "WordNotFoundError",
+    "dynamics_volume_scale",
     "ensure_compatible",
 ]
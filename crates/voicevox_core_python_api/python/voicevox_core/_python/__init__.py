@@ -1,7 +1,7 @@
 import dataclasses
 import typing
 from dataclasses import InitVar
-from typing import Literal, NewType, NoReturn, TypeAlias
+from typing import TYPE_CHECKING, Literal, NewType, NoReturn, TypeAlias
 from uuid import UUID
 
 from .._rust import (
@@ -21,6 +21,10 @@ from .._rust import (
 )
 from ._please_do_not_use import _Reserved
 
+if TYPE_CHECKING:
+    import numpy as np
+    from numpy.typing import NDArray
+
 Never: TypeAlias = NoReturn
 
 StyleId = NewType("StyleId", int)
@@ -211,6 +215,54 @@ class SupportedDevices:
     (``DmlExecutionProvider``)に対応する。必要な環境についてはそちらを参照。
     """
 
+    tensorrt: bool
+    """
+    TensorRTが利用可能。
+
+    ONNX Runtimeの `TensorRT Execution Provider <https://onnxruntime.ai/docs/execution-providers/TensorRT-ExecutionProvider.html>`_
+    (``TensorrtExecutionProvider``)に対応する。必要な環境についてはそちらを参照。
+    """
+
+    rocm: bool
+    """
+    ROCmが利用可能。
+
+    ONNX Runtimeの `ROCm Execution Provider <https://onnxruntime.ai/docs/execution-providers/ROCm-ExecutionProvider.html>`_
+    (``ROCMExecutionProvider``)に対応する。必要な環境についてはそちらを参照。
+    """
+
+    coreml: bool
+    """
+    Core MLが利用可能。
+
+    ONNX Runtimeの `Core ML Execution Provider <https://onnxruntime.ai/docs/execution-providers/CoreML-ExecutionProvider.html>`_
+    (``CoreMLExecutionProvider``)に対応する。必要な環境についてはそちらを参照。
+    """
+
+    xnnpack: bool
+    """
+    XNNPACKが利用可能。
+
+    ONNX Runtimeの `XNNPACK Execution Provider <https://onnxruntime.ai/docs/execution-providers/Xnnpack-ExecutionProvider.html>`_
+    (``XnnpackExecutionProvider``)に対応する。GPUを必要とせず、CPU上で動作する。
+    """
+
+    openvino: bool
+    """
+    OpenVINOが利用可能。
+
+    ONNX Runtimeの `OpenVINO Execution Provider <https://onnxruntime.ai/docs/execution-providers/OpenVINO-ExecutionProvider.html>`_
+    (``OpenVINOExecutionProvider``)に対応する。本ライブラリではCPUデバイスとして利用する。
+    """
+
+    onednn: bool
+    """
+    oneDNNが利用可能。
+
+    ONNX Runtimeの `oneDNN Execution Provider <https://onnxruntime.ai/docs/execution-providers/oneDNN-ExecutionProvider.html>`_
+    (``DnnlExecutionProvider``)に対応する。GPUを必要とせず、CPU上で動作する。
+    """
+
     _reserved: InitVar[Never]
 
     def __post_init__(self, reserved: Never) -> None:
@@ -284,6 +336,68 @@ def _(on_existing: OnExistingVoiceModelId):
     _: str = on_existing
 
 
+InferenceOperationKind: TypeAlias = (
+    Literal[
+        "talk.predict_duration",
+        "talk.predict_intonation",
+        "talk.decode",
+        "streaming_talk.predict_duration",
+        "streaming_talk.predict_intonation",
+        "streaming_talk.generate_full_intermediate",
+        "streaming_talk.render_audio_segment",
+        "singing_teacher.predict_sing_consonant_length",
+        "singing_teacher.predict_sing_f0",
+        "singing_teacher.predict_sing_volume",
+        "frame_decode.sf_decode",
+    ]
+    | _Reserved
+)
+"""
+推論の操作。 ``OperationPlacement`` の指定に用いる。
+
+ドメインと操作の名前を ``.`` で繋げたもので表される。
+
+``_Reserved`` は将来のために予約されている値。この値が存在することは決してない。
+"""
+
+
+def _(operation: InferenceOperationKind):
+    _: str = operation
+
+
+OperationDevice: TypeAlias = Literal["CPU", "ACCELERATOR"] | _Reserved
+"""
+推論の操作を行うデバイス。
+
+================= ==========================================================================
+値                説明
+``"CPU"``         CPU。
+``"ACCELERATOR"`` ``AccelerationMode`` によって選ばれたデバイス。 ``"CPU"`` のときや、利用可能な
+                  ものが無かったときはCPUとなる。
+``_Reserved``     将来のために予約されている値。この値が存在することは決してない。
+                  ``str`` のサブタイプであるため、 ``OperationDevice`` を ``str`` として
+                  扱うことは可能。
+================= ==========================================================================
+"""
+
+
+def _(device: OperationDevice):
+    _: str = device
+
+
+@dataclasses.dataclass
+class OperationPlacement:
+    """推論の操作の配置先。"""
+
+    device: OperationDevice
+    """推論を行うデバイス。"""
+
+    cpu_num_threads: int | None = None
+    """
+    CPU利用数。 ``None`` のときはシンセサイザ全体の設定に従う。0を指定すると環境に合わせたCPUが利用される。
+    """
+
+
 @dataclasses.dataclass
 class Mora:
     """
@@ -391,6 +505,33 @@ class AccentPhrase:
         _validate_accent_phrase(self)
 
 
+class TextAnalyzer(typing.Protocol):
+    """
+    テキスト解析器。
+
+    ``OpenJtalk`` の代わりに ``Synthesizer`` に渡すことで、独自のテキスト解析器を用いることができる。
+
+    ``asyncio.Synthesizer`` に渡す場合も :func:`analyze` は同期的なメソッドとし、内部のスレッドプール上で呼ばれる。
+    """
+
+    def analyze(self, text: str) -> list[AccentPhrase]:
+        """
+        日本語のテキストを解析する。
+
+        返り値は ``AccentPhrase`` として検証され、不正である場合は
+        |text-analyzer-analyze-analyze-text-error|_ となる。
+
+        Parameters
+        ----------
+        text
+            日本語のテキスト。
+
+        .. |text-analyzer-analyze-analyze-text-error| replace:: ``AnalyzeTextError``
+        .. _text-analyzer-analyze-analyze-text-error: #voicevox_core.AnalyzeTextError
+        """
+        ...
+
+
 @dataclasses.dataclass
 class AudioQuery:
     """
@@ -612,6 +753,14 @@ x : str
 """
 
 
+Dynamics: TypeAlias = Literal["pp", "p", "mp", "mf", "f", "ff"]
+"""
+音符の強弱。
+
+音量に掛ける倍率は :func:`voicevox_core.dynamics_volume_scale` で得られる。
+"""
+
+
 @dataclasses.dataclass
 class Note:
     """
@@ -651,7 +800,8 @@ class Note:
     """
     歌詞。
 
-    - 音符の場合、一つのモーラを表すひらがな/カタカナ（例: ``"ド"``, ``"ファ"``）。
+    - 音符の場合、一つ以上のモーラを表すひらがな/カタカナ（例: ``"ド"``, ``"ファ"``,
+      ``"きゃっ"``）。 ``"ー"`` は直前のモーラの母音を延ばす。
     - 休符の場合、空文字列。
     """
 
@@ -671,6 +821,21 @@ class Note:
     にコピーされる。歌唱音声には影響しない。
     """
 
+    dynamics: Dynamics | None = None
+    """
+    強弱。
+
+    ``None`` 以外の場合、この音符の区間の音量が :func:`voicevox_core.dynamics_volume_scale` の倍率で拡大または縮小される。
+    ``None`` は ``"mf"`` と同じ。
+    """
+
+    breath: bool = False
+    """
+    ブレスであるかどうか。
+
+    ``True`` の場合、休符の区間を無音ではなく息継ぎの音にする。休符でのみ ``True`` にできる。
+    """
+
     def validate(self) -> None:
         """
         このインスタンスが不正であるときエラーを返す。
@@ -683,6 +848,8 @@ class Note:
             - :attr:`frame_length` が負であるか、もしくは :math:`2^{53}-1` を超過する。
         - :attr:`key` が ``None`` かつ :attr:`lyric` が ``""`` 以外。
         - :attr:`key` が ``int`` かつ :attr:`lyric` が ``""`` 。
+        - :attr:`breath` が ``True`` かつ音符である。
+        - :attr:`lyric` が複数のモーラから成り、かつ :attr:`frame_length` がそのモーラ数より小さい。
 
         送出するエラーは |note-validate-invalid-query-error|_ 。
 
@@ -807,11 +974,19 @@ class FrameAudioQuery:
     を参照。
     """
 
-    f0: list[float]
-    """フレームごとの基本周波数。"""
+    f0: "NDArray[np.float32] | list[float]"
+    """
+    フレームごとの基本周波数。
+
+    このライブラリが返すインスタンスではNumPyの配列になっている。リストも受け付ける。
+    """
 
-    volume: list[float]
-    """フレームごとの音量。"""
+    volume: "NDArray[np.float32] | list[float]"
+    """
+    フレームごとの音量。
+
+    このライブラリが返すインスタンスではNumPyの配列になっている。リストも受け付ける。
+    """
 
     phonemes: list[FramePhoneme]
     """音素のリスト。"""
@@ -4,6 +4,7 @@ if TYPE_CHECKING:
     from voicevox_core import (
         AccentPhrase,
         AudioQuery,
+        Dynamics,
         FrameAudioQuery,
         FramePhoneme,
         Mora,
@@ -59,6 +60,11 @@ class InvalidModelDataError(Exception):
 
     ...
 
+class VerifyModelError(Exception):
+    """音声モデルの検証に失敗した。"""
+
+    ...
+
 class GetSupportedDevicesError(Exception):
     """サポートされているデバイス情報取得に失敗した。"""
 
@@ -161,6 +167,30 @@ def wav_from_s16le(pcm: bytes, sampling_rate: int, is_stereo: bool) -> bytes:
     """
     ...
 
+def dynamics_volume_scale(dynamics: Dynamics) -> float:
+    """
+    強弱に応じて音量に掛ける倍率を得る。 ``"mf"`` を ``1`` とする。
+
+    Parameters
+    ----------
+    dynamics : Dynamics
+        強弱
+
+    Returns
+    -------
+    float
+        倍率
+
+    Examples
+    --------
+    >>> from voicevox_core import dynamics_volume_scale
+    >>> dynamics_volume_scale("mf")
+    1.0
+    >>> dynamics_volume_scale("p") < dynamics_volume_scale("f")
+    True
+    """
+    ...
+
 def ensure_compatible(score: Score, frame_audio_query: FrameAudioQuery) -> None:
     """
     与えられた |ensure-compatible-score|_ と |ensure-compatible-frame-audio-query|_ の組み合わせが、基本周波数と音量の生成に利用できるかどうかを確認する。
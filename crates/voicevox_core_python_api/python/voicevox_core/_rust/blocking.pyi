@@ -1,18 +1,26 @@
+from collections.abc import Mapping, Sequence
 from os import PathLike
 from typing import TYPE_CHECKING, NoReturn, Union
 from uuid import UUID
 
 if TYPE_CHECKING:
+    import numpy as np
+    from numpy.typing import NDArray
+
     from voicevox_core import (
         AccelerationMode,
         AccentPhrase,
         AudioQuery,
         CharacterMeta,
         FrameAudioQuery,
+        InferenceOperationKind,
         OnExistingVoiceModelId,
+        OperationPlacement,
         Score,
         StyleId,
+        StyleType,
         SupportedDevices,
+        TextAnalyzer,
         UserDictWord,
         VoiceModelId,
     )
@@ -33,6 +41,19 @@ class VoiceModelFile:
             VVMファイルへのパス。
         """
         ...
+    @staticmethod
+    def from_bytes(data: bytes) -> VoiceModelFile:
+        """
+        メモリ上のVVMを開く。
+
+        ``data`` はコピーされる。
+
+        Parameters
+        ----------
+        data
+            VVMファイルの中身。
+        """
+        ...
     def __repr__(self) -> str: ...
     def close(self) -> None:
         """
@@ -182,13 +203,49 @@ class OpenJtalk:
         ...
 
 class AudioFeature:
+    """
+    音声合成用の中間表現。
+
+    :func:`Synthesizer.precompute_render` で作り、 :func:`Synthesizer.render`
+    で任意のフレーム区間を音声波形にする。
+    """
+
+    def __new__(cls, *args: object, **kwargs: object) -> NoReturn: ...
     @property
-    def frame_length(self) -> int: ...
+    def frame_length(self) -> int:
+        """全体のフレーム数。"""
+        ...
     @property
-    def frame_rate(self) -> float: ...
+    def frame_rate(self) -> float:
+        """フレームレート。全体の秒数は ``frame_length / frame_rate`` で表せる。"""
+        ...
     def __repr__(self) -> str: ...
     def __eq__(self, other: object) -> bool: ...
 
+class AudioChunks:
+    """
+    音声波形を少しずつ生成するイテレータ。
+
+    :func:`Synthesizer.synthesis_stream` で作られ、 ``chunk_length``
+    フレームごとの音声波形を ``numpy.ndarray[float32]`` として返す。
+
+    チャンクの前後の文脈も考慮して推論するため、チャンクをつなげた音声波形は全体を一度に合成したものとほぼ一致する。
+    ただしクロスフェードは行わないため、継ぎ目でサンプル値がわずかに不連続になりうる。
+    """
+
+    def __new__(cls, *args: object, **kwargs: object) -> NoReturn: ...
+    @property
+    def frame_length(self) -> int:
+        """全体のフレーム数。"""
+        ...
+    @property
+    def frame_rate(self) -> float:
+        """フレームレート。全体の秒数は ``frame_length / frame_rate`` で表せる。"""
+        ...
+    def __iter__(self) -> AudioChunks: ...
+    def __next__(self) -> NDArray[np.float32]: ...
+    def __repr__(self) -> str: ...
+
 class Synthesizer:
     """
     音声シンセサイザ。
@@ -198,20 +255,31 @@ class Synthesizer:
     onnxruntime
         ONNX Runtime。
     open_jtalk
-        Open JTalk。
+        Open JTalk。 :class:`voicevox_core.TextAnalyzer` を満たす任意のオブジェクトを渡すこともできる。
     acceleration_mode
         ハードウェアアクセラレーションモード。
     cpu_num_threads
         CPU利用数を指定。0を指定すると環境に合わせたCPUが利用される。
+    session_replicas
+        推論操作ごとに作成するセッションの数。2以上を指定すると、同じスタイルに対する音声合成を並行して行えるようになる。
+        CPUでは複製のそれぞれが ``cpu_num_threads`` 個のスレッドを用いるため、論理プロセッサの数を超えないように
+        ``cpu_num_threads`` を減らすこと。
+    operation_placements
+        推論の操作ごとの、推論を行うデバイスとCPU利用数。指定しなかった操作については、音素長や音高の推論といった軽いものは
+        CPUで、波形の生成といった重いものは ``"ACCELERATOR"`` で行われる。
     """
 
     def __init__(
         self,
         onnxruntime: Onnxruntime,
-        open_jtalk: OpenJtalk,
+        open_jtalk: OpenJtalk | TextAnalyzer,
         *,
         acceleration_mode: AccelerationMode = "AUTO",
         cpu_num_threads: int = 0,
+        session_replicas: int = 1,
+        operation_placements: (
+            Mapping[InferenceOperationKind, OperationPlacement] | None
+        ) = None,
     ) -> None: ...
     def __repr__(self) -> str: ...
     def __enter__(self) -> "Synthesizer": ...
@@ -221,8 +289,8 @@ class Synthesizer:
         """ONNX Runtime。"""
         ...
     @property
-    def open_jtalk(self) -> OpenJtalk:
-        """Open JTalk。"""
+    def open_jtalk(self) -> OpenJtalk | TextAnalyzer:
+        """Open JTalk、もしくはコンストラクト時に渡されたテキスト解析器。"""
         ...
     @property
     def is_gpu_mode(self) -> bool:
@@ -236,6 +304,9 @@ class Synthesizer:
         model: VoiceModelFile,
         *,
         on_existing: OnExistingVoiceModelId = "ERROR",
+        style_types: Sequence[StyleType] | None = None,
+        style_ids: Sequence[StyleId | int] | None = None,
+        trusted_public_keys: Sequence[bytes] | None = None,
     ) -> None:
         """
         モデルを読み込む。
@@ -246,6 +317,14 @@ class Synthesizer:
             読み込むモデル。
         on_existing
             同じ ``id`` の :class:`VoiceModelFile` が既に読み込まれていたときのふるまい。
+        style_types
+            指定すると、読み込むスタイルをこれらの :class:`StyleType` のものに限定する。残ったスタイルのいずれにも必要とされない推論モデルは読み込まれない。
+        style_ids
+            指定すると、読み込むスタイルをこれらのIDのものに限定する。VVMに含まれないIDを指定したときや、 ``style_types`` との両方を満たすスタイルが無いときは :class:`StyleNotFoundError` となる。
+
+            読み込まれなかったスタイルは :meth:`metas` に含まれず、音声合成に用いようとすると :class:`StyleNotFoundError` となる。
+        trusted_public_keys
+            指定すると、VVMの署名を検証し、これらのEd25519公開鍵（32バイト）のいずれかで署名されたもののみを読み込む。検証に失敗したときは :class:`VerifyModelError` となる。
         """
         ...
     def unload_voice_model(self, voice_model_id: VoiceModelId | UUID) -> None:
@@ -428,19 +507,55 @@ class Synthesizer:
             スタイルID。
         """
         ...
-    def __precompute_render(
+    def precompute_render(
         self,
         audio_query: AudioQuery,
         style_id: StyleId | int,
         *,
         enable_interrogative_upspeak: bool = True,
-    ) -> AudioFeature: ...
-    def __render(
+    ) -> AudioFeature:
+        """
+        :class:`AudioQuery` から音声合成用の中間表現を生成する。
+
+        得られた :class:`AudioFeature` は :func:`render`
+        で、一部の区間ずつ音声波形にすることができる。
+
+        Parameters
+        ----------
+        audio_query
+            :class:`AudioQuery` 。
+        style_id
+            スタイルID。 ``"streaming_talk"`` をサポートしている必要がある。
+        enable_interrogative_upspeak
+            疑問文の調整を有効にするかどうか。
+        """
+        ...
+    def render(
         self,
         audio: AudioFeature,
         start: int,
         stop: int,
-    ) -> bytes: ...
+    ) -> bytes:
+        """
+        中間表現のうち、 ``[start, stop)`` のフレーム区間を16bit PCMで音声波形にする。
+
+        区間の前後の文脈も考慮して推論するため、隣接する区間の結果をつなげたものは全体の結果とほぼ一致する。
+        ただしクロスフェードは行わないため、継ぎ目でサンプル値がわずかに不連続になりうる。
+
+        Parameters
+        ----------
+        audio
+            :func:`precompute_render` で生成した中間表現。
+        start
+            開始フレーム。
+        stop
+            終了フレーム。
+
+        Returns
+        -------
+        16bit PCMの音声波形。
+        """
+        ...
     def synthesis(
         self,
         audio_query: AudioQuery,
@@ -465,6 +580,66 @@ class Synthesizer:
         WAVデータ。
         """
         ...
+    def synthesis_array(
+        self,
+        audio_query: AudioQuery,
+        style_id: StyleId | int,
+        *,
+        enable_interrogative_upspeak: bool = True,
+    ) -> NDArray[np.float32]:
+        """
+        :class:`AudioQuery` から音声合成し、音声波形をNumPyの配列として返す。
+
+        推論中はGILを解放する。
+
+        Parameters
+        ----------
+        audio_query
+            :class:`AudioQuery` 。
+        style_id
+            スタイルID。
+        enable_interrogative_upspeak
+            疑問文の調整を有効にするかどうか。
+
+        Returns
+        -------
+        ``[-1.0, 1.0]`` の範囲の音声波形。 :attr:`AudioQuery.output_stereo`
+        が有効であれば ``(サンプル数, 2)`` の形になる。
+        """
+        ...
+    def synthesis_stream(
+        self,
+        audio_query: AudioQuery,
+        style_id: StyleId | int,
+        *,
+        chunk_length: int | None = None,
+        enable_interrogative_upspeak: bool = True,
+    ) -> AudioChunks:
+        """
+        :class:`AudioQuery` から、音声波形を少しずつ生成するイテレータを作る。
+
+        長いテキストについて、全体の音声合成を待たずに再生を始めたいときに用いる。
+        音声の中間表現の生成はこの関数の中で行い、音声波形の生成はチャンクごとに行う。
+        ``style_id`` のスタイルは ``"streaming_talk"`` をサポートしている必要がある。
+        推論中はGILを解放する。
+
+        .. code-block::
+
+            for wave in synthesizer.synthesis_stream(audio_query, style_id):
+                play(wave)
+
+        Parameters
+        ----------
+        audio_query
+            :class:`AudioQuery` 。
+        style_id
+            スタイルID。
+        chunk_length
+            1チャンクあたりのフレーム数。省略した場合は約1秒分。
+        enable_interrogative_upspeak
+            疑問文の調整を有効にするかどうか。
+        """
+        ...
     def tts_from_kana(
         self,
         kana: str,
@@ -14,6 +14,22 @@ Performance
 """
 
 # pyright: reportMissingModuleSource=false
-from ._rust.asyncio import Onnxruntime, OpenJtalk, Synthesizer, UserDict, VoiceModelFile
+from ._rust.asyncio import (
+    AudioChunks,
+    AudioFeature,
+    Onnxruntime,
+    OpenJtalk,
+    Synthesizer,
+    UserDict,
+    VoiceModelFile,
+)
 
-__all__ = ["Onnxruntime", "OpenJtalk", "Synthesizer", "UserDict", "VoiceModelFile"]
+__all__ = [
+    "AudioChunks",
+    "AudioFeature",
+    "Onnxruntime",
+    "OpenJtalk",
+    "Synthesizer",
+    "UserDict",
+    "VoiceModelFile",
+]
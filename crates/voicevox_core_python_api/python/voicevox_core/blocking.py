@@ -1,10 +1,9 @@
 """ブロッキング版API。"""
 
-# TODO: `AudioFeature`を復活させる
-# https://github.com/VOICEVOX/voicevox_core/issues/970
-
 # pyright: reportMissingModuleSource=false
 from ._rust.blocking import (
+    AudioChunks,
+    AudioFeature,
     Onnxruntime,
     OpenJtalk,
     Synthesizer,
@@ -13,6 +12,8 @@ from ._rust.blocking import (
 )
 
 __all__ = [
+    "AudioChunks",
+    "AudioFeature",
     "Onnxruntime",
     "OpenJtalk",
     "Synthesizer",
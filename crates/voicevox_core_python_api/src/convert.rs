@@ -5,6 +5,7 @@ use derive_more::From;
 use easy_ext::ext;
 use heck::{ToLowerCamelCase as _, ToSnakeCase as _};
 use num_bigint::BigInt;
+use numpy::{PyArray1, PyUntypedArray};
 use pyo3::{
     Bound, FromPyObject, IntoPyObject, PyAny, PyErr, PyResult, Python,
     exceptions::{PyException, PyValueError},
@@ -18,8 +19,9 @@ use serde::{Serialize, de::DeserializeOwned};
 use serde_json::json;
 use voicevox_core::{
     __internal::interop::{self, ToJsonValue as _, Validate},
-    AccelerationMode, AccentPhrase, AudioQuery, FrameAudioQuery, OnExistingVoiceModelId,
-    SupportedDevices, UserDictWord, UserDictWordPriority, VoiceModelMeta,
+    AccelerationMode, AccentPhrase, AudioQuery, Dynamics, FrameAudioQuery, InferenceOperationKind,
+    OnExistingVoiceModelId, OperationPlacement, StyleId, StyleType, SupportedDevices, UserDictWord,
+    UserDictWordPriority, VoiceModelMeta,
 };
 
 use crate::{
@@ -28,7 +30,7 @@ use crate::{
     InvalidModelFormatError, InvalidQueryError, InvalidWordError, LoadUserDictError,
     ModelAlreadyLoadedError, ModelNotFoundError, NotLoadedOpenjtalkDictError, OpenZipFileError,
     ParseKanaError, ReadZipEntryError, RunModelError, SaveUserDictError, StyleAlreadyLoadedError,
-    StyleNotFoundError, UseUserDictError, WordNotFoundError,
+    StyleNotFoundError, UseUserDictError, VerifyModelError, WordNotFoundError,
 };
 
 pub(crate) fn from_acceleration_mode(ob: &Bound<'_, PyAny>) -> PyResult<AccelerationMode> {
@@ -57,6 +59,73 @@ pub(crate) fn from_on_existing_voice_model_id(
     }
 }
 
+pub(crate) fn from_dynamics(ob: &Bound<'_, PyAny>) -> PyResult<Dynamics> {
+    match ob.extract::<&str>()? {
+        "pp" => Ok(Dynamics::Pp),
+        "p" => Ok(Dynamics::P),
+        "mp" => Ok(Dynamics::Mp),
+        "mf" => Ok(Dynamics::Mf),
+        "f" => Ok(Dynamics::F),
+        "ff" => Ok(Dynamics::Ff),
+        dynamics => Err(PyValueError::new_err(format!(
+            "`Dynamics` should be one of {{pp, p, mp, mf, f, ff}}: {dynamics}",
+            dynamics = PyString::new(ob.py(), dynamics).repr()?,
+        ))),
+    }
+}
+
+pub(crate) fn from_operation_placements(
+    ob: &Bound<'_, PyAny>,
+) -> PyResult<Vec<(InferenceOperationKind, OperationPlacement)>> {
+    if ob.is_none() {
+        return Ok(vec![]);
+    }
+    ob.call_method0("items")?
+        .try_iter()?
+        .map(|item| {
+            let (operation, placement) = item?.extract::<(Bound<'_, PyAny>, Bound<'_, PyAny>)>()?;
+            let operation = operation
+                .extract::<&str>()?
+                .parse()
+                .map_err(|err: anyhow::Error| PyValueError::new_err(err.to_string()))?;
+            let placement = serde_pyobject::from_pyobject(dataclasses_asdict(&placement)?)
+                .map_err(|serde_pyobject::Error(cause)| cause)?;
+            Ok((operation, placement))
+        })
+        .collect()
+}
+
+pub(crate) fn from_style_types(ob: &Bound<'_, PyAny>) -> PyResult<Option<Vec<StyleType>>> {
+    if ob.is_none() {
+        return Ok(None);
+    }
+    ob.try_iter()?
+        .map(|style_type| {
+            let style_type = style_type?;
+            let style_type = style_type.extract::<&str>()?;
+            match serde_json::from_value(json!(style_type)) {
+                Ok(style_type) => Ok(style_type),
+                Err(_) => Err(PyValueError::new_err(format!(
+                    "`StyleType` should be one of {{talk, singing_teacher, frame_decode, sing, \
+                     streaming_talk}}: {style_type}",
+                    style_type = PyString::new(ob.py(), style_type).repr()?,
+                ))),
+            }
+        })
+        .collect::<PyResult<_>>()
+        .map(Some)
+}
+
+pub(crate) fn from_style_ids(ob: &Bound<'_, PyAny>) -> PyResult<Option<Vec<StyleId>>> {
+    if ob.is_none() {
+        return Ok(None);
+    }
+    ob.try_iter()?
+        .map(|style_id| Ok(StyleId(style_id?.extract()?)))
+        .collect::<PyResult<_>>()
+        .map(Some)
+}
+
 pub(crate) fn from_audio_query<T: HasCamelCaseFields>(ob: &Bound<'_, PyAny>) -> PyResult<T> {
     let py = ob.py();
 
@@ -291,6 +360,21 @@ impl RustData for FrameAudioQuery {
                     .map(|frame_phoneme| frame_phoneme_cls.call((), Some(frame_phoneme.cast()?)))
                     .collect::<Result<Vec<_>, _>>()?,
             )?;
+            // TODO: typed_floatsにissueかPRを出しに行き、スライス変換かbytemuck対応を入れてもらう
+            kwargs.set_item(
+                "f0",
+                PyArray1::from_vec(py, self.f0.iter().map(|&f0| f32::from(f0)).collect()),
+            )?;
+            kwargs.set_item(
+                "volume",
+                PyArray1::from_vec(
+                    py,
+                    self.volume
+                        .iter()
+                        .map(|&volume| f32::from(volume))
+                        .collect(),
+                ),
+            )?;
             for key in kwargs.keys().iter() {
                 let key = key.cast::<PyString>()?.to_str()?;
                 let key_rename = key.to_snake_case();
@@ -318,7 +402,15 @@ pub(crate) fn from_query_like_via_serde<T: Validate>(instance: &Bound<'_, PyAny>
 fn dataclasses_asdict<'py>(instance: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyDict>> {
     let py = instance.py();
     let asdict = py.import("dataclasses")?.getattr("asdict")?;
-    asdict.call1((instance,))?.cast_into().map_err(Into::into)
+    let fields = asdict.call1((instance,))?.cast_into::<PyDict>()?;
+
+    // `FrameAudioQuery.f0`などでは、リストの代わりにNumPyの配列も受け付ける
+    for (key, value) in fields.copy()?.iter() {
+        if value.is_instance_of::<PyUntypedArray>() {
+            fields.set_item(key, value.call_method0("tolist")?)?;
+        }
+    }
+    Ok(fields)
 }
 
 fn to_dataclass_via_serde<'py>(
@@ -434,6 +526,7 @@ pub(crate) impl<T> voicevox_core::Result<T> {
                 ErrorKind::StyleAlreadyLoaded => StyleAlreadyLoadedError::new_err(msg),
                 ErrorKind::InvalidModelFormat => InvalidModelFormatError::new_err(msg),
                 ErrorKind::InvalidModelData => InvalidModelDataError::new_err(msg),
+                ErrorKind::VerifyModel => VerifyModelError::new_err(msg),
                 ErrorKind::GetSupportedDevices => GetSupportedDevicesError::new_err(msg),
                 ErrorKind::StyleNotFound => StyleNotFoundError::new_err(msg),
                 ErrorKind::ModelNotFound => ModelNotFoundError::new_err(msg),
@@ -472,7 +565,7 @@ pub(crate) impl<T> voicevox_core::Result<T> {
 impl SupportedDevices {
     fn to_py(self, py: Python<'_>) -> PyResult<Bound<'_, PyAny>> {
         assert!(match self.to_json_value() {
-            serde_json::Value::Object(o) => o.len() == 3, // `cpu`, `cuda`, `dml`
+            serde_json::Value::Object(o) => o.len() == 9, // `cpu`, `cuda`, `dml`, `tensorrt`, `rocm`, `coreml`, `xnnpack`, `openvino`, `onednn`
             _ => false,
         });
 
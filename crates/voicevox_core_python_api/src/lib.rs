@@ -11,9 +11,10 @@ use self::convert::{AudioQueryExt as _, ToDataclass, from_utf8_path};
 use easy_ext::ext;
 use log::{debug, warn};
 use macros::pyproject_project_version;
+use numpy::{PyArray1, PyArrayMethods as _};
 use pyo3::{
     Bound, Py, PyAny, PyResult, PyTypeInfo, Python, create_exception,
-    exceptions::{PyException, PyKeyError, PyValueError},
+    exceptions::{PyException, PyKeyError, PyTypeError, PyValueError},
     pyclass, pyfunction, pymodule,
     types::{PyAnyMethods as _, PyList, PyModule, PyModuleMethods as _, PyString},
     wrap_pyfunction,
@@ -44,6 +45,7 @@ fn rust(py: Python<'_>, module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add_wrapped(wrap_pyfunction!(_to_zenkaku))?;
     module.add_wrapped(wrap_pyfunction!(wav_from_s16le))?;
     module.add_wrapped(wrap_pyfunction!(ensure_compatible))?;
+    module.add_wrapped(wrap_pyfunction!(dynamics_volume_scale))?;
 
     add_exceptions(module)?;
 
@@ -54,6 +56,7 @@ fn rust(py: Python<'_>, module: &Bound<'_, PyModule>) -> PyResult<()> {
     blocking_module.add_class::<self::blocking::VoiceModelFile>()?;
     blocking_module.add_class::<self::blocking::UserDict>()?;
     blocking_module.add_class::<self::blocking::AudioFeature>()?;
+    blocking_module.add_class::<self::blocking::AudioChunks>()?;
     module.add_and_register_submodule(blocking_module)?;
 
     let asyncio_module = PyModule::new(py, "voicevox_core._rust.asyncio")?;
@@ -62,6 +65,8 @@ fn rust(py: Python<'_>, module: &Bound<'_, PyModule>) -> PyResult<()> {
     asyncio_module.add_class::<self::asyncio::OpenJtalk>()?;
     asyncio_module.add_class::<self::asyncio::VoiceModelFile>()?;
     asyncio_module.add_class::<self::asyncio::UserDict>()?;
+    asyncio_module.add_class::<self::asyncio::AudioFeature>()?;
+    asyncio_module.add_class::<self::asyncio::AudioChunks>()?;
     module.add_and_register_submodule(asyncio_module)
 }
 
@@ -102,6 +107,7 @@ exceptions! {
     StyleAlreadyLoadedError: PyException;
     InvalidModelFormatError: PyException;
     InvalidModelDataError: PyException;
+    VerifyModelError: PyException;
     GetSupportedDevicesError: PyException;
     StyleNotFoundError: PyKeyError;
     ModelNotFoundError: PyKeyError;
@@ -267,6 +273,41 @@ impl VoiceModelFilePyFields {
     }
 }
 
+/// `analyze`メソッドを持つ、Pythonのオブジェクトとしてのテキスト解析器。
+#[derive(Debug)]
+struct ForeignTextAnalyzer(Py<PyAny>);
+
+impl ForeignTextAnalyzer {
+    fn new(text_analyzer: Bound<'_, PyAny>) -> PyResult<Self> {
+        if !text_analyzer.hasattr("analyze")? {
+            return Err(PyTypeError::new_err(format!(
+                "expected `OpenJtalk` or an object with `analyze` method, got {}",
+                text_analyzer.repr()?,
+            )));
+        }
+        Ok(Self(text_analyzer.unbind()))
+    }
+}
+
+impl voicevox_core::blocking::TextAnalyzer for ForeignTextAnalyzer {
+    fn analyze(&self, text: &str) -> anyhow::Result<Vec<AccentPhrase>> {
+        Python::attach(|py| {
+            let accent_phrases = self.0.bind(py).call_method1("analyze", (text,))?;
+            convert::from_accent_phrases(&accent_phrases)
+        })
+        .map_err(Into::into)
+    }
+}
+
+impl voicevox_core::nonblocking::TextAnalyzer for ForeignTextAnalyzer {
+    async fn analyze(&self, text: &str) -> anyhow::Result<Vec<AccentPhrase>> {
+        let this = Python::attach(|py| Self(self.0.clone_ref(py)));
+        let text = text.to_owned();
+        ::blocking::unblock(move || voicevox_core::blocking::TextAnalyzer::analyze(&this, &text))
+            .await
+    }
+}
+
 #[pyclass(frozen)]
 struct _ReservedFields;
 
@@ -371,27 +412,58 @@ fn ensure_compatible(
     voicevox_core::ensure_compatible(&score, &frame_audio_query).into_py_result(py)
 }
 
+#[pyfunction]
+fn dynamics_volume_scale(
+    #[pyo3(from_py_with = convert::from_dynamics)] dynamics: voicevox_core::Dynamics,
+) -> f32 {
+    dynamics.volume_scale()
+}
+
+/// `synthesis_stream`で`chunk_length`が指定されなかったときの、1チャンクあたりのフレーム数。約1秒。
+fn default_chunk_length(frame_rate: f64) -> usize {
+    (frame_rate.round() as usize).max(1)
+}
+
+/// 音声波形を`numpy.ndarray[float32]`にする。ステレオであれば`(サンプル数, 2)`の形にする。
+fn to_ndarray(py: Python<'_>, wave: Vec<f32>, is_stereo: bool) -> PyResult<Bound<'_, PyAny>> {
+    let len = wave.len();
+    let wave = PyArray1::from_vec(py, wave);
+    if is_stereo {
+        return Ok(wave.reshape([len / 2, 2])?.into_any());
+    }
+    Ok(wave.into_any())
+}
+
 mod blocking {
-    use std::{ffi::OsString, path::PathBuf, sync::Arc};
+    use std::{
+        ffi::OsString,
+        num::NonZero,
+        path::PathBuf,
+        sync::{Arc, Mutex},
+    };
 
     use camino::Utf8PathBuf;
     use pyo3::{
-        Bound, IntoPyObject as _, Py, PyAny, PyRef, PyResult, PyTypeInfo as _, Python,
+        Bound, IntoPyObject as _, Py, PyAny, PyErr, PyRef, PyResult, PyTypeInfo as _, Python,
         exceptions::{PyIndexError, PyTypeError, PyValueError},
         pyclass, pymethods,
         sync::PyOnceLock,
-        types::{IntoPyDict as _, PyAnyMethods as _, PyDict, PyList, PyString, PyTuple, PyType},
+        types::{
+            IntoPyDict as _, PyAnyMethods as _, PyBytes, PyBytesMethods as _, PyDict, PyList,
+            PyString, PyTuple, PyType,
+        },
     };
     use ref_cast::RefCast as _;
     use uuid::Uuid;
     use voicevox_core::{
         __internal::interop::BlockingTextAnalyzerExt as _, AccelerationMode, AccentPhrase,
-        AudioQuery, FrameAudioQuery, OnExistingVoiceModelId, Score, StyleId, SupportedDevices,
-        UserDictWord, VoiceModelMeta,
+        AudioQuery, FrameAudioQuery, InferenceOperationKind, OnExistingVoiceModelId,
+        OperationPlacement, Score, StyleId, StyleType, SupportedDevices, UserDictWord,
+        VoiceModelMeta,
     };
 
     use crate::{
-        Closable, SingleTasked, VoiceModelFilePyFields,
+        Closable, ForeignTextAnalyzer, SingleTasked, VoiceModelFilePyFields,
         convert::{ToDataclass, VoicevoxCoreResultExt as _},
     };
 
@@ -420,18 +492,16 @@ mod blocking {
         #[staticmethod]
         fn open(py: Python<'_>, path: PathBuf) -> PyResult<Self> {
             let model = voicevox_core::blocking::VoiceModelFile::open(path).into_py_result(py)?;
+            Self::new_(py, model)
+        }
 
-            let id = model.id().0.into_pyobject(py)?.into();
-            let metas = ToDataclass::ref_cast(model.metas())
-                .into_pyobject(py)?
-                .into();
-
-            let model = Closable::new(model);
-
-            Ok(Self {
-                model,
-                fields: VoiceModelFilePyFields { id, metas },
-            })
+        #[staticmethod]
+        fn from_bytes(data: &Bound<'_, PyBytes>) -> PyResult<Self> {
+            let py = data.py();
+            let model =
+                voicevox_core::blocking::VoiceModelFile::from_bytes(data.as_bytes().to_owned())
+                    .into_py_result(py)?;
+            Self::new_(py, model)
         }
 
         fn __repr__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyString>> {
@@ -489,6 +559,20 @@ mod blocking {
         }
     }
 
+    impl VoiceModelFile {
+        fn new_(py: Python<'_>, model: voicevox_core::blocking::VoiceModelFile) -> PyResult<Self> {
+            let id = model.id().0.into_pyobject(py)?.into();
+            let metas = ToDataclass::ref_cast(model.metas())
+                .into_pyobject(py)?
+                .into();
+            let model = Closable::new(model);
+            Ok(Self {
+                model,
+                fields: VoiceModelFilePyFields { id, metas },
+            })
+        }
+    }
+
     static ONNXRUNTIME: PyOnceLock<Py<Onnxruntime>> = PyOnceLock::new();
 
     #[pyclass(frozen)]
@@ -632,12 +716,37 @@ mod blocking {
     }
 
     #[derive(derive_more::Debug)]
-    #[debug("{:?}", _0.get())]
-    struct OwnedOpenJtalk(Py<OpenJtalk>);
+    enum OwnedTextAnalyzer {
+        #[debug("{:?}", _0.get())]
+        OpenJtalk(Py<OpenJtalk>),
+        #[debug("{_0:?}")]
+        Foreign(ForeignTextAnalyzer),
+    }
+
+    impl OwnedTextAnalyzer {
+        fn new(text_analyzer: Bound<'_, PyAny>) -> PyResult<Self> {
+            match text_analyzer.cast_into::<OpenJtalk>() {
+                Ok(open_jtalk) => Ok(Self::OpenJtalk(open_jtalk.unbind())),
+                Err(err) => ForeignTextAnalyzer::new(err.into_inner()).map(Self::Foreign),
+            }
+        }
+
+        fn to_py(&self, py: Python<'_>) -> Py<PyAny> {
+            match self {
+                Self::OpenJtalk(open_jtalk) => open_jtalk.clone_ref(py).into_any(),
+                Self::Foreign(ForeignTextAnalyzer(text_analyzer)) => text_analyzer.clone_ref(py),
+            }
+        }
+    }
 
-    impl voicevox_core::blocking::TextAnalyzer for OwnedOpenJtalk {
+    impl voicevox_core::blocking::TextAnalyzer for OwnedTextAnalyzer {
         fn analyze(&self, text: &str) -> anyhow::Result<Vec<AccentPhrase>> {
-            self.0.get().open_jtalk.analyze(text)
+            match self {
+                Self::OpenJtalk(open_jtalk) => open_jtalk.get().open_jtalk.analyze(text),
+                Self::Foreign(text_analyzer) => {
+                    voicevox_core::blocking::TextAnalyzer::analyze(text_analyzer, text)
+                }
+            }
         }
     }
 
@@ -669,10 +778,69 @@ mod blocking {
         }
     }
 
+    #[pyclass(frozen)]
+    pub(crate) struct AudioChunks {
+        synthesizer: Py<Synthesizer>,
+        audio: voicevox_core::blocking::__AudioFeature,
+        is_stereo: bool,
+        chunk_length: usize,
+        position: Mutex<usize>,
+    }
+
+    #[pymethods]
+    impl AudioChunks {
+        #[getter]
+        fn frame_length(&self) -> usize {
+            self.audio.frame_length()
+        }
+
+        #[getter]
+        fn frame_rate(&self) -> f64 {
+            self.audio.frame_rate
+        }
+
+        fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+            slf
+        }
+
+        fn __next__<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyAny>>> {
+            let wave = py.detach(|| {
+                let mut position = self.position.lock().unwrap_or_else(|e| panic!("{e}"));
+                let stop = usize::min(*position + self.chunk_length, self.audio.frame_length());
+                if *position == stop {
+                    return Ok::<_, PyErr>(None);
+                }
+                let wave = self
+                    .synthesizer
+                    .get()
+                    .synthesizer
+                    .read()?
+                    .__render_wave(&self.audio, *position..stop);
+                if wave.is_ok() {
+                    *position = stop;
+                }
+                Ok(Some(wave))
+            })?;
+            let Some(wave) = wave else {
+                return Ok(None);
+            };
+            let wave = wave.into_py_result(py)?;
+            crate::to_ndarray(py, wave, self.is_stereo).map(Some)
+        }
+
+        fn __repr__(&self, py: Python<'_>) -> String {
+            let rust_api = PyString::new(py, &format!("{:?}", self.audio));
+            format!(
+                "<voicevox_core.blocking.{NAME} rust_api=<{rust_api:?}>>",
+                NAME = Self::NAME,
+            )
+        }
+    }
+
     #[pyclass(frozen)]
     pub(crate) struct Synthesizer {
         synthesizer:
-            Closable<voicevox_core::blocking::Synthesizer<OwnedOpenJtalk>, Self, SingleTasked>,
+            Closable<voicevox_core::blocking::Synthesizer<OwnedTextAnalyzer>, Self, SingleTasked>,
     }
 
     #[pymethods]
@@ -684,19 +852,32 @@ mod blocking {
             *,
             acceleration_mode = Default::default(),
             cpu_num_threads = voicevox_core::__internal::interop::DEFAULT_CPU_NUM_THREADS,
+            session_replicas = voicevox_core::__internal::interop::DEFAULT_SESSION_REPLICAS,
+            operation_placements = None,
         ))]
         fn new(
             onnxruntime: Onnxruntime,
-            open_jtalk: Py<OpenJtalk>,
+            open_jtalk: Bound<'_, PyAny>,
             #[pyo3(from_py_with = crate::convert::from_acceleration_mode)]
             acceleration_mode: AccelerationMode,
             cpu_num_threads: u16,
+            session_replicas: NonZero<usize>,
+            #[pyo3(from_py_with = crate::convert::from_operation_placements)]
+            operation_placements: Vec<(InferenceOperationKind, OperationPlacement)>,
             py: Python<'_>,
         ) -> PyResult<Self> {
-            let inner = voicevox_core::blocking::Synthesizer::builder(onnxruntime.0)
-                .text_analyzer(OwnedOpenJtalk(open_jtalk))
-                .acceleration_mode(acceleration_mode)
-                .cpu_num_threads(cpu_num_threads)
+            let inner = operation_placements
+                .into_iter()
+                .fold(
+                    voicevox_core::blocking::Synthesizer::builder(onnxruntime.0)
+                        .text_analyzer(OwnedTextAnalyzer::new(open_jtalk)?)
+                        .acceleration_mode(acceleration_mode)
+                        .cpu_num_threads(cpu_num_threads)
+                        .session_replicas(session_replicas),
+                    |builder, (operation, placement)| {
+                        builder.operation_placement(operation, placement)
+                    },
+                )
                 .build()
                 .into_py_result(py)?;
             Ok(Self {
@@ -747,9 +928,9 @@ mod blocking {
         }
 
         #[getter]
-        fn open_jtalk(&self, py: Python<'_>) -> PyResult<Py<OpenJtalk>> {
+        fn open_jtalk(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
             let this = self.synthesizer.read()?;
-            Ok(this.text_analyzer().0.clone_ref(py))
+            Ok(this.text_analyzer().to_py(py))
         }
 
         #[getter]
@@ -763,20 +944,39 @@ mod blocking {
             Ok(synthesizer.metas().into())
         }
 
-        #[pyo3(signature = (model, *, on_existing = Default::default()))]
+        #[pyo3(signature = (
+            model,
+            *,
+            on_existing = Default::default(),
+            style_types = None,
+            style_ids = None,
+            trusted_public_keys = None,
+        ))]
         fn load_voice_model(
             &self,
             model: &Bound<'_, VoiceModelFile>,
             #[pyo3(from_py_with = crate::convert::from_on_existing_voice_model_id)]
             on_existing: OnExistingVoiceModelId,
+            #[pyo3(from_py_with = crate::convert::from_style_types)] style_types: Option<
+                Vec<StyleType>,
+            >,
+            #[pyo3(from_py_with = crate::convert::from_style_ids)] style_ids: Option<Vec<StyleId>>,
+            trusted_public_keys: Option<Vec<[u8; 32]>>,
             py: Python<'_>,
         ) -> PyResult<()> {
             let this = self.synthesizer.read()?;
             let model = &model.get().model.read()?;
-            this.load_voice_model(model)
-                .on_existing(on_existing)
-                .perform()
-                .into_py_result(py)
+            let mut load = this.load_voice_model(model).on_existing(on_existing);
+            if let Some(style_types) = style_types {
+                load = load.style_types(style_types);
+            }
+            if let Some(style_ids) = style_ids {
+                load = load.style_ids(style_ids);
+            }
+            if let Some(trusted_public_keys) = trusted_public_keys {
+                load = load.trusted_public_keys(trusted_public_keys);
+            }
+            load.perform().into_py_result(py)
         }
 
         fn unload_voice_model(&self, voice_model_id: Uuid, py: Python<'_>) -> PyResult<()> {
@@ -894,9 +1094,6 @@ mod blocking {
                 .into_py_result(py)
         }
 
-        // TODO: 後で復活させる
-        // https://github.com/VOICEVOX/voicevox_core/issues/970
-        #[allow(non_snake_case)]
         #[pyo3(signature=(
             audio_query,
             style_id,
@@ -904,7 +1101,7 @@ mod blocking {
             enable_interrogative_upspeak =
                 voicevox_core::__internal::interop::DEFAULT_ENABLE_INTERROGATIVE_UPSPEAK,
         ))]
-        fn _Synthesizer__precompute_render(
+        fn precompute_render(
             &self,
             #[pyo3(from_py_with = crate::convert::from_audio_query)] audio_query: AudioQuery,
             style_id: u32,
@@ -921,10 +1118,7 @@ mod blocking {
             Ok(AudioFeature { audio })
         }
 
-        // TODO: 後で復活させる
-        // https://github.com/VOICEVOX/voicevox_core/issues/970
-        #[allow(non_snake_case)]
-        fn _Synthesizer__render(
+        fn render(
             &self,
             audio: &AudioFeature,
             start: usize,
@@ -970,6 +1164,76 @@ mod blocking {
                 .into_py_result(py)
         }
 
+        #[pyo3(signature=(
+            audio_query,
+            style_id,
+            *,
+            enable_interrogative_upspeak =
+                voicevox_core::__internal::interop::DEFAULT_ENABLE_INTERROGATIVE_UPSPEAK,
+        ))]
+        fn synthesis_array<'py>(
+            &self,
+            #[pyo3(from_py_with = crate::convert::from_audio_query)] audio_query: AudioQuery,
+            style_id: u32,
+            enable_interrogative_upspeak: bool,
+            py: Python<'py>,
+        ) -> PyResult<Bound<'py, PyAny>> {
+            let wave = py
+                .detach(|| {
+                    Ok::<_, PyErr>(
+                        self.synthesizer
+                            .read()?
+                            .synthesis(&audio_query, StyleId::new(style_id))
+                            .enable_interrogative_upspeak(enable_interrogative_upspeak)
+                            .__perform_wave(),
+                    )
+                })?
+                .into_py_result(py)?;
+            crate::to_ndarray(py, wave, audio_query.output_stereo)
+        }
+
+        #[pyo3(signature=(
+            audio_query,
+            style_id,
+            *,
+            chunk_length = None,
+            enable_interrogative_upspeak =
+                voicevox_core::__internal::interop::DEFAULT_ENABLE_INTERROGATIVE_UPSPEAK,
+        ))]
+        fn synthesis_stream(
+            slf: Bound<'_, Self>,
+            #[pyo3(from_py_with = crate::convert::from_audio_query)] audio_query: AudioQuery,
+            style_id: u32,
+            chunk_length: Option<usize>,
+            enable_interrogative_upspeak: bool,
+            py: Python<'_>,
+        ) -> PyResult<AudioChunks> {
+            if chunk_length == Some(0) {
+                return Err(PyValueError::new_err("`chunk_length` must be positive"));
+            }
+            let this = slf.get();
+            let audio = py
+                .detach(|| {
+                    Ok::<_, PyErr>(
+                        this.synthesizer
+                            .read()?
+                            .__precompute_render(&audio_query, StyleId::new(style_id))
+                            .enable_interrogative_upspeak(enable_interrogative_upspeak)
+                            .perform(),
+                    )
+                })?
+                .into_py_result(py)?;
+            let chunk_length =
+                chunk_length.unwrap_or_else(|| crate::default_chunk_length(audio.frame_rate));
+            Ok(AudioChunks {
+                synthesizer: slf.unbind(),
+                audio,
+                is_stereo: audio_query.output_stereo,
+                chunk_length,
+                position: Default::default(),
+            })
+        }
+
         #[pyo3(signature=(
             kana,
             style_id,
@@ -1155,26 +1419,30 @@ mod blocking {
 }
 
 mod asyncio {
-    use std::{ffi::OsString, path::PathBuf, sync::Arc};
+    use std::{ffi::OsString, num::NonZero, path::PathBuf, sync::Arc};
 
     use camino::Utf8PathBuf;
     use pyo3::{
         Bound, IntoPyObject as _, Py, PyAny, PyErr, PyRef, PyResult, PyTypeInfo as _, Python,
-        exceptions::PyTypeError,
+        exceptions::{PyIndexError, PyStopAsyncIteration, PyTypeError, PyValueError},
         pyclass, pymethods,
         sync::PyOnceLock,
-        types::{IntoPyDict as _, PyAnyMethods as _, PyDict, PyList, PyString, PyTuple, PyType},
+        types::{
+            IntoPyDict as _, PyAnyMethods as _, PyBytes, PyBytesMethods as _, PyDict, PyList,
+            PyString, PyTuple, PyType,
+        },
     };
     use ref_cast::RefCast as _;
     use uuid::Uuid;
     use voicevox_core::{
         __internal::interop::NonblockingTextAnalyzerExt as _, AccelerationMode, AccentPhrase,
-        AudioQuery, FrameAudioQuery, OnExistingVoiceModelId, Score, StyleId, SupportedDevices,
-        UserDictWord, VoiceModelMeta,
+        AudioQuery, FrameAudioQuery, InferenceOperationKind, OnExistingVoiceModelId,
+        OperationPlacement, Score, StyleId, StyleType, SupportedDevices, UserDictWord,
+        VoiceModelMeta,
     };
 
     use crate::{
-        Closable, Tokio, VoiceModelFilePyFields,
+        Closable, ForeignTextAnalyzer, Tokio, VoiceModelFilePyFields,
         convert::{ToDataclass, VoicevoxCoreResultExt as _},
     };
 
@@ -1203,21 +1471,14 @@ mod asyncio {
         #[staticmethod]
         async fn open(path: PathBuf) -> PyResult<Self> {
             let model = voicevox_core::nonblocking::VoiceModelFile::open(path).await;
-            let (model, id, metas) = Python::attach(|py| {
-                let model = Python::attach(|py| model.into_py_result(py))?;
-                let id = model.id().0.into_pyobject(py)?.into();
-                let metas = ToDataclass::ref_cast(model.metas())
-                    .into_pyobject(py)?
-                    .into();
-                Ok::<_, PyErr>((model, id, metas))
-            })?;
-
-            let model = Closable::new(model);
+            Self::new_(model)
+        }
 
-            Ok(Self {
-                model,
-                fields: VoiceModelFilePyFields { id, metas },
-            })
+        #[staticmethod]
+        async fn from_bytes(data: Py<PyBytes>) -> PyResult<Self> {
+            let data = Python::attach(|py| data.bind(py).as_bytes().to_owned());
+            let model = voicevox_core::nonblocking::VoiceModelFile::from_bytes(data).await;
+            Self::new_(model)
         }
 
         fn __repr__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyString>> {
@@ -1270,6 +1531,28 @@ mod asyncio {
         }
     }
 
+    impl VoiceModelFile {
+        fn new_(
+            model: voicevox_core::Result<voicevox_core::nonblocking::VoiceModelFile>,
+        ) -> PyResult<Self> {
+            let (model, id, metas) = Python::attach(|py| {
+                let model = model.into_py_result(py)?;
+                let id = model.id().0.into_pyobject(py)?.into();
+                let metas = ToDataclass::ref_cast(model.metas())
+                    .into_pyobject(py)?
+                    .into();
+                Ok::<_, PyErr>((model, id, metas))
+            })?;
+
+            let model = Closable::new(model);
+
+            Ok(Self {
+                model,
+                fields: VoiceModelFilePyFields { id, metas },
+            })
+        }
+    }
+
     static ONNXRUNTIME: PyOnceLock<Py<Onnxruntime>> = PyOnceLock::new();
 
     #[pyclass(frozen)]
@@ -1418,19 +1701,132 @@ mod asyncio {
     }
 
     #[derive(derive_more::Debug)]
-    #[debug("{:?}", _0.get())]
-    struct OwnedOpenJtalk(Py<OpenJtalk>);
+    enum OwnedTextAnalyzer {
+        #[debug("{:?}", _0.get())]
+        OpenJtalk(Py<OpenJtalk>),
+        #[debug("{_0:?}")]
+        Foreign(ForeignTextAnalyzer),
+    }
+
+    impl OwnedTextAnalyzer {
+        fn new(text_analyzer: Bound<'_, PyAny>) -> PyResult<Self> {
+            match text_analyzer.cast_into::<OpenJtalk>() {
+                Ok(open_jtalk) => Ok(Self::OpenJtalk(open_jtalk.unbind())),
+                Err(err) => ForeignTextAnalyzer::new(err.into_inner()).map(Self::Foreign),
+            }
+        }
 
-    impl voicevox_core::nonblocking::TextAnalyzer for OwnedOpenJtalk {
+        fn to_py(&self, py: Python<'_>) -> Py<PyAny> {
+            match self {
+                Self::OpenJtalk(open_jtalk) => open_jtalk.clone_ref(py).into_any(),
+                Self::Foreign(ForeignTextAnalyzer(text_analyzer)) => text_analyzer.clone_ref(py),
+            }
+        }
+    }
+
+    impl voicevox_core::nonblocking::TextAnalyzer for OwnedTextAnalyzer {
         async fn analyze(&self, text: &str) -> anyhow::Result<Vec<AccentPhrase>> {
-            self.0.get().open_jtalk.analyze(text).await
+            match self {
+                Self::OpenJtalk(open_jtalk) => open_jtalk.get().open_jtalk.analyze(text).await,
+                Self::Foreign(text_analyzer) => {
+                    voicevox_core::nonblocking::TextAnalyzer::analyze(text_analyzer, text).await
+                }
+            }
+        }
+    }
+
+    #[pyclass(frozen, eq)]
+    #[derive(PartialEq)]
+    pub(crate) struct AudioFeature {
+        audio: voicevox_core::nonblocking::__AudioFeature,
+    }
+
+    #[pymethods]
+    impl AudioFeature {
+        #[getter]
+        fn frame_length(&self) -> usize {
+            self.audio.frame_length()
+        }
+
+        #[getter]
+        fn frame_rate(&self) -> f64 {
+            self.audio.frame_rate
+        }
+
+        fn __repr__(&self, py: Python<'_>) -> String {
+            let Self { audio: rust_api } = self;
+            let rust_api = PyString::new(py, &format!("{rust_api:?}"));
+            format!(
+                "<voicevox_core.asyncio.{NAME} rust_api=<{rust_api:?}>>",
+                NAME = Self::NAME,
+            )
+        }
+    }
+
+    #[pyclass(frozen)]
+    pub(crate) struct AudioChunks {
+        synthesizer: Arc<
+            Closable<
+                voicevox_core::nonblocking::Synthesizer<OwnedTextAnalyzer>,
+                Synthesizer,
+                Tokio,
+            >,
+        >,
+        audio: voicevox_core::nonblocking::__AudioFeature,
+        is_stereo: bool,
+        chunk_length: usize,
+        position: async_lock::Mutex<usize>,
+    }
+
+    #[pymethods]
+    impl AudioChunks {
+        #[getter]
+        fn frame_length(&self) -> usize {
+            self.audio.frame_length()
+        }
+
+        #[getter]
+        fn frame_rate(&self) -> f64 {
+            self.audio.frame_rate
+        }
+
+        fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+            slf
+        }
+
+        async fn __anext__(&self) -> PyResult<Py<PyAny>> {
+            let mut position = self.position.lock().await;
+            let stop = usize::min(*position + self.chunk_length, self.audio.frame_length());
+            if *position == stop {
+                return Err(PyStopAsyncIteration::new_err(()));
+            }
+            let wave = self
+                .synthesizer
+                .read()?
+                .__render_wave(&self.audio, *position..stop)
+                .await;
+            if wave.is_ok() {
+                *position = stop;
+            }
+            Python::attach(|py| {
+                let wave = wave.into_py_result(py)?;
+                crate::to_ndarray(py, wave, self.is_stereo).map(Bound::unbind)
+            })
+        }
+
+        fn __repr__(&self, py: Python<'_>) -> String {
+            let rust_api = PyString::new(py, &format!("{:?}", self.audio));
+            format!(
+                "<voicevox_core.asyncio.{NAME} rust_api=<{rust_api:?}>>",
+                NAME = Self::NAME,
+            )
         }
     }
 
     #[pyclass(frozen)]
     pub(crate) struct Synthesizer {
         synthesizer:
-            Arc<Closable<voicevox_core::nonblocking::Synthesizer<OwnedOpenJtalk>, Self, Tokio>>,
+            Arc<Closable<voicevox_core::nonblocking::Synthesizer<OwnedTextAnalyzer>, Self, Tokio>>,
     }
 
     #[pymethods]
@@ -1442,18 +1838,31 @@ mod asyncio {
             *,
             acceleration_mode = Default::default(),
             cpu_num_threads = voicevox_core::__internal::interop::DEFAULT_CPU_NUM_THREADS,
+            session_replicas = voicevox_core::__internal::interop::DEFAULT_SESSION_REPLICAS,
+            operation_placements = None,
         ))]
         fn new(
             onnxruntime: Onnxruntime,
-            open_jtalk: Py<OpenJtalk>,
+            open_jtalk: Bound<'_, PyAny>,
             #[pyo3(from_py_with = crate::convert::from_acceleration_mode)]
             acceleration_mode: AccelerationMode,
             cpu_num_threads: u16,
+            session_replicas: NonZero<usize>,
+            #[pyo3(from_py_with = crate::convert::from_operation_placements)]
+            operation_placements: Vec<(InferenceOperationKind, OperationPlacement)>,
         ) -> PyResult<Self> {
-            let synthesizer = voicevox_core::nonblocking::Synthesizer::builder(onnxruntime.0)
-                .text_analyzer(OwnedOpenJtalk(open_jtalk))
-                .acceleration_mode(acceleration_mode)
-                .cpu_num_threads(cpu_num_threads)
+            let synthesizer = operation_placements
+                .into_iter()
+                .fold(
+                    voicevox_core::nonblocking::Synthesizer::builder(onnxruntime.0)
+                        .text_analyzer(OwnedTextAnalyzer::new(open_jtalk)?)
+                        .acceleration_mode(acceleration_mode)
+                        .cpu_num_threads(cpu_num_threads)
+                        .session_replicas(session_replicas),
+                    |builder, (operation, placement)| {
+                        builder.operation_placement(operation, placement)
+                    },
+                )
                 .build();
             let synthesizer = Python::attach(|py| synthesizer.into_py_result(py))?;
             let synthesizer = Closable::new(synthesizer).into();
@@ -1496,9 +1905,9 @@ mod asyncio {
         }
 
         #[getter]
-        fn open_jtalk(&self, py: Python<'_>) -> PyResult<Py<OpenJtalk>> {
+        fn open_jtalk(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
             let this = self.synthesizer.read()?;
-            Ok(this.text_analyzer().0.clone_ref(py))
+            Ok(this.text_analyzer().to_py(py))
         }
 
         #[getter]
@@ -1512,22 +1921,39 @@ mod asyncio {
             Ok(synthesizer.metas().into())
         }
 
-        #[pyo3(signature = (model, *, on_existing = Default::default()))]
+        #[pyo3(signature = (
+            model,
+            *,
+            on_existing = Default::default(),
+            style_types = None,
+            style_ids = None,
+            trusted_public_keys = None,
+        ))]
         async fn load_voice_model(
             &self,
             model: Py<VoiceModelFile>,
             #[pyo3(from_py_with = crate::convert::from_on_existing_voice_model_id)]
             on_existing: OnExistingVoiceModelId,
+            #[pyo3(from_py_with = crate::convert::from_style_types)] style_types: Option<
+                Vec<StyleType>,
+            >,
+            #[pyo3(from_py_with = crate::convert::from_style_ids)] style_ids: Option<Vec<StyleId>>,
+            trusted_public_keys: Option<Vec<[u8; 32]>>,
         ) -> PyResult<()> {
             let model = &*model.get().model.read()?;
-            let result = self
-                .synthesizer
-                .clone()
-                .read()?
-                .load_voice_model(model)
-                .on_existing(on_existing)
-                .perform()
-                .await;
+            let synthesizer = self.synthesizer.clone();
+            let synthesizer = synthesizer.read()?;
+            let mut load = synthesizer.load_voice_model(model).on_existing(on_existing);
+            if let Some(style_types) = style_types {
+                load = load.style_types(style_types);
+            }
+            if let Some(style_ids) = style_ids {
+                load = load.style_ids(style_ids);
+            }
+            if let Some(trusted_public_keys) = trusted_public_keys {
+                load = load.trusted_public_keys(trusted_public_keys);
+            }
+            let result = load.perform().await;
             Python::attach(|py| result.into_py_result(py))
         }
 
@@ -1646,6 +2072,52 @@ mod asyncio {
             Python::attach(|py| phrases.into_py_result(py))
         }
 
+        #[pyo3(signature=(
+            audio_query,
+            style_id,
+            *,
+            enable_interrogative_upspeak =
+                voicevox_core::__internal::interop::DEFAULT_ENABLE_INTERROGATIVE_UPSPEAK,
+        ))]
+        async fn precompute_render(
+            &self,
+            #[pyo3(from_py_with = crate::convert::from_audio_query)] audio_query: AudioQuery,
+            style_id: u32,
+            enable_interrogative_upspeak: bool,
+        ) -> PyResult<AudioFeature> {
+            let audio = self
+                .synthesizer
+                .read()?
+                .__precompute_render(&audio_query, StyleId::new(style_id))
+                .enable_interrogative_upspeak(enable_interrogative_upspeak)
+                .perform()
+                .await;
+            let audio = Python::attach(|py| audio.into_py_result(py))?;
+            Ok(AudioFeature { audio })
+        }
+
+        async fn render(
+            &self,
+            audio: Py<AudioFeature>,
+            start: usize,
+            stop: usize,
+        ) -> PyResult<Vec<u8>> {
+            let audio = &audio.get().audio;
+            if start > audio.frame_length() || stop > audio.frame_length() {
+                return Err(PyIndexError::new_err(format!(
+                    "({start}, {stop}) is out of range for audio feature of length {len}",
+                    len = audio.frame_length(),
+                )));
+            }
+            if start > stop {
+                return Err(PyValueError::new_err(format!(
+                    "({start}, {stop}) is invalid range because start > end",
+                )));
+            }
+            let pcm = self.synthesizer.read()?.__render(audio, start..stop).await;
+            Python::attach(|py| pcm.into_py_result(py))
+        }
+
         #[pyo3(signature=(
             audio_query,
             style_id,
@@ -1672,6 +2144,69 @@ mod asyncio {
             Python::attach(|py| wav.into_py_result(py))
         }
 
+        #[pyo3(signature=(
+            audio_query,
+            style_id,
+            *,
+            enable_interrogative_upspeak =
+                voicevox_core::__internal::interop::DEFAULT_ENABLE_INTERROGATIVE_UPSPEAK,
+        ))]
+        async fn synthesis_array(
+            &self,
+            #[pyo3(from_py_with = crate::convert::from_audio_query)] audio_query: AudioQuery,
+            style_id: u32,
+            enable_interrogative_upspeak: bool,
+        ) -> PyResult<Py<PyAny>> {
+            let wave = self
+                .synthesizer
+                .read()?
+                .synthesis(&audio_query, StyleId::new(style_id))
+                .enable_interrogative_upspeak(enable_interrogative_upspeak)
+                .__perform_wave()
+                .await;
+            Python::attach(|py| {
+                let wave = wave.into_py_result(py)?;
+                crate::to_ndarray(py, wave, audio_query.output_stereo).map(Bound::unbind)
+            })
+        }
+
+        #[pyo3(signature=(
+            audio_query,
+            style_id,
+            *,
+            chunk_length = None,
+            enable_interrogative_upspeak =
+                voicevox_core::__internal::interop::DEFAULT_ENABLE_INTERROGATIVE_UPSPEAK,
+        ))]
+        async fn synthesis_stream(
+            &self,
+            #[pyo3(from_py_with = crate::convert::from_audio_query)] audio_query: AudioQuery,
+            style_id: u32,
+            chunk_length: Option<usize>,
+            enable_interrogative_upspeak: bool,
+        ) -> PyResult<AudioChunks> {
+            if chunk_length == Some(0) {
+                return Err(PyValueError::new_err("`chunk_length` must be positive"));
+            }
+            let synthesizer = self.synthesizer.clone();
+            let audio = synthesizer
+                .read()?
+                .__precompute_render(&audio_query, StyleId::new(style_id))
+                .enable_interrogative_upspeak(enable_interrogative_upspeak)
+                .perform()
+                .await;
+            let audio = Python::attach(|py| audio.into_py_result(py))?;
+            let chunk_length =
+                chunk_length.unwrap_or_else(|| crate::default_chunk_length(audio.frame_rate));
+            Ok(AudioChunks {
+                synthesizer,
+                audio,
+                is_stereo: audio_query.output_stereo,
+                chunk_length,
+                position: Default::default(),
+            })
+        }
+
         #[pyo3(signature=(
             kana,
             style_id,
@@ -0,0 +1,48 @@
+use std::{error::Error as _, iter};
+
+use serde::{Serialize, de::DeserializeOwned};
+use wasm_bindgen::{JsCast as _, JsValue};
+
+/// [`voicevox_core::Error`]をJavaScriptの`Error`にする。
+///
+/// `name`は`{ErrorKind}Error`（例: `InvalidModelDataError`）となり、`cause`にはエラーの原因が連なる。
+pub(crate) fn to_js_error(err: voicevox_core::Error) -> JsValue {
+    let error = iter::successors(err.source(), |&source| source.source())
+        .map(|source| source.to_string())
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .fold(None::<js_sys::Error>, |cause, message| {
+            let error = js_sys::Error::new(&message);
+            if let Some(cause) = cause {
+                error.set_cause(&cause);
+            }
+            Some(error)
+        });
+
+    let top = js_sys::Error::new(&err.to_string());
+    top.set_name(&format!("{:?}Error", err.kind()));
+    if let Some(cause) = error {
+        top.set_cause(&cause);
+    }
+    top.unchecked_into()
+}
+
+/// 引数の型が不正であることを表す`TypeError`を作る。
+pub(crate) fn type_error(what: &str, err: impl std::fmt::Display) -> JsValue {
+    js_sys::TypeError::new(&format!("不正な{what}です: {err}")).unchecked_into()
+}
+
+/// Serdeの実装に従い、JavaScriptの値からRustの値にする。
+pub(crate) fn from_js<T: DeserializeOwned>(value: JsValue, what: &str) -> Result<T, JsValue> {
+    serde_wasm_bindgen::from_value(value).map_err(|err| type_error(what, err))
+}
+
+/// Serdeの実装に従い、Rustの値をJavaScriptの値にする。
+///
+/// マップはJavaScriptの`Map`ではなくオブジェクトとなる。
+pub(crate) fn to_js(value: &impl Serialize) -> Result<JsValue, JsValue> {
+    value
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(Into::into)
+}
@@ -0,0 +1,39 @@
+//! VOICEVOX COREのWebAssembly版。
+//!
+//! [wasm-bindgen]によりJavaScriptから利用できるようにする。推論には[onnxruntime-web]を用いる。
+//!
+//! ```js
+//! import { initOnnxruntime, Synthesizer, VoiceModelFile } from "voicevox_core_wasm";
+//!
+//! await initOnnxruntime();
+//! const synthesizer = new Synthesizer();
+//! const model = await VoiceModelFile.fromBytes(new Uint8Array(await (await fetch("0.vvm")).arrayBuffer()));
+//! await synthesizer.loadVoiceModel(model);
+//! const audioQuery = await synthesizer.createAudioQueryFromKana("コンニチワ'", 0);
+//! const wav = await synthesizer.synthesis(audioQuery, 0);
+//! ```
+//!
+//! # 制限
+//!
+//! - Open JTalkはWASM向けにビルドできないため、テキスト解析を伴うAPI（`createAudioQuery`や`tts`
+//!   など）は無い。AquesTalk風記法を用いるか、歌唱音声合成を用いること。
+//! - ファイルシステムが無いため、VVMはバイト列から開く。
+//! - vv-bin形式のモデルはonnxruntime-webでは扱えない。
+//! - スレッドを用いないため、VVMの展開や推論を含む全ての処理はJavaScriptのイベントループ上で行われる。`Promise`
+//!   を返すAPIであっても、処理の間はイベントループがブロックされる。UIを止めたくない場合はWeb Worker内で用いること。
+//!
+//! クエリなどのJavaScriptでの表現は、Rust APIのSerdeの実装に従う。
+//!
+//! [wasm-bindgen]: https://docs.rs/crate/wasm-bindgen
+//! [onnxruntime-web]: https://onnxruntime.ai/docs/get-started/with-javascript/web.html
+
+#![cfg(target_family = "wasm")]
+
+mod convert;
+mod onnxruntime;
+mod synthesizer;
+mod voice_model;
+
+pub use self::{
+    onnxruntime::init_onnxruntime, synthesizer::Synthesizer, voice_model::VoiceModelFile,
+};
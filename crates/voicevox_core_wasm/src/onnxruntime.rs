@@ -0,0 +1,16 @@
+use wasm_bindgen::{JsValue, prelude::wasm_bindgen};
+
+use crate::convert::to_js_error;
+
+/// onnxruntime-webを読み込み、VOICEVOX COREのONNX Runtimeとして初期化する。
+///
+/// [`Synthesizer`]を作る前に一度実行する必要がある。二度目以降は何もしない。
+///
+/// [`Synthesizer`]: crate::Synthesizer
+#[wasm_bindgen(js_name = initOnnxruntime)]
+pub async fn init_onnxruntime() -> Result<(), JsValue> {
+    voicevox_core::nonblocking::Onnxruntime::load_web_once()
+        .await
+        .map_err(to_js_error)?;
+    Ok(())
+}
@@ -0,0 +1,260 @@
+use std::{future::Future, rc::Rc};
+
+use js_sys::{Float32Array, Promise, Uint8Array};
+use uuid::Uuid;
+use voicevox_core::{
+    AccelerationMode, AccentPhrase, AudioQuery, FrameAudioQuery, Score, StyleId, VoiceModelId,
+};
+use wasm_bindgen::{JsValue, prelude::wasm_bindgen};
+use wasm_bindgen_futures::future_to_promise;
+
+use crate::{
+    VoiceModelFile,
+    convert::{from_js, to_js, to_js_error, type_error},
+};
+
+/// 音声シンセサイザ。
+///
+/// メソッドは全て`Promise`を返す。推論はJavaScriptのイベントループ上で行われる。
+#[wasm_bindgen]
+pub struct Synthesizer(Rc<voicevox_core::nonblocking::Synthesizer<()>>);
+
+#[wasm_bindgen]
+impl Synthesizer {
+    /// `Synthesizer`を作る。
+    ///
+    /// 先に`initOnnxruntime()`を実行しておく必要がある。
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Result<Synthesizer, JsValue> {
+        let onnxruntime = voicevox_core::nonblocking::Onnxruntime::get()
+            .ok_or_else(|| js_sys::Error::new("`initOnnxruntime()`が実行されていません"))?;
+        let synthesizer = voicevox_core::nonblocking::Synthesizer::builder(onnxruntime)
+            .acceleration_mode(AccelerationMode::Cpu)
+            .build()
+            .map_err(to_js_error)?;
+        Ok(Self(synthesizer.into()))
+    }
+
+    /// 今読み込んでいる音声モデルのメタ情報。
+    #[wasm_bindgen(getter)]
+    pub fn metas(&self) -> Result<JsValue, JsValue> {
+        to_js(&self.0.metas())
+    }
+
+    /// 音声モデルを読み込む。
+    #[wasm_bindgen(js_name = loadVoiceModel)]
+    pub fn load_voice_model(&self, model: &VoiceModelFile) -> Promise {
+        let model = model.0.clone();
+        self.spawn(async move |synthesizer| {
+            synthesizer
+                .load_voice_model(&model)
+                .perform()
+                .await
+                .map_err(to_js_error)?;
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
+    /// 音声モデルの読み込みを解除する。
+    #[wasm_bindgen(js_name = unloadVoiceModel)]
+    pub fn unload_voice_model(&self, voice_model_id: &str) -> Result<(), JsValue> {
+        self.0
+            .unload_voice_model(voice_model_id_from_str(voice_model_id)?)
+            .map_err(to_js_error)
+    }
+
+    /// 指定したIDの音声モデルが読み込まれているか判定する。
+    #[wasm_bindgen(js_name = isLoadedVoiceModel)]
+    pub fn is_loaded_voice_model(&self, voice_model_id: &str) -> Result<bool, JsValue> {
+        Ok(self
+            .0
+            .is_loaded_voice_model(voice_model_id_from_str(voice_model_id)?))
+    }
+
+    /// AquesTalk風記法から`AudioQuery`を生成する。
+    #[wasm_bindgen(js_name = createAudioQueryFromKana)]
+    pub fn create_audio_query_from_kana(&self, kana: String, style_id: u32) -> Promise {
+        self.spawn(async move |synthesizer| {
+            let audio_query = synthesizer
+                .create_audio_query_from_kana(&kana, StyleId::new(style_id))
+                .await
+                .map_err(to_js_error)?;
+            to_js(&audio_query)
+        })
+    }
+
+    /// AquesTalk風記法からアクセント句の配列を生成する。
+    #[wasm_bindgen(js_name = createAccentPhrasesFromKana)]
+    pub fn create_accent_phrases_from_kana(&self, kana: String, style_id: u32) -> Promise {
+        self.spawn(async move |synthesizer| {
+            let accent_phrases = synthesizer
+                .create_accent_phrases_from_kana(&kana, StyleId::new(style_id))
+                .await
+                .map_err(to_js_error)?;
+            to_js(&accent_phrases)
+        })
+    }
+
+    /// アクセント句の音高・音素長を変更した新しいアクセント句の配列を生成する。
+    #[wasm_bindgen(js_name = replaceMoraData)]
+    pub fn replace_mora_data(&self, accent_phrases: JsValue, style_id: u32) -> Promise {
+        self.spawn(async move |synthesizer| {
+            let accent_phrases = from_js::<Vec<AccentPhrase>>(accent_phrases, "アクセント句の列")?;
+            let accent_phrases = synthesizer
+                .replace_mora_data(&accent_phrases, StyleId::new(style_id))
+                .await
+                .map_err(to_js_error)?;
+            to_js(&accent_phrases)
+        })
+    }
+
+    /// アクセント句の音素長を変更した新しいアクセント句の配列を生成する。
+    #[wasm_bindgen(js_name = replacePhonemeLength)]
+    pub fn replace_phoneme_length(&self, accent_phrases: JsValue, style_id: u32) -> Promise {
+        self.spawn(async move |synthesizer| {
+            let accent_phrases = from_js::<Vec<AccentPhrase>>(accent_phrases, "アクセント句の列")?;
+            let accent_phrases = synthesizer
+                .replace_phoneme_length(&accent_phrases, StyleId::new(style_id))
+                .await
+                .map_err(to_js_error)?;
+            to_js(&accent_phrases)
+        })
+    }
+
+    /// アクセント句の音高を変更した新しいアクセント句の配列を生成する。
+    #[wasm_bindgen(js_name = replaceMoraPitch)]
+    pub fn replace_mora_pitch(&self, accent_phrases: JsValue, style_id: u32) -> Promise {
+        self.spawn(async move |synthesizer| {
+            let accent_phrases = from_js::<Vec<AccentPhrase>>(accent_phrases, "アクセント句の列")?;
+            let accent_phrases = synthesizer
+                .replace_mora_pitch(&accent_phrases, StyleId::new(style_id))
+                .await
+                .map_err(to_js_error)?;
+            to_js(&accent_phrases)
+        })
+    }
+
+    /// `AudioQuery`から音声合成を行い、WAVデータを返す。
+    pub fn synthesis(
+        &self,
+        audio_query: JsValue,
+        style_id: u32,
+        enable_interrogative_upspeak: Option<bool>,
+    ) -> Promise {
+        self.spawn(async move |synthesizer| {
+            let audio_query = from_js::<AudioQuery>(audio_query, "AudioQuery")?;
+            let mut synthesis = synthesizer.synthesis(&audio_query, StyleId::new(style_id));
+            if let Some(enable_interrogative_upspeak) = enable_interrogative_upspeak {
+                synthesis = synthesis.enable_interrogative_upspeak(enable_interrogative_upspeak);
+            }
+            let wav = synthesis.perform().await.map_err(to_js_error)?;
+            Ok(Uint8Array::from(&*wav).into())
+        })
+    }
+
+    /// AquesTalk風記法から音声合成を行い、WAVデータを返す。
+    #[wasm_bindgen(js_name = ttsFromKana)]
+    pub fn tts_from_kana(
+        &self,
+        kana: String,
+        style_id: u32,
+        enable_interrogative_upspeak: Option<bool>,
+    ) -> Promise {
+        self.spawn(async move |synthesizer| {
+            let mut tts = synthesizer.tts_from_kana(&kana, StyleId::new(style_id));
+            if let Some(enable_interrogative_upspeak) = enable_interrogative_upspeak {
+                tts = tts.enable_interrogative_upspeak(enable_interrogative_upspeak);
+            }
+            let wav = tts.perform().await.map_err(to_js_error)?;
+            Ok(Uint8Array::from(&*wav).into())
+        })
+    }
+
+    /// 楽譜から歌唱音声合成用のクエリを生成する。
+    #[wasm_bindgen(js_name = createSingFrameAudioQuery)]
+    pub fn create_sing_frame_audio_query(&self, score: JsValue, style_id: u32) -> Promise {
+        self.spawn(async move |synthesizer| {
+            let score = from_js::<Score>(score, "楽譜")?;
+            let frame_audio_query = synthesizer
+                .create_sing_frame_audio_query(&score, StyleId::new(style_id))
+                .await
+                .map_err(to_js_error)?;
+            to_js(&frame_audio_query)
+        })
+    }
+
+    /// 楽譜と歌唱音声合成用のクエリから、フレームごとの基本周波数を生成する。
+    #[wasm_bindgen(js_name = createSingFrameF0)]
+    pub fn create_sing_frame_f0(
+        &self,
+        score: JsValue,
+        frame_audio_query: JsValue,
+        style_id: u32,
+    ) -> Promise {
+        self.spawn(async move |synthesizer| {
+            let score = from_js::<Score>(score, "楽譜")?;
+            let frame_audio_query =
+                from_js::<FrameAudioQuery>(frame_audio_query, "FrameAudioQuery")?;
+            let f0 = synthesizer
+                .create_sing_frame_f0(&score, &frame_audio_query, StyleId::new(style_id))
+                .await
+                .map_err(to_js_error)?;
+            let f0 = f0.into_iter().map(f32::from).collect::<Vec<_>>();
+            Ok(Float32Array::from(&*f0).into())
+        })
+    }
+
+    /// 楽譜と歌唱音声合成用のクエリから、フレームごとの音量を生成する。
+    #[wasm_bindgen(js_name = createSingFrameVolume)]
+    pub fn create_sing_frame_volume(
+        &self,
+        score: JsValue,
+        frame_audio_query: JsValue,
+        style_id: u32,
+    ) -> Promise {
+        self.spawn(async move |synthesizer| {
+            let score = from_js::<Score>(score, "楽譜")?;
+            let frame_audio_query =
+                from_js::<FrameAudioQuery>(frame_audio_query, "FrameAudioQuery")?;
+            let volume = synthesizer
+                .create_sing_frame_volume(&score, &frame_audio_query, StyleId::new(style_id))
+                .await
+                .map_err(to_js_error)?;
+            let volume = volume.into_iter().map(f32::from).collect::<Vec<_>>();
+            Ok(Float32Array::from(&*volume).into())
+        })
+    }
+
+    /// 歌唱音声合成を行い、WAVデータを返す。
+    #[wasm_bindgen(js_name = frameSynthesis)]
+    pub fn frame_synthesis(&self, frame_audio_query: JsValue, style_id: u32) -> Promise {
+        self.spawn(async move |synthesizer| {
+            let frame_audio_query =
+                from_js::<FrameAudioQuery>(frame_audio_query, "FrameAudioQuery")?;
+            let wav = synthesizer
+                .frame_synthesis(&frame_audio_query, StyleId::new(style_id))
+                .perform()
+                .await
+                .map_err(to_js_error)?;
+            Ok(Uint8Array::from(&*wav).into())
+        })
+    }
+}
+
+impl Synthesizer {
+    fn spawn<F>(
+        &self,
+        f: impl FnOnce(Rc<voicevox_core::nonblocking::Synthesizer<()>>) -> F,
+    ) -> Promise
+    where
+        F: Future<Output = Result<JsValue, JsValue>> + 'static,
+    {
+        future_to_promise(f(self.0.clone()))
+    }
+}
+
+fn voice_model_id_from_str(s: &str) -> Result<VoiceModelId, JsValue> {
+    Uuid::parse_str(s)
+        .map(VoiceModelId)
+        .map_err(|err| type_error("音声モデルID", err))
+}
@@ -0,0 +1,35 @@
+use std::rc::Rc;
+
+use wasm_bindgen::{JsValue, prelude::wasm_bindgen};
+
+use crate::convert::{to_js, to_js_error};
+
+/// 音声モデルファイル。
+///
+/// VVMファイルと対応する。ファイルシステムが無いため、バイト列から開く。閉じるには`free()`する。
+#[wasm_bindgen]
+pub struct VoiceModelFile(pub(crate) Rc<voicevox_core::nonblocking::VoiceModelFile>);
+
+#[wasm_bindgen]
+impl VoiceModelFile {
+    /// VVMファイルの中身から開く。
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub async fn from_bytes(bytes: Vec<u8>) -> Result<VoiceModelFile, JsValue> {
+        let model = voicevox_core::nonblocking::VoiceModelFile::from_bytes(bytes)
+            .await
+            .map_err(to_js_error)?;
+        Ok(Self(model.into()))
+    }
+
+    /// ID。UUIDの文字列表現。
+    #[wasm_bindgen(getter)]
+    pub fn id(&self) -> String {
+        self.0.id().to_string()
+    }
+
+    /// メタ情報。
+    #[wasm_bindgen(getter)]
+    pub fn metas(&self) -> Result<JsValue, JsValue> {
+        to_js(self.0.metas())
+    }
+}
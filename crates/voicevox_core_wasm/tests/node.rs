@@ -0,0 +1,51 @@
+//! Node上で動かすテスト。
+//!
+//! `wasm-pack test --node crates/voicevox_core_wasm`で実行する。事前に`cargo build -p test_util`
+//! などで、test_util/data/model/sample.vvmを作っておく必要がある。
+
+#![cfg(target_family = "wasm")]
+
+use js_sys::{Reflect, Uint8Array};
+use voicevox_core_wasm::{Synthesizer, VoiceModelFile, init_onnxruntime};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const SAMPLE_VOICE_MODEL_FILE: &[u8] = include_bytes!("../../test_util/data/model/sample.vvm");
+
+#[wasm_bindgen_test]
+async fn voice_model_file_can_be_opened_from_bytes() {
+    let model = VoiceModelFile::from_bytes(SAMPLE_VOICE_MODEL_FILE.to_owned())
+        .await
+        .unwrap();
+    assert_eq!("018fa5b1-146c-71e9-b523-6f6dabcf05fe", model.id());
+}
+
+#[wasm_bindgen_test]
+async fn voice_model_file_rejects_non_zip() {
+    let Err(err) = VoiceModelFile::from_bytes(b"not a zip".to_vec()).await else {
+        panic!("should fail");
+    };
+    let name = Reflect::get(&err, &JsValue::from_str("name")).unwrap();
+    assert_eq!(Some("OpenZipFileError".to_owned()), name.as_string());
+}
+
+#[wasm_bindgen_test]
+async fn tts_from_kana_works() {
+    init_onnxruntime().await.unwrap();
+    let synthesizer = Synthesizer::new().unwrap();
+
+    let model = VoiceModelFile::from_bytes(SAMPLE_VOICE_MODEL_FILE.to_owned())
+        .await
+        .unwrap();
+    JsFuture::from(synthesizer.load_voice_model(&model))
+        .await
+        .unwrap();
+    assert!(synthesizer.is_loaded_voice_model(&model.id()).unwrap());
+
+    let wav = JsFuture::from(synthesizer.tts_from_kana("コンニチワ'".to_owned(), 302, None))
+        .await
+        .unwrap();
+    let wav = Uint8Array::new(&wav).to_vec();
+    assert_eq!(b"RIFF", &wav[..4]);
+}
@@ -0,0 +1,150 @@
+//! VVMの作成と検査を行うコマンド。
+
+use std::{io, path::PathBuf, process::ExitCode};
+
+use anyhow::Context as _;
+use clap::Parser as _;
+use voicevox_core::{
+    blocking::{Onnxruntime, VoiceModelFile},
+    vvm::{self, Severity},
+};
+
+#[derive(clap::Parser)]
+#[command(name("vvm"), about("VVMの作成と検査を行う"))]
+enum Args {
+    /// ディレクトリ内の`manifest.json`とそれが参照するファイルをVVMにまとめる
+    Pack {
+        /// `manifest.json`があるディレクトリ
+        dir: PathBuf,
+
+        /// 出力先のVVM
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+
+    /// VVMを検査する
+    ///
+    /// 複数のVVMを与えた場合、それらの間でスタイルIDが衝突していないかも検査する。
+    Lint {
+        #[arg(required(true))]
+        vvms: Vec<PathBuf>,
+
+        /// ONNX Runtimeのファイルパス。指定した場合、各モデルファイルの入出力も検査する
+        #[arg(long, value_name("PATH"))]
+        onnxruntime: Option<PathBuf>,
+    },
+
+    /// `vvm_format_version=1`のVVMを`vvm_format_version=2`に変換する
+    Upgrade {
+        vvm: PathBuf,
+
+        /// 出力先のVVM。指定しない場合は上書きする
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// VVMにEd25519で署名する
+    ///
+    /// 各ファイルのSHA-256ダイジェストを`manifest.json`に書き込み、`manifest.json`の署名を
+    /// `manifest.json.sig`として加える。署名に対応する公開鍵を標準出力に16進数で出力する。
+    Sign {
+        vvm: PathBuf,
+
+        /// 16進数で書かれた、Ed25519の秘密鍵（32バイト）のファイル
+        #[arg(long, value_name("PATH"))]
+        signing_key: PathBuf,
+
+        /// 出力先のVVM。指定しない場合は上書きする
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+fn main() -> anyhow::Result<ExitCode> {
+    setup_logger();
+
+    match Args::parse() {
+        Args::Pack { dir, output } => {
+            let vvm = vvm::pack(&dir)?;
+            fs_err::write(output, vvm)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Args::Lint { vvms, onnxruntime } => {
+            let onnxruntime = onnxruntime
+                .map(|filename| Onnxruntime::load_once().filename(filename).perform())
+                .transpose()?;
+
+            let models = vvms
+                .iter()
+                .map(|path| {
+                    let model = VoiceModelFile::open(path)
+                        .with_context(|| format!("`{}`を開けませんでした", path.display()))?;
+                    Ok((path, model))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?;
+
+            let diagnostics = vvm::lint(models.iter().map(|(_, model)| model), onnxruntime);
+
+            let mut has_error = false;
+            for vvm::Diagnostic {
+                voice_model_id,
+                problem,
+                ..
+            } in diagnostics
+            {
+                let (path, _) = models
+                    .iter()
+                    .find(|(_, model)| model.id() == voice_model_id)
+                    .expect("should be one of `models`");
+                let severity = problem.severity();
+                has_error |= severity == Severity::Error;
+                eprintln!(
+                    "{path}: {severity}: {problem:#}",
+                    path = path.display(),
+                    problem = anyhow::Error::from(problem),
+                );
+            }
+            Ok(if has_error {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            })
+        }
+        Args::Upgrade { vvm, output } => {
+            match vvm::upgrade(&fs_err::read(&vvm)?)? {
+                Some(upgraded) => fs_err::write(output.as_ref().unwrap_or(&vvm), upgraded)?,
+                None => eprintln!("`{}`は既に最新の形式です", vvm.display()),
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Args::Sign {
+            vvm,
+            signing_key: signing_key_path,
+            output,
+        } => {
+            let signing_key = &mut [0; 32];
+            hex::decode_to_slice(
+                fs_err::read_to_string(&signing_key_path)?.trim(),
+                signing_key,
+            )
+            .with_context(|| format!("`{}`が不正です", signing_key_path.display()))?;
+
+            let signed = vvm::sign(&fs_err::read(&vvm)?, signing_key)?;
+            fs_err::write(output.as_ref().unwrap_or(&vvm), signed)?;
+            println!("{}", hex::encode(vvm::public_key(signing_key)));
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
+
+fn setup_logger() {
+    tracing_subscriber::fmt()
+        .with_env_filter(format!(
+            "error,voicevox_core=warn,{}=info",
+            env!("CARGO_CRATE_NAME")
+        ))
+        .with_writer(io::stderr)
+        .with_target(false)
+        .without_time()
+        .init();
+}
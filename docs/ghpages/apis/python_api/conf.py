@@ -65,6 +65,8 @@ def _on_missing_reference(
     TARGETS = {
         "AccelerationMode",
         "CharacterVersion",
+        "InferenceOperationKind",
+        "OperationDevice",
         "StyleId",
         "StyleType",
         "UserDictWordType",